@@ -0,0 +1,298 @@
+//! Report of differences between two versions of the schema.
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::tl::{Definition, Schema};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    ParamAdded {
+        name: String,
+        type_: String,
+    },
+    ParamRemoved {
+        name: String,
+        type_: String,
+    },
+    ParamTypeChanged {
+        name: String,
+        old: String,
+        new: String,
+    },
+    ResultChanged {
+        old: String,
+        new: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::ParamAdded { name, type_ } => write!(f, "added `{}:{}`", name, type_),
+            Change::ParamRemoved { name, type_ } => write!(f, "removed `{}:{}`", name, type_),
+            Change::ParamTypeChanged { name, old, new } => {
+                write!(f, "`{}` changed from `{}` to `{}`", name, old, new)
+            }
+            Change::ResultChanged { old, new } => {
+                write!(f, "result changed from `{}` to `{}`", old, new)
+            }
+        }
+    }
+}
+
+/// Differences of constructors or functions of two schemas.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section {
+    pub added: Vec<Definition>,
+    pub removed: Vec<Definition>,
+    pub changed: BTreeMap<String, Vec<Change>>,
+}
+
+impl Section {
+    fn new(old: &[Definition], new: &[Definition]) -> Self {
+        let old: BTreeMap<_, _> = old.iter().map(|d| (d.name.as_str(), d)).collect();
+        let new: BTreeMap<_, _> = new.iter().map(|d| (d.name.as_str(), d)).collect();
+        let mut section = Section::default();
+        for (name, definition) in &new {
+            match old.get(name) {
+                None => section.added.push((*definition).clone()),
+                Some(old) => {
+                    let changes = changes(old, definition);
+                    if !changes.is_empty() {
+                        section.changed.insert(name.to_string(), changes);
+                    }
+                }
+            }
+        }
+        for (name, definition) in &old {
+            if !new.contains_key(name) {
+                section.removed.push((*definition).clone());
+            }
+        }
+        section
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, title: &str) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "## {}", title)?;
+        if !self.added.is_empty() {
+            writeln!(f, "\n### Added\n")?;
+            for definition in &self.added {
+                writeln!(f, "- `{}`", signature(definition))?;
+            }
+        }
+        if !self.removed.is_empty() {
+            writeln!(f, "\n### Removed\n")?;
+            for definition in &self.removed {
+                writeln!(f, "- `{}`", signature(definition))?;
+            }
+        }
+        if !self.changed.is_empty() {
+            writeln!(f, "\n### Changed\n")?;
+            for (name, changes) in &self.changed {
+                let changes: Vec<_> = changes.iter().map(ToString::to_string).collect();
+                writeln!(f, "- `{}`: {}", name, changes.join(", "))?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+/// Added, removed and changed classes, constructors and functions. Changes of documentation are ignored.
+///
+/// Displayed as Markdown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub added_classes: Vec<String>,
+    pub removed_classes: Vec<String>,
+    pub types: Section,
+    pub functions: Section,
+}
+
+impl Report {
+    pub fn new(old: &Schema, new: &Schema) -> Self {
+        Report {
+            added_classes: new
+                .classes
+                .keys()
+                .filter(|c| !old.classes.contains_key(*c))
+                .cloned()
+                .collect(),
+            removed_classes: old
+                .classes
+                .keys()
+                .filter(|c| !new.classes.contains_key(*c))
+                .cloned()
+                .collect(),
+            types: Section::new(&old.types, &new.types),
+            functions: Section::new(&old.functions, &new.functions),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added_classes.is_empty()
+            && self.removed_classes.is_empty()
+            && self.types.is_empty()
+            && self.functions.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+        if !self.added_classes.is_empty() || !self.removed_classes.is_empty() {
+            writeln!(f, "## Classes\n")?;
+            for class in &self.added_classes {
+                writeln!(f, "- added `{}`", class)?;
+            }
+            for class in &self.removed_classes {
+                writeln!(f, "- removed `{}`", class)?;
+            }
+            writeln!(f)?;
+        }
+        self.types.write(f, "Constructors")?;
+        self.functions.write(f, "Functions")
+    }
+}
+
+fn signature(definition: &Definition) -> String {
+    let mut signature = definition.name.clone();
+    for param in &definition.params {
+        signature.push_str(&format!(" {}:{}", param.name, param.type_));
+    }
+    format!("{} = {}", signature, definition.result)
+}
+
+fn changes(old: &Definition, new: &Definition) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for param in &new.params {
+        match old.params.iter().find(|p| p.name == param.name) {
+            None => changes.push(Change::ParamAdded {
+                name: param.name.clone(),
+                type_: param.type_.clone(),
+            }),
+            Some(old) if old.type_ != param.type_ => changes.push(Change::ParamTypeChanged {
+                name: param.name.clone(),
+                old: old.type_.clone(),
+                new: param.type_.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for param in &old.params {
+        if !new.params.iter().any(|p| p.name == param.name) {
+            changes.push(Change::ParamRemoved {
+                name: param.name.clone(),
+                type_: param.type_.clone(),
+            });
+        }
+    }
+    if old.result != new.result {
+        changes.push(Change::ResultChanged {
+            old: old.result.clone(),
+            new: new.result.clone(),
+        });
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, Report};
+    use crate::tl::Schema;
+
+    #[test]
+    fn test_report() {
+        let old = Schema::parse(
+            r#"
+//@class ChatList @description Describes a list of chats
+
+//@description A main list of chats
+chatListMain = ChatList;
+
+//@description A list of chats belonging to a chat filter @chat_filter_id Chat filter identifier
+chatListFilter chat_filter_id:int32 = ChatList;
+
+---functions---
+
+//@description Returns chats @chat_list The chat list @limit The maximum number of chats
+getChats chat_list:ChatList limit:int32 = Chats;
+
+//@description Logs out
+logOut = Ok;
+"#,
+        )
+        .unwrap();
+        let new = Schema::parse(
+            r#"
+//@class ChatList @description Describes a list of chats
+
+//@description A main list of chats, documented differently
+chatListMain = ChatList;
+
+//@description A list of chats belonging to a chat folder @chat_folder_id Chat folder identifier
+chatListFolder chat_folder_id:int32 = ChatList;
+
+---functions---
+
+//@description Returns chats @chat_list The chat list @limit The maximum number of chats
+getChats chat_list:ChatList limit:int53 = Chats;
+
+//@description Loads chats @chat_list The chat list @limit The maximum number of chats
+loadChats chat_list:ChatList limit:int32 = Ok;
+
+//@description Logs out @reason Reason
+logOut reason:string = Ok;
+"#,
+        )
+        .unwrap();
+        let report = Report::new(&old, &new);
+        assert!(report.added_classes.is_empty());
+        assert_eq!(report.types.added[0].name, "chatListFolder");
+        assert_eq!(report.types.removed[0].name, "chatListFilter");
+        assert!(report.types.changed.is_empty());
+        assert_eq!(report.functions.added[0].name, "loadChats");
+        assert_eq!(
+            report.functions.changed["getChats"],
+            vec![Change::ParamTypeChanged {
+                name: "limit".to_string(),
+                old: "int32".to_string(),
+                new: "int53".to_string()
+            }]
+        );
+        assert_eq!(
+            report.to_string(),
+            "## Constructors
+
+### Added
+
+- `chatListFolder chat_folder_id:int32 = ChatList`
+
+### Removed
+
+- `chatListFilter chat_filter_id:int32 = ChatList`
+
+## Functions
+
+### Added
+
+- `loadChats chat_list:ChatList limit:int32 = Ok`
+
+### Changed
+
+- `getChats`: `limit` changed from `int32` to `int53`
+- `logOut`: added `reason:string`
+
+"
+        );
+        assert_eq!(Report::new(&new, &new).to_string(), "No changes.\n");
+    }
+}
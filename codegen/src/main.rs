@@ -0,0 +1,146 @@
+//! Generator of `rust-tdlib` types and `Client` methods from TDLib API schema.
+//!
+//! ```text
+//! tdlib-codegen generate <td_api.tl> [--out <crate dir>] [--no-fmt]
+//! tdlib-codegen diff <old td_api.tl> <new td_api.tl>
+//! ```
+//!
+//! `generate` rewrites `src/types` (except the hand-written part of `_common.rs`) and `src/client/api.rs`
+//! of the crate, `diff` prints a Markdown report of changes between two versions of the schema.
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+mod diff;
+mod model;
+mod overrides;
+mod render;
+mod tl;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const USAGE: &str = "Usage:
+    tdlib-codegen generate <td_api.tl> [--out <crate dir>] [--no-fmt]
+    tdlib-codegen diff <old td_api.tl> <new td_api.tl>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("generate") => generate(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn read_schema(path: &str) -> Result<tl::Schema> {
+    let source = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    Ok(tl::Schema::parse(&source).map_err(|e| format!("{}: {}", path, e))?)
+}
+
+fn generate(args: &[String]) -> Result<()> {
+    let mut schema = None;
+    let mut out = PathBuf::from(".");
+    let mut format = true;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = args.next().ok_or(USAGE)?.into(),
+            "--no-fmt" => format = false,
+            path if schema.is_none() => schema = Some(path),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let schema = read_schema(schema.ok_or(USAGE)?)?;
+    let model = model::Model::new(&schema)?;
+
+    let files = render::files(&model);
+    let types_dir = out.join("src/types");
+    let generated: BTreeSet<PathBuf> = files.iter().map(|f| out.join(&f.path)).collect();
+    // files of types removed from the schema
+    for entry in fs::read_dir(&types_dir)? {
+        let path = entry?.path();
+        let is_stale = path.extension().is_some_and(|e| e == "rs")
+            && path.file_name() != Some("_common.rs".as_ref())
+            && !generated.contains(&path);
+        if is_stale {
+            fs::remove_file(&path)?;
+        }
+    }
+    for file in &files {
+        fs::write(out.join(&file.path), &file.content)?;
+    }
+    let common = types_dir.join("_common.rs");
+    fs::write(
+        &common,
+        replace_generated(
+            &fs::read_to_string(&common)?,
+            &render::common_traits(&model),
+        )?,
+    )?;
+
+    if format {
+        // `mod.rs` brings all the types, including `_common.rs`
+        rustfmt(&[types_dir.join("mod.rs"), out.join("src/client/api.rs")])?;
+    }
+    println!(
+        "{} enums, {} types, {} functions",
+        model.enums.len(),
+        model.types.len() + model.variants.values().map(Vec::len).sum::<usize>(),
+        model.functions.len()
+    );
+    Ok(())
+}
+
+// Replaces lines between `render::COMMON_BEGIN` and `render::COMMON_END`.
+fn replace_generated(source: &str, generated: &str) -> Result<String> {
+    let missing = || {
+        format!(
+            "`{}` and `{}` lines expected in _common.rs",
+            render::COMMON_BEGIN,
+            render::COMMON_END
+        )
+    };
+    let begin =
+        source.find(render::COMMON_BEGIN).ok_or_else(missing)? + render::COMMON_BEGIN.len() + 1;
+    let end = source[begin..]
+        .find(render::COMMON_END)
+        .ok_or_else(missing)?
+        + begin;
+    Ok(format!(
+        "{}{}{}",
+        &source[..begin],
+        generated,
+        &source[end..]
+    ))
+}
+
+fn rustfmt(paths: &[PathBuf]) -> Result<()> {
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .args(paths.iter().map(PathBuf::as_path).map(Path::as_os_str))
+        .status()
+        .map_err(|e| format!("rustfmt: {}", e))?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(format!("rustfmt: {}", status).into()),
+    }
+}
+
+fn diff(args: &[String]) -> Result<()> {
+    match args {
+        [old, new] => {
+            print!(
+                "{}",
+                diff::Report::new(&read_schema(old)?, &read_schema(new)?)
+            );
+            Ok(())
+        }
+        _ => Err(USAGE.into()),
+    }
+}
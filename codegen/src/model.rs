@@ -0,0 +1,512 @@
+//! Rust representation of the schema: structs, enums and their fields.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::overrides;
+use crate::tl::{Definition, Schema};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RustType {
+    I32,
+    I64,
+    F32,
+    Bool,
+    String,
+    Named(String),
+    Box(Box<RustType>),
+    Vec(Box<RustType>),
+    Option(Box<RustType>),
+}
+
+impl RustType {
+    /// Returned by getters by value.
+    pub fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            RustType::I32 | RustType::I64 | RustType::F32 | RustType::Bool
+        )
+    }
+}
+
+impl fmt::Display for RustType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustType::I32 => f.write_str("i32"),
+            RustType::I64 => f.write_str("i64"),
+            RustType::F32 => f.write_str("f32"),
+            RustType::Bool => f.write_str("bool"),
+            RustType::String => f.write_str("String"),
+            RustType::Named(name) => f.write_str(name),
+            RustType::Box(t) => write!(f, "Box<{}>", t),
+            RustType::Vec(t) => write!(f, "Vec<{}>", t),
+            RustType::Option(t) => write!(f, "Option<{}>", t),
+        }
+    }
+}
+
+/// Type of a field as declared in the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TlType {
+    Int32,
+    Int53,
+    Int64,
+    Double,
+    String,
+    Bool,
+    Vector(Box<TlType>),
+    Struct(String),
+    Enum(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Rust name, e.g. `type_`.
+    pub name: String,
+    /// Name in JSON, e.g. `type`.
+    pub tl_name: String,
+    pub description: String,
+    pub type_: RustType,
+    /// Serde attributes, each one is a line without indentation.
+    pub attributes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Struct {
+    pub name: String,
+    pub tl_name: String,
+    pub description: String,
+    pub fields: Vec<Field>,
+    /// Enum the struct is a variant of, its `TD*` trait is implemented by the struct.
+    pub class: Option<String>,
+    /// Rust type of the result, for functions.
+    pub returns: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    pub tl_name: String,
+    pub description: String,
+    pub struct_name: String,
+    pub boxed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enum {
+    pub name: String,
+    pub description: String,
+    /// Constructors of the class and functions returning it, ordered by their TL names.
+    pub variants: Vec<Variant>,
+}
+
+impl Enum {
+    /// Name of the variant for objects of unknown types, `Unknown` unless the schema has a variant with such name.
+    pub fn unknown_variant(&self) -> &'static str {
+        match self.variants.iter().any(|v| v.name == "Unknown") {
+            true => "UnknownType",
+            false => "Unknown",
+        }
+    }
+}
+
+/// Everything to be generated, ordered by names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Model {
+    pub enums: Vec<Enum>,
+    /// Structs of constructors which aren't variants of enums.
+    pub types: Vec<Struct>,
+    /// Structs of variants, by names of their enums.
+    pub variants: BTreeMap<String, Vec<Struct>>,
+    pub functions: Vec<Struct>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelError(pub String);
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ModelError {}
+
+/// `chatListMain` -> `ChatListMain`.
+pub fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `TMeUrlType` -> `t_me_url_type`, `getChats` -> `get_chats`.
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+fn field_name(tl_name: &str) -> String {
+    match tl_name {
+        "type" => "type_".to_string(),
+        name => name.to_string(),
+    }
+}
+
+struct Resolver<'a> {
+    schema: &'a Schema,
+    enums: BTreeSet<String>,
+    // struct name of a single-constructor type, by its type name
+    structs: BTreeMap<String, String>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(schema: &'a Schema) -> Self {
+        let mut enums: BTreeSet<String> = schema.classes.keys().cloned().collect();
+        for definition in &schema.types {
+            if to_pascal_case(&definition.name) != definition.result {
+                enums.insert(definition.result.clone());
+            }
+        }
+        let structs = schema
+            .types
+            .iter()
+            .filter(|t| !enums.contains(&t.result))
+            .map(|t| (t.result.clone(), to_pascal_case(&t.name)))
+            .collect();
+        Self {
+            schema,
+            enums,
+            structs,
+        }
+    }
+
+    fn tl_type(&self, type_: &str) -> Result<TlType, ModelError> {
+        Ok(match type_ {
+            "int32" | "Int32" => TlType::Int32,
+            "int53" | "Int53" => TlType::Int53,
+            "int64" | "Int64" => TlType::Int64,
+            "double" | "Double" => TlType::Double,
+            "string" | "String" | "bytes" | "Bytes" => TlType::String,
+            "Bool" => TlType::Bool,
+            _ => {
+                if let Some(element) = type_
+                    .strip_prefix("vector<")
+                    .and_then(|t| t.strip_suffix('>'))
+                {
+                    return Ok(TlType::Vector(Box::new(self.tl_type(element)?)));
+                }
+                if self.enums.contains(type_) {
+                    TlType::Enum(type_.to_string())
+                } else if let Some(name) = self.structs.get(type_) {
+                    TlType::Struct(name.clone())
+                } else if self.schema.type_definition(type_).is_some() {
+                    // bare constructor, e.g. `chatPhotoInfo`
+                    TlType::Struct(to_pascal_case(type_))
+                } else {
+                    return Err(ModelError(format!("unknown type `{}`", type_)));
+                }
+            }
+        })
+    }
+
+    fn rust_type(&self, type_: &TlType) -> RustType {
+        match type_ {
+            TlType::Int32 => RustType::I32,
+            TlType::Int53 | TlType::Int64 => RustType::I64,
+            TlType::Double => RustType::F32,
+            TlType::String => RustType::String,
+            TlType::Bool => RustType::Bool,
+            TlType::Vector(element) => RustType::Vec(Box::new(self.rust_type(element))),
+            TlType::Struct(name) | TlType::Enum(name) => RustType::Named(name.clone()),
+        }
+    }
+
+    fn attributes(type_: &TlType) -> Vec<String> {
+        let default = "#[serde(default)]".to_string();
+        match type_ {
+            TlType::Int64 => vec![
+                "#[serde(".to_string(),
+                "    deserialize_with = \"super::_common::number_from_string\",".to_string(),
+                "    serialize_with = \"super::_common::string_to_number\"".to_string(),
+                ")]".to_string(),
+                default,
+            ],
+            TlType::Vector(element) if **element == TlType::Int64 => vec![
+                "#[serde(deserialize_with = \"super::_common::vec_of_i64_from_str\")]".to_string(),
+                default,
+            ],
+            TlType::Struct(_) => vec![],
+            TlType::Enum(name) => vec![format!(
+                "#[serde(skip_serializing_if = \"{}::_is_default\")]",
+                name
+            )],
+            _ => vec![default],
+        }
+    }
+
+    fn field(
+        &self,
+        definition: &Definition,
+        param: &crate::tl::Param,
+        is_function: bool,
+    ) -> Result<Field, ModelError> {
+        let error =
+            |e: ModelError| ModelError(format!("{}.{}: {}", definition.name, param.name, e));
+        let schema_type = self.tl_type(&param.type_).map_err(error)?;
+        let mut attributes = Self::attributes(&schema_type);
+        let mut type_ = match overrides::field_type(&definition.name, &param.name) {
+            Some(type_) => self.rust_type(&self.tl_type(type_).map_err(error)?),
+            None => self.rust_type(&schema_type),
+        };
+        // variants containing their own enums
+        if let RustType::Named(name) = &type_ {
+            if !is_function && *name == definition.result && self.enums.contains(name) {
+                type_ = RustType::Box(Box::new(type_));
+            }
+        }
+        if param.description.contains("may be null") {
+            type_ = match type_ {
+                // elements may be null, the vector itself still has a default
+                RustType::Vec(element) => RustType::Vec(Box::new(RustType::Option(element))),
+                type_ => {
+                    attributes.clear();
+                    RustType::Option(Box::new(type_))
+                }
+            };
+        } else if overrides::is_optional_field(&definition.name, &param.name) {
+            type_ = RustType::Option(Box::new(type_));
+        }
+        if param.name == "type" {
+            attributes.insert(
+                0,
+                "#[serde(rename(serialize = \"type\", deserialize = \"type\"))]".to_string(),
+            );
+        }
+        Ok(Field {
+            name: field_name(&param.name),
+            tl_name: param.name.clone(),
+            description: param.description.clone(),
+            type_,
+            attributes,
+        })
+    }
+
+    fn result_type(&self, result: &str) -> Result<String, ModelError> {
+        match self.tl_type(result)? {
+            TlType::Struct(name) | TlType::Enum(name) => Ok(name),
+            _ => Err(ModelError(format!("unsupported result type `{}`", result))),
+        }
+    }
+
+    fn definition(&self, definition: &Definition, is_function: bool) -> Result<Struct, ModelError> {
+        let fields = definition
+            .params
+            .iter()
+            .map(|param| self.field(definition, param, is_function))
+            .collect::<Result<Vec<_>, _>>()?;
+        let returns = match is_function {
+            true => Some(self.result_type(&definition.result)?),
+            false => None,
+        };
+        let class = match &returns {
+            Some(returns) if self.enums.contains(returns) => Some(returns.clone()),
+            Some(_) => None,
+            None if self.enums.contains(&definition.result) => Some(definition.result.clone()),
+            None => None,
+        };
+        Ok(Struct {
+            name: to_pascal_case(&definition.name),
+            tl_name: definition.name.clone(),
+            description: definition.description.clone(),
+            fields,
+            class,
+            returns,
+        })
+    }
+}
+
+fn variant(class: &str, definition: &Struct) -> Variant {
+    let name = match definition.name.strip_prefix(class) {
+        Some(rest) if rest.starts_with(char::is_uppercase) => rest.to_string(),
+        _ => definition.name.clone(),
+    };
+    Variant {
+        name,
+        tl_name: definition.tl_name.clone(),
+        description: definition.description.clone(),
+        struct_name: definition.name.clone(),
+        boxed: overrides::is_boxed_variant(&definition.tl_name),
+    }
+}
+
+impl Model {
+    pub fn new(schema: &Schema) -> Result<Self, ModelError> {
+        let resolver = Resolver::new(schema);
+        let mut model = Model::default();
+        let mut variants: BTreeMap<String, Vec<Variant>> = BTreeMap::new();
+        for definition in &schema.types {
+            let definition = resolver.definition(definition, false)?;
+            match &definition.class {
+                Some(class) => {
+                    variants
+                        .entry(class.clone())
+                        .or_default()
+                        .push(variant(class, &definition));
+                    model
+                        .variants
+                        .entry(class.clone())
+                        .or_default()
+                        .push(definition);
+                }
+                None => model.types.push(definition),
+            }
+        }
+        for definition in &schema.functions {
+            let definition = resolver.definition(definition, true)?;
+            if let Some(class) = &definition.class {
+                variants
+                    .entry(class.clone())
+                    .or_default()
+                    .push(variant(class, &definition));
+            }
+            model.functions.push(definition);
+        }
+        for class in &resolver.enums {
+            let mut class_variants = variants.remove(class).unwrap_or_default();
+            class_variants.sort_by(|a, b| a.tl_name.cmp(&b.tl_name));
+            model.enums.push(Enum {
+                name: class.clone(),
+                description: schema.classes.get(class).cloned().unwrap_or_default(),
+                variants: class_variants,
+            });
+        }
+        model.types.sort_by(|a, b| a.name.cmp(&b.name));
+        model.functions.sort_by(|a, b| a.name.cmp(&b.name));
+        for structs in model.variants.values_mut() {
+            structs.sort_by(|a, b| a.tl_name.cmp(&b.tl_name));
+        }
+        Ok(model)
+    }
+}
+
+impl Model {
+    /// Every constructor of the schema, variants of `TdObject`.
+    pub fn objects(&self) -> Enum {
+        let structs = self.types.iter().chain(self.variants.values().flatten());
+        union(
+            "TdObject",
+            "Any TDLib object, tagged by its `@type`",
+            structs,
+        )
+    }
+
+    /// Every function of the schema, variants of `TdFunction`.
+    pub fn all_functions(&self) -> Enum {
+        union(
+            "TdFunction",
+            "Any TDLib function, tagged by its `@type`",
+            self.functions.iter(),
+        )
+    }
+}
+
+// Enum of the structs, named after them; variants are boxed to keep the enum small.
+fn union<'a>(name: &str, description: &str, structs: impl Iterator<Item = &'a Struct>) -> Enum {
+    let mut variants: Vec<_> = structs
+        .map(|s| Variant {
+            name: s.name.clone(),
+            tl_name: s.tl_name.clone(),
+            description: s.description.clone(),
+            struct_name: s.name.clone(),
+            boxed: true,
+        })
+        .collect();
+    variants.sort_by(|a, b| a.tl_name.cmp(&b.tl_name));
+    Enum {
+        name: name.to_string(),
+        description: description.to_string(),
+        variants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_snake_case, Model, RustType};
+    use crate::tl::Schema;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(to_snake_case("TMeUrlType"), "t_me_url_type");
+        assert_eq!(to_snake_case("getChats"), "get_chats");
+        assert_eq!(to_snake_case("ProxyTypeSocks5"), "proxy_type_socks5");
+    }
+
+    #[test]
+    fn test_model() {
+        let schema = Schema::parse(
+            r#"
+//@class RichText @description Describes a text object inside an instant-view web page
+
+//@description A bold rich text @text Text
+richTextBold text:RichText = RichText;
+
+//@description A concatenation of rich texts @texts Texts
+richTexts texts:vector<RichText> = RichText;
+
+//@description Contains a list of messages @total_count Approximate total count of messages found @messages List of messages; messages may be null
+messages total_count:int32 messages:vector<message> = Messages;
+
+//@description Describes a message @id Message identifier @media_album_id Unique identifier of an album this message belongs to @text Text; may be null
+message id:int53 media_album_id:int64 text:RichText = Message;
+
+---functions---
+
+//@description Returns a message @message_id Identifier of the message to get
+getText message_id:int53 = RichText;
+"#,
+        )
+        .unwrap();
+        let model = Model::new(&schema).unwrap();
+        let rich_text = &model.enums[0];
+        let variants: Vec<_> = rich_text.variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(variants, vec!["GetText", "Bold", "RichTexts"]);
+        let bold = &model.variants["RichText"][0];
+        assert_eq!(bold.fields[0].type_.to_string(), "Box<RichText>");
+        let message = &model.types[0];
+        assert_eq!(message.name, "Message");
+        assert_eq!(message.fields[0].type_, RustType::I64);
+        assert_eq!(message.fields[1].attributes.len(), 5);
+        assert_eq!(message.fields[2].type_.to_string(), "Option<RichText>");
+        assert!(message.fields[2].attributes.is_empty());
+        let messages = &model.types[1].fields[1];
+        assert_eq!(messages.type_.to_string(), "Vec<Option<Message>>");
+        assert_eq!(messages.attributes, vec!["#[serde(default)]"]);
+        assert_eq!(model.functions[0].class.as_deref(), Some("RichText"));
+        let objects: Vec<_> = model
+            .objects()
+            .variants
+            .into_iter()
+            .map(|v| v.name)
+            .collect();
+        assert_eq!(
+            objects,
+            vec!["Message", "Messages", "RichTextBold", "RichTexts"]
+        );
+        assert_eq!(model.all_functions().variants[0].struct_name, "GetText");
+    }
+}
@@ -0,0 +1,61 @@
+//! Deviations of generated code from the rules applied to the whole schema.
+//!
+//! Every entry changes public API of the generated types, so entries are only added or removed
+//! together with a CHANGELOG note.
+
+/// Constructors which are held in a `Box` by enum variants, so size of the enums stays small.
+pub const BOXED_VARIANTS: &[&str] = &[
+    "chatEventMessageDeleted",
+    "chatEventMessageEdited",
+    "chatEventMessagePinned",
+    "diceStickersRegular",
+    "diceStickersSlotMachine",
+    "inlineQueryResultGame",
+    "inlineQueryResultVideo",
+    "messageDice",
+    "messageText",
+    "notificationTypeNewMessage",
+    "notificationTypeNewPushMessage",
+    "pageBlockChatLink",
+    "richTextIcon",
+    "tMeUrlTypeChatInvite",
+    "updateNewChat",
+];
+
+/// Constructors with `pub(crate)` fields, so the entity cache can apply partial updates in place.
+/// Doesn't change public API.
+pub const CRATE_VISIBLE_FIELDS: &[&str] = &["chat", "user"];
+
+/// Fields which are `Option`, although their description doesn't say they may be null:
+/// `(constructor, field)`.
+pub const OPTIONAL_FIELDS: &[(&str, &str)] = &[
+    ("chat", "positions"),
+    ("chatPhotoInfo", "has_animation"),
+    ("profilePhoto", "has_animation"),
+    ("updateChatLastMessage", "positions"),
+];
+
+/// Fields of Rust types other than ones of their schema types: `(constructor, field, TL type)`.
+/// Serde attributes are still derived from the schema type.
+///
+/// `richTextPlain.text` is a `string` in the schema, but has always been generated as `Box<RichText>`.
+pub const FIELD_TYPES: &[(&str, &str, &str)] = &[("richTextPlain", "text", "RichText")];
+
+pub fn is_boxed_variant(constructor: &str) -> bool {
+    BOXED_VARIANTS.contains(&constructor)
+}
+
+pub fn has_crate_visible_fields(constructor: &str) -> bool {
+    CRATE_VISIBLE_FIELDS.contains(&constructor)
+}
+
+pub fn is_optional_field(constructor: &str, field: &str) -> bool {
+    OPTIONAL_FIELDS.contains(&(constructor, field))
+}
+
+pub fn field_type(constructor: &str, field: &str) -> Option<&'static str> {
+    FIELD_TYPES
+        .iter()
+        .find(|(c, f, _)| *c == constructor && *f == field)
+        .map(|(_, _, t)| *t)
+}
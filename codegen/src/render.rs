@@ -0,0 +1,658 @@
+//! Rust sources generated from the [Model].
+//!
+//! Templates produce code close to `rustfmt` output; generated files are formatted with `rustfmt` anyway.
+use std::fmt::Write;
+
+use crate::model::{to_snake_case, Enum, Field, Model, RustType, Struct};
+use crate::overrides;
+
+const HEADER: &str = "use crate::errors::Result;\nuse crate::types::*;\nuse uuid::Uuid;\n";
+
+const MOD_HEADER: &str = "//! Module provides all TDlib types.
+//! For types details you can see [TDlib API Scheme](https://github.com/tdlib/td/blob/master/td/generate/scheme/td_api.tl)
+pub use self::_common::{ClientId, RFunction, RObject};
+
+#[macro_use]
+mod _common;
+";
+
+const API_HEADER: &str = "use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::{errors::Result, types::*};
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+";
+
+/// Lines of `_common.rs` enclosing implementations of `TD*` traits for references.
+pub const COMMON_BEGIN: &str = "// Begin of code generated by tdlib-codegen.";
+pub const COMMON_END: &str = "// End of code generated by tdlib-codegen.";
+
+/// Generated file: path relative to the crate root and its content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub path: String,
+    pub content: String,
+}
+
+/// All files of `src/types` except `_common.rs`, and `src/client/api.rs`.
+pub fn files(model: &Model) -> Vec<File> {
+    let mut files = Vec::new();
+    let mut modules = Vec::new();
+    for enum_ in &model.enums {
+        let module = to_snake_case(&enum_.name);
+        let structs = model
+            .variants
+            .get(&enum_.name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        files.push(File {
+            path: format!("src/types/{}.rs", module),
+            content: enum_file(enum_, structs),
+        });
+        modules.push(module);
+    }
+    for struct_ in model.types.iter().chain(&model.functions) {
+        let module = to_snake_case(&struct_.name);
+        let mut content = HEADER.to_string();
+        content.push('\n');
+        content.push_str(&struct_definition(struct_));
+        files.push(File {
+            path: format!("src/types/{}.rs", module),
+            content,
+        });
+        modules.push(module);
+    }
+    for (module, content) in [
+        ("td_object", object_file(&model.objects())),
+        ("td_function", function_file(&model.all_functions())),
+    ] {
+        files.push(File {
+            path: format!("src/types/{}.rs", module),
+            content,
+        });
+        modules.push(module.to_string());
+    }
+    modules.sort();
+    files.push(File {
+        path: "src/types/mod.rs".to_string(),
+        content: mod_file(&modules),
+    });
+    files.push(File {
+        path: "src/client/api.rs".to_string(),
+        content: api_file(&model.functions),
+    });
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    files
+}
+
+fn mod_file(modules: &[String]) -> String {
+    let mut content = MOD_HEADER.to_string();
+    content.push('\n');
+    for module in modules {
+        writeln!(content, "pub use self::{}::*;", module).unwrap();
+    }
+    content.push('\n');
+    for module in modules {
+        writeln!(content, "mod {};", module).unwrap();
+    }
+    content
+}
+
+fn api_file(functions: &[Struct]) -> String {
+    let mut content = API_HEADER.to_string();
+    for (i, function) in functions.iter().enumerate() {
+        if i > 0 {
+            content.push('\n');
+        }
+        let method = to_snake_case(&function.name);
+        let returns = function.returns.as_deref().unwrap_or("Ok");
+        writeln!(content, "    // {}", function.description).unwrap();
+        writeln!(
+            content,
+            "    pub async fn {m}<C: AsRef<{n}>>(&self, {m}: C) -> Result<{r}> {{",
+            m = method,
+            n = function.name,
+            r = returns
+        )
+        .unwrap();
+        writeln!(content, "        self.make_request({}).await", method).unwrap();
+        content.push_str("    }\n");
+    }
+    content.push_str("}\n");
+    content
+}
+
+/// Implementations of `TD*` traits for references, placed into `_common.rs` between [COMMON_BEGIN] and [COMMON_END].
+pub fn common_traits(model: &Model) -> String {
+    let mut content = String::new();
+    for (i, enum_) in model.enums.iter().enumerate() {
+        if i > 0 {
+            content.push('\n');
+        }
+        let parameter = enum_.name.to_uppercase();
+        for reference in &["&'a", "&'a mut"] {
+            writeln!(
+                content,
+                "impl<'a, {p}: TD{n}> TD{n} for {r} {p} {{}}",
+                p = parameter,
+                n = enum_.name,
+                r = reference
+            )
+            .unwrap();
+        }
+    }
+    content
+}
+
+fn enum_file(enum_: &Enum, structs: &[Struct]) -> String {
+    let name = &enum_.name;
+    let unknown = enum_.unknown_variant();
+    let mut content = HEADER.to_string();
+    content.push_str("\nuse std::fmt::Debug;\n\n");
+    writeln!(content, "/// {}", enum_.description).unwrap();
+    writeln!(content, "pub trait TD{}: Debug + RObject {{}}\n", name).unwrap();
+    writeln!(content, "/// {}", enum_.description).unwrap();
+    content.push_str("#[derive(Debug, Clone, Serialize, Default)]\n");
+    content.push_str("#[serde(tag = \"@type\")]\n");
+    writeln!(content, "pub enum {} {{", name).unwrap();
+    content.push_str("    #[doc(hidden)]\n    #[default]\n    _Default,\n");
+    content.push_str(&variants(enum_, true));
+    content.push_str(&unknown_variant(
+        unknown,
+        "Object of a type unknown to this version of the library, e.g. sent by a newer TDLib",
+        true,
+    ));
+    content.push_str("}\n\n");
+    content.push_str(&robject_impl(enum_, true));
+    content.push('\n');
+    content.push_str(&deserialize_impl(enum_));
+    content.push('\n');
+
+    writeln!(content, "impl {} {{", name).unwrap();
+    content.push_str(
+        "    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+    #[doc(hidden)]
+    pub fn _is_default(&self) -> bool {
+",
+    );
+    writeln!(
+        content,
+        "        matches!(self, {}::_Default)\n    }}\n}}\n",
+        name
+    )
+    .unwrap();
+    writeln!(content, "impl AsRef<{n}> for {n} {{", n = name).unwrap();
+    writeln!(
+        content,
+        "    fn as_ref(&self) -> &{} {{\n        self\n    }}\n}}",
+        name
+    )
+    .unwrap();
+    for struct_ in structs {
+        content.push('\n');
+        content.push_str(&struct_definition(struct_));
+    }
+    content
+}
+
+// Variants wrapping structs, with serde renames if `renamed`.
+fn variants(enum_: &Enum, renamed: bool) -> String {
+    let mut content = String::new();
+    for variant in &enum_.variants {
+        writeln!(content, "    /// {}", variant.description).unwrap();
+        if renamed {
+            writeln!(content, "    #[serde(rename = \"{}\")]", variant.tl_name).unwrap();
+        }
+        match variant.boxed {
+            true => writeln!(
+                content,
+                "    {}(Box<{}>),",
+                variant.name, variant.struct_name
+            ),
+            false => writeln!(content, "    {}({}),", variant.name, variant.struct_name),
+        }
+        .unwrap();
+    }
+    content
+}
+
+// `Unknown` variant; `serde` adds attributes for derived `Serialize`.
+fn unknown_variant(name: &str, description: &str, serde: bool) -> String {
+    let mut content = format!("    /// {}\n", description);
+    if serde {
+        content.push_str(
+            "    #[serde(untagged, serialize_with = \"super::_common::serialize_unknown\")]\n",
+        );
+    }
+    writeln!(
+        content,
+        "    {} {{\n        type_name: String,\n        raw: serde_json::Value,\n    }},",
+        name
+    )
+    .unwrap();
+    content
+}
+
+// `RObject` delegating to variants; enums having `_Default` variant need a wildcard arm.
+fn robject_impl(enum_: &Enum, has_default: bool) -> String {
+    let name = &enum_.name;
+    let unknown = enum_.unknown_variant();
+    let wildcard = match has_default {
+        true => "\n            _ => None,\n",
+        false => "",
+    };
+    let mut content = String::new();
+    writeln!(content, "impl RObject for {} {{", name).unwrap();
+    content.push_str(
+        "    #[doc(hidden)]\n    fn extra(&self) -> Option<&str> {\n        match self {\n",
+    );
+    for variant in &enum_.variants {
+        writeln!(
+            content,
+            "            {}::{}(t) => t.extra(),",
+            name, variant.name
+        )
+        .unwrap();
+    }
+    writeln!(
+        content,
+        "            {}::{} {{ raw, .. }} => raw.get(\"@extra\").and_then(|e| e.as_str()),",
+        name, unknown
+    )
+    .unwrap();
+    writeln!(content, "{}        }}\n    }}", wildcard).unwrap();
+    content.push_str(
+        "    #[doc(hidden)]\n    fn client_id(&self) -> Option<i32> {\n        match self {\n",
+    );
+    for variant in &enum_.variants {
+        writeln!(
+            content,
+            "            {}::{}(t) => t.client_id(),",
+            name, variant.name
+        )
+        .unwrap();
+    }
+    writeln!(
+        content,
+        "            {}::{} {{ raw, .. }} => raw\n                .get(\"@client_id\")\n                .and_then(|c| c.as_i64())\n                .map(|c| c as i32),",
+        name, unknown
+    )
+    .unwrap();
+    writeln!(content, "{}        }}\n    }}\n}}", wildcard).unwrap();
+    content
+}
+
+// `from_json`, `type_name` and `AsRef` of `TdObject` and `TdFunction`.
+fn union_impl(enum_: &Enum, subject: &str) -> String {
+    let name = &enum_.name;
+    let mut content = String::new();
+    writeln!(content, "impl {} {{", name).unwrap();
+    content.push_str(
+        "    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+",
+    );
+    writeln!(
+        content,
+        "    /// Name of the {}, value of its `@type`\n    pub fn type_name(&self) -> &str {{\n        match self {{",
+        subject
+    )
+    .unwrap();
+    for variant in &enum_.variants {
+        writeln!(
+            content,
+            "            {}::{}(_) => \"{}\",",
+            name, variant.name, variant.tl_name
+        )
+        .unwrap();
+    }
+    writeln!(
+        content,
+        "            {}::{} {{ type_name, .. }} => type_name,\n        }}\n    }}\n}}\n",
+        name,
+        enum_.unknown_variant()
+    )
+    .unwrap();
+    writeln!(content, "impl AsRef<{n}> for {n} {{", n = name).unwrap();
+    writeln!(
+        content,
+        "    fn as_ref(&self) -> &{} {{\n        self\n    }}\n}}",
+        name
+    )
+    .unwrap();
+    content
+}
+
+fn object_file(enum_: &Enum) -> String {
+    let mut content = "use crate::errors::Result;\nuse crate::types::*;\n\n".to_string();
+    writeln!(content, "/// {}", enum_.description).unwrap();
+    content.push_str("#[derive(Debug, Clone, Serialize)]\n");
+    content.push_str("#[serde(tag = \"@type\")]\n");
+    writeln!(content, "pub enum {} {{", enum_.name).unwrap();
+    content.push_str(&variants(enum_, true));
+    content.push_str(&unknown_variant(
+        enum_.unknown_variant(),
+        "Object of a type unknown to this version of the library, e.g. sent by a newer TDLib",
+        true,
+    ));
+    content.push_str("}\n\n");
+    content.push_str(&robject_impl(enum_, false));
+    content.push('\n');
+    content.push_str(&union_impl(enum_, "object type"));
+    content.push('\n');
+    content.push_str(&deserialize_impl(enum_));
+    content
+}
+
+// Functions already serialize their `@type`, so serde implementations are generated instead of derived.
+fn function_file(enum_: &Enum) -> String {
+    let name = &enum_.name;
+    let unknown = enum_.unknown_variant();
+    let mut content = "use crate::errors::Result;
+use crate::types::*;
+use serde::{Serialize, Serializer};
+
+"
+    .to_string();
+    writeln!(content, "/// {}", enum_.description).unwrap();
+    content.push_str("#[derive(Debug, Clone)]\n");
+    writeln!(content, "pub enum {} {{", name).unwrap();
+    content.push_str(&variants(enum_, false));
+    content.push_str(&unknown_variant(
+        unknown,
+        "Function unknown to this version of the library, e.g. a method of a newer TDLib",
+        false,
+    ));
+    content.push_str("}\n\n");
+    content.push_str(&robject_impl(enum_, false));
+    content.push('\n');
+    writeln!(
+        content,
+        "impl RFunction for {} {{\n    type Response = TdObject;\n}}\n",
+        name
+    )
+    .unwrap();
+    content.push_str(&union_impl(enum_, "function"));
+    content.push('\n');
+
+    writeln!(content, "impl Serialize for {} {{", name).unwrap();
+    content.push_str(
+        "    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+",
+    );
+    for variant in &enum_.variants {
+        writeln!(
+            content,
+            "            {}::{}(f) => f.serialize(serializer),",
+            name, variant.name
+        )
+        .unwrap();
+    }
+    writeln!(
+        content,
+        "            {}::{} {{ raw, .. }} => raw.serialize(serializer),\n        }}\n    }}\n}}\n",
+        name, unknown
+    )
+    .unwrap();
+
+    content.push_str(&deserialize_impl(enum_));
+    content
+}
+
+// Dispatches by `@type`: objects of known types must be valid, only unknown types become `Unknown`.
+fn deserialize_impl(enum_: &Enum) -> String {
+    let name = &enum_.name;
+    let mut content = String::new();
+    writeln!(content, "impl<'de> serde::Deserialize<'de> for {} {{", name).unwrap();
+    content.push_str(
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+",
+    );
+    for variant in &enum_.variants {
+        writeln!(
+            content,
+            "            \"{}\" => {}::{}(super::_common::from_raw(raw)?),",
+            variant.tl_name, name, variant.name
+        )
+        .unwrap();
+    }
+    writeln!(
+        content,
+        "            _ => {}::{} {{ type_name, raw }},\n        }})\n    }}\n}}",
+        name,
+        enum_.unknown_variant()
+    )
+    .unwrap();
+    content
+}
+
+fn getter(field: &Field) -> String {
+    match field.type_.is_copy() {
+        true => format!(
+            "    pub fn {n}(&self) -> {t} {{\n        self.{n}\n    }}\n",
+            n = field.name,
+            t = field.type_
+        ),
+        false => format!(
+            "    pub fn {n}(&self) -> &{t} {{\n        &self.{n}\n    }}\n",
+            n = field.name,
+            t = field.type_
+        ),
+    }
+}
+
+fn setter(field: &Field) -> String {
+    let n = &field.name;
+    let (optional, type_) = match &field.type_ {
+        RustType::Option(type_) => (true, type_.as_ref()),
+        type_ => (false, type_),
+    };
+    let wrap = |value: String| match optional {
+        true => format!("Some({})", value),
+        false => value,
+    };
+    let (generic, parameter, value) = match type_ {
+        RustType::String => (
+            "<T: AsRef<str>>".to_string(),
+            "T".to_string(),
+            format!("{}.as_ref().to_string()", n),
+        ),
+        RustType::Named(_) | RustType::Box(_) => (
+            format!("<T: AsRef<{}>>", type_),
+            "T".to_string(),
+            format!("{}.as_ref().clone()", n),
+        ),
+        _ => (String::new(), type_.to_string(), n.to_string()),
+    };
+    format!(
+        "    pub fn {n}{g}(&mut self, {n}: {p}) -> &mut Self {{\n        self.inner.{n} = {v};\n        self\n    }}\n",
+        n = n,
+        g = generic,
+        p = parameter,
+        v = wrap(value)
+    )
+}
+
+fn struct_definition(struct_: &Struct) -> String {
+    let name = &struct_.name;
+    let is_function = struct_.returns.is_some();
+    let mut content = String::new();
+    writeln!(content, "/// {}", struct_.description).unwrap();
+    content.push_str("#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n");
+    writeln!(content, "pub struct {} {{", name).unwrap();
+    content.push_str(
+        "    #[doc(hidden)]
+    #[serde(rename(serialize = \"@extra\", deserialize = \"@extra\"))]
+    extra: Option<String>,
+    #[serde(rename(serialize = \"@client_id\", deserialize = \"@client_id\"))]
+    client_id: Option<i32>,
+",
+    );
+    let visibility = match overrides::has_crate_visible_fields(&struct_.tl_name) {
+        true => "pub(crate) ",
+        false => "",
+    };
+    for field in &struct_.fields {
+        writeln!(content, "    /// {}", field.description).unwrap();
+        if !field.attributes.is_empty() {
+            content.push('\n');
+            for attribute in &field.attributes {
+                writeln!(content, "    {}", attribute).unwrap();
+            }
+        }
+        writeln!(
+            content,
+            "    {}{}: {},",
+            visibility, field.name, field.type_
+        )
+        .unwrap();
+    }
+    if is_function {
+        content.push_str(
+            "\n    #[serde(rename(serialize = \"@type\", deserialize = \"@type\"))]\n    td_type: String,\n",
+        );
+    }
+    content.push_str("}\n\n");
+
+    writeln!(content, "impl RObject for {} {{", name).unwrap();
+    content.push_str(
+        "    #[doc(hidden)]
+    fn extra(&self) -> Option<&str> {
+        self.extra.as_deref()
+    }
+    #[doc(hidden)]
+    fn client_id(&self) -> Option<i32> {
+        self.client_id
+    }
+}
+
+",
+    );
+    if let Some(class) = &struct_.class {
+        writeln!(content, "impl TD{} for {} {{}}\n", class, name).unwrap();
+    }
+    if let Some(returns) = &struct_.returns {
+        writeln!(
+            content,
+            "impl RFunction for {} {{\n    type Response = {};\n}}\n",
+            name, returns
+        )
+        .unwrap();
+    }
+
+    writeln!(content, "impl {} {{", name).unwrap();
+    content.push_str(
+        "    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+",
+    );
+    writeln!(content, "    pub fn builder() -> {}Builder {{", name).unwrap();
+    writeln!(content, "        let mut inner = {}::default();", name).unwrap();
+    content.push_str("        inner.extra = Some(Uuid::new_v4().to_string());\n\n");
+    if is_function {
+        writeln!(
+            content,
+            "        inner.td_type = \"{}\".to_string();\n",
+            struct_.tl_name
+        )
+        .unwrap();
+    }
+    writeln!(content, "        {}Builder {{ inner }}\n    }}", name).unwrap();
+    for field in &struct_.fields {
+        content.push('\n');
+        content.push_str(&getter(field));
+    }
+    content.push_str("}\n\n");
+
+    writeln!(
+        content,
+        "#[doc(hidden)]\npub struct {n}Builder {{\n    inner: {n},\n}}\n",
+        n = name
+    )
+    .unwrap();
+    writeln!(
+        content,
+        "#[deprecated]\npub type RTD{n}Builder = {n}Builder;\n",
+        n = name
+    )
+    .unwrap();
+    writeln!(content, "impl {}Builder {{", name).unwrap();
+    writeln!(
+        content,
+        "    pub fn build(&self) -> {} {{\n        self.inner.clone()\n    }}",
+        name
+    )
+    .unwrap();
+    for field in &struct_.fields {
+        content.push('\n');
+        content.push_str(&setter(field));
+    }
+    content.push_str("}\n\n");
+
+    writeln!(
+        content,
+        "impl AsRef<{n}> for {n} {{\n    fn as_ref(&self) -> &{n} {{\n        self\n    }}\n}}\n",
+        n = name
+    )
+    .unwrap();
+    writeln!(
+        content,
+        "impl AsRef<{n}> for {n}Builder {{\n    fn as_ref(&self) -> &{n} {{\n        &self.inner\n    }}\n}}",
+        n = name
+    )
+    .unwrap();
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{api_file, common_traits};
+    use crate::model::Model;
+    use crate::tl::Schema;
+
+    #[test]
+    fn test_render() {
+        let schema = Schema::parse(
+            r#"
+//@class ChatList @description Describes a list of chats
+
+//@description A main list of chats
+chatListMain = ChatList;
+
+//@description Represents a list of chats @total_count Approximate total count of chats found @chat_ids List of chat identifiers
+chats total_count:int32 chat_ids:vector<int53> = Chats;
+
+---functions---
+
+//@description Returns an ordered list of chats @chat_list The chat list @limit The maximum number of chats to be returned
+getChats chat_list:ChatList limit:int32 = Chats;
+"#,
+        )
+        .unwrap();
+        let model = Model::new(&schema).unwrap();
+        assert_eq!(
+            common_traits(&model),
+            "impl<'a, CHATLIST: TDChatList> TDChatList for &'a CHATLIST {}
+impl<'a, CHATLIST: TDChatList> TDChatList for &'a mut CHATLIST {}
+"
+        );
+        assert!(api_file(&model.functions).contains(
+            "    // Returns an ordered list of chats
+    pub async fn get_chats<C: AsRef<GetChats>>(&self, get_chats: C) -> Result<Chats> {
+        self.make_request(get_chats).await
+    }
+}
+"
+        ));
+    }
+}
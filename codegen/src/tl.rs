@@ -0,0 +1,233 @@
+//! Parser of TDLib API schema, `td_api.tl`.
+//!
+//! Only the subset of TL used by TDLib is supported: one combinator per line, documented with
+//! `//@description`, `//@<param>` and `//@class` comments, which may be continued on lines starting with `//-`.
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Combinator of the schema: a type constructor or a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+    /// TL name, e.g. `chatListMain` or `getChats`.
+    pub name: String,
+    pub description: String,
+    pub params: Vec<Param>,
+    /// Name of the constructed type or of the type returned by the function, e.g. `ChatList`.
+    pub result: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    /// TL type as written in the schema, e.g. `int53`, `vector<message>` or `ChatList`.
+    pub type_: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schema {
+    /// Abstract classes declared with `//@class`, with their descriptions.
+    pub classes: BTreeMap<String, String>,
+    pub types: Vec<Definition>,
+    pub functions: Vec<Definition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Types defined by the schema header, they're mapped to Rust types directly.
+const BUILTIN_TYPES: &[&str] = &[
+    "Double", "String", "Int32", "Int53", "Int64", "Bytes", "Bool", "Vector t",
+];
+
+impl Schema {
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut schema = Schema::default();
+        let mut is_function = false;
+        let mut doc = String::new();
+        for (i, line) in source.lines().enumerate() {
+            let line = line.trim();
+            let error = |message: String| ParseError {
+                line: i + 1,
+                message,
+            };
+            if line.is_empty() {
+                continue;
+            }
+            if line == "---functions---" {
+                is_function = true;
+                doc.clear();
+                continue;
+            }
+            if line == "---types---" {
+                is_function = false;
+                doc.clear();
+                continue;
+            }
+            if let Some(continued) = line.strip_prefix("//-") {
+                doc.push(' ');
+                doc.push_str(continued.trim());
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix("//") {
+                let comment = comment.trim();
+                if comment.starts_with("@class ") {
+                    let tags = split_tags(comment);
+                    let class =
+                        tag(&tags, "class").ok_or_else(|| error("class without name".into()))?;
+                    let description = tag(&tags, "description").unwrap_or_default();
+                    schema
+                        .classes
+                        .insert(class.to_string(), description.to_string());
+                    doc.clear();
+                } else {
+                    if !doc.is_empty() {
+                        doc.push(' ');
+                    }
+                    doc.push_str(comment);
+                }
+                continue;
+            }
+            let definition = parse_definition(line, &doc).map_err(error)?;
+            doc.clear();
+            if BUILTIN_TYPES.contains(&definition.result.as_str()) {
+                continue;
+            }
+            if is_function {
+                schema.functions.push(definition);
+            } else {
+                schema.types.push(definition);
+            }
+        }
+        Ok(schema)
+    }
+
+    pub fn type_definition(&self, name: &str) -> Option<&Definition> {
+        self.types.iter().find(|t| t.name == name)
+    }
+}
+
+// Splits documentation like `@description Some text @name Other text` into tags.
+fn split_tags(doc: &str) -> Vec<(&str, &str)> {
+    let mut starts = Vec::new();
+    let bytes = doc.as_bytes();
+    for (i, c) in doc.char_indices() {
+        if c == '@' && (i == 0 || bytes[i - 1] == b' ') {
+            starts.push(i);
+        }
+    }
+    let mut tags = Vec::new();
+    for (n, &start) in starts.iter().enumerate() {
+        let end = starts.get(n + 1).copied().unwrap_or(doc.len());
+        let tag = &doc[start + 1..end];
+        match tag.find(' ') {
+            Some(space) => tags.push((&tag[..space], tag[space..].trim())),
+            None => tags.push((tag, "")),
+        }
+    }
+    tags
+}
+
+fn tag<'a>(tags: &[(&'a str, &'a str)], name: &str) -> Option<&'a str> {
+    tags.iter().find(|(n, _)| *n == name).map(|(_, text)| *text)
+}
+
+fn parse_definition(line: &str, doc: &str) -> Result<Definition, String> {
+    let line = line
+        .strip_suffix(';')
+        .ok_or_else(|| format!("`;` expected at the end of `{}`", line))?;
+    let (left, result) = match line.split_once(" = ") {
+        Some(parts) => parts,
+        None => return Err(format!("`=` expected in `{}`", line)),
+    };
+    let mut words = left.split_whitespace();
+    let name = words.next().ok_or("combinator name expected")?.to_string();
+    let tags = split_tags(doc);
+    let mut params = Vec::new();
+    for word in words {
+        // header definitions like `vector {t:Type} # [ t ] = Vector t;`
+        if !word.contains(':') {
+            continue;
+        }
+        let (param, type_) = word.split_once(':').unwrap_or_default();
+        // a param named `description` is documented as `param_description`
+        let description = match param {
+            "description" => tag(&tags, "param_description"),
+            _ => tag(&tags, param),
+        };
+        params.push(Param {
+            name: param.to_string(),
+            type_: type_.to_string(),
+            description: description.unwrap_or_default().to_string(),
+        });
+    }
+    Ok(Definition {
+        name,
+        description: tag(&tags, "description").unwrap_or_default().to_string(),
+        params,
+        result: result.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Schema;
+
+    #[test]
+    fn test_parse() {
+        let schema = Schema::parse(
+            r#"
+double ? = Double;
+vector {t:Type} # [ t ] = Vector t;
+
+//@class ChatList @description Describes a list of chats
+
+//@description A main list of chats
+chatListMain = ChatList;
+
+//@description A list of chats belonging to a chat filter @chat_filter_id Chat filter identifier
+chatListFilter chat_filter_id:int32 = ChatList;
+
+//@description Contains information about a bot command
+//@command Text of the bot command
+//@param_description Description of the bot command;
+//-may be empty
+botCommand command:string description:string = BotCommand;
+
+---functions---
+
+//@description Returns an ordered list of chats from the beginning of a chat list @chat_list The chat list @limit The maximum number of chats to be returned
+getChats chat_list:ChatList limit:int32 = Chats;
+"#,
+        )
+        .unwrap();
+        assert_eq!(schema.classes["ChatList"], "Describes a list of chats");
+        assert_eq!(schema.types.len(), 3);
+        let filter = schema.type_definition("chatListFilter").unwrap();
+        assert_eq!(filter.result, "ChatList");
+        assert_eq!(filter.params[0].type_, "int32");
+        assert_eq!(filter.params[0].description, "Chat filter identifier");
+        let command = schema.type_definition("botCommand").unwrap();
+        assert_eq!(
+            command.params[1].description,
+            "Description of the bot command; may be empty"
+        );
+        let get_chats = &schema.functions[0];
+        assert_eq!(get_chats.result, "Chats");
+        assert_eq!(
+            get_chats.params[1].description,
+            "The maximum number of chats to be returned"
+        );
+    }
+}
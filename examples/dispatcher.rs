@@ -0,0 +1,66 @@
+use rust_tdlib::client::dispatcher::DispatchError;
+use rust_tdlib::client::tdlib_client::TdJson;
+use rust_tdlib::client::{Context, Dispatcher};
+use rust_tdlib::{
+    client::{Client, Worker},
+    tdjson,
+    types::{TdlibParameters, UpdateChatTitle, UpdateNewMessage},
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Default)]
+struct Stats {
+    messages: AtomicUsize,
+}
+
+#[tokio::main]
+async fn main() {
+    tdjson::set_log_verbosity_level(1);
+    env_logger::init();
+    let tdlib_parameters = TdlibParameters::builder()
+        .database_directory("tddb")
+        .use_test_dc(false)
+        .api_id(std::env::var("API_ID").unwrap().parse::<i32>().unwrap())
+        .api_hash(std::env::var("API_HASH").unwrap())
+        .system_language_code("en")
+        .device_model("Desktop")
+        .system_version("Unknown")
+        .application_version(env!("CARGO_PKG_VERSION"))
+        .enable_storage_optimizer(true)
+        .build();
+
+    let client = Client::builder()
+        .with_tdlib_parameters(tdlib_parameters)
+        .build()
+        .unwrap();
+
+    let mut worker = Worker::builder().build().unwrap();
+    worker.start();
+    let client = worker.bind_client(client).await.unwrap();
+
+    Dispatcher::new(client, Stats::default())
+        .on_if(
+            |update: &UpdateNewMessage| !update.message().is_outgoing(),
+            |ctx: Context<TdJson, Stats>, update: UpdateNewMessage| async move {
+                let received = ctx.state().messages.fetch_add(1, Ordering::Relaxed) + 1;
+                log::info!(
+                    "message #{} in chat {}",
+                    received,
+                    update.message().chat_id()
+                );
+                Ok(())
+            },
+        )
+        .on(
+            |_: Context<TdJson, Stats>, update: UpdateChatTitle| async move {
+                log::info!("chat {} renamed to {}", update.chat_id(), update.title());
+                Ok(())
+            },
+        )
+        .on_error(|error: DispatchError| async move { log::error!("{}", error) })
+        // returns when the client is closed, e.g. after logging out from another device
+        .run()
+        .await;
+
+    worker.stop();
+}
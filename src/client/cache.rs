@@ -0,0 +1,329 @@
+//! In-memory cache of users, chats and groups, filled from updates.
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use futures::{Stream, StreamExt};
+
+use super::chat_list::ChatListId;
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::Result;
+use crate::types::{
+    BasicGroup, BasicGroupFullInfo, Chat, ChatPosition, GetBasicGroup, GetBasicGroupFullInfo,
+    GetChat, GetSupergroup, GetSupergroupFullInfo, GetUser, GetUserFullInfo, Supergroup,
+    SupergroupFullInfo, Update, User, UserFullInfo,
+};
+
+#[derive(Debug, Default)]
+struct Entities {
+    users: HashMap<i64, User>,
+    user_full_infos: HashMap<i64, UserFullInfo>,
+    chats: HashMap<i64, Chat>,
+    basic_groups: HashMap<i64, BasicGroup>,
+    basic_group_full_infos: HashMap<i64, BasicGroupFullInfo>,
+    supergroups: HashMap<i64, Supergroup>,
+    supergroup_full_infos: HashMap<i64, SupergroupFullInfo>,
+}
+
+/// Cache of users, chats, basic groups, supergroups and their full infos, maintained from updates:
+/// `updateUser`, `updateNewChat`, `updateBasicGroup`, `updateSupergroup`, `update*FullInfo`,
+/// and partial updates like `updateChatTitle`, `updateChatPhoto` or `updateUserStatus`.
+/// ```ignore
+/// let cache = Arc::new(EntityCache::new());
+/// tokio::spawn({
+///     let cache = cache.clone();
+///     let updates = client.subscribe(UpdateFilter::all());
+///     async move { cache.consume(updates).await }
+/// });
+/// let title = cache.get_or_fetch_chat(&client, chat_id).await?.title().clone();
+/// ```
+#[derive(Debug, Default)]
+pub struct EntityCache {
+    entities: RwLock<Entities>,
+}
+
+macro_rules! cached_entity {
+    ($(
+        $(#[$doc:meta])*
+        $get:ident, $get_or_fetch:ident, $field:ident: $t:ty = $request:ident.$id:ident => $method:ident;
+    )*) => {
+        $(
+            $(#[$doc])*
+            pub fn $get(&self, id: i64) -> Option<$t> {
+                self.read().$field.get(&id).cloned()
+            }
+
+            /// Returns cached object, or requests it with the client and caches the result.
+            pub async fn $get_or_fetch<R>(&self, client: &Client<R>, id: i64) -> Result<$t>
+            where
+                R: TdLibClient + Clone,
+            {
+                if let Some(cached) = self.$get(id) {
+                    return Ok(cached);
+                }
+                let fetched = client.$method($request::builder().$id(id).build()).await?;
+                // keep the object if an update cached it while the request was pending
+                Ok(self.write().$field.entry(id).or_insert(fetched).clone())
+            }
+        )*
+    };
+}
+
+impl EntityCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Entities> {
+        match self.entities.read() {
+            Ok(entities) => entities,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Entities> {
+        match self.entities.write() {
+            Ok(entities) => entities,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    cached_entity! {
+        user, get_or_fetch_user, users: User = GetUser.user_id => get_user;
+        user_full_info, get_or_fetch_user_full_info, user_full_infos: UserFullInfo = GetUserFullInfo.user_id => get_user_full_info;
+        chat, get_or_fetch_chat, chats: Chat = GetChat.chat_id => get_chat;
+        basic_group, get_or_fetch_basic_group, basic_groups: BasicGroup = GetBasicGroup.basic_group_id => get_basic_group;
+        basic_group_full_info, get_or_fetch_basic_group_full_info, basic_group_full_infos: BasicGroupFullInfo = GetBasicGroupFullInfo.basic_group_id => get_basic_group_full_info;
+        supergroup, get_or_fetch_supergroup, supergroups: Supergroup = GetSupergroup.supergroup_id => get_supergroup;
+        supergroup_full_info, get_or_fetch_supergroup_full_info, supergroup_full_infos: SupergroupFullInfo = GetSupergroupFullInfo.supergroup_id => get_supergroup_full_info;
+    }
+
+    /// Applies all updates from the stream, e.g. [UpdateReceiver](crate::client::UpdateReceiver).
+    pub async fn consume<U, St>(&self, updates: St)
+    where
+        U: AsRef<Update>,
+        St: Stream<Item = U>,
+    {
+        futures::pin_mut!(updates);
+        while let Some(update) = updates.next().await {
+            self.apply(update.as_ref());
+        }
+    }
+
+    /// Applies the update to cached objects.
+    pub fn apply(&self, update: &Update) {
+        match update {
+            Update::User(u) => {
+                self.write().users.insert(u.user().id(), u.user().clone());
+            }
+            Update::UserFullInfo(u) => {
+                self.write()
+                    .user_full_infos
+                    .insert(u.user_id(), u.user_full_info().clone());
+            }
+            Update::UserStatus(u) => {
+                if let Some(user) = self.write().users.get_mut(&u.user_id()) {
+                    user.status = u.status().clone();
+                }
+            }
+            Update::NewChat(u) => {
+                self.write().chats.insert(u.chat().id(), u.chat().clone());
+            }
+            Update::BasicGroup(u) => {
+                self.write()
+                    .basic_groups
+                    .insert(u.basic_group().id(), u.basic_group().clone());
+            }
+            Update::BasicGroupFullInfo(u) => {
+                self.write()
+                    .basic_group_full_infos
+                    .insert(u.basic_group_id(), u.basic_group_full_info().clone());
+            }
+            Update::Supergroup(u) => {
+                self.write()
+                    .supergroups
+                    .insert(u.supergroup().id(), u.supergroup().clone());
+            }
+            Update::SupergroupFullInfo(u) => {
+                self.write()
+                    .supergroup_full_infos
+                    .insert(u.supergroup_id(), u.supergroup_full_info().clone());
+            }
+            // partial updates of chats
+            Update::ChatActionBar(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.action_bar = u.action_bar().clone();
+            }),
+            Update::ChatDefaultDisableNotification(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.default_disable_notification = u.default_disable_notification();
+            }),
+            Update::ChatDraftMessage(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.draft_message = u.draft_message().clone();
+                chat.positions = Some(u.positions().clone());
+            }),
+            Update::ChatHasProtectedContent(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.has_protected_content = u.has_protected_content();
+            }),
+            Update::ChatHasScheduledMessages(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.has_scheduled_messages = u.has_scheduled_messages();
+            }),
+            Update::ChatIsBlocked(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.is_blocked = u.is_blocked();
+            }),
+            Update::ChatIsMarkedAsUnread(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.is_marked_as_unread = u.is_marked_as_unread();
+            }),
+            Update::ChatLastMessage(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.last_message = u.last_message().clone();
+                chat.positions = u.positions().clone();
+            }),
+            Update::ChatMessageSender(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.message_sender_id = u.message_sender_id().clone();
+            }),
+            Update::ChatMessageTtl(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.message_ttl = u.message_ttl();
+            }),
+            Update::ChatNotificationSettings(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.notification_settings = u.notification_settings().clone();
+            }),
+            Update::ChatPendingJoinRequests(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.pending_join_requests = u.pending_join_requests().clone();
+            }),
+            Update::ChatPermissions(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.permissions = u.permissions().clone();
+            }),
+            Update::ChatPhoto(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.photo = u.photo().clone();
+            }),
+            Update::ChatPosition(u) => self.patch_chat(u.chat_id(), |chat| {
+                set_chat_position(chat, u.position());
+            }),
+            Update::ChatReadInbox(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.last_read_inbox_message_id = u.last_read_inbox_message_id();
+                chat.unread_count = u.unread_count();
+            }),
+            Update::ChatReadOutbox(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.last_read_outbox_message_id = u.last_read_outbox_message_id();
+            }),
+            Update::ChatReplyMarkup(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.reply_markup_message_id = u.reply_markup_message_id();
+            }),
+            Update::ChatTheme(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.theme_name = u.theme_name().clone();
+            }),
+            Update::ChatTitle(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.title = u.title().clone();
+            }),
+            Update::ChatUnreadMentionCount(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.unread_mention_count = u.unread_mention_count();
+            }),
+            Update::ChatVideoChat(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.video_chat = u.video_chat().clone();
+            }),
+            Update::MessageMentionRead(u) => self.patch_chat(u.chat_id(), |chat| {
+                chat.unread_mention_count = u.unread_mention_count();
+            }),
+            _ => {}
+        }
+    }
+
+    // Changes the chat, if it's cached.
+    fn patch_chat<F: FnOnce(&mut Chat)>(&self, chat_id: i64, patch: F) {
+        if let Some(chat) = self.write().chats.get_mut(&chat_id) {
+            patch(chat);
+        }
+    }
+}
+
+// `updateChatPosition` replaces position in one chat list only, zero order removes it.
+fn set_chat_position(chat: &mut Chat, position: &ChatPosition) {
+    let positions = chat.positions.get_or_insert_with(Vec::new);
+    let list = ChatListId::from_chat_list(position.list());
+    positions.retain(|p| ChatListId::from_chat_list(p.list()) != list);
+    if position.order() != 0 {
+        positions.push(position.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityCache;
+    use crate::types::{Update, UserStatus};
+    use serde_json::json;
+
+    fn update(value: serde_json::Value) -> Update {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_apply() {
+        let cache = EntityCache::new();
+        cache.apply(&update(json!({
+            "@type": "updateNewChat",
+            "chat": {
+                "@type": "chat",
+                "id": 1,
+                "type": {"@type": "chatTypePrivate", "user_id": 1},
+                "title": "old title",
+                "permissions": {"@type": "chatPermissions"},
+                "positions": [{"@type": "chatPosition", "list": {"@type": "chatListMain"}, "order": "10"}],
+                "notification_settings": {"@type": "chatNotificationSettings"},
+                "video_chat": {"@type": "videoChat"},
+            },
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateChatTitle",
+            "chat_id": 1,
+            "title": "new title",
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateChatAction",
+            "chat_id": 1,
+            "message_thread_id": 0,
+            "sender_id": {"@type": "messageSenderUser", "user_id": 1},
+            "action": {"@type": "chatActionTyping"},
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateChatPosition",
+            "chat_id": 1,
+            "position": {"@type": "chatPosition", "list": {"@type": "chatListArchive"}, "order": "5"},
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateChatReadInbox",
+            "chat_id": 1,
+            "last_read_inbox_message_id": 20,
+            "unread_count": 3,
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateMessageMentionRead",
+            "chat_id": 1,
+            "message_id": 30,
+            "unread_mention_count": 2,
+        })));
+        let chat = cache.chat(1).unwrap();
+        assert_eq!(chat.title(), "new title");
+        assert_eq!(chat.last_read_inbox_message_id(), 20);
+        assert_eq!(chat.unread_count(), 3);
+        assert_eq!(chat.unread_mention_count(), 2);
+        assert_eq!(chat.positions().as_ref().unwrap().len(), 2);
+        assert!(cache.chat(2).is_none());
+
+        cache.apply(&update(json!({
+            "@type": "updateUser",
+            "user": {
+                "@type": "user",
+                "id": 7,
+                "first_name": "Jane",
+                "status": {"@type": "userStatusEmpty"},
+                "type": {"@type": "userTypeRegular"},
+            },
+        })));
+        cache.apply(&update(json!({
+            "@type": "updateUserStatus",
+            "user_id": 7,
+            "status": {"@type": "userStatusOnline", "expires": 100},
+        })));
+        let user = cache.user(7).unwrap();
+        assert_eq!(user.first_name(), "Jane");
+        assert!(matches!(user.status(), UserStatus::Online(_)));
+    }
+}
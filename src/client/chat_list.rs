@@ -0,0 +1,414 @@
+//! Ordered chat lists maintained from updates, as described in [Getting the list of chats](https://core.telegram.org/tdlib/getting-started#getting-the-lists-of-chats).
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use futures::{Stream, StreamExt};
+use tokio::sync::broadcast;
+
+use super::tdlib_client::TdLibClient;
+use super::updates::UpdateFilter;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{
+    ChatList, ChatListArchive, ChatListFilter, ChatListMain, ChatPosition, LoadChats, Update,
+};
+
+const CHANGES_CAPACITY: usize = 256;
+
+/// Identifier of a chat list, usable as a map key unlike [ChatList].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChatListId {
+    Main,
+    Archive,
+    /// List of chats of a chat filter with specified identifier.
+    Filter(i32),
+}
+
+impl ChatListId {
+    pub fn from_chat_list(chat_list: &ChatList) -> Option<Self> {
+        match chat_list {
+            ChatList::_Default | ChatList::Unknown { .. } => None,
+            ChatList::Main(_) => Some(ChatListId::Main),
+            ChatList::Archive(_) => Some(ChatListId::Archive),
+            ChatList::Filter(filter) => Some(ChatListId::Filter(filter.chat_filter_id())),
+        }
+    }
+}
+
+impl From<ChatListId> for ChatList {
+    fn from(id: ChatListId) -> Self {
+        match id {
+            ChatListId::Main => ChatList::Main(ChatListMain::builder().build()),
+            ChatListId::Archive => ChatList::Archive(ChatListArchive::builder().build()),
+            ChatListId::Filter(chat_filter_id) => ChatList::Filter(
+                ChatListFilter::builder()
+                    .chat_filter_id(chat_filter_id)
+                    .build(),
+            ),
+        }
+    }
+}
+
+/// Chat in a chat list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChatListEntry {
+    pub chat_id: i64,
+    pub order: i64,
+    pub is_pinned: bool,
+}
+
+/// Change of a chat position, sent to [ChatListState::subscribe] receivers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChatListChange {
+    pub list: ChatListId,
+    pub chat_id: i64,
+    /// New order of the chat in the list, 0 if the chat was removed from the list.
+    pub order: i64,
+}
+
+// chats sorted by descending order, then by descending chat id
+type SortedChats = BTreeSet<(Reverse<i64>, Reverse<i64>)>;
+
+#[derive(Debug, Default)]
+struct Lists {
+    sorted: HashMap<ChatListId, SortedChats>,
+    positions: HashMap<i64, HashMap<ChatListId, (i64, bool)>>,
+}
+
+impl Lists {
+    fn set_position(
+        &mut self,
+        chat_id: i64,
+        list: ChatListId,
+        order: i64,
+        is_pinned: bool,
+        changes: &mut Vec<ChatListChange>,
+    ) {
+        let positions = self.positions.entry(chat_id).or_default();
+        let previous = match order {
+            0 => positions.remove(&list),
+            _ => positions.insert(list, (order, is_pinned)),
+        };
+        if positions.is_empty() {
+            self.positions.remove(&chat_id);
+        }
+        let previous_order = previous.map(|(order, _)| order).unwrap_or_default();
+        if previous_order == order {
+            return;
+        }
+        let sorted = self.sorted.entry(list).or_default();
+        if previous_order != 0 {
+            sorted.remove(&(Reverse(previous_order), Reverse(chat_id)));
+        }
+        if order != 0 {
+            sorted.insert((Reverse(order), Reverse(chat_id)));
+        }
+        changes.push(ChatListChange {
+            list,
+            chat_id,
+            order,
+        });
+    }
+
+    fn set_positions(
+        &mut self,
+        chat_id: i64,
+        positions: &[ChatPosition],
+        changes: &mut Vec<ChatListChange>,
+    ) {
+        let new_positions: Vec<_> = positions
+            .iter()
+            .filter_map(|p| {
+                ChatListId::from_chat_list(p.list()).map(|list| (list, p.order(), p.is_pinned()))
+            })
+            .collect();
+        let removed: Vec<ChatListId> = self
+            .positions
+            .get(&chat_id)
+            .map(|current| {
+                current
+                    .keys()
+                    .filter(|list| !new_positions.iter().any(|(l, _, _)| l == *list))
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+        for list in removed {
+            self.set_position(chat_id, list, 0, false, changes);
+        }
+        for (list, order, is_pinned) in new_positions {
+            self.set_position(chat_id, list, order, is_pinned, changes);
+        }
+    }
+}
+
+/// Sorted chat lists (main, archive and lists of chat filters) maintained from
+/// `updateNewChat`, `updateChatPosition`, `updateChatLastMessage` and `updateChatDraftMessage` updates.
+/// ```ignore
+/// let state = Arc::new(ChatListState::new());
+/// tokio::spawn({
+///     let state = state.clone();
+///     let updates = client.subscribe(ChatListState::update_filter());
+///     async move { state.consume(updates).await }
+/// });
+/// state.load_chats(&client, ChatListId::Main, 100).await?;
+/// let top = state.top(ChatListId::Main, 20);
+/// ```
+#[derive(Debug)]
+pub struct ChatListState {
+    lists: RwLock<Lists>,
+    changes: broadcast::Sender<ChatListChange>,
+}
+
+impl Default for ChatListState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChatListState {
+    pub fn new() -> Self {
+        Self {
+            lists: RwLock::new(Lists::default()),
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
+        }
+    }
+
+    /// Filter of updates the state is built from, see [Client::subscribe](crate::client::Client::subscribe).
+    pub fn update_filter() -> UpdateFilter {
+        UpdateFilter::all()
+            .kind("updateNewChat")
+            .kind("updateChatPosition")
+            .kind("updateChatLastMessage")
+            .kind("updateChatDraftMessage")
+    }
+
+    /// Applies the update. Returns `true` if any chat list changed.
+    pub fn apply(&self, update: &Update) -> bool {
+        let mut changes = Vec::new();
+        {
+            let mut lists = match self.lists.write() {
+                Ok(lists) => lists,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            match update {
+                Update::NewChat(update) => {
+                    if let Some(positions) = update.chat().positions() {
+                        lists.set_positions(update.chat().id(), positions, &mut changes);
+                    }
+                }
+                Update::ChatPosition(update) => {
+                    let position = update.position();
+                    if let Some(list) = ChatListId::from_chat_list(position.list()) {
+                        lists.set_position(
+                            update.chat_id(),
+                            list,
+                            position.order(),
+                            position.is_pinned(),
+                            &mut changes,
+                        );
+                    }
+                }
+                Update::ChatLastMessage(update) => {
+                    if let Some(positions) = update.positions() {
+                        lists.set_positions(update.chat_id(), positions, &mut changes);
+                    }
+                }
+                Update::ChatDraftMessage(update) => {
+                    lists.set_positions(update.chat_id(), update.positions(), &mut changes);
+                }
+                _ => {}
+            }
+        }
+        let changed = !changes.is_empty();
+        for change in changes {
+            // there may be no receivers
+            let _ = self.changes.send(change);
+        }
+        changed
+    }
+
+    /// Applies all updates from the stream, e.g. [UpdateReceiver](crate::client::UpdateReceiver).
+    pub async fn consume<U, St>(&self, updates: St)
+    where
+        U: AsRef<Update>,
+        St: Stream<Item = U>,
+    {
+        futures::pin_mut!(updates);
+        while let Some(update) = updates.next().await {
+            self.apply(update.as_ref());
+        }
+    }
+
+    /// Returns first `n` chats of the list.
+    pub fn top(&self, list: ChatListId, n: usize) -> Vec<ChatListEntry> {
+        let lists = match self.lists.read() {
+            Ok(lists) => lists,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let sorted = match lists.sorted.get(&list) {
+            None => return Vec::new(),
+            Some(sorted) => sorted,
+        };
+        sorted
+            .iter()
+            .take(n)
+            .map(|(Reverse(order), Reverse(chat_id))| ChatListEntry {
+                chat_id: *chat_id,
+                order: *order,
+                is_pinned: lists
+                    .positions
+                    .get(chat_id)
+                    .and_then(|p| p.get(&list))
+                    .map(|(_, is_pinned)| *is_pinned)
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Number of known chats in the list.
+    pub fn len(&self, list: ChatListId) -> usize {
+        match self.lists.read() {
+            Ok(lists) => lists.sorted.get(&list).map(|s| s.len()).unwrap_or_default(),
+            Err(poisoned) => poisoned
+                .into_inner()
+                .sorted
+                .get(&list)
+                .map(|s| s.len())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn is_empty(&self, list: ChatListId) -> bool {
+        self.len(list) == 0
+    }
+
+    /// Returns receiver of chat position changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<ChatListChange> {
+        self.changes.subscribe()
+    }
+
+    /// Loads all chats of the list with `loadChats` requests of `page_size` chats until TDLib responds with 404.
+    /// Chats are delivered with updates, so the state must [consume](ChatListState::consume) them concurrently.
+    pub async fn load_chats<R>(
+        &self,
+        client: &Client<R>,
+        list: ChatListId,
+        page_size: i32,
+    ) -> Result<()>
+    where
+        R: TdLibClient + Clone,
+    {
+        loop {
+            // every page is a new request with its own `@extra`
+            let request = LoadChats::builder()
+                .chat_list(ChatList::from(list))
+                .limit(page_size)
+                .build();
+            match client.load_chats(request).await {
+                Ok(_) => {}
+                Err(Error::TDLibError(e)) if e.code() == 404 => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatListChange, ChatListId, ChatListState};
+    use crate::types::Update;
+    use serde_json::json;
+
+    fn update(value: serde_json::Value) -> Update {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn position(list: serde_json::Value, order: i64) -> serde_json::Value {
+        json!({"@type": "chatPosition", "list": list, "order": order.to_string(), "is_pinned": false})
+    }
+
+    #[test]
+    fn test_apply() {
+        let main = json!({"@type": "chatListMain"});
+        let filter = json!({"@type": "chatListFilter", "chat_filter_id": 3});
+        let state = ChatListState::new();
+        let mut changes = state.subscribe();
+
+        for (chat_id, order) in [(1, 10), (2, 30), (3, 20)] {
+            assert!(state.apply(&update(json!({
+                "@type": "updateChatPosition",
+                "chat_id": chat_id,
+                "position": position(main.clone(), order),
+            }))));
+        }
+        let top: Vec<_> = state
+            .top(ChatListId::Main, 2)
+            .iter()
+            .map(|e| e.chat_id)
+            .collect();
+        assert_eq!(top, vec![2, 3]);
+
+        // chat 2 leaves the main list and joins the filter one
+        assert!(state.apply(&update(json!({
+            "@type": "updateChatLastMessage",
+            "chat_id": 2,
+            "positions": [position(filter.clone(), 5)],
+        }))));
+        let top: Vec<_> = state
+            .top(ChatListId::Main, 10)
+            .iter()
+            .map(|e| e.chat_id)
+            .collect();
+        assert_eq!(top, vec![3, 1]);
+        assert_eq!(state.len(ChatListId::Filter(3)), 1);
+        assert!(state.is_empty(ChatListId::Archive));
+
+        let mut received = Vec::new();
+        while let Ok(change) = changes.try_recv() {
+            received.push(change);
+        }
+        assert_eq!(received.len(), 5);
+        assert_eq!(
+            received[3],
+            ChatListChange {
+                list: ChatListId::Main,
+                chat_id: 2,
+                order: 0
+            }
+        );
+    }
+
+    #[cfg(feature = "test-util")]
+    #[tokio::test]
+    async fn test_load_chats() {
+        use crate::client::fake_tdlib::{fixtures::start, FakeTdlib};
+        use std::collections::HashSet;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fake = FakeTdlib::new().authorized();
+        let pages = AtomicUsize::new(0);
+        fake.respond_with_fn("loadChats", move |_| {
+            match pages.fetch_add(1, Ordering::Relaxed) < 2 {
+                true => json!({"@type": "ok"}),
+                false => json!({"@type": "error", "code": 404, "message": "Not Found"}),
+            }
+        });
+        let (worker, client) = start(&fake).await;
+
+        let state = ChatListState::new();
+        state
+            .load_chats(&client, ChatListId::Main, 10)
+            .await
+            .unwrap();
+        let extras: HashSet<_> = fake
+            .sent_requests()
+            .iter()
+            .filter(|r| r["@type"] == "loadChats")
+            .map(|r| r["@extra"].to_string())
+            .collect();
+        assert_eq!(extras.len(), 3);
+        worker.stop();
+    }
+}
@@ -0,0 +1,293 @@
+//! Sending of messages awaiting their delivery to the server.
+//!
+//! Messages returned by `sendMessage`, `sendMessageAlbum` and `forwardMessages` have temporary identifiers
+//! and `messageSendingStatePending` sending state. TDLib reports the outcome later with
+//! `updateMessageSendSucceeded` or `updateMessageSendFailed`, both keyed by `old_message_id`.
+//! The outcome is awaited for the client's request timeout, or for a minute if it has none.
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::tdlib_client::TdLibClient;
+use super::updates::{UpdateFilter, UpdateReceiver};
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{
+    ForwardMessages, Message, MessageSendingState, RObject, SendMessage, SendMessageAlbum, Update,
+};
+
+// How long messages are awaited to be sent if the client has no request timeout.
+const DEFAULT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+fn outcome_filter(chat_id: i64) -> UpdateFilter {
+    UpdateFilter::all()
+        .kind("updateMessageSendSucceeded")
+        .kind("updateMessageSendFailed")
+        .chat(chat_id)
+}
+
+fn send_failed(message: &Message, old_message_id: i64, code: i32, text: &str) -> Error {
+    Error::MessageSendFailed {
+        message: Box::new(message.clone()),
+        old_message_id,
+        error: crate::types::Error::builder()
+            .code(code)
+            .message(text)
+            .build(),
+    }
+}
+
+// Replaces pending messages with sent ones, as they're reported by updates.
+// Fails on the first message which failed to be sent, if the outcome isn't known in `wait`
+// or if some of the updates were dropped. `method` and `extra` identify the sending request in timeout errors.
+async fn confirm(
+    mut updates: UpdateReceiver,
+    mut messages: Vec<Option<Message>>,
+    wait: Duration,
+    method: &str,
+    extra: Option<&str>,
+) -> Result<Vec<Option<Message>>> {
+    let mut pending = HashMap::new();
+    for (i, message) in messages.iter().enumerate() {
+        let message = match message {
+            Some(message) => message,
+            None => continue,
+        };
+        match message.sending_state() {
+            Some(MessageSendingState::Pending(_)) => {
+                pending.insert(message.id(), i);
+            }
+            Some(MessageSendingState::Failed(state)) => {
+                return Err(send_failed(
+                    message,
+                    message.id(),
+                    state.error_code(),
+                    state.error_message(),
+                ))
+            }
+            _ => {}
+        }
+    }
+    let deadline = tokio::time::Instant::now() + wait;
+    while !pending.is_empty() {
+        let update = match tokio::time::timeout_at(deadline, updates.recv()).await {
+            Ok(Some(update)) => update,
+            Ok(None) => return Err(Error::Internal("updates stream closed")),
+            Err(_) => {
+                return Err(Error::Timeout {
+                    method: method.to_string(),
+                    extra: extra.unwrap_or_default().to_string(),
+                })
+            }
+        };
+        if updates.lagged() > 0 {
+            return Err(Error::Internal("updates of sent messages were dropped"));
+        }
+        match update.as_ref() {
+            Update::MessageSendSucceeded(update) => {
+                if let Some(i) = pending.remove(&update.old_message_id()) {
+                    messages[i] = Some(update.message().clone());
+                }
+            }
+            Update::MessageSendFailed(update) if pending.contains_key(&update.old_message_id()) => {
+                return Err(send_failed(
+                    update.message(),
+                    update.old_message_id(),
+                    update.error_code(),
+                    update.error_message(),
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(messages)
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    fn confirm_timeout(&self) -> Duration {
+        self.request_timeout.unwrap_or(DEFAULT_CONFIRM_TIMEOUT)
+    }
+
+    /// Sends a message and waits until it's delivered to the server.
+    /// Returns the message with its final identifier, or [Error::MessageSendFailed] if it wasn't sent.
+    pub async fn send_message_confirmed<C: AsRef<SendMessage>>(
+        &self,
+        send_message: C,
+    ) -> Result<Message> {
+        // subscribe before sending, so the outcome can't be missed
+        let updates = self.subscribe(outcome_filter(send_message.as_ref().chat_id()));
+        let extra = send_message.as_ref().extra().map(str::to_string);
+        let message = self.send_message(send_message).await?;
+        match confirm(
+            updates,
+            vec![Some(message)],
+            self.confirm_timeout(),
+            "sendMessage",
+            extra.as_deref(),
+        )
+        .await?
+        .pop()
+        {
+            Some(Some(message)) => Ok(message),
+            _ => Err(Error::Internal("sent message is lost")),
+        }
+    }
+
+    /// Sends an album and waits until all its messages are delivered to the server.
+    /// Fails with [Error::MessageSendFailed] if any of the messages wasn't sent.
+    pub async fn send_message_album_confirmed<C: AsRef<SendMessageAlbum>>(
+        &self,
+        send_message_album: C,
+    ) -> Result<Vec<Message>> {
+        let updates = self.subscribe(outcome_filter(send_message_album.as_ref().chat_id()));
+        let extra = send_message_album.as_ref().extra().map(str::to_string);
+        let messages = self.send_message_album(send_message_album).await?;
+        let messages = confirm(
+            updates,
+            messages.messages().clone(),
+            self.confirm_timeout(),
+            "sendMessageAlbum",
+            extra.as_deref(),
+        )
+        .await?;
+        Ok(messages.into_iter().flatten().collect())
+    }
+
+    /// Forwards messages and waits until all of them are delivered to the server.
+    /// Messages which can't be forwarded are `None`, as in `forwardMessages` response.
+    /// Fails with [Error::MessageSendFailed] if any of the messages wasn't sent.
+    pub async fn forward_messages_confirmed<C: AsRef<ForwardMessages>>(
+        &self,
+        forward_messages: C,
+    ) -> Result<Vec<Option<Message>>> {
+        let updates = self.subscribe(outcome_filter(forward_messages.as_ref().chat_id()));
+        let extra = forward_messages.as_ref().extra().map(str::to_string);
+        let messages = self.forward_messages(forward_messages).await?;
+        confirm(
+            updates,
+            messages.messages().clone(),
+            self.confirm_timeout(),
+            "forwardMessages",
+            extra.as_deref(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::confirm;
+    use crate::client::updates::{Subscribers, UpdateFilter, UpdateReceiver};
+    use crate::errors::Error;
+    use crate::types::{
+        Message, MessageSendingState, MessageSendingStatePending, Update, UpdateMessageSendFailed,
+        UpdateMessageSendSucceeded,
+    };
+    use std::time::Duration;
+
+    const WAIT: Duration = Duration::from_secs(5);
+    const METHOD: &str = "sendMessageAlbum";
+
+    // Receiver of the updates, closed after them.
+    fn updates(updates: Vec<Update>, capacity: usize) -> UpdateReceiver {
+        let subscribers = Subscribers::default();
+        let receiver = subscribers.subscribe(UpdateFilter::all(), capacity);
+        for update in &updates {
+            subscribers.broadcast(&serde_json::to_value(update).unwrap(), update);
+        }
+        receiver
+    }
+
+    fn message(id: i64, pending: bool) -> Message {
+        let mut message = Message::builder();
+        message.id(id).chat_id(1);
+        if pending {
+            message.sending_state(MessageSendingState::Pending(
+                MessageSendingStatePending::builder().build(),
+            ));
+        }
+        message.build()
+    }
+
+    fn succeeded(old_message_id: i64, id: i64) -> Update {
+        Update::MessageSendSucceeded(
+            UpdateMessageSendSucceeded::builder()
+                .old_message_id(old_message_id)
+                .message(message(id, false))
+                .build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_confirm() {
+        let messages = vec![Some(message(-1, true)), None, Some(message(-2, true))];
+        let received = updates(
+            vec![succeeded(-3, 30), succeeded(-2, 20), succeeded(-1, 10)],
+            16,
+        );
+        let confirmed = confirm(received, messages.clone(), WAIT, METHOD, None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = confirmed
+            .iter()
+            .map(|m| m.as_ref().map(|m| m.id()))
+            .collect();
+        assert_eq!(ids, vec![Some(10), None, Some(20)]);
+
+        let failed = Update::MessageSendFailed(
+            UpdateMessageSendFailed::builder()
+                .old_message_id(-2)
+                .message(message(-2, false))
+                .error_code(403)
+                .error_message("CHAT_WRITE_FORBIDDEN")
+                .build(),
+        );
+        let received = updates(vec![succeeded(-1, 10), failed], 16);
+        match confirm(received, messages.clone(), WAIT, METHOD, None).await {
+            Err(Error::MessageSendFailed {
+                old_message_id,
+                error,
+                ..
+            }) => {
+                assert_eq!(old_message_id, -2);
+                assert_eq!(error.message(), "CHAT_WRITE_FORBIDDEN");
+            }
+            v => panic!("unexpected result: {:?}", v),
+        }
+
+        // stream ends before the outcome of the second message is known
+        let received = updates(vec![succeeded(-1, 10)], 16);
+        assert!(confirm(received, messages.clone(), WAIT, METHOD, None)
+            .await
+            .is_err());
+
+        // outcome of the second message is dropped
+        let received = updates(vec![succeeded(-1, 10), succeeded(-2, 20)], 1);
+        assert!(confirm(received, messages.clone(), WAIT, METHOD, None)
+            .await
+            .is_err());
+
+        // outcome of the second message never comes
+        let subscribers = Subscribers::default();
+        let received = subscribers.subscribe(UpdateFilter::all(), 16);
+        let update = succeeded(-1, 10);
+        subscribers.broadcast(&serde_json::to_value(&update).unwrap(), &update);
+        match confirm(
+            received,
+            messages,
+            Duration::from_millis(10),
+            METHOD,
+            Some("extra"),
+        )
+        .await
+        {
+            Err(Error::Timeout { method, extra }) => {
+                assert_eq!(method, METHOD);
+                assert_eq!(extra, "extra");
+            }
+            v => panic!("unexpected result: {:?}", v),
+        }
+    }
+}
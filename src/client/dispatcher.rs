@@ -0,0 +1,597 @@
+//! Routing of updates to typed async handlers.
+//!
+//! ```ignore
+//! let dispatcher = Dispatcher::new(client, AppState::default())
+//!     .on(|ctx: Context<TdJson, AppState>, update: UpdateNewMessage| async move {
+//!         ctx.state().messages.fetch_add(1, Ordering::Relaxed);
+//!         Ok(())
+//!     })
+//!     .on_if(
+//!         |query: &UpdateNewCallbackQuery| query.chat_id() > 0,
+//!         |ctx: Context<TdJson, AppState>, query: UpdateNewCallbackQuery| async move { Ok(()) },
+//!     )
+//!     .on_error(|error: DispatchError| async move { log::error!("{}", error) });
+//! dispatcher.run().await;
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::{FutureExt, Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::tdlib_client::TdLibClient;
+use super::updates::UpdateFilter;
+use super::Client;
+use crate::types::{self, Update};
+
+// Queues of idle chats are dropped when there are more of them.
+const MAX_IDLE_QUEUES: usize = 256;
+
+/// Error returned by a handler.
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type HandlerResult = std::result::Result<(), HandlerError>;
+
+/// Failure of a handler, passed to [Dispatcher::on_error].
+#[derive(Debug)]
+pub struct DispatchError {
+    /// Update the handler failed on.
+    pub update: Arc<Update>,
+    /// Error returned by the handler, or its panic message.
+    pub error: HandlerError,
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "handler failed: {}", self.error)
+    }
+}
+
+/// Context passed to every handler: the client which received an update and shared application state.
+pub struct Context<R, S>
+where
+    R: TdLibClient + Clone,
+{
+    client: Client<R>,
+    state: Arc<S>,
+}
+
+impl<R, S> Clone for Context<R, S>
+where
+    R: TdLibClient + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<R, S> Context<R, S>
+where
+    R: TdLibClient + Clone,
+{
+    pub fn client(&self) -> &Client<R> {
+        &self.client
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+/// Type which can be extracted from an [Update]: one of its variants, or the update itself.
+pub trait FromUpdate: Clone + Send + 'static {
+    fn from_update(update: &Update) -> Option<&Self>;
+}
+
+impl FromUpdate for Update {
+    fn from_update(update: &Update) -> Option<&Self> {
+        Some(update)
+    }
+}
+
+macro_rules! from_update {
+    ($($variant:ident => $t:ident,)*; boxed: $($boxed:ident => $boxed_t:ident,)*) => {
+        $(
+            impl FromUpdate for types::$t {
+                fn from_update(update: &Update) -> Option<&Self> {
+                    match update {
+                        Update::$variant(u) => Some(u),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+        $(
+            impl FromUpdate for types::$boxed_t {
+                fn from_update(update: &Update) -> Option<&Self> {
+                    match update {
+                        Update::$boxed(u) => Some(u.as_ref()),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+
+        // Doesn't compile if some variant of `Update` is missing from the list.
+        #[allow(dead_code)]
+        fn all_variants_listed(update: &Update) {
+            match update {
+                $(Update::$variant(_))|* | $(Update::$boxed(_))|* => {}
+                Update::Unknown { .. } | Update::_Default => {}
+            }
+        }
+    };
+}
+
+from_update! {
+TestUseUpdate => TestUseUpdate,
+ActiveNotifications => UpdateActiveNotifications,
+AnimatedEmojiMessageClicked => UpdateAnimatedEmojiMessageClicked,
+AnimationSearchParameters => UpdateAnimationSearchParameters,
+AuthorizationState => UpdateAuthorizationState,
+BasicGroup => UpdateBasicGroup,
+BasicGroupFullInfo => UpdateBasicGroupFullInfo,
+Call => UpdateCall,
+ChatAction => UpdateChatAction,
+ChatActionBar => UpdateChatActionBar,
+ChatDefaultDisableNotification => UpdateChatDefaultDisableNotification,
+ChatDraftMessage => UpdateChatDraftMessage,
+ChatFilters => UpdateChatFilters,
+ChatHasProtectedContent => UpdateChatHasProtectedContent,
+ChatHasScheduledMessages => UpdateChatHasScheduledMessages,
+ChatIsBlocked => UpdateChatIsBlocked,
+ChatIsMarkedAsUnread => UpdateChatIsMarkedAsUnread,
+ChatLastMessage => UpdateChatLastMessage,
+ChatMember => UpdateChatMember,
+ChatMessageSender => UpdateChatMessageSender,
+ChatMessageTtl => UpdateChatMessageTtl,
+ChatNotificationSettings => UpdateChatNotificationSettings,
+ChatOnlineMemberCount => UpdateChatOnlineMemberCount,
+ChatPendingJoinRequests => UpdateChatPendingJoinRequests,
+ChatPermissions => UpdateChatPermissions,
+ChatPhoto => UpdateChatPhoto,
+ChatPosition => UpdateChatPosition,
+ChatReadInbox => UpdateChatReadInbox,
+ChatReadOutbox => UpdateChatReadOutbox,
+ChatReplyMarkup => UpdateChatReplyMarkup,
+ChatTheme => UpdateChatTheme,
+ChatThemes => UpdateChatThemes,
+ChatTitle => UpdateChatTitle,
+ChatUnreadMentionCount => UpdateChatUnreadMentionCount,
+ChatVideoChat => UpdateChatVideoChat,
+ConnectionState => UpdateConnectionState,
+DeleteMessages => UpdateDeleteMessages,
+DiceEmojis => UpdateDiceEmojis,
+FavoriteStickers => UpdateFavoriteStickers,
+File => UpdateFile,
+FileGenerationStart => UpdateFileGenerationStart,
+FileGenerationStop => UpdateFileGenerationStop,
+GroupCall => UpdateGroupCall,
+GroupCallParticipant => UpdateGroupCallParticipant,
+HavePendingNotifications => UpdateHavePendingNotifications,
+InstalledStickerSets => UpdateInstalledStickerSets,
+LanguagePackStrings => UpdateLanguagePackStrings,
+MessageContent => UpdateMessageContent,
+MessageContentOpened => UpdateMessageContentOpened,
+MessageEdited => UpdateMessageEdited,
+MessageInteractionInfo => UpdateMessageInteractionInfo,
+MessageIsPinned => UpdateMessageIsPinned,
+MessageLiveLocationViewed => UpdateMessageLiveLocationViewed,
+MessageMentionRead => UpdateMessageMentionRead,
+MessageSendAcknowledged => UpdateMessageSendAcknowledged,
+MessageSendFailed => UpdateMessageSendFailed,
+MessageSendSucceeded => UpdateMessageSendSucceeded,
+NewCallSignalingData => UpdateNewCallSignalingData,
+NewCallbackQuery => UpdateNewCallbackQuery,
+NewChatJoinRequest => UpdateNewChatJoinRequest,
+NewChosenInlineResult => UpdateNewChosenInlineResult,
+NewCustomEvent => UpdateNewCustomEvent,
+NewCustomQuery => UpdateNewCustomQuery,
+NewInlineCallbackQuery => UpdateNewInlineCallbackQuery,
+NewInlineQuery => UpdateNewInlineQuery,
+NewMessage => UpdateNewMessage,
+NewPreCheckoutQuery => UpdateNewPreCheckoutQuery,
+NewShippingQuery => UpdateNewShippingQuery,
+Notification => UpdateNotification,
+NotificationGroup => UpdateNotificationGroup,
+Option => UpdateOption,
+Poll => UpdatePoll,
+PollAnswer => UpdatePollAnswer,
+RecentStickers => UpdateRecentStickers,
+SavedAnimations => UpdateSavedAnimations,
+ScopeNotificationSettings => UpdateScopeNotificationSettings,
+SecretChat => UpdateSecretChat,
+SelectedBackground => UpdateSelectedBackground,
+ServiceNotification => UpdateServiceNotification,
+StickerSet => UpdateStickerSet,
+SuggestedActions => UpdateSuggestedActions,
+Supergroup => UpdateSupergroup,
+SupergroupFullInfo => UpdateSupergroupFullInfo,
+TermsOfService => UpdateTermsOfService,
+TrendingStickerSets => UpdateTrendingStickerSets,
+UnreadChatCount => UpdateUnreadChatCount,
+UnreadMessageCount => UpdateUnreadMessageCount,
+User => UpdateUser,
+UserFullInfo => UpdateUserFullInfo,
+UserPrivacySettingRules => UpdateUserPrivacySettingRules,
+UserStatus => UpdateUserStatus,
+UsersNearby => UpdateUsersNearby,
+; boxed:
+NewChat => UpdateNewChat,
+}
+
+/// Returns identifier of the chat the update relates to, if any.
+pub fn update_chat_id(update: &Update) -> Option<i64> {
+    match update {
+        Update::NewMessage(u) => Some(u.message().chat_id()),
+        Update::MessageSendSucceeded(u) => Some(u.message().chat_id()),
+        Update::MessageSendFailed(u) => Some(u.message().chat_id()),
+        Update::NewChat(u) => Some(u.chat().id()),
+        Update::Unknown { raw, .. } => raw.get("chat_id").and_then(|c| c.as_i64()),
+        Update::AnimatedEmojiMessageClicked(u) => Some(u.chat_id()),
+        Update::ChatAction(u) => Some(u.chat_id()),
+        Update::ChatActionBar(u) => Some(u.chat_id()),
+        Update::ChatDefaultDisableNotification(u) => Some(u.chat_id()),
+        Update::ChatDraftMessage(u) => Some(u.chat_id()),
+        Update::ChatHasProtectedContent(u) => Some(u.chat_id()),
+        Update::ChatHasScheduledMessages(u) => Some(u.chat_id()),
+        Update::ChatIsBlocked(u) => Some(u.chat_id()),
+        Update::ChatIsMarkedAsUnread(u) => Some(u.chat_id()),
+        Update::ChatLastMessage(u) => Some(u.chat_id()),
+        Update::ChatMember(u) => Some(u.chat_id()),
+        Update::ChatMessageSender(u) => Some(u.chat_id()),
+        Update::ChatMessageTtl(u) => Some(u.chat_id()),
+        Update::ChatNotificationSettings(u) => Some(u.chat_id()),
+        Update::ChatOnlineMemberCount(u) => Some(u.chat_id()),
+        Update::ChatPendingJoinRequests(u) => Some(u.chat_id()),
+        Update::ChatPermissions(u) => Some(u.chat_id()),
+        Update::ChatPhoto(u) => Some(u.chat_id()),
+        Update::ChatPosition(u) => Some(u.chat_id()),
+        Update::ChatReadInbox(u) => Some(u.chat_id()),
+        Update::ChatReadOutbox(u) => Some(u.chat_id()),
+        Update::ChatReplyMarkup(u) => Some(u.chat_id()),
+        Update::ChatTheme(u) => Some(u.chat_id()),
+        Update::ChatTitle(u) => Some(u.chat_id()),
+        Update::ChatUnreadMentionCount(u) => Some(u.chat_id()),
+        Update::ChatVideoChat(u) => Some(u.chat_id()),
+        Update::DeleteMessages(u) => Some(u.chat_id()),
+        Update::MessageContent(u) => Some(u.chat_id()),
+        Update::MessageContentOpened(u) => Some(u.chat_id()),
+        Update::MessageEdited(u) => Some(u.chat_id()),
+        Update::MessageInteractionInfo(u) => Some(u.chat_id()),
+        Update::MessageIsPinned(u) => Some(u.chat_id()),
+        Update::MessageLiveLocationViewed(u) => Some(u.chat_id()),
+        Update::MessageMentionRead(u) => Some(u.chat_id()),
+        Update::MessageSendAcknowledged(u) => Some(u.chat_id()),
+        Update::NewCallbackQuery(u) => Some(u.chat_id()),
+        Update::NewChatJoinRequest(u) => Some(u.chat_id()),
+        Update::NotificationGroup(u) => Some(u.chat_id()),
+        _ => None,
+    }
+}
+
+type Handler<R, S> =
+    Box<dyn Fn(&Context<R, S>, &Update) -> Option<BoxFuture<'static, HandlerResult>> + Send + Sync>;
+
+type ErrorHandler = Box<dyn Fn(DispatchError) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Routes updates received by a client to handlers registered with [on](Dispatcher::on) and [on_if](Dispatcher::on_if).
+///
+/// Each update is handled by the first handler, registered for its type, whose predicate accepts it.
+/// Updates of different chats are handled concurrently, updates of one chat (see [update_chat_id]) are handled
+/// sequentially, in order of receiving. Updates not related to any chat are handled sequentially too.
+/// Errors and panics of handlers are passed to the [error handler](Dispatcher::on_error), or logged if it's not set.
+pub struct Dispatcher<R, S>
+where
+    R: TdLibClient + Clone,
+{
+    context: Context<R, S>,
+    handlers: Vec<Handler<R, S>>,
+    error_handler: Option<ErrorHandler>,
+}
+
+impl<R, S> fmt::Debug for Dispatcher<R, S>
+where
+    R: TdLibClient + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("handlers", &self.handlers.len())
+            .field("error_handler", &self.error_handler.is_some())
+            .finish()
+    }
+}
+
+impl<R, S> Dispatcher<R, S>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+    S: Send + Sync + 'static,
+{
+    /// Creates a dispatcher of updates received by the client, which must be bound to a [Worker](crate::client::Worker).
+    pub fn new(client: Client<R>, state: S) -> Self {
+        Self {
+            context: Context {
+                client,
+                state: Arc::new(state),
+            },
+            handlers: Vec::new(),
+            error_handler: None,
+        }
+    }
+
+    /// Registers a handler of updates of type `U`, e.g. [UpdateNewMessage](crate::types::UpdateNewMessage).
+    pub fn on<U, F, Fut>(self, handler: F) -> Self
+    where
+        U: FromUpdate,
+        F: Fn(Context<R, S>, U) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on_if(|_: &U| true, handler)
+    }
+
+    /// Registers a handler of updates of type `U` accepted by the predicate.
+    pub fn on_if<U, P, F, Fut>(mut self, predicate: P, handler: F) -> Self
+    where
+        U: FromUpdate,
+        P: Fn(&U) -> bool + Send + Sync + 'static,
+        F: Fn(Context<R, S>, U) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.handlers.push(Box::new(move |context, update| {
+            let update = U::from_update(update)?;
+            if !predicate(update) {
+                return None;
+            }
+            Some(handler(context.clone(), update.clone()).boxed())
+        }));
+        self
+    }
+
+    /// Sets handler of handlers' errors.
+    pub fn on_error<F, Fut>(mut self, error_handler: F) -> Self
+    where
+        F: Fn(DispatchError) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.error_handler = Some(Box::new(move |error| error_handler(error).boxed()));
+        self
+    }
+
+    /// Handles all updates of the client, see [Client::subscribe](crate::client::Client::subscribe).
+    /// Returns when the client is closed or its worker is stopped, after all received updates are handled.
+    pub async fn run(self) {
+        let updates = self.context.client.subscribe(UpdateFilter::all());
+        self.run_with(updates).await
+    }
+
+    /// Handles updates from the stream, e.g. received with [ClientBuilder::with_updates_sender](crate::client::ClientBuilder::with_updates_sender).
+    /// Returns when the stream ends, after all updates are handled.
+    pub async fn run_with<U, St>(self, updates: St)
+    where
+        U: Into<Arc<Update>>,
+        St: Stream<Item = U>,
+    {
+        let shared = Arc::new(self);
+        let mut queues: HashMap<Option<i64>, ChatQueue> = HashMap::new();
+        futures::pin_mut!(updates);
+        while let Some(update) = updates.next().await {
+            let update = update.into();
+            let chat_id = update_chat_id(&update);
+            if queues.len() > MAX_IDLE_QUEUES {
+                queues.retain(|_, queue| queue.pending.load(Ordering::SeqCst) > 0);
+            }
+            let queue = queues
+                .entry(chat_id)
+                .or_insert_with(|| ChatQueue::spawn(shared.clone()));
+            queue.pending.fetch_add(1, Ordering::SeqCst);
+            if let Err(mpsc::error::SendError(update)) = queue.sender.send(update) {
+                // queue's task may be gone only if the runtime is shutting down
+                log::error!(
+                    "update queue of chat {:?} is closed, {:?} dropped",
+                    chat_id,
+                    update
+                );
+            }
+        }
+        for (_, queue) in queues.drain() {
+            drop(queue.sender);
+            if let Err(e) = queue.task.await {
+                log::error!("update queue task failed: {}", e);
+            }
+        }
+    }
+
+    async fn handle(&self, update: Arc<Update>) {
+        for handler in &self.handlers {
+            let future = match handler(&self.context, &update) {
+                None => continue,
+                Some(future) => future,
+            };
+            let error: HandlerError = match AssertUnwindSafe(future).catch_unwind().await {
+                Ok(Ok(())) => return,
+                Ok(Err(error)) => error,
+                Err(panic) => match panic.downcast::<String>() {
+                    Ok(message) => format!("handler panicked: {}", message).into(),
+                    Err(panic) => match panic.downcast::<&str>() {
+                        Ok(message) => format!("handler panicked: {}", message).into(),
+                        Err(_) => "handler panicked".into(),
+                    },
+                },
+            };
+            let error = DispatchError { update, error };
+            match &self.error_handler {
+                Some(error_handler) => error_handler(error).await,
+                None => log::error!("{}, update: {:?}", error, error.update),
+            }
+            return;
+        }
+        log::trace!("no handler for update {:?}", update);
+    }
+}
+
+struct ChatQueue {
+    sender: mpsc::UnboundedSender<Arc<Update>>,
+    // updates sent to the queue and not handled yet
+    pending: Arc<AtomicUsize>,
+    task: JoinHandle<()>,
+}
+
+impl ChatQueue {
+    fn spawn<R, S>(dispatcher: Arc<Dispatcher<R, S>>) -> Self
+    where
+        R: TdLibClient + Clone + Send + Sync + 'static,
+        S: Send + Sync + 'static,
+    {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Arc<Update>>();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let task_pending = pending.clone();
+        let task = tokio::spawn(async move {
+            while let Some(update) = receiver.recv().await {
+                dispatcher.handle(update).await;
+                task_pending.fetch_sub(1, Ordering::SeqCst);
+            }
+        });
+        Self {
+            sender,
+            pending,
+            task,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::{Context, DispatchError, Dispatcher, FromUpdate};
+    use crate::client::fake_tdlib::{fixtures::start, FakeTdlib};
+    use crate::client::ClientBuilder;
+    use crate::types::{Chat, TdlibParameters, Update, UpdateChatTitle, UpdateNewChat};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct State {
+        titles: Mutex<Vec<String>>,
+        errors: Mutex<Vec<String>>,
+    }
+
+    fn title_update(chat_id: i64, title: &str) -> Arc<Update> {
+        Arc::new(Update::ChatTitle(
+            UpdateChatTitle::builder()
+                .chat_id(chat_id)
+                .title(title)
+                .build(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_dispatch() {
+        let client = ClientBuilder::new(FakeTdlib::new())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        let state = Arc::new(State::default());
+        let errors = state.clone();
+        let dispatcher = Dispatcher::new(client, state.clone())
+            .on_if(
+                |update: &UpdateChatTitle| update.title() == "fail",
+                |_: Context<FakeTdlib, Arc<State>>, _: UpdateChatTitle| async move {
+                    Err("failed".into())
+                },
+            )
+            .on(
+                |ctx: Context<FakeTdlib, Arc<State>>, update: UpdateChatTitle| async move {
+                    if update.chat_id() == 1 {
+                        // updates of other chats are handled meanwhile
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                    }
+                    ctx.state()
+                        .titles
+                        .lock()
+                        .unwrap()
+                        .push(update.title().clone());
+                    Ok(())
+                },
+            )
+            .on_error(move |error: DispatchError| {
+                let errors = errors.clone();
+                async move { errors.errors.lock().unwrap().push(error.error.to_string()) }
+            });
+
+        dispatcher
+            .run_with(futures::stream::iter(vec![
+                title_update(1, "first"),
+                title_update(1, "second"),
+                title_update(2, "third"),
+                title_update(2, "fail"),
+            ]))
+            .await;
+
+        let titles = state.titles.lock().unwrap().clone();
+        assert_eq!(titles, vec!["third", "first", "second"]);
+        assert_eq!(*state.errors.lock().unwrap(), vec!["failed".to_string()]);
+    }
+
+    #[test]
+    fn test_from_update() {
+        let new_chat = Update::NewChat(Box::new(
+            UpdateNewChat::builder()
+                .chat(Chat::builder().id(3).build())
+                .build(),
+        ));
+        assert_eq!(
+            UpdateNewChat::from_update(&new_chat).map(|u| u.chat().id()),
+            Some(3)
+        );
+        assert!(UpdateChatTitle::from_update(&new_chat).is_none());
+        let title = title_update(1, "title");
+        assert_eq!(
+            UpdateChatTitle::from_update(&title).map(|u| u.title().as_str()),
+            Some("title")
+        );
+        assert!(UpdateNewChat::from_update(&title).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_ends_with_worker() {
+        let fake = FakeTdlib::new().authorized();
+        let (worker, client) = start(&fake).await;
+        let handled = Arc::new(State::default());
+        let run = tokio::spawn(
+            Dispatcher::new(client, handled.clone())
+                .on(
+                    |ctx: Context<FakeTdlib, Arc<State>>, update: UpdateChatTitle| async move {
+                        ctx.state()
+                            .titles
+                            .lock()
+                            .unwrap()
+                            .push(update.title().clone());
+                        Ok(())
+                    },
+                )
+                .run(),
+        );
+        // the dispatcher may not be subscribed yet
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while handled.titles.lock().unwrap().is_empty() {
+                fake.push_update(1, &title_update(1, "title"));
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        worker.stop();
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
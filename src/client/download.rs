@@ -0,0 +1,566 @@
+//! Downloads of files awaiting their completion, see [DownloadManager].
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use futures::Stream;
+use tokio::sync::{oneshot, watch};
+
+use super::tdlib_client::TdLibClient;
+use super::updates::{UpdateFilter, UpdateReceiver};
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{CancelDownloadFile, DownloadFile, File, Update};
+
+// Latest known state of every file being downloaded, by file identifier.
+type Files = Mutex<HashMap<i32, watch::Sender<File>>>;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Progress of a download, built from the latest `updateFile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Size of the downloaded part of the file, in bytes.
+    pub downloaded_size: i32,
+    /// Size of the file in bytes if known, its approximate size otherwise; 0 if unknown.
+    pub expected_size: i32,
+    pub is_active: bool,
+    pub is_completed: bool,
+}
+
+impl DownloadProgress {
+    pub fn from_file(file: &File) -> Self {
+        Self {
+            downloaded_size: file.local().downloaded_size(),
+            expected_size: match file.size() {
+                0 => file.expected_size(),
+                size => size,
+            },
+            is_active: file.local().is_downloading_active(),
+            is_completed: file.local().is_downloading_completed(),
+        }
+    }
+
+    // Download can't progress any more.
+    fn is_finished(&self) -> bool {
+        self.is_completed || !self.is_active
+    }
+}
+
+struct Waiter {
+    priority: i32,
+    // order of arrival, earlier waiters go first among ones with equal priority
+    seq: u64,
+    sender: oneshot::Sender<Slot>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+#[derive(Default)]
+struct SlotsState {
+    active: usize,
+    next_seq: u64,
+    waiting: BinaryHeap<Waiter>,
+}
+
+// Limits number of concurrent downloads, waiting downloads get slots in order of their priority.
+struct Slots {
+    max: usize,
+    state: Mutex<SlotsState>,
+}
+
+impl Slots {
+    fn new(max: usize) -> Arc<Self> {
+        Arc::new(Self {
+            max: max.max(1),
+            state: Mutex::new(SlotsState::default()),
+        })
+    }
+
+    async fn acquire(self: &Arc<Self>, priority: i32) -> Slot {
+        loop {
+            let receiver = {
+                let mut state = lock(&self.state);
+                if state.active < self.max {
+                    state.active += 1;
+                    return Slot(self.clone());
+                }
+                let (sender, receiver) = oneshot::channel();
+                let seq = state.next_seq;
+                state.next_seq += 1;
+                state.waiting.push(Waiter {
+                    priority,
+                    seq,
+                    sender,
+                });
+                receiver
+            };
+            // a slot is dropped together with the channel if the waiter is gone, so it's never lost
+            if let Ok(slot) = receiver.await {
+                return slot;
+            }
+        }
+    }
+
+    fn release(self: &Arc<Self>) {
+        let waiter = {
+            let mut state = lock(&self.state);
+            match state.waiting.pop() {
+                Some(waiter) => waiter,
+                None => {
+                    state.active -= 1;
+                    return;
+                }
+            }
+        };
+        // if the waiter stopped waiting, the returned slot is dropped and passed to the next one
+        let _ = waiter.sender.send(Slot(self.clone()));
+    }
+}
+
+impl std::fmt::Debug for Slots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = lock(&self.state);
+        f.debug_struct("Slots")
+            .field("max", &self.max)
+            .field("active", &state.active)
+            .field("waiting", &state.waiting.len())
+            .finish()
+    }
+}
+
+// Permission to run a download, released on drop.
+struct Slot(Arc<Slots>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.release()
+    }
+}
+
+impl std::fmt::Debug for Slot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Slot")
+    }
+}
+
+/// Downloads files with at most `max_concurrent` downloads at a time, tracking their progress with `updateFile`.
+/// ```ignore
+/// let downloads = DownloadManager::new(client.clone(), 4);
+/// let download = downloads.download(file_id, 16).await?;
+/// let mut progress = download.progress();
+/// tokio::spawn(async move {
+///     while let Some(p) = progress.next().await {
+///         println!("{}/{}", p.downloaded_size, p.expected_size);
+///     }
+/// });
+/// let file = download.save_to("photo.jpg").await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DownloadManager<R>
+where
+    R: TdLibClient + Clone,
+{
+    client: Client<R>,
+    slots: Arc<Slots>,
+    files: Arc<Files>,
+}
+
+impl<R> DownloadManager<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    /// Creates the manager and spawns a task tracking `updateFile` updates, so it must be called within Tokio runtime.
+    pub fn new(client: Client<R>, max_concurrent: usize) -> Self {
+        let files = Arc::new(Files::default());
+        let updates = client.subscribe(UpdateFilter::all().kind("updateFile"));
+        tokio::spawn(track_files(Arc::downgrade(&files), updates));
+        Self {
+            client,
+            slots: Slots::new(max_concurrent),
+            files,
+        }
+    }
+
+    /// Starts download of the file when a slot is available, slots are given to downloads with higher `priority` first.
+    /// `priority` (1-32) is passed to TDLib too.
+    pub async fn download(&self, file_id: i32, priority: i32) -> Result<Download<R>> {
+        let slot = self.slots.acquire(priority).await;
+        // subscribe before the request, so no update is missed
+        let receiver = lock(&self.files)
+            .entry(file_id)
+            .or_insert_with(|| watch::channel(File::default()).0)
+            .subscribe();
+        let downloaded = self
+            .client
+            .download_file(
+                DownloadFile::builder()
+                    .file_id(file_id)
+                    .priority(priority)
+                    .build(),
+            )
+            .await;
+        let file = match downloaded {
+            Ok(file) => file,
+            Err(e) => {
+                drop(receiver);
+                let mut files = lock(&self.files);
+                if files.get(&file_id).is_some_and(|s| s.receiver_count() == 0) {
+                    files.remove(&file_id);
+                }
+                return Err(e);
+            }
+        };
+        if let Some(sender) = lock(&self.files).get(&file_id) {
+            // `updateFile` received while the request was running is newer than the response
+            sender.send_if_modified(|current| match is_newer(&file, current) {
+                true => {
+                    *current = file;
+                    true
+                }
+                false => false,
+            });
+        }
+        Ok(Download {
+            file_id,
+            client: self.client.clone(),
+            files: self.files.clone(),
+            receiver,
+            _slot: slot,
+            finished: false,
+        })
+    }
+}
+
+// Whether `file` is a later state of the download than `current`.
+fn is_newer(file: &File, current: &File) -> bool {
+    if current.id() == 0 {
+        return true;
+    }
+    let (file, current) = (file.local(), current.local());
+    !current.is_downloading_completed()
+        && (file.is_downloading_completed() || file.downloaded_size() > current.downloaded_size())
+}
+
+async fn track_files(files: Weak<Files>, mut updates: UpdateReceiver) {
+    while let Some(update) = updates.recv().await {
+        let files = match files.upgrade() {
+            Some(files) => files,
+            None => return,
+        };
+        if let Update::File(update) = update.as_ref() {
+            let mut files = lock(&files);
+            let file_id = update.file().id();
+            if let Some(sender) = files.get(&file_id) {
+                // nobody waits for the file anymore
+                if sender.send(update.file().clone()).is_err() {
+                    files.remove(&file_id);
+                }
+            }
+        }
+    }
+}
+
+/// Handle of a started download, returned by [DownloadManager::download].
+/// Download is cancelled with `cancelDownloadFile` when the handle is dropped before its completion,
+/// unless there are other handles or progress streams of the same file.
+#[derive(Debug)]
+pub struct Download<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    file_id: i32,
+    client: Client<R>,
+    files: Arc<Files>,
+    receiver: watch::Receiver<File>,
+    _slot: Slot,
+    finished: bool,
+}
+
+impl<R> Download<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    /// Latest known state of the file.
+    pub fn file(&self) -> File {
+        self.receiver.borrow().clone()
+    }
+
+    /// Stream of progress changes, starting with the current one. It ends when the download completes or stops.
+    pub fn progress(&self) -> impl Stream<Item = DownloadProgress> + Send + 'static {
+        let receiver = self.receiver.clone();
+        futures::stream::unfold(
+            (receiver, None::<DownloadProgress>),
+            |(mut receiver, last)| async move {
+                match last {
+                    Some(last) if last.is_finished() => return None,
+                    Some(_) => receiver.changed().await.ok()?,
+                    None => {}
+                }
+                let progress = DownloadProgress::from_file(&receiver.borrow_and_update());
+                Some((progress, (receiver, Some(progress))))
+            },
+        )
+    }
+
+    /// Waits until the download completes. Fails if the download was stopped, e.g. cancelled.
+    pub async fn wait(mut self) -> Result<File> {
+        loop {
+            let file = self.receiver.borrow_and_update().clone();
+            let progress = DownloadProgress::from_file(&file);
+            if progress.is_completed {
+                self.finished = true;
+                return Ok(file);
+            }
+            if !progress.is_active {
+                self.finished = true;
+                return Err(Error::Internal("file download was stopped"));
+            }
+            if self.receiver.changed().await.is_err() {
+                return Err(Error::Internal("file download is not tracked anymore"));
+            }
+        }
+    }
+
+    /// Waits until the download completes, then copies the file to `path`.
+    pub async fn save_to<P: AsRef<Path>>(self, path: P) -> Result<File> {
+        let file = self.wait().await?;
+        let from = file.local().path().clone();
+        let to = path.as_ref().to_path_buf();
+        match tokio::task::spawn_blocking(move || std::fs::copy(from, to)).await {
+            Ok(copied) => copied?,
+            Err(_) => return Err(Error::Internal("copying of downloaded file failed")),
+        };
+        Ok(file)
+    }
+}
+
+impl<R> Drop for Download<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if self.finished || self.receiver.borrow().local().is_downloading_completed() {
+            return;
+        }
+        let is_last = match lock(&self.files).get(&self.file_id) {
+            Some(sender) => sender.receiver_count() <= 1,
+            None => true,
+        };
+        if !is_last {
+            return;
+        }
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => runtime,
+            Err(_) => {
+                log::warn!(
+                    "download of file {} can't be cancelled outside of runtime",
+                    self.file_id
+                );
+                return;
+            }
+        };
+        let client = self.client.clone();
+        let file_id = self.file_id;
+        runtime.spawn(async move {
+            let cancel = CancelDownloadFile::builder()
+                .file_id(file_id)
+                .only_if_pending(false)
+                .build();
+            if let Err(e) = client.cancel_download_file(cancel).await {
+                log::warn!("can't cancel download of file {}: {}", file_id, e);
+            }
+        });
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::{lock, DownloadManager, DownloadProgress, Slots};
+    use crate::client::fake_tdlib::{fixtures::start, FakeTdlib};
+    use crate::types::{File, LocalFile, Update, UpdateFile};
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    const FILE_ID: i32 = 7;
+
+    #[tokio::test]
+    async fn test_slots() {
+        let slots = Slots::new(1);
+        let first = slots.acquire(1).await;
+
+        let mut waiting = Vec::new();
+        for priority in [1, 5, 3, 5] {
+            let slots = slots.clone();
+            waiting.push(tokio::spawn(async move {
+                let slot = slots.acquire(priority).await;
+                (priority, slot)
+            }));
+            tokio::task::yield_now().await;
+        }
+        // gives up waiting, its slot must not be lost
+        waiting.remove(0).abort();
+        tokio::task::yield_now().await;
+        assert_eq!(slots.state.lock().unwrap().waiting.len(), 4);
+
+        drop(first);
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            let mut granted = None;
+            while granted.is_none() {
+                tokio::task::yield_now().await;
+                if let Some(i) = waiting.iter().position(|w| w.is_finished()) {
+                    granted = Some(waiting.remove(i).await.unwrap());
+                }
+            }
+            let (priority, slot) = granted.unwrap();
+            assert_eq!(slots.state.lock().unwrap().active, 1);
+            order.push(priority);
+            drop(slot);
+        }
+        assert_eq!(order, vec![5, 5, 3]);
+        assert_eq!(slots.state.lock().unwrap().active, 0);
+        assert!(slots.state.lock().unwrap().waiting.is_empty());
+    }
+
+    fn file(downloaded_size: i32, is_active: bool) -> File {
+        File::builder()
+            .id(FILE_ID)
+            .size(100)
+            .local(
+                LocalFile::builder()
+                    .path("/tmp/file")
+                    .downloaded_size(downloaded_size)
+                    .is_downloading_active(is_active)
+                    .is_downloading_completed(downloaded_size == 100)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn update_file(file: File) -> Update {
+        Update::File(UpdateFile::builder().file(file).build())
+    }
+
+    #[tokio::test]
+    async fn test_download_completion() {
+        let fake = FakeTdlib::new().authorized();
+        let pusher = fake.clone();
+        // the completed file arrives before the response to `downloadFile`
+        fake.respond_with_fn("downloadFile", move |_| {
+            pusher.push_update(1, &update_file(file(100, false)));
+            serde_json::to_value(file(0, true)).unwrap()
+        });
+        let (worker, client) = start(&fake).await;
+        let downloads = DownloadManager::new(client, 1);
+
+        let download = downloads.download(FILE_ID, 1).await.unwrap();
+        let file = timeout(Duration::from_secs(5), download.wait())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(file.local().downloaded_size(), 100);
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_download_progress() {
+        let fake = FakeTdlib::new().authorized();
+        fake.respond_with("downloadFile", file(0, true));
+        let (worker, client) = start(&fake).await;
+        let downloads = DownloadManager::new(client, 1);
+
+        let download = downloads.download(FILE_ID, 1).await.unwrap();
+        let mut progress = Box::pin(download.progress());
+        let next = Duration::from_secs(5);
+        let first = timeout(next, progress.next()).await.unwrap().unwrap();
+        assert_eq!(first.downloaded_size, 0);
+        fake.push_update(1, &update_file(file(50, true)));
+        let second = timeout(next, progress.next()).await.unwrap().unwrap();
+        assert_eq!(second.downloaded_size, 50);
+        fake.push_update(1, &update_file(file(100, false)));
+        assert_eq!(
+            timeout(next, progress.next()).await.unwrap().unwrap(),
+            DownloadProgress {
+                downloaded_size: 100,
+                expected_size: 100,
+                is_active: false,
+                is_completed: true,
+            }
+        );
+        assert_eq!(timeout(next, progress.next()).await.unwrap(), None);
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_drop() {
+        let fake = FakeTdlib::new().authorized();
+        fake.respond_with("downloadFile", file(0, true));
+        fake.respond_with("cancelDownloadFile", serde_json::json!({"@type": "ok"}));
+        let (worker, client) = start(&fake).await;
+        let downloads = DownloadManager::new(client, 1);
+
+        drop(downloads.download(FILE_ID, 1).await.unwrap());
+        timeout(Duration::from_secs(5), async {
+            while !fake
+                .sent_requests()
+                .iter()
+                .any(|r| r["@type"] == "cancelDownloadFile" && r["file_id"] == FILE_ID)
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        // the slot is released with the handle
+        let download = timeout(Duration::from_secs(5), downloads.download(FILE_ID, 1))
+            .await
+            .unwrap()
+            .unwrap();
+        drop(download);
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_download_error() {
+        let fake = FakeTdlib::new().authorized();
+        fake.respond_with_error("downloadFile", 400, "FILE_ID_INVALID");
+        let (worker, client) = start(&fake).await;
+        let downloads = DownloadManager::new(client, 1);
+
+        assert!(downloads.download(FILE_ID, 1).await.is_err());
+        assert!(lock(&downloads.files).is_empty());
+        worker.stop();
+    }
+}
@@ -0,0 +1,531 @@
+//! In-process [TdLibClient](crate::client::tdlib_client::TdLibClient) implementation for tests.
+//! It requires neither `libtdjson` nor network: authorization flow is simulated and responses are scripted.
+//! ```ignore
+//! let fake = FakeTdlib::new();
+//! fake.respond_with("getChats", Chats::builder().chat_ids(vec![1, 2]).build());
+//! let mut worker = WorkerBuilder::new(fake.clone())
+//!     .with_auth_state_handler(AuthStateHandlerProxy::default())
+//!     .build()?;
+//! worker.start();
+//! let client = worker.bind_client(ClientBuilder::new(fake.clone())./* ... */.build()?).await?;
+//! ```
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::tdlib_client::{ClientId, TdLibClient};
+use crate::errors::Result;
+use crate::types::{RFunction, Update};
+
+/// Default code accepted by `checkAuthenticationCode`.
+pub const DEFAULT_AUTH_CODE: &str = "12345";
+
+type Responder = Arc<dyn Fn(&Value) -> Value + Send + Sync>;
+
+/// Authorization state of a fake client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FakeAuthState {
+    WaitTdlibParameters,
+    WaitPhoneNumber,
+    WaitCode,
+    Ready,
+    Closed,
+}
+
+impl FakeAuthState {
+    fn to_json(self, phone_number: &str) -> Value {
+        match self {
+            FakeAuthState::WaitTdlibParameters => {
+                json!({"@type": "authorizationStateWaitTdlibParameters"})
+            }
+            FakeAuthState::WaitPhoneNumber => json!({"@type": "authorizationStateWaitPhoneNumber"}),
+            FakeAuthState::WaitCode => json!({
+                "@type": "authorizationStateWaitCode",
+                "code_info": {
+                    "@type": "authenticationCodeInfo",
+                    "phone_number": phone_number,
+                    "type": {"@type": "authenticationCodeTypeSms", "length": 5},
+                    "timeout": 0,
+                },
+            }),
+            FakeAuthState::Ready => json!({"@type": "authorizationStateReady"}),
+            FakeAuthState::Closed => json!({"@type": "authorizationStateClosed"}),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FakeClient {
+    auth_state: FakeAuthState,
+    phone_number: String,
+}
+
+struct State {
+    last_client_id: ClientId,
+    clients: HashMap<ClientId, FakeClient>,
+    responders: HashMap<String, Responder>,
+    queue: VecDeque<Value>,
+    sent: Vec<Value>,
+    auth_code: String,
+    initial_auth_state: FakeAuthState,
+}
+
+/// Scriptable fake TDLib. Clones share the same state, so the same instance has to be passed
+/// to [WorkerBuilder](crate::client::WorkerBuilder) and [ClientBuilder](crate::client::ClientBuilder).
+///
+/// Out of the box it handles authorization requests, walking each client through
+/// `WaitTdlibParameters` → `WaitPhoneNumber` → `WaitCode` → `Ready`, plus `getAuthorizationState`,
+/// `getApplicationConfig` and `close`.
+/// Any other request is answered with a response registered by `@type` with [respond_with](FakeTdlib::respond_with)
+/// or [respond_with_fn](FakeTdlib::respond_with_fn), or with a `404` error if nothing is registered.
+/// `@extra` and `@client_id` of requests are copied into responses.
+#[derive(Clone)]
+pub struct FakeTdlib {
+    inner: Arc<(Mutex<State>, Condvar)>,
+}
+
+impl fmt::Debug for FakeTdlib {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("FakeTdlib")
+            .field("clients", &state.clients)
+            .field("queued", &state.queue.len())
+            .finish()
+    }
+}
+
+impl Default for FakeTdlib {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeTdlib {
+    pub fn new() -> Self {
+        let state = State {
+            last_client_id: 0,
+            clients: HashMap::new(),
+            responders: HashMap::new(),
+            queue: VecDeque::new(),
+            sent: Vec::new(),
+            auth_code: DEFAULT_AUTH_CODE.to_string(),
+            initial_auth_state: FakeAuthState::WaitTdlibParameters,
+        };
+        Self {
+            inner: Arc::new((Mutex::new(state), Condvar::new())),
+        }
+    }
+
+    /// Code which `checkAuthenticationCode` accepts; any other code is rejected with `PHONE_CODE_INVALID` error.
+    pub fn with_auth_code<S: Into<String>>(self, code: S) -> Self {
+        self.state().auth_code = code.into();
+        self
+    }
+
+    /// New clients start in `Ready` state, so authorization flow is skipped.
+    pub fn authorized(self) -> Self {
+        self.state().initial_auth_state = FakeAuthState::Ready;
+        self
+    }
+
+    /// Answers every request with specified `@type` (for example `getMe`) with the same response.
+    pub fn respond_with<T: Serialize>(&self, method: &str, response: T) {
+        let response = serde_json::to_value(response).expect("response must be serializable");
+        self.respond_with_fn(method, move |_| response.clone());
+    }
+
+    /// Answers every request with specified `@type` with a response generated from the request.
+    pub fn respond_with_fn<F>(&self, method: &str, responder: F)
+    where
+        F: Fn(&Value) -> Value + Send + Sync + 'static,
+    {
+        self.state()
+            .responders
+            .insert(method.to_string(), Arc::new(responder));
+    }
+
+    /// Answers every request with specified `@type` with TDLib error.
+    pub fn respond_with_error<S: Into<String>>(&self, method: &str, code: i32, message: S) {
+        let message = message.into();
+        self.respond_with_fn(method, move |_| error(code, &message));
+    }
+
+    /// Sends an update to specified client.
+    pub fn push_update(&self, client_id: ClientId, update: &Update) {
+        let mut update = serde_json::to_value(update).expect("update must be serializable");
+        // builders fill `@extra`, but updates with it would be treated as responses
+        if let Some(update) = update.as_object_mut() {
+            update.remove("@extra");
+        }
+        self.push_raw(client_id, update);
+    }
+
+    /// Sends arbitrary JSON object to specified client.
+    pub fn push_raw(&self, client_id: ClientId, mut data: Value) {
+        if let Some(data) = data.as_object_mut() {
+            data.insert("@client_id".to_string(), client_id.into());
+        }
+        self.enqueue(&mut self.state(), data);
+    }
+
+    /// All requests sent with [send](TdLibClient::send) so far, in order.
+    pub fn sent_requests(&self) -> Vec<Value> {
+        self.state().sent.clone()
+    }
+
+    /// Current authorization state of the client.
+    pub fn auth_state(&self, client_id: ClientId) -> Option<FakeAuthState> {
+        self.state()
+            .clients
+            .get(&client_id)
+            .map(|client| client.auth_state)
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.inner.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn enqueue(&self, state: &mut State, data: Value) {
+        state.queue.push_back(data);
+        self.inner.1.notify_one();
+    }
+
+    fn set_auth_state(&self, state: &mut State, client_id: ClientId, auth_state: FakeAuthState) {
+        let phone_number = match state.clients.get_mut(&client_id) {
+            None => return,
+            Some(client) => {
+                client.auth_state = auth_state;
+                client.phone_number.clone()
+            }
+        };
+        let update = json!({
+            "@type": "updateAuthorizationState",
+            "@client_id": client_id,
+            "authorization_state": auth_state.to_json(&phone_number),
+        });
+        self.enqueue(state, update);
+    }
+
+    // Handles built-in requests. Returns response and authorization state to switch to after it.
+    fn handle_builtin(
+        &self,
+        state: &mut State,
+        client_id: ClientId,
+        request: &Value,
+    ) -> Option<(Value, Option<FakeAuthState>)> {
+        let client = state.clients.get_mut(&client_id)?;
+        let current = client.auth_state;
+        let unexpected = || error(400, "Unexpected authorization request");
+        let result = match td_type(request) {
+            "getAuthorizationState" => (current.to_json(&client.phone_number), None),
+            "getApplicationConfig" => (json!({"@type": "jsonValueObject", "members": []}), None),
+            "setTdlibParameters" if current == FakeAuthState::WaitTdlibParameters => {
+                (ok(), Some(FakeAuthState::WaitPhoneNumber))
+            }
+            "setAuthenticationPhoneNumber" if current == FakeAuthState::WaitPhoneNumber => {
+                client.phone_number = request["phone_number"].as_str().unwrap_or("").to_string();
+                (ok(), Some(FakeAuthState::WaitCode))
+            }
+            "checkAuthenticationBotToken" if current == FakeAuthState::WaitPhoneNumber => {
+                (ok(), Some(FakeAuthState::Ready))
+            }
+            "checkAuthenticationCode" if current == FakeAuthState::WaitCode => {
+                if request["code"].as_str() == Some(state.auth_code.as_str()) {
+                    (ok(), Some(FakeAuthState::Ready))
+                } else {
+                    (error(400, "PHONE_CODE_INVALID"), None)
+                }
+            }
+            "setTdlibParameters"
+            | "setAuthenticationPhoneNumber"
+            | "checkAuthenticationBotToken"
+            | "checkAuthenticationCode" => (unexpected(), None),
+            "close" => (ok(), Some(FakeAuthState::Closed)),
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn respond(&self, client_id: Option<ClientId>, request: Value) -> Value {
+        let mut state = self.state();
+        let builtin = match client_id {
+            Some(client_id) if !state.responders.contains_key(td_type(&request)) => {
+                self.handle_builtin(&mut state, client_id, &request)
+            }
+            _ => None,
+        };
+        let (mut response, next_state) = match builtin {
+            Some(result) => result,
+            None => match state.responders.get(td_type(&request)).cloned() {
+                // don't hold the lock while user-defined responder works
+                Some(responder) => {
+                    drop(state);
+                    let response = responder(&request);
+                    state = self.state();
+                    (response, None)
+                }
+                None => (error(404, "Not Found"), None),
+            },
+        };
+        if let Some(response) = response.as_object_mut() {
+            if let Some(extra) = request.get("@extra") {
+                response.insert("@extra".to_string(), extra.clone());
+            }
+            if let Some(client_id) = client_id {
+                response.insert("@client_id".to_string(), client_id.into());
+            }
+        }
+        if let (Some(client_id), Some(next_state)) = (client_id, next_state) {
+            self.enqueue(&mut state, response.clone());
+            self.set_auth_state(&mut state, client_id, next_state);
+            return Value::Null;
+        }
+        response
+    }
+}
+
+impl TdLibClient for FakeTdlib {
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> Result<()> {
+        let request = serde_json::to_value(&fnc)?;
+        self.state().sent.push(request.clone());
+        let response = self.respond(Some(client_id), request);
+        if !response.is_null() {
+            self.enqueue(&mut self.state(), response);
+        }
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let (state, _) = self
+            .inner
+            .1
+            .wait_timeout_while(self.state(), Duration::from_secs_f64(timeout), |state| {
+                state.queue.is_empty()
+            })
+            .unwrap_or_else(|e| e.into_inner());
+        let mut state = state;
+        state.queue.pop_front().map(|data| data.to_string())
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>> {
+        let request = serde_json::to_value(&fnc)?;
+        Ok(Some(self.respond(None, request).to_string()))
+    }
+
+    fn new_client(&self) -> ClientId {
+        let mut state = self.state();
+        state.last_client_id += 1;
+        let client_id = state.last_client_id;
+        let initial_auth_state = state.initial_auth_state;
+        state.clients.insert(
+            client_id,
+            FakeClient {
+                auth_state: initial_auth_state,
+                phone_number: String::new(),
+            },
+        );
+        self.set_auth_state(&mut state, client_id, initial_auth_state);
+        client_id
+    }
+}
+
+fn td_type(request: &Value) -> &str {
+    request["@type"].as_str().unwrap_or_default()
+}
+
+fn ok() -> Value {
+    json!({"@type": "ok"})
+}
+
+fn error(code: i32, message: &str) -> Value {
+    json!({"@type": "error", "code": code, "message": message})
+}
+
+/// Setup shared by tests running a client against [FakeTdlib].
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::FakeTdlib;
+    use crate::client::{AuthStateHandlerProxy, Client, ClientBuilder, Worker, WorkerBuilder};
+    use crate::types::TdlibParameters;
+    use serde_json::json;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    /// Scripts responses to `getMe` (user 42) and `searchPublicChats` (one chat,
+    /// with identifier equal to the length of the query).
+    pub(crate) fn with_responses(fake: FakeTdlib) -> FakeTdlib {
+        fake.respond_with(
+            "getMe",
+            json!({
+                "@type": "user",
+                "id": 42,
+                "status": {"@type": "userStatusEmpty"},
+                "type": {"@type": "userTypeRegular"},
+            }),
+        );
+        fake.respond_with_fn("searchPublicChats", |request| {
+            let query = request["query"].as_str().unwrap_or_default();
+            json!({"@type": "chats", "total_count": 1, "chat_ids": [query.len()]})
+        });
+        fake
+    }
+
+    /// Starts a worker and binds a client to the [authorized](FakeTdlib::authorized) fake.
+    /// The worker must be kept alive while the client is used.
+    pub(crate) async fn start(
+        fake: &FakeTdlib,
+    ) -> (Worker<AuthStateHandlerProxy, FakeTdlib>, Client<FakeTdlib>) {
+        let mut worker = WorkerBuilder::new(fake.clone())
+            .with_auth_state_handler(AuthStateHandlerProxy::default())
+            .with_read_updates_timeout(0.01)
+            .build()
+            .unwrap();
+        worker.start();
+        let client = ClientBuilder::new(fake.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        let client = timeout(Duration::from_secs(5), worker.bind_client(client))
+            .await
+            .unwrap()
+            .unwrap();
+        timeout(Duration::from_secs(5), worker.wait_client_state(&client))
+            .await
+            .unwrap()
+            .unwrap();
+        (worker, client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::with_responses;
+    use super::{FakeAuthState, FakeTdlib, DEFAULT_AUTH_CODE};
+    use crate::client::auth_handler::ClientAuthStateHandler;
+    use crate::client::tdlib_client::TdLibClient;
+    use crate::client::{
+        AuthStateHandlerProxy, ClientBuilder, ClientIdentifier, ClientState, WorkerBuilder,
+    };
+    use crate::errors::Error;
+    use crate::types::*;
+    use async_trait::async_trait;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[derive(Debug, Clone)]
+    struct TestAuthHandler;
+
+    #[async_trait]
+    impl ClientAuthStateHandler for TestAuthHandler {
+        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> String {
+            DEFAULT_AUTH_CODE.to_string()
+        }
+
+        async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
+            unreachable!()
+        }
+
+        async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> String {
+            unreachable!()
+        }
+
+        async fn handle_wait_client_identifier(
+            &self,
+            _: &AuthorizationStateWaitPhoneNumber,
+        ) -> ClientIdentifier {
+            ClientIdentifier::PhoneNumber("+10000000000".to_string())
+        }
+
+        async fn handle_wait_registration(
+            &self,
+            _: &AuthorizationStateWaitRegistration,
+        ) -> (String, String) {
+            unreachable!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_flow_and_scripted_responses() {
+        let fake = with_responses(FakeTdlib::new());
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(10);
+        let mut worker = WorkerBuilder::new(fake.clone())
+            .with_auth_state_handler(AuthStateHandlerProxy::default())
+            .with_read_updates_timeout(0.01)
+            .build()
+            .unwrap();
+        worker.start();
+        let client = ClientBuilder::new(fake.clone())
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .with_client_auth_state_handler(TestAuthHandler)
+            .with_updates_sender(sender)
+            .build()
+            .unwrap();
+        let client = timeout(Duration::from_secs(5), worker.bind_client(client))
+            .await
+            .unwrap()
+            .unwrap();
+        let state = timeout(Duration::from_secs(5), worker.wait_client_state(&client))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state, ClientState::Opened);
+        assert_eq!(fake.auth_state(1), Some(FakeAuthState::Ready));
+
+        let me = client.get_me(GetMe::builder().build()).await.unwrap();
+        assert_eq!(me.id(), 42);
+        let chats = client
+            .search_public_chats(SearchPublicChats::builder().query("query").build())
+            .await
+            .unwrap();
+        assert_eq!(chats.chat_ids(), &vec![5]);
+        match client.get_contacts(GetContacts::builder().build()).await {
+            Err(Error::TDLibError(err)) => assert_eq!(err.code(), 404),
+            v => panic!("unexpected result: {:?}", v),
+        }
+
+        fake.push_update(
+            1,
+            &Update::ChatTitle(UpdateChatTitle::builder().chat_id(7).title("new").build()),
+        );
+        match timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .unwrap()
+        {
+            Some(update) => assert!(matches!(*update, Update::ChatTitle(_))),
+            None => panic!("updates channel closed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_auth_code() {
+        let fake = FakeTdlib::new().with_auth_code("54321");
+        let client_id = fake.new_client();
+        fake.send(
+            client_id,
+            SetTdlibParameters::builder()
+                .parameters(TdlibParameters::builder().build())
+                .build(),
+        )
+        .unwrap();
+        fake.send(client_id, SetAuthenticationPhoneNumber::builder().build())
+            .unwrap();
+        fake.send(
+            client_id,
+            CheckAuthenticationCode::builder()
+                .code(DEFAULT_AUTH_CODE)
+                .build(),
+        )
+        .unwrap();
+        assert_eq!(fake.auth_state(client_id), Some(FakeAuthState::WaitCode));
+        let mut received = Vec::new();
+        while let Some(data) = fake.receive(0.0) {
+            received.push(data);
+        }
+        assert!(received.last().unwrap().contains("PHONE_CODE_INVALID"));
+    }
+}
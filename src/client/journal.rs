@@ -0,0 +1,456 @@
+//! Recording of TDLib traffic into a JSONL journal and its deterministic replay.
+//!
+//! [RecordingTdLibClient] wraps any [TdLibClient] (usually [TdJson](crate::client::tdlib_client::TdJson))
+//! and writes every payload passing through it as one [JournalEntry] per line.
+//! [ReplayTdLibClient] reads such a journal and plays it back, so a captured session can be reproduced offline:
+//! ```ignore
+//! let recording = RecordingTdLibClient::create(TdJson::new(), "incident.jsonl")?;
+//! // ... pass `recording` to WorkerBuilder::new and ClientBuilder::new
+//!
+//! let replay = ReplayTdLibClient::open("incident.jsonl")?;
+//! // ... pass `replay` to WorkerBuilder::new and ClientBuilder::new, run the same handlers
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::tdlib_client::{ClientId, TdLibClient};
+use crate::errors::Result;
+use crate::types::RFunction;
+
+/// Kind of journal entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalEntryKind {
+    /// New TDLib client created.
+    NewClient,
+    /// Request sent with [TdLibClient::send].
+    Send,
+    /// Request executed synchronously with [TdLibClient::execute].
+    Execute,
+    /// Result of the preceding `Execute` entry.
+    ExecuteResult,
+    /// Data (response or update) received with [TdLibClient::receive].
+    Receive,
+}
+
+/// Single line of a journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Milliseconds since UNIX epoch.
+    pub timestamp: u64,
+    pub kind: JournalEntryKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl JournalEntry {
+    fn new(
+        kind: JournalEntryKind,
+        client_id: Option<ClientId>,
+        data: Option<serde_json::Value>,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        Self {
+            timestamp,
+            kind,
+            client_id,
+            data,
+        }
+    }
+
+    fn td_type(&self) -> Option<&str> {
+        self.data.as_ref()?.get("@type")?.as_str()
+    }
+
+    fn extra(&self) -> Option<&str> {
+        self.data.as_ref()?.get("@extra")?.as_str()
+    }
+}
+
+/// [TdLibClient] wrapper which writes all passing payloads into a journal.
+#[derive(Clone)]
+pub struct RecordingTdLibClient<C: TdLibClient + Clone> {
+    inner: C,
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl<C> fmt::Debug for RecordingTdLibClient<C>
+where
+    C: TdLibClient + Clone + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecordingTdLibClient")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> RecordingTdLibClient<C>
+where
+    C: TdLibClient + Clone,
+{
+    /// Journal lines will be written to `writer`.
+    pub fn new<W: Write + Send + 'static>(inner: C, writer: W) -> Self {
+        Self {
+            inner,
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Creates (or truncates) journal file at `path`.
+    pub fn create<P: AsRef<Path>>(inner: C, path: P) -> Result<Self> {
+        Ok(Self::new(inner, BufWriter::new(File::create(path)?)))
+    }
+
+    /// Returns wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn record(&self, entry: JournalEntry) {
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let result = serde_json::to_writer(&mut *writer, &entry)
+            .map_err(std::io::Error::from)
+            .and_then(|_| writer.write_all(b"\n"))
+            .and_then(|_| writer.flush());
+        if let Err(err) = result {
+            log::warn!("can't write journal entry: {}", err);
+        }
+    }
+}
+
+impl<C> TdLibClient for RecordingTdLibClient<C>
+where
+    C: TdLibClient + Clone,
+{
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> Result<()> {
+        // recorded before sending, so a response never precedes its request in the journal
+        self.record(JournalEntry::new(
+            JournalEntryKind::Send,
+            Some(client_id),
+            Some(serde_json::to_value(&fnc)?),
+        ));
+        self.inner.send(client_id, fnc)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let received = self.inner.receive(timeout)?;
+        match serde_json::from_str::<serde_json::Value>(&received) {
+            Ok(data) => {
+                let client_id = data
+                    .get("@client_id")
+                    .and_then(|v| v.as_i64())
+                    .map(|v| v as ClientId);
+                self.record(JournalEntry::new(
+                    JournalEntryKind::Receive,
+                    client_id,
+                    Some(data),
+                ))
+            }
+            Err(err) => log::warn!("can't record received data: {}", err),
+        }
+        Some(received)
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>> {
+        self.record(JournalEntry::new(
+            JournalEntryKind::Execute,
+            None,
+            Some(serde_json::to_value(&fnc)?),
+        ));
+        let result = self.inner.execute(fnc)?;
+        let data = match &result {
+            None => None,
+            Some(result) => Some(serde_json::from_str(result)?),
+        };
+        self.record(JournalEntry::new(
+            JournalEntryKind::ExecuteResult,
+            None,
+            data,
+        ));
+        Ok(result)
+    }
+
+    fn new_client(&self) -> ClientId {
+        let client_id = self.inner.new_client();
+        self.record(JournalEntry::new(
+            JournalEntryKind::NewClient,
+            Some(client_id),
+            None,
+        ));
+        client_id
+    }
+}
+
+struct ReplayState {
+    entries: Vec<JournalEntry>,
+    // index of the next entry to be returned by `receive`
+    next_receive: usize,
+    // index of the next entry to be returned by `new_client`
+    next_new_client: usize,
+    // indexes of recorded requests already matched with replayed ones
+    matched: Vec<bool>,
+    // recorded `@extra` -> `@extra` of replayed request
+    extras: HashMap<String, String>,
+}
+
+impl ReplayState {
+    // Finds the first not yet matched request of specified kind and type.
+    fn match_request(
+        &mut self,
+        kind: JournalEntryKind,
+        request: &serde_json::Value,
+    ) -> Option<usize> {
+        let td_type = request.get("@type").and_then(|t| t.as_str());
+        let index = (0..self.entries.len()).find(|&i| {
+            !self.matched[i] && self.entries[i].kind == kind && self.entries[i].td_type() == td_type
+        })?;
+        self.matched[index] = true;
+        if let (Some(recorded), Some(actual)) = (
+            self.entries[index].extra(),
+            request.get("@extra").and_then(|e| e.as_str()),
+        ) {
+            self.extras.insert(recorded.to_string(), actual.to_string());
+        }
+        Some(index)
+    }
+
+    // Returns next received data if it can be delivered: responses are held until their requests are replayed.
+    fn pop_received(&mut self) -> Option<serde_json::Value> {
+        while let Some(entry) = self.entries.get(self.next_receive) {
+            if entry.kind != JournalEntryKind::Receive {
+                self.next_receive += 1;
+                continue;
+            }
+            let mut data = entry.data.clone()?;
+            if let Some(extra) = entry.extra() {
+                let actual = self.extras.get(extra)?;
+                data["@extra"] = serde_json::Value::String(actual.clone());
+            }
+            self.next_receive += 1;
+            return Some(data);
+        }
+        None
+    }
+}
+
+/// [TdLibClient] which plays back a journal written by [RecordingTdLibClient].
+///
+/// Received data is returned in recorded order. Recorded responses are delivered only after
+/// the matching request (the first not yet replayed one with the same `@type`) is sent,
+/// and their `@extra` is rewritten to the one of the replayed request.
+/// Client identifiers are returned as recorded.
+#[derive(Clone)]
+pub struct ReplayTdLibClient {
+    inner: Arc<(Mutex<ReplayState>, Condvar)>,
+}
+
+impl fmt::Debug for ReplayTdLibClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("ReplayTdLibClient")
+            .field("entries", &state.entries.len())
+            .field("next_receive", &state.next_receive)
+            .finish()
+    }
+}
+
+impl ReplayTdLibClient {
+    pub fn new(entries: Vec<JournalEntry>) -> Self {
+        let matched = vec![false; entries.len()];
+        Self {
+            inner: Arc::new((
+                Mutex::new(ReplayState {
+                    entries,
+                    next_receive: 0,
+                    next_new_client: 0,
+                    matched,
+                    extras: HashMap::new(),
+                }),
+                Condvar::new(),
+            )),
+        }
+    }
+
+    /// Reads journal, one [JournalEntry] per line.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self> {
+        let mut entries = Vec::new();
+        for line in BufReader::new(reader).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                entries.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(Self::new(entries))
+    }
+
+    /// Reads journal file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    /// Determines that all recorded data was received.
+    pub fn is_finished(&self) -> bool {
+        let state = self.state();
+        !state.entries[state.next_receive..]
+            .iter()
+            .any(|entry| entry.kind == JournalEntryKind::Receive)
+    }
+
+    fn state(&self) -> MutexGuard<'_, ReplayState> {
+        self.inner.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl TdLibClient for ReplayTdLibClient {
+    fn send<Fnc: RFunction>(&self, _client_id: ClientId, fnc: Fnc) -> Result<()> {
+        let request = serde_json::to_value(&fnc)?;
+        if self
+            .state()
+            .match_request(JournalEntryKind::Send, &request)
+            .is_none()
+        {
+            log::warn!("request not found in journal: {}", request);
+        }
+        self.inner.1.notify_all();
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let mut state = self.state();
+        let deadline = Duration::from_secs_f64(timeout);
+        let started = std::time::Instant::now();
+        loop {
+            if let Some(data) = state.pop_received() {
+                return Some(data.to_string());
+            }
+            let left = deadline.checked_sub(started.elapsed())?;
+            state = self
+                .inner
+                .1
+                .wait_timeout(state, left)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>> {
+        let request = serde_json::to_value(&fnc)?;
+        let mut state = self.state();
+        let result = state
+            .match_request(JournalEntryKind::Execute, &request)
+            .and_then(|index| {
+                state.entries[index + 1..]
+                    .iter()
+                    .find(|entry| entry.kind == JournalEntryKind::ExecuteResult)
+            })
+            .and_then(|entry| entry.data.as_ref())
+            .map(|data| data.to_string());
+        Ok(result)
+    }
+
+    fn new_client(&self) -> ClientId {
+        let mut state = self.state();
+        let start = state.next_new_client;
+        match (start..state.entries.len())
+            .find(|&i| state.entries[i].kind == JournalEntryKind::NewClient)
+        {
+            Some(index) => {
+                state.next_new_client = index + 1;
+                state.entries[index].client_id.unwrap_or_default()
+            }
+            None => {
+                log::warn!("no more clients in journal");
+                0
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::{JournalEntryKind, RecordingTdLibClient, ReplayTdLibClient};
+    use crate::client::fake_tdlib::FakeTdlib;
+    use crate::client::tdlib_client::TdLibClient;
+    use crate::client::{AuthStateHandlerProxy, ClientBuilder, ClientState, WorkerBuilder};
+    use crate::types::{SearchPublicChats, TdlibParameters};
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    async fn search<T: TdLibClient + Send + Sync + Clone + 'static>(tdlib_client: T) -> Vec<i64> {
+        let mut worker = WorkerBuilder::new(tdlib_client.clone())
+            .with_auth_state_handler(AuthStateHandlerProxy::default())
+            .with_read_updates_timeout(0.01)
+            .build()
+            .unwrap();
+        worker.start();
+        let client = ClientBuilder::new(tdlib_client)
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .build()
+            .unwrap();
+        let client = timeout(Duration::from_secs(5), worker.bind_client(client))
+            .await
+            .unwrap()
+            .unwrap();
+        let state = timeout(Duration::from_secs(5), worker.wait_client_state(&client))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state, ClientState::Opened);
+        let chats = client
+            .search_public_chats(SearchPublicChats::builder().query("rust").build())
+            .await
+            .unwrap();
+        worker.stop();
+        chats.chat_ids().clone()
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let fake = FakeTdlib::new().authorized();
+        fake.respond_with_fn("searchPublicChats", |request| {
+            serde_json::json!({"@type": "chats", "total_count": 1, "chat_ids": [request["query"].as_str().unwrap().len()]})
+        });
+        let journal = SharedBuffer::default();
+        let recorded = search(RecordingTdLibClient::new(fake, journal.clone())).await;
+        assert_eq!(recorded, vec![4]);
+
+        let journal = journal.0.lock().unwrap().clone();
+        let replay = ReplayTdLibClient::from_reader(&journal[..]).unwrap();
+        {
+            let state = replay.state();
+            assert_eq!(state.entries[0].kind, JournalEntryKind::NewClient);
+            assert!(state
+                .entries
+                .iter()
+                .any(|e| e.td_type() == Some("searchPublicChats")));
+        }
+        assert_eq!(search(replay.clone()).await, recorded);
+        assert!(replay.is_finished());
+    }
+}
@@ -0,0 +1,270 @@
+//! Hooks around every request made by a [Client](crate::client::Client).
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::errors::{Error, Result};
+use crate::types::{RFunction, RObject};
+
+/// Request passing through middlewares: TDLib method name and its JSON representation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    payload: Value,
+}
+
+impl Request {
+    pub(crate) fn new<T: RFunction>(function: &T) -> Result<Self> {
+        Self::from_value(serde_json::to_value(function)?)
+    }
+
+    // `@extra` is generated unless it's already set, e.g. for functions parsed from JSON.
+    pub(crate) fn from_value(mut payload: Value) -> Result<Self> {
+        let object = payload
+            .as_object_mut()
+            .ok_or(Error::BadRequest("request must be a JSON object"))?;
+        if !object.get("@type").is_some_and(|t| t.is_string()) {
+            return Err(Error::BadRequest("request must have `@type` string field"));
+        }
+        if !object.get("@extra").is_some_and(|e| e.is_string()) {
+            object.insert(
+                "@extra".to_string(),
+                Value::String(uuid::Uuid::new_v4().to_string()),
+            );
+        }
+        Ok(Self { payload })
+    }
+
+    /// TDLib method name (its `@type`), e.g. `sendMessage`.
+    pub fn method(&self) -> &str {
+        self.payload
+            .get("@type")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default()
+    }
+
+    /// `@extra` of the request, used to match it with the response.
+    pub fn extra(&self) -> Option<&str> {
+        self.payload.get("@extra").and_then(|e| e.as_str())
+    }
+
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    /// Allows to rewrite the request. Keep `@extra` untouched, otherwise the response can't be received.
+    pub fn payload_mut(&mut self) -> &mut Value {
+        &mut self.payload
+    }
+
+    pub(crate) fn as_function(&self) -> RawFunction<'_> {
+        RawFunction(&self.payload)
+    }
+}
+
+// Sends already serialized request with a TdLibClient.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub(crate) struct RawFunction<'a>(&'a Value);
+
+impl RObject for RawFunction<'_> {
+    fn extra(&self) -> Option<&str> {
+        self.0.get("@extra").and_then(|e| e.as_str())
+    }
+
+    fn client_id(&self) -> Option<i32> {
+        self.0
+            .get("@client_id")
+            .and_then(|c| c.as_i64())
+            .map(|c| c as i32)
+    }
+}
+
+impl RFunction for RawFunction<'_> {
+    type Response = Value;
+}
+
+/// Decision made by [Middleware::on_response].
+#[derive(Debug)]
+pub enum Outcome {
+    /// Passes the response (possibly rewritten) to the previous middleware, or to the caller.
+    Done(Result<Value>),
+    /// Sends the request once again, through all middlewares registered after this one.
+    Retry(Request),
+}
+
+/// Middleware wraps every request made by a [Client](crate::client::Client), see [ClientBuilder::with_middleware](crate::client::ClientBuilder::with_middleware).
+///
+/// Middlewares run in order of registration: [on_request](Middleware::on_request) from the first to the last one,
+/// then the request is sent to TDLib, then [on_response](Middleware::on_response) from the last to the first one.
+/// Response is a raw TDLib object, TDLib errors are already converted into [Error::TDLibError](crate::errors::Error::TDLibError).
+/// ```ignore
+/// #[derive(Debug)]
+/// struct Logger;
+///
+/// #[async_trait]
+/// impl Middleware for Logger {
+///     async fn on_response(&self, request: &Request, response: Result<Value>) -> Outcome {
+///         if let Err(e) = &response {
+///             log::warn!("{} failed: {}", request.method(), e);
+///         }
+///         Outcome::Done(response)
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Debug + Send + Sync {
+    /// Called before the request is sent, may rewrite it.
+    /// Returned response is used instead of sending the request: it's passed to `on_response` of previous middlewares only.
+    async fn on_request(&self, _request: &mut Request) -> Option<Result<Value>> {
+        None
+    }
+
+    /// Called with the response of the request, as it was passed to next middlewares by `on_request`.
+    async fn on_response(&self, _request: &Request, response: Result<Value>) -> Outcome {
+        Outcome::Done(response)
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    async fn on_request(&self, request: &mut Request) -> Option<Result<Value>> {
+        (**self).on_request(request).await
+    }
+
+    async fn on_response(&self, request: &Request, response: Result<Value>) -> Outcome {
+        (**self).on_response(request, response).await
+    }
+}
+
+// Runs the request through middlewares, `send` is called for requests which were not short-circuited.
+pub(crate) async fn run<F, Fut>(
+    middlewares: &[Arc<dyn Middleware>],
+    mut request: Request,
+    send: F,
+) -> Result<Value>
+where
+    F: Fn(Request) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    // requests as they were passed to the next middleware, needed for `on_response`
+    let mut passed: Vec<Request> = Vec::with_capacity(middlewares.len());
+    let mut start = 0;
+    'send: loop {
+        passed.truncate(start);
+        let mut response = None;
+        for middleware in &middlewares[start..] {
+            if let Some(short_circuited) = middleware.on_request(&mut request).await {
+                response = Some(short_circuited);
+                break;
+            }
+            passed.push(request.clone());
+        }
+        let mut response = match response {
+            Some(response) => response,
+            None => send(request.clone()).await,
+        };
+        while let Some(passed_request) = passed.pop() {
+            let position = passed.len();
+            match middlewares[position]
+                .on_response(&passed_request, response)
+                .await
+            {
+                Outcome::Done(r) => response = r,
+                Outcome::Retry(retried) => {
+                    log::debug!("{} retried by middleware", retried.method());
+                    passed.push(passed_request);
+                    request = retried;
+                    start = position + 1;
+                    continue 'send;
+                }
+            }
+        }
+        return response;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Middleware, Outcome, Request};
+    use crate::errors::{Error, Result};
+    use crate::types::GetMe;
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, Default)]
+    struct RetryOnce {
+        retried: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Middleware for RetryOnce {
+        async fn on_response(&self, request: &Request, response: Result<Value>) -> Outcome {
+            match response {
+                Err(_) if self.retried.fetch_add(1, Ordering::SeqCst) == 0 => {
+                    Outcome::Retry(request.clone())
+                }
+                response => Outcome::Done(response),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct Rewrite;
+
+    #[async_trait]
+    impl Middleware for Rewrite {
+        async fn on_request(&self, request: &mut Request) -> Option<Result<Value>> {
+            request.payload_mut()["@type"] = json!("getMe");
+            None
+        }
+    }
+
+    #[derive(Debug)]
+    struct Cached;
+
+    #[async_trait]
+    impl Middleware for Cached {
+        async fn on_request(&self, request: &mut Request) -> Option<Result<Value>> {
+            if request.method() == "getOption" {
+                return Some(Ok(json!({"@type": "optionValueEmpty"})));
+            }
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run() {
+        let request = Request::new(&GetMe::builder().build()).unwrap();
+        let sent = AtomicUsize::new(0);
+        let send = |request: Request| {
+            let attempt = sent.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match attempt {
+                    0 => Err(Error::Internal("first attempt fails")),
+                    _ => Ok(json!({"@type": "user", "requested": request.method()})),
+                }
+            }
+        };
+
+        let retry_once = Arc::new(RetryOnce::default());
+        let middlewares: Vec<Arc<dyn Middleware>> =
+            vec![retry_once.clone(), Arc::new(Cached), Arc::new(Rewrite)];
+        let mut option_request = request.clone();
+        option_request.payload_mut()["@type"] = json!("getOption");
+        let response = run(&middlewares, option_request, send).await.unwrap();
+        assert_eq!(response["@type"], "optionValueEmpty");
+        assert_eq!(sent.load(Ordering::SeqCst), 0);
+
+        let mut chat_request = request;
+        chat_request.payload_mut()["@type"] = json!("getChat");
+        let response = run(&middlewares, chat_request, send).await.unwrap();
+        assert_eq!(response["requested"], "getMe");
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+        assert_eq!(retry_once.retried.load(Ordering::SeqCst), 1);
+    }
+}
@@ -13,12 +13,56 @@ pub mod auth_handler;
 #[doc(hidden)]
 pub mod tdlib_client;
 
+/// Recording and replay of TDLib traffic.
+pub mod journal;
+
+/// Automatic retries of failed requests.
+pub mod retry;
+
+/// Throttling of message-sending requests.
+pub mod rate_limit;
+
+/// Hooks around every request made by a client.
+pub mod middleware;
+
+/// Delivery of updates to multiple subscribers.
+pub mod updates;
+
+/// Routing of updates to typed handlers.
+pub mod dispatcher;
+
+/// In-memory cache of users, chats and groups.
+pub mod cache;
+/// Sorted chat lists maintained from updates.
+pub mod chat_list;
+/// Sending of messages awaiting their delivery.
+mod confirm;
+/// Downloads of files with progress tracking.
+pub mod download;
+/// Streams paging through history, search results and members.
+pub mod paging;
+
+/// Fake TDLib backend for tests.
+#[cfg(feature = "test-util")]
+pub mod fake_tdlib;
+
 pub use auth_handler::{
     AuthStateHandler, AuthStateHandlerProxy, ClientIdentifier, ConsoleAuthStateHandler,
     SignalAuthStateHandler,
 };
-use observer::OBSERVER;
+pub use cache::EntityCache;
+pub use chat_list::{ChatListId, ChatListState};
+pub use dispatcher::{Context, Dispatcher};
+pub use download::{Download, DownloadManager, DownloadProgress};
+pub use middleware::Middleware;
+use middleware::Request;
+use observer::Observer;
+pub use paging::PageBounds;
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use retry::{RetryEvent, RetryPolicy};
 use serde::de::DeserializeOwned;
+use updates::{Subscribers, DEFAULT_SUBSCRIPTION_CAPACITY};
+pub use updates::{UpdateFilter, UpdateReceiver};
 pub use worker::{Worker, WorkerBuilder};
 
 use crate::client::auth_handler::ClientAuthStateHandler;
@@ -33,7 +77,11 @@ use crate::{
     utils,
 };
 use async_trait::async_trait;
-use tdlib_client::{TdJson, TdLibClient};
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(feature = "tdjson")]
+use tdlib_client::TdJson;
+use tdlib_client::TdLibClient;
 use tokio::sync::mpsc;
 
 const CLIENT_NOT_AUTHORIZED: Error = Error::Internal("client not authorized yet");
@@ -183,6 +231,12 @@ where
     tdlib_parameters: TdlibParameters,
     auth_state_channel_size: Option<usize>,
     auth_handler: Box<dyn ClientAuthStateHandler>,
+    request_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    subscribers: Arc<Subscribers>,
+    observer: Option<Arc<Observer>>,
 }
 
 impl<S> Client<S>
@@ -227,9 +281,35 @@ where
         }
     }
 
+    fn set_observer(&mut self, observer: Arc<Observer>) {
+        self.observer = Some(observer);
+    }
+
     pub(crate) fn updates_sender(&self) -> &Option<mpsc::Sender<Box<Update>>> {
         &self.updates_sender
     }
+
+    pub(crate) fn subscribers(&self) -> &Subscribers {
+        &self.subscribers
+    }
+
+    /// Returns a receiver of updates matching the filter, independent of other subscribers and of
+    /// [updates sender](crate::client::ClientBuilder::with_updates_sender). Clones of the client share subscribers.
+    /// Receiver's channel has [DEFAULT_SUBSCRIPTION_CAPACITY](crate::client::updates::DEFAULT_SUBSCRIPTION_CAPACITY) size,
+    /// updates which don't fit into it are dropped and counted by [UpdateReceiver::lagged].
+    /// Receiver ends when the client is closed or its worker is stopped.
+    /// ```ignore
+    /// let mut messages = client.subscribe(UpdateFilter::all().kind("updateNewMessage"));
+    /// while let Some(update) = messages.recv().await { ... }
+    /// ```
+    pub fn subscribe(&self, filter: UpdateFilter) -> UpdateReceiver {
+        self.subscribe_with_capacity(filter, DEFAULT_SUBSCRIPTION_CAPACITY)
+    }
+
+    /// Same as [subscribe](Client::subscribe), with specified capacity of receiver's channel.
+    pub fn subscribe_with_capacity(&self, filter: UpdateFilter, capacity: usize) -> UpdateReceiver {
+        self.subscribers.subscribe(filter, capacity)
+    }
 }
 
 #[derive(Debug)]
@@ -243,16 +323,36 @@ where
     tdlib_client: R,
     auth_state_channel_size: Option<usize>,
     auth_handler: A,
+    request_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    middlewares: Vec<Arc<dyn Middleware>>,
 }
 
+#[cfg(feature = "tdjson")]
 impl Default for ClientBuilder<TdJson, ConsoleClientStateHandler> {
     fn default() -> Self {
+        Self::new(TdJson::new())
+    }
+}
+
+impl<R> ClientBuilder<R, ConsoleClientStateHandler>
+where
+    R: TdLibClient + Clone,
+{
+    /// Creates builder of a client which interacts with TDLib using specified [TdLibClient](crate::client::tdlib_client::TdLibClient),
+    /// for example [DynamicTdJson](crate::client::tdlib_client::DynamicTdJson).
+    pub fn new(tdlib_client: R) -> Self {
         Self {
             updates_sender: None,
             tdlib_parameters: None,
             auth_state_channel_size: None,
-            tdlib_client: TdJson::new(),
+            tdlib_client,
             auth_handler: ConsoleClientStateHandler,
+            request_timeout: None,
+            retry_policy: None,
+            rate_limiter: None,
+            middlewares: Vec::new(),
         }
     }
 }
@@ -283,6 +383,35 @@ where
         self
     }
 
+    /// Default timeout for every request made by the client.
+    /// If TDLib does not answer in time, request fails with [Error::Timeout](crate::errors::Error::Timeout).
+    /// Can be overridden per call with [Client::with_timeout](crate::client::Client::with_timeout).
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables automatic retries of failed requests, e.g. on `FLOOD_WAIT` or network errors.
+    /// Retries are not performed by default. See [RetryPolicy](crate::client::retry::RetryPolicy).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Enables client-side throttling of message-sending requests, see [RateLimiter](crate::client::rate_limit::RateLimiter).
+    /// Share a clone of the same limiter between clients to apply common limits to all of them.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Adds a middleware which wraps every request, see [Middleware](crate::client::middleware::Middleware).
+    /// Middlewares run in order of registration, all of them run inside retries made according to [with_retry_policy](ClientBuilder::with_retry_policy).
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
     /// Use it to bound specific auth handler with a client.
     pub fn with_client_auth_state_handler<NA: ClientAuthStateHandler + Clone>(
         self,
@@ -294,6 +423,10 @@ where
             updates_sender: self.updates_sender,
             tdlib_parameters: self.tdlib_parameters,
             auth_state_channel_size: self.auth_state_channel_size,
+            request_timeout: self.request_timeout,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+            middlewares: self.middlewares,
         }
     }
 
@@ -305,6 +438,10 @@ where
             tdlib_parameters: self.tdlib_parameters,
             auth_state_channel_size: self.auth_state_channel_size,
             auth_handler: self.auth_handler,
+            request_timeout: self.request_timeout,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+            middlewares: self.middlewares,
         }
     }
 
@@ -313,17 +450,22 @@ where
             return Err(Error::BadRequest("tdlib_parameters not set"));
         };
 
-        let client = Client::new(
+        let mut client = Client::new(
             self.tdlib_client,
             self.auth_handler,
             self.updates_sender,
             self.tdlib_parameters.unwrap(),
             self.auth_state_channel_size,
         );
+        client.request_timeout = self.request_timeout;
+        client.retry_policy = self.retry_policy;
+        client.rate_limiter = self.rate_limiter;
+        client.middlewares = self.middlewares;
         Ok(client)
     }
 }
 
+#[cfg(feature = "tdjson")]
 impl Client<TdJson> {
     pub fn builder() -> ClientBuilder<TdJson, ConsoleClientStateHandler> {
         ClientBuilder::default()
@@ -351,9 +493,26 @@ where
             auth_state_channel_size,
             is_started: false,
             client_id: None,
+            request_timeout: None,
+            retry_policy: None,
+            rate_limiter: None,
+            middlewares: Vec::new(),
+            subscribers: Arc::new(Subscribers::default()),
+            observer: None,
         }
     }
 
+    /// Returns a copy of the client which uses specified timeout for its requests instead of
+    /// the one set with [ClientBuilder::with_request_timeout](crate::client::ClientBuilder::with_request_timeout).
+    /// ```ignore
+    /// client.with_timeout(Duration::from_secs(5)).send_message(message).await?;
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        let mut client = self.clone();
+        client.request_timeout = Some(timeout);
+        client
+    }
+
     pub fn set_updates_sender(&mut self, updates_sender: mpsc::Sender<Box<Update>>) -> Result<()> {
         match self.is_started {
             true => Err(Error::BadRequest(
@@ -366,6 +525,38 @@ where
         }
     }
 
+    /// Sends any TDLib function and returns its [Response](RFunction::Response).
+    /// Allows to write code generic over functions, like wrappers adding logging or batching.
+    /// ```ignore
+    /// let me: User = client.call(GetMe::builder().build()).await?;
+    /// ```
+    pub async fn call<F: RFunction>(&self, function: F) -> Result<F::Response> {
+        let request = Request::new(&function)?;
+        log::trace!("sending request: {:?}", function);
+        let response = self.send_request(request).await?;
+        parse_response(response)
+    }
+
+    /// Sends a request built by hand, e.g. a method added in a newer TDLib, and returns its raw response.
+    /// The request must be a JSON object with `@type`; `@extra` is generated unless it's set.
+    /// The request passes through middlewares and retry policy, TDLib errors are returned as [Error::TDLibError].
+    /// ```ignore
+    /// let story = client
+    ///     .send_raw(json!({"@type": "getStory", "story_sender_chat_id": chat_id, "story_id": 1}))
+    ///     .await?;
+    /// ```
+    pub async fn send_raw(&self, request: serde_json::Value) -> Result<serde_json::Value> {
+        let request = Request::from_value(request)?;
+        log::trace!("sending raw request: {:?}", request);
+        self.send_request(request).await
+    }
+
+    /// Same as [send_raw](Client::send_raw), but deserializes the response into `T`.
+    pub async fn send_raw_as<T: DeserializeOwned>(&self, request: serde_json::Value) -> Result<T> {
+        let response = self.send_raw(request).await?;
+        parse_response(response)
+    }
+
     /// Just a shortcut for `crate::client::client::Client::close`, allows you to stop the client.
     pub async fn stop(&self) -> Result<Ok> {
         self.close(Close::builder().build()).await
@@ -376,19 +567,73 @@ where
         Ok(self.client_id.replace(client_id).unwrap_or_default())
     }
 
-    async fn make_request<T: RFunction, P: AsRef<T>, Q: DeserializeOwned>(
-        &self,
-        param: P,
-    ) -> Result<Q> {
-        let extra = param.as_ref().extra().ok_or(NO_EXTRA)?;
-        let signal = OBSERVER.subscribe(extra);
-        log::trace!("sending request: {:?}", param.as_ref());
+    async fn make_request<T: RFunction, P: AsRef<T>>(&self, param: P) -> Result<T::Response> {
+        self.call(param.as_ref()).await
+    }
+
+    // Sends the request through middlewares, repeating it according to the retry policy.
+    async fn send_request(&self, request: Request) -> Result<serde_json::Value> {
+        let retry_policy = match &self.retry_policy {
+            None => return self.run_middlewares(request).await,
+            Some(retry_policy) => retry_policy,
+        };
+        let mut attempt = 1;
+        loop {
+            let error = match self.run_middlewares(request.clone()).await {
+                Ok(v) => return Ok(v),
+                Err(e) => e,
+            };
+            match retry_policy.delay(request.method(), attempt, &error) {
+                None => return Err(error),
+                Some(delay) => {
+                    retry_policy.notify(&RetryEvent {
+                        method: request.method(),
+                        attempt,
+                        delay,
+                        error: &error,
+                    });
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn run_middlewares(&self, request: Request) -> Result<serde_json::Value> {
+        if self.middlewares.is_empty() {
+            return self.dispatch(request).await;
+        }
+        middleware::run(&self.middlewares, request, |request| self.dispatch(request)).await
+    }
+
+    // Sends the request to TDLib and waits for its response.
+    async fn dispatch(&self, request: Request) -> Result<serde_json::Value> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            let chat_id = request.payload().get("chat_id").and_then(|c| c.as_i64());
+            rate_limiter.acquire(request.method(), chat_id).await;
+        }
+        let extra = request.extra().ok_or(NO_EXTRA)?;
+        let signal = self
+            .observer
+            .as_ref()
+            .ok_or(CLIENT_NOT_AUTHORIZED)?
+            .subscribe(extra);
         self.tdlib_client.send(
             self.get_client_id().ok_or(CLIENT_NOT_AUTHORIZED)?,
-            param.as_ref(),
+            request.as_function(),
         )?;
-        let received = signal.await;
-        OBSERVER.unsubscribe(extra);
+        let received = match self.request_timeout {
+            None => signal.await,
+            Some(timeout) => match tokio::time::timeout(timeout, signal).await {
+                Ok(received) => received,
+                Err(_) => {
+                    return Err(Error::Timeout {
+                        method: request.method().to_string(),
+                        extra: extra.to_string(),
+                    });
+                }
+            },
+        };
         match received {
             Err(_) => Err(CLOSED_RECEIVER_ERROR),
             Ok(v) => {
@@ -401,19 +646,85 @@ where
                         }
                     }
                 } else {
-                    match serde_json::from_value::<Q>(v) {
-                        Ok(v) => Ok(v),
-                        Err(e) => {
-                            log::error!("response serialization error: {:?}", e);
-                            Err(INVALID_RESPONSE_ERROR)
-                        }
-                    }
+                    Ok(v)
                 }
             }
         }
     }
 }
 
+fn parse_response<Q: DeserializeOwned>(response: serde_json::Value) -> Result<Q> {
+    match serde_json::from_value::<Q>(response) {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            log::error!("response serialization error: {:?}", e);
+            Err(INVALID_RESPONSE_ERROR)
+        }
+    }
+}
+
 fn error_received(value: &serde_json::Value) -> bool {
     value.get("@type") == Some(&serde_json::Value::String("error".to_string()))
 }
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::fake_tdlib::fixtures::{start, with_responses};
+    use super::fake_tdlib::FakeTdlib;
+    use crate::errors::Error;
+    use crate::types::{Chats, GetMe, SearchPublicChats, TdFunction, TdObject, User};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_call() {
+        let fake = with_responses(FakeTdlib::new().authorized());
+        let (worker, client) = start(&fake).await;
+
+        let me: User = client.call(GetMe::builder().build()).await.unwrap();
+        assert_eq!(me.id(), 42);
+        let search = SearchPublicChats::builder().query("call").build();
+        let chats = client.call(&search).await.unwrap();
+        assert_eq!(chats.chat_ids(), &vec![4]);
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_call_td_function() {
+        let fake = with_responses(FakeTdlib::new().authorized());
+        let (worker, client) = start(&fake).await;
+
+        let function = TdFunction::from_json(r#"{"@type":"getMe"}"#).unwrap();
+        match client.call(function).await.unwrap() {
+            TdObject::User(user) => assert_eq!(user.id(), 42),
+            v => panic!("unexpected result: {:?}", v),
+        }
+        worker.stop();
+    }
+
+    #[tokio::test]
+    async fn test_send_raw() {
+        let fake = with_responses(FakeTdlib::new().authorized());
+        fake.respond_with("getStory", json!({"@type": "story", "id": 3}));
+        let (worker, client) = start(&fake).await;
+
+        let story = client
+            .send_raw(json!({"@type": "getStory", "story_id": 3}))
+            .await
+            .unwrap();
+        assert_eq!(story["id"], 3);
+        let chats: Chats = client
+            .send_raw_as(json!({"@type": "searchPublicChats", "query": "raw"}))
+            .await
+            .unwrap();
+        assert_eq!(chats.chat_ids(), &vec![3]);
+        match client.send_raw(json!({"@type": "getStories"})).await {
+            Err(Error::TDLibError(err)) => assert_eq!(err.code(), 404),
+            v => panic!("unexpected result: {:?}", v),
+        }
+        assert!(matches!(
+            client.send_raw(json!({"story_id": 3})).await,
+            Err(Error::BadRequest(_))
+        ));
+        worker.stop();
+    }
+}
@@ -1,18 +1,27 @@
 use futures::channel::oneshot;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 #[doc(hidden)]
 use std::sync::RwLock;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
-lazy_static::lazy_static! {
-    pub(super) static ref OBSERVER: Observer = Observer::new();
+#[derive(Debug)]
+struct PendingRequest {
+    sender: oneshot::Sender<serde_json::Value>,
+    created_at: Instant,
 }
 
+/// Routes TDLib responses to requests waiting for them, using `@extra` as a correlation key.
+/// Each [Worker](crate::client::worker::Worker) owns its own observer and shares it with bound clients.
+#[derive(Debug, Default)]
 pub(super) struct Observer {
-    channels: RwLock<HashMap<String, oneshot::Sender<serde_json::Value>>>,
+    channels: RwLock<HashMap<String, PendingRequest>>,
 }
 
 impl Observer {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             channels: RwLock::new(HashMap::new()),
         }
@@ -27,9 +36,9 @@ impl Observer {
                     None => {
                         log::warn!("no subscribers for {}", extra);
                     }
-                    Some(sender) => {
+                    Some(pending) => {
                         log::trace!("signal send for {}", extra);
-                        if let Err(t) = sender.send(t) {
+                        if let Err(t) = pending.sender.send(t) {
                             log::warn!("request already closed, received update: {:?}", t)
                         };
                     }
@@ -43,24 +52,101 @@ impl Observer {
         }
     }
 
-    pub fn subscribe(&self, extra: &str) -> oneshot::Receiver<serde_json::Value> {
+    /// Registers a subscription for the response with specified `@extra`.
+    /// The subscription is removed when returned [Subscription] is dropped.
+    pub fn subscribe(&self, extra: &str) -> Subscription<'_> {
         let (sender, receiver) = oneshot::channel();
         match self.channels.write() {
             Ok(mut map) => {
-                map.insert(extra.to_string(), sender);
+                map.insert(
+                    extra.to_string(),
+                    PendingRequest {
+                        sender,
+                        created_at: Instant::now(),
+                    },
+                );
                 log::trace!("subscribed for {}", extra);
             }
             _ => {
                 log::warn!("can't acquire lock for notifier map");
             }
         };
-        receiver
+        Subscription {
+            observer: self,
+            extra: extra.to_string(),
+            receiver,
+        }
     }
 
-    pub fn unsubscribe(&self, extra: &str) {
+    fn unsubscribe(&self, extra: &str) {
         if let Ok(mut map) = self.channels.write() {
-            log::trace!("remove {} subscription", &extra);
-            map.remove(extra);
+            if map.remove(extra).is_some() {
+                log::trace!("remove {} subscription", &extra);
+            }
         };
     }
+
+    /// Number of requests still waiting for a response.
+    pub fn pending_count(&self) -> usize {
+        self.channels
+            .read()
+            .map(|map| map.len())
+            .unwrap_or_default()
+    }
+
+    /// Age of the oldest request still waiting for a response.
+    pub fn oldest_pending_age(&self) -> Option<Duration> {
+        self.channels
+            .read()
+            .ok()?
+            .values()
+            .map(|pending| pending.created_at.elapsed())
+            .max()
+    }
+}
+
+/// Pending response subscription. Resolves with the response and unsubscribes itself on drop,
+/// so dropped requests never leave stale entries in the [Observer].
+pub(super) struct Subscription<'a> {
+    observer: &'a Observer,
+    extra: String,
+    receiver: oneshot::Receiver<serde_json::Value>,
+}
+
+impl<'a> Future for Subscription<'a> {
+    type Output = Result<serde_json::Value, oneshot::Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx)
+    }
+}
+
+impl<'a> Drop for Subscription<'a> {
+    fn drop(&mut self) {
+        self.observer.unsubscribe(&self.extra);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Observer;
+    use futures::FutureExt;
+
+    #[test]
+    fn test_subscription_removed_on_drop() {
+        let observer = Observer::new();
+        let first = observer.subscribe("first");
+        let second = observer.subscribe("second");
+        assert_eq!(observer.pending_count(), 2);
+        assert!(observer.oldest_pending_age().is_some());
+
+        drop(first);
+        assert_eq!(observer.pending_count(), 1);
+
+        let response = serde_json::json!({"@type": "ok", "@extra": "second"});
+        assert!(observer.notify(response.clone()).is_none());
+        assert_eq!(observer.pending_count(), 0);
+        assert_eq!(second.now_or_never(), Some(Ok(response)));
+        assert!(observer.oldest_pending_age().is_none());
+    }
 }
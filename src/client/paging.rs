@@ -0,0 +1,411 @@
+//! Streams paging through chat history, search results, supergroup members and chat event log.
+//!
+//! Each stream requests pages lazily, moving the cursor of the request (`from_message_id`, `offset_*`, `offset`
+//! or `from_event_id`) past the last received item. TDLib may return fewer items than requested even if there are more,
+//! so only an empty page (or a page of already received items) ends a stream. Items are deduplicated.
+//! Streams end after the first error. They are not `Unpin`, so pin them (e.g. with `futures::pin_mut!`) before polling.
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+
+use futures::Stream;
+
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::Result;
+use crate::types::{
+    ChatEvent, ChatMember, GetChatEventLog, GetChatHistory, GetSupergroupMembers, Message,
+    MessageSender, SearchChatMessages, SearchMessages,
+};
+
+/// Maximum number of members TDLib returns with one `getSupergroupMembers` request.
+pub const MAX_SUPERGROUP_MEMBERS_LIMIT: i32 = 200;
+
+/// Limits of a paging stream: it ends on the first item out of bounds.
+/// ```ignore
+/// let history = client.get_chat_history_stream(
+///     GetChatHistory::builder().chat_id(chat_id).limit(100).build(),
+///     PageBounds::default().max_items(1000).min_date(week_ago),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageBounds {
+    max_items: Option<usize>,
+    min_date: Option<i32>,
+    min_message_id: Option<i64>,
+}
+
+impl PageBounds {
+    /// Stops after specified number of items.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Stops on the first message or event sent before specified Unix time.
+    pub fn min_date(mut self, min_date: i32) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Stops on the first message with identifier less than specified one.
+    pub fn min_message_id(mut self, min_message_id: i64) -> Self {
+        self.min_message_id = Some(min_message_id);
+        self
+    }
+
+    fn admits<T: Paged>(&self, item: &T, yielded: usize) -> bool {
+        if matches!(self.max_items, Some(max_items) if yielded >= max_items) {
+            return false;
+        }
+        if let (Some(min_date), Some(date)) = (self.min_date, item.date()) {
+            if date < min_date {
+                return false;
+            }
+        }
+        if let (Some(min_message_id), Some(message_id)) = (self.min_message_id, item.message_id()) {
+            if message_id < min_message_id {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Item of a paged response.
+trait Paged {
+    type Key: Hash + Eq;
+
+    fn key(&self) -> Self::Key;
+
+    fn date(&self) -> Option<i32> {
+        None
+    }
+
+    fn message_id(&self) -> Option<i64> {
+        None
+    }
+}
+
+impl Paged for Message {
+    // messages of different chats may have equal identifiers
+    type Key = (i64, i64);
+
+    fn key(&self) -> Self::Key {
+        (self.chat_id(), self.id())
+    }
+
+    fn date(&self) -> Option<i32> {
+        Some(Message::date(self))
+    }
+
+    fn message_id(&self) -> Option<i64> {
+        Some(self.id())
+    }
+}
+
+impl Paged for ChatEvent {
+    type Key = i64;
+
+    fn key(&self) -> Self::Key {
+        self.id()
+    }
+
+    fn date(&self) -> Option<i32> {
+        Some(ChatEvent::date(self))
+    }
+}
+
+impl Paged for ChatMember {
+    // user identifiers are positive and chat identifiers are negative, so they don't clash
+    type Key = i64;
+
+    fn key(&self) -> Self::Key {
+        match self.member_id() {
+            MessageSender::User(user) => user.user_id(),
+            MessageSender::Chat(chat) => chat.chat_id(),
+            MessageSender::_Default | MessageSender::Unknown { .. } => 0,
+        }
+    }
+}
+
+struct Page<T, C> {
+    items: Vec<T>,
+    // cursor of the next page, `None` if there are no more pages
+    next: Option<C>,
+}
+
+struct Pager<T: Paged, C, F> {
+    cursor: Option<C>,
+    fetch: F,
+    bounds: PageBounds,
+    buffer: VecDeque<T>,
+    seen: HashSet<T::Key>,
+    yielded: usize,
+}
+
+fn paginate<T, C, F, Fut>(cursor: C, bounds: PageBounds, fetch: F) -> impl Stream<Item = Result<T>>
+where
+    T: Paged,
+    F: FnMut(C) -> Fut,
+    Fut: Future<Output = Result<Page<T, C>>>,
+{
+    let pager = Pager {
+        cursor: Some(cursor),
+        fetch,
+        bounds,
+        buffer: VecDeque::new(),
+        seen: HashSet::new(),
+        yielded: 0,
+    };
+    futures::stream::unfold(pager, |mut pager| async move {
+        loop {
+            if let Some(item) = pager.buffer.pop_front() {
+                if !pager.bounds.admits(&item, pager.yielded) {
+                    return None;
+                }
+                pager.yielded += 1;
+                return Some((Ok(item), pager));
+            }
+            // stream ends after an error, as there is no cursor anymore
+            let cursor = pager.cursor.take()?;
+            let page = match (pager.fetch)(cursor).await {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), pager)),
+            };
+            for item in page.items {
+                if pager.seen.insert(item.key()) {
+                    pager.buffer.push_back(item);
+                }
+            }
+            // a page without new items means the cursor doesn't move anymore
+            if !pager.buffer.is_empty() {
+                pager.cursor = page.next;
+            }
+        }
+    })
+}
+
+fn messages_page<C>(
+    messages: Vec<Option<Message>>,
+    next: impl FnOnce(&Message) -> C,
+) -> Page<Message, C> {
+    let items: Vec<Message> = messages.into_iter().flatten().collect();
+    let next = items.last().map(next);
+    Page { items, next }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Stream of chat messages, from the newest to the oldest, starting with `from_message_id` of the request.
+    /// `limit` of the request is the page size.
+    pub fn get_chat_history_stream<C: AsRef<GetChatHistory>>(
+        &self,
+        get_chat_history: C,
+        bounds: PageBounds,
+    ) -> impl Stream<Item = Result<Message>> {
+        let client = self.clone();
+        let template = get_chat_history.as_ref().clone();
+        let first = (template.from_message_id(), template.offset());
+        paginate(first, bounds, move |(from_message_id, offset)| {
+            let client = client.clone();
+            let request = GetChatHistory::builder()
+                .chat_id(template.chat_id())
+                .from_message_id(from_message_id)
+                .offset(offset)
+                .limit(template.limit())
+                .only_local(template.only_local())
+                .build();
+            async move {
+                let messages = client.get_chat_history(request).await?;
+                Ok(messages_page(messages.messages().clone(), |last| {
+                    (last.id(), 0)
+                }))
+            }
+        })
+    }
+
+    /// Stream of messages found in a chat, from the newest to the oldest. `limit` of the request is the page size.
+    pub fn search_chat_messages_stream<C: AsRef<SearchChatMessages>>(
+        &self,
+        search_chat_messages: C,
+        bounds: PageBounds,
+    ) -> impl Stream<Item = Result<Message>> {
+        let client = self.clone();
+        let template = search_chat_messages.as_ref().clone();
+        let first = (template.from_message_id(), template.offset());
+        paginate(first, bounds, move |(from_message_id, offset)| {
+            let client = client.clone();
+            let request = SearchChatMessages::builder()
+                .chat_id(template.chat_id())
+                .query(template.query())
+                .sender_id(template.sender_id())
+                .from_message_id(from_message_id)
+                .offset(offset)
+                .limit(template.limit())
+                .filter(template.filter())
+                .message_thread_id(template.message_thread_id())
+                .build();
+            async move {
+                let messages = client.search_chat_messages(request).await?;
+                Ok(messages_page(messages.messages().clone(), |last| {
+                    (last.id(), 0)
+                }))
+            }
+        })
+    }
+
+    /// Stream of messages found in all chats of a chat list, from the newest to the oldest.
+    /// `limit` of the request is the page size.
+    pub fn search_messages_stream<C: AsRef<SearchMessages>>(
+        &self,
+        search_messages: C,
+        bounds: PageBounds,
+    ) -> impl Stream<Item = Result<Message>> {
+        let client = self.clone();
+        let template = search_messages.as_ref().clone();
+        let first = (
+            template.offset_date(),
+            template.offset_chat_id(),
+            template.offset_message_id(),
+        );
+        paginate(
+            first,
+            bounds,
+            move |(offset_date, offset_chat_id, offset_message_id)| {
+                let client = client.clone();
+                let request = SearchMessages::builder()
+                    .chat_list(template.chat_list())
+                    .query(template.query())
+                    .offset_date(offset_date)
+                    .offset_chat_id(offset_chat_id)
+                    .offset_message_id(offset_message_id)
+                    .limit(template.limit())
+                    .filter(template.filter())
+                    .min_date(template.min_date())
+                    .max_date(template.max_date())
+                    .build();
+                async move {
+                    let messages = client.search_messages(request).await?;
+                    Ok(messages_page(messages.messages().clone(), |last| {
+                        (last.date(), last.chat_id(), last.id())
+                    }))
+                }
+            },
+        )
+    }
+
+    /// Stream of supergroup members, starting with `offset` of the request.
+    /// `limit` of the request is the page size, up to [MAX_SUPERGROUP_MEMBERS_LIMIT].
+    pub fn get_supergroup_members_stream<C: AsRef<GetSupergroupMembers>>(
+        &self,
+        get_supergroup_members: C,
+        bounds: PageBounds,
+    ) -> impl Stream<Item = Result<ChatMember>> {
+        let client = self.clone();
+        let template = get_supergroup_members.as_ref().clone();
+        paginate(template.offset(), bounds, move |offset| {
+            let client = client.clone();
+            let request = GetSupergroupMembers::builder()
+                .supergroup_id(template.supergroup_id())
+                .filter(template.filter())
+                .offset(offset)
+                .limit(template.limit().clamp(1, MAX_SUPERGROUP_MEMBERS_LIMIT))
+                .build();
+            async move {
+                let members = client.get_supergroup_members(request).await?;
+                let items = members.members().clone();
+                let next_offset = offset + items.len() as i32;
+                let next = if items.is_empty() || next_offset >= members.total_count() {
+                    None
+                } else {
+                    Some(next_offset)
+                };
+                Ok(Page { items, next })
+            }
+        })
+    }
+
+    /// Stream of chat events, from the newest to the oldest, starting with `from_event_id` of the request.
+    /// `limit` of the request is the page size.
+    pub fn get_chat_event_log_stream<C: AsRef<GetChatEventLog>>(
+        &self,
+        get_chat_event_log: C,
+        bounds: PageBounds,
+    ) -> impl Stream<Item = Result<ChatEvent>> {
+        let client = self.clone();
+        let template = get_chat_event_log.as_ref().clone();
+        paginate(template.from_event_id(), bounds, move |from_event_id| {
+            let client = client.clone();
+            let request = GetChatEventLog::builder()
+                .chat_id(template.chat_id())
+                .query(template.query())
+                .from_event_id(from_event_id)
+                .limit(template.limit())
+                .filters(template.filters())
+                .user_ids(template.user_ids().clone())
+                .build();
+            async move {
+                let events = client.get_chat_event_log(request).await?;
+                let items = events.events().clone();
+                let next = items.last().map(|last| last.id());
+                Ok(Page { items, next })
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paginate, Page, PageBounds};
+    use crate::errors::{Error, Result};
+    use crate::types::Message;
+    use futures::StreamExt;
+
+    fn message(id: i64) -> Message {
+        Message::builder().id(id).chat_id(1).date(id as i32).build()
+    }
+
+    // history of messages 1..=10, pages are shorter than requested and overlap
+    async fn fetch(from_message_id: i64) -> Result<Page<Message, i64>> {
+        if from_message_id == 0 {
+            return Err(Error::Internal("invalid cursor"));
+        }
+        let items: Vec<_> = (1..=from_message_id.min(10))
+            .rev()
+            .take(3)
+            .map(message)
+            .collect();
+        let next = items.last().map(|m| m.id() + 1);
+        Ok(Page { items, next })
+    }
+
+    async fn ids(bounds: PageBounds, from_message_id: i64) -> Vec<Result<i64>> {
+        paginate(from_message_id, bounds, fetch)
+            .map(|m| m.map(|m| m.id()))
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let all: Vec<i64> = ids(PageBounds::default(), 100)
+            .await
+            .into_iter()
+            .map(|id| id.unwrap())
+            .collect();
+        assert_eq!(all, (1..=10).rev().collect::<Vec<_>>());
+
+        let bounded = ids(PageBounds::default().max_items(4), 100).await;
+        assert_eq!(bounded.len(), 4);
+        let bounded = ids(PageBounds::default().min_date(6), 100).await;
+        assert_eq!(bounded.len(), 5);
+        let bounded = ids(PageBounds::default().min_message_id(9), 100).await;
+        assert_eq!(bounded.len(), 2);
+
+        let failed = ids(PageBounds::default(), 0).await;
+        assert!(matches!(failed.as_slice(), [Err(_)]));
+    }
+}
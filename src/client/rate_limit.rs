@@ -0,0 +1,260 @@
+//! Client-side throttling of outgoing messages.
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+// Per-chat buckets are dropped when they're full again, but only when there are too many of them.
+const MAX_IDLE_CHAT_BUCKETS: usize = 1024;
+
+const SEND_METHODS: &[&str] = &[
+    "sendMessage",
+    "sendMessageAlbum",
+    "sendInlineQueryResultMessage",
+    "sendBotStartMessage",
+    "forwardMessages",
+    "resendMessages",
+    "editMessageText",
+    "editMessageCaption",
+    "editMessageMedia",
+    "editMessageLiveLocation",
+    "editMessageReplyMarkup",
+];
+
+/// Limit of a token bucket: `count` requests per `period`, at most `burst` of them at once.
+/// Both `count` and `burst` are at least 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    count: u32,
+    period: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    pub fn new(count: u32, period: Duration) -> Self {
+        let count = count.max(1);
+        Self {
+            count,
+            period,
+            burst: count,
+        }
+    }
+
+    /// Number of requests which may be sent without delay after a pause. Equals to `count` by default.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    pub fn per_second(count: u32) -> Self {
+        Self::new(count, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(count: u32) -> Self {
+        Self::new(count, Duration::from_secs(60))
+    }
+
+    fn tokens_per_second(&self) -> f64 {
+        self.count as f64 / self.period.as_secs_f64()
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    refill_rate: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            capacity: limit.burst as f64,
+            refill_rate: limit.tokens_per_second(),
+            tokens: limit.burst as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    // Takes a token, possibly in advance, and returns time to wait until the token becomes available.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_rate)
+        }
+    }
+
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.capacity
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    global: Bucket,
+    chats: HashMap<i64, Bucket>,
+}
+
+/// Token-bucket limiter of message-sending requests, see [ClientBuilder::with_rate_limiter](crate::client::ClientBuilder::with_rate_limiter).
+///
+/// Every request of a limited method (`sendMessage`, `sendMessageAlbum`, `forwardMessages`, `editMessageText` and so on)
+/// takes a token from the global bucket and from the bucket of its `chat_id`, waiting until both are available.
+/// Private chats (positive `chat_id`) and groups or channels (negative `chat_id`) have separate limits.
+/// Clones of the limiter share their buckets.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    private_chat_limit: RateLimit,
+    group_chat_limit: RateLimit,
+    methods: HashSet<String>,
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimiter {
+    pub fn new(
+        global_limit: RateLimit,
+        private_chat_limit: RateLimit,
+        group_chat_limit: RateLimit,
+    ) -> Self {
+        Self {
+            private_chat_limit,
+            group_chat_limit,
+            methods: SEND_METHODS.iter().map(|m| m.to_string()).collect(),
+            state: Arc::new(Mutex::new(State {
+                global: Bucket::new(&global_limit, Instant::now()),
+                chats: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Limits from [Bot API FAQ](https://core.telegram.org/bots/faq#my-bot-is-hitting-limits-how-do-i-avoid-this):
+    /// 30 messages per second overall, 1 message per second in a private chat and 20 messages per minute in a group.
+    pub fn for_bots() -> Self {
+        Self::new(
+            RateLimit::per_second(30),
+            RateLimit::per_second(1).with_burst(3),
+            RateLimit::per_minute(20).with_burst(3),
+        )
+    }
+
+    /// Telegram does not publish limits for user accounts, these are conservative ones:
+    /// 1 message per second overall with bursts of 5, per-chat limits are the same as [for_bots](RateLimiter::for_bots) ones.
+    pub fn for_users() -> Self {
+        Self::new(
+            RateLimit::per_second(1).with_burst(5),
+            RateLimit::per_second(1).with_burst(3),
+            RateLimit::per_minute(20).with_burst(3),
+        )
+    }
+
+    /// Limits requests of one more method, e.g. `sendChatAction`.
+    pub fn limit_method<S: Into<String>>(mut self, method: S) -> Self {
+        self.methods.insert(method.into());
+        self
+    }
+
+    /// Checks if requests of the method are limited.
+    pub fn is_limited(&self, method: &str) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// Waits until the request may be sent. Requests of unlimited methods pass immediately.
+    pub async fn acquire(&self, method: &str, chat_id: Option<i64>) {
+        if !self.is_limited(method) {
+            return;
+        }
+        let delay = self.reserve(chat_id, Instant::now());
+        if !delay.is_zero() {
+            log::debug!("{} to chat {:?} throttled for {:?}", method, chat_id, delay);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn reserve(&self, chat_id: Option<i64>, now: Instant) -> Duration {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let global_delay = state.global.reserve(now);
+        let chat_delay = match chat_id {
+            None => Duration::ZERO,
+            Some(chat_id) => {
+                if state.chats.len() >= MAX_IDLE_CHAT_BUCKETS {
+                    state.chats.retain(|_, bucket| !bucket.is_full(now));
+                }
+                let limit = if chat_id < 0 {
+                    &self.group_chat_limit
+                } else {
+                    &self.private_chat_limit
+                };
+                state
+                    .chats
+                    .entry(chat_id)
+                    .or_insert_with(|| Bucket::new(limit, now))
+                    .reserve(now)
+            }
+        };
+        global_delay.max(chat_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RateLimit, RateLimiter};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    #[test]
+    fn test_reserve() {
+        let limiter = RateLimiter::new(
+            RateLimit::per_second(10).with_burst(3),
+            RateLimit::per_second(1),
+            RateLimit::per_minute(20).with_burst(1),
+        );
+        let now = Instant::now();
+        assert_eq!(limiter.reserve(Some(1), now), Duration::ZERO);
+        // per-chat limit
+        assert_eq!(limiter.reserve(Some(1), now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve(Some(-1), now), Duration::ZERO);
+        // global limit: burst is exhausted, then 10 tokens per second
+        assert_eq!(limiter.reserve(None, now), Duration::from_millis(100));
+        assert_eq!(limiter.reserve(None, now), Duration::from_millis(200));
+
+        let later = now + Duration::from_secs(2);
+        assert_eq!(limiter.reserve(Some(1), later), Duration::ZERO);
+        // group chats get 1 token per 3 seconds
+        let delay = limiter.reserve(Some(-1), later);
+        assert!(delay > Duration::from_millis(999) && delay < Duration::from_millis(1001));
+
+        assert!(limiter.is_limited("sendMessage"));
+        assert!(!limiter.is_limited("getChat"));
+    }
+
+    #[test]
+    fn test_zero_limit() {
+        assert_eq!(RateLimit::per_second(0), RateLimit::per_second(1));
+        assert_eq!(
+            RateLimit::per_second(1).with_burst(0),
+            RateLimit::per_second(1)
+        );
+        let limiter = RateLimiter::new(
+            RateLimit::per_second(0),
+            RateLimit::per_minute(0),
+            RateLimit::per_minute(0),
+        );
+        let now = Instant::now();
+        assert_eq!(limiter.reserve(Some(1), now), Duration::ZERO);
+        assert_eq!(limiter.reserve(Some(1), now), Duration::from_secs(60));
+    }
+}
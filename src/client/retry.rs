@@ -0,0 +1,270 @@
+//! Automatic retries of failed requests.
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::errors::Error;
+
+type RetryCallback = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// Information about a request which is going to be repeated.
+#[derive(Debug)]
+pub struct RetryEvent<'a> {
+    /// TDLib method name, e.g. `sendMessage`.
+    pub method: &'a str,
+    /// Number of the failed attempt, starting from 1.
+    pub attempt: u32,
+    /// Time to wait before the next attempt.
+    pub delay: Duration,
+    /// Error of the failed attempt.
+    pub error: &'a Error,
+}
+
+/// Policy of retrying failed requests, see [ClientBuilder::with_retry_policy](crate::client::ClientBuilder::with_retry_policy).
+///
+/// A request is repeated if its error [is retryable](crate::errors::Error::is_retryable) and its method is allowed to be repeated:
+/// by default only idempotent methods (`get*` and `search*`) are repeated, use [retry_method](RetryPolicy::retry_method)
+/// or [retry_all_methods](RetryPolicy::retry_all_methods) to extend the list.
+/// Delay grows exponentially from [initial_backoff](RetryPolicy::initial_backoff) up to [max_backoff](RetryPolicy::max_backoff),
+/// retry-after time specified by the server (e.g. `429: Too Many Requests: retry after N`) takes precedence.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: f64,
+    max_retry_after: Duration,
+    retry_all_methods: bool,
+    methods: HashSet<String>,
+    on_retry: Option<RetryCallback>,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("max_retry_after", &self.max_retry_after)
+            .field("retry_all_methods", &self.retry_all_methods)
+            .field("methods", &self.methods)
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.1,
+            max_retry_after: Duration::from_secs(60),
+            retry_all_methods: false,
+            methods: HashSet::new(),
+            on_retry: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total number of attempts, including the first one. Default is 3.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Delay before the first retry. Default is 1 second.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Upper bound of exponentially growing delay. Default is 30 seconds.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Factor the delay grows with after each attempt, at least 1. Default is 2.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        // `max` also replaces NaN
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Random deviation of the delay, as a fraction of it: 0.1 means ±10%. Default is 0.1.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = match jitter.is_nan() {
+            true => 0.0,
+            false => jitter.clamp(0.0, 1.0),
+        };
+        self
+    }
+
+    /// Requests are not repeated if server asks to wait longer than specified. Default is 60 seconds.
+    pub fn max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    /// Allows to repeat requests of specified method, e.g. `sendMessage`.
+    pub fn retry_method<S: Into<String>>(mut self, method: S) -> Self {
+        self.methods.insert(method.into());
+        self
+    }
+
+    /// Allows to repeat requests of any method, including non-idempotent ones.
+    pub fn retry_all_methods(mut self) -> Self {
+        self.retry_all_methods = true;
+        self
+    }
+
+    /// Callback called before each wait, may be used to collect metrics.
+    pub fn on_retry<F>(mut self, on_retry: F) -> Self
+    where
+        F: Fn(&RetryEvent) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(on_retry));
+        self
+    }
+
+    fn is_method_allowed(&self, method: &str) -> bool {
+        self.retry_all_methods
+            || method.starts_with("get")
+            || method.starts_with("search")
+            || self.methods.contains(method)
+    }
+
+    /// Returns delay before the next attempt, or `None` if the request must not be repeated.
+    pub(crate) fn delay(&self, method: &str, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !error.is_retryable() || !self.is_method_allowed(method)
+        {
+            return None;
+        }
+        let delay = match error.retry_after() {
+            Some(retry_after) if retry_after > self.max_retry_after => return None,
+            Some(retry_after) => retry_after,
+            None => {
+                let backoff = self.initial_backoff.as_secs_f64()
+                    * self.multiplier.powi(attempt.saturating_sub(1) as i32);
+                // `max_backoff` may not survive the round trip through `f64`
+                Duration::try_from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()))
+                    .unwrap_or(self.max_backoff)
+            }
+        };
+        Some(self.apply_jitter(delay))
+    }
+
+    fn apply_jitter(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let factor = rand::thread_rng().gen_range(1.0 - self.jitter..=1.0 + self.jitter);
+        Duration::try_from_secs_f64(delay.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+    }
+
+    pub(crate) fn notify(&self, event: &RetryEvent) {
+        log::warn!(
+            "{} failed on attempt {}: {}, retrying in {:?}",
+            event.method,
+            event.attempt,
+            event.error,
+            event.delay
+        );
+        if let Some(on_retry) = &self.on_retry {
+            on_retry(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::errors::{Error, TDLibError};
+    use std::time::Duration;
+
+    fn tdlib_error(code: i32, message: &str) -> Error {
+        Error::TDLibError(TDLibError::builder().code(code).message(message).build())
+    }
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new()
+            .jitter(0.0)
+            .max_attempts(4)
+            .max_backoff(Duration::from_secs(3));
+        let internal = tdlib_error(500, "Internal Server Error");
+        assert_eq!(
+            policy.delay("getChat", 1, &internal),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay("getChat", 2, &internal),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.delay("getChat", 3, &internal),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(policy.delay("getChat", 4, &internal), None);
+        // not idempotent
+        assert_eq!(policy.delay("sendMessage", 1, &internal), None);
+        // not retryable
+        assert_eq!(
+            policy.delay("getChat", 1, &tdlib_error(400, "CHAT_ID_INVALID")),
+            None
+        );
+
+        let policy = policy
+            .retry_method("sendMessage")
+            .max_retry_after(Duration::from_secs(10));
+        let flood_wait = tdlib_error(429, "Too Many Requests: retry after 7");
+        assert_eq!(
+            policy.delay("sendMessage", 1, &flood_wait),
+            Some(Duration::from_secs(7))
+        );
+        let long_flood_wait = tdlib_error(429, "Too Many Requests: retry after 70");
+        assert_eq!(policy.delay("sendMessage", 1, &long_flood_wait), None);
+
+        for multiplier in [-2.0, f64::NAN] {
+            let policy = RetryPolicy::new().jitter(0.0).multiplier(multiplier);
+            assert_eq!(
+                policy.delay("getChat", 2, &internal),
+                Some(Duration::from_secs(1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_jitter() {
+        let policy = RetryPolicy::new().jitter(0.5);
+        let delay = policy
+            .delay("getChat", 1, &tdlib_error(500, "Internal Server Error"))
+            .unwrap();
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1500));
+
+        let internal = tdlib_error(500, "Internal Server Error");
+        let policy = RetryPolicy::new().jitter(f64::NAN);
+        assert_eq!(
+            policy.delay("getChat", 1, &internal),
+            Some(Duration::from_secs(1))
+        );
+        let policy = RetryPolicy::new()
+            .jitter(1.0)
+            .initial_backoff(Duration::MAX)
+            .max_backoff(Duration::MAX);
+        assert!(policy.delay("getChat", 1, &internal).is_some());
+    }
+}
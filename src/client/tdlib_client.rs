@@ -1,27 +1,40 @@
 use crate::errors::Result;
-use crate::tdjson;
 use crate::types::RFunction;
+#[cfg(feature = "dynamic-tdjson")]
+use std::ffi::OsStr;
+#[cfg(feature = "dynamic-tdjson")]
+use std::sync::Arc;
+
+#[cfg(feature = "tdjson")]
+use crate::tdjson;
+#[cfg(feature = "dynamic-tdjson")]
+use crate::tdjson_dynamic::TdJsonLibrary;
+
+pub use crate::types::ClientId;
 
 /// A bridge between TDLib and rust-tdlib.
 pub trait TdLibClient {
-    fn send<Fnc: RFunction>(&self, client_id: tdjson::ClientId, fnc: Fnc) -> Result<()>;
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> Result<()>;
     fn receive(&self, timeout: f64) -> Option<String>;
     fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>>;
-    fn new_client(&self) -> tdjson::ClientId;
+    fn new_client(&self) -> ClientId;
 }
 
+#[cfg(feature = "tdjson")]
 #[derive(Clone, Debug, Copy)]
 /// Base implementation. See [tdjson](crate::tdjson) for details.
 pub struct TdJson;
 
+#[cfg(feature = "tdjson")]
 impl Default for TdJson {
     fn default() -> Self {
         Self
     }
 }
 
+#[cfg(feature = "tdjson")]
 impl TdLibClient for TdJson {
-    fn send<Fnc: RFunction>(&self, client_id: tdjson::ClientId, fnc: Fnc) -> Result<()> {
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> Result<()> {
         let json = fnc.to_json()?;
         tdjson::send(client_id, &json[..]);
         Ok(())
@@ -36,13 +49,63 @@ impl TdLibClient for TdJson {
         Ok(tdjson::execute(&json[..]))
     }
 
-    fn new_client(&self) -> tdjson::ClientId {
+    fn new_client(&self) -> ClientId {
         tdjson::new_client()
     }
 }
 
+#[cfg(feature = "tdjson")]
 impl TdJson {
     pub fn new() -> Self {
         Self
     }
 }
+
+#[cfg(feature = "dynamic-tdjson")]
+#[derive(Clone, Debug)]
+/// Implementation which loads `libtdjson` in runtime. See [tdjson_dynamic](crate::tdjson_dynamic) for details.
+pub struct DynamicTdJson {
+    library: Arc<TdJsonLibrary>,
+}
+
+#[cfg(feature = "dynamic-tdjson")]
+impl TdLibClient for DynamicTdJson {
+    fn send<Fnc: RFunction>(&self, client_id: ClientId, fnc: Fnc) -> Result<()> {
+        let json = fnc.to_json()?;
+        self.library.send(client_id, &json[..]);
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        self.library.receive(timeout)
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>> {
+        let json = fnc.to_json()?;
+        Ok(self.library.execute(&json[..]))
+    }
+
+    fn new_client(&self) -> ClientId {
+        self.library.new_client()
+    }
+}
+
+#[cfg(feature = "dynamic-tdjson")]
+impl DynamicTdJson {
+    /// Loads `libtdjson` from specified path.
+    pub fn load<P: AsRef<OsStr>>(path: P) -> Result<Self> {
+        Ok(Self::from_library(TdJsonLibrary::load(path)?))
+    }
+
+    /// Loads `libtdjson` from path specified with [TDJSON_PATH_ENV](crate::tdjson_dynamic::TDJSON_PATH_ENV)
+    /// environment variable or from default system locations.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::from_library(TdJsonLibrary::from_env()?))
+    }
+
+    pub fn from_library(library: TdJsonLibrary) -> Self {
+        Self {
+            library: Arc::new(library),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Fan-out of updates to multiple subscribers, see [Client::subscribe](crate::client::Client::subscribe).
+use std::collections::HashSet;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use super::dispatcher::update_chat_id;
+use crate::types::Update;
+
+/// Capacity of a channel created by [Client::subscribe](crate::client::Client::subscribe).
+pub const DEFAULT_SUBSCRIPTION_CAPACITY: usize = 1024;
+
+type UpdatePredicate = Arc<dyn Fn(&Update) -> bool + Send + Sync>;
+
+/// Selects updates delivered to a subscriber.
+/// All specified conditions must match; if several kinds or chats are specified, any of them matches.
+/// ```ignore
+/// let messages = client.subscribe(UpdateFilter::all().kind("updateNewMessage").chat(chat_id));
+/// ```
+#[derive(Clone, Default)]
+pub struct UpdateFilter {
+    kinds: Option<HashSet<String>>,
+    chat_ids: Option<HashSet<i64>>,
+    predicate: Option<UpdatePredicate>,
+}
+
+impl fmt::Debug for UpdateFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UpdateFilter")
+            .field("kinds", &self.kinds)
+            .field("chat_ids", &self.chat_ids)
+            .field("predicate", &self.predicate.is_some())
+            .finish()
+    }
+}
+
+impl UpdateFilter {
+    /// Matches all updates.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Matches updates of specified kind, i.e. TDLib type name like `updateNewMessage`.
+    pub fn kind<S: Into<String>>(mut self, kind: S) -> Self {
+        self.kinds
+            .get_or_insert_with(HashSet::new)
+            .insert(kind.into());
+        self
+    }
+
+    /// Matches updates related to specified chat: ones with `chat_id` field, new messages and new chats.
+    pub fn chat(mut self, chat_id: i64) -> Self {
+        self.chat_ids
+            .get_or_insert_with(HashSet::new)
+            .insert(chat_id);
+        self
+    }
+
+    /// Matches updates accepted by the predicate.
+    pub fn predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&Update) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    fn matches(&self, kind: &str, chat_id: Option<i64>, update: &Update) -> bool {
+        if let Some(kinds) = &self.kinds {
+            if !kinds.contains(kind) {
+                return false;
+            }
+        }
+        if let Some(chat_ids) = &self.chat_ids {
+            match chat_id {
+                Some(chat_id) if chat_ids.contains(&chat_id) => {}
+                _ => return false,
+            }
+        }
+        match &self.predicate {
+            Some(predicate) => predicate(update),
+            None => true,
+        }
+    }
+}
+
+/// Receiving half of a subscription, returned by [Client::subscribe](crate::client::Client::subscribe).
+/// Updates are dropped instead of stalling other subscribers when the receiver doesn't keep up,
+/// use [lagged](UpdateReceiver::lagged) to check it. Subscription ends when the receiver is dropped.
+#[derive(Debug)]
+pub struct UpdateReceiver {
+    receiver: mpsc::Receiver<Arc<Update>>,
+    lagged: Arc<AtomicU64>,
+}
+
+impl UpdateReceiver {
+    pub async fn recv(&mut self) -> Option<Arc<Update>> {
+        self.receiver.recv().await
+    }
+
+    /// Number of updates dropped because the receiver's channel was full.
+    pub fn lagged(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for UpdateReceiver {
+    type Item = Arc<Update>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+#[derive(Debug)]
+struct Subscriber {
+    id: usize,
+    filter: UpdateFilter,
+    sender: mpsc::Sender<Arc<Update>>,
+    lagged: Arc<AtomicU64>,
+}
+
+/// Subscribers of a client, shared between its clones.
+#[derive(Debug, Default)]
+pub(crate) struct Subscribers {
+    next_id: AtomicUsize,
+    subscribers: RwLock<Vec<Subscriber>>,
+    closed: AtomicBool,
+}
+
+impl Subscribers {
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Vec<Subscriber>> {
+        match self.subscribers.write() {
+            Ok(subscribers) => subscribers,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Returns a receiver of matching updates. Receiver of closed subscribers ends immediately.
+    pub fn subscribe(&self, filter: UpdateFilter, capacity: usize) -> UpdateReceiver {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let lagged = Arc::new(AtomicU64::new(0));
+        let subscriber = Subscriber {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            filter,
+            sender,
+            lagged: lagged.clone(),
+        };
+        let mut subscribers = self.write();
+        // checked under the lock, so `close` can't miss the subscriber
+        if !self.closed.load(Ordering::Acquire) {
+            subscribers.push(subscriber);
+        }
+        UpdateReceiver { receiver, lagged }
+    }
+
+    /// Ends all receivers after updates already sent to them, until [reopen](Subscribers::reopen) is called.
+    /// Called when the client is closed or its worker stops.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.write().clear();
+    }
+
+    /// Allows new subscriptions again, when the client is bound to a worker.
+    pub fn reopen(&self) {
+        self.closed.store(false, Ordering::Release);
+    }
+
+    /// Sends the update to every matching subscriber without waiting. `raw` is the update as received from TDLib.
+    pub fn broadcast(&self, raw: &Value, update: &Update) {
+        let kind = raw.get("@type").and_then(|t| t.as_str()).unwrap_or("");
+        let chat_id = update_chat_id(update);
+        let mut shared = None;
+        let mut closed = false;
+        {
+            let subscribers = match self.subscribers.read() {
+                Ok(subscribers) => subscribers,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            for subscriber in subscribers.iter() {
+                if !subscriber.filter.matches(kind, chat_id, update) {
+                    continue;
+                }
+                let shared = shared.get_or_insert_with(|| Arc::new(update.clone()));
+                match subscriber.sender.try_send(shared.clone()) {
+                    Ok(_) => {}
+                    Err(mpsc::error::TrySendError::Full(_)) => {
+                        let lagged = subscriber.lagged.fetch_add(1, Ordering::Relaxed) + 1;
+                        log::warn!(
+                            "subscriber {} is lagging, {} dropped, {} dropped in total",
+                            subscriber.id,
+                            kind,
+                            lagged
+                        );
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => closed = true,
+                }
+            }
+        }
+        if closed {
+            self.write().retain(|s| !s.sender.is_closed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Subscribers, UpdateFilter};
+    use crate::types::Update;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let subscribers = Subscribers::default();
+        let mut all = subscribers.subscribe(UpdateFilter::all(), 10);
+        let mut titles = subscribers.subscribe(UpdateFilter::all().kind("updateChatTitle"), 10);
+        let mut chat = subscribers.subscribe(UpdateFilter::all().chat(1), 1);
+        let dropped = subscribers.subscribe(UpdateFilter::all(), 10);
+        drop(dropped);
+
+        for raw in [
+            json!({"@type": "updateChatTitle", "chat_id": 1, "title": "first"}),
+            json!({"@type": "updateChatTitle", "chat_id": 2, "title": "second"}),
+            json!({"@type": "updateChatTitle", "chat_id": 1, "title": "third"}),
+            json!({"@type": "updateOption", "name": "version", "value": {"@type": "optionValueEmpty"}}),
+        ] {
+            let update: Update = serde_json::from_value(raw.clone()).unwrap();
+            subscribers.broadcast(&raw, &update);
+        }
+
+        assert_eq!(subscribers.subscribers.read().unwrap().len(), 3);
+        let mut received = 0;
+        while all.receiver.try_recv().is_ok() {
+            received += 1;
+        }
+        assert_eq!(received, 4);
+        let mut received = Vec::new();
+        while let Ok(update) = titles.receiver.try_recv() {
+            match update.as_ref() {
+                Update::ChatTitle(u) => received.push(u.title().clone()),
+                u => panic!("unexpected update: {:?}", u),
+            }
+        }
+        assert_eq!(received, vec!["first", "second", "third"]);
+        // channel of the chat subscriber has room for one update only
+        assert!(chat.recv().await.is_some());
+        assert!(chat.receiver.try_recv().is_err());
+        assert_eq!(chat.lagged(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_close() {
+        let subscribers = Subscribers::default();
+        let mut before = subscribers.subscribe(UpdateFilter::all(), 10);
+        let raw = json!({"@type": "updateChatTitle", "chat_id": 1, "title": "title"});
+        let update: Update = serde_json::from_value(raw.clone()).unwrap();
+        subscribers.broadcast(&raw, &update);
+
+        subscribers.close();
+        // updates sent before closing are still received
+        assert!(before.recv().await.is_some());
+        assert!(before.recv().await.is_none());
+        let mut after = subscribers.subscribe(UpdateFilter::all(), 10);
+        assert!(after.recv().await.is_none());
+
+        subscribers.reopen();
+        let mut reopened = subscribers.subscribe(UpdateFilter::all(), 10);
+        subscribers.broadcast(&raw, &update);
+        assert!(reopened.recv().await.is_some());
+    }
+}
@@ -1,21 +1,23 @@
 //! Handlers for all incoming data
+#[cfg(feature = "tdjson")]
+use super::tdlib_client::TdJson;
 use super::{
     auth_handler::{AuthStateHandler, ConsoleAuthStateHandler},
-    observer::OBSERVER,
-    tdlib_client::{TdJson, TdLibClient},
+    observer::Observer,
+    tdlib_client::{ClientId, TdLibClient},
     {Client, ClientState},
 };
 use crate::client::{ClientIdentifier, CLIENT_NOT_AUTHORIZED};
 use crate::types::{CheckAuthenticationBotToken, GetAuthorizationState, JsonValue};
 use crate::{
     errors::{Error, Result},
-    tdjson::ClientId,
     types::{
         AuthorizationState, CheckAuthenticationCode, CheckAuthenticationPassword,
         CheckDatabaseEncryptionKey, GetApplicationConfig, RObject, RegisterUser,
         SetAuthenticationPhoneNumber, SetTdlibParameters, Update, UpdateAuthorizationState,
     },
 };
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -39,14 +41,26 @@ where
     tdlib_client: T,
 }
 
+#[cfg(feature = "tdjson")]
 impl Default for WorkerBuilder<ConsoleAuthStateHandler, TdJson> {
     /// Provides default implementation with [ConsoleAuthStateHandler](crate::client::client::ConsoleAuthStateHandler)
     fn default() -> Self {
+        Self::new(TdJson::new())
+    }
+}
+
+impl<T> WorkerBuilder<ConsoleAuthStateHandler, T>
+where
+    T: TdLibClient + Send + Sync + Clone + 'static,
+{
+    /// Creates builder of a worker which receives TDLib data using specified [TdLibClient](crate::client::tdlib_client::TdLibClient).
+    /// Clients bound to the worker must use the same implementation.
+    pub fn new(tdlib_client: T) -> Self {
         Self {
             read_updates_timeout: 1.0,
             channels_send_timeout: 5.0,
             auth_state_handler: ConsoleAuthStateHandler::new(),
-            tdlib_client: TdJson::new(),
+            tdlib_client,
         }
     }
 }
@@ -153,8 +167,10 @@ where
     channels_send_timeout: Duration,
     tdlib_client: S,
     clients: Arc<RwLock<ClientsMap<S>>>,
+    observer: Arc<Observer>,
 }
 
+#[cfg(feature = "tdjson")]
 impl Worker<ConsoleAuthStateHandler, TdJson> {
     pub fn builder() -> WorkerBuilder<ConsoleAuthStateHandler, TdJson> {
         WorkerBuilder::default()
@@ -194,6 +210,8 @@ where
             AuthorizationState::GetAuthorizationState(_) => {
                 panic!()
             }
+            // a state added in a newer TDLib, authorization can't be completed by the worker
+            AuthorizationState::Unknown { .. } => Ok((ClientState::Authorizing, state)),
         }
     }
 
@@ -248,6 +266,7 @@ where
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("new client created: {}", client_id);
         client.set_client_id(client_id)?;
+        client.set_observer(self.observer.clone());
         self.store_client_context(&client).await?;
 
         Ok(client)
@@ -260,6 +279,7 @@ where
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("new client created: {}", client_id);
         let old_client_id = client.reload(client_id).await?;
+        client.set_observer(self.observer.clone());
         self.store_client_context(&client).await?;
         self.clients.write().await.remove(&old_client_id);
 
@@ -286,18 +306,30 @@ where
 
         let client_id = client.get_client_id().ok_or(CLIENT_NOT_AUTHORIZED)?;
 
+        client.subscribers().reopen();
         self.clients.write().await.insert(client_id, ctx);
         log::debug!("new client added");
 
         // We need to call any tdlib method to retrieve first response.
         // Otherwise client can't be authorized: no `UpdateAuthorizationState` send by TDLib.
-        first_internal_request(&client.get_tdlib_client(), client_id).await;
+        first_internal_request(&client.get_tdlib_client(), &self.observer, client_id).await;
 
         log::trace!("received first internal response");
 
         Ok(())
     }
 
+    /// Number of requests sent by bound clients and still waiting for TDLib response.
+    /// Constantly growing value usually means that responses are lost.
+    pub fn pending_requests_count(&self) -> usize {
+        self.observer.pending_count()
+    }
+
+    /// Returns how long the oldest pending request has been waiting for TDLib response.
+    pub fn oldest_pending_request_age(&self) -> Option<Duration> {
+        self.observer.oldest_pending_age()
+    }
+
     /// Determines that the worker is running.
     pub fn is_running(&self) -> bool {
         self.run_flag.load(Ordering::Acquire)
@@ -309,6 +341,7 @@ where
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("new client created: {}", client_id);
         client.set_client_id(client_id).unwrap();
+        client.set_observer(self.observer.clone());
 
         let (psx, prx) = mpsc::channel::<ClientState>(5);
         let ctx = ClientContext {
@@ -340,6 +373,7 @@ where
             channels_send_timeout: time::Duration::from_secs_f64(channels_send_timeout),
             auth_state_handler: Arc::new(auth_state_handler),
             clients: Arc::new(RwLock::new(clients)),
+            observer: Arc::new(Observer::new()),
         }
     }
 
@@ -380,6 +414,7 @@ where
         let recv_timeout = self.read_updates_timeout;
         let send_timeout = self.channels_send_timeout;
         let tdlib_client = Arc::new(self.tdlib_client.clone());
+        let observer = self.observer.clone();
 
         tokio::spawn(async move {
             let current = tokio::runtime::Handle::try_current().unwrap();
@@ -391,9 +426,20 @@ where
                     .unwrap()
                 {
                     log::trace!("received json from tdlib: {}", json);
-                    handle_td_resp_received(json.as_str(), &auth_sx, &clients, send_timeout).await;
+                    handle_td_resp_received(
+                        json.as_str(),
+                        &observer,
+                        &auth_sx,
+                        &clients,
+                        send_timeout,
+                    )
+                    .await;
                 }
             }
+            // subscriptions of the clients end with the worker
+            for ctx in clients.read().await.values() {
+                ctx.client().subscribers().close();
+            }
         })
     }
 
@@ -483,6 +529,7 @@ where
 
 async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
     response: &str,
+    observer: &Observer,
     auth_sx: &mpsc::Sender<UpdateAuthorizationState>,
     clients: &RwLock<ClientsMap<S>>,
     send_timeout: Duration,
@@ -490,12 +537,15 @@ async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
     match serde_json::from_str::<serde_json::Value>(response) {
         Err(e) => log::error!("can't deserialize tdlib data: {}", e),
         Ok(t) => {
-            if let Some(t) = OBSERVER.notify(t) {
-                match serde_json::from_value::<Update>(t) {
+            if let Some(t) = observer.notify(t) {
+                match Update::deserialize(&t) {
                     Err(err) => {
                         log::error!("cannot deserialize to update: {err:?}, data: {response:?}")
                     }
                     Ok(update) => {
+                        if let Update::Unknown { type_name, .. } = &update {
+                            log::debug!("update of unknown type received: {}", type_name);
+                        }
                         if let Update::AuthorizationState(auth_state) = update {
                             log::trace!("auth state send: {:?}", auth_state);
                             match auth_sx.send_timeout(auth_state, send_timeout).await {
@@ -515,6 +565,7 @@ async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
                                     )
                                 }
                                 Some(ctx) => {
+                                    ctx.client().subscribers().broadcast(&t, &update);
                                     if let Some(sender) = ctx.client().updates_sender() {
                                         log::trace!("sending update to client");
                                         match sender
@@ -565,10 +616,15 @@ where
     let mut result_state = None;
     let res = match state {
         AuthorizationState::_Default => Ok(()),
+        AuthorizationState::Unknown { type_name, .. } => {
+            log::warn!("unsupported authorization state: {}", type_name);
+            Ok(())
+        }
         AuthorizationState::Closing(_) => Ok(()),
         AuthorizationState::LoggingOut(_) => Ok(()),
         AuthorizationState::Closed(_) => {
             result_state = Some(ClientState::Closed);
+            client.subscribers().close();
             Ok(())
         }
         AuthorizationState::Ready(_) => {
@@ -711,7 +767,11 @@ where
     res
 }
 
-async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: ClientId) {
+async fn first_internal_request<S: TdLibClient>(
+    tdlib_client: &S,
+    observer: &Observer,
+    client_id: ClientId,
+) {
     let req = GetApplicationConfig::builder().build();
     let extra = match req.as_ref().extra().ok_or(Error::Internal(
         "invalid tdlib response type, not have `extra` field",
@@ -722,15 +782,13 @@ async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: Cli
             return;
         }
     };
-    let signal = OBSERVER.subscribe(extra);
+    let signal = observer.subscribe(extra);
     if let Err(err) = tdlib_client.send(client_id, req.as_ref()) {
         log::error!("{}", err);
         return;
     };
 
-    let received = signal.await;
-    OBSERVER.unsubscribe(extra);
-    match received {
+    match signal.await {
         Err(_) => log::error!("receiver already closed"),
         Ok(v) => {
             log::trace!("first internal response: {v}");
@@ -743,11 +801,10 @@ async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: Cli
 
 #[cfg(test)]
 mod tests {
-    use crate::client::tdlib_client::TdLibClient;
-    use crate::client::worker::Worker;
-    use crate::client::Client;
-    use crate::errors::Result;
-    use crate::tdjson;
+    use crate::client::tdlib_client::{ClientId, TdLibClient};
+    use crate::client::worker::WorkerBuilder;
+    use crate::client::ClientBuilder;
+    use crate::errors::{Error, Result};
     use crate::types::{Chats, RFunction, RObject, SearchPublicChats, TdlibParameters};
     use std::time::Duration;
     use tokio::time::timeout;
@@ -769,7 +826,7 @@ mod tests {
     }
 
     impl TdLibClient for MockedRawApi {
-        fn send<Fnc: RFunction>(&self, _client_id: tdjson::ClientId, _fnc: Fnc) -> Result<()> {
+        fn send<Fnc: RFunction>(&self, _client_id: ClientId, _fnc: Fnc) -> Result<()> {
             Ok(())
         }
 
@@ -781,7 +838,7 @@ mod tests {
             unimplemented!()
         }
 
-        fn new_client(&self) -> tdjson::ClientId {
+        fn new_client(&self) -> ClientId {
             1
         }
     }
@@ -789,15 +846,11 @@ mod tests {
     #[tokio::test]
     async fn test_start_and_auth() {
         let mocked_raw_api = MockedRawApi::new();
-        let mut worker = Worker::builder()
-            .with_tdlib_client(mocked_raw_api.clone())
-            .build()
-            .unwrap();
+        let mut worker = WorkerBuilder::new(mocked_raw_api.clone()).build().unwrap();
         let res = timeout(
             Duration::from_millis(50),
             worker.bind_client(
-                Client::builder()
-                    .with_tdlib_client(mocked_raw_api.clone())
+                ClientBuilder::new(mocked_raw_api.clone())
                     .with_tdlib_parameters(TdlibParameters::builder().build())
                     .build()
                     .unwrap(),
@@ -817,8 +870,7 @@ mod tests {
         let res = timeout(
             Duration::from_millis(50),
             worker.bind_client(
-                Client::builder()
-                    .with_tdlib_client(mocked_raw_api.clone())
+                ClientBuilder::new(mocked_raw_api.clone())
                     .with_tdlib_parameters(TdlibParameters::builder().build())
                     .build()
                     .unwrap(),
@@ -855,16 +907,12 @@ mod tests {
         mocked_raw_api.set_to_receive(to_receive);
         log::trace!("chats objects: {:?}", chats_object);
 
-        let mut worker = Worker::builder()
-            .with_tdlib_client(mocked_raw_api.clone())
-            .build()
-            .unwrap();
+        let mut worker = WorkerBuilder::new(mocked_raw_api.clone()).build().unwrap();
         worker.start();
 
         let client = worker
             .set_client(
-                Client::builder()
-                    .with_tdlib_client(mocked_raw_api.clone())
+                ClientBuilder::new(mocked_raw_api.clone())
                     .with_tdlib_parameters(TdlibParameters::builder().build())
                     .build()
                     .unwrap(),
@@ -882,4 +930,40 @@ mod tests {
             Ok(Ok(result)) => assert_eq!(result.chat_ids(), &vec![1, 2, 3]),
         }
     }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let mocked_raw_api = MockedRawApi::new();
+        let mut worker = WorkerBuilder::new(mocked_raw_api.clone()).build().unwrap();
+        worker.start();
+
+        let client = worker
+            .set_client(
+                ClientBuilder::new(mocked_raw_api.clone())
+                    .with_tdlib_parameters(TdlibParameters::builder().build())
+                    .with_request_timeout(Duration::from_secs(10))
+                    .build()
+                    .unwrap(),
+            )
+            .await;
+
+        let search_req = SearchPublicChats::builder().build();
+        match timeout(
+            Duration::from_secs(1),
+            client
+                .with_timeout(Duration::from_millis(50))
+                .search_public_chats(&search_req),
+        )
+        .await
+        {
+            Err(_) => panic!("per-call timeout not applied"),
+            Ok(Err(Error::Timeout { method, extra })) => {
+                assert_eq!(method, "searchPublicChats");
+                assert_eq!(extra, search_req.extra().unwrap());
+            }
+            Ok(v) => panic!("unexpected result: {:?}", v),
+        }
+        // expired request must not stay in worker's observer
+        assert_eq!(worker.pending_requests_count(), 0);
+    }
 }
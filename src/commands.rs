@@ -0,0 +1,586 @@
+//! Parsing of bot commands like `/command@botname arg1 "arg two"`.
+//!
+//! Commands are declared with [bot_commands](crate::bot_commands) macro, which implements [BotCommands] for an enum:
+//! ```
+//! use rust_tdlib::bot_commands;
+//! use rust_tdlib::commands::{BotCommands, Rest};
+//!
+//! bot_commands! {
+//!     #[derive(Debug, PartialEq)]
+//!     pub enum Command {
+//!         #[command("help", "Shows help", lang("ru" = "Показывает справку"))]
+//!         Help,
+//!         #[command("ban", "Bans a user", scopes(AllChatAdministrators))]
+//!         Ban(i64, Option<String>),
+//!         #[command("echo", "Repeats the text")]
+//!         Echo(Rest),
+//!     }
+//! }
+//!
+//! let command = Command::parse("/ban@my_bot 42 \"too many ads\"", "my_bot").unwrap();
+//! assert_eq!(command, Command::Ban(42, Some("too many ads".to_string())));
+//! ```
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::{
+    BotCommand, BotCommandScope, Message, MessageContent, SetCommands, TextEntityType,
+};
+
+/// Error occurred during command parsing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandParseError {
+    /// Text is not a command.
+    NotACommand,
+    /// Command is addressed to another bot, e.g. `/start@other_bot`; it should be ignored.
+    OtherBot(String),
+    UnknownCommand(String),
+    /// Quoted argument is not closed.
+    UnclosedQuote,
+    MissingArgument {
+        position: usize,
+        expected: String,
+        usage: String,
+    },
+    InvalidArgument {
+        position: usize,
+        value: String,
+        expected: String,
+        reason: String,
+        usage: String,
+    },
+    TooManyArguments {
+        usage: String,
+    },
+}
+
+impl CommandParseError {
+    fn with_usage(mut self, command_usage: String) -> Self {
+        match &mut self {
+            CommandParseError::MissingArgument { usage, .. }
+            | CommandParseError::InvalidArgument { usage, .. }
+            | CommandParseError::TooManyArguments { usage } => *usage = command_usage,
+            _ => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for CommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandParseError::NotACommand => write!(f, "not a command"),
+            CommandParseError::OtherBot(username) => {
+                write!(f, "command is addressed to @{}", username)
+            }
+            CommandParseError::UnknownCommand(command) => write!(f, "unknown command /{}", command),
+            CommandParseError::UnclosedQuote => write!(f, "unclosed quote"),
+            CommandParseError::MissingArgument {
+                position,
+                expected,
+                usage,
+            } => write!(
+                f,
+                "missing argument {}: expected {}, usage: {}",
+                position, expected, usage
+            ),
+            CommandParseError::InvalidArgument {
+                position,
+                value,
+                expected,
+                reason,
+                usage,
+            } => write!(
+                f,
+                "invalid argument {} \"{}\": expected {} ({}), usage: {}",
+                position, value, expected, reason, usage
+            ),
+            CommandParseError::TooManyArguments { usage } => {
+                write!(f, "too many arguments, usage: {}", usage)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandParseError {}
+
+/// Arguments of a command, split by whitespaces. Arguments containing whitespaces may be quoted with `"`,
+/// `\"` and `\\` inside quotes are unescaped. Arguments are split as they're consumed,
+/// so [rest](Args::rest) gets the remaining text as it was written.
+#[derive(Debug)]
+pub struct Args<'a> {
+    raw: &'a str,
+    // offset of unconsumed arguments in `raw`
+    offset: usize,
+    consumed: usize,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        Self {
+            raw,
+            offset: 0,
+            consumed: 0,
+        }
+    }
+
+    /// Checks if all arguments are consumed.
+    pub fn is_empty(&self) -> bool {
+        self.raw[self.offset..].trim_start().is_empty()
+    }
+
+    // Splits next argument off, `None` if there are no arguments left.
+    fn next_token(&mut self) -> Result<Option<String>, CommandParseError> {
+        let rest = &self.raw[self.offset..];
+        let mut chars = rest
+            .char_indices()
+            .skip_while(|(_, c)| c.is_whitespace())
+            .peekable();
+        let mut token = String::new();
+        let end = match chars.next() {
+            None => return Ok(None),
+            Some((_, '"')) => {
+                let mut end = None;
+                while let Some((i, c)) = chars.next() {
+                    match c {
+                        '"' => {
+                            end = Some(i + 1);
+                            break;
+                        }
+                        '\\' => match chars.peek() {
+                            Some(&(_, escaped)) if escaped == '"' || escaped == '\\' => {
+                                token.push(escaped);
+                                chars.next();
+                            }
+                            _ => token.push(c),
+                        },
+                        c => token.push(c),
+                    }
+                }
+                end.ok_or(CommandParseError::UnclosedQuote)?
+            }
+            Some((start, c)) => {
+                token.push(c);
+                let mut end = start + c.len_utf8();
+                for (i, c) in chars.take_while(|(_, c)| !c.is_whitespace()) {
+                    token.push(c);
+                    end = i + c.len_utf8();
+                }
+                end
+            }
+        };
+        self.offset += end;
+        self.consumed += 1;
+        Ok(Some(token))
+    }
+
+    /// Parses next argument.
+    pub fn next_parsed<T>(&mut self, expected: &str) -> Result<T, CommandParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let position = self.consumed + 1;
+        let value = self
+            .next_token()?
+            .ok_or_else(|| CommandParseError::MissingArgument {
+                position,
+                expected: expected.to_string(),
+                usage: String::new(),
+            })?;
+        value
+            .parse()
+            .map_err(|e: T::Err| CommandParseError::InvalidArgument {
+                position,
+                value: value.clone(),
+                expected: expected.to_string(),
+                reason: e.to_string(),
+                usage: String::new(),
+            })
+    }
+
+    /// Consumes all remaining arguments and returns them as they were written.
+    pub fn rest(&mut self) -> &'a str {
+        let rest = self.raw[self.offset..].trim();
+        self.offset = self.raw.len();
+        rest
+    }
+
+    fn finish(&self) -> Result<(), CommandParseError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(CommandParseError::TooManyArguments {
+                usage: String::new(),
+            }),
+        }
+    }
+}
+
+/// Type of a command argument.
+pub trait CommandArg: Sized {
+    fn parse_arg(args: &mut Args<'_>) -> Result<Self, CommandParseError>;
+
+    /// Placeholder of the argument in command usage, e.g. `<integer>`.
+    fn placeholder() -> String;
+}
+
+macro_rules! command_arg {
+    ($placeholder:literal: $($t:ty),*) => {
+        $(
+            impl CommandArg for $t {
+                fn parse_arg(args: &mut Args<'_>) -> Result<Self, CommandParseError> {
+                    args.next_parsed($placeholder)
+                }
+
+                fn placeholder() -> String {
+                    format!("<{}>", $placeholder)
+                }
+            }
+        )*
+    };
+}
+
+command_arg!("integer": i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+command_arg!("number": f32, f64);
+command_arg!("text": String);
+command_arg!("character": char);
+command_arg!("true|false": bool);
+
+/// Optional argument, must be the last one or followed by optional ones only.
+impl<T: CommandArg> CommandArg for Option<T> {
+    fn parse_arg(args: &mut Args<'_>) -> Result<Self, CommandParseError> {
+        match args.is_empty() {
+            true => Ok(None),
+            false => T::parse_arg(args).map(Some),
+        }
+    }
+
+    fn placeholder() -> String {
+        format!("[{}]", T::placeholder())
+    }
+}
+
+/// All remaining arguments as they were written, including whitespaces and quotes. Must be the last argument.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Rest(pub String);
+
+impl CommandArg for Rest {
+    fn parse_arg(args: &mut Args<'_>) -> Result<Self, CommandParseError> {
+        Ok(Rest(args.rest().to_string()))
+    }
+
+    fn placeholder() -> String {
+        "<text...>".to_string()
+    }
+}
+
+/// Description of a command, used to generate [BotCommand] list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandInfo {
+    /// Command name, without leading slash.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Descriptions for specific languages: pairs of two-letter language code and description.
+    pub translations: &'static [(&'static str, &'static str)],
+    /// Names of [BotCommandScope] variants the command is visible in, e.g. `botCommandScopeAllGroupChats`.
+    /// Command is visible in all scopes if empty.
+    pub scopes: &'static [&'static str],
+    /// Command usage, e.g. `/ban <integer> [<text>]`.
+    pub usage: String,
+}
+
+impl CommandInfo {
+    /// Description for specified language, falls back to the default one.
+    pub fn description(&self, language_code: &str) -> &'static str {
+        self.translations
+            .iter()
+            .find(|(language, _)| *language == language_code)
+            .map(|(_, description)| *description)
+            .unwrap_or(self.description)
+    }
+
+    pub fn is_visible_in(&self, scope: &BotCommandScope) -> bool {
+        self.scopes.is_empty() || self.scopes.contains(&scope_name(scope))
+    }
+}
+
+fn scope_name(scope: &BotCommandScope) -> &'static str {
+    match scope {
+        BotCommandScope::_Default | BotCommandScope::Unknown { .. } => "",
+        BotCommandScope::AllChatAdministrators(_) => "botCommandScopeAllChatAdministrators",
+        BotCommandScope::AllGroupChats(_) => "botCommandScopeAllGroupChats",
+        BotCommandScope::AllPrivateChats(_) => "botCommandScopeAllPrivateChats",
+        BotCommandScope::Chat(_) => "botCommandScopeChat",
+        BotCommandScope::ChatAdministrators(_) => "botCommandScopeChatAdministrators",
+        BotCommandScope::ChatMember(_) => "botCommandScopeChatMember",
+        BotCommandScope::Default(_) => "botCommandScopeDefault",
+    }
+}
+
+/// Splits command text into command name and its arguments.
+/// Returns [CommandParseError::OtherBot] if the command mentions a bot other than `bot_username`.
+pub fn split_command<'a>(
+    text: &'a str,
+    bot_username: &str,
+) -> Result<(&'a str, &'a str), CommandParseError> {
+    let text = text.trim_start();
+    let text = text
+        .strip_prefix('/')
+        .ok_or(CommandParseError::NotACommand)?;
+    let (command, args) = match text.find(char::is_whitespace) {
+        Some(end) => (&text[..end], text[end..].trim_start()),
+        None => (text, ""),
+    };
+    let (command, mention) = match command.split_once('@') {
+        Some((command, mention)) => (command, Some(mention)),
+        None => (command, None),
+    };
+    if command.is_empty() {
+        return Err(CommandParseError::NotACommand);
+    }
+    let bot_username = bot_username.trim_start_matches('@');
+    match mention {
+        Some(mention)
+            if !bot_username.is_empty() && !mention.eq_ignore_ascii_case(bot_username) =>
+        {
+            Err(CommandParseError::OtherBot(mention.to_string()))
+        }
+        _ => Ok((command, args)),
+    }
+}
+
+/// Set of commands supported by a bot, usually implemented with [bot_commands](crate::bot_commands) macro.
+pub trait BotCommands: Sized {
+    /// Descriptions of all commands.
+    fn commands() -> Vec<CommandInfo>;
+
+    /// Builds a command from its name (without leading slash and bot username) and arguments.
+    fn from_args(command: &str, args: &mut Args<'_>) -> Result<Self, CommandParseError>;
+
+    /// Parses text like `/command@botname arg1 "arg two"`. `bot_username` is username of the bot itself,
+    /// it may be obtained with [fetch_bot_username]; commands addressed to other bots are rejected.
+    fn parse(text: &str, bot_username: &str) -> Result<Self, CommandParseError> {
+        let (command, raw_args) = split_command(text, bot_username)?;
+        let usage = || {
+            Self::commands()
+                .into_iter()
+                .find(|info| info.name.eq_ignore_ascii_case(command))
+                .map(|info| info.usage)
+                .unwrap_or_default()
+        };
+        let mut args = Args::new(raw_args);
+        let parsed = Self::from_args(command, &mut args).map_err(|e| e.with_usage(usage()))?;
+        args.finish().map_err(|e| e.with_usage(usage()))?;
+        Ok(parsed)
+    }
+
+    /// Parses text message starting with a bot command entity.
+    fn parse_message(message: &Message, bot_username: &str) -> Result<Self, CommandParseError> {
+        let text = match message.content() {
+            MessageContent::MessageText(content) => content.text(),
+            _ => return Err(CommandParseError::NotACommand),
+        };
+        let starts_with_command = text
+            .entities()
+            .iter()
+            .any(|e| e.offset() == 0 && matches!(e.type_(), TextEntityType::BotCommand(_)));
+        if !starts_with_command {
+            return Err(CommandParseError::NotACommand);
+        }
+        Self::parse(text.text(), bot_username)
+    }
+
+    /// Commands visible in the scope, with descriptions for the language (empty string for the default one).
+    fn bot_commands(scope: &BotCommandScope, language_code: &str) -> Vec<BotCommand> {
+        Self::commands()
+            .iter()
+            .filter(|info| info.is_visible_in(scope))
+            .map(|info| {
+                BotCommand::builder()
+                    .command(info.name)
+                    .description(info.description(language_code))
+                    .build()
+            })
+            .collect()
+    }
+
+    /// Request which sets the list of commands for the scope and the language, see [bot_commands](BotCommands::bot_commands).
+    fn set_commands(scope: BotCommandScope, language_code: &str) -> SetCommands {
+        let commands = Self::bot_commands(&scope, language_code);
+        SetCommands::builder()
+            .scope(scope)
+            .language_code(language_code)
+            .commands(commands)
+            .build()
+    }
+}
+
+/// Returns username of the client's own user, to be passed to [BotCommands::parse].
+#[cfg(feature = "client-core")]
+pub async fn fetch_bot_username<R>(
+    client: &crate::client::Client<R>,
+) -> crate::errors::Result<String>
+where
+    R: crate::client::tdlib_client::TdLibClient + Clone,
+{
+    let me = client
+        .get_me(crate::types::GetMe::builder().build())
+        .await?;
+    Ok(me.username().clone())
+}
+
+/// Declares an enum of bot commands and implements [BotCommands](crate::commands::BotCommands) for it.
+///
+/// Every variant has `#[command(name, description)]` attribute, optionally followed by
+/// `scopes(...)` with [BotCommandScope](crate::types::BotCommandScope) variants the command is visible in
+/// and `lang("code" = "description", ...)` with translated descriptions.
+/// Variant fields are command arguments, their types must implement [CommandArg](crate::commands::CommandArg).
+/// See [commands](crate::commands) module for an example.
+#[macro_export]
+macro_rules! bot_commands {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                #[command(
+                    $command:literal, $description:literal
+                    $(, scopes($($scope:ident),* $(,)?))?
+                    $(, lang($($language:literal = $translation:literal),* $(,)?))?
+                    $(,)?
+                )]
+                $variant:ident $(($($arg:ty),* $(,)?))?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($variant $(($($arg),*))?),*
+        }
+
+        impl $crate::commands::BotCommands for $name {
+            fn commands() -> Vec<$crate::commands::CommandInfo> {
+                vec![$(
+                    $crate::commands::CommandInfo {
+                        name: $command,
+                        description: $description,
+                        translations: &[$($(($language, $translation)),*)?],
+                        scopes: &[$($(concat!("botCommandScope", stringify!($scope))),*)?],
+                        usage: {
+                            #[allow(unused_mut)]
+                            let mut usage = String::from(concat!("/", $command));
+                            $($(
+                                usage.push(' ');
+                                usage.push_str(&<$arg as $crate::commands::CommandArg>::placeholder());
+                            )*)?
+                            usage
+                        },
+                    }
+                ),*]
+            }
+
+            fn from_args(
+                command: &str,
+                #[allow(unused_variables)] args: &mut $crate::commands::Args<'_>,
+            ) -> Result<Self, $crate::commands::CommandParseError> {
+                $(
+                    if command.eq_ignore_ascii_case($command) {
+                        return Ok($name::$variant $(($(
+                            <$arg as $crate::commands::CommandArg>::parse_arg(args)?
+                        ),*))?);
+                    }
+                )*
+                Err($crate::commands::CommandParseError::UnknownCommand(command.to_string()))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BotCommands, CommandParseError, Rest};
+    use crate::types::{BotCommandScope, BotCommandScopeAllGroupChats, BotCommandScopeDefault};
+
+    bot_commands! {
+        #[derive(Debug, PartialEq)]
+        enum Command {
+            #[command("help", "Shows help", lang("ru" = "Справка"))]
+            Help,
+            #[command("ban", "Bans a user", scopes(AllGroupChats))]
+            Ban(i64, Option<String>),
+            #[command("echo", "Repeats the text")]
+            Echo(Rest),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Command::parse("/help", "bot"), Ok(Command::Help));
+        assert_eq!(Command::parse("/HELP@Bot", "@bot"), Ok(Command::Help));
+        assert_eq!(
+            Command::parse("/ban 42 \"too \\\"many\\\" ads\"", "bot"),
+            Ok(Command::Ban(42, Some("too \"many\" ads".to_string())))
+        );
+        assert_eq!(Command::parse("/ban 42", "bot"), Ok(Command::Ban(42, None)));
+        assert_eq!(
+            Command::parse("/echo  hello   \"world\" ", "bot"),
+            Ok(Command::Echo(Rest("hello   \"world\"".to_string())))
+        );
+        assert_eq!(
+            Command::parse("/echo \"hello world", "bot"),
+            Ok(Command::Echo(Rest("\"hello world".to_string())))
+        );
+        assert_eq!(
+            Command::parse("/help@other_bot", "bot"),
+            Err(CommandParseError::OtherBot("other_bot".to_string()))
+        );
+        assert_eq!(
+            Command::parse("help", "bot"),
+            Err(CommandParseError::NotACommand)
+        );
+        assert_eq!(
+            Command::parse("/kick", "bot"),
+            Err(CommandParseError::UnknownCommand("kick".to_string()))
+        );
+        assert_eq!(
+            Command::parse("/unknown \"x", "bot"),
+            Err(CommandParseError::UnknownCommand("unknown".to_string()))
+        );
+        assert_eq!(
+            Command::parse("/ban \"42", "bot"),
+            Err(CommandParseError::UnclosedQuote)
+        );
+        assert_eq!(
+            Command::parse("/ban", "bot").unwrap_err().to_string(),
+            "missing argument 1: expected integer, usage: /ban <integer> [<text>]"
+        );
+        assert_eq!(
+            Command::parse("/ban me", "bot").unwrap_err().to_string(),
+            "invalid argument 1 \"me\": expected integer (invalid digit found in string), usage: /ban <integer> [<text>]"
+        );
+        assert_eq!(
+            Command::parse("/help me", "bot").unwrap_err().to_string(),
+            "too many arguments, usage: /help"
+        );
+    }
+
+    #[test]
+    fn test_bot_commands() {
+        let default_scope = BotCommandScope::Default(BotCommandScopeDefault::builder().build());
+        let commands = Command::bot_commands(&default_scope, "ru");
+        let commands: Vec<_> = commands
+            .iter()
+            .map(|c| (c.command().as_str(), c.description().as_str()))
+            .collect();
+        assert_eq!(
+            commands,
+            vec![("help", "Справка"), ("echo", "Repeats the text")]
+        );
+
+        let group_scope =
+            BotCommandScope::AllGroupChats(BotCommandScopeAllGroupChats::builder().build());
+        let request = Command::set_commands(group_scope, "en");
+        assert_eq!(request.commands().len(), 3);
+        assert_eq!(request.commands()[0].description(), "Shows help");
+        assert_eq!(request.language_code(), "en");
+    }
+}
@@ -1,7 +1,86 @@
-use std::{error, fmt, io};
+use std::{error, fmt, io, time::Duration};
 
 pub type TDLibError = crate::types::Error;
 
+/// Parsed kind of [TDLibError], based on its code and message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TDLibErrorKind {
+    /// Too many requests, the request may be repeated after specified time (`429: Too Many Requests: retry after N`, `FLOOD_WAIT_N`, `SLOWMODE_WAIT_N`).
+    FloodWait(Duration),
+    /// Request must be repeated in another datacenter (`303: PHONE_MIGRATE_N` and so on).
+    Migrate(Option<i32>),
+    /// Request failed because of connection problems or server timeout.
+    Network,
+    /// Invalid request parameters (`400`), for example `PHONE_CODE_INVALID`.
+    InvalidArgument,
+    /// Client is not authorized or authorization was revoked (`401`).
+    Unauthorized,
+    /// Not enough rights (`403`), for example `CHAT_WRITE_FORBIDDEN`.
+    Forbidden,
+    /// Requested object not found (`404`).
+    NotFound,
+    /// Request can't be handled now and shouldn't be repeated (`406`).
+    NotAcceptable,
+    /// Request aborted because TDLib client was closed.
+    Aborted,
+    /// Internal TDLib or server error (`500`).
+    Internal,
+    /// Error with unknown code.
+    Other,
+}
+
+impl TDLibErrorKind {
+    /// Determines whether the request may succeed if it is repeated later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            TDLibErrorKind::FloodWait(_)
+                | TDLibErrorKind::Migrate(_)
+                | TDLibErrorKind::Network
+                | TDLibErrorKind::Internal
+        )
+    }
+}
+
+impl TDLibError {
+    /// Classifies the error by its code and message.
+    pub fn kind(&self) -> TDLibErrorKind {
+        let message = self.message().as_str();
+        if let Some(seconds) = parse_retry_after(message) {
+            return TDLibErrorKind::FloodWait(Duration::from_secs(seconds));
+        }
+        match self.code() {
+            303 => TDLibErrorKind::Migrate(
+                message
+                    .rsplit('_')
+                    .next()
+                    .and_then(|dc_id| dc_id.parse().ok()),
+            ),
+            400 => TDLibErrorKind::InvalidArgument,
+            401 => TDLibErrorKind::Unauthorized,
+            403 => TDLibErrorKind::Forbidden,
+            404 => TDLibErrorKind::NotFound,
+            406 => TDLibErrorKind::NotAcceptable,
+            420 | 429 => TDLibErrorKind::FloodWait(Duration::default()),
+            500 if message == "Request aborted" => TDLibErrorKind::Aborted,
+            500 => TDLibErrorKind::Internal,
+            502..=504 | -504..=-500 => TDLibErrorKind::Network,
+            _ => TDLibErrorKind::Other,
+        }
+    }
+}
+
+// Extracts seconds from "Too Many Requests: retry after 30", "FLOOD_WAIT_30" or "SLOWMODE_WAIT_30".
+fn parse_retry_after(message: &str) -> Option<u64> {
+    if let Some(seconds) = message.strip_prefix("Too Many Requests: retry after ") {
+        return seconds.trim().parse().ok();
+    }
+    ["FLOOD_WAIT_", "SLOWMODE_WAIT_"]
+        .iter()
+        .find_map(|prefix| message.strip_prefix(prefix))
+        .and_then(|seconds| seconds.parse().ok())
+}
+
 #[derive(Debug)]
 pub enum Error {
     Io(io::Error),
@@ -9,6 +88,22 @@ pub enum Error {
     TDLibError(TDLibError),
     Internal(&'static str),
     BadRequest(&'static str),
+    /// `libtdjson` can't be loaded in runtime.
+    #[cfg(feature = "dynamic-tdjson")]
+    TdJsonLoad(crate::tdjson_dynamic::LoadError),
+    /// TDLib did not answer the request within configured timeout.
+    Timeout {
+        method: String,
+        extra: String,
+    },
+    /// Message was accepted by TDLib, but then failed to be sent (`updateMessageSendFailed`).
+    MessageSendFailed {
+        /// Failed message, with its final identifier and `messageSendingStateFailed` sending state.
+        message: Box<crate::types::Message>,
+        /// Temporary identifier the message had while it was being sent.
+        old_message_id: i64,
+        error: TDLibError,
+    },
 }
 
 #[deprecated]
@@ -29,7 +124,7 @@ impl fmt::Display for Error {
                 write!(f, "{}", err)
             }
             Error::TDLibError(err) => {
-                write!(f, "{:?}", err)
+                write!(f, "TDLib error {}: {}", err.code(), err.message())
             }
             Error::Internal(err) => {
                 write!(f, "{}", err)
@@ -37,6 +132,56 @@ impl fmt::Display for Error {
             Error::BadRequest(err) => {
                 write!(f, "{}", err)
             }
+            #[cfg(feature = "dynamic-tdjson")]
+            Error::TdJsonLoad(err) => {
+                write!(f, "{}", err)
+            }
+            Error::Timeout { method, extra } => {
+                write!(f, "request {} ({}) timed out", method, extra)
+            }
+            Error::MessageSendFailed {
+                old_message_id,
+                error,
+                ..
+            } => {
+                write!(
+                    f,
+                    "message {} was not sent: TDLib error {}: {}",
+                    old_message_id,
+                    error.code(),
+                    error.message()
+                )
+            }
+        }
+    }
+}
+
+impl Error {
+    /// Returns parsed kind of the error if it was returned by TDLib.
+    pub fn tdlib_error_kind(&self) -> Option<TDLibErrorKind> {
+        match self {
+            Error::TDLibError(err) | Error::MessageSendFailed { error: err, .. } => {
+                Some(err.kind())
+            }
+            _ => None,
+        }
+    }
+
+    /// Determines whether the failed request may succeed if it is repeated later.
+    pub fn is_retryable(&self) -> bool {
+        match self.tdlib_error_kind() {
+            Some(kind) => kind.is_retryable(),
+            None => matches!(self, Error::Timeout { .. }),
+        }
+    }
+
+    /// Time to wait before the request can be repeated, if TDLib specified it.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self.tdlib_error_kind() {
+            Some(TDLibErrorKind::FloodWait(retry_after)) if !retry_after.is_zero() => {
+                Some(retry_after)
+            }
+            _ => None,
         }
     }
 }
@@ -49,6 +194,10 @@ impl error::Error for Error {
             Error::Internal(_) => None,
             Error::TDLibError(_) => None,
             Error::BadRequest(_) => None,
+            #[cfg(feature = "dynamic-tdjson")]
+            Error::TdJsonLoad(ref err) => Some(err),
+            Error::Timeout { .. } => None,
+            Error::MessageSendFailed { .. } => None,
         }
     }
 }
@@ -65,10 +214,17 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "dynamic-tdjson")]
+impl From<crate::tdjson_dynamic::LoadError> for Error {
+    fn from(err: crate::tdjson_dynamic::LoadError) -> Error {
+        Error::TdJsonLoad(err)
+    }
+}
+
 const CLOSED_CHANNEL_ERROR: Error = Error::Internal("channel closed");
 const SEND_TO_CHANNEL_TIMEOUT: Error = Error::Internal("timeout for mpsc occurred");
 
-#[cfg(feature = "client")]
+#[cfg(feature = "client-core")]
 impl<T> From<tokio::sync::mpsc::error::SendTimeoutError<T>> for Error {
     fn from(err: tokio::sync::mpsc::error::SendTimeoutError<T>) -> Self {
         match err {
@@ -77,3 +233,72 @@ impl<T> From<tokio::sync::mpsc::error::SendTimeoutError<T>> for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, TDLibError, TDLibErrorKind};
+    use std::time::Duration;
+
+    fn tdlib_error(code: i32, message: &str) -> Error {
+        Error::TDLibError(TDLibError::builder().code(code).message(message).build())
+    }
+
+    #[test]
+    fn test_tdlib_error_kind() {
+        let cases = [
+            (
+                429,
+                "Too Many Requests: retry after 30",
+                TDLibErrorKind::FloodWait(Duration::from_secs(30)),
+            ),
+            (
+                420,
+                "FLOOD_WAIT_5",
+                TDLibErrorKind::FloodWait(Duration::from_secs(5)),
+            ),
+            (
+                400,
+                "SLOWMODE_WAIT_10",
+                TDLibErrorKind::FloodWait(Duration::from_secs(10)),
+            ),
+            (303, "PHONE_MIGRATE_2", TDLibErrorKind::Migrate(Some(2))),
+            (400, "PHONE_CODE_INVALID", TDLibErrorKind::InvalidArgument),
+            (401, "Unauthorized", TDLibErrorKind::Unauthorized),
+            (403, "CHAT_WRITE_FORBIDDEN", TDLibErrorKind::Forbidden),
+            (404, "Not Found", TDLibErrorKind::NotFound),
+            (500, "Request aborted", TDLibErrorKind::Aborted),
+            (-503, "Timeout", TDLibErrorKind::Network),
+            (1, "unknown", TDLibErrorKind::Other),
+        ];
+        for (code, message, kind) in cases {
+            assert_eq!(tdlib_error(code, message).tdlib_error_kind(), Some(kind));
+        }
+    }
+
+    #[test]
+    fn test_retry() {
+        let flood_wait = tdlib_error(429, "Too Many Requests: retry after 3");
+        assert!(flood_wait.is_retryable());
+        assert_eq!(flood_wait.retry_after(), Some(Duration::from_secs(3)));
+
+        let forbidden = tdlib_error(403, "CHAT_WRITE_FORBIDDEN");
+        assert!(!forbidden.is_retryable());
+        assert_eq!(forbidden.retry_after(), None);
+        assert_eq!(
+            forbidden.to_string(),
+            "TDLib error 403: CHAT_WRITE_FORBIDDEN"
+        );
+
+        let send_failed = Error::MessageSendFailed {
+            message: Box::new(crate::types::Message::builder().build()),
+            old_message_id: 1,
+            error: TDLibError::builder().code(500).message("Internal").build(),
+        };
+        assert!(send_failed.is_retryable());
+        let timeout = Error::Timeout {
+            method: "sendMessage".to_string(),
+            extra: String::new(),
+        };
+        assert!(timeout.is_retryable());
+    }
+}
@@ -0,0 +1,975 @@
+//! Conversion of [FormattedText] to and from MarkdownV2 and HTML markup, as described in
+//! [formatting options](https://core.telegram.org/bots/api#formatting-options), without `parseTextEntities` requests.
+//!
+//! Only entities which can be expressed with markup are rendered: bold, italic, underline, strikethrough, code, pre,
+//! text URLs and mentions by name (`tg://user?id=...` links). The rest, like URLs or hashtags, are detected by Telegram,
+//! so they're rendered as plain text. Overlapping entities are split to nest properly, entities inside code are dropped.
+//! Spoilers are accepted by parsers, but dropped: TDLib schema of this version has no entity type for them.
+//!
+//! Offsets and lengths of entities are measured in UTF-16 code units, see [utf16_to_byte_offset].
+//! ```
+//! use rust_tdlib::formatting::{from_markdown_v2, to_html};
+//!
+//! let text = from_markdown_v2("*bold _italic bold_* [link](https://example.com)").unwrap();
+//! assert_eq!(text.text(), "bold italic bold link");
+//! assert_eq!(
+//!     to_html(&text),
+//!     "<b>bold <i>italic bold</i></b> <a href=\"https://example.com\">link</a>"
+//! );
+//! ```
+use std::cmp::Reverse;
+use std::convert::TryFrom;
+use std::{error, fmt};
+
+use crate::types::{
+    FormattedText, TextEntity, TextEntityType, TextEntityTypeBold, TextEntityTypeCode,
+    TextEntityTypeItalic, TextEntityTypeMentionName, TextEntityTypePre, TextEntityTypePreCode,
+    TextEntityTypeStrikethrough, TextEntityTypeTextUrl, TextEntityTypeUnderline,
+};
+
+const MENTION_URL_PREFIX: &str = "tg://user?id=";
+
+// Characters which must be escaped in MarkdownV2 outside of code.
+const MARKDOWN_V2_RESERVED: &str = "_*[]()~`>#+-=|{}.!\\";
+
+/// Error of markup parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupError {
+    /// Byte offset of the error in the markup.
+    pub position: usize,
+    pub message: String,
+}
+
+impl MarkupError {
+    fn new<S: Into<String>>(position: usize, message: S) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl error::Error for MarkupError {}
+
+/// Length of the text in UTF-16 code units, which are units of [TextEntity] offsets and lengths.
+pub fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+/// Converts UTF-16 offset in the text to byte offset. Returns `None` if the offset is out of the text
+/// or points inside of a character.
+pub fn utf16_to_byte_offset(text: &str, offset: usize) -> Option<usize> {
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in text.char_indices() {
+        if utf16_offset == offset {
+            return Some(byte_offset);
+        }
+        if utf16_offset > offset {
+            return None;
+        }
+        utf16_offset += c.len_utf16();
+    }
+    if utf16_offset == offset {
+        Some(text.len())
+    } else {
+        None
+    }
+}
+
+/// Part of the text covered by the entity.
+pub fn entity_text<'a>(text: &'a FormattedText, entity: &TextEntity) -> Option<&'a str> {
+    let start = usize::try_from(entity.offset()).ok()?;
+    let end = start.checked_add(usize::try_from(entity.length()).ok()?)?;
+    let start = utf16_to_byte_offset(text.text(), start)?;
+    let end = utf16_to_byte_offset(text.text(), end)?;
+    text.text().get(start..end)
+}
+
+// Entity which can be expressed with markup.
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Code,
+    /// Pre-formatted code block with its language, empty if not specified.
+    Pre(String),
+    TextUrl(String),
+    MentionName(i64),
+}
+
+impl Kind {
+    fn from_entity_type(type_: &TextEntityType) -> Option<Self> {
+        Some(match type_ {
+            TextEntityType::Bold(_) => Kind::Bold,
+            TextEntityType::Italic(_) => Kind::Italic,
+            TextEntityType::Underline(_) => Kind::Underline,
+            TextEntityType::Strikethrough(_) => Kind::Strikethrough,
+            TextEntityType::Code(_) => Kind::Code,
+            TextEntityType::Pre(_) => Kind::Pre(String::new()),
+            TextEntityType::PreCode(pre) => Kind::Pre(pre.language().clone()),
+            TextEntityType::TextUrl(url) => Kind::TextUrl(url.url().clone()),
+            TextEntityType::MentionName(mention) => Kind::MentionName(mention.user_id()),
+            _ => return None,
+        })
+    }
+
+    fn from_url(url: String) -> Self {
+        match url
+            .strip_prefix(MENTION_URL_PREFIX)
+            .and_then(|user_id| user_id.parse().ok())
+        {
+            Some(user_id) => Kind::MentionName(user_id),
+            None => Kind::TextUrl(url),
+        }
+    }
+
+    fn into_entity_type(self) -> TextEntityType {
+        match self {
+            Kind::Bold => TextEntityType::Bold(TextEntityTypeBold::builder().build()),
+            Kind::Italic => TextEntityType::Italic(TextEntityTypeItalic::builder().build()),
+            Kind::Underline => {
+                TextEntityType::Underline(TextEntityTypeUnderline::builder().build())
+            }
+            Kind::Strikethrough => {
+                TextEntityType::Strikethrough(TextEntityTypeStrikethrough::builder().build())
+            }
+            Kind::Code => TextEntityType::Code(TextEntityTypeCode::builder().build()),
+            Kind::Pre(language) if language.is_empty() => {
+                TextEntityType::Pre(TextEntityTypePre::builder().build())
+            }
+            Kind::Pre(language) => {
+                TextEntityType::PreCode(TextEntityTypePreCode::builder().language(language).build())
+            }
+            Kind::TextUrl(url) => {
+                TextEntityType::TextUrl(TextEntityTypeTextUrl::builder().url(url).build())
+            }
+            Kind::MentionName(user_id) => TextEntityType::MentionName(
+                TextEntityTypeMentionName::builder()
+                    .user_id(user_id)
+                    .build(),
+            ),
+        }
+    }
+
+    fn is_code(&self) -> bool {
+        matches!(self, Kind::Code | Kind::Pre(_))
+    }
+
+    fn url(&self) -> Option<String> {
+        match self {
+            Kind::TextUrl(url) => Some(url.clone()),
+            Kind::MentionName(user_id) => Some(format!("{}{}", MENTION_URL_PREFIX, user_id)),
+            _ => None,
+        }
+    }
+}
+
+// Entity with UTF-16 range `start..end`.
+#[derive(Debug, Clone)]
+struct Span {
+    start: usize,
+    end: usize,
+    kind: Kind,
+}
+
+// Renderable entities of the text, outer ones first.
+fn spans(text: &FormattedText) -> Vec<Span> {
+    let len = utf16_len(text.text());
+    let mut spans: Vec<Span> = text
+        .entities()
+        .iter()
+        .filter_map(|entity| {
+            let kind = Kind::from_entity_type(entity.type_())?;
+            let start = usize::try_from(entity.offset()).ok()?;
+            let end = start
+                .checked_add(usize::try_from(entity.length()).ok()?)?
+                .min(len);
+            if start < end {
+                Some(Span { start, end, kind })
+            } else {
+                None
+            }
+        })
+        .collect();
+    spans.sort_by_key(|span| (span.start, Reverse(span.end)));
+    let mut code: Vec<(usize, usize)> = Vec::new();
+    spans.retain(|span| {
+        if code
+            .iter()
+            .any(|&(start, end)| span.start >= start && span.start < end)
+        {
+            return false;
+        }
+        if span.kind.is_code() {
+            code.push((span.start, span.end));
+        }
+        true
+    });
+    spans
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Markup {
+    MarkdownV2,
+    Html,
+}
+
+struct Writer {
+    markup: Markup,
+    out: String,
+    // MarkdownV2 output ends with a marker ending with `_`, so `_` of the next marker would be ambiguous
+    after_underscore: bool,
+}
+
+impl Writer {
+    fn text(&mut self, text: &str, in_code: bool) {
+        if text.is_empty() {
+            return;
+        }
+        self.after_underscore = false;
+        for c in text.chars() {
+            match self.markup {
+                Markup::MarkdownV2 => {
+                    let reserved = if in_code {
+                        c == '`' || c == '\\'
+                    } else {
+                        MARKDOWN_V2_RESERVED.contains(c)
+                    };
+                    if reserved {
+                        self.out.push('\\');
+                    }
+                    self.out.push(c);
+                }
+                Markup::Html => push_html_escaped(&mut self.out, c),
+            }
+        }
+    }
+
+    fn marker(&mut self, marker: &str) {
+        if self.after_underscore && marker.starts_with('_') {
+            // Telegram ignores `\r`, it only separates `_` and `__` markers
+            self.out.push('\r');
+        }
+        self.out.push_str(marker);
+        self.after_underscore = marker.ends_with('_');
+    }
+
+    fn open(&mut self, kind: &Kind) {
+        match self.markup {
+            Markup::MarkdownV2 => match kind {
+                Kind::Bold => self.marker("*"),
+                Kind::Italic => self.marker("_"),
+                Kind::Underline => self.marker("__"),
+                Kind::Strikethrough => self.marker("~"),
+                Kind::Code => self.marker("`"),
+                Kind::Pre(language) => self.marker(&format!("```{}\n", language)),
+                Kind::TextUrl(_) | Kind::MentionName(_) => self.marker("["),
+            },
+            Markup::Html => {
+                let tag = match kind {
+                    Kind::Bold => "<b>".to_string(),
+                    Kind::Italic => "<i>".to_string(),
+                    Kind::Underline => "<u>".to_string(),
+                    Kind::Strikethrough => "<s>".to_string(),
+                    Kind::Code => "<code>".to_string(),
+                    Kind::Pre(language) if language.is_empty() => "<pre>".to_string(),
+                    Kind::Pre(language) => {
+                        let mut tag = "<pre><code class=\"language-".to_string();
+                        language
+                            .chars()
+                            .for_each(|c| push_html_escaped(&mut tag, c));
+                        tag.push_str("\">");
+                        tag
+                    }
+                    Kind::TextUrl(_) | Kind::MentionName(_) => {
+                        let mut tag = "<a href=\"".to_string();
+                        let url = kind.url().unwrap_or_default();
+                        url.chars().for_each(|c| push_html_escaped(&mut tag, c));
+                        tag.push_str("\">");
+                        tag
+                    }
+                };
+                self.out.push_str(&tag);
+            }
+        }
+    }
+
+    fn close(&mut self, kind: &Kind) {
+        match self.markup {
+            Markup::MarkdownV2 => match kind {
+                Kind::Bold => self.marker("*"),
+                Kind::Italic => self.marker("_"),
+                Kind::Underline => self.marker("__"),
+                Kind::Strikethrough => self.marker("~"),
+                Kind::Code => self.marker("`"),
+                Kind::Pre(_) => self.marker("```"),
+                Kind::TextUrl(_) | Kind::MentionName(_) => {
+                    let mut link = "](".to_string();
+                    for c in kind.url().unwrap_or_default().chars() {
+                        if c == ')' || c == '\\' {
+                            link.push('\\');
+                        }
+                        link.push(c);
+                    }
+                    link.push(')');
+                    self.marker(&link);
+                }
+            },
+            Markup::Html => self.out.push_str(match kind {
+                Kind::Bold => "</b>",
+                Kind::Italic => "</i>",
+                Kind::Underline => "</u>",
+                Kind::Strikethrough => "</s>",
+                Kind::Code => "</code>",
+                Kind::Pre(language) if language.is_empty() => "</pre>",
+                Kind::Pre(_) => "</code></pre>",
+                Kind::TextUrl(_) | Kind::MentionName(_) => "</a>",
+            }),
+        }
+    }
+}
+
+fn push_html_escaped(out: &mut String, c: char) {
+    match c {
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '&' => out.push_str("&amp;"),
+        '"' => out.push_str("&quot;"),
+        c => out.push(c),
+    }
+}
+
+fn render(text: &FormattedText, markup: Markup) -> String {
+    let spans = spans(text);
+    let mut points: Vec<usize> = spans.iter().flat_map(|s| [s.start, s.end]).collect();
+    points.sort_unstable();
+    points.dedup();
+
+    let mut writer = Writer {
+        markup,
+        out: String::with_capacity(text.text().len()),
+        after_underscore: false,
+    };
+    // indices of open spans, outer ones first
+    let mut stack: Vec<usize> = Vec::new();
+    let transition = |writer: &mut Writer, stack: &mut Vec<usize>, position: usize| {
+        let active: Vec<usize> = (0..spans.len())
+            .filter(|&i| spans[i].start <= position && position < spans[i].end)
+            .collect();
+        let common = stack
+            .iter()
+            .zip(active.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        for &i in stack[common..].iter().rev() {
+            writer.close(&spans[i].kind);
+        }
+        for &i in &active[common..] {
+            writer.open(&spans[i].kind);
+        }
+        *stack = active;
+    };
+    let in_code = |stack: &[usize]| stack.iter().any(|&i| spans[i].kind.is_code());
+
+    let source = text.text();
+    let mut next_point = 0;
+    let mut position = 0;
+    let mut segment_start = 0;
+    for (byte_offset, c) in source.char_indices() {
+        if next_point < points.len() && points[next_point] <= position {
+            writer.text(&source[segment_start..byte_offset], in_code(&stack));
+            segment_start = byte_offset;
+            while next_point < points.len() && points[next_point] <= position {
+                next_point += 1;
+            }
+            transition(&mut writer, &mut stack, position);
+        }
+        position += c.len_utf16();
+    }
+    writer.text(&source[segment_start..], in_code(&stack));
+    transition(&mut writer, &mut stack, usize::MAX);
+    writer.out
+}
+
+/// Renders the text as MarkdownV2.
+pub fn to_markdown_v2(text: &FormattedText) -> String {
+    render(text, Markup::MarkdownV2)
+}
+
+/// Renders the text as HTML.
+pub fn to_html(text: &FormattedText) -> String {
+    render(text, Markup::Html)
+}
+
+// Collects text and entities of parsed markup.
+#[derive(Default)]
+struct Builder {
+    text: String,
+    len: usize,
+    // entities with order of their opening
+    spans: Vec<(usize, Span)>,
+    opened: usize,
+}
+
+impl Builder {
+    fn push(&mut self, c: char) {
+        self.text.push(c);
+        self.len += c.len_utf16();
+    }
+
+    // Returns order of an entity opened at current position, to keep outer entities first.
+    fn open(&mut self) -> usize {
+        self.opened += 1;
+        self.opened
+    }
+
+    fn close(&mut self, order: usize, start: usize, kind: Option<Kind>) {
+        if let Some(kind) = kind {
+            if start < self.len {
+                let span = Span {
+                    start,
+                    end: self.len,
+                    kind,
+                };
+                self.spans.push((order, span));
+            }
+        }
+    }
+
+    fn build(mut self) -> FormattedText {
+        self.spans
+            .sort_by_key(|(order, span)| (span.start, Reverse(span.end), *order));
+        let entities: Vec<TextEntity> = self
+            .spans
+            .into_iter()
+            .map(|(_, span)| {
+                TextEntity::builder()
+                    .offset(span.start as i32)
+                    .length((span.end - span.start) as i32)
+                    .type_(span.kind.into_entity_type())
+                    .build()
+            })
+            .collect();
+        FormattedText::builder()
+            .text(self.text)
+            .entities(entities)
+            .build()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Link,
+}
+
+impl Marker {
+    fn kind(self) -> Option<Kind> {
+        match self {
+            Marker::Bold => Some(Kind::Bold),
+            Marker::Italic => Some(Kind::Italic),
+            Marker::Underline => Some(Kind::Underline),
+            Marker::Strikethrough => Some(Kind::Strikethrough),
+            Marker::Spoiler | Marker::Link => None,
+        }
+    }
+}
+
+struct OpenMarker {
+    marker: Marker,
+    start: usize,
+    order: usize,
+    position: usize,
+}
+
+/// Parses MarkdownV2 markup.
+pub fn from_markdown_v2(markup: &str) -> Result<FormattedText, MarkupError> {
+    let mut builder = Builder::default();
+    let mut open: Vec<OpenMarker> = Vec::new();
+    let mut chars = markup.char_indices().peekable();
+    let mut after_underscore = false;
+    while let Some((position, c)) = chars.next() {
+        let was_after_underscore = std::mem::replace(&mut after_underscore, false);
+        let marker = match c {
+            '\\' => {
+                match chars.next() {
+                    Some((_, escaped)) => builder.push(escaped),
+                    None => return Err(MarkupError::new(position, "unfinished escape sequence")),
+                }
+                continue;
+            }
+            '\r' if was_after_underscore && matches!(chars.peek(), Some((_, '_'))) => continue,
+            '*' => Marker::Bold,
+            '_' => {
+                after_underscore = true;
+                match chars.next_if(|(_, c)| *c == '_') {
+                    Some(_) => Marker::Underline,
+                    None => Marker::Italic,
+                }
+            }
+            '~' => Marker::Strikethrough,
+            '|' if chars.next_if(|(_, c)| *c == '|').is_some() => Marker::Spoiler,
+            '`' => {
+                let start = builder.len;
+                let order = builder.open();
+                let kind = if chars.next_if(|(_, c)| *c == '`').is_some() {
+                    if chars.next_if(|(_, c)| *c == '`').is_none() {
+                        // "``" is an empty inline code
+                        continue;
+                    }
+                    let language = parse_pre_language(&mut chars);
+                    parse_code(&mut chars, &mut builder, position, "```")?;
+                    Kind::Pre(language)
+                } else {
+                    parse_code(&mut chars, &mut builder, position, "`")?;
+                    Kind::Code
+                };
+                builder.close(order, start, Some(kind));
+                continue;
+            }
+            '[' => {
+                let order = builder.open();
+                open.push(OpenMarker {
+                    marker: Marker::Link,
+                    start: builder.len,
+                    order,
+                    position,
+                });
+                continue;
+            }
+            ']' => {
+                let link = match open.pop() {
+                    Some(link) if link.marker == Marker::Link => link,
+                    _ => return Err(MarkupError::new(position, "unexpected end of link text")),
+                };
+                if chars.next_if(|(_, c)| *c == '(').is_none() {
+                    return Err(MarkupError::new(
+                        position,
+                        "link text must be followed by URL",
+                    ));
+                }
+                let mut url = String::new();
+                loop {
+                    match chars.next() {
+                        Some((escape, '\\')) => match chars.next() {
+                            Some((_, escaped)) => url.push(escaped),
+                            None => {
+                                return Err(MarkupError::new(escape, "unfinished escape sequence"))
+                            }
+                        },
+                        Some((_, ')')) => {
+                            let kind = Kind::from_url(url);
+                            builder.close(link.order, link.start, Some(kind));
+                            break;
+                        }
+                        Some((_, c)) => url.push(c),
+                        None => return Err(MarkupError::new(position, "URL is not closed")),
+                    }
+                }
+                continue;
+            }
+            c if MARKDOWN_V2_RESERVED.contains(c) => {
+                return Err(MarkupError::new(
+                    position,
+                    format!("character '{}' is reserved and must be escaped", c),
+                ))
+            }
+            c => {
+                builder.push(c);
+                continue;
+            }
+        };
+        match open.iter().rposition(|o| o.marker == marker) {
+            Some(i) if i + 1 == open.len() => {
+                let opened = open.remove(i);
+                builder.close(opened.order, opened.start, marker.kind());
+            }
+            Some(i) => {
+                return Err(MarkupError::new(
+                    open[i + 1].position,
+                    "entity must be closed before its outer entity",
+                ))
+            }
+            None => {
+                let order = builder.open();
+                open.push(OpenMarker {
+                    marker,
+                    start: builder.len,
+                    order,
+                    position,
+                });
+            }
+        }
+    }
+    match open.last() {
+        Some(opened) => Err(MarkupError::new(opened.position, "entity is not closed")),
+        None => Ok(builder.build()),
+    }
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::CharIndices<'a>>;
+
+// Language of a pre-formatted block is on the line of opening "```", it's skipped with the line break.
+fn parse_pre_language(chars: &mut Chars) -> String {
+    let mut lookahead = chars.clone();
+    let mut language = String::new();
+    loop {
+        match lookahead.next() {
+            Some((_, '\n')) => break,
+            Some((_, c)) if !c.is_whitespace() && c != '`' && c != '\\' => language.push(c),
+            _ => return String::new(),
+        }
+    }
+    *chars = lookahead;
+    language
+}
+
+fn parse_code(
+    chars: &mut Chars,
+    builder: &mut Builder,
+    position: usize,
+    end: &str,
+) -> Result<(), MarkupError> {
+    loop {
+        match chars.next() {
+            Some((_, '\\')) => match chars.next() {
+                Some((_, escaped)) => builder.push(escaped),
+                None => return Err(MarkupError::new(position, "unfinished escape sequence")),
+            },
+            Some((_, '`')) if end == "`" => return Ok(()),
+            Some((_, '`')) => {
+                let mut lookahead = chars.clone();
+                if matches!(
+                    (lookahead.next(), lookahead.next()),
+                    (Some((_, '`')), Some((_, '`')))
+                ) {
+                    *chars = lookahead;
+                    return Ok(());
+                }
+                builder.push('`');
+            }
+            Some((_, c)) => builder.push(c),
+            None => return Err(MarkupError::new(position, "code is not closed")),
+        }
+    }
+}
+
+struct OpenTag {
+    name: String,
+    start: usize,
+    order: usize,
+    position: usize,
+    kind: Option<Kind>,
+}
+
+/// Parses HTML markup with tags supported by Telegram: `b`, `strong`, `i`, `em`, `u`, `ins`, `s`, `strike`, `del`,
+/// `a`, `code`, `pre`, `span class="tg-spoiler"` and `tg-spoiler`.
+pub fn from_html(markup: &str) -> Result<FormattedText, MarkupError> {
+    let mut builder = Builder::default();
+    let mut open: Vec<OpenTag> = Vec::new();
+    let mut position = 0;
+    while let Some(c) = markup[position..].chars().next() {
+        match c {
+            '<' => {
+                let end = match markup[position..].find('>') {
+                    Some(end) => position + end,
+                    None => return Err(MarkupError::new(position, "tag is not closed")),
+                };
+                let tag = &markup[position + 1..end];
+                if let Some(name) = tag.strip_prefix('/') {
+                    let name = name.trim().to_lowercase();
+                    match open.pop() {
+                        Some(opened) if opened.name == name => {
+                            builder.close(opened.order, opened.start, opened.kind)
+                        }
+                        _ => {
+                            return Err(MarkupError::new(
+                                position,
+                                format!("unexpected closing tag </{}>", name),
+                            ))
+                        }
+                    }
+                } else {
+                    let opened = parse_tag(tag, position, &mut builder, &mut open)?;
+                    open.push(opened);
+                }
+                position = end + 1;
+            }
+            '&' => {
+                let (decoded, len) = decode_html_entity(&markup[position..]);
+                builder.push(decoded);
+                position += len;
+            }
+            c => {
+                builder.push(c);
+                position += c.len_utf8();
+            }
+        }
+    }
+    match open.last() {
+        Some(opened) => Err(MarkupError::new(
+            opened.position,
+            format!("tag <{}> is not closed", opened.name),
+        )),
+        None => Ok(builder.build()),
+    }
+}
+
+fn parse_tag(
+    tag: &str,
+    position: usize,
+    builder: &mut Builder,
+    open: &mut [OpenTag],
+) -> Result<OpenTag, MarkupError> {
+    let tag = tag.trim_end_matches('/').trim();
+    let (name, attributes) = match tag.find(char::is_whitespace) {
+        Some(i) => (&tag[..i], &tag[i..]),
+        None => (tag, ""),
+    };
+    let name = name.to_lowercase();
+    let attributes = parse_attributes(attributes, position)?;
+    let attribute = |name: &str| {
+        attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.clone())
+    };
+    let kind = match name.as_str() {
+        "b" | "strong" => Some(Kind::Bold),
+        "i" | "em" => Some(Kind::Italic),
+        "u" | "ins" => Some(Kind::Underline),
+        "s" | "strike" | "del" => Some(Kind::Strikethrough),
+        "tg-spoiler" => None,
+        "span" if attribute("class").as_deref() == Some("tg-spoiler") => None,
+        "a" => attribute("href").map(Kind::from_url),
+        "pre" => Some(Kind::Pre(String::new())),
+        "code" => {
+            let language = attribute("class")
+                .and_then(|class| class.strip_prefix("language-").map(str::to_string));
+            match (open.last_mut(), language) {
+                // `<pre><code class="language-rust">` specifies language of the block
+                (Some(pre), Some(language))
+                    if pre.start == builder.len && pre.kind == Some(Kind::Pre(String::new())) =>
+                {
+                    pre.kind = Some(Kind::Pre(language));
+                    None
+                }
+                _ => Some(Kind::Code),
+            }
+        }
+        _ => {
+            return Err(MarkupError::new(
+                position,
+                format!("unsupported tag <{}>", name),
+            ))
+        }
+    };
+    Ok(OpenTag {
+        name,
+        start: builder.len,
+        order: builder.open(),
+        position,
+        kind,
+    })
+}
+
+fn parse_attributes(source: &str, position: usize) -> Result<Vec<(String, String)>, MarkupError> {
+    let mut attributes = Vec::new();
+    let mut rest = source.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_lowercase();
+        rest = rest[name_end..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            None => String::new(),
+            Some(value) => {
+                let value = value.trim_start();
+                let (raw, tail) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => match value[1..].find(quote) {
+                        Some(end) => (&value[1..end + 1], &value[end + 2..]),
+                        None => {
+                            return Err(MarkupError::new(
+                                position,
+                                format!("value of attribute {} is not closed", name),
+                            ))
+                        }
+                    },
+                    _ => {
+                        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = tail.trim_start();
+                decode_html(raw)
+            }
+        };
+        attributes.push((name, value));
+    }
+    Ok(attributes)
+}
+
+fn decode_html(source: &str) -> String {
+    let mut decoded = String::with_capacity(source.len());
+    let mut position = 0;
+    while let Some(c) = source[position..].chars().next() {
+        if c == '&' {
+            let (c, len) = decode_html_entity(&source[position..]);
+            decoded.push(c);
+            position += len;
+        } else {
+            decoded.push(c);
+            position += c.len_utf8();
+        }
+    }
+    decoded
+}
+
+// Decodes an entity at the start of the source like `&amp;` or `&#128512;`, returns `&` itself if it's not an entity.
+fn decode_html_entity(source: &str) -> (char, usize) {
+    let end = match source.find(';') {
+        Some(end) if end <= 10 => end,
+        _ => return ('&', 1),
+    };
+    let name = &source[1..end];
+    let decoded = match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => name
+            .strip_prefix("#x")
+            .or_else(|| name.strip_prefix("#X"))
+            .map(|hex| u32::from_str_radix(hex, 16))
+            .or_else(|| name.strip_prefix('#').map(|dec| dec.parse::<u32>()))
+            .and_then(|code| code.ok())
+            .and_then(char::from_u32),
+    };
+    match decoded {
+        Some(c) => (c, end + 1),
+        None => ('&', 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        entity_text, from_html, from_markdown_v2, to_html, to_markdown_v2, utf16_to_byte_offset,
+        MarkupError,
+    };
+    use crate::types::{FormattedText, TextEntity, TextEntityType};
+    use serde_json::json;
+
+    fn entity(offset: i32, length: i32, type_: serde_json::Value) -> TextEntity {
+        TextEntity::builder()
+            .offset(offset)
+            .length(length)
+            .type_(serde_json::from_value::<TextEntityType>(type_).unwrap())
+            .build()
+    }
+
+    #[test]
+    fn test_render() {
+        // "😀" takes two UTF-16 code units
+        let text = FormattedText::builder()
+            .text("😀 bold-italic italic")
+            .entities(vec![
+                entity(3, 11, json!({"@type": "textEntityTypeBold"})),
+                entity(8, 13, json!({"@type": "textEntityTypeItalic"})),
+                entity(
+                    15,
+                    6,
+                    json!({"@type": "textEntityTypeTextUrl", "url": "https://a.b/(c)"}),
+                ),
+                entity(16, 2, json!({"@type": "textEntityTypeCode"})),
+            ])
+            .build();
+        assert_eq!(utf16_to_byte_offset(text.text(), 3), Some(5));
+        assert_eq!(utf16_to_byte_offset(text.text(), 1), None);
+        assert_eq!(entity_text(&text, &text.entities()[0]), Some("bold-italic"));
+        assert_eq!(
+            to_html(&text),
+            "😀 <b>bold-<i>italic</i></b><i> <a href=\"https://a.b/(c)\">i<code>ta</code>lic</a></i>"
+        );
+        let markdown = to_markdown_v2(&text);
+        assert_eq!(
+            markdown,
+            "😀 *bold\\-_italic_*_ [i`ta`lic](https://a.b/(c\\))_"
+        );
+        // entities are split at overlaps, so they differ but cover the same ranges
+        let parsed = from_markdown_v2(&markdown).unwrap();
+        assert_eq!(parsed.text(), text.text());
+        assert_eq!(to_markdown_v2(&parsed), markdown);
+        assert_eq!(
+            to_html(&from_html(&to_html(&text)).unwrap()),
+            to_html(&text)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let text = from_markdown_v2(
+            "___italic underline_\r__ ```rust\nfn main() {}``` [Jane](tg://user?id=42) \\*",
+        )
+        .unwrap();
+        assert_eq!(text.text(), "italic underline fn main() {} Jane *");
+        let types: Vec<_> = text
+            .entities()
+            .iter()
+            .map(|e| {
+                (
+                    e.offset(),
+                    e.length(),
+                    serde_json::to_value(e.type_()).unwrap()["@type"].clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                (0, 16, json!("textEntityTypeUnderline")),
+                (0, 16, json!("textEntityTypeItalic")),
+                (17, 12, json!("textEntityTypePreCode")),
+                (30, 4, json!("textEntityTypeMentionName")),
+            ]
+        );
+        assert_eq!(
+            to_markdown_v2(&text),
+            "__\r_italic underline_\r__ ```rust\nfn main() {}``` [Jane](tg://user?id=42) \\*"
+        );
+
+        let text = from_html(
+            "<b>a &amp; <i>b</i></b> <pre><code class=\"language-rust\">x &lt; y</code></pre> <tg-spoiler>c</tg-spoiler>",
+        )
+        .unwrap();
+        assert_eq!(text.text(), "a & b x < y c");
+        assert_eq!(text.entities().len(), 3);
+        assert!(
+            matches!(text.entities()[2].type_(), TextEntityType::PreCode(p) if p.language() == "rust")
+        );
+
+        assert!(from_markdown_v2("*bold _italic* text_").is_err());
+        assert!(from_markdown_v2("1.5").is_err());
+        assert_eq!(
+            from_markdown_v2("[a](http\\").unwrap_err(),
+            MarkupError::new(8, "unfinished escape sequence")
+        );
+        assert!(from_html("<b>bold</i>").is_err());
+        assert!(from_html("<marquee>text</marquee>").is_err());
+    }
+}
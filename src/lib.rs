@@ -48,13 +48,21 @@
 #[macro_use]
 extern crate serde_derive;
 
-#[cfg(feature = "client")]
+#[cfg(feature = "client-core")]
 pub mod client;
 
 #[cfg(feature = "tdjson")]
 pub mod tdjson;
 
+#[cfg(feature = "dynamic-tdjson")]
+pub mod tdjson_dynamic;
+
+#[cfg(any(feature = "tdjson", feature = "dynamic-tdjson"))]
+pub mod td_log;
+
+pub mod commands;
 pub mod errors;
+pub mod formatting;
 pub mod types;
 
 mod utils;
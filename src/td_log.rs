@@ -0,0 +1,94 @@
+//! Routing of TDLib internal logs, received with `td_set_log_message_callback`, into Rust logging facilities.
+//! Use [tdjson::set_log_message_callback](crate::tdjson::set_log_message_callback) (or the same method of
+//! [TdJsonLibrary](crate::tdjson_dynamic::TdJsonLibrary)) to register a callback.
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic;
+use std::sync::RwLock;
+
+/// Log target used for messages forwarded by [forward_to_log].
+pub const LOG_TARGET: &str = "tdlib";
+
+pub(crate) type LogMessageCallback = Box<dyn Fn(i32, &str) + Send + Sync>;
+
+// TDLib accepts only one callback per process and it can't carry any user data,
+// so registered callback is stored here and called by `log_message_trampoline`.
+static LOG_MESSAGE_CALLBACK: RwLock<Option<LogMessageCallback>> = RwLock::new(None);
+
+pub(crate) fn set_callback(callback: Option<LogMessageCallback>) {
+    match LOG_MESSAGE_CALLBACK.write() {
+        Ok(mut current) => *current = callback,
+        Err(poisoned) => *poisoned.into_inner() = callback,
+    }
+}
+
+pub(crate) extern "C" fn log_message_trampoline(verbosity_level: c_int, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    // unwinding into TDLib is undefined behavior
+    let _ = panic::catch_unwind(|| {
+        if let Ok(guard) = LOG_MESSAGE_CALLBACK.read() {
+            if let Some(callback) = guard.as_ref() {
+                callback(verbosity_level, message.trim_end());
+            }
+        }
+    });
+}
+
+/// Maps TDLib verbosity level to [log::Level].
+/// 0 (fatal errors) and 1 (errors) become `Error`, 2 - `Warn`, 3 - `Info`, 4 - `Debug`, higher levels - `Trace`.
+pub fn verbosity_to_level(verbosity_level: i32) -> log::Level {
+    match verbosity_level {
+        i32::MIN..=1 => log::Level::Error,
+        2 => log::Level::Warn,
+        3 => log::Level::Info,
+        4 => log::Level::Debug,
+        _ => log::Level::Trace,
+    }
+}
+
+/// Forwards TDLib log message to [log] crate with [LOG_TARGET] target
+/// (and to `tracing` if `tracing` feature is enabled).
+pub fn forward_to_log(verbosity_level: i32, message: &str) {
+    let level = verbosity_to_level(verbosity_level);
+    log::log!(target: LOG_TARGET, level, "{}", message);
+    #[cfg(feature = "tracing")]
+    match level {
+        log::Level::Error => tracing::error!(target: LOG_TARGET, verbosity_level, "{}", message),
+        log::Level::Warn => tracing::warn!(target: LOG_TARGET, verbosity_level, "{}", message),
+        log::Level::Info => tracing::info!(target: LOG_TARGET, verbosity_level, "{}", message),
+        log::Level::Debug => tracing::debug!(target: LOG_TARGET, verbosity_level, "{}", message),
+        log::Level::Trace => tracing::trace!(target: LOG_TARGET, verbosity_level, "{}", message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{log_message_trampoline, set_callback, verbosity_to_level};
+    use std::ffi::CString;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_log_message_trampoline() {
+        assert_eq!(verbosity_to_level(0), log::Level::Error);
+        assert_eq!(verbosity_to_level(2), log::Level::Warn);
+        assert_eq!(verbosity_to_level(1024), log::Level::Trace);
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        set_callback(Some(Box::new(move |level, message| {
+            sink.lock().unwrap().push((level, message.to_string()))
+        })));
+        let message = CString::new("[ 0][t 1][Td.cpp:1] fatal\n").unwrap();
+        log_message_trampoline(0, message.as_ptr());
+        set_callback(None);
+        log_message_trampoline(0, message.as_ptr());
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![(0, "[ 0][t 1][Td.cpp:1] fatal".to_string())]
+        );
+    }
+}
@@ -4,7 +4,9 @@ use std::ffi::CString;
 use std::os::raw::{c_char, c_double, c_int, c_long};
 use std::ptr;
 
-pub type ClientId = i32;
+use crate::td_log;
+
+pub use crate::types::ClientId;
 
 #[link(name = "tdjson")]
 extern "C" {
@@ -12,6 +14,10 @@ extern "C" {
     fn td_send(client_id: c_int, request: *const c_char);
     fn td_receive(timeout: c_double) -> *const c_char;
     fn td_execute(request: *const c_char) -> *const c_char;
+    fn td_set_log_message_callback(
+        max_verbosity_level: c_int,
+        callback: Option<extern "C" fn(verbosity_level: c_int, message: *const c_char)>,
+    );
 
     // Deprecated. Use setLogVerbosityLevel request instead.
     fn td_set_log_verbosity_level(level: c_int);
@@ -48,6 +54,30 @@ pub fn receive(timeout: f64) -> Option<String> {
     }
 }
 
+/// Sets the callback that will be called when a message is added to the internal TDLib log.
+/// None of the TDLib methods can be called from the callback.
+/// Callback is called for fatal errors (verbosity level 0) even if `max_verbosity_level` is lower.
+pub fn set_log_message_callback<F>(max_verbosity_level: i32, callback: F)
+where
+    F: Fn(i32, &str) + Send + Sync + 'static,
+{
+    td_log::set_callback(Some(Box::new(callback)));
+    unsafe {
+        td_set_log_message_callback(max_verbosity_level, Some(td_log::log_message_trampoline))
+    };
+}
+
+/// Forwards TDLib internal log into [log] crate. See [td_log::forward_to_log].
+pub fn set_log_message_callback_to_log(max_verbosity_level: i32) {
+    set_log_message_callback(max_verbosity_level, td_log::forward_to_log);
+}
+
+/// Removes callback set with [set_log_message_callback].
+pub fn remove_log_message_callback() {
+    unsafe { td_set_log_message_callback(0, None) };
+    td_log::set_callback(None);
+}
+
 // Deprecated. Use setLogVerbosityLevel request instead.
 pub fn set_log_verbosity_level(level: i32) {
     unsafe { td_set_log_verbosity_level(level) };
@@ -0,0 +1,192 @@
+//! Runtime-loaded counterpart of [tdjson](crate::tdjson).
+//! Resolves methods defined in [td/td_json_client.h](https://github.com/tdlib/td/blob/master/td/telegram/td_json_client.h)
+//! with `dlopen` instead of linking `libtdjson` at build time, so TDLib build can be chosen when application starts.
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::os::raw::{c_char, c_double, c_int};
+use std::{error, fmt};
+
+use libloading::Library;
+
+use crate::td_log;
+
+pub use crate::types::ClientId;
+
+/// Environment variable which may contain path to `libtdjson`. See [TdJsonLibrary::from_env].
+pub const TDJSON_PATH_ENV: &str = "RUST_TDLIB_TDJSON_PATH";
+
+type CreateClientIdFn = unsafe extern "C" fn() -> c_int;
+type SendFn = unsafe extern "C" fn(client_id: c_int, request: *const c_char);
+type ReceiveFn = unsafe extern "C" fn(timeout: c_double) -> *const c_char;
+type ExecuteFn = unsafe extern "C" fn(request: *const c_char) -> *const c_char;
+type SetLogMessageCallbackFn = unsafe extern "C" fn(
+    max_verbosity_level: c_int,
+    callback: Option<extern "C" fn(verbosity_level: c_int, message: *const c_char)>,
+);
+
+/// Error occurred during `libtdjson` loading.
+#[derive(Debug)]
+pub enum LoadError {
+    /// Library can't be opened.
+    Library {
+        path: OsString,
+        source: libloading::Error,
+    },
+    /// Library opened, but required symbol not found in it.
+    MissingSymbol {
+        symbol: &'static str,
+        source: libloading::Error,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Library { path, source } => {
+                write!(f, "can't load {}: {}", path.to_string_lossy(), source)
+            }
+            LoadError::MissingSymbol { symbol, source } => {
+                write!(f, "symbol {} not found in tdjson: {}", symbol, source)
+            }
+        }
+    }
+}
+
+impl error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            LoadError::Library { source, .. } => Some(source),
+            LoadError::MissingSymbol { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Loaded `libtdjson` with resolved symbols.
+/// Library stays loaded while the value (or any of its clones behind an `Arc`) is alive.
+pub struct TdJsonLibrary {
+    td_create_client_id: CreateClientIdFn,
+    td_send: SendFn,
+    td_receive: ReceiveFn,
+    td_execute: ExecuteFn,
+    td_set_log_message_callback: SetLogMessageCallbackFn,
+    // must be dropped last: symbols above point into the library
+    _library: Library,
+}
+
+impl fmt::Debug for TdJsonLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TdJsonLibrary").finish_non_exhaustive()
+    }
+}
+
+impl TdJsonLibrary {
+    /// Loads `libtdjson` from specified path.
+    pub fn load<P: AsRef<OsStr>>(path: P) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        // Safety: loading TDLib runs its static initializers, which are safe to be called at any time.
+        let library = unsafe { Library::new(path) }.map_err(|source| LoadError::Library {
+            path: path.to_os_string(),
+            source,
+        })?;
+        // Safety: signatures match ones declared in td_json_client.h.
+        unsafe {
+            Ok(Self {
+                td_create_client_id: symbol(&library, "td_create_client_id")?,
+                td_send: symbol(&library, "td_send")?,
+                td_receive: symbol(&library, "td_receive")?,
+                td_execute: symbol(&library, "td_execute")?,
+                td_set_log_message_callback: symbol(&library, "td_set_log_message_callback")?,
+                _library: library,
+            })
+        }
+    }
+
+    /// Loads `libtdjson` from path specified with [TDJSON_PATH_ENV] environment variable.
+    /// If the variable is not set, platform-specific library name (e.g. `libtdjson.so`) is used,
+    /// so the library will be searched in default system locations.
+    pub fn from_env() -> Result<Self, LoadError> {
+        match std::env::var_os(TDJSON_PATH_ENV) {
+            Some(path) => Self::load(path),
+            None => Self::load(libloading::library_filename("tdjson")),
+        }
+    }
+
+    pub fn new_client(&self) -> ClientId {
+        unsafe { (self.td_create_client_id)() }
+    }
+
+    pub fn send(&self, client_id: ClientId, request: &str) {
+        let cstring = CString::new(request).unwrap();
+        unsafe { (self.td_send)(client_id, cstring.as_ptr()) }
+    }
+
+    pub fn execute(&self, request: &str) -> Option<String> {
+        let cstring = CString::new(request).unwrap();
+        unsafe {
+            (self.td_execute)(cstring.as_ptr())
+                .as_ref()
+                .map(|response| CStr::from_ptr(response).to_string_lossy().into_owned())
+        }
+    }
+
+    pub fn receive(&self, timeout: f64) -> Option<String> {
+        unsafe {
+            (self.td_receive)(timeout)
+                .as_ref()
+                .map(|response| CStr::from_ptr(response).to_string_lossy().into_owned())
+        }
+    }
+
+    /// See [tdjson::set_log_message_callback](crate::tdjson::set_log_message_callback).
+    /// Callback stays registered in TDLib while the library is loaded, remove it before the library is dropped.
+    pub fn set_log_message_callback<F>(&self, max_verbosity_level: i32, callback: F)
+    where
+        F: Fn(i32, &str) + Send + Sync + 'static,
+    {
+        td_log::set_callback(Some(Box::new(callback)));
+        unsafe {
+            (self.td_set_log_message_callback)(
+                max_verbosity_level,
+                Some(td_log::log_message_trampoline),
+            )
+        };
+    }
+
+    /// Forwards TDLib internal log into [log] crate. See [td_log::forward_to_log].
+    pub fn set_log_message_callback_to_log(&self, max_verbosity_level: i32) {
+        self.set_log_message_callback(max_verbosity_level, td_log::forward_to_log);
+    }
+
+    /// Removes callback set with [set_log_message_callback](TdJsonLibrary::set_log_message_callback).
+    pub fn remove_log_message_callback(&self) {
+        unsafe { (self.td_set_log_message_callback)(0, None) };
+        td_log::set_callback(None);
+    }
+}
+
+unsafe fn symbol<T: Copy>(library: &Library, name: &'static str) -> Result<T, LoadError> {
+    let mut symbol_name = name.as_bytes().to_vec();
+    symbol_name.push(0);
+    library
+        .get::<T>(&symbol_name)
+        .map(|symbol| *symbol)
+        .map_err(|source| LoadError::MissingSymbol {
+            symbol: name,
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadError, TdJsonLibrary};
+
+    #[test]
+    fn test_load_missing_library() {
+        match TdJsonLibrary::load("/nonexistent/libtdjson.so") {
+            Err(LoadError::Library { path, .. }) => {
+                assert_eq!(path, "/nonexistent/libtdjson.so")
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("library loaded"),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::{Debug, Display};
 use std::str::FromStr;
 
 use serde::{
-    de::{Deserialize, Deserializer, Error as SerdeDeError},
+    de::{Deserialize, DeserializeOwned, Deserializer, Error as SerdeDeError},
     ser::{Serialize, Serializer},
 };
 
@@ -16,6 +16,9 @@ where
     Ok(serde_json::from_str(json)?)
 }
 
+/// Identifier of a TDLib client instance, returned by `td_create_client_id`.
+pub type ClientId = i32;
+
 /// All tdlib type abstract class defined the same behavior
 pub trait RObject: Debug {
     #[doc(hidden)]
@@ -23,7 +26,11 @@ pub trait RObject: Debug {
     fn client_id(&self) -> Option<i32>;
 }
 
+/// TDLib function, a request which can be sent to TDLib
 pub trait RFunction: Debug + RObject + Serialize {
+    /// Type of the object TDLib responds with
+    type Response: DeserializeOwned;
+
     fn to_json(&self) -> Result<String> {
         Ok(serde_json::to_string(self)?)
     }
@@ -47,9 +54,14 @@ impl<'a, RObj: RObject> RObject for &'a mut RObj {
     }
 }
 
-impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
-impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
+impl<'a, Fnc: RFunction> RFunction for &'a Fnc {
+    type Response = Fnc::Response;
+}
+impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {
+    type Response = Fnc::Response;
+}
 
+// Begin of code generated by tdlib-codegen.
 impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType
     for &'a AUTHENTICATIONCODETYPE
 {
@@ -478,6 +490,7 @@ impl<'a, USERTYPE: TDUserType> TDUserType for &'a mut USERTYPE {}
 
 impl<'a, VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &'a VECTORPATHCOMMAND {}
 impl<'a, VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &'a mut VECTORPATHCOMMAND {}
+// End of code generated by tdlib-codegen.
 
 pub(super) fn number_from_string<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
@@ -512,10 +525,46 @@ where
     Ok(r)
 }
 
+// Objects of unknown types are kept as they are, so they can be inspected and sent back.
+pub(super) fn serialize_unknown<S>(
+    _type_name: &String,
+    raw: &serde_json::Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    raw.serialize(serializer)
+}
+
+pub(super) fn deserialize_unknown<'de, D>(
+    deserializer: D,
+) -> Result<(String, serde_json::Value), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = serde_json::Value::deserialize(deserializer)?;
+    match raw.get("@type").and_then(|t| t.as_str()) {
+        Some(type_name) => Ok((type_name.to_string(), raw)),
+        None => Err(D::Error::custom("missing field `@type`")),
+    }
+}
+
+// Deserializes a variant of an enum dispatched by `@type`, errors of known types aren't hidden by `Unknown`.
+pub(super) fn from_raw<T, E>(raw: serde_json::Value) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: SerdeDeError,
+{
+    serde_json::from_value(raw).map_err(E::custom)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::types::RObject;
     use crate::types::{
-        ClosedVectorPath, File, Message, MessageContent, Sticker, Thumbnail, Update,
+        ClosedVectorPath, File, Message, MessageContent, Sticker, TdFunction, TdObject, Thumbnail,
+        Update,
     };
     use serde::de::DeserializeOwned;
 
@@ -585,6 +634,78 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_deserialize_unknown() {
+        let data = r#"{"@type":"updateStoryDeleted","story_id":1,"@client_id":3}"#;
+        let update: Update = serde_json::from_str(data).unwrap();
+        match &update {
+            Update::Unknown { type_name, raw } => {
+                assert_eq!(type_name, "updateStoryDeleted");
+                assert_eq!(raw["story_id"], 1);
+            }
+            v => panic!("unexpected update: {:?}", v),
+        }
+        assert_eq!(update.client_id(), Some(3));
+        let value: serde_json::Value = serde_json::from_str(data).unwrap();
+        assert_eq!(serde_json::to_value(&update).unwrap(), value);
+
+        assert(
+            r#"{"@type":"updateMessageContent","chat_id":1,"message_id":2,"new_content":{"@type":"messageStory","story_id":5}}"#,
+            |res: &serde_json::Result<Update>| matches!(res, Ok(Update::MessageContent(u)) if matches!(u.new_content(), MessageContent::Unknown { type_name, .. } if type_name == "messageStory")),
+        );
+        assert(r#"{"story_id":1}"#, |res: &serde_json::Result<Update>| {
+            res.is_err()
+        });
+        // known types must match the schema, they don't fall back to `Unknown`
+        assert(
+            r#"{"@type":"updateChatTitle","chat_id":"abc","title":"t"}"#,
+            |res: &serde_json::Result<Update>| res.is_err(),
+        );
+        assert(
+            r#"{"@type":"updateMessageContent","chat_id":1,"message_id":2,"new_content":{"@type":"messageText","text":5}}"#,
+            |res: &serde_json::Result<Update>| res.is_err(),
+        );
+    }
+
+    #[test]
+    fn test_td_object_and_function() {
+        let object =
+            TdObject::from_json(r#"{"@type":"chats","total_count":1,"chat_ids":[5],"@extra":"x"}"#)
+                .unwrap();
+        match &object {
+            TdObject::Chats(chats) => assert_eq!(chats.chat_ids(), &vec![5]),
+            v => panic!("unexpected object: {:?}", v),
+        }
+        assert_eq!(object.type_name(), "chats");
+        assert_eq!(object.extra(), Some("x"));
+        let object = TdObject::from_json(r#"{"@type":"story","id":3}"#).unwrap();
+        assert!(matches!(&object, TdObject::Unknown { type_name, .. } if type_name == "story"));
+        assert!(
+            TdObject::from_json(r#"{"@type":"chats","total_count":"x","chat_ids":[5]}"#).is_err()
+        );
+
+        let data = r#"{"@type":"getChat","chat_id":7,"@extra":"y"}"#;
+        let function = TdFunction::from_json(data).unwrap();
+        match &function {
+            TdFunction::GetChat(get_chat) => assert_eq!(get_chat.chat_id(), 7),
+            v => panic!("unexpected function: {:?}", v),
+        }
+        assert_eq!(function.type_name(), "getChat");
+        assert_eq!(function.extra(), Some("y"));
+        let mut value: serde_json::Value = serde_json::from_str(data).unwrap();
+        value["@client_id"] = serde_json::Value::Null;
+        assert_eq!(serde_json::to_value(&function).unwrap(), value);
+
+        let data = r#"{"@type":"getStory","story_id":3}"#;
+        let function = TdFunction::from_json(data).unwrap();
+        assert_eq!(function.type_name(), "getStory");
+        assert_eq!(
+            serde_json::to_value(&function).unwrap(),
+            serde_json::from_str::<serde_json::Value>(data).unwrap()
+        );
+        assert!(TdFunction::from_json(r#"{"@type":"getChat","chat_id":"x"}"#).is_err());
+    }
+
     fn assert<T: std::fmt::Debug + DeserializeOwned>(
         data: &'_ str,
         exp: fn(&serde_json::Result<T>) -> bool,
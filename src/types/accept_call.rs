@@ -17,7 +17,7 @@ pub struct AcceptCall {
     /// The call protocols supported by the application
     protocol: CallProtocol,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for AcceptCall {
     }
 }
 
-impl RFunction for AcceptCall {}
+impl RFunction for AcceptCall {
+    type Response = Ok;
+}
 
 impl AcceptCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
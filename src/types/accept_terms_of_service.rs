@@ -15,7 +15,7 @@ pub struct AcceptTermsOfService {
     #[serde(default)]
     terms_of_service_id: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AcceptTermsOfService {
     }
 }
 
-impl RFunction for AcceptTermsOfService {}
+impl RFunction for AcceptTermsOfService {
+    type Response = Ok;
+}
 
 impl AcceptTermsOfService {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct AddChatMember {
     #[serde(default)]
     forward_limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for AddChatMember {
     }
 }
 
-impl RFunction for AddChatMember {}
+impl RFunction for AddChatMember {
+    type Response = Ok;
+}
 
 impl AddChatMember {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
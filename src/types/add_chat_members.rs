@@ -19,7 +19,7 @@ pub struct AddChatMembers {
     #[serde(default)]
     user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for AddChatMembers {
     }
 }
 
-impl RFunction for AddChatMembers {}
+impl RFunction for AddChatMembers {
+    type Response = Ok;
+}
 
 impl AddChatMembers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
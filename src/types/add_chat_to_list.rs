@@ -19,7 +19,7 @@ pub struct AddChatToList {
     #[serde(skip_serializing_if = "ChatList::_is_default")]
     chat_list: ChatList,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for AddChatToList {
     }
 }
 
-impl RFunction for AddChatToList {}
+impl RFunction for AddChatToList {
+    type Response = Ok;
+}
 
 impl AddChatToList {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
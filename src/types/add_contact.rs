@@ -17,7 +17,7 @@ pub struct AddContact {
     #[serde(default)]
     share_phone_number: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for AddContact {
     }
 }
 
-impl RFunction for AddContact {}
+impl RFunction for AddContact {
+    type Response = Ok;
+}
 
 impl AddContact {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct AddCustomServerLanguagePack {
     #[serde(default)]
     language_pack_id: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AddCustomServerLanguagePack {
     }
 }
 
-impl RFunction for AddCustomServerLanguagePack {}
+impl RFunction for AddCustomServerLanguagePack {
+    type Response = Ok;
+}
 
 impl AddCustomServerLanguagePack {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
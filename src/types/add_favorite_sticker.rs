@@ -15,7 +15,7 @@ pub struct AddFavoriteSticker {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     sticker: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AddFavoriteSticker {
     }
 }
 
-impl RFunction for AddFavoriteSticker {}
+impl RFunction for AddFavoriteSticker {
+    type Response = Ok;
+}
 
 impl AddFavoriteSticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
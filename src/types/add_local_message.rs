@@ -31,7 +31,7 @@ pub struct AddLocalMessage {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for AddLocalMessage {
     }
 }
 
-impl RFunction for AddLocalMessage {}
+impl RFunction for AddLocalMessage {
+    type Response = Message;
+}
 
 impl AddLocalMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
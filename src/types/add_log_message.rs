@@ -19,7 +19,7 @@ pub struct AddLogMessage {
     #[serde(default)]
     text: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for AddLogMessage {
     }
 }
 
-impl RFunction for AddLogMessage {}
+impl RFunction for AddLogMessage {
+    type Response = Ok;
+}
 
 impl AddLogMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct AddNetworkStatistics {
     #[serde(skip_serializing_if = "NetworkStatisticsEntry::_is_default")]
     entry: NetworkStatisticsEntry,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AddNetworkStatistics {
     }
 }
 
-impl RFunction for AddNetworkStatistics {}
+impl RFunction for AddNetworkStatistics {
+    type Response = Ok;
+}
 
 impl AddNetworkStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -28,7 +28,7 @@ pub struct AddProxy {
     #[serde(skip_serializing_if = "ProxyType::_is_default")]
     type_: ProxyType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -43,7 +43,9 @@ impl RObject for AddProxy {
     }
 }
 
-impl RFunction for AddProxy {}
+impl RFunction for AddProxy {
+    type Response = Proxy;
+}
 
 impl AddProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
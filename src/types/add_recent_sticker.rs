@@ -19,7 +19,7 @@ pub struct AddRecentSticker {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     sticker: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for AddRecentSticker {
     }
 }
 
-impl RFunction for AddRecentSticker {}
+impl RFunction for AddRecentSticker {
+    type Response = Stickers;
+}
 
 impl AddRecentSticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
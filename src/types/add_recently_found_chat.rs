@@ -15,7 +15,7 @@ pub struct AddRecentlyFoundChat {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AddRecentlyFoundChat {
     }
 }
 
-impl RFunction for AddRecentlyFoundChat {}
+impl RFunction for AddRecentlyFoundChat {
+    type Response = Ok;
+}
 
 impl AddRecentlyFoundChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct AddSavedAnimation {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     animation: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for AddSavedAnimation {
     }
 }
 
-impl RFunction for AddSavedAnimation {}
+impl RFunction for AddSavedAnimation {
+    type Response = Ok;
+}
 
 impl AddSavedAnimation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct AddStickerToSet {
     #[serde(skip_serializing_if = "InputSticker::_is_default")]
     sticker: InputSticker,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for AddStickerToSet {
     }
 }
 
-impl RFunction for AddStickerToSet {}
+impl RFunction for AddStickerToSet {
+    type Response = StickerSet;
+}
 
 impl AddStickerToSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -35,7 +35,7 @@ pub struct AnswerCallbackQuery {
     #[serde(default)]
     cache_time: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for AnswerCallbackQuery {
     }
 }
 
-impl RFunction for AnswerCallbackQuery {}
+impl RFunction for AnswerCallbackQuery {
+    type Response = Ok;
+}
 
 impl AnswerCallbackQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct AnswerCustomQuery {
     #[serde(default)]
     data: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for AnswerCustomQuery {
     }
 }
 
-impl RFunction for AnswerCustomQuery {}
+impl RFunction for AnswerCustomQuery {
+    type Response = Ok;
+}
 
 impl AnswerCustomQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -43,7 +43,7 @@ pub struct AnswerInlineQuery {
     #[serde(default)]
     switch_pm_parameter: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -58,7 +58,9 @@ impl RObject for AnswerInlineQuery {
     }
 }
 
-impl RFunction for AnswerInlineQuery {}
+impl RFunction for AnswerInlineQuery {
+    type Response = Ok;
+}
 
 impl AnswerInlineQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct AnswerPreCheckoutQuery {
     #[serde(default)]
     error_message: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for AnswerPreCheckoutQuery {
     }
 }
 
-impl RFunction for AnswerPreCheckoutQuery {}
+impl RFunction for AnswerPreCheckoutQuery {
+    type Response = Ok;
+}
 
 impl AnswerPreCheckoutQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct AnswerShippingQuery {
     #[serde(default)]
     error_message: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for AnswerShippingQuery {
     }
 }
 
-impl RFunction for AnswerShippingQuery {}
+impl RFunction for AnswerShippingQuery {
+    type Response = Ok;
+}
 
 impl AnswerShippingQuery {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
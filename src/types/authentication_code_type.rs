@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDAuthenticationCodeType: Debug + RObject {}
 
 /// Provides information about the method by which an authentication code is delivered to the user
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum AuthenticationCodeType {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum AuthenticationCodeType {
     /// An authentication code is delivered via a private Telegram message, which can be viewed from another active session
     #[serde(rename = "authenticationCodeTypeTelegramMessage")]
     TelegramMessage(AuthenticationCodeTypeTelegramMessage),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for AuthenticationCodeType {
@@ -40,6 +46,9 @@ impl RObject for AuthenticationCodeType {
             AuthenticationCodeType::MissedCall(t) => t.extra(),
             AuthenticationCodeType::Sms(t) => t.extra(),
             AuthenticationCodeType::TelegramMessage(t) => t.extra(),
+            AuthenticationCodeType::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -52,12 +61,42 @@ impl RObject for AuthenticationCodeType {
             AuthenticationCodeType::MissedCall(t) => t.client_id(),
             AuthenticationCodeType::Sms(t) => t.client_id(),
             AuthenticationCodeType::TelegramMessage(t) => t.client_id(),
+            AuthenticationCodeType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for AuthenticationCodeType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "authenticationCodeTypeCall" => {
+                AuthenticationCodeType::Call(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeFlashCall" => {
+                AuthenticationCodeType::FlashCall(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeMissedCall" => {
+                AuthenticationCodeType::MissedCall(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeSms" => {
+                AuthenticationCodeType::Sms(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeTelegramMessage" => {
+                AuthenticationCodeType::TelegramMessage(super::_common::from_raw(raw)?)
+            }
+            _ => AuthenticationCodeType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl AuthenticationCodeType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
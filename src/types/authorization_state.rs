@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDAuthorizationState: Debug + RObject {}
 
 /// Represents the current authorization state of the TDLib client
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum AuthorizationState {
     #[doc(hidden)]
@@ -50,6 +50,12 @@ pub enum AuthorizationState {
     /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
     #[serde(rename = "getAuthorizationState")]
     GetAuthorizationState(GetAuthorizationState),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for AuthorizationState {
@@ -68,6 +74,7 @@ impl RObject for AuthorizationState {
             AuthorizationState::WaitRegistration(t) => t.extra(),
             AuthorizationState::WaitTdlibParameters(t) => t.extra(),
             AuthorizationState::GetAuthorizationState(t) => t.extra(),
+            AuthorizationState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -87,12 +94,61 @@ impl RObject for AuthorizationState {
             AuthorizationState::WaitRegistration(t) => t.client_id(),
             AuthorizationState::WaitTdlibParameters(t) => t.client_id(),
             AuthorizationState::GetAuthorizationState(t) => t.client_id(),
+            AuthorizationState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for AuthorizationState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "authorizationStateClosed" => {
+                AuthorizationState::Closed(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateClosing" => {
+                AuthorizationState::Closing(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateLoggingOut" => {
+                AuthorizationState::LoggingOut(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateReady" => AuthorizationState::Ready(super::_common::from_raw(raw)?),
+            "authorizationStateWaitCode" => {
+                AuthorizationState::WaitCode(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitEncryptionKey" => {
+                AuthorizationState::WaitEncryptionKey(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitOtherDeviceConfirmation" => {
+                AuthorizationState::WaitOtherDeviceConfirmation(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitPassword" => {
+                AuthorizationState::WaitPassword(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitPhoneNumber" => {
+                AuthorizationState::WaitPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitRegistration" => {
+                AuthorizationState::WaitRegistration(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitTdlibParameters" => {
+                AuthorizationState::WaitTdlibParameters(super::_common::from_raw(raw)?)
+            }
+            "getAuthorizationState" => {
+                AuthorizationState::GetAuthorizationState(super::_common::from_raw(raw)?)
+            }
+            _ => AuthorizationState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl AuthorizationState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
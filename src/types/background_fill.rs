@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDBackgroundFill: Debug + RObject {}
 
 /// Describes a fill of a background
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum BackgroundFill {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum BackgroundFill {
     /// Describes a solid fill of a background
     #[serde(rename = "backgroundFillSolid")]
     Solid(BackgroundFillSolid),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for BackgroundFill {
@@ -32,6 +38,7 @@ impl RObject for BackgroundFill {
             BackgroundFill::FreeformGradient(t) => t.extra(),
             BackgroundFill::Gradient(t) => t.extra(),
             BackgroundFill::Solid(t) => t.extra(),
+            BackgroundFill::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,32 @@ impl RObject for BackgroundFill {
             BackgroundFill::FreeformGradient(t) => t.client_id(),
             BackgroundFill::Gradient(t) => t.client_id(),
             BackgroundFill::Solid(t) => t.client_id(),
+            BackgroundFill::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for BackgroundFill {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "backgroundFillFreeformGradient" => {
+                BackgroundFill::FreeformGradient(super::_common::from_raw(raw)?)
+            }
+            "backgroundFillGradient" => BackgroundFill::Gradient(super::_common::from_raw(raw)?),
+            "backgroundFillSolid" => BackgroundFill::Solid(super::_common::from_raw(raw)?),
+            _ => BackgroundFill::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl BackgroundFill {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
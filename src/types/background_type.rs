@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDBackgroundType: Debug + RObject {}
 
 /// Describes the type of a background
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum BackgroundType {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum BackgroundType {
     /// A wallpaper in JPEG format
     #[serde(rename = "backgroundTypeWallpaper")]
     Wallpaper(BackgroundTypeWallpaper),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for BackgroundType {
@@ -32,6 +38,7 @@ impl RObject for BackgroundType {
             BackgroundType::Fill(t) => t.extra(),
             BackgroundType::Pattern(t) => t.extra(),
             BackgroundType::Wallpaper(t) => t.extra(),
+            BackgroundType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,30 @@ impl RObject for BackgroundType {
             BackgroundType::Fill(t) => t.client_id(),
             BackgroundType::Pattern(t) => t.client_id(),
             BackgroundType::Wallpaper(t) => t.client_id(),
+            BackgroundType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for BackgroundType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "backgroundTypeFill" => BackgroundType::Fill(super::_common::from_raw(raw)?),
+            "backgroundTypePattern" => BackgroundType::Pattern(super::_common::from_raw(raw)?),
+            "backgroundTypeWallpaper" => BackgroundType::Wallpaper(super::_common::from_raw(raw)?),
+            _ => BackgroundType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl BackgroundType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
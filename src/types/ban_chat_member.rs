@@ -27,7 +27,7 @@ pub struct BanChatMember {
     #[serde(default)]
     revoke_messages: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for BanChatMember {
     }
 }
 
-impl RFunction for BanChatMember {}
+impl RFunction for BanChatMember {
+    type Response = Ok;
+}
 
 impl BanChatMember {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct BlockMessageSenderFromReplies {
     #[serde(default)]
     report_spam: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for BlockMessageSenderFromReplies {
     }
 }
 
-impl RFunction for BlockMessageSenderFromReplies {}
+impl RFunction for BlockMessageSenderFromReplies {
+    type Response = Ok;
+}
 
 impl BlockMessageSenderFromReplies {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
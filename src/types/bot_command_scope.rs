@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDBotCommandScope: Debug + RObject {}
 
 /// Represents the scope to which bot commands are relevant
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum BotCommandScope {
     #[doc(hidden)]
@@ -35,6 +35,12 @@ pub enum BotCommandScope {
     /// A scope covering all users
     #[serde(rename = "botCommandScopeDefault")]
     Default(BotCommandScopeDefault),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for BotCommandScope {
@@ -48,6 +54,7 @@ impl RObject for BotCommandScope {
             BotCommandScope::ChatAdministrators(t) => t.extra(),
             BotCommandScope::ChatMember(t) => t.extra(),
             BotCommandScope::Default(t) => t.extra(),
+            BotCommandScope::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -62,12 +69,44 @@ impl RObject for BotCommandScope {
             BotCommandScope::ChatAdministrators(t) => t.client_id(),
             BotCommandScope::ChatMember(t) => t.client_id(),
             BotCommandScope::Default(t) => t.client_id(),
+            BotCommandScope::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for BotCommandScope {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "botCommandScopeAllChatAdministrators" => {
+                BotCommandScope::AllChatAdministrators(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeAllGroupChats" => {
+                BotCommandScope::AllGroupChats(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeAllPrivateChats" => {
+                BotCommandScope::AllPrivateChats(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeChat" => BotCommandScope::Chat(super::_common::from_raw(raw)?),
+            "botCommandScopeChatAdministrators" => {
+                BotCommandScope::ChatAdministrators(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeChatMember" => {
+                BotCommandScope::ChatMember(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeDefault" => BotCommandScope::Default(super::_common::from_raw(raw)?),
+            _ => BotCommandScope::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl BotCommandScope {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCallDiscardReason: Debug + RObject {}
 
 /// Describes the reason why a call was discarded
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CallDiscardReason {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum CallDiscardReason {
     /// The call was ended before the conversation started. It was canceled by the caller or missed by the other party
     #[serde(rename = "callDiscardReasonMissed")]
     Missed(CallDiscardReasonMissed),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CallDiscardReason {
@@ -40,6 +46,7 @@ impl RObject for CallDiscardReason {
             CallDiscardReason::Empty(t) => t.extra(),
             CallDiscardReason::HungUp(t) => t.extra(),
             CallDiscardReason::Missed(t) => t.extra(),
+            CallDiscardReason::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -52,12 +59,36 @@ impl RObject for CallDiscardReason {
             CallDiscardReason::Empty(t) => t.client_id(),
             CallDiscardReason::HungUp(t) => t.client_id(),
             CallDiscardReason::Missed(t) => t.client_id(),
+            CallDiscardReason::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CallDiscardReason {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "callDiscardReasonDeclined" => {
+                CallDiscardReason::Declined(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonDisconnected" => {
+                CallDiscardReason::Disconnected(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonEmpty" => CallDiscardReason::Empty(super::_common::from_raw(raw)?),
+            "callDiscardReasonHungUp" => CallDiscardReason::HungUp(super::_common::from_raw(raw)?),
+            "callDiscardReasonMissed" => CallDiscardReason::Missed(super::_common::from_raw(raw)?),
+            _ => CallDiscardReason::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CallDiscardReason {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
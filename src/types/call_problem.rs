@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCallProblem: Debug + RObject {}
 
 /// Describes the exact type of a problem with a call
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CallProblem {
     #[doc(hidden)]
@@ -41,6 +41,12 @@ pub enum CallProblem {
     /// The other side couldn't hear the user
     #[serde(rename = "callProblemSilentRemote")]
     SilentRemote(CallProblemSilentRemote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CallProblem {
@@ -56,6 +62,7 @@ impl RObject for CallProblem {
             CallProblem::PixelatedVideo(t) => t.extra(),
             CallProblem::SilentLocal(t) => t.extra(),
             CallProblem::SilentRemote(t) => t.extra(),
+            CallProblem::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -72,12 +79,44 @@ impl RObject for CallProblem {
             CallProblem::PixelatedVideo(t) => t.client_id(),
             CallProblem::SilentLocal(t) => t.client_id(),
             CallProblem::SilentRemote(t) => t.client_id(),
+            CallProblem::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CallProblem {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "callProblemDistortedSpeech" => {
+                CallProblem::DistortedSpeech(super::_common::from_raw(raw)?)
+            }
+            "callProblemDistortedVideo" => {
+                CallProblem::DistortedVideo(super::_common::from_raw(raw)?)
+            }
+            "callProblemDropped" => CallProblem::Dropped(super::_common::from_raw(raw)?),
+            "callProblemEcho" => CallProblem::Echo(super::_common::from_raw(raw)?),
+            "callProblemInterruptions" => {
+                CallProblem::Interruptions(super::_common::from_raw(raw)?)
+            }
+            "callProblemNoise" => CallProblem::Noise(super::_common::from_raw(raw)?),
+            "callProblemPixelatedVideo" => {
+                CallProblem::PixelatedVideo(super::_common::from_raw(raw)?)
+            }
+            "callProblemSilentLocal" => CallProblem::SilentLocal(super::_common::from_raw(raw)?),
+            "callProblemSilentRemote" => CallProblem::SilentRemote(super::_common::from_raw(raw)?),
+            _ => CallProblem::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CallProblem {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
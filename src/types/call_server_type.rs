@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCallServerType: Debug + RObject {}
 
 /// Describes the type of a call server
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CallServerType {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum CallServerType {
     /// A WebRTC server
     #[serde(rename = "callServerTypeWebrtc")]
     Webrtc(CallServerTypeWebrtc),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CallServerType {
@@ -28,6 +34,7 @@ impl RObject for CallServerType {
         match self {
             CallServerType::TelegramReflector(t) => t.extra(),
             CallServerType::Webrtc(t) => t.extra(),
+            CallServerType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,31 @@ impl RObject for CallServerType {
         match self {
             CallServerType::TelegramReflector(t) => t.client_id(),
             CallServerType::Webrtc(t) => t.client_id(),
+            CallServerType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CallServerType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "callServerTypeTelegramReflector" => {
+                CallServerType::TelegramReflector(super::_common::from_raw(raw)?)
+            }
+            "callServerTypeWebrtc" => CallServerType::Webrtc(super::_common::from_raw(raw)?),
+            _ => CallServerType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CallServerType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
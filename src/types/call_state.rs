@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCallState: Debug + RObject {}
 
 /// Describes the current call state
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CallState {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum CallState {
     /// The call is ready to use
     #[serde(rename = "callStateReady")]
     Ready(CallStateReady),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CallState {
@@ -44,6 +50,7 @@ impl RObject for CallState {
             CallState::HangingUp(t) => t.extra(),
             CallState::Pending(t) => t.extra(),
             CallState::Ready(t) => t.extra(),
+            CallState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -57,12 +64,33 @@ impl RObject for CallState {
             CallState::HangingUp(t) => t.client_id(),
             CallState::Pending(t) => t.client_id(),
             CallState::Ready(t) => t.client_id(),
+            CallState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CallState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "callStateDiscarded" => CallState::Discarded(super::_common::from_raw(raw)?),
+            "callStateError" => CallState::Error(super::_common::from_raw(raw)?),
+            "callStateExchangingKeys" => CallState::ExchangingKeys(super::_common::from_raw(raw)?),
+            "callStateHangingUp" => CallState::HangingUp(super::_common::from_raw(raw)?),
+            "callStatePending" => CallState::Pending(super::_common::from_raw(raw)?),
+            "callStateReady" => CallState::Ready(super::_common::from_raw(raw)?),
+            _ => CallState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CallState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCallbackQueryPayload: Debug + RObject {}
 
 /// Represents a payload of a callback query
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CallbackQueryPayload {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum CallbackQueryPayload {
     /// The payload for a game callback button
     #[serde(rename = "callbackQueryPayloadGame")]
     Game(CallbackQueryPayloadGame),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CallbackQueryPayload {
@@ -32,6 +38,7 @@ impl RObject for CallbackQueryPayload {
             CallbackQueryPayload::Data(t) => t.extra(),
             CallbackQueryPayload::DataWithPassword(t) => t.extra(),
             CallbackQueryPayload::Game(t) => t.extra(),
+            CallbackQueryPayload::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,36 @@ impl RObject for CallbackQueryPayload {
             CallbackQueryPayload::Data(t) => t.client_id(),
             CallbackQueryPayload::DataWithPassword(t) => t.client_id(),
             CallbackQueryPayload::Game(t) => t.client_id(),
+            CallbackQueryPayload::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CallbackQueryPayload {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "callbackQueryPayloadData" => {
+                CallbackQueryPayload::Data(super::_common::from_raw(raw)?)
+            }
+            "callbackQueryPayloadDataWithPassword" => {
+                CallbackQueryPayload::DataWithPassword(super::_common::from_raw(raw)?)
+            }
+            "callbackQueryPayloadGame" => {
+                CallbackQueryPayload::Game(super::_common::from_raw(raw)?)
+            }
+            _ => CallbackQueryPayload::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CallbackQueryPayload {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -11,7 +11,7 @@ pub struct CanTransferOwnership {
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for CanTransferOwnership {
 
 impl TDCanTransferOwnershipResult for CanTransferOwnership {}
 
-impl RFunction for CanTransferOwnership {}
+impl RFunction for CanTransferOwnership {
+    type Response = CanTransferOwnershipResult;
+}
 
 impl CanTransferOwnership {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
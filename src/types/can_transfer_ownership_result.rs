@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCanTransferOwnershipResult: Debug + RObject {}
 
 /// Represents result of checking whether the current session can be used to transfer a chat ownership to another user
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CanTransferOwnershipResult {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum CanTransferOwnershipResult {
     /// The session was created recently, user needs to wait
     #[serde(rename = "canTransferOwnershipResultSessionTooFresh")]
     SessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CanTransferOwnershipResult {
@@ -40,6 +46,9 @@ impl RObject for CanTransferOwnershipResult {
             CanTransferOwnershipResult::PasswordNeeded(t) => t.extra(),
             CanTransferOwnershipResult::PasswordTooFresh(t) => t.extra(),
             CanTransferOwnershipResult::SessionTooFresh(t) => t.extra(),
+            CanTransferOwnershipResult::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -52,12 +61,42 @@ impl RObject for CanTransferOwnershipResult {
             CanTransferOwnershipResult::PasswordNeeded(t) => t.client_id(),
             CanTransferOwnershipResult::PasswordTooFresh(t) => t.client_id(),
             CanTransferOwnershipResult::SessionTooFresh(t) => t.client_id(),
+            CanTransferOwnershipResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CanTransferOwnershipResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "canTransferOwnership" => {
+                CanTransferOwnershipResult::CanTransferOwnership(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultOk" => {
+                CanTransferOwnershipResult::Ok(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultPasswordNeeded" => {
+                CanTransferOwnershipResult::PasswordNeeded(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultPasswordTooFresh" => {
+                CanTransferOwnershipResult::PasswordTooFresh(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultSessionTooFresh" => {
+                CanTransferOwnershipResult::SessionTooFresh(super::_common::from_raw(raw)?)
+            }
+            _ => CanTransferOwnershipResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CanTransferOwnershipResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -19,7 +19,7 @@ pub struct CancelDownloadFile {
     #[serde(default)]
     only_if_pending: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CancelDownloadFile {
     }
 }
 
-impl RFunction for CancelDownloadFile {}
+impl RFunction for CancelDownloadFile {
+    type Response = Ok;
+}
 
 impl CancelDownloadFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
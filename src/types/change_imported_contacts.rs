@@ -15,7 +15,7 @@ pub struct ChangeImportedContacts {
     #[serde(default)]
     contacts: Vec<Contact>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ChangeImportedContacts {
     }
 }
 
-impl RFunction for ChangeImportedContacts {}
+impl RFunction for ChangeImportedContacts {
+    type Response = ImportedContacts;
+}
 
 impl ChangeImportedContacts {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct ChangeStickerSet {
     #[serde(default)]
     is_archived: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for ChangeStickerSet {
     }
 }
 
-impl RFunction for ChangeStickerSet {}
+impl RFunction for ChangeStickerSet {
+    type Response = Ok;
+}
 
 impl ChangeStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatAction: Debug + RObject {}
 
 /// Describes the different types of activity in a chat
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatAction {
     #[doc(hidden)]
@@ -59,6 +59,12 @@ pub enum ChatAction {
     /// The user is watching animations sent by the other party by clicking on an animated emoji
     #[serde(rename = "chatActionWatchingAnimations")]
     WatchingAnimations(ChatActionWatchingAnimations),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatAction {
@@ -80,6 +86,7 @@ impl RObject for ChatAction {
             ChatAction::UploadingVideoNote(t) => t.extra(),
             ChatAction::UploadingVoiceNote(t) => t.extra(),
             ChatAction::WatchingAnimations(t) => t.extra(),
+            ChatAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -102,12 +109,68 @@ impl RObject for ChatAction {
             ChatAction::UploadingVideoNote(t) => t.client_id(),
             ChatAction::UploadingVoiceNote(t) => t.client_id(),
             ChatAction::WatchingAnimations(t) => t.client_id(),
+            ChatAction::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatAction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatActionCancel" => ChatAction::Cancel(super::_common::from_raw(raw)?),
+            "chatActionChoosingContact" => {
+                ChatAction::ChoosingContact(super::_common::from_raw(raw)?)
+            }
+            "chatActionChoosingLocation" => {
+                ChatAction::ChoosingLocation(super::_common::from_raw(raw)?)
+            }
+            "chatActionChoosingSticker" => {
+                ChatAction::ChoosingSticker(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVideo" => {
+                ChatAction::RecordingVideo(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVideoNote" => {
+                ChatAction::RecordingVideoNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVoiceNote" => {
+                ChatAction::RecordingVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionStartPlayingGame" => {
+                ChatAction::StartPlayingGame(super::_common::from_raw(raw)?)
+            }
+            "chatActionTyping" => ChatAction::Typing(super::_common::from_raw(raw)?),
+            "chatActionUploadingDocument" => {
+                ChatAction::UploadingDocument(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingPhoto" => {
+                ChatAction::UploadingPhoto(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVideo" => {
+                ChatAction::UploadingVideo(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVideoNote" => {
+                ChatAction::UploadingVideoNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVoiceNote" => {
+                ChatAction::UploadingVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionWatchingAnimations" => {
+                ChatAction::WatchingAnimations(super::_common::from_raw(raw)?)
+            }
+            _ => ChatAction::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
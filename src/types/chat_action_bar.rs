@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatActionBar: Debug + RObject {}
 
 /// Describes actions which must be possible to do through a chat action bar
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatActionBar {
     #[doc(hidden)]
@@ -35,6 +35,12 @@ pub enum ChatActionBar {
     /// The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber
     #[serde(rename = "chatActionBarSharePhoneNumber")]
     SharePhoneNumber(ChatActionBarSharePhoneNumber),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatActionBar {
@@ -48,6 +54,7 @@ impl RObject for ChatActionBar {
             ChatActionBar::ReportSpam(t) => t.extra(),
             ChatActionBar::ReportUnrelatedLocation(t) => t.extra(),
             ChatActionBar::SharePhoneNumber(t) => t.extra(),
+            ChatActionBar::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -62,12 +69,44 @@ impl RObject for ChatActionBar {
             ChatActionBar::ReportSpam(t) => t.client_id(),
             ChatActionBar::ReportUnrelatedLocation(t) => t.client_id(),
             ChatActionBar::SharePhoneNumber(t) => t.client_id(),
+            ChatActionBar::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatActionBar {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatActionBarAddContact" => ChatActionBar::AddContact(super::_common::from_raw(raw)?),
+            "chatActionBarInviteMembers" => {
+                ChatActionBar::InviteMembers(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarJoinRequest" => {
+                ChatActionBar::JoinRequest(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarReportAddBlock" => {
+                ChatActionBar::ReportAddBlock(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarReportSpam" => ChatActionBar::ReportSpam(super::_common::from_raw(raw)?),
+            "chatActionBarReportUnrelatedLocation" => {
+                ChatActionBar::ReportUnrelatedLocation(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarSharePhoneNumber" => {
+                ChatActionBar::SharePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            _ => ChatActionBar::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatActionBar {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatEventAction: Debug + RObject {}
 
 /// Represents a chat event
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatEventAction {
     #[doc(hidden)]
@@ -118,6 +118,12 @@ pub enum ChatEventAction {
     ChatEventVideoChatParticipantVolumeLevelChanged(
         ChatEventVideoChatParticipantVolumeLevelChanged,
     ),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatEventAction {
@@ -158,6 +164,7 @@ impl RObject for ChatEventAction {
             ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.extra(),
             ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) => t.extra(),
             ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.extra(),
+            ChatEventAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -199,12 +206,137 @@ impl RObject for ChatEventAction {
             ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.client_id(),
             ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) => t.client_id(),
             ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.client_id(),
+            ChatEventAction::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatEventAction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatEventDescriptionChanged" => {
+                ChatEventAction::ChatEventDescriptionChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventHasProtectedContentToggled" => {
+                ChatEventAction::ChatEventHasProtectedContentToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkDeleted" => {
+                ChatEventAction::ChatEventInviteLinkDeleted(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkEdited" => {
+                ChatEventAction::ChatEventInviteLinkEdited(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkRevoked" => {
+                ChatEventAction::ChatEventInviteLinkRevoked(super::_common::from_raw(raw)?)
+            }
+            "chatEventInvitesToggled" => {
+                ChatEventAction::ChatEventInvitesToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventIsAllHistoryAvailableToggled" => {
+                ChatEventAction::ChatEventIsAllHistoryAvailableToggled(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "chatEventLinkedChatChanged" => {
+                ChatEventAction::ChatEventLinkedChatChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventLocationChanged" => {
+                ChatEventAction::ChatEventLocationChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberInvited" => {
+                ChatEventAction::ChatEventMemberInvited(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoined" => {
+                ChatEventAction::ChatEventMemberJoined(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoinedByInviteLink" => {
+                ChatEventAction::ChatEventMemberJoinedByInviteLink(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoinedByRequest" => {
+                ChatEventAction::ChatEventMemberJoinedByRequest(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberLeft" => {
+                ChatEventAction::ChatEventMemberLeft(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberPromoted" => {
+                ChatEventAction::ChatEventMemberPromoted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberRestricted" => {
+                ChatEventAction::ChatEventMemberRestricted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageDeleted" => {
+                ChatEventAction::ChatEventMessageDeleted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageEdited" => {
+                ChatEventAction::ChatEventMessageEdited(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessagePinned" => {
+                ChatEventAction::ChatEventMessagePinned(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageTtlChanged" => {
+                ChatEventAction::ChatEventMessageTtlChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageUnpinned" => {
+                ChatEventAction::ChatEventMessageUnpinned(super::_common::from_raw(raw)?)
+            }
+            "chatEventPermissionsChanged" => {
+                ChatEventAction::ChatEventPermissionsChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventPhotoChanged" => {
+                ChatEventAction::ChatEventPhotoChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventPollStopped" => {
+                ChatEventAction::ChatEventPollStopped(super::_common::from_raw(raw)?)
+            }
+            "chatEventSignMessagesToggled" => {
+                ChatEventAction::ChatEventSignMessagesToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventSlowModeDelayChanged" => {
+                ChatEventAction::ChatEventSlowModeDelayChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventStickerSetChanged" => {
+                ChatEventAction::ChatEventStickerSetChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventTitleChanged" => {
+                ChatEventAction::ChatEventTitleChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventUsernameChanged" => {
+                ChatEventAction::ChatEventUsernameChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatCreated" => {
+                ChatEventAction::ChatEventVideoChatCreated(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatEnded" => {
+                ChatEventAction::ChatEventVideoChatEnded(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatMuteNewParticipantsToggled" => {
+                ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(
+                    super::_common::from_raw(raw)?,
+                )
+            }
+            "chatEventVideoChatParticipantIsMutedToggled" => {
+                ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(
+                    super::_common::from_raw(raw)?,
+                )
+            }
+            "chatEventVideoChatParticipantVolumeLevelChanged" => {
+                ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(
+                    super::_common::from_raw(raw)?,
+                )
+            }
+            _ => ChatEventAction::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatEventAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
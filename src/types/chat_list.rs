@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatList: Debug + RObject {}
 
 /// Describes a list of chats
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatList {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum ChatList {
     /// A main list of chats
     #[serde(rename = "chatListMain")]
     Main(ChatListMain),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatList {
@@ -32,6 +38,7 @@ impl RObject for ChatList {
             ChatList::Archive(t) => t.extra(),
             ChatList::Filter(t) => t.extra(),
             ChatList::Main(t) => t.extra(),
+            ChatList::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,30 @@ impl RObject for ChatList {
             ChatList::Archive(t) => t.client_id(),
             ChatList::Filter(t) => t.client_id(),
             ChatList::Main(t) => t.client_id(),
+            ChatList::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatList {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatListArchive" => ChatList::Archive(super::_common::from_raw(raw)?),
+            "chatListFilter" => ChatList::Filter(super::_common::from_raw(raw)?),
+            "chatListMain" => ChatList::Main(super::_common::from_raw(raw)?),
+            _ => ChatList::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatList {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
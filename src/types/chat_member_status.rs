@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatMemberStatus: Debug + RObject {}
 
 /// Provides information about the status of a member in a chat
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatMemberStatus {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum ChatMemberStatus {
     /// The user is under certain restrictions in the chat. Not supported in basic groups and channels
     #[serde(rename = "chatMemberStatusRestricted")]
     Restricted(ChatMemberStatusRestricted),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatMemberStatus {
@@ -44,6 +50,7 @@ impl RObject for ChatMemberStatus {
             ChatMemberStatus::Left(t) => t.extra(),
             ChatMemberStatus::Member(t) => t.extra(),
             ChatMemberStatus::Restricted(t) => t.extra(),
+            ChatMemberStatus::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -57,12 +64,37 @@ impl RObject for ChatMemberStatus {
             ChatMemberStatus::Left(t) => t.client_id(),
             ChatMemberStatus::Member(t) => t.client_id(),
             ChatMemberStatus::Restricted(t) => t.client_id(),
+            ChatMemberStatus::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatMemberStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatMemberStatusAdministrator" => {
+                ChatMemberStatus::Administrator(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusBanned" => ChatMemberStatus::Banned(super::_common::from_raw(raw)?),
+            "chatMemberStatusCreator" => ChatMemberStatus::Creator(super::_common::from_raw(raw)?),
+            "chatMemberStatusLeft" => ChatMemberStatus::Left(super::_common::from_raw(raw)?),
+            "chatMemberStatusMember" => ChatMemberStatus::Member(super::_common::from_raw(raw)?),
+            "chatMemberStatusRestricted" => {
+                ChatMemberStatus::Restricted(super::_common::from_raw(raw)?)
+            }
+            _ => ChatMemberStatus::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatMemberStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
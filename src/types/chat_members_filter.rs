@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in searchChatMembers
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatMembersFilter {
     #[doc(hidden)]
@@ -35,6 +35,12 @@ pub enum ChatMembersFilter {
     /// Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup
     #[serde(rename = "chatMembersFilterRestricted")]
     Restricted(ChatMembersFilterRestricted),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatMembersFilter {
@@ -48,6 +54,7 @@ impl RObject for ChatMembersFilter {
             ChatMembersFilter::Members(t) => t.extra(),
             ChatMembersFilter::Mention(t) => t.extra(),
             ChatMembersFilter::Restricted(t) => t.extra(),
+            ChatMembersFilter::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -62,12 +69,44 @@ impl RObject for ChatMembersFilter {
             ChatMembersFilter::Members(t) => t.client_id(),
             ChatMembersFilter::Mention(t) => t.client_id(),
             ChatMembersFilter::Restricted(t) => t.client_id(),
+            ChatMembersFilter::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatMembersFilter {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatMembersFilterAdministrators" => {
+                ChatMembersFilter::Administrators(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterBanned" => ChatMembersFilter::Banned(super::_common::from_raw(raw)?),
+            "chatMembersFilterBots" => ChatMembersFilter::Bots(super::_common::from_raw(raw)?),
+            "chatMembersFilterContacts" => {
+                ChatMembersFilter::Contacts(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterMembers" => {
+                ChatMembersFilter::Members(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterMention" => {
+                ChatMembersFilter::Mention(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterRestricted" => {
+                ChatMembersFilter::Restricted(super::_common::from_raw(raw)?)
+            }
+            _ => ChatMembersFilter::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatMembersFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatReportReason: Debug + RObject {}
 
 /// Describes the reason why a chat is reported
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatReportReason {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum ChatReportReason {
     /// The chat promotes violence
     #[serde(rename = "chatReportReasonViolence")]
     Violence(ChatReportReasonViolence),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatReportReason {
@@ -52,6 +58,7 @@ impl RObject for ChatReportReason {
             ChatReportReason::Spam(t) => t.extra(),
             ChatReportReason::UnrelatedLocation(t) => t.extra(),
             ChatReportReason::Violence(t) => t.extra(),
+            ChatReportReason::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -67,12 +74,45 @@ impl RObject for ChatReportReason {
             ChatReportReason::Spam(t) => t.client_id(),
             ChatReportReason::UnrelatedLocation(t) => t.client_id(),
             ChatReportReason::Violence(t) => t.client_id(),
+            ChatReportReason::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatReportReason {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatReportReasonChildAbuse" => {
+                ChatReportReason::ChildAbuse(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonCopyright" => {
+                ChatReportReason::Copyright(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonCustom" => ChatReportReason::Custom(super::_common::from_raw(raw)?),
+            "chatReportReasonFake" => ChatReportReason::Fake(super::_common::from_raw(raw)?),
+            "chatReportReasonPornography" => {
+                ChatReportReason::Pornography(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonSpam" => ChatReportReason::Spam(super::_common::from_raw(raw)?),
+            "chatReportReasonUnrelatedLocation" => {
+                ChatReportReason::UnrelatedLocation(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonViolence" => {
+                ChatReportReason::Violence(super::_common::from_raw(raw)?)
+            }
+            _ => ChatReportReason::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatReportReason {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatSource: Debug + RObject {}
 
 /// Describes a reason why an external chat is shown in a chat list
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatSource {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum ChatSource {
     /// The chat contains a public service announcement
     #[serde(rename = "chatSourcePublicServiceAnnouncement")]
     PublicServiceAnnouncement(ChatSourcePublicServiceAnnouncement),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatSource {
@@ -28,6 +34,7 @@ impl RObject for ChatSource {
         match self {
             ChatSource::MtprotoProxy(t) => t.extra(),
             ChatSource::PublicServiceAnnouncement(t) => t.extra(),
+            ChatSource::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,31 @@ impl RObject for ChatSource {
         match self {
             ChatSource::MtprotoProxy(t) => t.client_id(),
             ChatSource::PublicServiceAnnouncement(t) => t.client_id(),
+            ChatSource::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatSource {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatSourceMtprotoProxy" => ChatSource::MtprotoProxy(super::_common::from_raw(raw)?),
+            "chatSourcePublicServiceAnnouncement" => {
+                ChatSource::PublicServiceAnnouncement(super::_common::from_raw(raw)?)
+            }
+            _ => ChatSource::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatSource {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatStatistics: Debug + RObject {}
 
 /// Contains a detailed statistics about a chat
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatStatistics {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum ChatStatistics {
     /// Returns detailed statistics about a chat. Currently, this method can be used only for supergroups and channels. Can be used only if supergroupFullInfo.can_get_statistics == true
     #[serde(rename = "getChatStatistics")]
     GetChatStatistics(GetChatStatistics),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatStatistics {
@@ -32,6 +38,7 @@ impl RObject for ChatStatistics {
             ChatStatistics::Channel(t) => t.extra(),
             ChatStatistics::Supergroup(t) => t.extra(),
             ChatStatistics::GetChatStatistics(t) => t.extra(),
+            ChatStatistics::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,34 @@ impl RObject for ChatStatistics {
             ChatStatistics::Channel(t) => t.client_id(),
             ChatStatistics::Supergroup(t) => t.client_id(),
             ChatStatistics::GetChatStatistics(t) => t.client_id(),
+            ChatStatistics::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatStatistics {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatStatisticsChannel" => ChatStatistics::Channel(super::_common::from_raw(raw)?),
+            "chatStatisticsSupergroup" => {
+                ChatStatistics::Supergroup(super::_common::from_raw(raw)?)
+            }
+            "getChatStatistics" => {
+                ChatStatistics::GetChatStatistics(super::_common::from_raw(raw)?)
+            }
+            _ => ChatStatistics::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
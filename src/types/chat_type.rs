@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDChatType: Debug + RObject {}
 
 /// Describes the type of a chat
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ChatType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum ChatType {
     /// A supergroup or channel (with unlimited members)
     #[serde(rename = "chatTypeSupergroup")]
     Supergroup(ChatTypeSupergroup),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ChatType {
@@ -36,6 +42,7 @@ impl RObject for ChatType {
             ChatType::Private(t) => t.extra(),
             ChatType::Secret(t) => t.extra(),
             ChatType::Supergroup(t) => t.extra(),
+            ChatType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for ChatType {
             ChatType::Private(t) => t.client_id(),
             ChatType::Secret(t) => t.client_id(),
             ChatType::Supergroup(t) => t.client_id(),
+            ChatType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ChatType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "chatTypeBasicGroup" => ChatType::BasicGroup(super::_common::from_raw(raw)?),
+            "chatTypePrivate" => ChatType::Private(super::_common::from_raw(raw)?),
+            "chatTypeSecret" => ChatType::Secret(super::_common::from_raw(raw)?),
+            "chatTypeSupergroup" => ChatType::Supergroup(super::_common::from_raw(raw)?),
+            _ => ChatType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ChatType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
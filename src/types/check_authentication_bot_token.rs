@@ -15,7 +15,7 @@ pub struct CheckAuthenticationBotToken {
     #[serde(default)]
     token: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckAuthenticationBotToken {
     }
 }
 
-impl RFunction for CheckAuthenticationBotToken {}
+impl RFunction for CheckAuthenticationBotToken {
+    type Response = Ok;
+}
 
 impl CheckAuthenticationBotToken {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
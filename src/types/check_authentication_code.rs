@@ -15,7 +15,7 @@ pub struct CheckAuthenticationCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckAuthenticationCode {
     }
 }
 
-impl RFunction for CheckAuthenticationCode {}
+impl RFunction for CheckAuthenticationCode {
+    type Response = Ok;
+}
 
 impl CheckAuthenticationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckAuthenticationPassword {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckAuthenticationPassword {
     }
 }
 
-impl RFunction for CheckAuthenticationPassword {}
+impl RFunction for CheckAuthenticationPassword {
+    type Response = Ok;
+}
 
 impl CheckAuthenticationPassword {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckAuthenticationPasswordRecoveryCode {
     #[serde(default)]
     recovery_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckAuthenticationPasswordRecoveryCode {
     }
 }
 
-impl RFunction for CheckAuthenticationPasswordRecoveryCode {}
+impl RFunction for CheckAuthenticationPasswordRecoveryCode {
+    type Response = Ok;
+}
 
 impl CheckAuthenticationPasswordRecoveryCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
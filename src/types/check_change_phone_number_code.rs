@@ -15,7 +15,7 @@ pub struct CheckChangePhoneNumberCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckChangePhoneNumberCode {
     }
 }
 
-impl RFunction for CheckChangePhoneNumberCode {}
+impl RFunction for CheckChangePhoneNumberCode {
+    type Response = Ok;
+}
 
 impl CheckChangePhoneNumberCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
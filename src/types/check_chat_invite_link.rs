@@ -15,7 +15,7 @@ pub struct CheckChatInviteLink {
     #[serde(default)]
     invite_link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckChatInviteLink {
     }
 }
 
-impl RFunction for CheckChatInviteLink {}
+impl RFunction for CheckChatInviteLink {
+    type Response = ChatInviteLinkInfo;
+}
 
 impl CheckChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct CheckChatUsername {
     #[serde(default)]
     username: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for CheckChatUsername {
 
 impl TDCheckChatUsernameResult for CheckChatUsername {}
 
-impl RFunction for CheckChatUsername {}
+impl RFunction for CheckChatUsername {
+    type Response = CheckChatUsernameResult;
+}
 
 impl CheckChatUsername {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCheckChatUsernameResult: Debug + RObject {}
 
 /// Represents result of checking whether a username can be set for a chat
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CheckChatUsernameResult {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum CheckChatUsernameResult {
     /// The username is occupied
     #[serde(rename = "checkChatUsernameResultUsernameOccupied")]
     UsernameOccupied(CheckChatUsernameResultUsernameOccupied),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CheckChatUsernameResult {
@@ -44,6 +50,9 @@ impl RObject for CheckChatUsernameResult {
             CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.extra(),
             CheckChatUsernameResult::UsernameInvalid(t) => t.extra(),
             CheckChatUsernameResult::UsernameOccupied(t) => t.extra(),
+            CheckChatUsernameResult::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -57,12 +66,45 @@ impl RObject for CheckChatUsernameResult {
             CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.client_id(),
             CheckChatUsernameResult::UsernameInvalid(t) => t.client_id(),
             CheckChatUsernameResult::UsernameOccupied(t) => t.client_id(),
+            CheckChatUsernameResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CheckChatUsernameResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "checkChatUsername" => {
+                CheckChatUsernameResult::CheckChatUsername(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultOk" => {
+                CheckChatUsernameResult::Ok(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultPublicChatsTooMuch" => {
+                CheckChatUsernameResult::PublicChatsTooMuch(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultPublicGroupsUnavailable" => {
+                CheckChatUsernameResult::PublicGroupsUnavailable(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultUsernameInvalid" => {
+                CheckChatUsernameResult::UsernameInvalid(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultUsernameOccupied" => {
+                CheckChatUsernameResult::UsernameOccupied(super::_common::from_raw(raw)?)
+            }
+            _ => CheckChatUsernameResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CheckChatUsernameResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
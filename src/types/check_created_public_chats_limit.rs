@@ -16,7 +16,7 @@ pub struct CheckCreatedPublicChatsLimit {
     #[serde(skip_serializing_if = "PublicChatType::_is_default")]
     type_: PublicChatType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -31,7 +31,9 @@ impl RObject for CheckCreatedPublicChatsLimit {
     }
 }
 
-impl RFunction for CheckCreatedPublicChatsLimit {}
+impl RFunction for CheckCreatedPublicChatsLimit {
+    type Response = Ok;
+}
 
 impl CheckCreatedPublicChatsLimit {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckDatabaseEncryptionKey {
     #[serde(default)]
     encryption_key: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckDatabaseEncryptionKey {
     }
 }
 
-impl RFunction for CheckDatabaseEncryptionKey {}
+impl RFunction for CheckDatabaseEncryptionKey {
+    type Response = Ok;
+}
 
 impl CheckDatabaseEncryptionKey {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckEmailAddressVerificationCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckEmailAddressVerificationCode {
     }
 }
 
-impl RFunction for CheckEmailAddressVerificationCode {}
+impl RFunction for CheckEmailAddressVerificationCode {
+    type Response = Ok;
+}
 
 impl CheckEmailAddressVerificationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
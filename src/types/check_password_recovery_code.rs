@@ -15,7 +15,7 @@ pub struct CheckPasswordRecoveryCode {
     #[serde(default)]
     recovery_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckPasswordRecoveryCode {
     }
 }
 
-impl RFunction for CheckPasswordRecoveryCode {}
+impl RFunction for CheckPasswordRecoveryCode {
+    type Response = Ok;
+}
 
 impl CheckPasswordRecoveryCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckPhoneNumberConfirmationCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckPhoneNumberConfirmationCode {
     }
 }
 
-impl RFunction for CheckPhoneNumberConfirmationCode {}
+impl RFunction for CheckPhoneNumberConfirmationCode {
+    type Response = Ok;
+}
 
 impl CheckPhoneNumberConfirmationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckPhoneNumberVerificationCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckPhoneNumberVerificationCode {
     }
 }
 
-impl RFunction for CheckPhoneNumberVerificationCode {}
+impl RFunction for CheckPhoneNumberVerificationCode {
+    type Response = Ok;
+}
 
 impl CheckPhoneNumberVerificationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckRecoveryEmailAddressCode {
     #[serde(default)]
     code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CheckRecoveryEmailAddressCode {
     }
 }
 
-impl RFunction for CheckRecoveryEmailAddressCode {}
+impl RFunction for CheckRecoveryEmailAddressCode {
+    type Response = PasswordState;
+}
 
 impl CheckRecoveryEmailAddressCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CheckStickerSetName {
     #[serde(default)]
     name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for CheckStickerSetName {
 
 impl TDCheckStickerSetNameResult for CheckStickerSetName {}
 
-impl RFunction for CheckStickerSetName {}
+impl RFunction for CheckStickerSetName {
+    type Response = CheckStickerSetNameResult;
+}
 
 impl CheckStickerSetName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
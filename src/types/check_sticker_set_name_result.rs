@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDCheckStickerSetNameResult: Debug + RObject {}
 
 /// Represents result of checking whether a name can be used for a new sticker set
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum CheckStickerSetNameResult {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum CheckStickerSetNameResult {
     /// The name can be set
     #[serde(rename = "checkStickerSetNameResultOk")]
     Ok(CheckStickerSetNameResultOk),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for CheckStickerSetNameResult {
@@ -36,6 +42,9 @@ impl RObject for CheckStickerSetNameResult {
             CheckStickerSetNameResult::NameInvalid(t) => t.extra(),
             CheckStickerSetNameResult::NameOccupied(t) => t.extra(),
             CheckStickerSetNameResult::Ok(t) => t.extra(),
+            CheckStickerSetNameResult::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -47,12 +56,39 @@ impl RObject for CheckStickerSetNameResult {
             CheckStickerSetNameResult::NameInvalid(t) => t.client_id(),
             CheckStickerSetNameResult::NameOccupied(t) => t.client_id(),
             CheckStickerSetNameResult::Ok(t) => t.client_id(),
+            CheckStickerSetNameResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for CheckStickerSetNameResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "checkStickerSetName" => {
+                CheckStickerSetNameResult::CheckStickerSetName(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultNameInvalid" => {
+                CheckStickerSetNameResult::NameInvalid(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultNameOccupied" => {
+                CheckStickerSetNameResult::NameOccupied(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultOk" => {
+                CheckStickerSetNameResult::Ok(super::_common::from_raw(raw)?)
+            }
+            _ => CheckStickerSetNameResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl CheckStickerSetNameResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -15,7 +15,7 @@ pub struct CleanFileName {
     #[serde(default)]
     file_name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CleanFileName {
     }
 }
 
-impl RFunction for CleanFileName {}
+impl RFunction for CleanFileName {
+    type Response = Text;
+}
 
 impl CleanFileName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct ClearAllDraftMessages {
     #[serde(default)]
     exclude_secret_chats: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ClearAllDraftMessages {
     }
 }
 
-impl RFunction for ClearAllDraftMessages {}
+impl RFunction for ClearAllDraftMessages {
+    type Response = Ok;
+}
 
 impl ClearAllDraftMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct ClearRecentStickers {
     #[serde(default)]
     is_attached: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ClearRecentStickers {
     }
 }
 
-impl RFunction for ClearRecentStickers {}
+impl RFunction for ClearRecentStickers {
+    type Response = Ok;
+}
 
 impl ClearRecentStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CloseSecretChat {
     #[serde(default)]
     secret_chat_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CloseSecretChat {
     }
 }
 
-impl RFunction for CloseSecretChat {}
+impl RFunction for CloseSecretChat {
+    type Response = Ok;
+}
 
 impl CloseSecretChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
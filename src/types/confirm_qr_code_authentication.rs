@@ -15,7 +15,7 @@ pub struct ConfirmQrCodeAuthentication {
     #[serde(default)]
     link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ConfirmQrCodeAuthentication {
     }
 }
 
-impl RFunction for ConfirmQrCodeAuthentication {}
+impl RFunction for ConfirmQrCodeAuthentication {
+    type Response = Session;
+}
 
 impl ConfirmQrCodeAuthentication {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
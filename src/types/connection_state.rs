@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDConnectionState: Debug + RObject {}
 
 /// Describes the current state of the connection to Telegram servers
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ConnectionState {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum ConnectionState {
     /// Currently waiting for the network to become available. Use setNetworkType to change the available network type
     #[serde(rename = "connectionStateWaitingForNetwork")]
     WaitingForNetwork(ConnectionStateWaitingForNetwork),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ConnectionState {
@@ -40,6 +46,7 @@ impl RObject for ConnectionState {
             ConnectionState::Ready(t) => t.extra(),
             ConnectionState::Updating(t) => t.extra(),
             ConnectionState::WaitingForNetwork(t) => t.extra(),
+            ConnectionState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -52,12 +59,38 @@ impl RObject for ConnectionState {
             ConnectionState::Ready(t) => t.client_id(),
             ConnectionState::Updating(t) => t.client_id(),
             ConnectionState::WaitingForNetwork(t) => t.client_id(),
+            ConnectionState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ConnectionState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "connectionStateConnecting" => {
+                ConnectionState::Connecting(super::_common::from_raw(raw)?)
+            }
+            "connectionStateConnectingToProxy" => {
+                ConnectionState::ConnectingToProxy(super::_common::from_raw(raw)?)
+            }
+            "connectionStateReady" => ConnectionState::Ready(super::_common::from_raw(raw)?),
+            "connectionStateUpdating" => ConnectionState::Updating(super::_common::from_raw(raw)?),
+            "connectionStateWaitingForNetwork" => {
+                ConnectionState::WaitingForNetwork(super::_common::from_raw(raw)?)
+            }
+            _ => ConnectionState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ConnectionState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -19,7 +19,7 @@ pub struct CreateBasicGroupChat {
     #[serde(default)]
     force: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CreateBasicGroupChat {
     }
 }
 
-impl RFunction for CreateBasicGroupChat {}
+impl RFunction for CreateBasicGroupChat {
+    type Response = Chat;
+}
 
 impl CreateBasicGroupChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -21,7 +21,7 @@ pub struct CreateCall {
     #[serde(default)]
     is_video: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for CreateCall {
     }
 }
 
-impl RFunction for CreateCall {}
+impl RFunction for CreateCall {
+    type Response = CallId;
+}
 
 impl CreateCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
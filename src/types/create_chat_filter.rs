@@ -13,7 +13,7 @@ pub struct CreateChatFilter {
     /// Chat filter
     filter: ChatFilter,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for CreateChatFilter {
     }
 }
 
-impl RFunction for CreateChatFilter {}
+impl RFunction for CreateChatFilter {
+    type Response = ChatFilterInfo;
+}
 
 impl CreateChatFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -31,7 +31,7 @@ pub struct CreateChatInviteLink {
     #[serde(default)]
     creates_join_request: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for CreateChatInviteLink {
     }
 }
 
-impl RFunction for CreateChatInviteLink {}
+impl RFunction for CreateChatInviteLink {
+    type Response = ChatInviteLink;
+}
 
 impl CreateChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
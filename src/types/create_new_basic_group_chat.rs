@@ -19,7 +19,7 @@ pub struct CreateNewBasicGroupChat {
     #[serde(default)]
     title: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CreateNewBasicGroupChat {
     }
 }
 
-impl RFunction for CreateNewBasicGroupChat {}
+impl RFunction for CreateNewBasicGroupChat {
+    type Response = Chat;
+}
 
 impl CreateNewBasicGroupChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct CreateNewSecretChat {
     #[serde(default)]
     user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for CreateNewSecretChat {
     }
 }
 
-impl RFunction for CreateNewSecretChat {}
+impl RFunction for CreateNewSecretChat {
+    type Response = Chat;
+}
 
 impl CreateNewSecretChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
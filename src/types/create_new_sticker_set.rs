@@ -35,7 +35,7 @@ pub struct CreateNewStickerSet {
     #[serde(default)]
     source: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for CreateNewStickerSet {
     }
 }
 
-impl RFunction for CreateNewStickerSet {}
+impl RFunction for CreateNewStickerSet {
+    type Response = StickerSet;
+}
 
 impl CreateNewStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -29,7 +29,7 @@ pub struct CreateNewSupergroupChat {
     #[serde(default)]
     for_import: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for CreateNewSupergroupChat {
     }
 }
 
-impl RFunction for CreateNewSupergroupChat {}
+impl RFunction for CreateNewSupergroupChat {
+    type Response = Chat;
+}
 
 impl CreateNewSupergroupChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
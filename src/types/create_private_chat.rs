@@ -19,7 +19,7 @@ pub struct CreatePrivateChat {
     #[serde(default)]
     force: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CreatePrivateChat {
     }
 }
 
-impl RFunction for CreatePrivateChat {}
+impl RFunction for CreatePrivateChat {
+    type Response = Chat;
+}
 
 impl CreatePrivateChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
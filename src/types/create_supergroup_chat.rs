@@ -19,7 +19,7 @@ pub struct CreateSupergroupChat {
     #[serde(default)]
     force: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CreateSupergroupChat {
     }
 }
 
-impl RFunction for CreateSupergroupChat {}
+impl RFunction for CreateSupergroupChat {
+    type Response = Chat;
+}
 
 impl CreateSupergroupChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct CreateTemporaryPassword {
     #[serde(default)]
     valid_for: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for CreateTemporaryPassword {
     }
 }
 
-impl RFunction for CreateTemporaryPassword {}
+impl RFunction for CreateTemporaryPassword {
+    type Response = TemporaryPasswordState;
+}
 
 impl CreateTemporaryPassword {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
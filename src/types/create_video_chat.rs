@@ -23,7 +23,7 @@ pub struct CreateVideoChat {
     #[serde(default)]
     start_date: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for CreateVideoChat {
     }
 }
 
-impl RFunction for CreateVideoChat {}
+impl RFunction for CreateVideoChat {
+    type Response = GroupCallId;
+}
 
 impl CreateVideoChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
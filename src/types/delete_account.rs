@@ -15,7 +15,7 @@ pub struct DeleteAccount {
     #[serde(default)]
     reason: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for DeleteAccount {
     }
 }
 
-impl RFunction for DeleteAccount {}
+impl RFunction for DeleteAccount {
+    type Response = Ok;
+}
 
 impl DeleteAccount {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
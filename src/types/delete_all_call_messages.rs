@@ -15,7 +15,7 @@ pub struct DeleteAllCallMessages {
     #[serde(default)]
     revoke: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for DeleteAllCallMessages {
     }
 }
 
-impl RFunction for DeleteAllCallMessages {}
+impl RFunction for DeleteAllCallMessages {
+    type Response = Ok;
+}
 
 impl DeleteAllCallMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
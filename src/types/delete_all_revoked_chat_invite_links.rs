@@ -19,7 +19,7 @@ pub struct DeleteAllRevokedChatInviteLinks {
     #[serde(default)]
     creator_user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteAllRevokedChatInviteLinks {
     }
 }
 
-impl RFunction for DeleteAllRevokedChatInviteLinks {}
+impl RFunction for DeleteAllRevokedChatInviteLinks {
+    type Response = Ok;
+}
 
 impl DeleteAllRevokedChatInviteLinks {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
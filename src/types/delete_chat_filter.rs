@@ -15,7 +15,7 @@ pub struct DeleteChatFilter {
     #[serde(default)]
     chat_filter_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for DeleteChatFilter {
     }
 }
 
-impl RFunction for DeleteChatFilter {}
+impl RFunction for DeleteChatFilter {
+    type Response = Ok;
+}
 
 impl DeleteChatFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
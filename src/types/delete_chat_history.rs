@@ -23,7 +23,7 @@ pub struct DeleteChatHistory {
     #[serde(default)]
     revoke: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for DeleteChatHistory {
     }
 }
 
-impl RFunction for DeleteChatHistory {}
+impl RFunction for DeleteChatHistory {
+    type Response = Ok;
+}
 
 impl DeleteChatHistory {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
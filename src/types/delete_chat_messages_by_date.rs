@@ -27,7 +27,7 @@ pub struct DeleteChatMessagesByDate {
     #[serde(default)]
     revoke: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for DeleteChatMessagesByDate {
     }
 }
 
-impl RFunction for DeleteChatMessagesByDate {}
+impl RFunction for DeleteChatMessagesByDate {
+    type Response = Ok;
+}
 
 impl DeleteChatMessagesByDate {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct DeleteChatMessagesBySender {
     #[serde(skip_serializing_if = "MessageSender::_is_default")]
     sender_id: MessageSender,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteChatMessagesBySender {
     }
 }
 
-impl RFunction for DeleteChatMessagesBySender {}
+impl RFunction for DeleteChatMessagesBySender {
+    type Response = Ok;
+}
 
 impl DeleteChatMessagesBySender {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
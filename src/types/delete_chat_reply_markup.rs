@@ -19,7 +19,7 @@ pub struct DeleteChatReplyMarkup {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteChatReplyMarkup {
     }
 }
 
-impl RFunction for DeleteChatReplyMarkup {}
+impl RFunction for DeleteChatReplyMarkup {
+    type Response = Ok;
+}
 
 impl DeleteChatReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
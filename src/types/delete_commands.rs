@@ -19,7 +19,7 @@ pub struct DeleteCommands {
     #[serde(default)]
     language_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteCommands {
     }
 }
 
-impl RFunction for DeleteCommands {}
+impl RFunction for DeleteCommands {
+    type Response = Ok;
+}
 
 impl DeleteCommands {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
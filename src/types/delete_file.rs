@@ -15,7 +15,7 @@ pub struct DeleteFile {
     #[serde(default)]
     file_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for DeleteFile {
     }
 }
 
-impl RFunction for DeleteFile {}
+impl RFunction for DeleteFile {
+    type Response = Ok;
+}
 
 impl DeleteFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
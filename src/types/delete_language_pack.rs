@@ -15,7 +15,7 @@ pub struct DeleteLanguagePack {
     #[serde(default)]
     language_pack_id: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for DeleteLanguagePack {
     }
 }
 
-impl RFunction for DeleteLanguagePack {}
+impl RFunction for DeleteLanguagePack {
+    type Response = Ok;
+}
 
 impl DeleteLanguagePack {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct DeleteMessages {
     #[serde(default)]
     revoke: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for DeleteMessages {
     }
 }
 
-impl RFunction for DeleteMessages {}
+impl RFunction for DeleteMessages {
+    type Response = Ok;
+}
 
 impl DeleteMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
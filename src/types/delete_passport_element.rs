@@ -16,7 +16,7 @@ pub struct DeletePassportElement {
     #[serde(skip_serializing_if = "PassportElementType::_is_default")]
     type_: PassportElementType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -31,7 +31,9 @@ impl RObject for DeletePassportElement {
     }
 }
 
-impl RFunction for DeletePassportElement {}
+impl RFunction for DeletePassportElement {
+    type Response = Ok;
+}
 
 impl DeletePassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
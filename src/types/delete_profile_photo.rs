@@ -19,7 +19,7 @@ pub struct DeleteProfilePhoto {
     #[serde(default)]
     profile_photo_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteProfilePhoto {
     }
 }
 
-impl RFunction for DeleteProfilePhoto {}
+impl RFunction for DeleteProfilePhoto {
+    type Response = Ok;
+}
 
 impl DeleteProfilePhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
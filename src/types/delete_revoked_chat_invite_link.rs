@@ -19,7 +19,7 @@ pub struct DeleteRevokedChatInviteLink {
     #[serde(default)]
     invite_link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DeleteRevokedChatInviteLink {
     }
 }
 
-impl RFunction for DeleteRevokedChatInviteLink {}
+impl RFunction for DeleteRevokedChatInviteLink {
+    type Response = Ok;
+}
 
 impl DeleteRevokedChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDDeviceToken: Debug + RObject {}
 
 /// Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, the correct application platform must be specified and a valid server authentication data must be uploaded at https://my.telegram.org
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum DeviceToken {
     #[doc(hidden)]
@@ -47,6 +47,12 @@ pub enum DeviceToken {
     /// A token for Windows Push Notification Services
     #[serde(rename = "deviceTokenWindowsPush")]
     WindowsPush(DeviceTokenWindowsPush),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for DeviceToken {
@@ -64,6 +70,7 @@ impl RObject for DeviceToken {
             DeviceToken::UbuntuPush(t) => t.extra(),
             DeviceToken::WebPush(t) => t.extra(),
             DeviceToken::WindowsPush(t) => t.extra(),
+            DeviceToken::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -82,12 +89,48 @@ impl RObject for DeviceToken {
             DeviceToken::UbuntuPush(t) => t.client_id(),
             DeviceToken::WebPush(t) => t.client_id(),
             DeviceToken::WindowsPush(t) => t.client_id(),
+            DeviceToken::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for DeviceToken {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "deviceTokenApplePush" => DeviceToken::ApplePush(super::_common::from_raw(raw)?),
+            "deviceTokenApplePushVoIP" => {
+                DeviceToken::ApplePushVoIP(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenBlackBerryPush" => {
+                DeviceToken::BlackBerryPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenFirebaseCloudMessaging" => {
+                DeviceToken::FirebaseCloudMessaging(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenMicrosoftPush" => {
+                DeviceToken::MicrosoftPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenMicrosoftPushVoIP" => {
+                DeviceToken::MicrosoftPushVoIP(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenSimplePush" => DeviceToken::SimplePush(super::_common::from_raw(raw)?),
+            "deviceTokenTizenPush" => DeviceToken::TizenPush(super::_common::from_raw(raw)?),
+            "deviceTokenUbuntuPush" => DeviceToken::UbuntuPush(super::_common::from_raw(raw)?),
+            "deviceTokenWebPush" => DeviceToken::WebPush(super::_common::from_raw(raw)?),
+            "deviceTokenWindowsPush" => DeviceToken::WindowsPush(super::_common::from_raw(raw)?),
+            _ => DeviceToken::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl DeviceToken {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDDiceStickers: Debug + RObject {}
 
 /// Contains animated stickers which must be used for dice animation rendering
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum DiceStickers {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum DiceStickers {
     /// Animated stickers to be combined into a slot machine
     #[serde(rename = "diceStickersSlotMachine")]
     SlotMachine(Box<DiceStickersSlotMachine>),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for DiceStickers {
@@ -28,6 +34,7 @@ impl RObject for DiceStickers {
         match self {
             DiceStickers::Regular(t) => t.extra(),
             DiceStickers::SlotMachine(t) => t.extra(),
+            DiceStickers::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for DiceStickers {
         match self {
             DiceStickers::Regular(t) => t.client_id(),
             DiceStickers::SlotMachine(t) => t.client_id(),
+            DiceStickers::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for DiceStickers {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "diceStickersRegular" => DiceStickers::Regular(super::_common::from_raw(raw)?),
+            "diceStickersSlotMachine" => DiceStickers::SlotMachine(super::_common::from_raw(raw)?),
+            _ => DiceStickers::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl DiceStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
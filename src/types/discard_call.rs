@@ -35,7 +35,7 @@ pub struct DiscardCall {
     #[serde(default)]
     connection_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for DiscardCall {
     }
 }
 
-impl RFunction for DiscardCall {}
+impl RFunction for DiscardCall {
+    type Response = Ok;
+}
 
 impl DiscardCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct DisconnectWebsite {
     #[serde(default)]
     website_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for DisconnectWebsite {
     }
 }
 
-impl RFunction for DisconnectWebsite {}
+impl RFunction for DisconnectWebsite {
+    type Response = Ok;
+}
 
 impl DisconnectWebsite {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
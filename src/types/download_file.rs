@@ -31,7 +31,7 @@ pub struct DownloadFile {
     #[serde(default)]
     synchronous: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for DownloadFile {
     }
 }
 
-impl RFunction for DownloadFile {}
+impl RFunction for DownloadFile {
+    type Response = File;
+}
 
 impl DownloadFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
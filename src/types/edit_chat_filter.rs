@@ -17,7 +17,7 @@ pub struct EditChatFilter {
     /// The edited chat filter
     filter: ChatFilter,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for EditChatFilter {
     }
 }
 
-impl RFunction for EditChatFilter {}
+impl RFunction for EditChatFilter {
+    type Response = ChatFilterInfo;
+}
 
 impl EditChatFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -35,7 +35,7 @@ pub struct EditChatInviteLink {
     #[serde(default)]
     creates_join_request: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for EditChatInviteLink {
     }
 }
 
-impl RFunction for EditChatInviteLink {}
+impl RFunction for EditChatInviteLink {
+    type Response = ChatInviteLink;
+}
 
 impl EditChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
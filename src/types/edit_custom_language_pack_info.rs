@@ -13,7 +13,7 @@ pub struct EditCustomLanguagePackInfo {
     /// New information about the custom local language pack
     info: LanguagePackInfo,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for EditCustomLanguagePackInfo {
     }
 }
 
-impl RFunction for EditCustomLanguagePackInfo {}
+impl RFunction for EditCustomLanguagePackInfo {
+    type Response = Ok;
+}
 
 impl EditCustomLanguagePackInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
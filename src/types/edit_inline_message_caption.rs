@@ -21,7 +21,7 @@ pub struct EditInlineMessageCaption {
     /// New message content caption; pass null to remove caption; 0-GetOption("message_caption_length_max") characters
     caption: FormattedText,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for EditInlineMessageCaption {
     }
 }
 
-impl RFunction for EditInlineMessageCaption {}
+impl RFunction for EditInlineMessageCaption {
+    type Response = Ok;
+}
 
 impl EditInlineMessageCaption {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
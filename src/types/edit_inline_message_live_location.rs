@@ -29,7 +29,7 @@ pub struct EditInlineMessageLiveLocation {
     #[serde(default)]
     proximity_alert_radius: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for EditInlineMessageLiveLocation {
     }
 }
 
-impl RFunction for EditInlineMessageLiveLocation {}
+impl RFunction for EditInlineMessageLiveLocation {
+    type Response = Ok;
+}
 
 impl EditInlineMessageLiveLocation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct EditInlineMessageMedia {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for EditInlineMessageMedia {
     }
 }
 
-impl RFunction for EditInlineMessageMedia {}
+impl RFunction for EditInlineMessageMedia {
+    type Response = Ok;
+}
 
 impl EditInlineMessageMedia {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
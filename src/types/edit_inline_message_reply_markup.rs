@@ -19,7 +19,7 @@ pub struct EditInlineMessageReplyMarkup {
     #[serde(skip_serializing_if = "ReplyMarkup::_is_default")]
     reply_markup: ReplyMarkup,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for EditInlineMessageReplyMarkup {
     }
 }
 
-impl RFunction for EditInlineMessageReplyMarkup {}
+impl RFunction for EditInlineMessageReplyMarkup {
+    type Response = Ok;
+}
 
 impl EditInlineMessageReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
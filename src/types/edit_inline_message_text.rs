@@ -23,7 +23,7 @@ pub struct EditInlineMessageText {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for EditInlineMessageText {
     }
 }
 
-impl RFunction for EditInlineMessageText {}
+impl RFunction for EditInlineMessageText {
+    type Response = Ok;
+}
 
 impl EditInlineMessageText {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -25,7 +25,7 @@ pub struct EditMessageCaption {
     /// New message content caption; 0-GetOption("message_caption_length_max") characters; pass null to remove caption
     caption: FormattedText,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -40,7 +40,9 @@ impl RObject for EditMessageCaption {
     }
 }
 
-impl RFunction for EditMessageCaption {}
+impl RFunction for EditMessageCaption {
+    type Response = Message;
+}
 
 impl EditMessageCaption {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -33,7 +33,7 @@ pub struct EditMessageLiveLocation {
     #[serde(default)]
     proximity_alert_radius: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -48,7 +48,9 @@ impl RObject for EditMessageLiveLocation {
     }
 }
 
-impl RFunction for EditMessageLiveLocation {}
+impl RFunction for EditMessageLiveLocation {
+    type Response = Message;
+}
 
 impl EditMessageLiveLocation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
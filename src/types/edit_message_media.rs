@@ -27,7 +27,7 @@ pub struct EditMessageMedia {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for EditMessageMedia {
     }
 }
 
-impl RFunction for EditMessageMedia {}
+impl RFunction for EditMessageMedia {
+    type Response = Message;
+}
 
 impl EditMessageMedia {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
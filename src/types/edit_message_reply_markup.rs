@@ -23,7 +23,7 @@ pub struct EditMessageReplyMarkup {
     #[serde(skip_serializing_if = "ReplyMarkup::_is_default")]
     reply_markup: ReplyMarkup,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for EditMessageReplyMarkup {
     }
 }
 
-impl RFunction for EditMessageReplyMarkup {}
+impl RFunction for EditMessageReplyMarkup {
+    type Response = Message;
+}
 
 impl EditMessageReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct EditMessageSchedulingState {
     #[serde(skip_serializing_if = "MessageSchedulingState::_is_default")]
     scheduling_state: MessageSchedulingState,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for EditMessageSchedulingState {
     }
 }
 
-impl RFunction for EditMessageSchedulingState {}
+impl RFunction for EditMessageSchedulingState {
+    type Response = Ok;
+}
 
 impl EditMessageSchedulingState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
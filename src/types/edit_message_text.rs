@@ -27,7 +27,7 @@ pub struct EditMessageText {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for EditMessageText {
     }
 }
 
-impl RFunction for EditMessageText {}
+impl RFunction for EditMessageText {
+    type Response = Message;
+}
 
 impl EditMessageText {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
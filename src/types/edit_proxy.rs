@@ -32,7 +32,7 @@ pub struct EditProxy {
     #[serde(skip_serializing_if = "ProxyType::_is_default")]
     type_: ProxyType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -47,7 +47,9 @@ impl RObject for EditProxy {
     }
 }
 
-impl RFunction for EditProxy {}
+impl RFunction for EditProxy {
+    type Response = Proxy;
+}
 
 impl EditProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct EnableProxy {
     #[serde(default)]
     proxy_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for EnableProxy {
     }
 }
 
-impl RFunction for EnableProxy {}
+impl RFunction for EnableProxy {
+    type Response = Ok;
+}
 
 impl EnableProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct EndGroupCall {
     #[serde(default)]
     group_call_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for EndGroupCall {
     }
 }
 
-impl RFunction for EndGroupCall {}
+impl RFunction for EndGroupCall {
+    type Response = Ok;
+}
 
 impl EndGroupCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct EndGroupCallScreenSharing {
     #[serde(default)]
     group_call_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for EndGroupCallScreenSharing {
     }
 }
 
-impl RFunction for EndGroupCallScreenSharing {}
+impl RFunction for EndGroupCallScreenSharing {
+    type Response = Ok;
+}
 
 impl EndGroupCallScreenSharing {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
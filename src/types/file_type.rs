@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDFileType: Debug + RObject {}
 
 /// Represents the type of a file
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum FileType {
     #[doc(hidden)]
@@ -62,6 +62,12 @@ pub enum FileType {
     /// The file is a wallpaper or a background pattern
     #[serde(rename = "fileTypeWallpaper")]
     Wallpaper(FileTypeWallpaper),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    UnknownType {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for FileType {
@@ -84,6 +90,7 @@ impl RObject for FileType {
             FileType::VideoNote(t) => t.extra(),
             FileType::VoiceNote(t) => t.extra(),
             FileType::Wallpaper(t) => t.extra(),
+            FileType::UnknownType { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -107,12 +114,43 @@ impl RObject for FileType {
             FileType::VideoNote(t) => t.client_id(),
             FileType::VoiceNote(t) => t.client_id(),
             FileType::Wallpaper(t) => t.client_id(),
+            FileType::UnknownType { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for FileType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "fileTypeAnimation" => FileType::Animation(super::_common::from_raw(raw)?),
+            "fileTypeAudio" => FileType::Audio(super::_common::from_raw(raw)?),
+            "fileTypeDocument" => FileType::Document(super::_common::from_raw(raw)?),
+            "fileTypeNone" => FileType::None(super::_common::from_raw(raw)?),
+            "fileTypePhoto" => FileType::Photo(super::_common::from_raw(raw)?),
+            "fileTypeProfilePhoto" => FileType::ProfilePhoto(super::_common::from_raw(raw)?),
+            "fileTypeSecret" => FileType::Secret(super::_common::from_raw(raw)?),
+            "fileTypeSecretThumbnail" => FileType::SecretThumbnail(super::_common::from_raw(raw)?),
+            "fileTypeSecure" => FileType::Secure(super::_common::from_raw(raw)?),
+            "fileTypeSticker" => FileType::Sticker(super::_common::from_raw(raw)?),
+            "fileTypeThumbnail" => FileType::Thumbnail(super::_common::from_raw(raw)?),
+            "fileTypeUnknown" => FileType::Unknown(super::_common::from_raw(raw)?),
+            "fileTypeVideo" => FileType::Video(super::_common::from_raw(raw)?),
+            "fileTypeVideoNote" => FileType::VideoNote(super::_common::from_raw(raw)?),
+            "fileTypeVoiceNote" => FileType::VoiceNote(super::_common::from_raw(raw)?),
+            "fileTypeWallpaper" => FileType::Wallpaper(super::_common::from_raw(raw)?),
+            _ => FileType::UnknownType { type_name, raw },
+        })
+    }
+}
+
 impl FileType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -21,7 +21,7 @@ pub struct FinishFileGeneration {
     /// If passed, the file generation has failed and must be terminated; pass null if the file generation succeeded
     error: Error,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for FinishFileGeneration {
     }
 }
 
-impl RFunction for FinishFileGeneration {}
+impl RFunction for FinishFileGeneration {
+    type Response = Ok;
+}
 
 impl FinishFileGeneration {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -37,7 +37,7 @@ pub struct ForwardMessages {
     #[serde(default)]
     only_preview: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -52,7 +52,9 @@ impl RObject for ForwardMessages {
     }
 }
 
-impl RFunction for ForwardMessages {}
+impl RFunction for ForwardMessages {
+    type Response = Messages;
+}
 
 impl ForwardMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetAllPassportElements {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetAllPassportElements {
     }
 }
 
-impl RFunction for GetAllPassportElements {}
+impl RFunction for GetAllPassportElements {
+    type Response = PassportElements;
+}
 
 impl GetAllPassportElements {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetAnimatedEmoji {
     #[serde(default)]
     emoji: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetAnimatedEmoji {
     }
 }
 
-impl RFunction for GetAnimatedEmoji {}
+impl RFunction for GetAnimatedEmoji {
+    type Response = AnimatedEmoji;
+}
 
 impl GetAnimatedEmoji {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
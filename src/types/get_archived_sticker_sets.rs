@@ -27,7 +27,7 @@ pub struct GetArchivedStickerSets {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetArchivedStickerSets {
     }
 }
 
-impl RFunction for GetArchivedStickerSets {}
+impl RFunction for GetArchivedStickerSets {
+    type Response = StickerSets;
+}
 
 impl GetArchivedStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -11,7 +11,7 @@ pub struct GetAuthorizationState {
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for GetAuthorizationState {
 
 impl TDAuthorizationState for GetAuthorizationState {}
 
-impl RFunction for GetAuthorizationState {}
+impl RFunction for GetAuthorizationState {
+    type Response = AuthorizationState;
+}
 
 impl GetAuthorizationState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -20,7 +20,7 @@ pub struct GetBackgroundUrl {
     #[serde(skip_serializing_if = "BackgroundType::_is_default")]
     type_: BackgroundType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -35,7 +35,9 @@ impl RObject for GetBackgroundUrl {
     }
 }
 
-impl RFunction for GetBackgroundUrl {}
+impl RFunction for GetBackgroundUrl {
+    type Response = HttpUrl;
+}
 
 impl GetBackgroundUrl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
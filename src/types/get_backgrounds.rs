@@ -15,7 +15,7 @@ pub struct GetBackgrounds {
     #[serde(default)]
     for_dark_theme: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetBackgrounds {
     }
 }
 
-impl RFunction for GetBackgrounds {}
+impl RFunction for GetBackgrounds {
+    type Response = Backgrounds;
+}
 
 impl GetBackgrounds {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetBankCardInfo {
     #[serde(default)]
     bank_card_number: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetBankCardInfo {
     }
 }
 
-impl RFunction for GetBankCardInfo {}
+impl RFunction for GetBankCardInfo {
+    type Response = BankCardInfo;
+}
 
 impl GetBankCardInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
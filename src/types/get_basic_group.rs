@@ -15,7 +15,7 @@ pub struct GetBasicGroup {
     #[serde(default)]
     basic_group_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetBasicGroup {
     }
 }
 
-impl RFunction for GetBasicGroup {}
+impl RFunction for GetBasicGroup {
+    type Response = BasicGroup;
+}
 
 impl GetBasicGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetBlockedMessageSenders {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetBlockedMessageSenders {
     }
 }
 
-impl RFunction for GetBlockedMessageSenders {}
+impl RFunction for GetBlockedMessageSenders {
+    type Response = MessageSenders;
+}
 
 impl GetBlockedMessageSenders {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
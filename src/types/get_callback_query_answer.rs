@@ -23,7 +23,7 @@ pub struct GetCallbackQueryAnswer {
     #[serde(skip_serializing_if = "CallbackQueryPayload::_is_default")]
     payload: CallbackQueryPayload,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetCallbackQueryAnswer {
     }
 }
 
-impl RFunction for GetCallbackQueryAnswer {}
+impl RFunction for GetCallbackQueryAnswer {
+    type Response = CallbackQueryAnswer;
+}
 
 impl GetCallbackQueryAnswer {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct GetCallbackQueryMessage {
     #[serde(default)]
     callback_query_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetCallbackQueryMessage {
     }
 }
 
-impl RFunction for GetCallbackQueryMessage {}
+impl RFunction for GetCallbackQueryMessage {
+    type Response = Message;
+}
 
 impl GetCallbackQueryMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetChat {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetChat {
     }
 }
 
-impl RFunction for GetChat {}
+impl RFunction for GetChat {
+    type Response = Chat;
+}
 
 impl GetChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -37,7 +37,7 @@ pub struct GetChatEventLog {
     #[serde(default)]
     user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -52,7 +52,9 @@ impl RObject for GetChatEventLog {
     }
 }
 
-impl RFunction for GetChatEventLog {}
+impl RFunction for GetChatEventLog {
+    type Response = ChatEvents;
+}
 
 impl GetChatEventLog {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetChatFilter {
     #[serde(default)]
     chat_filter_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetChatFilter {
     }
 }
 
-impl RFunction for GetChatFilter {}
+impl RFunction for GetChatFilter {
+    type Response = ChatFilter;
+}
 
 impl GetChatFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
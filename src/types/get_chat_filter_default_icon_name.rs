@@ -13,7 +13,7 @@ pub struct GetChatFilterDefaultIconName {
     /// Chat filter
     filter: ChatFilter,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for GetChatFilterDefaultIconName {
     }
 }
 
-impl RFunction for GetChatFilterDefaultIconName {}
+impl RFunction for GetChatFilterDefaultIconName {
+    type Response = Text;
+}
 
 impl GetChatFilterDefaultIconName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -31,7 +31,7 @@ pub struct GetChatHistory {
     #[serde(default)]
     only_local: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for GetChatHistory {
     }
 }
 
-impl RFunction for GetChatHistory {}
+impl RFunction for GetChatHistory {
+    type Response = Messages;
+}
 
 impl GetChatHistory {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetChatInviteLink {
     #[serde(default)]
     invite_link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetChatInviteLink {
     }
 }
 
-impl RFunction for GetChatInviteLink {}
+impl RFunction for GetChatInviteLink {
+    type Response = ChatInviteLink;
+}
 
 impl GetChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
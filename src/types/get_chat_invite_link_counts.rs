@@ -15,7 +15,7 @@ pub struct GetChatInviteLinkCounts {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetChatInviteLinkCounts {
     }
 }
 
-impl RFunction for GetChatInviteLinkCounts {}
+impl RFunction for GetChatInviteLinkCounts {
+    type Response = ChatInviteLinkCounts;
+}
 
 impl GetChatInviteLinkCounts {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -25,7 +25,7 @@ pub struct GetChatInviteLinkMembers {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -40,7 +40,9 @@ impl RObject for GetChatInviteLinkMembers {
     }
 }
 
-impl RFunction for GetChatInviteLinkMembers {}
+impl RFunction for GetChatInviteLinkMembers {
+    type Response = ChatInviteLinkMembers;
+}
 
 impl GetChatInviteLinkMembers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -35,7 +35,7 @@ pub struct GetChatInviteLinks {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for GetChatInviteLinks {
     }
 }
 
-impl RFunction for GetChatInviteLinks {}
+impl RFunction for GetChatInviteLinks {
+    type Response = ChatInviteLinks;
+}
 
 impl GetChatInviteLinks {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -29,7 +29,7 @@ pub struct GetChatJoinRequests {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for GetChatJoinRequests {
     }
 }
 
-impl RFunction for GetChatJoinRequests {}
+impl RFunction for GetChatJoinRequests {
+    type Response = ChatJoinRequests;
+}
 
 impl GetChatJoinRequests {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
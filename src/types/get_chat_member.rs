@@ -19,7 +19,7 @@ pub struct GetChatMember {
     #[serde(skip_serializing_if = "MessageSender::_is_default")]
     member_id: MessageSender,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetChatMember {
     }
 }
 
-impl RFunction for GetChatMember {}
+impl RFunction for GetChatMember {
+    type Response = ChatMember;
+}
 
 impl GetChatMember {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetChatMessageByDate {
     #[serde(default)]
     date: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetChatMessageByDate {
     }
 }
 
-impl RFunction for GetChatMessageByDate {}
+impl RFunction for GetChatMessageByDate {
+    type Response = Message;
+}
 
 impl GetChatMessageByDate {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
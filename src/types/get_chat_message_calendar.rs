@@ -23,7 +23,7 @@ pub struct GetChatMessageCalendar {
     #[serde(default)]
     from_message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetChatMessageCalendar {
     }
 }
 
-impl RFunction for GetChatMessageCalendar {}
+impl RFunction for GetChatMessageCalendar {
+    type Response = MessageCalendar;
+}
 
 impl GetChatMessageCalendar {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
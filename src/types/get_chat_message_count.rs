@@ -23,7 +23,7 @@ pub struct GetChatMessageCount {
     #[serde(default)]
     return_local: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetChatMessageCount {
     }
 }
 
-impl RFunction for GetChatMessageCount {}
+impl RFunction for GetChatMessageCount {
+    type Response = Count;
+}
 
 impl GetChatMessageCount {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetChatNotificationSettingsExceptions {
     #[serde(default)]
     compare_sound: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetChatNotificationSettingsExceptions {
     }
 }
 
-impl RFunction for GetChatNotificationSettingsExceptions {}
+impl RFunction for GetChatNotificationSettingsExceptions {
+    type Response = Chats;
+}
 
 impl GetChatNotificationSettingsExceptions {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
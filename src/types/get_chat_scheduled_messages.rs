@@ -15,7 +15,7 @@ pub struct GetChatScheduledMessages {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetChatScheduledMessages {
     }
 }
 
-impl RFunction for GetChatScheduledMessages {}
+impl RFunction for GetChatScheduledMessages {
+    type Response = Messages;
+}
 
 impl GetChatScheduledMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
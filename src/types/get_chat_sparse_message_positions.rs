@@ -27,7 +27,7 @@ pub struct GetChatSparseMessagePositions {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetChatSparseMessagePositions {
     }
 }
 
-impl RFunction for GetChatSparseMessagePositions {}
+impl RFunction for GetChatSparseMessagePositions {
+    type Response = MessagePositions;
+}
 
 impl GetChatSparseMessagePositions {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
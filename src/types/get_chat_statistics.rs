@@ -19,7 +19,7 @@ pub struct GetChatStatistics {
     #[serde(default)]
     is_dark: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for GetChatStatistics {
 
 impl TDChatStatistics for GetChatStatistics {}
 
-impl RFunction for GetChatStatistics {}
+impl RFunction for GetChatStatistics {
+    type Response = ChatStatistics;
+}
 
 impl GetChatStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
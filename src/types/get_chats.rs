@@ -19,7 +19,7 @@ pub struct GetChats {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetChats {
     }
 }
 
-impl RFunction for GetChats {}
+impl RFunction for GetChats {
+    type Response = Chats;
+}
 
 impl GetChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetCommands {
     #[serde(default)]
     language_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetCommands {
     }
 }
 
-impl RFunction for GetCommands {}
+impl RFunction for GetCommands {
+    type Response = BotCommands;
+}
 
 impl GetCommands {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -16,7 +16,7 @@ pub struct GetCreatedPublicChats {
     #[serde(skip_serializing_if = "PublicChatType::_is_default")]
     type_: PublicChatType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -31,7 +31,9 @@ impl RObject for GetCreatedPublicChats {
     }
 }
 
-impl RFunction for GetCreatedPublicChats {}
+impl RFunction for GetCreatedPublicChats {
+    type Response = Chats;
+}
 
 impl GetCreatedPublicChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
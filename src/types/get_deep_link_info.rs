@@ -15,7 +15,7 @@ pub struct GetDeepLinkInfo {
     #[serde(default)]
     link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetDeepLinkInfo {
     }
 }
 
-impl RFunction for GetDeepLinkInfo {}
+impl RFunction for GetDeepLinkInfo {
+    type Response = DeepLinkInfo;
+}
 
 impl GetDeepLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetEmojiSuggestionsUrl {
     #[serde(default)]
     language_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetEmojiSuggestionsUrl {
     }
 }
 
-impl RFunction for GetEmojiSuggestionsUrl {}
+impl RFunction for GetEmojiSuggestionsUrl {
+    type Response = HttpUrl;
+}
 
 impl GetEmojiSuggestionsUrl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
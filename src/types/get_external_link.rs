@@ -19,7 +19,7 @@ pub struct GetExternalLink {
     #[serde(default)]
     allow_write_access: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetExternalLink {
     }
 }
 
-impl RFunction for GetExternalLink {}
+impl RFunction for GetExternalLink {
+    type Response = HttpUrl;
+}
 
 impl GetExternalLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
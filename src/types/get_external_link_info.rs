@@ -15,7 +15,7 @@ pub struct GetExternalLinkInfo {
     #[serde(default)]
     link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for GetExternalLinkInfo {
 
 impl TDLoginUrlInfo for GetExternalLinkInfo {}
 
-impl RFunction for GetExternalLinkInfo {}
+impl RFunction for GetExternalLinkInfo {
+    type Response = LoginUrlInfo;
+}
 
 impl GetExternalLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
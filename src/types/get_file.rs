@@ -15,7 +15,7 @@ pub struct GetFile {
     #[serde(default)]
     file_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetFile {
     }
 }
 
-impl RFunction for GetFile {}
+impl RFunction for GetFile {
+    type Response = File;
+}
 
 impl GetFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetFileDownloadedPrefixSize {
     #[serde(default)]
     offset: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetFileDownloadedPrefixSize {
     }
 }
 
-impl RFunction for GetFileDownloadedPrefixSize {}
+impl RFunction for GetFileDownloadedPrefixSize {
+    type Response = Count;
+}
 
 impl GetFileDownloadedPrefixSize {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetFileExtension {
     #[serde(default)]
     mime_type: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetFileExtension {
     }
 }
 
-impl RFunction for GetFileExtension {}
+impl RFunction for GetFileExtension {
+    type Response = Text;
+}
 
 impl GetFileExtension {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
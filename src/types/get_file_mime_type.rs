@@ -15,7 +15,7 @@ pub struct GetFileMimeType {
     #[serde(default)]
     file_name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetFileMimeType {
     }
 }
 
-impl RFunction for GetFileMimeType {}
+impl RFunction for GetFileMimeType {
+    type Response = Text;
+}
 
 impl GetFileMimeType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
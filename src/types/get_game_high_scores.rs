@@ -23,7 +23,7 @@ pub struct GetGameHighScores {
     #[serde(default)]
     user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetGameHighScores {
     }
 }
 
-impl RFunction for GetGameHighScores {}
+impl RFunction for GetGameHighScores {
+    type Response = GameHighScores;
+}
 
 impl GetGameHighScores {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetGroupCall {
     #[serde(default)]
     group_call_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetGroupCall {
     }
 }
 
-impl RFunction for GetGroupCall {}
+impl RFunction for GetGroupCall {
+    type Response = GroupCall;
+}
 
 impl GetGroupCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetGroupCallInviteLink {
     #[serde(default)]
     can_self_unmute: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetGroupCallInviteLink {
     }
 }
 
-impl RFunction for GetGroupCallInviteLink {}
+impl RFunction for GetGroupCallInviteLink {
+    type Response = HttpUrl;
+}
 
 impl GetGroupCallInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
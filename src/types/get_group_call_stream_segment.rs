@@ -31,7 +31,7 @@ pub struct GetGroupCallStreamSegment {
     #[serde(skip_serializing_if = "GroupCallVideoQuality::_is_default")]
     video_quality: GroupCallVideoQuality,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for GetGroupCallStreamSegment {
     }
 }
 
-impl RFunction for GetGroupCallStreamSegment {}
+impl RFunction for GetGroupCallStreamSegment {
+    type Response = FilePart;
+}
 
 impl GetGroupCallStreamSegment {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct GetGroupsInCommon {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetGroupsInCommon {
     }
 }
 
-impl RFunction for GetGroupsInCommon {}
+impl RFunction for GetGroupsInCommon {
+    type Response = Chats;
+}
 
 impl GetGroupsInCommon {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetInlineGameHighScores {
     #[serde(default)]
     user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetInlineGameHighScores {
     }
 }
 
-impl RFunction for GetInlineGameHighScores {}
+impl RFunction for GetInlineGameHighScores {
+    type Response = GameHighScores;
+}
 
 impl GetInlineGameHighScores {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
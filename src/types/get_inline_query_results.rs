@@ -29,7 +29,7 @@ pub struct GetInlineQueryResults {
     #[serde(default)]
     offset: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for GetInlineQueryResults {
     }
 }
 
-impl RFunction for GetInlineQueryResults {}
+impl RFunction for GetInlineQueryResults {
+    type Response = InlineQueryResults;
+}
 
 impl GetInlineQueryResults {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
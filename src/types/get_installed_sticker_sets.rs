@@ -15,7 +15,7 @@ pub struct GetInstalledStickerSets {
     #[serde(default)]
     is_masks: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetInstalledStickerSets {
     }
 }
 
-impl RFunction for GetInstalledStickerSets {}
+impl RFunction for GetInstalledStickerSets {
+    type Response = StickerSets;
+}
 
 impl GetInstalledStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetInternalLinkType {
     #[serde(default)]
     link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for GetInternalLinkType {
 
 impl TDInternalLinkType for GetInternalLinkType {}
 
-impl RFunction for GetInternalLinkType {}
+impl RFunction for GetInternalLinkType {
+    type Response = InternalLinkType;
+}
 
 impl GetInternalLinkType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetJsonString {
     #[serde(skip_serializing_if = "JsonValue::_is_default")]
     json_value: JsonValue,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetJsonString {
     }
 }
 
-impl RFunction for GetJsonString {}
+impl RFunction for GetJsonString {
+    type Response = Text;
+}
 
 impl GetJsonString {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetJsonValue {
     #[serde(default)]
     json: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for GetJsonValue {
 
 impl TDJsonValue for GetJsonValue {}
 
-impl RFunction for GetJsonValue {}
+impl RFunction for GetJsonValue {
+    type Response = JsonValue;
+}
 
 impl GetJsonValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetLanguagePackInfo {
     #[serde(default)]
     language_pack_id: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetLanguagePackInfo {
     }
 }
 
-impl RFunction for GetLanguagePackInfo {}
+impl RFunction for GetLanguagePackInfo {
+    type Response = LanguagePackInfo;
+}
 
 impl GetLanguagePackInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
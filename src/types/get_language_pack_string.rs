@@ -27,7 +27,7 @@ pub struct GetLanguagePackString {
     #[serde(default)]
     key: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for GetLanguagePackString {
 
 impl TDLanguagePackStringValue for GetLanguagePackString {}
 
-impl RFunction for GetLanguagePackString {}
+impl RFunction for GetLanguagePackString {
+    type Response = LanguagePackStringValue;
+}
 
 impl GetLanguagePackString {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetLanguagePackStrings {
     #[serde(default)]
     keys: Vec<String>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetLanguagePackStrings {
     }
 }
 
-impl RFunction for GetLanguagePackStrings {}
+impl RFunction for GetLanguagePackStrings {
+    type Response = LanguagePackStrings;
+}
 
 impl GetLanguagePackStrings {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
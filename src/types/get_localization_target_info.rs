@@ -15,7 +15,7 @@ pub struct GetLocalizationTargetInfo {
     #[serde(default)]
     only_local: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetLocalizationTargetInfo {
     }
 }
 
-impl RFunction for GetLocalizationTargetInfo {}
+impl RFunction for GetLocalizationTargetInfo {
+    type Response = LocalizationTargetInfo;
+}
 
 impl GetLocalizationTargetInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
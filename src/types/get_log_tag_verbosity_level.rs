@@ -15,7 +15,7 @@ pub struct GetLogTagVerbosityLevel {
     #[serde(default)]
     tag: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetLogTagVerbosityLevel {
     }
 }
 
-impl RFunction for GetLogTagVerbosityLevel {}
+impl RFunction for GetLogTagVerbosityLevel {
+    type Response = LogVerbosityLevel;
+}
 
 impl GetLogTagVerbosityLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
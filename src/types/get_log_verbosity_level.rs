@@ -11,7 +11,7 @@ pub struct GetLogVerbosityLevel {
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -26,7 +26,9 @@ impl RObject for GetLogVerbosityLevel {
     }
 }
 
-impl RFunction for GetLogVerbosityLevel {}
+impl RFunction for GetLogVerbosityLevel {
+    type Response = LogVerbosityLevel;
+}
 
 impl GetLogVerbosityLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct GetLoginUrl {
     #[serde(default)]
     allow_write_access: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetLoginUrl {
     }
 }
 
-impl RFunction for GetLoginUrl {}
+impl RFunction for GetLoginUrl {
+    type Response = HttpUrl;
+}
 
 impl GetLoginUrl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
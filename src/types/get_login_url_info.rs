@@ -23,7 +23,7 @@ pub struct GetLoginUrlInfo {
     #[serde(default)]
     button_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -40,7 +40,9 @@ impl RObject for GetLoginUrlInfo {
 
 impl TDLoginUrlInfo for GetLoginUrlInfo {}
 
-impl RFunction for GetLoginUrlInfo {}
+impl RFunction for GetLoginUrlInfo {
+    type Response = LoginUrlInfo;
+}
 
 impl GetLoginUrlInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
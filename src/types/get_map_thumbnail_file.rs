@@ -33,7 +33,7 @@ pub struct GetMapThumbnailFile {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -48,7 +48,9 @@ impl RObject for GetMapThumbnailFile {
     }
 }
 
-impl RFunction for GetMapThumbnailFile {}
+impl RFunction for GetMapThumbnailFile {
+    type Response = File;
+}
 
 impl GetMapThumbnailFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -13,7 +13,7 @@ pub struct GetMarkdownText {
     /// The text
     text: FormattedText,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for GetMarkdownText {
     }
 }
 
-impl RFunction for GetMarkdownText {}
+impl RFunction for GetMarkdownText {
+    type Response = FormattedText;
+}
 
 impl GetMarkdownText {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
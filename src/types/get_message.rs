@@ -19,7 +19,7 @@ pub struct GetMessage {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetMessage {
     }
 }
 
-impl RFunction for GetMessage {}
+impl RFunction for GetMessage {
+    type Response = Message;
+}
 
 impl GetMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
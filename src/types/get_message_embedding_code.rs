@@ -23,7 +23,7 @@ pub struct GetMessageEmbeddingCode {
     #[serde(default)]
     for_album: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetMessageEmbeddingCode {
     }
 }
 
-impl RFunction for GetMessageEmbeddingCode {}
+impl RFunction for GetMessageEmbeddingCode {
+    type Response = Text;
+}
 
 impl GetMessageEmbeddingCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
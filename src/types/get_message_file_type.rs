@@ -15,7 +15,7 @@ pub struct GetMessageFileType {
     #[serde(default)]
     message_file_head: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for GetMessageFileType {
 
 impl TDMessageFileType for GetMessageFileType {}
 
-impl RFunction for GetMessageFileType {}
+impl RFunction for GetMessageFileType {
+    type Response = MessageFileType;
+}
 
 impl GetMessageFileType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
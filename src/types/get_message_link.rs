@@ -31,7 +31,7 @@ pub struct GetMessageLink {
     #[serde(default)]
     for_comment: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for GetMessageLink {
     }
 }
 
-impl RFunction for GetMessageLink {}
+impl RFunction for GetMessageLink {
+    type Response = MessageLink;
+}
 
 impl GetMessageLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetMessageLinkInfo {
     #[serde(default)]
     url: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetMessageLinkInfo {
     }
 }
 
-impl RFunction for GetMessageLinkInfo {}
+impl RFunction for GetMessageLinkInfo {
+    type Response = MessageLinkInfo;
+}
 
 impl GetMessageLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetMessageLocally {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetMessageLocally {
     }
 }
 
-impl RFunction for GetMessageLocally {}
+impl RFunction for GetMessageLocally {
+    type Response = Message;
+}
 
 impl GetMessageLocally {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct GetMessagePublicForwards {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetMessagePublicForwards {
     }
 }
 
-impl RFunction for GetMessagePublicForwards {}
+impl RFunction for GetMessagePublicForwards {
+    type Response = FoundMessages;
+}
 
 impl GetMessagePublicForwards {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct GetMessageStatistics {
     #[serde(default)]
     is_dark: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetMessageStatistics {
     }
 }
 
-impl RFunction for GetMessageStatistics {}
+impl RFunction for GetMessageStatistics {
+    type Response = MessageStatistics;
+}
 
 impl GetMessageStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetMessageThread {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetMessageThread {
     }
 }
 
-impl RFunction for GetMessageThread {}
+impl RFunction for GetMessageThread {
+    type Response = MessageThreadInfo;
+}
 
 impl GetMessageThread {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
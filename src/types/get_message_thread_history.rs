@@ -31,7 +31,7 @@ pub struct GetMessageThreadHistory {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for GetMessageThreadHistory {
     }
 }
 
-impl RFunction for GetMessageThreadHistory {}
+impl RFunction for GetMessageThreadHistory {
+    type Response = Messages;
+}
 
 impl GetMessageThreadHistory {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
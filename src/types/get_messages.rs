@@ -19,7 +19,7 @@ pub struct GetMessages {
     #[serde(default)]
     message_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetMessages {
     }
 }
 
-impl RFunction for GetMessages {}
+impl RFunction for GetMessages {
+    type Response = Messages;
+}
 
 impl GetMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
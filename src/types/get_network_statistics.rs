@@ -15,7 +15,7 @@ pub struct GetNetworkStatistics {
     #[serde(default)]
     only_current: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetNetworkStatistics {
     }
 }
 
-impl RFunction for GetNetworkStatistics {}
+impl RFunction for GetNetworkStatistics {
+    type Response = NetworkStatistics;
+}
 
 impl GetNetworkStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetOption {
     #[serde(default)]
     name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for GetOption {
 
 impl TDOptionValue for GetOption {}
 
-impl RFunction for GetOption {}
+impl RFunction for GetOption {
+    type Response = OptionValue;
+}
 
 impl GetOption {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
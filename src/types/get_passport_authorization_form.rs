@@ -27,7 +27,7 @@ pub struct GetPassportAuthorizationForm {
     #[serde(default)]
     nonce: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetPassportAuthorizationForm {
     }
 }
 
-impl RFunction for GetPassportAuthorizationForm {}
+impl RFunction for GetPassportAuthorizationForm {
+    type Response = PassportAuthorizationForm;
+}
 
 impl GetPassportAuthorizationForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
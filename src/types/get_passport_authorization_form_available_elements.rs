@@ -19,7 +19,7 @@ pub struct GetPassportAuthorizationFormAvailableElements {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetPassportAuthorizationFormAvailableElements {
     }
 }
 
-impl RFunction for GetPassportAuthorizationFormAvailableElements {}
+impl RFunction for GetPassportAuthorizationFormAvailableElements {
+    type Response = PassportElementsWithErrors;
+}
 
 impl GetPassportAuthorizationFormAvailableElements {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
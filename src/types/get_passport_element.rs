@@ -20,7 +20,7 @@ pub struct GetPassportElement {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -37,7 +37,9 @@ impl RObject for GetPassportElement {
 
 impl TDPassportElement for GetPassportElement {}
 
-impl RFunction for GetPassportElement {}
+impl RFunction for GetPassportElement {
+    type Response = PassportElement;
+}
 
 impl GetPassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
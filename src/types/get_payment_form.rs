@@ -21,7 +21,7 @@ pub struct GetPaymentForm {
     /// Preferred payment form theme; pass null to use the default theme
     theme: PaymentFormTheme,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for GetPaymentForm {
     }
 }
 
-impl RFunction for GetPaymentForm {}
+impl RFunction for GetPaymentForm {
+    type Response = PaymentForm;
+}
 
 impl GetPaymentForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
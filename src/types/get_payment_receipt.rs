@@ -19,7 +19,7 @@ pub struct GetPaymentReceipt {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetPaymentReceipt {
     }
 }
 
-impl RFunction for GetPaymentReceipt {}
+impl RFunction for GetPaymentReceipt {
+    type Response = PaymentReceipt;
+}
 
 impl GetPaymentReceipt {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
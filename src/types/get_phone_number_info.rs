@@ -15,7 +15,7 @@ pub struct GetPhoneNumberInfo {
     #[serde(default)]
     phone_number_prefix: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetPhoneNumberInfo {
     }
 }
 
-impl RFunction for GetPhoneNumberInfo {}
+impl RFunction for GetPhoneNumberInfo {
+    type Response = PhoneNumberInfo;
+}
 
 impl GetPhoneNumberInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetPhoneNumberInfoSync {
     #[serde(default)]
     phone_number_prefix: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetPhoneNumberInfoSync {
     }
 }
 
-impl RFunction for GetPhoneNumberInfoSync {}
+impl RFunction for GetPhoneNumberInfoSync {
+    type Response = PhoneNumberInfo;
+}
 
 impl GetPhoneNumberInfoSync {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
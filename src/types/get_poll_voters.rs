@@ -31,7 +31,7 @@ pub struct GetPollVoters {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for GetPollVoters {
     }
 }
 
-impl RFunction for GetPollVoters {}
+impl RFunction for GetPollVoters {
+    type Response = Users;
+}
 
 impl GetPollVoters {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
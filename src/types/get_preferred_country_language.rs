@@ -15,7 +15,7 @@ pub struct GetPreferredCountryLanguage {
     #[serde(default)]
     country_code: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetPreferredCountryLanguage {
     }
 }
 
-impl RFunction for GetPreferredCountryLanguage {}
+impl RFunction for GetPreferredCountryLanguage {
+    type Response = Text;
+}
 
 impl GetPreferredCountryLanguage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
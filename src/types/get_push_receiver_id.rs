@@ -15,7 +15,7 @@ pub struct GetPushReceiverId {
     #[serde(default)]
     payload: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetPushReceiverId {
     }
 }
 
-impl RFunction for GetPushReceiverId {}
+impl RFunction for GetPushReceiverId {
+    type Response = PushReceiverId;
+}
 
 impl GetPushReceiverId {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetRecentStickers {
     #[serde(default)]
     is_attached: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetRecentStickers {
     }
 }
 
-impl RFunction for GetRecentStickers {}
+impl RFunction for GetRecentStickers {
+    type Response = Stickers;
+}
 
 impl GetRecentStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetRecentlyOpenedChats {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetRecentlyOpenedChats {
     }
 }
 
-impl RFunction for GetRecentlyOpenedChats {}
+impl RFunction for GetRecentlyOpenedChats {
+    type Response = Chats;
+}
 
 impl GetRecentlyOpenedChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
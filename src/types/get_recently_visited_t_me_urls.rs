@@ -15,7 +15,7 @@ pub struct GetRecentlyVisitedTMeUrls {
     #[serde(default)]
     referrer: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetRecentlyVisitedTMeUrls {
     }
 }
 
-impl RFunction for GetRecentlyVisitedTMeUrls {}
+impl RFunction for GetRecentlyVisitedTMeUrls {
+    type Response = TMeUrls;
+}
 
 impl GetRecentlyVisitedTMeUrls {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
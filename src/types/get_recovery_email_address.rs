@@ -15,7 +15,7 @@ pub struct GetRecoveryEmailAddress {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetRecoveryEmailAddress {
     }
 }
 
-impl RFunction for GetRecoveryEmailAddress {}
+impl RFunction for GetRecoveryEmailAddress {
+    type Response = RecoveryEmailAddress;
+}
 
 impl GetRecoveryEmailAddress {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
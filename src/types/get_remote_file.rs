@@ -19,7 +19,7 @@ pub struct GetRemoteFile {
     #[serde(skip_serializing_if = "FileType::_is_default")]
     file_type: FileType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetRemoteFile {
     }
 }
 
-impl RFunction for GetRemoteFile {}
+impl RFunction for GetRemoteFile {
+    type Response = File;
+}
 
 impl GetRemoteFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetRepliedMessage {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetRepliedMessage {
     }
 }
 
-impl RFunction for GetRepliedMessage {}
+impl RFunction for GetRepliedMessage {
+    type Response = Message;
+}
 
 impl GetRepliedMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetScopeNotificationSettings {
     #[serde(skip_serializing_if = "NotificationSettingsScope::_is_default")]
     scope: NotificationSettingsScope,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetScopeNotificationSettings {
     }
 }
 
-impl RFunction for GetScopeNotificationSettings {}
+impl RFunction for GetScopeNotificationSettings {
+    type Response = ScopeNotificationSettings;
+}
 
 impl GetScopeNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetSecretChat {
     #[serde(default)]
     secret_chat_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetSecretChat {
     }
 }
 
-impl RFunction for GetSecretChat {}
+impl RFunction for GetSecretChat {
+    type Response = SecretChat;
+}
 
 impl GetSecretChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct GetStatisticalGraph {
     #[serde(default)]
     x: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -40,7 +40,9 @@ impl RObject for GetStatisticalGraph {
 
 impl TDStatisticalGraph for GetStatisticalGraph {}
 
-impl RFunction for GetStatisticalGraph {}
+impl RFunction for GetStatisticalGraph {
+    type Response = StatisticalGraph;
+}
 
 impl GetStatisticalGraph {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetStickerEmojis {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     sticker: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetStickerEmojis {
     }
 }
 
-impl RFunction for GetStickerEmojis {}
+impl RFunction for GetStickerEmojis {
+    type Response = Emojis;
+}
 
 impl GetStickerEmojis {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetStickerSet {
     #[serde(default)]
     set_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetStickerSet {
     }
 }
 
-impl RFunction for GetStickerSet {}
+impl RFunction for GetStickerSet {
+    type Response = StickerSet;
+}
 
 impl GetStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
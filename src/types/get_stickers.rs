@@ -19,7 +19,7 @@ pub struct GetStickers {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetStickers {
     }
 }
 
-impl RFunction for GetStickers {}
+impl RFunction for GetStickers {
+    type Response = Stickers;
+}
 
 impl GetStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
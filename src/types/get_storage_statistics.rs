@@ -15,7 +15,7 @@ pub struct GetStorageStatistics {
     #[serde(default)]
     chat_limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetStorageStatistics {
     }
 }
 
-impl RFunction for GetStorageStatistics {}
+impl RFunction for GetStorageStatistics {
+    type Response = StorageStatistics;
+}
 
 impl GetStorageStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetSuggestedFileName {
     #[serde(default)]
     directory: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetSuggestedFileName {
     }
 }
 
-impl RFunction for GetSuggestedFileName {}
+impl RFunction for GetSuggestedFileName {
+    type Response = Text;
+}
 
 impl GetSuggestedFileName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetSuggestedStickerSetName {
     #[serde(default)]
     title: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetSuggestedStickerSetName {
     }
 }
 
-impl RFunction for GetSuggestedStickerSetName {}
+impl RFunction for GetSuggestedStickerSetName {
+    type Response = Text;
+}
 
 impl GetSuggestedStickerSetName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetSupergroupFullInfo {
     #[serde(default)]
     supergroup_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetSupergroupFullInfo {
     }
 }
 
-impl RFunction for GetSupergroupFullInfo {}
+impl RFunction for GetSupergroupFullInfo {
+    type Response = SupergroupFullInfo;
+}
 
 impl GetSupergroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct GetSupergroupMembers {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for GetSupergroupMembers {
     }
 }
 
-impl RFunction for GetSupergroupMembers {}
+impl RFunction for GetSupergroupMembers {
+    type Response = ChatMembers;
+}
 
 impl GetSupergroupMembers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
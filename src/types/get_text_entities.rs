@@ -15,7 +15,7 @@ pub struct GetTextEntities {
     #[serde(default)]
     text: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetTextEntities {
     }
 }
 
-impl RFunction for GetTextEntities {}
+impl RFunction for GetTextEntities {
+    type Response = TextEntities;
+}
 
 impl GetTextEntities {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetTopChats {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetTopChats {
     }
 }
 
-impl RFunction for GetTopChats {}
+impl RFunction for GetTopChats {
+    type Response = Chats;
+}
 
 impl GetTopChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct GetTrendingStickerSets {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetTrendingStickerSets {
     }
 }
 
-impl RFunction for GetTrendingStickerSets {}
+impl RFunction for GetTrendingStickerSets {
+    type Response = StickerSets;
+}
 
 impl GetTrendingStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetUser {
     #[serde(default)]
     user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetUser {
     }
 }
 
-impl RFunction for GetUser {}
+impl RFunction for GetUser {
+    type Response = User;
+}
 
 impl GetUser {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
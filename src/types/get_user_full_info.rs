@@ -15,7 +15,7 @@ pub struct GetUserFullInfo {
     #[serde(default)]
     user_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetUserFullInfo {
     }
 }
 
-impl RFunction for GetUserFullInfo {}
+impl RFunction for GetUserFullInfo {
+    type Response = UserFullInfo;
+}
 
 impl GetUserFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct GetUserPrivacySettingRules {
     #[serde(skip_serializing_if = "UserPrivacySetting::_is_default")]
     setting: UserPrivacySetting,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetUserPrivacySettingRules {
     }
 }
 
-impl RFunction for GetUserPrivacySettingRules {}
+impl RFunction for GetUserPrivacySettingRules {
+    type Response = UserPrivacySettingRules;
+}
 
 impl GetUserPrivacySettingRules {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct GetUserProfilePhotos {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for GetUserProfilePhotos {
     }
 }
 
-impl RFunction for GetUserProfilePhotos {}
+impl RFunction for GetUserProfilePhotos {
+    type Response = ChatPhotos;
+}
 
 impl GetUserProfilePhotos {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
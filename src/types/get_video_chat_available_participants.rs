@@ -15,7 +15,7 @@ pub struct GetVideoChatAvailableParticipants {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for GetVideoChatAvailableParticipants {
     }
 }
 
-impl RFunction for GetVideoChatAvailableParticipants {}
+impl RFunction for GetVideoChatAvailableParticipants {
+    type Response = MessageSenders;
+}
 
 impl GetVideoChatAvailableParticipants {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
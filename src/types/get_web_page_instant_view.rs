@@ -19,7 +19,7 @@ pub struct GetWebPageInstantView {
     #[serde(default)]
     force_full: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for GetWebPageInstantView {
     }
 }
 
-impl RFunction for GetWebPageInstantView {}
+impl RFunction for GetWebPageInstantView {
+    type Response = WebPageInstantView;
+}
 
 impl GetWebPageInstantView {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
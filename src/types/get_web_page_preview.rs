@@ -13,7 +13,7 @@ pub struct GetWebPagePreview {
     /// Message text with formatting
     text: FormattedText,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for GetWebPagePreview {
     }
 }
 
-impl RFunction for GetWebPagePreview {}
+impl RFunction for GetWebPagePreview {
+    type Response = WebPage;
+}
 
 impl GetWebPagePreview {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDGroupCallVideoQuality: Debug + RObject {}
 
 /// Describes the quality of a group call video
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum GroupCallVideoQuality {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum GroupCallVideoQuality {
     /// The worst available video quality
     #[serde(rename = "groupCallVideoQualityThumbnail")]
     Thumbnail(GroupCallVideoQualityThumbnail),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for GroupCallVideoQuality {
@@ -32,6 +38,9 @@ impl RObject for GroupCallVideoQuality {
             GroupCallVideoQuality::Full(t) => t.extra(),
             GroupCallVideoQuality::Medium(t) => t.extra(),
             GroupCallVideoQuality::Thumbnail(t) => t.extra(),
+            GroupCallVideoQuality::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -42,12 +51,36 @@ impl RObject for GroupCallVideoQuality {
             GroupCallVideoQuality::Full(t) => t.client_id(),
             GroupCallVideoQuality::Medium(t) => t.client_id(),
             GroupCallVideoQuality::Thumbnail(t) => t.client_id(),
+            GroupCallVideoQuality::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for GroupCallVideoQuality {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "groupCallVideoQualityFull" => {
+                GroupCallVideoQuality::Full(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoQualityMedium" => {
+                GroupCallVideoQuality::Medium(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoQualityThumbnail" => {
+                GroupCallVideoQuality::Thumbnail(super::_common::from_raw(raw)?)
+            }
+            _ => GroupCallVideoQuality::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl GroupCallVideoQuality {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
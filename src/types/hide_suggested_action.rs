@@ -15,7 +15,7 @@ pub struct HideSuggestedAction {
     #[serde(skip_serializing_if = "SuggestedAction::_is_default")]
     action: SuggestedAction,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for HideSuggestedAction {
     }
 }
 
-impl RFunction for HideSuggestedAction {}
+impl RFunction for HideSuggestedAction {
+    type Response = Ok;
+}
 
 impl HideSuggestedAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct ImportContacts {
     #[serde(default)]
     contacts: Vec<Contact>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ImportContacts {
     }
 }
 
-impl RFunction for ImportContacts {}
+impl RFunction for ImportContacts {
+    type Response = ImportedContacts;
+}
 
 impl ImportContacts {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct ImportMessages {
     #[serde(default)]
     attached_files: Vec<InputFile>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ImportMessages {
     }
 }
 
-impl RFunction for ImportMessages {}
+impl RFunction for ImportMessages {
+    type Response = Ok;
+}
 
 impl ImportMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
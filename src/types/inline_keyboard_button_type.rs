@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInlineKeyboardButtonType: Debug + RObject {}
 
 /// Describes the type of an inline keyboard button
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InlineKeyboardButtonType {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum InlineKeyboardButtonType {
     /// A button with a user reference to be handled in the same way as textEntityTypeMentionName entities
     #[serde(rename = "inlineKeyboardButtonTypeUser")]
     User(InlineKeyboardButtonTypeUser),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InlineKeyboardButtonType {
@@ -52,6 +58,9 @@ impl RObject for InlineKeyboardButtonType {
             InlineKeyboardButtonType::SwitchInline(t) => t.extra(),
             InlineKeyboardButtonType::Url(t) => t.extra(),
             InlineKeyboardButtonType::User(t) => t.extra(),
+            InlineKeyboardButtonType::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -67,12 +76,51 @@ impl RObject for InlineKeyboardButtonType {
             InlineKeyboardButtonType::SwitchInline(t) => t.client_id(),
             InlineKeyboardButtonType::Url(t) => t.client_id(),
             InlineKeyboardButtonType::User(t) => t.client_id(),
+            InlineKeyboardButtonType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InlineKeyboardButtonType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inlineKeyboardButtonTypeBuy" => {
+                InlineKeyboardButtonType::Buy(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallback" => {
+                InlineKeyboardButtonType::Callback(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallbackGame" => {
+                InlineKeyboardButtonType::CallbackGame(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallbackWithPassword" => {
+                InlineKeyboardButtonType::CallbackWithPassword(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeLoginUrl" => {
+                InlineKeyboardButtonType::LoginUrl(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeSwitchInline" => {
+                InlineKeyboardButtonType::SwitchInline(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeUrl" => {
+                InlineKeyboardButtonType::Url(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeUser" => {
+                InlineKeyboardButtonType::User(super::_common::from_raw(raw)?)
+            }
+            _ => InlineKeyboardButtonType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InlineKeyboardButtonType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InlineQueryResult {
     #[doc(hidden)]
@@ -50,6 +50,12 @@ pub enum InlineQueryResult {
     /// Represents a voice note
     #[serde(rename = "inlineQueryResultVoiceNote")]
     VoiceNote(InlineQueryResultVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InlineQueryResult {
@@ -68,6 +74,7 @@ impl RObject for InlineQueryResult {
             InlineQueryResult::Venue(t) => t.extra(),
             InlineQueryResult::Video(t) => t.extra(),
             InlineQueryResult::VoiceNote(t) => t.extra(),
+            InlineQueryResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -87,12 +94,53 @@ impl RObject for InlineQueryResult {
             InlineQueryResult::Venue(t) => t.client_id(),
             InlineQueryResult::Video(t) => t.client_id(),
             InlineQueryResult::VoiceNote(t) => t.client_id(),
+            InlineQueryResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inlineQueryResultAnimation" => {
+                InlineQueryResult::Animation(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultArticle" => {
+                InlineQueryResult::Article(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultAudio" => InlineQueryResult::Audio(super::_common::from_raw(raw)?),
+            "inlineQueryResultContact" => {
+                InlineQueryResult::Contact(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultDocument" => {
+                InlineQueryResult::Document(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultGame" => InlineQueryResult::Game(super::_common::from_raw(raw)?),
+            "inlineQueryResultLocation" => {
+                InlineQueryResult::Location(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultPhoto" => InlineQueryResult::Photo(super::_common::from_raw(raw)?),
+            "inlineQueryResultSticker" => {
+                InlineQueryResult::Sticker(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultVenue" => InlineQueryResult::Venue(super::_common::from_raw(raw)?),
+            "inlineQueryResultVideo" => InlineQueryResult::Video(super::_common::from_raw(raw)?),
+            "inlineQueryResultVoiceNote" => {
+                InlineQueryResult::VoiceNote(super::_common::from_raw(raw)?)
+            }
+            _ => InlineQueryResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InlineQueryResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputBackground: Debug + RObject {}
 
 /// Contains information about background to set
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputBackground {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum InputBackground {
     /// A background from the server
     #[serde(rename = "inputBackgroundRemote")]
     Remote(InputBackgroundRemote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputBackground {
@@ -28,6 +34,7 @@ impl RObject for InputBackground {
         match self {
             InputBackground::Local(t) => t.extra(),
             InputBackground::Remote(t) => t.extra(),
+            InputBackground::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for InputBackground {
         match self {
             InputBackground::Local(t) => t.client_id(),
             InputBackground::Remote(t) => t.client_id(),
+            InputBackground::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputBackground {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputBackgroundLocal" => InputBackground::Local(super::_common::from_raw(raw)?),
+            "inputBackgroundRemote" => InputBackground::Remote(super::_common::from_raw(raw)?),
+            _ => InputBackground::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputChatPhoto: Debug + RObject {}
 
 /// Describes a photo to be set as a user profile or chat photo
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputChatPhoto {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum InputChatPhoto {
     /// A static photo in JPEG format
     #[serde(rename = "inputChatPhotoStatic")]
     Static(InputChatPhotoStatic),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputChatPhoto {
@@ -32,6 +38,7 @@ impl RObject for InputChatPhoto {
             InputChatPhoto::Animation(t) => t.extra(),
             InputChatPhoto::Previous(t) => t.extra(),
             InputChatPhoto::Static(t) => t.extra(),
+            InputChatPhoto::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,30 @@ impl RObject for InputChatPhoto {
             InputChatPhoto::Animation(t) => t.client_id(),
             InputChatPhoto::Previous(t) => t.client_id(),
             InputChatPhoto::Static(t) => t.client_id(),
+            InputChatPhoto::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputChatPhoto {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputChatPhotoAnimation" => InputChatPhoto::Animation(super::_common::from_raw(raw)?),
+            "inputChatPhotoPrevious" => InputChatPhoto::Previous(super::_common::from_raw(raw)?),
+            "inputChatPhotoStatic" => InputChatPhoto::Static(super::_common::from_raw(raw)?),
+            _ => InputChatPhoto::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputChatPhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
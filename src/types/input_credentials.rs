@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputCredentials: Debug + RObject {}
 
 /// Contains information about the payment method chosen by the user
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputCredentials {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum InputCredentials {
     /// Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password
     #[serde(rename = "inputCredentialsSaved")]
     Saved(InputCredentialsSaved),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputCredentials {
@@ -36,6 +42,7 @@ impl RObject for InputCredentials {
             InputCredentials::GooglePay(t) => t.extra(),
             InputCredentials::New(t) => t.extra(),
             InputCredentials::Saved(t) => t.extra(),
+            InputCredentials::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,35 @@ impl RObject for InputCredentials {
             InputCredentials::GooglePay(t) => t.client_id(),
             InputCredentials::New(t) => t.client_id(),
             InputCredentials::Saved(t) => t.client_id(),
+            InputCredentials::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputCredentials {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputCredentialsApplePay" => {
+                InputCredentials::ApplePay(super::_common::from_raw(raw)?)
+            }
+            "inputCredentialsGooglePay" => {
+                InputCredentials::GooglePay(super::_common::from_raw(raw)?)
+            }
+            "inputCredentialsNew" => InputCredentials::New(super::_common::from_raw(raw)?),
+            "inputCredentialsSaved" => InputCredentials::Saved(super::_common::from_raw(raw)?),
+            _ => InputCredentials::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputCredentials {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputFile: Debug + RObject {}
 
 /// Points to a file
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputFile {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum InputFile {
     /// A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application
     #[serde(rename = "inputFileRemote")]
     Remote(InputFileRemote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputFile {
@@ -36,6 +42,7 @@ impl RObject for InputFile {
             InputFile::Id(t) => t.extra(),
             InputFile::Local(t) => t.extra(),
             InputFile::Remote(t) => t.extra(),
+            InputFile::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for InputFile {
             InputFile::Id(t) => t.client_id(),
             InputFile::Local(t) => t.client_id(),
             InputFile::Remote(t) => t.client_id(),
+            InputFile::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputFile {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputFileGenerated" => InputFile::Generated(super::_common::from_raw(raw)?),
+            "inputFileId" => InputFile::Id(super::_common::from_raw(raw)?),
+            "inputFileLocal" => InputFile::Local(super::_common::from_raw(raw)?),
+            "inputFileRemote" => InputFile::Remote(super::_common::from_raw(raw)?),
+            _ => InputFile::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query; for bots only
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputInlineQueryResult {
     #[doc(hidden)]
@@ -50,6 +50,12 @@ pub enum InputInlineQueryResult {
     /// Represents a link to an opus-encoded audio file within an OGG container, single channel audio
     #[serde(rename = "inputInlineQueryResultVoiceNote")]
     VoiceNote(InputInlineQueryResultVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputInlineQueryResult {
@@ -68,6 +74,9 @@ impl RObject for InputInlineQueryResult {
             InputInlineQueryResult::Venue(t) => t.extra(),
             InputInlineQueryResult::Video(t) => t.extra(),
             InputInlineQueryResult::VoiceNote(t) => t.extra(),
+            InputInlineQueryResult::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -87,12 +96,63 @@ impl RObject for InputInlineQueryResult {
             InputInlineQueryResult::Venue(t) => t.client_id(),
             InputInlineQueryResult::Video(t) => t.client_id(),
             InputInlineQueryResult::VoiceNote(t) => t.client_id(),
+            InputInlineQueryResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputInlineQueryResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputInlineQueryResultAnimation" => {
+                InputInlineQueryResult::Animation(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultArticle" => {
+                InputInlineQueryResult::Article(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultAudio" => {
+                InputInlineQueryResult::Audio(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultContact" => {
+                InputInlineQueryResult::Contact(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultDocument" => {
+                InputInlineQueryResult::Document(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultGame" => {
+                InputInlineQueryResult::Game(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultLocation" => {
+                InputInlineQueryResult::Location(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultPhoto" => {
+                InputInlineQueryResult::Photo(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultSticker" => {
+                InputInlineQueryResult::Sticker(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVenue" => {
+                InputInlineQueryResult::Venue(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVideo" => {
+                InputInlineQueryResult::Video(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVoiceNote" => {
+                InputInlineQueryResult::VoiceNote(super::_common::from_raw(raw)?)
+            }
+            _ => InputInlineQueryResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputInlineQueryResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputMessageContent: Debug + RObject {}
 
 /// The content of a message to send
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputMessageContent {
     #[doc(hidden)]
@@ -65,6 +65,12 @@ pub enum InputMessageContent {
     /// A voice note message
     #[serde(rename = "inputMessageVoiceNote")]
     InputMessageVoiceNote(InputMessageVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputMessageContent {
@@ -88,6 +94,7 @@ impl RObject for InputMessageContent {
             InputMessageContent::InputMessageVideo(t) => t.extra(),
             InputMessageContent::InputMessageVideoNote(t) => t.extra(),
             InputMessageContent::InputMessageVoiceNote(t) => t.extra(),
+            InputMessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -112,12 +119,78 @@ impl RObject for InputMessageContent {
             InputMessageContent::InputMessageVideo(t) => t.client_id(),
             InputMessageContent::InputMessageVideoNote(t) => t.client_id(),
             InputMessageContent::InputMessageVoiceNote(t) => t.client_id(),
+            InputMessageContent::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputMessageContent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputMessageAnimation" => {
+                InputMessageContent::InputMessageAnimation(super::_common::from_raw(raw)?)
+            }
+            "inputMessageAudio" => {
+                InputMessageContent::InputMessageAudio(super::_common::from_raw(raw)?)
+            }
+            "inputMessageContact" => {
+                InputMessageContent::InputMessageContact(super::_common::from_raw(raw)?)
+            }
+            "inputMessageDice" => {
+                InputMessageContent::InputMessageDice(super::_common::from_raw(raw)?)
+            }
+            "inputMessageDocument" => {
+                InputMessageContent::InputMessageDocument(super::_common::from_raw(raw)?)
+            }
+            "inputMessageForwarded" => {
+                InputMessageContent::InputMessageForwarded(super::_common::from_raw(raw)?)
+            }
+            "inputMessageGame" => {
+                InputMessageContent::InputMessageGame(super::_common::from_raw(raw)?)
+            }
+            "inputMessageInvoice" => {
+                InputMessageContent::InputMessageInvoice(super::_common::from_raw(raw)?)
+            }
+            "inputMessageLocation" => {
+                InputMessageContent::InputMessageLocation(super::_common::from_raw(raw)?)
+            }
+            "inputMessagePhoto" => {
+                InputMessageContent::InputMessagePhoto(super::_common::from_raw(raw)?)
+            }
+            "inputMessagePoll" => {
+                InputMessageContent::InputMessagePoll(super::_common::from_raw(raw)?)
+            }
+            "inputMessageSticker" => {
+                InputMessageContent::InputMessageSticker(super::_common::from_raw(raw)?)
+            }
+            "inputMessageText" => {
+                InputMessageContent::InputMessageText(super::_common::from_raw(raw)?)
+            }
+            "inputMessageVenue" => {
+                InputMessageContent::InputMessageVenue(super::_common::from_raw(raw)?)
+            }
+            "inputMessageVideo" => {
+                InputMessageContent::InputMessageVideo(super::_common::from_raw(raw)?)
+            }
+            "inputMessageVideoNote" => {
+                InputMessageContent::InputMessageVideoNote(super::_common::from_raw(raw)?)
+            }
+            "inputMessageVoiceNote" => {
+                InputMessageContent::InputMessageVoiceNote(super::_common::from_raw(raw)?)
+            }
+            _ => InputMessageContent::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element to be saved
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputPassportElement {
     #[doc(hidden)]
@@ -53,6 +53,12 @@ pub enum InputPassportElement {
     /// A Telegram Passport element to be saved containing the user's utility bill
     #[serde(rename = "inputPassportElementUtilityBill")]
     UtilityBill(InputPassportElementUtilityBill),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputPassportElement {
@@ -72,6 +78,7 @@ impl RObject for InputPassportElement {
             InputPassportElement::RentalAgreement(t) => t.extra(),
             InputPassportElement::TemporaryRegistration(t) => t.extra(),
             InputPassportElement::UtilityBill(t) => t.extra(),
+            InputPassportElement::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -92,12 +99,66 @@ impl RObject for InputPassportElement {
             InputPassportElement::RentalAgreement(t) => t.client_id(),
             InputPassportElement::TemporaryRegistration(t) => t.client_id(),
             InputPassportElement::UtilityBill(t) => t.client_id(),
+            InputPassportElement::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputPassportElement {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputPassportElementAddress" => {
+                InputPassportElement::Address(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementBankStatement" => {
+                InputPassportElement::BankStatement(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementDriverLicense" => {
+                InputPassportElement::DriverLicense(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementEmailAddress" => {
+                InputPassportElement::EmailAddress(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementIdentityCard" => {
+                InputPassportElement::IdentityCard(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementInternalPassport" => {
+                InputPassportElement::InternalPassport(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPassport" => {
+                InputPassportElement::Passport(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPassportRegistration" => {
+                InputPassportElement::PassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPersonalDetails" => {
+                InputPassportElement::PersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPhoneNumber" => {
+                InputPassportElement::PhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementRentalAgreement" => {
+                InputPassportElement::RentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementTemporaryRegistration" => {
+                InputPassportElement::TemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementUtilityBill" => {
+                InputPassportElement::UtilityBill(super::_common::from_raw(raw)?)
+            }
+            _ => InputPassportElement::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputPassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element; for bots only
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputPassportElementErrorSource {
     #[doc(hidden)]
@@ -41,6 +41,12 @@ pub enum InputPassportElementErrorSource {
     /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
     #[serde(rename = "inputPassportElementErrorSourceUnspecified")]
     Unspecified(InputPassportElementErrorSourceUnspecified),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputPassportElementErrorSource {
@@ -56,6 +62,9 @@ impl RObject for InputPassportElementErrorSource {
             InputPassportElementErrorSource::TranslationFile(t) => t.extra(),
             InputPassportElementErrorSource::TranslationFiles(t) => t.extra(),
             InputPassportElementErrorSource::Unspecified(t) => t.extra(),
+            InputPassportElementErrorSource::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -72,12 +81,54 @@ impl RObject for InputPassportElementErrorSource {
             InputPassportElementErrorSource::TranslationFile(t) => t.client_id(),
             InputPassportElementErrorSource::TranslationFiles(t) => t.client_id(),
             InputPassportElementErrorSource::Unspecified(t) => t.client_id(),
+            InputPassportElementErrorSource::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputPassportElementErrorSource {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputPassportElementErrorSourceDataField" => {
+                InputPassportElementErrorSource::DataField(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFile" => {
+                InputPassportElementErrorSource::File(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFiles" => {
+                InputPassportElementErrorSource::Files(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFrontSide" => {
+                InputPassportElementErrorSource::FrontSide(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceReverseSide" => {
+                InputPassportElementErrorSource::ReverseSide(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceSelfie" => {
+                InputPassportElementErrorSource::Selfie(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceTranslationFile" => {
+                InputPassportElementErrorSource::TranslationFile(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceTranslationFiles" => {
+                InputPassportElementErrorSource::TranslationFiles(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceUnspecified" => {
+                InputPassportElementErrorSource::Unspecified(super::_common::from_raw(raw)?)
+            }
+            _ => InputPassportElementErrorSource::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputPassportElementErrorSource {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInputSticker: Debug + RObject {}
 
 /// Describes a sticker that needs to be added to a sticker set
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InputSticker {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum InputSticker {
     /// A static sticker in PNG format, which will be converted to WEBP server-side
     #[serde(rename = "inputStickerStatic")]
     Static(InputStickerStatic),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InputSticker {
@@ -28,6 +34,7 @@ impl RObject for InputSticker {
         match self {
             InputSticker::Animated(t) => t.extra(),
             InputSticker::Static(t) => t.extra(),
+            InputSticker::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for InputSticker {
         match self {
             InputSticker::Animated(t) => t.client_id(),
             InputSticker::Static(t) => t.client_id(),
+            InputSticker::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InputSticker {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "inputStickerAnimated" => InputSticker::Animated(super::_common::from_raw(raw)?),
+            "inputStickerStatic" => InputSticker::Static(super::_common::from_raw(raw)?),
+            _ => InputSticker::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InputSticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
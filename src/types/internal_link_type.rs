@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDInternalLinkType: Debug + RObject {}
 
 /// Describes an internal https://t.me or tg: link, which must be processed by the app in a special way
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum InternalLinkType {
     #[doc(hidden)]
@@ -89,6 +89,12 @@ pub enum InternalLinkType {
     /// The link is a link to a video chat. Call searchPublicChat with the given chat username, and then joinGoupCall with the given invite hash to process the link
     #[serde(rename = "internalLinkTypeVideoChat")]
     VideoChat(InternalLinkTypeVideoChat),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for InternalLinkType {
@@ -120,6 +126,7 @@ impl RObject for InternalLinkType {
             InternalLinkType::UnknownDeepLink(t) => t.extra(),
             InternalLinkType::UnsupportedProxy(t) => t.extra(),
             InternalLinkType::VideoChat(t) => t.extra(),
+            InternalLinkType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -152,12 +159,94 @@ impl RObject for InternalLinkType {
             InternalLinkType::UnknownDeepLink(t) => t.client_id(),
             InternalLinkType::UnsupportedProxy(t) => t.client_id(),
             InternalLinkType::VideoChat(t) => t.client_id(),
+            InternalLinkType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for InternalLinkType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getInternalLinkType" => {
+                InternalLinkType::GetInternalLinkType(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeActiveSessions" => {
+                InternalLinkType::ActiveSessions(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeAuthenticationCode" => {
+                InternalLinkType::AuthenticationCode(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBackground" => {
+                InternalLinkType::Background(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBotStart" => {
+                InternalLinkType::BotStart(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBotStartInGroup" => {
+                InternalLinkType::BotStartInGroup(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeChangePhoneNumber" => {
+                InternalLinkType::ChangePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeChatInvite" => {
+                InternalLinkType::ChatInvite(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeFilterSettings" => {
+                InternalLinkType::FilterSettings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeGame" => InternalLinkType::Game(super::_common::from_raw(raw)?),
+            "internalLinkTypeLanguagePack" => {
+                InternalLinkType::LanguagePack(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeMessage" => InternalLinkType::Message(super::_common::from_raw(raw)?),
+            "internalLinkTypeMessageDraft" => {
+                InternalLinkType::MessageDraft(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypePassportDataRequest" => {
+                InternalLinkType::PassportDataRequest(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypePhoneNumberConfirmation" => {
+                InternalLinkType::PhoneNumberConfirmation(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeProxy" => InternalLinkType::Proxy(super::_common::from_raw(raw)?),
+            "internalLinkTypePublicChat" => {
+                InternalLinkType::PublicChat(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeQrCodeAuthentication" => {
+                InternalLinkType::QrCodeAuthentication(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeSettings" => {
+                InternalLinkType::Settings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeStickerSet" => {
+                InternalLinkType::StickerSet(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeTheme" => InternalLinkType::Theme(super::_common::from_raw(raw)?),
+            "internalLinkTypeThemeSettings" => {
+                InternalLinkType::ThemeSettings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeUnknownDeepLink" => {
+                InternalLinkType::UnknownDeepLink(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeUnsupportedProxy" => {
+                InternalLinkType::UnsupportedProxy(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeVideoChat" => {
+                InternalLinkType::VideoChat(super::_common::from_raw(raw)?)
+            }
+            _ => InternalLinkType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl InternalLinkType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -19,7 +19,7 @@ pub struct InviteGroupCallParticipants {
     #[serde(default)]
     user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for InviteGroupCallParticipants {
     }
 }
 
-impl RFunction for InviteGroupCallParticipants {}
+impl RFunction for InviteGroupCallParticipants {
+    type Response = Ok;
+}
 
 impl InviteGroupCallParticipants {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct JoinChatByInviteLink {
     #[serde(default)]
     invite_link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for JoinChatByInviteLink {
     }
 }
 
-impl RFunction for JoinChatByInviteLink {}
+impl RFunction for JoinChatByInviteLink {
+    type Response = Chat;
+}
 
 impl JoinChatByInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -39,7 +39,7 @@ pub struct JoinGroupCall {
     #[serde(default)]
     invite_hash: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -54,7 +54,9 @@ impl RObject for JoinGroupCall {
     }
 }
 
-impl RFunction for JoinGroupCall {}
+impl RFunction for JoinGroupCall {
+    type Response = Text;
+}
 
 impl JoinGroupCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDJsonValue: Debug + RObject {}
 
 /// Represents a JSON value
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum JsonValue {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum JsonValue {
     /// Represents a string JSON value
     #[serde(rename = "jsonValueString")]
     String(JsonValueString),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for JsonValue {
@@ -52,6 +58,7 @@ impl RObject for JsonValue {
             JsonValue::Number(t) => t.extra(),
             JsonValue::Object(t) => t.extra(),
             JsonValue::String(t) => t.extra(),
+            JsonValue::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -67,12 +74,37 @@ impl RObject for JsonValue {
             JsonValue::Number(t) => t.client_id(),
             JsonValue::Object(t) => t.client_id(),
             JsonValue::String(t) => t.client_id(),
+            JsonValue::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for JsonValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getApplicationConfig" => {
+                JsonValue::GetApplicationConfig(super::_common::from_raw(raw)?)
+            }
+            "getJsonValue" => JsonValue::GetJsonValue(super::_common::from_raw(raw)?),
+            "jsonValueArray" => JsonValue::Array(super::_common::from_raw(raw)?),
+            "jsonValueBoolean" => JsonValue::Boolean(super::_common::from_raw(raw)?),
+            "jsonValueNull" => JsonValue::Null(super::_common::from_raw(raw)?),
+            "jsonValueNumber" => JsonValue::Number(super::_common::from_raw(raw)?),
+            "jsonValueObject" => JsonValue::Object(super::_common::from_raw(raw)?),
+            "jsonValueString" => JsonValue::String(super::_common::from_raw(raw)?),
+            _ => JsonValue::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl JsonValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDKeyboardButtonType: Debug + RObject {}
 
 /// Describes a keyboard button type
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum KeyboardButtonType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum KeyboardButtonType {
     /// A simple button, with text that must be sent when the button is pressed
     #[serde(rename = "keyboardButtonTypeText")]
     Text(KeyboardButtonTypeText),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for KeyboardButtonType {
@@ -36,6 +42,7 @@ impl RObject for KeyboardButtonType {
             KeyboardButtonType::RequestPhoneNumber(t) => t.extra(),
             KeyboardButtonType::RequestPoll(t) => t.extra(),
             KeyboardButtonType::Text(t) => t.extra(),
+            KeyboardButtonType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,37 @@ impl RObject for KeyboardButtonType {
             KeyboardButtonType::RequestPhoneNumber(t) => t.client_id(),
             KeyboardButtonType::RequestPoll(t) => t.client_id(),
             KeyboardButtonType::Text(t) => t.client_id(),
+            KeyboardButtonType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for KeyboardButtonType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "keyboardButtonTypeRequestLocation" => {
+                KeyboardButtonType::RequestLocation(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeRequestPhoneNumber" => {
+                KeyboardButtonType::RequestPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeRequestPoll" => {
+                KeyboardButtonType::RequestPoll(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeText" => KeyboardButtonType::Text(super::_common::from_raw(raw)?),
+            _ => KeyboardButtonType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl KeyboardButtonType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDLanguagePackStringValue: Debug + RObject {}
 
 /// Represents the value of a string in a language pack
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum LanguagePackStringValue {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum LanguagePackStringValue {
     /// A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info
     #[serde(rename = "languagePackStringValuePluralized")]
     Pluralized(LanguagePackStringValuePluralized),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for LanguagePackStringValue {
@@ -36,6 +42,9 @@ impl RObject for LanguagePackStringValue {
             LanguagePackStringValue::Deleted(t) => t.extra(),
             LanguagePackStringValue::Ordinary(t) => t.extra(),
             LanguagePackStringValue::Pluralized(t) => t.extra(),
+            LanguagePackStringValue::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -47,12 +56,39 @@ impl RObject for LanguagePackStringValue {
             LanguagePackStringValue::Deleted(t) => t.client_id(),
             LanguagePackStringValue::Ordinary(t) => t.client_id(),
             LanguagePackStringValue::Pluralized(t) => t.client_id(),
+            LanguagePackStringValue::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for LanguagePackStringValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getLanguagePackString" => {
+                LanguagePackStringValue::GetLanguagePackString(super::_common::from_raw(raw)?)
+            }
+            "languagePackStringValueDeleted" => {
+                LanguagePackStringValue::Deleted(super::_common::from_raw(raw)?)
+            }
+            "languagePackStringValueOrdinary" => {
+                LanguagePackStringValue::Ordinary(super::_common::from_raw(raw)?)
+            }
+            "languagePackStringValuePluralized" => {
+                LanguagePackStringValue::Pluralized(super::_common::from_raw(raw)?)
+            }
+            _ => LanguagePackStringValue::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl LanguagePackStringValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
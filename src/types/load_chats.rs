@@ -19,7 +19,7 @@ pub struct LoadChats {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for LoadChats {
     }
 }
 
-impl RFunction for LoadChats {}
+impl RFunction for LoadChats {
+    type Response = Ok;
+}
 
 impl LoadChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
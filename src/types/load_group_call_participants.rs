@@ -19,7 +19,7 @@ pub struct LoadGroupCallParticipants {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for LoadGroupCallParticipants {
     }
 }
 
-impl RFunction for LoadGroupCallParticipants {}
+impl RFunction for LoadGroupCallParticipants {
+    type Response = Ok;
+}
 
 impl LoadGroupCallParticipants {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
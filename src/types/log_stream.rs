@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDLogStream: Debug + RObject {}
 
 /// Describes a stream to which TDLib internal log is written
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum LogStream {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum LogStream {
     /// The log is written to a file
     #[serde(rename = "logStreamFile")]
     File(LogStreamFile),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for LogStream {
@@ -36,6 +42,7 @@ impl RObject for LogStream {
             LogStream::Default(t) => t.extra(),
             LogStream::Empty(t) => t.extra(),
             LogStream::File(t) => t.extra(),
+            LogStream::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for LogStream {
             LogStream::Default(t) => t.client_id(),
             LogStream::Empty(t) => t.client_id(),
             LogStream::File(t) => t.client_id(),
+            LogStream::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for LogStream {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getLogStream" => LogStream::GetLogStream(super::_common::from_raw(raw)?),
+            "logStreamDefault" => LogStream::Default(super::_common::from_raw(raw)?),
+            "logStreamEmpty" => LogStream::Empty(super::_common::from_raw(raw)?),
+            "logStreamFile" => LogStream::File(super::_common::from_raw(raw)?),
+            _ => LogStream::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl LogStream {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
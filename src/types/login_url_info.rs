@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDLoginUrlInfo: Debug + RObject {}
 
 /// Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum LoginUrlInfo {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum LoginUrlInfo {
     /// An authorization confirmation dialog needs to be shown to the user
     #[serde(rename = "loginUrlInfoRequestConfirmation")]
     RequestConfirmation(LoginUrlInfoRequestConfirmation),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for LoginUrlInfo {
@@ -36,6 +42,7 @@ impl RObject for LoginUrlInfo {
             LoginUrlInfo::GetLoginUrlInfo(t) => t.extra(),
             LoginUrlInfo::Open(t) => t.extra(),
             LoginUrlInfo::RequestConfirmation(t) => t.extra(),
+            LoginUrlInfo::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,35 @@ impl RObject for LoginUrlInfo {
             LoginUrlInfo::GetLoginUrlInfo(t) => t.client_id(),
             LoginUrlInfo::Open(t) => t.client_id(),
             LoginUrlInfo::RequestConfirmation(t) => t.client_id(),
+            LoginUrlInfo::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for LoginUrlInfo {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getExternalLinkInfo" => {
+                LoginUrlInfo::GetExternalLinkInfo(super::_common::from_raw(raw)?)
+            }
+            "getLoginUrlInfo" => LoginUrlInfo::GetLoginUrlInfo(super::_common::from_raw(raw)?),
+            "loginUrlInfoOpen" => LoginUrlInfo::Open(super::_common::from_raw(raw)?),
+            "loginUrlInfoRequestConfirmation" => {
+                LoginUrlInfo::RequestConfirmation(super::_common::from_raw(raw)?)
+            }
+            _ => LoginUrlInfo::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl LoginUrlInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMaskPoint: Debug + RObject {}
 
 /// Part of the face, relative to which a mask is placed
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MaskPoint {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum MaskPoint {
     /// The mask is placed relatively to the mouth
     #[serde(rename = "maskPointMouth")]
     Mouth(MaskPointMouth),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MaskPoint {
@@ -36,6 +42,7 @@ impl RObject for MaskPoint {
             MaskPoint::Eyes(t) => t.extra(),
             MaskPoint::Forehead(t) => t.extra(),
             MaskPoint::Mouth(t) => t.extra(),
+            MaskPoint::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for MaskPoint {
             MaskPoint::Eyes(t) => t.client_id(),
             MaskPoint::Forehead(t) => t.client_id(),
             MaskPoint::Mouth(t) => t.client_id(),
+            MaskPoint::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MaskPoint {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "maskPointChin" => MaskPoint::Chin(super::_common::from_raw(raw)?),
+            "maskPointEyes" => MaskPoint::Eyes(super::_common::from_raw(raw)?),
+            "maskPointForehead" => MaskPoint::Forehead(super::_common::from_raw(raw)?),
+            "maskPointMouth" => MaskPoint::Mouth(super::_common::from_raw(raw)?),
+            _ => MaskPoint::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MaskPoint {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageContent: Debug + RObject {}
 
 /// Contains the content of a message
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageContent {
     #[doc(hidden)]
@@ -161,6 +161,12 @@ pub enum MessageContent {
     /// The current user has connected a website by logging in using Telegram Login Widget on it
     #[serde(rename = "messageWebsiteConnected")]
     MessageWebsiteConnected(MessageWebsiteConnected),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageContent {
@@ -216,6 +222,7 @@ impl RObject for MessageContent {
             MessageContent::MessageVideoNote(t) => t.extra(),
             MessageContent::MessageVoiceNote(t) => t.extra(),
             MessageContent::MessageWebsiteConnected(t) => t.extra(),
+            MessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -272,12 +279,138 @@ impl RObject for MessageContent {
             MessageContent::MessageVideoNote(t) => t.client_id(),
             MessageContent::MessageVoiceNote(t) => t.client_id(),
             MessageContent::MessageWebsiteConnected(t) => t.client_id(),
+            MessageContent::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageContent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "messageAnimatedEmoji" => {
+                MessageContent::MessageAnimatedEmoji(super::_common::from_raw(raw)?)
+            }
+            "messageAnimation" => MessageContent::MessageAnimation(super::_common::from_raw(raw)?),
+            "messageAudio" => MessageContent::MessageAudio(super::_common::from_raw(raw)?),
+            "messageBasicGroupChatCreate" => {
+                MessageContent::MessageBasicGroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "messageCall" => MessageContent::MessageCall(super::_common::from_raw(raw)?),
+            "messageChatAddMembers" => {
+                MessageContent::MessageChatAddMembers(super::_common::from_raw(raw)?)
+            }
+            "messageChatChangePhoto" => {
+                MessageContent::MessageChatChangePhoto(super::_common::from_raw(raw)?)
+            }
+            "messageChatChangeTitle" => {
+                MessageContent::MessageChatChangeTitle(super::_common::from_raw(raw)?)
+            }
+            "messageChatDeleteMember" => {
+                MessageContent::MessageChatDeleteMember(super::_common::from_raw(raw)?)
+            }
+            "messageChatDeletePhoto" => {
+                MessageContent::MessageChatDeletePhoto(super::_common::from_raw(raw)?)
+            }
+            "messageChatJoinByLink" => {
+                MessageContent::MessageChatJoinByLink(super::_common::from_raw(raw)?)
+            }
+            "messageChatJoinByRequest" => {
+                MessageContent::MessageChatJoinByRequest(super::_common::from_raw(raw)?)
+            }
+            "messageChatSetTheme" => {
+                MessageContent::MessageChatSetTheme(super::_common::from_raw(raw)?)
+            }
+            "messageChatSetTtl" => {
+                MessageContent::MessageChatSetTtl(super::_common::from_raw(raw)?)
+            }
+            "messageChatUpgradeFrom" => {
+                MessageContent::MessageChatUpgradeFrom(super::_common::from_raw(raw)?)
+            }
+            "messageChatUpgradeTo" => {
+                MessageContent::MessageChatUpgradeTo(super::_common::from_raw(raw)?)
+            }
+            "messageContact" => MessageContent::MessageContact(super::_common::from_raw(raw)?),
+            "messageContactRegistered" => {
+                MessageContent::MessageContactRegistered(super::_common::from_raw(raw)?)
+            }
+            "messageCustomServiceAction" => {
+                MessageContent::MessageCustomServiceAction(super::_common::from_raw(raw)?)
+            }
+            "messageDice" => MessageContent::MessageDice(super::_common::from_raw(raw)?),
+            "messageDocument" => MessageContent::MessageDocument(super::_common::from_raw(raw)?),
+            "messageExpiredPhoto" => {
+                MessageContent::MessageExpiredPhoto(super::_common::from_raw(raw)?)
+            }
+            "messageExpiredVideo" => {
+                MessageContent::MessageExpiredVideo(super::_common::from_raw(raw)?)
+            }
+            "messageGame" => MessageContent::MessageGame(super::_common::from_raw(raw)?),
+            "messageGameScore" => MessageContent::MessageGameScore(super::_common::from_raw(raw)?),
+            "messageInviteVideoChatParticipants" => {
+                MessageContent::MessageInviteVideoChatParticipants(super::_common::from_raw(raw)?)
+            }
+            "messageInvoice" => MessageContent::MessageInvoice(super::_common::from_raw(raw)?),
+            "messageLocation" => MessageContent::MessageLocation(super::_common::from_raw(raw)?),
+            "messagePassportDataReceived" => {
+                MessageContent::MessagePassportDataReceived(super::_common::from_raw(raw)?)
+            }
+            "messagePassportDataSent" => {
+                MessageContent::MessagePassportDataSent(super::_common::from_raw(raw)?)
+            }
+            "messagePaymentSuccessful" => {
+                MessageContent::MessagePaymentSuccessful(super::_common::from_raw(raw)?)
+            }
+            "messagePaymentSuccessfulBot" => {
+                MessageContent::MessagePaymentSuccessfulBot(super::_common::from_raw(raw)?)
+            }
+            "messagePhoto" => MessageContent::MessagePhoto(super::_common::from_raw(raw)?),
+            "messagePinMessage" => {
+                MessageContent::MessagePinMessage(super::_common::from_raw(raw)?)
+            }
+            "messagePoll" => MessageContent::MessagePoll(super::_common::from_raw(raw)?),
+            "messageProximityAlertTriggered" => {
+                MessageContent::MessageProximityAlertTriggered(super::_common::from_raw(raw)?)
+            }
+            "messageScreenshotTaken" => {
+                MessageContent::MessageScreenshotTaken(super::_common::from_raw(raw)?)
+            }
+            "messageSticker" => MessageContent::MessageSticker(super::_common::from_raw(raw)?),
+            "messageSupergroupChatCreate" => {
+                MessageContent::MessageSupergroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "messageText" => MessageContent::MessageText(super::_common::from_raw(raw)?),
+            "messageUnsupported" => {
+                MessageContent::MessageUnsupported(super::_common::from_raw(raw)?)
+            }
+            "messageVenue" => MessageContent::MessageVenue(super::_common::from_raw(raw)?),
+            "messageVideo" => MessageContent::MessageVideo(super::_common::from_raw(raw)?),
+            "messageVideoChatEnded" => {
+                MessageContent::MessageVideoChatEnded(super::_common::from_raw(raw)?)
+            }
+            "messageVideoChatScheduled" => {
+                MessageContent::MessageVideoChatScheduled(super::_common::from_raw(raw)?)
+            }
+            "messageVideoChatStarted" => {
+                MessageContent::MessageVideoChatStarted(super::_common::from_raw(raw)?)
+            }
+            "messageVideoNote" => MessageContent::MessageVideoNote(super::_common::from_raw(raw)?),
+            "messageVoiceNote" => MessageContent::MessageVoiceNote(super::_common::from_raw(raw)?),
+            "messageWebsiteConnected" => {
+                MessageContent::MessageWebsiteConnected(super::_common::from_raw(raw)?)
+            }
+            _ => MessageContent::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageFileType: Debug + RObject {}
 
 /// Contains information about a file with messages exported from another app
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageFileType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum MessageFileType {
     /// The messages was exported from a chat of unknown type
     #[serde(rename = "messageFileTypeUnknown")]
     Unknown(MessageFileTypeUnknown),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    UnknownType {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageFileType {
@@ -36,6 +42,7 @@ impl RObject for MessageFileType {
             MessageFileType::Group(t) => t.extra(),
             MessageFileType::Private(t) => t.extra(),
             MessageFileType::Unknown(t) => t.extra(),
+            MessageFileType::UnknownType { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,33 @@ impl RObject for MessageFileType {
             MessageFileType::Group(t) => t.client_id(),
             MessageFileType::Private(t) => t.client_id(),
             MessageFileType::Unknown(t) => t.client_id(),
+            MessageFileType::UnknownType { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageFileType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getMessageFileType" => {
+                MessageFileType::GetMessageFileType(super::_common::from_raw(raw)?)
+            }
+            "messageFileTypeGroup" => MessageFileType::Group(super::_common::from_raw(raw)?),
+            "messageFileTypePrivate" => MessageFileType::Private(super::_common::from_raw(raw)?),
+            "messageFileTypeUnknown" => MessageFileType::Unknown(super::_common::from_raw(raw)?),
+            _ => MessageFileType::UnknownType { type_name, raw },
+        })
+    }
+}
+
 impl MessageFileType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
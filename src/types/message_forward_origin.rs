@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageForwardOrigin: Debug + RObject {}
 
 /// Contains information about the origin of a forwarded message
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageForwardOrigin {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum MessageForwardOrigin {
     /// The message was originally sent by a known user
     #[serde(rename = "messageForwardOriginUser")]
     User(MessageForwardOriginUser),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageForwardOrigin {
@@ -40,6 +46,7 @@ impl RObject for MessageForwardOrigin {
             MessageForwardOrigin::HiddenUser(t) => t.extra(),
             MessageForwardOrigin::MessageImport(t) => t.extra(),
             MessageForwardOrigin::User(t) => t.extra(),
+            MessageForwardOrigin::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -52,12 +59,42 @@ impl RObject for MessageForwardOrigin {
             MessageForwardOrigin::HiddenUser(t) => t.client_id(),
             MessageForwardOrigin::MessageImport(t) => t.client_id(),
             MessageForwardOrigin::User(t) => t.client_id(),
+            MessageForwardOrigin::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageForwardOrigin {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "messageForwardOriginChannel" => {
+                MessageForwardOrigin::Channel(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginChat" => {
+                MessageForwardOrigin::Chat(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginHiddenUser" => {
+                MessageForwardOrigin::HiddenUser(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginMessageImport" => {
+                MessageForwardOrigin::MessageImport(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginUser" => {
+                MessageForwardOrigin::User(super::_common::from_raw(raw)?)
+            }
+            _ => MessageForwardOrigin::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MessageForwardOrigin {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
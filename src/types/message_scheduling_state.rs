@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageSchedulingState: Debug + RObject {}
 
 /// Contains information about the time when a scheduled message will be sent
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageSchedulingState {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum MessageSchedulingState {
     /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
     #[serde(rename = "messageSchedulingStateSendWhenOnline")]
     SendWhenOnline(MessageSchedulingStateSendWhenOnline),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageSchedulingState {
@@ -28,6 +34,9 @@ impl RObject for MessageSchedulingState {
         match self {
             MessageSchedulingState::SendAtDate(t) => t.extra(),
             MessageSchedulingState::SendWhenOnline(t) => t.extra(),
+            MessageSchedulingState::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -37,12 +46,33 @@ impl RObject for MessageSchedulingState {
         match self {
             MessageSchedulingState::SendAtDate(t) => t.client_id(),
             MessageSchedulingState::SendWhenOnline(t) => t.client_id(),
+            MessageSchedulingState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageSchedulingState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "messageSchedulingStateSendAtDate" => {
+                MessageSchedulingState::SendAtDate(super::_common::from_raw(raw)?)
+            }
+            "messageSchedulingStateSendWhenOnline" => {
+                MessageSchedulingState::SendWhenOnline(super::_common::from_raw(raw)?)
+            }
+            _ => MessageSchedulingState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MessageSchedulingState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
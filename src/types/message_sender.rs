@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageSender: Debug + RObject {}
 
 /// Contains information about the sender of a message
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageSender {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum MessageSender {
     /// The message was sent by a known user
     #[serde(rename = "messageSenderUser")]
     User(MessageSenderUser),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageSender {
@@ -28,6 +34,7 @@ impl RObject for MessageSender {
         match self {
             MessageSender::Chat(t) => t.extra(),
             MessageSender::User(t) => t.extra(),
+            MessageSender::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for MessageSender {
         match self {
             MessageSender::Chat(t) => t.client_id(),
             MessageSender::User(t) => t.client_id(),
+            MessageSender::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageSender {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "messageSenderChat" => MessageSender::Chat(super::_common::from_raw(raw)?),
+            "messageSenderUser" => MessageSender::User(super::_common::from_raw(raw)?),
+            _ => MessageSender::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MessageSender {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
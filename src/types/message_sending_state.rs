@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDMessageSendingState: Debug + RObject {}
 
 /// Contains information about the sending state of the message
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum MessageSendingState {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum MessageSendingState {
     /// The message is being sent now, but has not yet been delivered to the server
     #[serde(rename = "messageSendingStatePending")]
     Pending(MessageSendingStatePending),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for MessageSendingState {
@@ -28,6 +34,7 @@ impl RObject for MessageSendingState {
         match self {
             MessageSendingState::Failed(t) => t.extra(),
             MessageSendingState::Pending(t) => t.extra(),
+            MessageSendingState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,33 @@ impl RObject for MessageSendingState {
         match self {
             MessageSendingState::Failed(t) => t.client_id(),
             MessageSendingState::Pending(t) => t.client_id(),
+            MessageSendingState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for MessageSendingState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "messageSendingStateFailed" => {
+                MessageSendingState::Failed(super::_common::from_raw(raw)?)
+            }
+            "messageSendingStatePending" => {
+                MessageSendingState::Pending(super::_common::from_raw(raw)?)
+            }
+            _ => MessageSendingState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl MessageSendingState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -1,6 +1,6 @@
 //! Module provides all TDlib types.
 //! For types details you can see [TDlib API Scheme](https://github.com/tdlib/td/blob/master/td/generate/scheme/td_api.tl)
-pub(crate) use self::_common::{RFunction, RObject};
+pub use self::_common::{ClientId, RFunction, RObject};
 
 #[macro_use]
 mod _common;
@@ -696,6 +696,8 @@ pub use self::synchronize_language_pack::*;
 pub use self::t_me_url::*;
 pub use self::t_me_url_type::*;
 pub use self::t_me_urls::*;
+pub use self::td_function::*;
+pub use self::td_object::*;
 pub use self::tdlib_parameters::*;
 pub use self::temporary_password_state::*;
 pub use self::terminate_all_other_sessions::*;
@@ -1468,6 +1470,8 @@ mod synchronize_language_pack;
 mod t_me_url;
 mod t_me_url_type;
 mod t_me_urls;
+mod td_function;
+mod td_object;
 mod tdlib_parameters;
 mod temporary_password_state;
 mod terminate_all_other_sessions;
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDNetworkStatisticsEntry: Debug + RObject {}
 
 /// Contains statistics about network usage
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum NetworkStatisticsEntry {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum NetworkStatisticsEntry {
     /// Contains information about the total amount of data that was used to send and receive files
     #[serde(rename = "networkStatisticsEntryFile")]
     File(NetworkStatisticsEntryFile),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for NetworkStatisticsEntry {
@@ -28,6 +34,9 @@ impl RObject for NetworkStatisticsEntry {
         match self {
             NetworkStatisticsEntry::Call(t) => t.extra(),
             NetworkStatisticsEntry::File(t) => t.extra(),
+            NetworkStatisticsEntry::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -37,12 +46,33 @@ impl RObject for NetworkStatisticsEntry {
         match self {
             NetworkStatisticsEntry::Call(t) => t.client_id(),
             NetworkStatisticsEntry::File(t) => t.client_id(),
+            NetworkStatisticsEntry::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for NetworkStatisticsEntry {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "networkStatisticsEntryCall" => {
+                NetworkStatisticsEntry::Call(super::_common::from_raw(raw)?)
+            }
+            "networkStatisticsEntryFile" => {
+                NetworkStatisticsEntry::File(super::_common::from_raw(raw)?)
+            }
+            _ => NetworkStatisticsEntry::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl NetworkStatisticsEntry {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
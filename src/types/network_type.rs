@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDNetworkType: Debug + RObject {}
 
 /// Represents the type of a network
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum NetworkType {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum NetworkType {
     /// A Wi-Fi network
     #[serde(rename = "networkTypeWiFi")]
     WiFi(NetworkTypeWiFi),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for NetworkType {
@@ -40,6 +46,7 @@ impl RObject for NetworkType {
             NetworkType::None(t) => t.extra(),
             NetworkType::Other(t) => t.extra(),
             NetworkType::WiFi(t) => t.extra(),
+            NetworkType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -52,12 +59,34 @@ impl RObject for NetworkType {
             NetworkType::None(t) => t.client_id(),
             NetworkType::Other(t) => t.client_id(),
             NetworkType::WiFi(t) => t.client_id(),
+            NetworkType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for NetworkType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "networkTypeMobile" => NetworkType::Mobile(super::_common::from_raw(raw)?),
+            "networkTypeMobileRoaming" => {
+                NetworkType::MobileRoaming(super::_common::from_raw(raw)?)
+            }
+            "networkTypeNone" => NetworkType::None(super::_common::from_raw(raw)?),
+            "networkTypeOther" => NetworkType::Other(super::_common::from_raw(raw)?),
+            "networkTypeWiFi" => NetworkType::WiFi(super::_common::from_raw(raw)?),
+            _ => NetworkType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl NetworkType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
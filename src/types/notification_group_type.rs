@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDNotificationGroupType: Debug + RObject {}
 
 /// Describes the type of notifications in a notification group
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum NotificationGroupType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum NotificationGroupType {
     /// A group containing a notification of type notificationTypeNewSecretChat
     #[serde(rename = "notificationGroupTypeSecretChat")]
     SecretChat(NotificationGroupTypeSecretChat),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for NotificationGroupType {
@@ -36,6 +42,9 @@ impl RObject for NotificationGroupType {
             NotificationGroupType::Mentions(t) => t.extra(),
             NotificationGroupType::Messages(t) => t.extra(),
             NotificationGroupType::SecretChat(t) => t.extra(),
+            NotificationGroupType::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -47,12 +56,39 @@ impl RObject for NotificationGroupType {
             NotificationGroupType::Mentions(t) => t.client_id(),
             NotificationGroupType::Messages(t) => t.client_id(),
             NotificationGroupType::SecretChat(t) => t.client_id(),
+            NotificationGroupType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for NotificationGroupType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "notificationGroupTypeCalls" => {
+                NotificationGroupType::Calls(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeMentions" => {
+                NotificationGroupType::Mentions(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeMessages" => {
+                NotificationGroupType::Messages(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeSecretChat" => {
+                NotificationGroupType::SecretChat(super::_common::from_raw(raw)?)
+            }
+            _ => NotificationGroupType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl NotificationGroupType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDNotificationSettingsScope: Debug + RObject {}
 
 /// Describes the types of chats to which notification settings are relevant
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum NotificationSettingsScope {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum NotificationSettingsScope {
     /// Notification settings applied to all private and secret chats when the corresponding chat setting has a default value
     #[serde(rename = "notificationSettingsScopePrivateChats")]
     PrivateChats(NotificationSettingsScopePrivateChats),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for NotificationSettingsScope {
@@ -32,6 +38,9 @@ impl RObject for NotificationSettingsScope {
             NotificationSettingsScope::ChannelChats(t) => t.extra(),
             NotificationSettingsScope::GroupChats(t) => t.extra(),
             NotificationSettingsScope::PrivateChats(t) => t.extra(),
+            NotificationSettingsScope::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -42,12 +51,36 @@ impl RObject for NotificationSettingsScope {
             NotificationSettingsScope::ChannelChats(t) => t.client_id(),
             NotificationSettingsScope::GroupChats(t) => t.client_id(),
             NotificationSettingsScope::PrivateChats(t) => t.client_id(),
+            NotificationSettingsScope::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for NotificationSettingsScope {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "notificationSettingsScopeChannelChats" => {
+                NotificationSettingsScope::ChannelChats(super::_common::from_raw(raw)?)
+            }
+            "notificationSettingsScopeGroupChats" => {
+                NotificationSettingsScope::GroupChats(super::_common::from_raw(raw)?)
+            }
+            "notificationSettingsScopePrivateChats" => {
+                NotificationSettingsScope::PrivateChats(super::_common::from_raw(raw)?)
+            }
+            _ => NotificationSettingsScope::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl NotificationSettingsScope {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
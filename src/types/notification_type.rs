@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDNotificationType: Debug + RObject {}
 
 /// Contains detailed information about a notification
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum NotificationType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum NotificationType {
     /// New secret chat was created
     #[serde(rename = "notificationTypeNewSecretChat")]
     NewSecretChat(NotificationTypeNewSecretChat),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for NotificationType {
@@ -36,6 +42,7 @@ impl RObject for NotificationType {
             NotificationType::NewMessage(t) => t.extra(),
             NotificationType::NewPushMessage(t) => t.extra(),
             NotificationType::NewSecretChat(t) => t.extra(),
+            NotificationType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,37 @@ impl RObject for NotificationType {
             NotificationType::NewMessage(t) => t.client_id(),
             NotificationType::NewPushMessage(t) => t.client_id(),
             NotificationType::NewSecretChat(t) => t.client_id(),
+            NotificationType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for NotificationType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "notificationTypeNewCall" => NotificationType::NewCall(super::_common::from_raw(raw)?),
+            "notificationTypeNewMessage" => {
+                NotificationType::NewMessage(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewPushMessage" => {
+                NotificationType::NewPushMessage(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewSecretChat" => {
+                NotificationType::NewSecretChat(super::_common::from_raw(raw)?)
+            }
+            _ => NotificationType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl NotificationType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
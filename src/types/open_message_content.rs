@@ -19,7 +19,7 @@ pub struct OpenMessageContent {
     #[serde(default)]
     message_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for OpenMessageContent {
     }
 }
 
-impl RFunction for OpenMessageContent {}
+impl RFunction for OpenMessageContent {
+    type Response = Ok;
+}
 
 impl OpenMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -47,7 +47,7 @@ pub struct OptimizeStorage {
     #[serde(default)]
     chat_limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -62,7 +62,9 @@ impl RObject for OptimizeStorage {
     }
 }
 
-impl RFunction for OptimizeStorage {}
+impl RFunction for OptimizeStorage {
+    type Response = StorageStatistics;
+}
 
 impl OptimizeStorage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDOptionValue: Debug + RObject {}
 
 /// Represents the value of an option
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum OptionValue {
     #[doc(hidden)]
@@ -29,6 +29,12 @@ pub enum OptionValue {
     /// Represents a string option
     #[serde(rename = "optionValueString")]
     String(OptionValueString),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for OptionValue {
@@ -40,6 +46,7 @@ impl RObject for OptionValue {
             OptionValue::Empty(t) => t.extra(),
             OptionValue::Integer(t) => t.extra(),
             OptionValue::String(t) => t.extra(),
+            OptionValue::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -52,12 +59,32 @@ impl RObject for OptionValue {
             OptionValue::Empty(t) => t.client_id(),
             OptionValue::Integer(t) => t.client_id(),
             OptionValue::String(t) => t.client_id(),
+            OptionValue::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for OptionValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getOption" => OptionValue::GetOption(super::_common::from_raw(raw)?),
+            "optionValueBoolean" => OptionValue::Boolean(super::_common::from_raw(raw)?),
+            "optionValueEmpty" => OptionValue::Empty(super::_common::from_raw(raw)?),
+            "optionValueInteger" => OptionValue::Integer(super::_common::from_raw(raw)?),
+            "optionValueString" => OptionValue::String(super::_common::from_raw(raw)?),
+            _ => OptionValue::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl OptionValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPageBlock: Debug + RObject {}
 
 /// Describes a block of an instant view web page
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PageBlock {
     #[doc(hidden)]
@@ -101,6 +101,12 @@ pub enum PageBlock {
     /// A voice note
     #[serde(rename = "pageBlockVoiceNote")]
     VoiceNote(PageBlockVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PageBlock {
@@ -136,6 +142,7 @@ impl RObject for PageBlock {
             PageBlock::Title(t) => t.extra(),
             PageBlock::Video(t) => t.extra(),
             PageBlock::VoiceNote(t) => t.extra(),
+            PageBlock::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -172,12 +179,58 @@ impl RObject for PageBlock {
             PageBlock::Title(t) => t.client_id(),
             PageBlock::Video(t) => t.client_id(),
             PageBlock::VoiceNote(t) => t.client_id(),
+            PageBlock::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PageBlock {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "pageBlockAnchor" => PageBlock::Anchor(super::_common::from_raw(raw)?),
+            "pageBlockAnimation" => PageBlock::Animation(super::_common::from_raw(raw)?),
+            "pageBlockAudio" => PageBlock::Audio(super::_common::from_raw(raw)?),
+            "pageBlockAuthorDate" => PageBlock::AuthorDate(super::_common::from_raw(raw)?),
+            "pageBlockBlockQuote" => PageBlock::BlockQuote(super::_common::from_raw(raw)?),
+            "pageBlockChatLink" => PageBlock::ChatLink(super::_common::from_raw(raw)?),
+            "pageBlockCollage" => PageBlock::Collage(super::_common::from_raw(raw)?),
+            "pageBlockCover" => PageBlock::Cover(super::_common::from_raw(raw)?),
+            "pageBlockDetails" => PageBlock::Details(super::_common::from_raw(raw)?),
+            "pageBlockDivider" => PageBlock::Divider(super::_common::from_raw(raw)?),
+            "pageBlockEmbedded" => PageBlock::Embedded(super::_common::from_raw(raw)?),
+            "pageBlockEmbeddedPost" => PageBlock::EmbeddedPost(super::_common::from_raw(raw)?),
+            "pageBlockFooter" => PageBlock::Footer(super::_common::from_raw(raw)?),
+            "pageBlockHeader" => PageBlock::Header(super::_common::from_raw(raw)?),
+            "pageBlockKicker" => PageBlock::Kicker(super::_common::from_raw(raw)?),
+            "pageBlockList" => PageBlock::List(super::_common::from_raw(raw)?),
+            "pageBlockMap" => PageBlock::Map(super::_common::from_raw(raw)?),
+            "pageBlockParagraph" => PageBlock::Paragraph(super::_common::from_raw(raw)?),
+            "pageBlockPhoto" => PageBlock::Photo(super::_common::from_raw(raw)?),
+            "pageBlockPreformatted" => PageBlock::Preformatted(super::_common::from_raw(raw)?),
+            "pageBlockPullQuote" => PageBlock::PullQuote(super::_common::from_raw(raw)?),
+            "pageBlockRelatedArticles" => {
+                PageBlock::RelatedArticles(super::_common::from_raw(raw)?)
+            }
+            "pageBlockSlideshow" => PageBlock::Slideshow(super::_common::from_raw(raw)?),
+            "pageBlockSubheader" => PageBlock::Subheader(super::_common::from_raw(raw)?),
+            "pageBlockSubtitle" => PageBlock::Subtitle(super::_common::from_raw(raw)?),
+            "pageBlockTable" => PageBlock::Table(super::_common::from_raw(raw)?),
+            "pageBlockTitle" => PageBlock::Title(super::_common::from_raw(raw)?),
+            "pageBlockVideo" => PageBlock::Video(super::_common::from_raw(raw)?),
+            "pageBlockVoiceNote" => PageBlock::VoiceNote(super::_common::from_raw(raw)?),
+            _ => PageBlock::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PageBlock {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
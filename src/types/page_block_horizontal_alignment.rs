@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPageBlockHorizontalAlignment: Debug + RObject {}
 
 /// Describes a horizontal alignment of a table cell content
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PageBlockHorizontalAlignment {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum PageBlockHorizontalAlignment {
     /// The content must be right-aligned
     #[serde(rename = "pageBlockHorizontalAlignmentRight")]
     Right(PageBlockHorizontalAlignmentRight),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PageBlockHorizontalAlignment {
@@ -32,6 +38,9 @@ impl RObject for PageBlockHorizontalAlignment {
             PageBlockHorizontalAlignment::Center(t) => t.extra(),
             PageBlockHorizontalAlignment::Left(t) => t.extra(),
             PageBlockHorizontalAlignment::Right(t) => t.extra(),
+            PageBlockHorizontalAlignment::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -42,12 +51,36 @@ impl RObject for PageBlockHorizontalAlignment {
             PageBlockHorizontalAlignment::Center(t) => t.client_id(),
             PageBlockHorizontalAlignment::Left(t) => t.client_id(),
             PageBlockHorizontalAlignment::Right(t) => t.client_id(),
+            PageBlockHorizontalAlignment::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PageBlockHorizontalAlignment {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "pageBlockHorizontalAlignmentCenter" => {
+                PageBlockHorizontalAlignment::Center(super::_common::from_raw(raw)?)
+            }
+            "pageBlockHorizontalAlignmentLeft" => {
+                PageBlockHorizontalAlignment::Left(super::_common::from_raw(raw)?)
+            }
+            "pageBlockHorizontalAlignmentRight" => {
+                PageBlockHorizontalAlignment::Right(super::_common::from_raw(raw)?)
+            }
+            _ => PageBlockHorizontalAlignment::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PageBlockHorizontalAlignment {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPageBlockVerticalAlignment: Debug + RObject {}
 
 /// Describes a Vertical alignment of a table cell content
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PageBlockVerticalAlignment {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum PageBlockVerticalAlignment {
     /// The content must be top-aligned
     #[serde(rename = "pageBlockVerticalAlignmentTop")]
     Top(PageBlockVerticalAlignmentTop),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PageBlockVerticalAlignment {
@@ -32,6 +38,9 @@ impl RObject for PageBlockVerticalAlignment {
             PageBlockVerticalAlignment::Bottom(t) => t.extra(),
             PageBlockVerticalAlignment::Middle(t) => t.extra(),
             PageBlockVerticalAlignment::Top(t) => t.extra(),
+            PageBlockVerticalAlignment::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -42,12 +51,36 @@ impl RObject for PageBlockVerticalAlignment {
             PageBlockVerticalAlignment::Bottom(t) => t.client_id(),
             PageBlockVerticalAlignment::Middle(t) => t.client_id(),
             PageBlockVerticalAlignment::Top(t) => t.client_id(),
+            PageBlockVerticalAlignment::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PageBlockVerticalAlignment {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "pageBlockVerticalAlignmentBottom" => {
+                PageBlockVerticalAlignment::Bottom(super::_common::from_raw(raw)?)
+            }
+            "pageBlockVerticalAlignmentMiddle" => {
+                PageBlockVerticalAlignment::Middle(super::_common::from_raw(raw)?)
+            }
+            "pageBlockVerticalAlignmentTop" => {
+                PageBlockVerticalAlignment::Top(super::_common::from_raw(raw)?)
+            }
+            _ => PageBlockVerticalAlignment::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PageBlockVerticalAlignment {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
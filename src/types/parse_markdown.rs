@@ -13,7 +13,7 @@ pub struct ParseMarkdown {
     /// The text to parse. For example, "__italic__ ~~strikethrough~~ **bold** `code` ```pre``` __[italic__ text_url](telegram.org) __italic**bold italic__bold**"
     text: FormattedText,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for ParseMarkdown {
     }
 }
 
-impl RFunction for ParseMarkdown {}
+impl RFunction for ParseMarkdown {
+    type Response = FormattedText;
+}
 
 impl ParseMarkdown {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ParseTextEntities {
     #[serde(skip_serializing_if = "TextParseMode::_is_default")]
     parse_mode: TextParseMode,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ParseTextEntities {
     }
 }
 
-impl RFunction for ParseTextEntities {}
+impl RFunction for ParseTextEntities {
+    type Response = FormattedText;
+}
 
 impl ParseTextEntities {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
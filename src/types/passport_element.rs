@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PassportElement {
     #[doc(hidden)]
@@ -59,6 +59,12 @@ pub enum PassportElement {
     /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
     #[serde(rename = "setPassportElement")]
     SetPassportElement(SetPassportElement),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PassportElement {
@@ -80,6 +86,7 @@ impl RObject for PassportElement {
             PassportElement::TemporaryRegistration(t) => t.extra(),
             PassportElement::UtilityBill(t) => t.extra(),
             PassportElement::SetPassportElement(t) => t.extra(),
+            PassportElement::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -102,12 +109,68 @@ impl RObject for PassportElement {
             PassportElement::TemporaryRegistration(t) => t.client_id(),
             PassportElement::UtilityBill(t) => t.client_id(),
             PassportElement::SetPassportElement(t) => t.client_id(),
+            PassportElement::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PassportElement {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getPassportElement" => {
+                PassportElement::GetPassportElement(super::_common::from_raw(raw)?)
+            }
+            "passportElementAddress" => PassportElement::Address(super::_common::from_raw(raw)?),
+            "passportElementBankStatement" => {
+                PassportElement::BankStatement(super::_common::from_raw(raw)?)
+            }
+            "passportElementDriverLicense" => {
+                PassportElement::DriverLicense(super::_common::from_raw(raw)?)
+            }
+            "passportElementEmailAddress" => {
+                PassportElement::EmailAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementIdentityCard" => {
+                PassportElement::IdentityCard(super::_common::from_raw(raw)?)
+            }
+            "passportElementInternalPassport" => {
+                PassportElement::InternalPassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementPassport" => PassportElement::Passport(super::_common::from_raw(raw)?),
+            "passportElementPassportRegistration" => {
+                PassportElement::PassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementPersonalDetails" => {
+                PassportElement::PersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "passportElementPhoneNumber" => {
+                PassportElement::PhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "passportElementRentalAgreement" => {
+                PassportElement::RentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTemporaryRegistration" => {
+                PassportElement::TemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementUtilityBill" => {
+                PassportElement::UtilityBill(super::_common::from_raw(raw)?)
+            }
+            "setPassportElement" => {
+                PassportElement::SetPassportElement(super::_common::from_raw(raw)?)
+            }
+            _ => PassportElement::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
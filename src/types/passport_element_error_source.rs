@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PassportElementErrorSource {
     #[doc(hidden)]
@@ -41,6 +41,12 @@ pub enum PassportElementErrorSource {
     /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
     #[serde(rename = "passportElementErrorSourceUnspecified")]
     Unspecified(PassportElementErrorSourceUnspecified),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PassportElementErrorSource {
@@ -56,6 +62,9 @@ impl RObject for PassportElementErrorSource {
             PassportElementErrorSource::TranslationFile(t) => t.extra(),
             PassportElementErrorSource::TranslationFiles(t) => t.extra(),
             PassportElementErrorSource::Unspecified(t) => t.extra(),
+            PassportElementErrorSource::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -72,12 +81,54 @@ impl RObject for PassportElementErrorSource {
             PassportElementErrorSource::TranslationFile(t) => t.client_id(),
             PassportElementErrorSource::TranslationFiles(t) => t.client_id(),
             PassportElementErrorSource::Unspecified(t) => t.client_id(),
+            PassportElementErrorSource::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PassportElementErrorSource {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "passportElementErrorSourceDataField" => {
+                PassportElementErrorSource::DataField(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFile" => {
+                PassportElementErrorSource::File(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFiles" => {
+                PassportElementErrorSource::Files(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFrontSide" => {
+                PassportElementErrorSource::FrontSide(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceReverseSide" => {
+                PassportElementErrorSource::ReverseSide(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceSelfie" => {
+                PassportElementErrorSource::Selfie(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceTranslationFile" => {
+                PassportElementErrorSource::TranslationFile(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceTranslationFiles" => {
+                PassportElementErrorSource::TranslationFiles(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceUnspecified" => {
+                PassportElementErrorSource::Unspecified(super::_common::from_raw(raw)?)
+            }
+            _ => PassportElementErrorSource::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PassportElementErrorSource {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPassportElementType: Debug + RObject {}
 
 /// Contains the type of a Telegram Passport element
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PassportElementType {
     #[doc(hidden)]
@@ -53,6 +53,12 @@ pub enum PassportElementType {
     /// A Telegram Passport element containing the user's utility bill
     #[serde(rename = "passportElementTypeUtilityBill")]
     UtilityBill(PassportElementTypeUtilityBill),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PassportElementType {
@@ -72,6 +78,7 @@ impl RObject for PassportElementType {
             PassportElementType::RentalAgreement(t) => t.extra(),
             PassportElementType::TemporaryRegistration(t) => t.extra(),
             PassportElementType::UtilityBill(t) => t.extra(),
+            PassportElementType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -92,12 +99,66 @@ impl RObject for PassportElementType {
             PassportElementType::RentalAgreement(t) => t.client_id(),
             PassportElementType::TemporaryRegistration(t) => t.client_id(),
             PassportElementType::UtilityBill(t) => t.client_id(),
+            PassportElementType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PassportElementType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "passportElementTypeAddress" => {
+                PassportElementType::Address(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeBankStatement" => {
+                PassportElementType::BankStatement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeDriverLicense" => {
+                PassportElementType::DriverLicense(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeEmailAddress" => {
+                PassportElementType::EmailAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeIdentityCard" => {
+                PassportElementType::IdentityCard(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeInternalPassport" => {
+                PassportElementType::InternalPassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePassport" => {
+                PassportElementType::Passport(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePassportRegistration" => {
+                PassportElementType::PassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePersonalDetails" => {
+                PassportElementType::PersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePhoneNumber" => {
+                PassportElementType::PhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeRentalAgreement" => {
+                PassportElementType::RentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeTemporaryRegistration" => {
+                PassportElementType::TemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeUtilityBill" => {
+                PassportElementType::UtilityBill(super::_common::from_raw(raw)?)
+            }
+            _ => PassportElementType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PassportElementType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
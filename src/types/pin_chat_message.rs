@@ -27,7 +27,7 @@ pub struct PinChatMessage {
     #[serde(default)]
     only_for_self: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for PinChatMessage {
     }
 }
 
-impl RFunction for PinChatMessage {}
+impl RFunction for PinChatMessage {
+    type Response = Ok;
+}
 
 impl PinChatMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
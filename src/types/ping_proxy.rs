@@ -15,7 +15,7 @@ pub struct PingProxy {
     #[serde(default)]
     proxy_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for PingProxy {
     }
 }
 
-impl RFunction for PingProxy {}
+impl RFunction for PingProxy {
+    type Response = Seconds;
+}
 
 impl PingProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
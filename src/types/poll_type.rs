@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPollType: Debug + RObject {}
 
 /// Describes the type of a poll
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PollType {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum PollType {
     /// A regular poll
     #[serde(rename = "pollTypeRegular")]
     Regular(PollTypeRegular),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PollType {
@@ -28,6 +34,7 @@ impl RObject for PollType {
         match self {
             PollType::Quiz(t) => t.extra(),
             PollType::Regular(t) => t.extra(),
+            PollType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for PollType {
         match self {
             PollType::Quiz(t) => t.client_id(),
             PollType::Regular(t) => t.client_id(),
+            PollType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PollType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "pollTypeQuiz" => PollType::Quiz(super::_common::from_raw(raw)?),
+            "pollTypeRegular" => PollType::Regular(super::_common::from_raw(raw)?),
+            _ => PollType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PollType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -23,7 +23,7 @@ pub struct ProcessChatJoinRequest {
     #[serde(default)]
     approve: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ProcessChatJoinRequest {
     }
 }
 
-impl RFunction for ProcessChatJoinRequest {}
+impl RFunction for ProcessChatJoinRequest {
+    type Response = Ok;
+}
 
 impl ProcessChatJoinRequest {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct ProcessChatJoinRequests {
     #[serde(default)]
     approve: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ProcessChatJoinRequests {
     }
 }
 
-impl RFunction for ProcessChatJoinRequests {}
+impl RFunction for ProcessChatJoinRequests {
+    type Response = Ok;
+}
 
 impl ProcessChatJoinRequests {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct ProcessPushNotification {
     #[serde(default)]
     payload: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ProcessPushNotification {
     }
 }
 
-impl RFunction for ProcessPushNotification {}
+impl RFunction for ProcessPushNotification {
+    type Response = Ok;
+}
 
 impl ProcessPushNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
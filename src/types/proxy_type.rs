@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDProxyType: Debug + RObject {}
 
 /// Describes the type of a proxy server
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ProxyType {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum ProxyType {
     /// A SOCKS5 proxy server
     #[serde(rename = "proxyTypeSocks5")]
     Socks5(ProxyTypeSocks5),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ProxyType {
@@ -32,6 +38,7 @@ impl RObject for ProxyType {
             ProxyType::Http(t) => t.extra(),
             ProxyType::Mtproto(t) => t.extra(),
             ProxyType::Socks5(t) => t.extra(),
+            ProxyType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,30 @@ impl RObject for ProxyType {
             ProxyType::Http(t) => t.client_id(),
             ProxyType::Mtproto(t) => t.client_id(),
             ProxyType::Socks5(t) => t.client_id(),
+            ProxyType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ProxyType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "proxyTypeHttp" => ProxyType::Http(super::_common::from_raw(raw)?),
+            "proxyTypeMtproto" => ProxyType::Mtproto(super::_common::from_raw(raw)?),
+            "proxyTypeSocks5" => ProxyType::Socks5(super::_common::from_raw(raw)?),
+            _ => ProxyType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ProxyType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
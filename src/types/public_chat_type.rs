@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPublicChatType: Debug + RObject {}
 
 /// Describes a type of public chats
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PublicChatType {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum PublicChatType {
     /// The chat is public, because it is a location-based supergroup
     #[serde(rename = "publicChatTypeIsLocationBased")]
     IsLocationBased(PublicChatTypeIsLocationBased),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PublicChatType {
@@ -28,6 +34,7 @@ impl RObject for PublicChatType {
         match self {
             PublicChatType::HasUsername(t) => t.extra(),
             PublicChatType::IsLocationBased(t) => t.extra(),
+            PublicChatType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,33 @@ impl RObject for PublicChatType {
         match self {
             PublicChatType::HasUsername(t) => t.client_id(),
             PublicChatType::IsLocationBased(t) => t.client_id(),
+            PublicChatType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PublicChatType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "publicChatTypeHasUsername" => {
+                PublicChatType::HasUsername(super::_common::from_raw(raw)?)
+            }
+            "publicChatTypeIsLocationBased" => {
+                PublicChatType::IsLocationBased(super::_common::from_raw(raw)?)
+            }
+            _ => PublicChatType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PublicChatType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDPushMessageContent: Debug + RObject {}
 
 /// Contains content of a push message notification
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum PushMessageContent {
     #[doc(hidden)]
@@ -98,6 +98,12 @@ pub enum PushMessageContent {
     /// A voice note message
     #[serde(rename = "pushMessageContentVoiceNote")]
     VoiceNote(PushMessageContentVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for PushMessageContent {
@@ -132,6 +138,7 @@ impl RObject for PushMessageContent {
             PushMessageContent::Video(t) => t.extra(),
             PushMessageContent::VideoNote(t) => t.extra(),
             PushMessageContent::VoiceNote(t) => t.extra(),
+            PushMessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -167,12 +174,99 @@ impl RObject for PushMessageContent {
             PushMessageContent::Video(t) => t.client_id(),
             PushMessageContent::VideoNote(t) => t.client_id(),
             PushMessageContent::VoiceNote(t) => t.client_id(),
+            PushMessageContent::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for PushMessageContent {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "pushMessageContentAnimation" => {
+                PushMessageContent::Animation(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentAudio" => PushMessageContent::Audio(super::_common::from_raw(raw)?),
+            "pushMessageContentBasicGroupChatCreate" => {
+                PushMessageContent::BasicGroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatAddMembers" => {
+                PushMessageContent::ChatAddMembers(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatChangePhoto" => {
+                PushMessageContent::ChatChangePhoto(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatChangeTitle" => {
+                PushMessageContent::ChatChangeTitle(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatDeleteMember" => {
+                PushMessageContent::ChatDeleteMember(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatJoinByLink" => {
+                PushMessageContent::ChatJoinByLink(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatJoinByRequest" => {
+                PushMessageContent::ChatJoinByRequest(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatSetTheme" => {
+                PushMessageContent::ChatSetTheme(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentContact" => {
+                PushMessageContent::Contact(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentContactRegistered" => {
+                PushMessageContent::ContactRegistered(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentDocument" => {
+                PushMessageContent::Document(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentGame" => PushMessageContent::Game(super::_common::from_raw(raw)?),
+            "pushMessageContentGameScore" => {
+                PushMessageContent::GameScore(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentHidden" => {
+                PushMessageContent::Hidden(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentInvoice" => {
+                PushMessageContent::Invoice(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentLocation" => {
+                PushMessageContent::Location(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentMediaAlbum" => {
+                PushMessageContent::MediaAlbum(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentMessageForwards" => {
+                PushMessageContent::MessageForwards(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentPhoto" => PushMessageContent::Photo(super::_common::from_raw(raw)?),
+            "pushMessageContentPoll" => PushMessageContent::Poll(super::_common::from_raw(raw)?),
+            "pushMessageContentScreenshotTaken" => {
+                PushMessageContent::ScreenshotTaken(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentSticker" => {
+                PushMessageContent::Sticker(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentText" => PushMessageContent::Text(super::_common::from_raw(raw)?),
+            "pushMessageContentVideo" => PushMessageContent::Video(super::_common::from_raw(raw)?),
+            "pushMessageContentVideoNote" => {
+                PushMessageContent::VideoNote(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentVoiceNote" => {
+                PushMessageContent::VoiceNote(super::_common::from_raw(raw)?)
+            }
+            _ => PushMessageContent::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl PushMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
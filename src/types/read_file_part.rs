@@ -23,7 +23,7 @@ pub struct ReadFilePart {
     #[serde(default)]
     count: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ReadFilePart {
     }
 }
 
-impl RFunction for ReadFilePart {}
+impl RFunction for ReadFilePart {
+    type Response = FilePart;
+}
 
 impl ReadFilePart {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
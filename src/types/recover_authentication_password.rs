@@ -23,7 +23,7 @@ pub struct RecoverAuthenticationPassword {
     #[serde(default)]
     new_hint: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for RecoverAuthenticationPassword {
     }
 }
 
-impl RFunction for RecoverAuthenticationPassword {}
+impl RFunction for RecoverAuthenticationPassword {
+    type Response = Ok;
+}
 
 impl RecoverAuthenticationPassword {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
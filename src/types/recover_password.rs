@@ -23,7 +23,7 @@ pub struct RecoverPassword {
     #[serde(default)]
     new_hint: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for RecoverPassword {
     }
 }
 
-impl RFunction for RecoverPassword {}
+impl RFunction for RecoverPassword {
+    type Response = PasswordState;
+}
 
 impl RecoverPassword {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct RegisterDevice {
     #[serde(default)]
     other_user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RegisterDevice {
     }
 }
 
-impl RFunction for RegisterDevice {}
+impl RFunction for RegisterDevice {
+    type Response = PushReceiverId;
+}
 
 impl RegisterDevice {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
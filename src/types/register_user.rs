@@ -19,7 +19,7 @@ pub struct RegisterUser {
     #[serde(default)]
     last_name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RegisterUser {
     }
 }
 
-impl RFunction for RegisterUser {}
+impl RFunction for RegisterUser {
+    type Response = Ok;
+}
 
 impl RegisterUser {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
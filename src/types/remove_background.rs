@@ -19,7 +19,7 @@ pub struct RemoveBackground {
     #[serde(default)]
     background_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RemoveBackground {
     }
 }
 
-impl RFunction for RemoveBackground {}
+impl RFunction for RemoveBackground {
+    type Response = Ok;
+}
 
 impl RemoveBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
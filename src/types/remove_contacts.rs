@@ -15,7 +15,7 @@ pub struct RemoveContacts {
     #[serde(default)]
     user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RemoveContacts {
     }
 }
 
-impl RFunction for RemoveContacts {}
+impl RFunction for RemoveContacts {
+    type Response = Ok;
+}
 
 impl RemoveContacts {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
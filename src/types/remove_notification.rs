@@ -19,7 +19,7 @@ pub struct RemoveNotification {
     #[serde(default)]
     notification_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RemoveNotification {
     }
 }
 
-impl RFunction for RemoveNotification {}
+impl RFunction for RemoveNotification {
+    type Response = Ok;
+}
 
 impl RemoveNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
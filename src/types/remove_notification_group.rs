@@ -19,7 +19,7 @@ pub struct RemoveNotificationGroup {
     #[serde(default)]
     max_notification_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RemoveNotificationGroup {
     }
 }
 
-impl RFunction for RemoveNotificationGroup {}
+impl RFunction for RemoveNotificationGroup {
+    type Response = Ok;
+}
 
 impl RemoveNotificationGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
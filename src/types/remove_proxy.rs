@@ -15,7 +15,7 @@ pub struct RemoveProxy {
     #[serde(default)]
     proxy_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RemoveProxy {
     }
 }
 
-impl RFunction for RemoveProxy {}
+impl RFunction for RemoveProxy {
+    type Response = Ok;
+}
 
 impl RemoveProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
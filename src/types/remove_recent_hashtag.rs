@@ -15,7 +15,7 @@ pub struct RemoveRecentHashtag {
     #[serde(default)]
     hashtag: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RemoveRecentHashtag {
     }
 }
 
-impl RFunction for RemoveRecentHashtag {}
+impl RFunction for RemoveRecentHashtag {
+    type Response = Ok;
+}
 
 impl RemoveRecentHashtag {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct RemoveRecentSticker {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     sticker: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RemoveRecentSticker {
     }
 }
 
-impl RFunction for RemoveRecentSticker {}
+impl RFunction for RemoveRecentSticker {
+    type Response = Ok;
+}
 
 impl RemoveRecentSticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct RemoveSavedAnimation {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     animation: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RemoveSavedAnimation {
     }
 }
 
-impl RFunction for RemoveSavedAnimation {}
+impl RFunction for RemoveSavedAnimation {
+    type Response = Ok;
+}
 
 impl RemoveSavedAnimation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
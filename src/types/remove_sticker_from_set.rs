@@ -15,7 +15,7 @@ pub struct RemoveStickerFromSet {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     sticker: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RemoveStickerFromSet {
     }
 }
 
-impl RFunction for RemoveStickerFromSet {}
+impl RFunction for RemoveStickerFromSet {
+    type Response = Ok;
+}
 
 impl RemoveStickerFromSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
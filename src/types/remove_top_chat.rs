@@ -19,7 +19,7 @@ pub struct RemoveTopChat {
     #[serde(default)]
     chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RemoveTopChat {
     }
 }
 
-impl RFunction for RemoveTopChat {}
+impl RFunction for RemoveTopChat {
+    type Response = Ok;
+}
 
 impl RemoveTopChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
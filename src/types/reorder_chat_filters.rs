@@ -15,7 +15,7 @@ pub struct ReorderChatFilters {
     #[serde(default)]
     chat_filter_ids: Vec<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ReorderChatFilters {
     }
 }
 
-impl RFunction for ReorderChatFilters {}
+impl RFunction for ReorderChatFilters {
+    type Response = Ok;
+}
 
 impl ReorderChatFilters {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
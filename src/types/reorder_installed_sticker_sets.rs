@@ -20,7 +20,7 @@ pub struct ReorderInstalledStickerSets {
     #[serde(default)]
     sticker_set_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -35,7 +35,9 @@ impl RObject for ReorderInstalledStickerSets {
     }
 }
 
-impl RFunction for ReorderInstalledStickerSets {}
+impl RFunction for ReorderInstalledStickerSets {
+    type Response = Ok;
+}
 
 impl ReorderInstalledStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
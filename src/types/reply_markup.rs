@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDReplyMarkup: Debug + RObject {}
 
 /// Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ReplyMarkup {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum ReplyMarkup {
     /// Contains a custom keyboard layout to quickly reply to bots
     #[serde(rename = "replyMarkupShowKeyboard")]
     ShowKeyboard(ReplyMarkupShowKeyboard),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ReplyMarkup {
@@ -36,6 +42,7 @@ impl RObject for ReplyMarkup {
             ReplyMarkup::InlineKeyboard(t) => t.extra(),
             ReplyMarkup::RemoveKeyboard(t) => t.extra(),
             ReplyMarkup::ShowKeyboard(t) => t.extra(),
+            ReplyMarkup::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,35 @@ impl RObject for ReplyMarkup {
             ReplyMarkup::InlineKeyboard(t) => t.client_id(),
             ReplyMarkup::RemoveKeyboard(t) => t.client_id(),
             ReplyMarkup::ShowKeyboard(t) => t.client_id(),
+            ReplyMarkup::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ReplyMarkup {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "replyMarkupForceReply" => ReplyMarkup::ForceReply(super::_common::from_raw(raw)?),
+            "replyMarkupInlineKeyboard" => {
+                ReplyMarkup::InlineKeyboard(super::_common::from_raw(raw)?)
+            }
+            "replyMarkupRemoveKeyboard" => {
+                ReplyMarkup::RemoveKeyboard(super::_common::from_raw(raw)?)
+            }
+            "replyMarkupShowKeyboard" => ReplyMarkup::ShowKeyboard(super::_common::from_raw(raw)?),
+            _ => ReplyMarkup::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
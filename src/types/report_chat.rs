@@ -27,7 +27,7 @@ pub struct ReportChat {
     #[serde(default)]
     text: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for ReportChat {
     }
 }
 
-impl RFunction for ReportChat {}
+impl RFunction for ReportChat {
+    type Response = Ok;
+}
 
 impl ReportChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct ReportChatPhoto {
     #[serde(default)]
     text: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for ReportChatPhoto {
     }
 }
 
-impl RFunction for ReportChatPhoto {}
+impl RFunction for ReportChatPhoto {
+    type Response = Ok;
+}
 
 impl ReportChatPhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
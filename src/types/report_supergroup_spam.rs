@@ -19,7 +19,7 @@ pub struct ReportSupergroupSpam {
     #[serde(default)]
     message_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ReportSupergroupSpam {
     }
 }
 
-impl RFunction for ReportSupergroupSpam {}
+impl RFunction for ReportSupergroupSpam {
+    type Response = Ok;
+}
 
 impl ReportSupergroupSpam {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
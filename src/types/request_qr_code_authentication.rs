@@ -15,7 +15,7 @@ pub struct RequestQrCodeAuthentication {
     #[serde(default)]
     other_user_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for RequestQrCodeAuthentication {
     }
 }
 
-impl RFunction for RequestQrCodeAuthentication {}
+impl RFunction for RequestQrCodeAuthentication {
+    type Response = Ok;
+}
 
 impl RequestQrCodeAuthentication {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ResendMessages {
     #[serde(default)]
     message_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ResendMessages {
     }
 }
 
-impl RFunction for ResendMessages {}
+impl RFunction for ResendMessages {
+    type Response = Messages;
+}
 
 impl ResendMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
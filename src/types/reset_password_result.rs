@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDResetPasswordResult: Debug + RObject {}
 
 /// Represents result of 2-step verification password reset
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ResetPasswordResult {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum ResetPasswordResult {
     /// The password reset request is pending
     #[serde(rename = "resetPasswordResultPending")]
     Pending(ResetPasswordResultPending),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ResetPasswordResult {
@@ -36,6 +42,7 @@ impl RObject for ResetPasswordResult {
             ResetPasswordResult::Declined(t) => t.extra(),
             ResetPasswordResult::Ok(t) => t.extra(),
             ResetPasswordResult::Pending(t) => t.extra(),
+            ResetPasswordResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,35 @@ impl RObject for ResetPasswordResult {
             ResetPasswordResult::Declined(t) => t.client_id(),
             ResetPasswordResult::Ok(t) => t.client_id(),
             ResetPasswordResult::Pending(t) => t.client_id(),
+            ResetPasswordResult::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ResetPasswordResult {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "resetPassword" => ResetPasswordResult::ResetPassword(super::_common::from_raw(raw)?),
+            "resetPasswordResultDeclined" => {
+                ResetPasswordResult::Declined(super::_common::from_raw(raw)?)
+            }
+            "resetPasswordResultOk" => ResetPasswordResult::Ok(super::_common::from_raw(raw)?),
+            "resetPasswordResultPending" => {
+                ResetPasswordResult::Pending(super::_common::from_raw(raw)?)
+            }
+            _ => ResetPasswordResult::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ResetPasswordResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -19,7 +19,7 @@ pub struct RevokeChatInviteLink {
     #[serde(default)]
     invite_link: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for RevokeChatInviteLink {
     }
 }
 
-impl RFunction for RevokeChatInviteLink {}
+impl RFunction for RevokeChatInviteLink {
+    type Response = ChatInviteLinks;
+}
 
 impl RevokeChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
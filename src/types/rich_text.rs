@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDRichText: Debug + RObject {}
 
 /// Describes a text object inside an instant-view web page
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum RichText {
     #[doc(hidden)]
@@ -65,6 +65,12 @@ pub enum RichText {
     /// A concatenation of rich texts
     #[serde(rename = "richTexts")]
     RichTexts(RichTexts),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for RichText {
@@ -88,6 +94,7 @@ impl RObject for RichText {
             RichText::Underline(t) => t.extra(),
             RichText::Url(t) => t.extra(),
             RichText::RichTexts(t) => t.extra(),
+            RichText::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -112,12 +119,44 @@ impl RObject for RichText {
             RichText::Underline(t) => t.client_id(),
             RichText::Url(t) => t.client_id(),
             RichText::RichTexts(t) => t.client_id(),
+            RichText::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for RichText {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "richTextAnchor" => RichText::Anchor(super::_common::from_raw(raw)?),
+            "richTextAnchorLink" => RichText::AnchorLink(super::_common::from_raw(raw)?),
+            "richTextBold" => RichText::Bold(super::_common::from_raw(raw)?),
+            "richTextEmailAddress" => RichText::EmailAddress(super::_common::from_raw(raw)?),
+            "richTextFixed" => RichText::Fixed(super::_common::from_raw(raw)?),
+            "richTextIcon" => RichText::Icon(super::_common::from_raw(raw)?),
+            "richTextItalic" => RichText::Italic(super::_common::from_raw(raw)?),
+            "richTextMarked" => RichText::Marked(super::_common::from_raw(raw)?),
+            "richTextPhoneNumber" => RichText::PhoneNumber(super::_common::from_raw(raw)?),
+            "richTextPlain" => RichText::Plain(super::_common::from_raw(raw)?),
+            "richTextReference" => RichText::Reference(super::_common::from_raw(raw)?),
+            "richTextStrikethrough" => RichText::Strikethrough(super::_common::from_raw(raw)?),
+            "richTextSubscript" => RichText::Subscript(super::_common::from_raw(raw)?),
+            "richTextSuperscript" => RichText::Superscript(super::_common::from_raw(raw)?),
+            "richTextUnderline" => RichText::Underline(super::_common::from_raw(raw)?),
+            "richTextUrl" => RichText::Url(super::_common::from_raw(raw)?),
+            "richTexts" => RichText::RichTexts(super::_common::from_raw(raw)?),
+            _ => RichText::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl RichText {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
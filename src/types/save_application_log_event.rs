@@ -24,7 +24,7 @@ pub struct SaveApplicationLogEvent {
     #[serde(skip_serializing_if = "JsonValue::_is_default")]
     data: JsonValue,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -39,7 +39,9 @@ impl RObject for SaveApplicationLogEvent {
     }
 }
 
-impl RFunction for SaveApplicationLogEvent {}
+impl RFunction for SaveApplicationLogEvent {
+    type Response = Ok;
+}
 
 impl SaveApplicationLogEvent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SearchBackground {
     #[serde(default)]
     name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SearchBackground {
     }
 }
 
-impl RFunction for SearchBackground {}
+impl RFunction for SearchBackground {
+    type Response = Background;
+}
 
 impl SearchBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
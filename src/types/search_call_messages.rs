@@ -23,7 +23,7 @@ pub struct SearchCallMessages {
     #[serde(default)]
     only_missed: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SearchCallMessages {
     }
 }
 
-impl RFunction for SearchCallMessages {}
+impl RFunction for SearchCallMessages {
+    type Response = Messages;
+}
 
 impl SearchCallMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
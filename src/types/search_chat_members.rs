@@ -27,7 +27,7 @@ pub struct SearchChatMembers {
     #[serde(skip_serializing_if = "ChatMembersFilter::_is_default")]
     filter: ChatMembersFilter,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for SearchChatMembers {
     }
 }
 
-impl RFunction for SearchChatMembers {}
+impl RFunction for SearchChatMembers {
+    type Response = ChatMembers;
+}
 
 impl SearchChatMembers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -43,7 +43,7 @@ pub struct SearchChatMessages {
     #[serde(default)]
     message_thread_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -58,7 +58,9 @@ impl RObject for SearchChatMessages {
     }
 }
 
-impl RFunction for SearchChatMessages {}
+impl RFunction for SearchChatMessages {
+    type Response = Messages;
+}
 
 impl SearchChatMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
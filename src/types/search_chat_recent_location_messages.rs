@@ -19,7 +19,7 @@ pub struct SearchChatRecentLocationMessages {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchChatRecentLocationMessages {
     }
 }
 
-impl RFunction for SearchChatRecentLocationMessages {}
+impl RFunction for SearchChatRecentLocationMessages {
+    type Response = Messages;
+}
 
 impl SearchChatRecentLocationMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
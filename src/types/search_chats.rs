@@ -19,7 +19,7 @@ pub struct SearchChats {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchChats {
     }
 }
 
-impl RFunction for SearchChats {}
+impl RFunction for SearchChats {
+    type Response = Chats;
+}
 
 impl SearchChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
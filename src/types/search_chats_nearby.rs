@@ -13,7 +13,7 @@ pub struct SearchChatsNearby {
     /// Current user location
     location: Location,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for SearchChatsNearby {
     }
 }
 
-impl RFunction for SearchChatsNearby {}
+impl RFunction for SearchChatsNearby {
+    type Response = ChatsNearby;
+}
 
 impl SearchChatsNearby {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SearchChatsOnServer {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchChatsOnServer {
     }
 }
 
-impl RFunction for SearchChatsOnServer {}
+impl RFunction for SearchChatsOnServer {
+    type Response = Chats;
+}
 
 impl SearchChatsOnServer {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
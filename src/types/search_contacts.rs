@@ -19,7 +19,7 @@ pub struct SearchContacts {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchContacts {
     }
 }
 
-impl RFunction for SearchContacts {}
+impl RFunction for SearchContacts {
+    type Response = Users;
+}
 
 impl SearchContacts {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SearchEmojis {
     #[serde(default)]
     input_language_codes: Vec<String>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SearchEmojis {
     }
 }
 
-impl RFunction for SearchEmojis {}
+impl RFunction for SearchEmojis {
+    type Response = Emojis;
+}
 
 impl SearchEmojis {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SearchHashtags {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchHashtags {
     }
 }
 
-impl RFunction for SearchHashtags {}
+impl RFunction for SearchHashtags {
+    type Response = Hashtags;
+}
 
 impl SearchHashtags {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SearchInstalledStickerSets {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SearchInstalledStickerSets {
     }
 }
 
-impl RFunction for SearchInstalledStickerSets {}
+impl RFunction for SearchInstalledStickerSets {
+    type Response = StickerSets;
+}
 
 impl SearchInstalledStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
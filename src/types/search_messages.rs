@@ -47,7 +47,7 @@ pub struct SearchMessages {
     #[serde(default)]
     max_date: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -62,7 +62,9 @@ impl RObject for SearchMessages {
     }
 }
 
-impl RFunction for SearchMessages {}
+impl RFunction for SearchMessages {
+    type Response = Messages;
+}
 
 impl SearchMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDSearchMessagesFilter: Debug + RObject {}
 
 /// Represents a filter for message search results
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum SearchMessagesFilter {
     #[doc(hidden)]
@@ -62,6 +62,12 @@ pub enum SearchMessagesFilter {
     /// Returns only voice note messages
     #[serde(rename = "searchMessagesFilterVoiceNote")]
     VoiceNote(SearchMessagesFilterVoiceNote),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for SearchMessagesFilter {
@@ -84,6 +90,7 @@ impl RObject for SearchMessagesFilter {
             SearchMessagesFilter::VideoNote(t) => t.extra(),
             SearchMessagesFilter::VoiceAndVideoNote(t) => t.extra(),
             SearchMessagesFilter::VoiceNote(t) => t.extra(),
+            SearchMessagesFilter::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -107,12 +114,73 @@ impl RObject for SearchMessagesFilter {
             SearchMessagesFilter::VideoNote(t) => t.client_id(),
             SearchMessagesFilter::VoiceAndVideoNote(t) => t.client_id(),
             SearchMessagesFilter::VoiceNote(t) => t.client_id(),
+            SearchMessagesFilter::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for SearchMessagesFilter {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "searchMessagesFilterAnimation" => {
+                SearchMessagesFilter::Animation(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterAudio" => {
+                SearchMessagesFilter::Audio(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterChatPhoto" => {
+                SearchMessagesFilter::ChatPhoto(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterDocument" => {
+                SearchMessagesFilter::Document(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterEmpty" => {
+                SearchMessagesFilter::Empty(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterFailedToSend" => {
+                SearchMessagesFilter::FailedToSend(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterMention" => {
+                SearchMessagesFilter::Mention(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPhoto" => {
+                SearchMessagesFilter::Photo(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPhotoAndVideo" => {
+                SearchMessagesFilter::PhotoAndVideo(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPinned" => {
+                SearchMessagesFilter::Pinned(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterUnreadMention" => {
+                SearchMessagesFilter::UnreadMention(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterUrl" => SearchMessagesFilter::Url(super::_common::from_raw(raw)?),
+            "searchMessagesFilterVideo" => {
+                SearchMessagesFilter::Video(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVideoNote" => {
+                SearchMessagesFilter::VideoNote(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVoiceAndVideoNote" => {
+                SearchMessagesFilter::VoiceAndVideoNote(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVoiceNote" => {
+                SearchMessagesFilter::VoiceNote(super::_common::from_raw(raw)?)
+            }
+            _ => SearchMessagesFilter::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl SearchMessagesFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -15,7 +15,7 @@ pub struct SearchPublicChat {
     #[serde(default)]
     username: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SearchPublicChat {
     }
 }
 
-impl RFunction for SearchPublicChat {}
+impl RFunction for SearchPublicChat {
+    type Response = Chat;
+}
 
 impl SearchPublicChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SearchPublicChats {
     #[serde(default)]
     query: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SearchPublicChats {
     }
 }
 
-impl RFunction for SearchPublicChats {}
+impl RFunction for SearchPublicChats {
+    type Response = Chats;
+}
 
 impl SearchPublicChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -31,7 +31,7 @@ pub struct SearchSecretMessages {
     #[serde(skip_serializing_if = "SearchMessagesFilter::_is_default")]
     filter: SearchMessagesFilter,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for SearchSecretMessages {
     }
 }
 
-impl RFunction for SearchSecretMessages {}
+impl RFunction for SearchSecretMessages {
+    type Response = FoundMessages;
+}
 
 impl SearchSecretMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
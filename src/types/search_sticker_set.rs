@@ -15,7 +15,7 @@ pub struct SearchStickerSet {
     #[serde(default)]
     name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SearchStickerSet {
     }
 }
 
-impl RFunction for SearchStickerSet {}
+impl RFunction for SearchStickerSet {
+    type Response = StickerSet;
+}
 
 impl SearchStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
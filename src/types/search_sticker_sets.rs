@@ -15,7 +15,7 @@ pub struct SearchStickerSets {
     #[serde(default)]
     query: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SearchStickerSets {
     }
 }
 
-impl RFunction for SearchStickerSets {}
+impl RFunction for SearchStickerSets {
+    type Response = StickerSets;
+}
 
 impl SearchStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
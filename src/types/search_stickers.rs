@@ -19,7 +19,7 @@ pub struct SearchStickers {
     #[serde(default)]
     limit: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SearchStickers {
     }
 }
 
-impl RFunction for SearchStickers {}
+impl RFunction for SearchStickers {
+    type Response = Stickers;
+}
 
 impl SearchStickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
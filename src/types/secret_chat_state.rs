@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDSecretChatState: Debug + RObject {}
 
 /// Describes the current secret chat state
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum SecretChatState {
     #[doc(hidden)]
@@ -23,6 +23,12 @@ pub enum SecretChatState {
     /// The secret chat is ready to use
     #[serde(rename = "secretChatStateReady")]
     Ready(SecretChatStateReady),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for SecretChatState {
@@ -32,6 +38,7 @@ impl RObject for SecretChatState {
             SecretChatState::Closed(t) => t.extra(),
             SecretChatState::Pending(t) => t.extra(),
             SecretChatState::Ready(t) => t.extra(),
+            SecretChatState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -42,12 +49,30 @@ impl RObject for SecretChatState {
             SecretChatState::Closed(t) => t.client_id(),
             SecretChatState::Pending(t) => t.client_id(),
             SecretChatState::Ready(t) => t.client_id(),
+            SecretChatState::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for SecretChatState {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "secretChatStateClosed" => SecretChatState::Closed(super::_common::from_raw(raw)?),
+            "secretChatStatePending" => SecretChatState::Pending(super::_common::from_raw(raw)?),
+            "secretChatStateReady" => SecretChatState::Ready(super::_common::from_raw(raw)?),
+            _ => SecretChatState::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl SecretChatState {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
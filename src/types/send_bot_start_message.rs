@@ -23,7 +23,7 @@ pub struct SendBotStartMessage {
     #[serde(default)]
     parameter: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SendBotStartMessage {
     }
 }
 
-impl RFunction for SendBotStartMessage {}
+impl RFunction for SendBotStartMessage {
+    type Response = Message;
+}
 
 impl SendBotStartMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SendCallDebugInformation {
     #[serde(default)]
     debug_information: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SendCallDebugInformation {
     }
 }
 
-impl RFunction for SendCallDebugInformation {}
+impl RFunction for SendCallDebugInformation {
+    type Response = Ok;
+}
 
 impl SendCallDebugInformation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct SendCallRating {
     #[serde(default)]
     problems: Vec<CallProblem>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for SendCallRating {
     }
 }
 
-impl RFunction for SendCallRating {}
+impl RFunction for SendCallRating {
+    type Response = Ok;
+}
 
 impl SendCallRating {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
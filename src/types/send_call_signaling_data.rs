@@ -19,7 +19,7 @@ pub struct SendCallSignalingData {
     #[serde(default)]
     data: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SendCallSignalingData {
     }
 }
 
-impl RFunction for SendCallSignalingData {}
+impl RFunction for SendCallSignalingData {
+    type Response = Ok;
+}
 
 impl SendCallSignalingData {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SendChatAction {
     #[serde(skip_serializing_if = "ChatAction::_is_default")]
     action: ChatAction,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SendChatAction {
     }
 }
 
-impl RFunction for SendChatAction {}
+impl RFunction for SendChatAction {
+    type Response = Ok;
+}
 
 impl SendChatAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
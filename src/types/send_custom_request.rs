@@ -19,7 +19,7 @@ pub struct SendCustomRequest {
     #[serde(default)]
     parameters: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SendCustomRequest {
     }
 }
 
-impl RFunction for SendCustomRequest {}
+impl RFunction for SendCustomRequest {
+    type Response = CustomRequestResult;
+}
 
 impl SendCustomRequest {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SendEmailAddressVerificationCode {
     #[serde(default)]
     email_address: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SendEmailAddressVerificationCode {
     }
 }
 
-impl RFunction for SendEmailAddressVerificationCode {}
+impl RFunction for SendEmailAddressVerificationCode {
+    type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl SendEmailAddressVerificationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -41,7 +41,7 @@ pub struct SendInlineQueryResultMessage {
     #[serde(default)]
     hide_via_bot: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -56,7 +56,9 @@ impl RObject for SendInlineQueryResultMessage {
     }
 }
 
-impl RFunction for SendInlineQueryResultMessage {}
+impl RFunction for SendInlineQueryResultMessage {
+    type Response = Message;
+}
 
 impl SendInlineQueryResultMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
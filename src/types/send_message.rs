@@ -33,7 +33,7 @@ pub struct SendMessage {
     #[serde(skip_serializing_if = "InputMessageContent::_is_default")]
     input_message_content: InputMessageContent,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -48,7 +48,9 @@ impl RObject for SendMessage {
     }
 }
 
-impl RFunction for SendMessage {}
+impl RFunction for SendMessage {
+    type Response = Message;
+}
 
 impl SendMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
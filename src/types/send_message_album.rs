@@ -29,7 +29,7 @@ pub struct SendMessageAlbum {
     #[serde(default)]
     input_message_contents: Vec<InputMessageContent>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -44,7 +44,9 @@ impl RObject for SendMessageAlbum {
     }
 }
 
-impl RFunction for SendMessageAlbum {}
+impl RFunction for SendMessageAlbum {
+    type Response = Messages;
+}
 
 impl SendMessageAlbum {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
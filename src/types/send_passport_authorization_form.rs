@@ -19,7 +19,7 @@ pub struct SendPassportAuthorizationForm {
     #[serde(default)]
     types: Vec<PassportElementType>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SendPassportAuthorizationForm {
     }
 }
 
-impl RFunction for SendPassportAuthorizationForm {}
+impl RFunction for SendPassportAuthorizationForm {
+    type Response = Ok;
+}
 
 impl SendPassportAuthorizationForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
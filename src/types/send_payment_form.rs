@@ -43,7 +43,7 @@ pub struct SendPaymentForm {
     #[serde(default)]
     tip_amount: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -58,7 +58,9 @@ impl RObject for SendPaymentForm {
     }
 }
 
-impl RFunction for SendPaymentForm {}
+impl RFunction for SendPaymentForm {
+    type Response = PaymentResult;
+}
 
 impl SendPaymentForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
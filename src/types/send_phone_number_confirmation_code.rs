@@ -21,7 +21,7 @@ pub struct SendPhoneNumberConfirmationCode {
     /// Settings for the authentication of the user's phone number; pass null to use default settings
     settings: PhoneNumberAuthenticationSettings,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for SendPhoneNumberConfirmationCode {
     }
 }
 
-impl RFunction for SendPhoneNumberConfirmationCode {}
+impl RFunction for SendPhoneNumberConfirmationCode {
+    type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberConfirmationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SendPhoneNumberVerificationCode {
     /// Settings for the authentication of the user's phone number; pass null to use default settings
     settings: PhoneNumberAuthenticationSettings,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SendPhoneNumberVerificationCode {
     }
 }
 
-impl RFunction for SendPhoneNumberVerificationCode {}
+impl RFunction for SendPhoneNumberVerificationCode {
+    type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberVerificationCode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -13,7 +13,7 @@ pub struct SetAccountTtl {
     /// New account TTL
     ttl: AccountTtl,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for SetAccountTtl {
     }
 }
 
-impl RFunction for SetAccountTtl {}
+impl RFunction for SetAccountTtl {
+    type Response = Ok;
+}
 
 impl SetAccountTtl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
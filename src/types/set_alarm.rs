@@ -15,7 +15,7 @@ pub struct SetAlarm {
     #[serde(default)]
     seconds: f32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetAlarm {
     }
 }
 
-impl RFunction for SetAlarm {}
+impl RFunction for SetAlarm {
+    type Response = Ok;
+}
 
 impl SetAlarm {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
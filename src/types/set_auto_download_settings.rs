@@ -18,7 +18,7 @@ pub struct SetAutoDownloadSettings {
     #[serde(skip_serializing_if = "NetworkType::_is_default")]
     type_: NetworkType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -33,7 +33,9 @@ impl RObject for SetAutoDownloadSettings {
     }
 }
 
-impl RFunction for SetAutoDownloadSettings {}
+impl RFunction for SetAutoDownloadSettings {
+    type Response = Ok;
+}
 
 impl SetAutoDownloadSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -24,7 +24,7 @@ pub struct SetBackground {
     #[serde(default)]
     for_dark_theme: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -39,7 +39,9 @@ impl RObject for SetBackground {
     }
 }
 
-impl RFunction for SetBackground {}
+impl RFunction for SetBackground {
+    type Response = Background;
+}
 
 impl SetBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SetBio {
     #[serde(default)]
     bio: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetBio {
     }
 }
 
-impl RFunction for SetBio {}
+impl RFunction for SetBio {
+    type Response = Ok;
+}
 
 impl SetBio {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetBotUpdatesStatus {
     #[serde(default)]
     error_message: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetBotUpdatesStatus {
     }
 }
 
-impl RFunction for SetBotUpdatesStatus {}
+impl RFunction for SetBotUpdatesStatus {
+    type Response = Ok;
+}
 
 impl SetBotUpdatesStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetChatClientData {
     #[serde(default)]
     client_data: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatClientData {
     }
 }
 
-impl RFunction for SetChatClientData {}
+impl RFunction for SetChatClientData {
+    type Response = Ok;
+}
 
 impl SetChatClientData {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
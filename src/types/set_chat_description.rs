@@ -19,7 +19,7 @@ pub struct SetChatDescription {
     #[serde(default)]
     description: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatDescription {
     }
 }
 
-impl RFunction for SetChatDescription {}
+impl RFunction for SetChatDescription {
+    type Response = Ok;
+}
 
 impl SetChatDescription {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetChatDiscussionGroup {
     #[serde(default)]
     discussion_chat_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatDiscussionGroup {
     }
 }
 
-impl RFunction for SetChatDiscussionGroup {}
+impl RFunction for SetChatDiscussionGroup {
+    type Response = Ok;
+}
 
 impl SetChatDiscussionGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
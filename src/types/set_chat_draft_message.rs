@@ -21,7 +21,7 @@ pub struct SetChatDraftMessage {
     /// New draft message; pass null to remove the draft
     draft_message: DraftMessage,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for SetChatDraftMessage {
     }
 }
 
-impl RFunction for SetChatDraftMessage {}
+impl RFunction for SetChatDraftMessage {
+    type Response = Ok;
+}
 
 impl SetChatDraftMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SetChatLocation {
     /// New location for the chat; must be valid and not null
     location: ChatLocation,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetChatLocation {
     }
 }
 
-impl RFunction for SetChatLocation {}
+impl RFunction for SetChatLocation {
+    type Response = Ok;
+}
 
 impl SetChatLocation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
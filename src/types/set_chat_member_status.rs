@@ -23,7 +23,7 @@ pub struct SetChatMemberStatus {
     #[serde(skip_serializing_if = "ChatMemberStatus::_is_default")]
     status: ChatMemberStatus,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetChatMemberStatus {
     }
 }
 
-impl RFunction for SetChatMemberStatus {}
+impl RFunction for SetChatMemberStatus {
+    type Response = Ok;
+}
 
 impl SetChatMemberStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
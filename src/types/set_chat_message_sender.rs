@@ -19,7 +19,7 @@ pub struct SetChatMessageSender {
     #[serde(skip_serializing_if = "MessageSender::_is_default")]
     message_sender_id: MessageSender,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatMessageSender {
     }
 }
 
-impl RFunction for SetChatMessageSender {}
+impl RFunction for SetChatMessageSender {
+    type Response = Ok;
+}
 
 impl SetChatMessageSender {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
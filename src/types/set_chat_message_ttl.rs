@@ -19,7 +19,7 @@ pub struct SetChatMessageTtl {
     #[serde(default)]
     ttl: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatMessageTtl {
     }
 }
 
-impl RFunction for SetChatMessageTtl {}
+impl RFunction for SetChatMessageTtl {
+    type Response = Ok;
+}
 
 impl SetChatMessageTtl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
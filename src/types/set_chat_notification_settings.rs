@@ -17,7 +17,7 @@ pub struct SetChatNotificationSettings {
     /// New notification settings for the chat. If the chat is muted for more than 1 week, it is considered to be muted forever
     notification_settings: ChatNotificationSettings,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetChatNotificationSettings {
     }
 }
 
-impl RFunction for SetChatNotificationSettings {}
+impl RFunction for SetChatNotificationSettings {
+    type Response = Ok;
+}
 
 impl SetChatNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SetChatPermissions {
     /// New non-administrator members permissions in the chat
     permissions: ChatPermissions,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetChatPermissions {
     }
 }
 
-impl RFunction for SetChatPermissions {}
+impl RFunction for SetChatPermissions {
+    type Response = Ok;
+}
 
 impl SetChatPermissions {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
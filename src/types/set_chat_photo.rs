@@ -19,7 +19,7 @@ pub struct SetChatPhoto {
     #[serde(skip_serializing_if = "InputChatPhoto::_is_default")]
     photo: InputChatPhoto,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatPhoto {
     }
 }
 
-impl RFunction for SetChatPhoto {}
+impl RFunction for SetChatPhoto {
+    type Response = Ok;
+}
 
 impl SetChatPhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetChatSlowModeDelay {
     #[serde(default)]
     slow_mode_delay: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatSlowModeDelay {
     }
 }
 
-impl RFunction for SetChatSlowModeDelay {}
+impl RFunction for SetChatSlowModeDelay {
+    type Response = Ok;
+}
 
 impl SetChatSlowModeDelay {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
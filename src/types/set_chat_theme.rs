@@ -19,7 +19,7 @@ pub struct SetChatTheme {
     #[serde(default)]
     theme_name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatTheme {
     }
 }
 
-impl RFunction for SetChatTheme {}
+impl RFunction for SetChatTheme {
+    type Response = Ok;
+}
 
 impl SetChatTheme {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetChatTitle {
     #[serde(default)]
     title: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetChatTitle {
     }
 }
 
-impl RFunction for SetChatTitle {}
+impl RFunction for SetChatTitle {
+    type Response = Ok;
+}
 
 impl SetChatTitle {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SetCommands {
     #[serde(default)]
     commands: Vec<BotCommand>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetCommands {
     }
 }
 
-impl RFunction for SetCommands {}
+impl RFunction for SetCommands {
+    type Response = Ok;
+}
 
 impl SetCommands {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SetCustomLanguagePack {
     #[serde(default)]
     strings: Vec<LanguagePackString>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetCustomLanguagePack {
     }
 }
 
-impl RFunction for SetCustomLanguagePack {}
+impl RFunction for SetCustomLanguagePack {
+    type Response = Ok;
+}
 
 impl SetCustomLanguagePack {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SetCustomLanguagePackString {
     /// New language pack string
     new_string: LanguagePackString,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetCustomLanguagePackString {
     }
 }
 
-impl RFunction for SetCustomLanguagePackString {}
+impl RFunction for SetCustomLanguagePackString {
+    type Response = Ok;
+}
 
 impl SetCustomLanguagePackString {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SetDatabaseEncryptionKey {
     #[serde(default)]
     new_encryption_key: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetDatabaseEncryptionKey {
     }
 }
 
-impl RFunction for SetDatabaseEncryptionKey {}
+impl RFunction for SetDatabaseEncryptionKey {
+    type Response = Ok;
+}
 
 impl SetDatabaseEncryptionKey {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -27,7 +27,7 @@ pub struct SetFileGenerationProgress {
     #[serde(default)]
     local_prefix_size: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for SetFileGenerationProgress {
     }
 }
 
-impl RFunction for SetFileGenerationProgress {}
+impl RFunction for SetFileGenerationProgress {
+    type Response = Ok;
+}
 
 impl SetFileGenerationProgress {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -35,7 +35,7 @@ pub struct SetGameScore {
     #[serde(default)]
     force: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -50,7 +50,9 @@ impl RObject for SetGameScore {
     }
 }
 
-impl RFunction for SetGameScore {}
+impl RFunction for SetGameScore {
+    type Response = Message;
+}
 
 impl SetGameScore {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SetGroupCallParticipantIsSpeaking {
     #[serde(default)]
     is_speaking: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetGroupCallParticipantIsSpeaking {
     }
 }
 
-impl RFunction for SetGroupCallParticipantIsSpeaking {}
+impl RFunction for SetGroupCallParticipantIsSpeaking {
+    type Response = Ok;
+}
 
 impl SetGroupCallParticipantIsSpeaking {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
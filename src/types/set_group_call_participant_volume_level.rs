@@ -23,7 +23,7 @@ pub struct SetGroupCallParticipantVolumeLevel {
     #[serde(default)]
     volume_level: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetGroupCallParticipantVolumeLevel {
     }
 }
 
-impl RFunction for SetGroupCallParticipantVolumeLevel {}
+impl RFunction for SetGroupCallParticipantVolumeLevel {
+    type Response = Ok;
+}
 
 impl SetGroupCallParticipantVolumeLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetGroupCallTitle {
     #[serde(default)]
     title: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetGroupCallTitle {
     }
 }
 
-impl RFunction for SetGroupCallTitle {}
+impl RFunction for SetGroupCallTitle {
+    type Response = Ok;
+}
 
 impl SetGroupCallTitle {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
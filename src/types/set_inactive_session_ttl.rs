@@ -15,7 +15,7 @@ pub struct SetInactiveSessionTtl {
     #[serde(default)]
     inactive_session_ttl_days: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetInactiveSessionTtl {
     }
 }
 
-impl RFunction for SetInactiveSessionTtl {}
+impl RFunction for SetInactiveSessionTtl {
+    type Response = Ok;
+}
 
 impl SetInactiveSessionTtl {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -31,7 +31,7 @@ pub struct SetInlineGameScore {
     #[serde(default)]
     force: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for SetInlineGameScore {
     }
 }
 
-impl RFunction for SetInlineGameScore {}
+impl RFunction for SetInlineGameScore {
+    type Response = Ok;
+}
 
 impl SetInlineGameScore {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
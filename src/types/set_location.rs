@@ -13,7 +13,7 @@ pub struct SetLocation {
     /// The new location of the user
     location: Location,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for SetLocation {
     }
 }
 
-impl RFunction for SetLocation {}
+impl RFunction for SetLocation {
+    type Response = Ok;
+}
 
 impl SetLocation {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SetLogStream {
     #[serde(skip_serializing_if = "LogStream::_is_default")]
     log_stream: LogStream,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetLogStream {
     }
 }
 
-impl RFunction for SetLogStream {}
+impl RFunction for SetLogStream {
+    type Response = Ok;
+}
 
 impl SetLogStream {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
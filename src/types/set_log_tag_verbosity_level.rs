@@ -19,7 +19,7 @@ pub struct SetLogTagVerbosityLevel {
     #[serde(default)]
     new_verbosity_level: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetLogTagVerbosityLevel {
     }
 }
 
-impl RFunction for SetLogTagVerbosityLevel {}
+impl RFunction for SetLogTagVerbosityLevel {
+    type Response = Ok;
+}
 
 impl SetLogTagVerbosityLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct SetLogVerbosityLevel {
     #[serde(default)]
     new_verbosity_level: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetLogVerbosityLevel {
     }
 }
 
-impl RFunction for SetLogVerbosityLevel {}
+impl RFunction for SetLogVerbosityLevel {
+    type Response = Ok;
+}
 
 impl SetLogVerbosityLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
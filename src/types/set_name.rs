@@ -19,7 +19,7 @@ pub struct SetName {
     #[serde(default)]
     last_name: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetName {
     }
 }
 
-impl RFunction for SetName {}
+impl RFunction for SetName {
+    type Response = Ok;
+}
 
 impl SetName {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -16,7 +16,7 @@ pub struct SetNetworkType {
     #[serde(skip_serializing_if = "NetworkType::_is_default")]
     type_: NetworkType,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -31,7 +31,9 @@ impl RObject for SetNetworkType {
     }
 }
 
-impl RFunction for SetNetworkType {}
+impl RFunction for SetNetworkType {
+    type Response = Ok;
+}
 
 impl SetNetworkType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetOption {
     #[serde(skip_serializing_if = "OptionValue::_is_default")]
     value: OptionValue,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetOption {
     }
 }
 
-impl RFunction for SetOption {}
+impl RFunction for SetOption {
+    type Response = Ok;
+}
 
 impl SetOption {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetPassportElement {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -36,7 +36,9 @@ impl RObject for SetPassportElement {
 
 impl TDPassportElement for SetPassportElement {}
 
-impl RFunction for SetPassportElement {}
+impl RFunction for SetPassportElement {
+    type Response = PassportElement;
+}
 
 impl SetPassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
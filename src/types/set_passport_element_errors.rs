@@ -19,7 +19,7 @@ pub struct SetPassportElementErrors {
     #[serde(default)]
     errors: Vec<InputPassportElementError>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetPassportElementErrors {
     }
 }
 
-impl RFunction for SetPassportElementErrors {}
+impl RFunction for SetPassportElementErrors {
+    type Response = Ok;
+}
 
 impl SetPassportElementErrors {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
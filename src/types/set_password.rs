@@ -31,7 +31,7 @@ pub struct SetPassword {
     #[serde(default)]
     new_recovery_email_address: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -46,7 +46,9 @@ impl RObject for SetPassword {
     }
 }
 
-impl RFunction for SetPassword {}
+impl RFunction for SetPassword {
+    type Response = PasswordState;
+}
 
 impl SetPassword {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
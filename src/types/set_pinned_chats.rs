@@ -19,7 +19,7 @@ pub struct SetPinnedChats {
     #[serde(default)]
     chat_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetPinnedChats {
     }
 }
 
-impl RFunction for SetPinnedChats {}
+impl RFunction for SetPinnedChats {
+    type Response = Ok;
+}
 
 impl SetPinnedChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SetPollAnswer {
     #[serde(default)]
     option_ids: Vec<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetPollAnswer {
     }
 }
 
-impl RFunction for SetPollAnswer {}
+impl RFunction for SetPollAnswer {
+    type Response = Ok;
+}
 
 impl SetPollAnswer {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
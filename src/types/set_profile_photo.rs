@@ -15,7 +15,7 @@ pub struct SetProfilePhoto {
     #[serde(skip_serializing_if = "InputChatPhoto::_is_default")]
     photo: InputChatPhoto,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetProfilePhoto {
     }
 }
 
-impl RFunction for SetProfilePhoto {}
+impl RFunction for SetProfilePhoto {
+    type Response = Ok;
+}
 
 impl SetProfilePhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
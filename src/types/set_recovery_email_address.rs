@@ -19,7 +19,7 @@ pub struct SetRecoveryEmailAddress {
     #[serde(default)]
     new_recovery_email_address: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetRecoveryEmailAddress {
     }
 }
 
-impl RFunction for SetRecoveryEmailAddress {}
+impl RFunction for SetRecoveryEmailAddress {
+    type Response = PasswordState;
+}
 
 impl SetRecoveryEmailAddress {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
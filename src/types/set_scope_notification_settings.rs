@@ -17,7 +17,7 @@ pub struct SetScopeNotificationSettings {
     /// The new notification settings for the given scope
     notification_settings: ScopeNotificationSettings,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetScopeNotificationSettings {
     }
 }
 
-impl RFunction for SetScopeNotificationSettings {}
+impl RFunction for SetScopeNotificationSettings {
+    type Response = Ok;
+}
 
 impl SetScopeNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetStickerPositionInSet {
     #[serde(default)]
     position: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetStickerPositionInSet {
     }
 }
 
-impl RFunction for SetStickerPositionInSet {}
+impl RFunction for SetStickerPositionInSet {
+    type Response = Ok;
+}
 
 impl SetStickerPositionInSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SetStickerSetThumbnail {
     #[serde(skip_serializing_if = "InputFile::_is_default")]
     thumbnail: InputFile,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetStickerSetThumbnail {
     }
 }
 
-impl RFunction for SetStickerSetThumbnail {}
+impl RFunction for SetStickerSetThumbnail {
+    type Response = StickerSet;
+}
 
 impl SetStickerSetThumbnail {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct SetSupergroupStickerSet {
     #[serde(default)]
     sticker_set_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for SetSupergroupStickerSet {
     }
 }
 
-impl RFunction for SetSupergroupStickerSet {}
+impl RFunction for SetSupergroupStickerSet {
+    type Response = Ok;
+}
 
 impl SetSupergroupStickerSet {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
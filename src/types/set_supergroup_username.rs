@@ -19,7 +19,7 @@ pub struct SetSupergroupUsername {
     #[serde(default)]
     username: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetSupergroupUsername {
     }
 }
 
-impl RFunction for SetSupergroupUsername {}
+impl RFunction for SetSupergroupUsername {
+    type Response = Ok;
+}
 
 impl SetSupergroupUsername {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
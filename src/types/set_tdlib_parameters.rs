@@ -13,7 +13,7 @@ pub struct SetTdlibParameters {
     /// Parameters for TDLib initialization
     parameters: TdlibParameters,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for SetTdlibParameters {
     }
 }
 
-impl RFunction for SetTdlibParameters {}
+impl RFunction for SetTdlibParameters {
+    type Response = Ok;
+}
 
 impl SetTdlibParameters {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -17,7 +17,7 @@ pub struct SetUserPrivacySettingRules {
     /// The new privacy rules
     rules: UserPrivacySettingRules,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -32,7 +32,9 @@ impl RObject for SetUserPrivacySettingRules {
     }
 }
 
-impl RFunction for SetUserPrivacySettingRules {}
+impl RFunction for SetUserPrivacySettingRules {
+    type Response = Ok;
+}
 
 impl SetUserPrivacySettingRules {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
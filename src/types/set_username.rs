@@ -15,7 +15,7 @@ pub struct SetUsername {
     #[serde(default)]
     username: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SetUsername {
     }
 }
 
-impl RFunction for SetUsername {}
+impl RFunction for SetUsername {
+    type Response = Ok;
+}
 
 impl SetUsername {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct SetVideoChatDefaultParticipant {
     #[serde(skip_serializing_if = "MessageSender::_is_default")]
     default_participant_id: MessageSender,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for SetVideoChatDefaultParticipant {
     }
 }
 
-impl RFunction for SetVideoChatDefaultParticipant {}
+impl RFunction for SetVideoChatDefaultParticipant {
+    type Response = Ok;
+}
 
 impl SetVideoChatDefaultParticipant {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
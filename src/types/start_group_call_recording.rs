@@ -27,7 +27,7 @@ pub struct StartGroupCallRecording {
     #[serde(default)]
     use_portrait_orientation: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for StartGroupCallRecording {
     }
 }
 
-impl RFunction for StartGroupCallRecording {}
+impl RFunction for StartGroupCallRecording {
+    type Response = Ok;
+}
 
 impl StartGroupCallRecording {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct StartGroupCallScreenSharing {
     #[serde(default)]
     payload: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for StartGroupCallScreenSharing {
     }
 }
 
-impl RFunction for StartGroupCallScreenSharing {}
+impl RFunction for StartGroupCallScreenSharing {
+    type Response = Text;
+}
 
 impl StartGroupCallScreenSharing {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
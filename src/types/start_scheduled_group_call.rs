@@ -15,7 +15,7 @@ pub struct StartScheduledGroupCall {
     #[serde(default)]
     group_call_id: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for StartScheduledGroupCall {
     }
 }
 
-impl RFunction for StartScheduledGroupCall {}
+impl RFunction for StartScheduledGroupCall {
+    type Response = Ok;
+}
 
 impl StartScheduledGroupCall {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
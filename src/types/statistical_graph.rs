@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDStatisticalGraph: Debug + RObject {}
 
 /// Describes a statistical graph
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum StatisticalGraph {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum StatisticalGraph {
     /// An error message to be shown to the user instead of the graph
     #[serde(rename = "statisticalGraphError")]
     Error(StatisticalGraphError),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for StatisticalGraph {
@@ -36,6 +42,7 @@ impl RObject for StatisticalGraph {
             StatisticalGraph::Async(t) => t.extra(),
             StatisticalGraph::Data(t) => t.extra(),
             StatisticalGraph::Error(t) => t.extra(),
+            StatisticalGraph::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,33 @@ impl RObject for StatisticalGraph {
             StatisticalGraph::Async(t) => t.client_id(),
             StatisticalGraph::Data(t) => t.client_id(),
             StatisticalGraph::Error(t) => t.client_id(),
+            StatisticalGraph::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for StatisticalGraph {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "getStatisticalGraph" => {
+                StatisticalGraph::GetStatisticalGraph(super::_common::from_raw(raw)?)
+            }
+            "statisticalGraphAsync" => StatisticalGraph::Async(super::_common::from_raw(raw)?),
+            "statisticalGraphData" => StatisticalGraph::Data(super::_common::from_raw(raw)?),
+            "statisticalGraphError" => StatisticalGraph::Error(super::_common::from_raw(raw)?),
+            _ => StatisticalGraph::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl StatisticalGraph {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -23,7 +23,7 @@ pub struct StopPoll {
     #[serde(skip_serializing_if = "ReplyMarkup::_is_default")]
     reply_markup: ReplyMarkup,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for StopPoll {
     }
 }
 
-impl RFunction for StopPoll {}
+impl RFunction for StopPoll {
+    type Response = Ok;
+}
 
 impl StopPoll {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDSuggestedAction: Debug + RObject {}
 
 /// Describes an action suggested to the current user
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum SuggestedAction {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum SuggestedAction {
     /// Suggests the user to view a hint about the meaning of one and two check marks on sent messages
     #[serde(rename = "suggestedActionViewChecksHint")]
     ViewChecksHint(SuggestedActionViewChecksHint),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for SuggestedAction {
@@ -44,6 +50,7 @@ impl RObject for SuggestedAction {
             SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.extra(),
             SuggestedAction::SetPassword(t) => t.extra(),
             SuggestedAction::ViewChecksHint(t) => t.extra(),
+            SuggestedAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -57,12 +64,45 @@ impl RObject for SuggestedAction {
             SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.client_id(),
             SuggestedAction::SetPassword(t) => t.client_id(),
             SuggestedAction::ViewChecksHint(t) => t.client_id(),
+            SuggestedAction::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for SuggestedAction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "suggestedActionCheckPassword" => {
+                SuggestedAction::CheckPassword(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionCheckPhoneNumber" => {
+                SuggestedAction::CheckPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionConvertToBroadcastGroup" => {
+                SuggestedAction::ConvertToBroadcastGroup(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionEnableArchiveAndMuteNewChats" => {
+                SuggestedAction::EnableArchiveAndMuteNewChats(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionSetPassword" => {
+                SuggestedAction::SetPassword(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionViewChecksHint" => {
+                SuggestedAction::ViewChecksHint(super::_common::from_raw(raw)?)
+            }
+            _ => SuggestedAction::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl SuggestedAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDSupergroupMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in getSupergroupMembers
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum SupergroupMembersFilter {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum SupergroupMembersFilter {
     /// Used to search for supergroup or channel members via a (string) query
     #[serde(rename = "supergroupMembersFilterSearch")]
     Search(SupergroupMembersFilterSearch),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for SupergroupMembersFilter {
@@ -52,6 +58,9 @@ impl RObject for SupergroupMembersFilter {
             SupergroupMembersFilter::Recent(t) => t.extra(),
             SupergroupMembersFilter::Restricted(t) => t.extra(),
             SupergroupMembersFilter::Search(t) => t.extra(),
+            SupergroupMembersFilter::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -67,12 +76,51 @@ impl RObject for SupergroupMembersFilter {
             SupergroupMembersFilter::Recent(t) => t.client_id(),
             SupergroupMembersFilter::Restricted(t) => t.client_id(),
             SupergroupMembersFilter::Search(t) => t.client_id(),
+            SupergroupMembersFilter::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for SupergroupMembersFilter {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "supergroupMembersFilterAdministrators" => {
+                SupergroupMembersFilter::Administrators(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterBanned" => {
+                SupergroupMembersFilter::Banned(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterBots" => {
+                SupergroupMembersFilter::Bots(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterContacts" => {
+                SupergroupMembersFilter::Contacts(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterMention" => {
+                SupergroupMembersFilter::Mention(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterRecent" => {
+                SupergroupMembersFilter::Recent(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterRestricted" => {
+                SupergroupMembersFilter::Restricted(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterSearch" => {
+                SupergroupMembersFilter::Search(super::_common::from_raw(raw)?)
+            }
+            _ => SupergroupMembersFilter::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl SupergroupMembersFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -15,7 +15,7 @@ pub struct SynchronizeLanguagePack {
     #[serde(default)]
     language_pack_id: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for SynchronizeLanguagePack {
     }
 }
 
-impl RFunction for SynchronizeLanguagePack {}
+impl RFunction for SynchronizeLanguagePack {
+    type Response = Ok;
+}
 
 impl SynchronizeLanguagePack {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
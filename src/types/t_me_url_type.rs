@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDTMeUrlType: Debug + RObject {}
 
 /// Describes the type of a URL linking to an internal Telegram entity
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum TMeUrlType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum TMeUrlType {
     /// A URL linking to a user
     #[serde(rename = "tMeUrlTypeUser")]
     User(TMeUrlTypeUser),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for TMeUrlType {
@@ -36,6 +42,7 @@ impl RObject for TMeUrlType {
             TMeUrlType::StickerSet(t) => t.extra(),
             TMeUrlType::Supergroup(t) => t.extra(),
             TMeUrlType::User(t) => t.extra(),
+            TMeUrlType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for TMeUrlType {
             TMeUrlType::StickerSet(t) => t.client_id(),
             TMeUrlType::Supergroup(t) => t.client_id(),
             TMeUrlType::User(t) => t.client_id(),
+            TMeUrlType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for TMeUrlType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "tMeUrlTypeChatInvite" => TMeUrlType::ChatInvite(super::_common::from_raw(raw)?),
+            "tMeUrlTypeStickerSet" => TMeUrlType::StickerSet(super::_common::from_raw(raw)?),
+            "tMeUrlTypeSupergroup" => TMeUrlType::Supergroup(super::_common::from_raw(raw)?),
+            "tMeUrlTypeUser" => TMeUrlType::User(super::_common::from_raw(raw)?),
+            _ => TMeUrlType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl TMeUrlType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -0,0 +1,3838 @@
+use crate::errors::Result;
+use crate::types::*;
+use serde::{Serialize, Serializer};
+
+/// Any TDLib function, tagged by its `@type`
+#[derive(Debug, Clone)]
+pub enum TdFunction {
+    /// Accepts an incoming call
+    AcceptCall(Box<AcceptCall>),
+    /// Accepts Telegram terms of services
+    AcceptTermsOfService(Box<AcceptTermsOfService>),
+    /// Adds a new member to a chat. Members can't be added to private or secret chats
+    AddChatMember(Box<AddChatMember>),
+    /// Adds multiple new members to a chat. Currently, this method is only available for supergroups and channels. This method can't be used to join a chat. Members can't be added to a channel if it has more than 200 members
+    AddChatMembers(Box<AddChatMembers>),
+    /// Adds a chat to a chat list. A chat can't be simultaneously in Main and Archive chat lists, so it is automatically removed from another one if needed
+    AddChatToList(Box<AddChatToList>),
+    /// Adds a user to the contact list or edits an existing contact by their user identifier
+    AddContact(Box<AddContact>),
+    /// Adds a custom server language pack to the list of installed language packs in current localization target. Can be called before authorization
+    AddCustomServerLanguagePack(Box<AddCustomServerLanguagePack>),
+    /// Adds a new sticker to the list of favorite stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list
+    AddFavoriteSticker(Box<AddFavoriteSticker>),
+    /// Adds a local message to a chat. The message is persistent across application restarts only if the message database is used. Returns the added message
+    AddLocalMessage(Box<AddLocalMessage>),
+    /// Adds a message to TDLib internal log. Can be called synchronously
+    AddLogMessage(Box<AddLogMessage>),
+    /// Adds the specified data to data usage statistics. Can be called before authorization
+    AddNetworkStatistics(Box<AddNetworkStatistics>),
+    /// Adds a proxy server for network requests. Can be called before authorization
+    AddProxy(Box<AddProxy>),
+    /// Manually adds a new sticker to the list of recently used stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list
+    AddRecentSticker(Box<AddRecentSticker>),
+    /// Adds a chat to the list of recently found chats. The chat is added to the beginning of the list. If the chat is already in the list, it will be removed from the list first
+    AddRecentlyFoundChat(Box<AddRecentlyFoundChat>),
+    /// Manually adds a new animation to the list of saved animations. The new animation is added to the beginning of the list. If the animation was already in the list, it is removed first. Only non-secret video animations with MIME type "video/mp4" can be added to the list
+    AddSavedAnimation(Box<AddSavedAnimation>),
+    /// Adds a new sticker to a set; for bots only. Returns the sticker set
+    AddStickerToSet(Box<AddStickerToSet>),
+    /// Sets the result of a callback query; for bots only
+    AnswerCallbackQuery(Box<AnswerCallbackQuery>),
+    /// Answers a custom query; for bots only
+    AnswerCustomQuery(Box<AnswerCustomQuery>),
+    /// Sets the result of an inline query; for bots only
+    AnswerInlineQuery(Box<AnswerInlineQuery>),
+    /// Sets the result of a pre-checkout query; for bots only
+    AnswerPreCheckoutQuery(Box<AnswerPreCheckoutQuery>),
+    /// Sets the result of a shipping query; for bots only
+    AnswerShippingQuery(Box<AnswerShippingQuery>),
+    /// Bans a member in a chat. Members can't be banned in private or secret chats. In supergroups and channels, the user will not be able to return to the group on their own using invite links, etc., unless unbanned first
+    BanChatMember(Box<BanChatMember>),
+    /// Blocks an original sender of a message in the Replies chat
+    BlockMessageSenderFromReplies(Box<BlockMessageSenderFromReplies>),
+    /// Checks whether the current session can be used to transfer a chat ownership to another user
+    CanTransferOwnership(Box<CanTransferOwnership>),
+    /// Stops the downloading of a file. If a file has already been downloaded, does nothing
+    CancelDownloadFile(Box<CancelDownloadFile>),
+    /// Cancels reset of 2-step verification password. The method can be called if passwordState.pending_reset_date > 0
+    CancelPasswordReset(Box<CancelPasswordReset>),
+    /// Stops the uploading of a file. Supported only for files uploaded by using uploadFile. For other files the behavior is undefined
+    CancelUploadFile(Box<CancelUploadFile>),
+    /// Changes imported contacts using the list of contacts saved on the device. Imports newly added contacts and, if at least the file database is enabled, deletes recently deleted contacts. Query result depends on the result of the previous query, so only one query is possible at the same time
+    ChangeImportedContacts(Box<ChangeImportedContacts>),
+    /// Changes the phone number of the user and sends an authentication code to the user's new phone number. On success, returns information about the sent code
+    ChangePhoneNumber(Box<ChangePhoneNumber>),
+    /// Installs/uninstalls or activates/archives a sticker set
+    ChangeStickerSet(Box<ChangeStickerSet>),
+    /// Checks the authentication token of a bot; to log in as a bot. Works only when the current authorization state is authorizationStateWaitPhoneNumber. Can be used instead of setAuthenticationPhoneNumber and checkAuthenticationCode to log in
+    CheckAuthenticationBotToken(Box<CheckAuthenticationBotToken>),
+    /// Checks the authentication code. Works only when the current authorization state is authorizationStateWaitCode
+    CheckAuthenticationCode(Box<CheckAuthenticationCode>),
+    /// Checks the authentication password for correctness. Works only when the current authorization state is authorizationStateWaitPassword
+    CheckAuthenticationPassword(Box<CheckAuthenticationPassword>),
+    /// Checks whether a password recovery code sent to an email address is valid. Works only when the current authorization state is authorizationStateWaitPassword
+    CheckAuthenticationPasswordRecoveryCode(Box<CheckAuthenticationPasswordRecoveryCode>),
+    /// Checks the authentication code sent to confirm a new phone number of the user
+    CheckChangePhoneNumberCode(Box<CheckChangePhoneNumberCode>),
+    /// Checks the validity of an invite link for a chat and returns information about the corresponding chat
+    CheckChatInviteLink(Box<CheckChatInviteLink>),
+    /// Checks whether a username can be set for a chat
+    CheckChatUsername(Box<CheckChatUsername>),
+    /// Checks whether the maximum number of owned public chats has been reached. Returns corresponding error if the limit was reached
+    CheckCreatedPublicChatsLimit(Box<CheckCreatedPublicChatsLimit>),
+    /// Checks the database encryption key for correctness. Works only when the current authorization state is authorizationStateWaitEncryptionKey
+    CheckDatabaseEncryptionKey(Box<CheckDatabaseEncryptionKey>),
+    /// Checks the email address verification code for Telegram Passport
+    CheckEmailAddressVerificationCode(Box<CheckEmailAddressVerificationCode>),
+    /// Checks whether a 2-step verification password recovery code sent to an email address is valid
+    CheckPasswordRecoveryCode(Box<CheckPasswordRecoveryCode>),
+    /// Checks phone number confirmation code
+    CheckPhoneNumberConfirmationCode(Box<CheckPhoneNumberConfirmationCode>),
+    /// Checks the phone number verification code for Telegram Passport
+    CheckPhoneNumberVerificationCode(Box<CheckPhoneNumberVerificationCode>),
+    /// Checks the 2-step verification recovery email address verification code
+    CheckRecoveryEmailAddressCode(Box<CheckRecoveryEmailAddressCode>),
+    /// Checks whether a name can be used for a new sticker set
+    CheckStickerSetName(Box<CheckStickerSetName>),
+    /// Removes potentially dangerous characters from the name of a file. The encoding of the file name is supposed to be UTF-8. Returns an empty string on failure. Can be called synchronously
+    CleanFileName(Box<CleanFileName>),
+    /// Clears draft messages in all chats
+    ClearAllDraftMessages(Box<ClearAllDraftMessages>),
+    /// Clears all imported contacts, contact list remains unchanged
+    ClearImportedContacts(Box<ClearImportedContacts>),
+    /// Clears the list of recently used stickers
+    ClearRecentStickers(Box<ClearRecentStickers>),
+    /// Clears the list of recently found chats
+    ClearRecentlyFoundChats(Box<ClearRecentlyFoundChats>),
+    /// Informs TDLib that a message with an animated emoji was clicked by the user. Returns a big animated sticker to be played or a 404 error if usual animation needs to be played
+    ClickAnimatedEmojiMessage(Box<ClickAnimatedEmojiMessage>),
+    /// Closes the TDLib instance. All databases will be flushed to disk and properly closed. After the close completes, updateAuthorizationState with authorizationStateClosed will be sent. Can be called before initialization
+    Close(Box<Close>),
+    /// Informs TDLib that the chat is closed by the user. Many useful activities depend on the chat being opened or closed
+    CloseChat(Box<CloseChat>),
+    /// Closes a secret chat, effectively transferring its state to secretChatStateClosed
+    CloseSecretChat(Box<CloseSecretChat>),
+    /// Confirms QR code authentication on another device. Returns created session on success
+    ConfirmQrCodeAuthentication(Box<ConfirmQrCodeAuthentication>),
+    /// Returns an existing chat corresponding to a known basic group
+    CreateBasicGroupChat(Box<CreateBasicGroupChat>),
+    /// Creates a new call
+    CreateCall(Box<CreateCall>),
+    /// Creates new chat filter. Returns information about the created chat filter
+    CreateChatFilter(Box<CreateChatFilter>),
+    /// Creates a new invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat
+    CreateChatInviteLink(Box<CreateChatInviteLink>),
+    /// Creates a new basic group and sends a corresponding messageBasicGroupChatCreate. Returns the newly created chat
+    CreateNewBasicGroupChat(Box<CreateNewBasicGroupChat>),
+    /// Creates a new secret chat. Returns the newly created chat
+    CreateNewSecretChat(Box<CreateNewSecretChat>),
+    /// Creates a new sticker set. Returns the newly created sticker set
+    CreateNewStickerSet(Box<CreateNewStickerSet>),
+    /// Creates a new supergroup or channel and sends a corresponding messageSupergroupChatCreate. Returns the newly created chat
+    CreateNewSupergroupChat(Box<CreateNewSupergroupChat>),
+    /// Returns an existing chat corresponding to a given user
+    CreatePrivateChat(Box<CreatePrivateChat>),
+    /// Returns an existing chat corresponding to a known secret chat
+    CreateSecretChat(Box<CreateSecretChat>),
+    /// Returns an existing chat corresponding to a known supergroup or channel
+    CreateSupergroupChat(Box<CreateSupergroupChat>),
+    /// Creates a new temporary password for processing payments
+    CreateTemporaryPassword(Box<CreateTemporaryPassword>),
+    /// Creates a video chat (a group call bound to a chat). Available only for basic groups, supergroups and channels; requires can_manage_video_chats rights
+    CreateVideoChat(Box<CreateVideoChat>),
+    /// Deletes the account of the current user, deleting all information associated with the user from the server. The phone number of the account can be used to create a new account. Can be called before authorization when the current authorization state is authorizationStateWaitPassword
+    DeleteAccount(Box<DeleteAccount>),
+    /// Deletes all call messages
+    DeleteAllCallMessages(Box<DeleteAllCallMessages>),
+    /// Deletes all revoked chat invite links created by a given chat administrator. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links
+    DeleteAllRevokedChatInviteLinks(Box<DeleteAllRevokedChatInviteLinks>),
+    /// Deletes a chat along with all messages in the corresponding chat for all chat members; requires owner privileges. For group chats this will release the username and remove all members. Chats with more than 1000 members can't be deleted using this method
+    DeleteChat(Box<DeleteChat>),
+    /// Deletes existing chat filter
+    DeleteChatFilter(Box<DeleteChatFilter>),
+    /// Deletes all messages in the chat. Use chat.can_be_deleted_only_for_self and chat.can_be_deleted_for_all_users fields to find whether and how the method can be applied to the chat
+    DeleteChatHistory(Box<DeleteChatHistory>),
+    /// Deletes all messages between the specified dates in a chat. Supported only for private chats and basic groups. Messages sent in the last 30 seconds will not be deleted
+    DeleteChatMessagesByDate(Box<DeleteChatMessagesByDate>),
+    /// Deletes all messages sent by the specified message sender in a chat. Supported only for supergroups; requires can_delete_messages administrator privileges
+    DeleteChatMessagesBySender(Box<DeleteChatMessagesBySender>),
+    /// Deletes the default reply markup from a chat. Must be called after a one-time keyboard or a ForceReply reply markup has been used. UpdateChatReplyMarkup will be sent if the reply markup is changed
+    DeleteChatReplyMarkup(Box<DeleteChatReplyMarkup>),
+    /// Deletes commands supported by the bot for the given user scope and language; for bots only
+    DeleteCommands(Box<DeleteCommands>),
+    /// Deletes a file from the TDLib file cache
+    DeleteFile(Box<DeleteFile>),
+    /// Deletes all information about a language pack in the current localization target. The language pack which is currently in use (including base language pack) or is being synchronized can't be deleted. Can be called before authorization
+    DeleteLanguagePack(Box<DeleteLanguagePack>),
+    /// Deletes messages
+    DeleteMessages(Box<DeleteMessages>),
+    /// Deletes a Telegram Passport element
+    DeletePassportElement(Box<DeletePassportElement>),
+    /// Deletes a profile photo
+    DeleteProfilePhoto(Box<DeleteProfilePhoto>),
+    /// Deletes revoked chat invite links. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links
+    DeleteRevokedChatInviteLink(Box<DeleteRevokedChatInviteLink>),
+    /// Deletes saved credentials for all payment provider bots
+    DeleteSavedCredentials(Box<DeleteSavedCredentials>),
+    /// Deletes saved order info
+    DeleteSavedOrderInfo(Box<DeleteSavedOrderInfo>),
+    /// Closes the TDLib instance, destroying all local data without a proper logout. The current user session will remain in the list of all active sessions. All local data will be destroyed. After the destruction completes updateAuthorizationState with authorizationStateClosed will be sent. Can be called before authorization
+    Destroy(Box<Destroy>),
+    /// Disables the currently enabled proxy. Can be called before authorization
+    DisableProxy(Box<DisableProxy>),
+    /// Discards a call
+    DiscardCall(Box<DiscardCall>),
+    /// Disconnects all websites from the current user's Telegram account
+    DisconnectAllWebsites(Box<DisconnectAllWebsites>),
+    /// Disconnects website from the current user's Telegram account
+    DisconnectWebsite(Box<DisconnectWebsite>),
+    /// Downloads a file from the cloud. Download progress and completion of the download will be notified through updateFile updates
+    DownloadFile(Box<DownloadFile>),
+    /// Edits existing chat filter. Returns information about the edited chat filter
+    EditChatFilter(Box<EditChatFilter>),
+    /// Edits a non-primary invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links
+    EditChatInviteLink(Box<EditChatInviteLink>),
+    /// Edits information about a custom local language pack in the current localization target. Can be called before authorization
+    EditCustomLanguagePackInfo(Box<EditCustomLanguagePackInfo>),
+    /// Edits the caption of an inline message sent via a bot; for bots only
+    EditInlineMessageCaption(Box<EditInlineMessageCaption>),
+    /// Edits the content of a live location in an inline message sent via a bot; for bots only
+    EditInlineMessageLiveLocation(Box<EditInlineMessageLiveLocation>),
+    /// Edits the content of a message with an animation, an audio, a document, a photo or a video in an inline message sent via a bot; for bots only
+    EditInlineMessageMedia(Box<EditInlineMessageMedia>),
+    /// Edits the reply markup of an inline message sent via a bot; for bots only
+    EditInlineMessageReplyMarkup(Box<EditInlineMessageReplyMarkup>),
+    /// Edits the text of an inline text or game message sent via a bot; for bots only
+    EditInlineMessageText(Box<EditInlineMessageText>),
+    /// Edits the message content caption. Returns the edited message after the edit is completed on the server side
+    EditMessageCaption(Box<EditMessageCaption>),
+    /// Edits the message content of a live location. Messages can be edited for a limited period of time specified in the live location. Returns the edited message after the edit is completed on the server side
+    EditMessageLiveLocation(Box<EditMessageLiveLocation>),
+    /// Edits the content of a message with an animation, an audio, a document, a photo or a video, including message caption. If only the caption needs to be edited, use editMessageCaption instead. The media can't be edited if the message was set to self-destruct or to a self-destructing media. The type of message content in an album can't be changed with exception of replacing a photo with a video or vice versa. Returns the edited message after the edit is completed on the server side
+    EditMessageMedia(Box<EditMessageMedia>),
+    /// Edits the message reply markup; for bots only. Returns the edited message after the edit is completed on the server side
+    EditMessageReplyMarkup(Box<EditMessageReplyMarkup>),
+    /// Edits the time when a scheduled message will be sent. Scheduling state of all messages in the same album or forwarded together with the message will be also changed
+    EditMessageSchedulingState(Box<EditMessageSchedulingState>),
+    /// Edits the text of a message (or a text of a game message). Returns the edited message after the edit is completed on the server side
+    EditMessageText(Box<EditMessageText>),
+    /// Edits an existing proxy server for network requests. Can be called before authorization
+    EditProxy(Box<EditProxy>),
+    /// Enables a proxy. Only one proxy can be enabled at a time. Can be called before authorization
+    EnableProxy(Box<EnableProxy>),
+    /// Ends a group call. Requires groupCall.can_be_managed
+    EndGroupCall(Box<EndGroupCall>),
+    /// Ends recording of an active group call. Requires groupCall.can_be_managed group call flag
+    EndGroupCallRecording(Box<EndGroupCallRecording>),
+    /// Ends screen sharing in a joined group call
+    EndGroupCallScreenSharing(Box<EndGroupCallScreenSharing>),
+    /// Finishes the file generation
+    FinishFileGeneration(Box<FinishFileGeneration>),
+    /// Forwards previously sent messages. Returns the forwarded messages in the same order as the message identifiers passed in message_ids. If a message can't be forwarded, null will be returned instead of the message
+    ForwardMessages(Box<ForwardMessages>),
+    /// Returns the period of inactivity after which the account of the current user will automatically be deleted
+    GetAccountTtl(Box<GetAccountTtl>),
+    /// Returns all active live locations that need to be updated by the application. The list is persistent across application restarts only if the message database is used
+    GetActiveLiveLocationMessages(Box<GetActiveLiveLocationMessages>),
+    /// Returns all active sessions of the current user
+    GetActiveSessions(Box<GetActiveSessions>),
+    /// Returns all available Telegram Passport elements
+    GetAllPassportElements(Box<GetAllPassportElements>),
+    /// Returns an animated emoji corresponding to a given emoji. Returns a 404 error if the emoji has no animated emoji
+    GetAnimatedEmoji(Box<GetAnimatedEmoji>),
+    /// Returns application config, provided by the server. Can be called before authorization
+    GetApplicationConfig(Box<GetApplicationConfig>),
+    /// Returns the link for downloading official Telegram application to be used when the current user invites friends to Telegram
+    GetApplicationDownloadLink(Box<GetApplicationDownloadLink>),
+    /// Returns a list of archived sticker sets
+    GetArchivedStickerSets(Box<GetArchivedStickerSets>),
+    /// Returns a list of sticker sets attached to a file. Currently, only photos and videos can have attached sticker sets
+    GetAttachedStickerSets(Box<GetAttachedStickerSets>),
+    /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
+    GetAuthorizationState(Box<GetAuthorizationState>),
+    /// Returns auto-download settings presets for the current user
+    GetAutoDownloadSettingsPresets(Box<GetAutoDownloadSettingsPresets>),
+    /// Constructs a persistent HTTP URL for a background
+    GetBackgroundUrl(Box<GetBackgroundUrl>),
+    /// Returns backgrounds installed by the user
+    GetBackgrounds(Box<GetBackgrounds>),
+    /// Returns information about a bank card
+    GetBankCardInfo(Box<GetBankCardInfo>),
+    /// Returns information about a basic group by its identifier. This is an offline request if the current user is not a bot
+    GetBasicGroup(Box<GetBasicGroup>),
+    /// Returns full information about a basic group by its identifier
+    GetBasicGroupFullInfo(Box<GetBasicGroupFullInfo>),
+    /// Returns users and chats that were blocked by the current user
+    GetBlockedMessageSenders(Box<GetBlockedMessageSenders>),
+    /// Sends a callback query to a bot and returns an answer. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires
+    GetCallbackQueryAnswer(Box<GetCallbackQueryAnswer>),
+    /// Returns information about a message with the callback button that originated a callback query; for bots only
+    GetCallbackQueryMessage(Box<GetCallbackQueryMessage>),
+    /// Returns information about a chat by its identifier, this is an offline request if the current user is not a bot
+    GetChat(Box<GetChat>),
+    /// Returns a list of administrators of the chat with their custom titles
+    GetChatAdministrators(Box<GetChatAdministrators>),
+    /// Returns list of message sender identifiers, which can be used to send messages in a chat
+    GetChatAvailableMessageSenders(Box<GetChatAvailableMessageSenders>),
+    /// Returns a list of service actions taken by chat members and administrators in the last 48 hours. Available only for supergroups and channels. Requires administrator rights. Returns results in reverse chronological order (i. e., in order of decreasing event_id)
+    GetChatEventLog(Box<GetChatEventLog>),
+    /// Returns information about a chat filter by its identifier
+    GetChatFilter(Box<GetChatFilter>),
+    /// Returns default icon name for a filter. Can be called synchronously
+    GetChatFilterDefaultIconName(Box<GetChatFilterDefaultIconName>),
+    /// Returns messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib. This is an offline request if only_local is true
+    GetChatHistory(Box<GetChatHistory>),
+    /// Returns information about an invite link. Requires administrator privileges and can_invite_users right in the chat to get own links and owner privileges to get other links
+    GetChatInviteLink(Box<GetChatInviteLink>),
+    /// Returns list of chat administrators with number of their invite links. Requires owner privileges in the chat
+    GetChatInviteLinkCounts(Box<GetChatInviteLinkCounts>),
+    /// Returns chat members joined a chat via an invite link. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links
+    GetChatInviteLinkMembers(Box<GetChatInviteLinkMembers>),
+    /// Returns invite links for a chat created by specified administrator. Requires administrator privileges and can_invite_users right in the chat to get own links and owner privileges to get other links
+    GetChatInviteLinks(Box<GetChatInviteLinks>),
+    /// Returns pending join requests in a chat
+    GetChatJoinRequests(Box<GetChatJoinRequests>),
+    /// Returns chat lists to which the chat can be added. This is an offline request
+    GetChatListsToAddChat(Box<GetChatListsToAddChat>),
+    /// Returns information about a single member of a chat
+    GetChatMember(Box<GetChatMember>),
+    /// Returns the last message sent in a chat no later than the specified date
+    GetChatMessageByDate(Box<GetChatMessageByDate>),
+    /// Returns information about the next messages of the specified type in the chat split by days. Returns the results in reverse chronological order. Can return partial result for the last returned day. Behavior of this method depends on the value of the option "utc_time_offset"
+    GetChatMessageCalendar(Box<GetChatMessageCalendar>),
+    /// Returns approximate number of messages of the specified type in the chat
+    GetChatMessageCount(Box<GetChatMessageCount>),
+    /// Returns list of chats with non-default notification settings
+    GetChatNotificationSettingsExceptions(Box<GetChatNotificationSettingsExceptions>),
+    /// Returns information about a newest pinned message in the chat
+    GetChatPinnedMessage(Box<GetChatPinnedMessage>),
+    /// Returns all scheduled messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id)
+    GetChatScheduledMessages(Box<GetChatScheduledMessages>),
+    /// Returns sparse positions of messages of the specified type in the chat to be used for shared media scroll implementation. Returns the results in reverse chronological order (i.e., in order of decreasing message_id). Cannot be used in secret chats or with searchMessagesFilterFailedToSend filter without an enabled message database
+    GetChatSparseMessagePositions(Box<GetChatSparseMessagePositions>),
+    /// Returns sponsored message to be shown in a chat; for channel chats only. Returns a 404 error if there is no sponsored message in the chat
+    GetChatSponsoredMessage(Box<GetChatSponsoredMessage>),
+    /// Returns detailed statistics about a chat. Currently, this method can be used only for supergroups and channels. Can be used only if supergroupFullInfo.can_get_statistics == true
+    GetChatStatistics(Box<GetChatStatistics>),
+    /// Returns an ordered list of chats from the beginning of a chat list. For informational purposes only. Use loadChats and updates processing instead to maintain chat lists in a consistent state
+    GetChats(Box<GetChats>),
+    /// Returns the list of commands supported by the bot for the given user scope and language; for bots only
+    GetCommands(Box<GetCommands>),
+    /// Returns all website where the current user used Telegram to log in
+    GetConnectedWebsites(Box<GetConnectedWebsites>),
+    /// Returns all user contacts
+    GetContacts(Box<GetContacts>),
+    /// Returns information about existing countries. Can be called before authorization
+    GetCountries(Box<GetCountries>),
+    /// Uses the current IP address to find the current country. Returns two-letter ISO 3166-1 alpha-2 country code. Can be called before authorization
+    GetCountryCode(Box<GetCountryCode>),
+    /// Returns a list of public chats of the specified type, owned by the user
+    GetCreatedPublicChats(Box<GetCreatedPublicChats>),
+    /// Returns all updates needed to restore current TDLib state, i.e. all actual UpdateAuthorizationState/UpdateUser/UpdateNewChat and others. This is especially useful if TDLib is run in a separate process. Can be called before initialization
+    GetCurrentState(Box<GetCurrentState>),
+    /// Returns database statistics
+    GetDatabaseStatistics(Box<GetDatabaseStatistics>),
+    /// Returns information about a tg:// deep link. Use "tg://need_update_for_some_feature" or "tg:some_unsupported_feature" for testing. Returns a 404 error for unknown links. Can be called before authorization
+    GetDeepLinkInfo(Box<GetDeepLinkInfo>),
+    /// Returns an HTTP URL which can be used to automatically log in to the translation platform and suggest new emoji replacements. The URL will be valid for 30 seconds after generation
+    GetEmojiSuggestionsUrl(Box<GetEmojiSuggestionsUrl>),
+    /// Returns an HTTP URL which can be used to automatically authorize the current user on a website after clicking an HTTP link. Use the method getExternalLinkInfo to find whether a prior user confirmation is needed
+    GetExternalLink(Box<GetExternalLink>),
+    /// Returns information about an action to be done when the current user clicks an external link. Don't use this method for links from secret chats if web page preview is disabled in secret chats
+    GetExternalLinkInfo(Box<GetExternalLinkInfo>),
+    /// Returns favorite stickers
+    GetFavoriteStickers(Box<GetFavoriteStickers>),
+    /// Returns information about a file; this is an offline request
+    GetFile(Box<GetFile>),
+    /// Returns file downloaded prefix size from a given offset, in bytes
+    GetFileDownloadedPrefixSize(Box<GetFileDownloadedPrefixSize>),
+    /// Returns the extension of a file, guessed by its MIME type. Returns an empty string on failure. Can be called synchronously
+    GetFileExtension(Box<GetFileExtension>),
+    /// Returns the MIME type of a file, guessed by its extension. Returns an empty string on failure. Can be called synchronously
+    GetFileMimeType(Box<GetFileMimeType>),
+    /// Returns the high scores for a game and some part of the high score table in the range of the specified user; for bots only
+    GetGameHighScores(Box<GetGameHighScores>),
+    /// Returns information about a group call
+    GetGroupCall(Box<GetGroupCall>),
+    /// Returns invite link to a video chat in a public chat
+    GetGroupCallInviteLink(Box<GetGroupCallInviteLink>),
+    /// Returns a file with a segment of a group call stream in a modified OGG format for audio or MPEG-4 format for video
+    GetGroupCallStreamSegment(Box<GetGroupCallStreamSegment>),
+    /// Returns a list of common group chats with a given user. Chats are sorted by their type and creation date
+    GetGroupsInCommon(Box<GetGroupsInCommon>),
+    /// Returns the total number of imported contacts
+    GetImportedContactCount(Box<GetImportedContactCount>),
+    /// Returns a list of recently inactive supergroups and channels. Can be used when user reaches limit on the number of joined supergroups and channels and receives CHANNELS_TOO_MUCH error
+    GetInactiveSupergroupChats(Box<GetInactiveSupergroupChats>),
+    /// Returns game high scores and some part of the high score table in the range of the specified user; for bots only
+    GetInlineGameHighScores(Box<GetInlineGameHighScores>),
+    /// Sends an inline query to a bot and returns its results. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires
+    GetInlineQueryResults(Box<GetInlineQueryResults>),
+    /// Returns a list of installed sticker sets
+    GetInstalledStickerSets(Box<GetInstalledStickerSets>),
+    /// Returns information about the type of an internal link. Returns a 404 error if the link is not internal. Can be called before authorization
+    GetInternalLinkType(Box<GetInternalLinkType>),
+    /// Converts a JsonValue object to corresponding JSON-serialized string. Can be called synchronously
+    GetJsonString(Box<GetJsonString>),
+    /// Converts a JSON-serialized string to corresponding JsonValue object. Can be called synchronously
+    GetJsonValue(Box<GetJsonValue>),
+    /// Returns information about a language pack. Returned language pack identifier may be different from a provided one. Can be called before authorization
+    GetLanguagePackInfo(Box<GetLanguagePackInfo>),
+    /// Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. Can be called synchronously
+    GetLanguagePackString(Box<GetLanguagePackString>),
+    /// Returns strings from a language pack in the current localization target by their keys. Can be called before authorization
+    GetLanguagePackStrings(Box<GetLanguagePackStrings>),
+    /// Returns information about the current localization target. This is an offline request if only_local is true. Can be called before authorization
+    GetLocalizationTargetInfo(Box<GetLocalizationTargetInfo>),
+    /// Returns information about currently used log stream for internal logging of TDLib. Can be called synchronously
+    GetLogStream(Box<GetLogStream>),
+    /// Returns current verbosity level for a specified TDLib internal log tag. Can be called synchronously
+    GetLogTagVerbosityLevel(Box<GetLogTagVerbosityLevel>),
+    /// Returns list of available TDLib internal log tags, for example, ["actor", "binlog", "connections", "notifications", "proxy"]. Can be called synchronously
+    GetLogTags(Box<GetLogTags>),
+    /// Returns current verbosity level of the internal logging of TDLib. Can be called synchronously
+    GetLogVerbosityLevel(Box<GetLogVerbosityLevel>),
+    /// Returns an HTTP URL which can be used to automatically authorize the user on a website after clicking an inline button of type inlineKeyboardButtonTypeLoginUrl. Use the method getLoginUrlInfo to find whether a prior user confirmation is needed. If an error is returned, then the button must be handled as an ordinary URL button
+    GetLoginUrl(Box<GetLoginUrl>),
+    /// Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button
+    GetLoginUrlInfo(Box<GetLoginUrlInfo>),
+    /// Returns information about a file with a map thumbnail in PNG format. Only map thumbnail files with size less than 1MB can be downloaded
+    GetMapThumbnailFile(Box<GetMapThumbnailFile>),
+    /// Replaces text entities with Markdown formatting in a human-friendly format. Entities that can't be represented in Markdown unambiguously are kept as is. Can be called synchronously
+    GetMarkdownText(Box<GetMarkdownText>),
+    /// Returns the current user
+    GetMe(Box<GetMe>),
+    /// Returns information about a message
+    GetMessage(Box<GetMessage>),
+    /// Returns an HTML code for embedding the message. Available only for messages in supergroups and channels with a username
+    GetMessageEmbeddingCode(Box<GetMessageEmbeddingCode>),
+    /// Returns information about a file with messages exported from another app
+    GetMessageFileType(Box<GetMessageFileType>),
+    /// Returns a confirmation text to be shown to the user before starting message import
+    GetMessageImportConfirmationText(Box<GetMessageImportConfirmationText>),
+    /// Returns an HTTPS link to a message in a chat. Available only for already sent messages in supergroups and channels, or if message.can_get_media_timestamp_links and a media timestamp link is generated. This is an offline request
+    GetMessageLink(Box<GetMessageLink>),
+    /// Returns information about a public or private message link. Can be called for any internal link of the type internalLinkTypeMessage
+    GetMessageLinkInfo(Box<GetMessageLinkInfo>),
+    /// Returns information about a message, if it is available locally without sending network request. This is an offline request
+    GetMessageLocally(Box<GetMessageLocally>),
+    /// Returns forwarded copies of a channel message to different public channels. For optimal performance, the number of returned messages is chosen by TDLib
+    GetMessagePublicForwards(Box<GetMessagePublicForwards>),
+    /// Returns detailed statistics about a message. Can be used only if message.can_get_statistics == true
+    GetMessageStatistics(Box<GetMessageStatistics>),
+    /// Returns information about a message thread. Can be used only if message.can_get_message_thread == true
+    GetMessageThread(Box<GetMessageThread>),
+    /// Returns messages in a message thread of a message. Can be used only if message.can_get_message_thread == true. Message thread of a channel message is in the channel's linked supergroup. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib
+    GetMessageThreadHistory(Box<GetMessageThreadHistory>),
+    /// Returns viewers of a recent outgoing message in a basic group or a supergroup chat. For video notes and voice notes only users, opened content of the message, are returned. The method can be called if message.can_get_viewers == true
+    GetMessageViewers(Box<GetMessageViewers>),
+    /// Returns information about messages. If a message is not found, returns null on the corresponding position of the result
+    GetMessages(Box<GetMessages>),
+    /// Returns network data usage statistics. Can be called before authorization
+    GetNetworkStatistics(Box<GetNetworkStatistics>),
+    /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
+    GetOption(Box<GetOption>),
+    /// Returns a Telegram Passport authorization form for sharing data with a service
+    GetPassportAuthorizationForm(Box<GetPassportAuthorizationForm>),
+    /// Returns already available Telegram Passport elements suitable for completing a Telegram Passport authorization form. Result can be received only once for each authorization form
+    GetPassportAuthorizationFormAvailableElements(
+        Box<GetPassportAuthorizationFormAvailableElements>,
+    ),
+    /// Returns one of the available Telegram Passport elements
+    GetPassportElement(Box<GetPassportElement>),
+    /// Returns the current state of 2-step verification
+    GetPasswordState(Box<GetPasswordState>),
+    /// Returns an invoice payment form. This method must be called when the user presses inlineKeyboardButtonBuy
+    GetPaymentForm(Box<GetPaymentForm>),
+    /// Returns information about a successful payment
+    GetPaymentReceipt(Box<GetPaymentReceipt>),
+    /// Returns information about a phone number by its prefix. Can be called before authorization
+    GetPhoneNumberInfo(Box<GetPhoneNumberInfo>),
+    /// Returns information about a phone number by its prefix synchronously. getCountries must be called at least once after changing localization to the specified language if properly localized country information is expected. Can be called synchronously
+    GetPhoneNumberInfoSync(Box<GetPhoneNumberInfoSync>),
+    /// Returns users voted for the specified option in a non-anonymous polls. For optimal performance, the number of returned users is chosen by TDLib
+    GetPollVoters(Box<GetPollVoters>),
+    /// Returns an IETF language tag of the language preferred in the country, which must be used to fill native fields in Telegram Passport personal details. Returns a 404 error if unknown
+    GetPreferredCountryLanguage(Box<GetPreferredCountryLanguage>),
+    /// Returns list of proxies that are currently set up. Can be called before authorization
+    GetProxies(Box<GetProxies>),
+    /// Returns an HTTPS link, which can be used to add a proxy. Available only for SOCKS5 and MTProto proxies. Can be called before authorization
+    GetProxyLink(Box<GetProxyLink>),
+    /// Returns a globally unique push notification subscription identifier for identification of an account, which has received a push notification. Can be called synchronously
+    GetPushReceiverId(Box<GetPushReceiverId>),
+    /// Returns up to 20 recently used inline bots in the order of their last usage
+    GetRecentInlineBots(Box<GetRecentInlineBots>),
+    /// Returns a list of recently used stickers
+    GetRecentStickers(Box<GetRecentStickers>),
+    /// Returns recently opened chats, this is an offline request. Returns chats in the order of last opening
+    GetRecentlyOpenedChats(Box<GetRecentlyOpenedChats>),
+    /// Returns t.me URLs recently visited by a newly registered user
+    GetRecentlyVisitedTMeUrls(Box<GetRecentlyVisitedTMeUrls>),
+    /// Returns recommended chat filters for the current user
+    GetRecommendedChatFilters(Box<GetRecommendedChatFilters>),
+    /// Returns a 2-step verification recovery email address that was previously set up. This method can be used to verify a password provided by the user
+    GetRecoveryEmailAddress(Box<GetRecoveryEmailAddress>),
+    /// Returns information about a file by its remote ID; this is an offline request. Can be used to register a URL as a file for further uploading, or sending as a message. Even the request succeeds, the file can be used only if it is still accessible to the user. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application
+    GetRemoteFile(Box<GetRemoteFile>),
+    /// Returns information about a message that is replied by a given message. Also returns the pinned message, the game message, and the invoice message for messages of the types messagePinMessage, messageGameScore, and messagePaymentSuccessful respectively
+    GetRepliedMessage(Box<GetRepliedMessage>),
+    /// Returns saved animations
+    GetSavedAnimations(Box<GetSavedAnimations>),
+    /// Returns saved order info, if any
+    GetSavedOrderInfo(Box<GetSavedOrderInfo>),
+    /// Returns the notification settings for chats of a given type
+    GetScopeNotificationSettings(Box<GetScopeNotificationSettings>),
+    /// Returns information about a secret chat by its identifier. This is an offline request
+    GetSecretChat(Box<GetSecretChat>),
+    /// Loads an asynchronous or a zoomed in statistical graph
+    GetStatisticalGraph(Box<GetStatisticalGraph>),
+    /// Returns emoji corresponding to a sticker. The list is only for informational purposes, because a sticker is always sent with a fixed emoji from the corresponding Sticker object
+    GetStickerEmojis(Box<GetStickerEmojis>),
+    /// Returns information about a sticker set by its identifier
+    GetStickerSet(Box<GetStickerSet>),
+    /// Returns stickers from the installed sticker sets that correspond to a given emoji. If the emoji is non-empty, favorite and recently used stickers may also be returned
+    GetStickers(Box<GetStickers>),
+    /// Returns storage usage statistics. Can be called before authorization
+    GetStorageStatistics(Box<GetStorageStatistics>),
+    /// Quickly returns approximate storage usage statistics. Can be called before authorization
+    GetStorageStatisticsFast(Box<GetStorageStatisticsFast>),
+    /// Returns suggested name for saving a file in a given directory
+    GetSuggestedFileName(Box<GetSuggestedFileName>),
+    /// Returns a suggested name for a new sticker set with a given title
+    GetSuggestedStickerSetName(Box<GetSuggestedStickerSetName>),
+    /// Returns a list of basic group and supergroup chats, which can be used as a discussion group for a channel. Returned basic group chats must be first upgraded to supergroups before they can be set as a discussion group. To set a returned supergroup as a discussion group, access to its old messages must be enabled using toggleSupergroupIsAllHistoryAvailable first
+    GetSuitableDiscussionChats(Box<GetSuitableDiscussionChats>),
+    /// Returns information about a supergroup or a channel by its identifier. This is an offline request if the current user is not a bot
+    GetSupergroup(Box<GetSupergroup>),
+    /// Returns full information about a supergroup or a channel by its identifier, cached for up to 1 minute
+    GetSupergroupFullInfo(Box<GetSupergroupFullInfo>),
+    /// Returns information about members or banned users in a supergroup or channel. Can be used only if supergroupFullInfo.can_get_members == true; additionally, administrator privileges may be required for some filters
+    GetSupergroupMembers(Box<GetSupergroupMembers>),
+    /// Returns a user that can be contacted to get support
+    GetSupportUser(Box<GetSupportUser>),
+    /// Returns information about the current temporary password
+    GetTemporaryPasswordState(Box<GetTemporaryPasswordState>),
+    /// Returns all entities (mentions, hashtags, cashtags, bot commands, bank card numbers, URLs, and email addresses) contained in the text. Can be called synchronously
+    GetTextEntities(Box<GetTextEntities>),
+    /// Returns a list of frequently used chats. Supported only if the chat info database is enabled
+    GetTopChats(Box<GetTopChats>),
+    /// Returns a list of trending sticker sets. For optimal performance, the number of returned sticker sets is chosen by TDLib
+    GetTrendingStickerSets(Box<GetTrendingStickerSets>),
+    /// Returns information about a user by their identifier. This is an offline request if the current user is not a bot
+    GetUser(Box<GetUser>),
+    /// Returns full information about a user by their identifier
+    GetUserFullInfo(Box<GetUserFullInfo>),
+    /// Returns the current privacy settings
+    GetUserPrivacySettingRules(Box<GetUserPrivacySettingRules>),
+    /// Returns the profile photos of a user. The result of this query may be outdated: some photos might have been deleted already
+    GetUserProfilePhotos(Box<GetUserProfilePhotos>),
+    /// Returns list of participant identifiers, on whose behalf a video chat in the chat can be joined
+    GetVideoChatAvailableParticipants(Box<GetVideoChatAvailableParticipants>),
+    /// Returns an instant view version of a web page if available. Returns a 404 error if the web page has no instant view page
+    GetWebPageInstantView(Box<GetWebPageInstantView>),
+    /// Returns a web page preview by the text of the message. Do not call this function too often. Returns a 404 error if the web page has no preview
+    GetWebPagePreview(Box<GetWebPagePreview>),
+    /// Hides a suggested action
+    HideSuggestedAction(Box<HideSuggestedAction>),
+    /// Adds new contacts or edits existing contacts by their phone numbers; contacts' user identifiers are ignored
+    ImportContacts(Box<ImportContacts>),
+    /// Imports messages exported from another app
+    ImportMessages(Box<ImportMessages>),
+    /// Invites users to an active group call. Sends a service message of type messageInviteToGroupCall for video chats
+    InviteGroupCallParticipants(Box<InviteGroupCallParticipants>),
+    /// Adds the current user as a new member to a chat. Private and secret chats can't be joined using this method
+    JoinChat(Box<JoinChat>),
+    /// Uses an invite link to add the current user to the chat if possible
+    JoinChatByInviteLink(Box<JoinChatByInviteLink>),
+    /// Joins an active group call. Returns join response payload for tgcalls
+    JoinGroupCall(Box<JoinGroupCall>),
+    /// Removes the current user from chat members. Private and secret chats can't be left using this method
+    LeaveChat(Box<LeaveChat>),
+    /// Leaves a group call
+    LeaveGroupCall(Box<LeaveGroupCall>),
+    /// Loads more chats from a chat list. The loaded chats and their positions in the chat list will be sent through updates. Chats are sorted by the pair (chat.position.order, chat.id) in descending order. Returns a 404 error if all chats have been loaded
+    LoadChats(Box<LoadChats>),
+    /// Loads more participants of a group call. The loaded participants will be received through updates. Use the field groupCall.loaded_all_participants to check whether all participants have already been loaded
+    LoadGroupCallParticipants(Box<LoadGroupCallParticipants>),
+    /// Closes the TDLib instance after a proper logout. Requires an available network connection. All local data will be destroyed. After the logout completes, updateAuthorizationState with authorizationStateClosed will be sent
+    LogOut(Box<LogOut>),
+    /// Informs TDLib that the chat is opened by the user. Many useful activities depend on the chat being opened or closed (e.g., in supergroups and channels all updates are received only for opened chats)
+    OpenChat(Box<OpenChat>),
+    /// Informs TDLib that the message content has been opened (e.g., the user has opened a photo, video, document, location or venue, or has listened to an audio file or voice note message). An updateMessageContentOpened update will be generated if something has changed
+    OpenMessageContent(Box<OpenMessageContent>),
+    /// Optimizes storage usage, i.e. deletes some files and returns new storage usage statistics. Secret thumbnails can't be deleted
+    OptimizeStorage(Box<OptimizeStorage>),
+    /// Parses Markdown entities in a human-friendly format, ignoring markup errors. Can be called synchronously
+    ParseMarkdown(Box<ParseMarkdown>),
+    /// Parses Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities contained in the text. Can be called synchronously
+    ParseTextEntities(Box<ParseTextEntities>),
+    /// Pins a message in a chat; requires can_pin_messages rights or can_edit_messages rights in the channel
+    PinChatMessage(Box<PinChatMessage>),
+    /// Computes time needed to receive a response from a Telegram server through a proxy. Can be called before authorization
+    PingProxy(Box<PingProxy>),
+    /// Handles a pending join request in a chat
+    ProcessChatJoinRequest(Box<ProcessChatJoinRequest>),
+    /// Handles all pending join requests for a given link in a chat
+    ProcessChatJoinRequests(Box<ProcessChatJoinRequests>),
+    /// Handles a push notification. Returns error with code 406 if the push notification is not supported and connection to the server is required to fetch new data. Can be called before authorization
+    ProcessPushNotification(Box<ProcessPushNotification>),
+    /// Marks all mentions in a chat as read
+    ReadAllChatMentions(Box<ReadAllChatMentions>),
+    /// Reads a part of a file from the TDLib file cache and returns read bytes. This method is intended to be used only if the application has no direct access to TDLib's file system, because it is usually slower than a direct read from the file
+    ReadFilePart(Box<ReadFilePart>),
+    /// Recovers the password with a password recovery code sent to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword
+    RecoverAuthenticationPassword(Box<RecoverAuthenticationPassword>),
+    /// Recovers the 2-step verification password using a recovery code sent to an email address that was previously set up
+    RecoverPassword(Box<RecoverPassword>),
+    /// Registers the currently used device for receiving push notifications. Returns a globally unique identifier of the push notification subscription
+    RegisterDevice(Box<RegisterDevice>),
+    /// Finishes user registration. Works only when the current authorization state is authorizationStateWaitRegistration
+    RegisterUser(Box<RegisterUser>),
+    /// Removes background from the list of installed backgrounds
+    RemoveBackground(Box<RemoveBackground>),
+    /// Removes a chat action bar without any other action
+    RemoveChatActionBar(Box<RemoveChatActionBar>),
+    /// Removes users from the contact list
+    RemoveContacts(Box<RemoveContacts>),
+    /// Removes a sticker from the list of favorite stickers
+    RemoveFavoriteSticker(Box<RemoveFavoriteSticker>),
+    /// Removes an active notification from notification list. Needs to be called only if the notification is removed by the current user
+    RemoveNotification(Box<RemoveNotification>),
+    /// Removes a group of active notifications. Needs to be called only if the notification group is removed by the current user
+    RemoveNotificationGroup(Box<RemoveNotificationGroup>),
+    /// Removes a proxy server. Can be called before authorization
+    RemoveProxy(Box<RemoveProxy>),
+    /// Removes a hashtag from the list of recently used hashtags
+    RemoveRecentHashtag(Box<RemoveRecentHashtag>),
+    /// Removes a sticker from the list of recently used stickers
+    RemoveRecentSticker(Box<RemoveRecentSticker>),
+    /// Removes a chat from the list of recently found chats
+    RemoveRecentlyFoundChat(Box<RemoveRecentlyFoundChat>),
+    /// Removes an animation from the list of saved animations
+    RemoveSavedAnimation(Box<RemoveSavedAnimation>),
+    /// Removes a sticker from the set to which it belongs; for bots only. The sticker set must have been created by the bot
+    RemoveStickerFromSet(Box<RemoveStickerFromSet>),
+    /// Removes a chat from the list of frequently used chats. Supported only if the chat info database is enabled
+    RemoveTopChat(Box<RemoveTopChat>),
+    /// Changes the order of chat filters
+    ReorderChatFilters(Box<ReorderChatFilters>),
+    /// Changes the order of installed sticker sets
+    ReorderInstalledStickerSets(Box<ReorderInstalledStickerSets>),
+    /// Replaces current primary invite link for a chat with a new primary invite link. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right
+    ReplacePrimaryChatInviteLink(Box<ReplacePrimaryChatInviteLink>),
+    /// Reports a chat to the Telegram moderators. A chat can be reported only from the chat action bar, or if chat.can_be_reported
+    ReportChat(Box<ReportChat>),
+    /// Reports a chat photo to the Telegram moderators. A chat photo can be reported only if chat.can_be_reported
+    ReportChatPhoto(Box<ReportChatPhoto>),
+    /// Reports messages in a supergroup as spam; requires administrator rights in the supergroup
+    ReportSupergroupSpam(Box<ReportSupergroupSpam>),
+    /// Requests to send a password recovery code to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword
+    RequestAuthenticationPasswordRecovery(Box<RequestAuthenticationPasswordRecovery>),
+    /// Requests to send a 2-step verification password recovery code to an email address that was previously set up
+    RequestPasswordRecovery(Box<RequestPasswordRecovery>),
+    /// Requests QR code authentication by scanning a QR code on another logged in device. Works only when the current authorization state is authorizationStateWaitPhoneNumber, or if there is no pending authentication query and the current authorization state is authorizationStateWaitCode, authorizationStateWaitRegistration, or authorizationStateWaitPassword
+    RequestQrCodeAuthentication(Box<RequestQrCodeAuthentication>),
+    /// Re-sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitCode, the next_code_type of the result is not null and the server-specified timeout has passed
+    ResendAuthenticationCode(Box<ResendAuthenticationCode>),
+    /// Re-sends the authentication code sent to confirm a new phone number for the current user. Works only if the previously received authenticationCodeInfo next_code_type was not null and the server-specified timeout has passed
+    ResendChangePhoneNumberCode(Box<ResendChangePhoneNumberCode>),
+    /// Re-sends the code to verify an email address to be added to a user's Telegram Passport
+    ResendEmailAddressVerificationCode(Box<ResendEmailAddressVerificationCode>),
+    /// Resends messages which failed to send. Can be called only for messages for which messageSendingStateFailed.can_retry is true and after specified in messageSendingStateFailed.retry_after time passed. If a message is re-sent, the corresponding failed to send message is deleted. Returns the sent messages in the same order as the message identifiers passed in message_ids. If a message can't be re-sent, null will be returned instead of the message
+    ResendMessages(Box<ResendMessages>),
+    /// Resends phone number confirmation code
+    ResendPhoneNumberConfirmationCode(Box<ResendPhoneNumberConfirmationCode>),
+    /// Re-sends the code to verify a phone number to be added to a user's Telegram Passport
+    ResendPhoneNumberVerificationCode(Box<ResendPhoneNumberVerificationCode>),
+    /// Resends the 2-step verification recovery email address verification code
+    ResendRecoveryEmailAddressCode(Box<ResendRecoveryEmailAddressCode>),
+    /// Resets all notification settings to their default values. By default, all chats are unmuted, the sound is set to "default" and message previews are shown
+    ResetAllNotificationSettings(Box<ResetAllNotificationSettings>),
+    /// Resets list of installed backgrounds to its default value
+    ResetBackgrounds(Box<ResetBackgrounds>),
+    /// Resets all network data usage statistics to zero. Can be called before authorization
+    ResetNetworkStatistics(Box<ResetNetworkStatistics>),
+    /// Removes 2-step verification password without previous password and access to recovery email address. The password can't be reset immediately and the request needs to be repeated after the specified time
+    ResetPassword(Box<ResetPassword>),
+    /// Revokes invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links. If a primary link is revoked, then additionally to the revoked link returns new primary link
+    RevokeChatInviteLink(Box<RevokeChatInviteLink>),
+    /// Revokes invite link for a group call. Requires groupCall.can_be_managed group call flag
+    RevokeGroupCallInviteLink(Box<RevokeGroupCallInviteLink>),
+    /// Saves application log event on the server. Can be called before authorization
+    SaveApplicationLogEvent(Box<SaveApplicationLogEvent>),
+    /// Searches for a background by its name
+    SearchBackground(Box<SearchBackground>),
+    /// Searches for call messages. Returns the results in reverse chronological order (i. e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib
+    SearchCallMessages(Box<SearchCallMessages>),
+    /// Searches for a specified query in the first name, last name and username of the members of a specified chat. Requires administrator rights in channels
+    SearchChatMembers(Box<SearchChatMembers>),
+    /// Searches for messages with given words in the chat. Returns the results in reverse chronological order, i.e. in order of decreasing message_id. Cannot be used in secret chats with a non-empty query (searchSecretMessages must be used instead), or without an enabled message database. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit
+    SearchChatMessages(Box<SearchChatMessages>),
+    /// Returns information about the recent locations of chat members that were sent to the chat. Returns up to 1 location message per user
+    SearchChatRecentLocationMessages(Box<SearchChatRecentLocationMessages>),
+    /// Searches for the specified query in the title and username of already known chats, this is an offline request. Returns chats in the order seen in the main chat list
+    SearchChats(Box<SearchChats>),
+    /// Returns a list of users and location-based supergroups nearby. The list of users nearby will be updated for 60 seconds after the request by the updates updateUsersNearby. The request must be sent again every 25 seconds with adjusted location to not miss new chats
+    SearchChatsNearby(Box<SearchChatsNearby>),
+    /// Searches for the specified query in the title and username of already known chats via request to the server. Returns chats in the order seen in the main chat list
+    SearchChatsOnServer(Box<SearchChatsOnServer>),
+    /// Searches for the specified query in the first names, last names and usernames of the known user contacts
+    SearchContacts(Box<SearchContacts>),
+    /// Searches for emojis by keywords. Supported only if the file database is enabled
+    SearchEmojis(Box<SearchEmojis>),
+    /// Searches for recently used hashtags by their prefix
+    SearchHashtags(Box<SearchHashtags>),
+    /// Searches for installed sticker sets by looking for specified query in their title and name
+    SearchInstalledStickerSets(Box<SearchInstalledStickerSets>),
+    /// Searches for messages in all chats except secret chats. Returns the results in reverse chronological order (i.e., in order of decreasing (date, chat_id, message_id)). For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit
+    SearchMessages(Box<SearchMessages>),
+    /// Searches a public chat by its username. Currently, only private chats, supergroups and channels can be public. Returns the chat if found; otherwise an error is returned
+    SearchPublicChat(Box<SearchPublicChat>),
+    /// Searches public chats by looking for specified query in their username and title. Currently, only private chats, supergroups and channels can be public. Returns a meaningful number of results. Excludes private chats with contacts and chats from the chat list from the results
+    SearchPublicChats(Box<SearchPublicChats>),
+    /// Searches for messages in secret chats. Returns the results in reverse chronological order. For optimal performance, the number of returned messages is chosen by TDLib
+    SearchSecretMessages(Box<SearchSecretMessages>),
+    /// Searches for a sticker set by its name
+    SearchStickerSet(Box<SearchStickerSet>),
+    /// Searches for ordinary sticker sets by looking for specified query in their title and name. Excludes installed sticker sets from the results
+    SearchStickerSets(Box<SearchStickerSets>),
+    /// Searches for stickers from public sticker sets that correspond to a given emoji
+    SearchStickers(Box<SearchStickers>),
+    /// Invites a bot to a chat (if it is not yet a member) and sends it the /start command. Bots can't be invited to a private chat other than the chat with the bot. Bots can't be invited to channels (although they can be added as admins) and secret chats. Returns the sent message
+    SendBotStartMessage(Box<SendBotStartMessage>),
+    /// Sends debug information for a call
+    SendCallDebugInformation(Box<SendCallDebugInformation>),
+    /// Sends a call rating
+    SendCallRating(Box<SendCallRating>),
+    /// Sends call signaling data
+    SendCallSignalingData(Box<SendCallSignalingData>),
+    /// Sends a notification about user activity in a chat
+    SendChatAction(Box<SendChatAction>),
+    /// Sends a notification about a screenshot taken in a chat. Supported only in private and secret chats
+    SendChatScreenshotTakenNotification(Box<SendChatScreenshotTakenNotification>),
+    /// Sends a custom request; for bots only
+    SendCustomRequest(Box<SendCustomRequest>),
+    /// Sends a code to verify an email address to be added to a user's Telegram Passport
+    SendEmailAddressVerificationCode(Box<SendEmailAddressVerificationCode>),
+    /// Sends the result of an inline query as a message. Returns the sent message. Always clears a chat draft message
+    SendInlineQueryResultMessage(Box<SendInlineQueryResultMessage>),
+    /// Sends a message. Returns the sent message
+    SendMessage(Box<SendMessage>),
+    /// Sends 2-10 messages grouped together into an album. Currently, only audio, document, photo and video messages can be grouped into an album. Documents and audio files can be only grouped in an album with messages of the same type. Returns sent messages
+    SendMessageAlbum(Box<SendMessageAlbum>),
+    /// Sends a Telegram Passport authorization form, effectively sharing data with the service. This method must be called after getPassportAuthorizationFormAvailableElements if some previously available elements are going to be reused
+    SendPassportAuthorizationForm(Box<SendPassportAuthorizationForm>),
+    /// Sends a filled-out payment form to the bot for final verification
+    SendPaymentForm(Box<SendPaymentForm>),
+    /// Sends phone number confirmation code to handle links of the type internalLinkTypePhoneNumberConfirmation
+    SendPhoneNumberConfirmationCode(Box<SendPhoneNumberConfirmationCode>),
+    /// Sends a code to verify a phone number to be added to a user's Telegram Passport
+    SendPhoneNumberVerificationCode(Box<SendPhoneNumberVerificationCode>),
+    /// Changes the period of inactivity after which the account of the current user will automatically be deleted
+    SetAccountTtl(Box<SetAccountTtl>),
+    /// Succeeds after a specified amount of time has passed. Can be called before initialization
+    SetAlarm(Box<SetAlarm>),
+    /// Sets the phone number of the user and sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitPhoneNumber, or if there is no pending authentication query and the current authorization state is authorizationStateWaitCode, authorizationStateWaitRegistration, or authorizationStateWaitPassword
+    SetAuthenticationPhoneNumber(Box<SetAuthenticationPhoneNumber>),
+    /// Sets auto-download settings
+    SetAutoDownloadSettings(Box<SetAutoDownloadSettings>),
+    /// Changes the background selected by the user; adds background to the list of installed backgrounds
+    SetBackground(Box<SetBackground>),
+    /// Changes the bio of the current user
+    SetBio(Box<SetBio>),
+    /// Informs the server about the number of pending bot updates if they haven't been processed for a long time; for bots only
+    SetBotUpdatesStatus(Box<SetBotUpdatesStatus>),
+    /// Changes application-specific data associated with a chat
+    SetChatClientData(Box<SetChatClientData>),
+    /// Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info administrator right
+    SetChatDescription(Box<SetChatDescription>),
+    /// Changes the discussion group of a channel chat; requires can_change_info administrator right in the channel if it is specified
+    SetChatDiscussionGroup(Box<SetChatDiscussionGroup>),
+    /// Changes the draft message in a chat
+    SetChatDraftMessage(Box<SetChatDraftMessage>),
+    /// Changes the location of a chat. Available only for some location-based supergroups, use supergroupFullInfo.can_set_location to check whether the method is allowed to use
+    SetChatLocation(Box<SetChatLocation>),
+    /// Changes the status of a chat member, needs appropriate privileges. This function is currently not suitable for transferring chat ownership; use transferChatOwnership instead. Use addChatMember or banChatMember if some additional parameters needs to be passed
+    SetChatMemberStatus(Box<SetChatMemberStatus>),
+    /// Selects a message sender to send messages in a chat
+    SetChatMessageSender(Box<SetChatMessageSender>),
+    /// Changes the message TTL in a chat. Requires can_delete_messages administrator right in basic groups, supergroups and channels Message TTL can't be changed in a chat with the current user (Saved Messages) and the chat 777000 (Telegram)
+    SetChatMessageTtl(Box<SetChatMessageTtl>),
+    /// Changes the notification settings of a chat. Notification settings of a chat with the current user (Saved Messages) can't be changed
+    SetChatNotificationSettings(Box<SetChatNotificationSettings>),
+    /// Changes the chat members permissions. Supported only for basic groups and supergroups. Requires can_restrict_members administrator right
+    SetChatPermissions(Box<SetChatPermissions>),
+    /// Changes the photo of a chat. Supported only for basic groups, supergroups and channels. Requires can_change_info administrator right
+    SetChatPhoto(Box<SetChatPhoto>),
+    /// Changes the slow mode delay of a chat. Available only for supergroups; requires can_restrict_members rights
+    SetChatSlowModeDelay(Box<SetChatSlowModeDelay>),
+    /// Changes the chat theme. Supported only in private and secret chats
+    SetChatTheme(Box<SetChatTheme>),
+    /// Changes the chat title. Supported only for basic groups, supergroups and channels. Requires can_change_info administrator right
+    SetChatTitle(Box<SetChatTitle>),
+    /// Sets the list of commands supported by the bot for the given user scope and language; for bots only
+    SetCommands(Box<SetCommands>),
+    /// Adds or changes a custom local language pack to the current localization target
+    SetCustomLanguagePack(Box<SetCustomLanguagePack>),
+    /// Adds, edits or deletes a string in a custom local language pack. Can be called before authorization
+    SetCustomLanguagePackString(Box<SetCustomLanguagePackString>),
+    /// Changes the database encryption key. Usually the encryption key is never changed and is stored in some OS keychain
+    SetDatabaseEncryptionKey(Box<SetDatabaseEncryptionKey>),
+    /// Informs TDLib on a file generation progress
+    SetFileGenerationProgress(Box<SetFileGenerationProgress>),
+    /// Updates the game score of the specified user in the game; for bots only
+    SetGameScore(Box<SetGameScore>),
+    /// Informs TDLib that speaking state of a participant of an active group has changed
+    SetGroupCallParticipantIsSpeaking(Box<SetGroupCallParticipantIsSpeaking>),
+    /// Changes volume level of a participant of an active group call. If the current user can manage the group call, then the participant's volume level will be changed for all users with the default volume level
+    SetGroupCallParticipantVolumeLevel(Box<SetGroupCallParticipantVolumeLevel>),
+    /// Sets group call title. Requires groupCall.can_be_managed group call flag
+    SetGroupCallTitle(Box<SetGroupCallTitle>),
+    /// Changes the period of inactivity after which sessions will automatically be terminated
+    SetInactiveSessionTtl(Box<SetInactiveSessionTtl>),
+    /// Updates the game score of the specified user in a game; for bots only
+    SetInlineGameScore(Box<SetInlineGameScore>),
+    /// Changes the location of the current user. Needs to be called if GetOption("is_location_visible") is true and location changes for more than 1 kilometer
+    SetLocation(Box<SetLocation>),
+    /// Sets new log stream for internal logging of TDLib. Can be called synchronously
+    SetLogStream(Box<SetLogStream>),
+    /// Sets the verbosity level for a specified TDLib internal log tag. Can be called synchronously
+    SetLogTagVerbosityLevel(Box<SetLogTagVerbosityLevel>),
+    /// Sets the verbosity level of the internal logging of TDLib. Can be called synchronously
+    SetLogVerbosityLevel(Box<SetLogVerbosityLevel>),
+    /// Changes the first and last name of the current user
+    SetName(Box<SetName>),
+    /// Sets the current network type. Can be called before authorization. Calling this method forces all network connections to reopen, mitigating the delay in switching between different networks, so it must be called whenever the network is changed, even if the network type remains the same. Network type is used to check whether the library can use the network at all and also for collecting detailed network data usage statistics
+    SetNetworkType(Box<SetNetworkType>),
+    /// Sets the value of an option. (Check the list of available options on https://core.telegram.org/tdlib/options.) Only writable options can be set. Can be called before authorization
+    SetOption(Box<SetOption>),
+    /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
+    SetPassportElement(Box<SetPassportElement>),
+    /// Informs the user that some of the elements in their Telegram Passport contain errors; for bots only. The user will not be able to resend the elements, until the errors are fixed
+    SetPassportElementErrors(Box<SetPassportElementErrors>),
+    /// Changes the password for the current user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed
+    SetPassword(Box<SetPassword>),
+    /// Changes the order of pinned chats
+    SetPinnedChats(Box<SetPinnedChats>),
+    /// Changes the user answer to a poll. A poll in quiz mode can be answered only once
+    SetPollAnswer(Box<SetPollAnswer>),
+    /// Changes a profile photo for the current user
+    SetProfilePhoto(Box<SetProfilePhoto>),
+    /// Changes the 2-step verification recovery email address of the user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed. If new_recovery_email_address is the same as the email address that is currently set up, this call succeeds immediately and aborts all other requests waiting for an email confirmation
+    SetRecoveryEmailAddress(Box<SetRecoveryEmailAddress>),
+    /// Changes notification settings for chats of a given type
+    SetScopeNotificationSettings(Box<SetScopeNotificationSettings>),
+    /// Changes the position of a sticker in the set to which it belongs; for bots only. The sticker set must have been created by the bot
+    SetStickerPositionInSet(Box<SetStickerPositionInSet>),
+    /// Sets a sticker set thumbnail; for bots only. Returns the sticker set
+    SetStickerSetThumbnail(Box<SetStickerSetThumbnail>),
+    /// Changes the sticker set of a supergroup; requires can_change_info administrator right
+    SetSupergroupStickerSet(Box<SetSupergroupStickerSet>),
+    /// Changes the username of a supergroup or channel, requires owner privileges in the supergroup or channel
+    SetSupergroupUsername(Box<SetSupergroupUsername>),
+    /// Sets the parameters for TDLib initialization. Works only when the current authorization state is authorizationStateWaitTdlibParameters
+    SetTdlibParameters(Box<SetTdlibParameters>),
+    /// Changes user privacy settings
+    SetUserPrivacySettingRules(Box<SetUserPrivacySettingRules>),
+    /// Changes the username of the current user
+    SetUsername(Box<SetUsername>),
+    /// Changes default participant identifier, on whose behalf a video chat in the chat will be joined
+    SetVideoChatDefaultParticipant(Box<SetVideoChatDefaultParticipant>),
+    /// Shares the phone number of the current user with a mutual contact. Supposed to be called when the user clicks on chatActionBarSharePhoneNumber
+    SharePhoneNumber(Box<SharePhoneNumber>),
+    /// Starts recording of an active group call. Requires groupCall.can_be_managed group call flag
+    StartGroupCallRecording(Box<StartGroupCallRecording>),
+    /// Starts screen sharing in a joined group call. Returns join response payload for tgcalls
+    StartGroupCallScreenSharing(Box<StartGroupCallScreenSharing>),
+    /// Starts a scheduled group call
+    StartScheduledGroupCall(Box<StartScheduledGroupCall>),
+    /// Stops a poll. A poll in a message can be stopped when the message has can_be_edited flag set
+    StopPoll(Box<StopPoll>),
+    /// Fetches the latest versions of all strings from a language pack in the current localization target from the server. This method doesn't need to be called explicitly for the current used/base language packs. Can be called before authorization
+    SynchronizeLanguagePack(Box<SynchronizeLanguagePack>),
+    /// Terminates all other sessions of the current user
+    TerminateAllOtherSessions(Box<TerminateAllOtherSessions>),
+    /// Terminates a session of the current user
+    TerminateSession(Box<TerminateSession>),
+    /// Returns the received bytes; for testing only. This is an offline method. Can be called before authorization
+    TestCallBytes(Box<TestCallBytes>),
+    /// Does nothing; for testing only. This is an offline method. Can be called before authorization
+    TestCallEmpty(Box<TestCallEmpty>),
+    /// Returns the received string; for testing only. This is an offline method. Can be called before authorization
+    TestCallString(Box<TestCallString>),
+    /// Returns the received vector of numbers; for testing only. This is an offline method. Can be called before authorization
+    TestCallVectorInt(Box<TestCallVectorInt>),
+    /// Returns the received vector of objects containing a number; for testing only. This is an offline method. Can be called before authorization
+    TestCallVectorIntObject(Box<TestCallVectorIntObject>),
+    /// Returns the received vector of strings; for testing only. This is an offline method. Can be called before authorization
+    TestCallVectorString(Box<TestCallVectorString>),
+    /// Returns the received vector of objects containing a string; for testing only. This is an offline method. Can be called before authorization
+    TestCallVectorStringObject(Box<TestCallVectorStringObject>),
+    /// Forces an updates.getDifference call to the Telegram servers; for testing only
+    TestGetDifference(Box<TestGetDifference>),
+    /// Sends a simple network request to the Telegram servers; for testing only. Can be called before authorization
+    TestNetwork(Box<TestNetwork>),
+    /// Sends a simple network request to the Telegram servers via proxy; for testing only. Can be called before authorization
+    TestProxy(Box<TestProxy>),
+    /// Returns the specified error and ensures that the Error object is used; for testing only. Can be called synchronously
+    TestReturnError(Box<TestReturnError>),
+    /// Returns the squared received number; for testing only. This is an offline method. Can be called before authorization
+    TestSquareInt(Box<TestSquareInt>),
+    /// Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization
+    TestUseUpdate(Box<TestUseUpdate>),
+    /// Changes the value of the default disable_notification parameter, used when a message is sent to a chat
+    ToggleChatDefaultDisableNotification(Box<ToggleChatDefaultDisableNotification>),
+    /// Changes the ability of users to save, forward, or copy chat content. Supported only for basic groups, supergroups and channels. Requires owner privileges
+    ToggleChatHasProtectedContent(Box<ToggleChatHasProtectedContent>),
+    /// Changes the marked as unread state of a chat
+    ToggleChatIsMarkedAsUnread(Box<ToggleChatIsMarkedAsUnread>),
+    /// Changes the pinned state of a chat. There can be up to GetOption("pinned_chat_count_max")/GetOption("pinned_archived_chat_count_max") pinned non-secret chats and the same number of secret chats in the main/arhive chat list
+    ToggleChatIsPinned(Box<ToggleChatIsPinned>),
+    /// Toggles whether the current user will receive a notification when the group call will start; scheduled group calls only
+    ToggleGroupCallEnabledStartNotification(Box<ToggleGroupCallEnabledStartNotification>),
+    /// Toggles whether current user's video is enabled
+    ToggleGroupCallIsMyVideoEnabled(Box<ToggleGroupCallIsMyVideoEnabled>),
+    /// Toggles whether current user's video is paused
+    ToggleGroupCallIsMyVideoPaused(Box<ToggleGroupCallIsMyVideoPaused>),
+    /// Toggles whether new participants of a group call can be unmuted only by administrators of the group call. Requires groupCall.can_toggle_mute_new_participants group call flag
+    ToggleGroupCallMuteNewParticipants(Box<ToggleGroupCallMuteNewParticipants>),
+    /// Toggles whether a group call participant hand is rased
+    ToggleGroupCallParticipantIsHandRaised(Box<ToggleGroupCallParticipantIsHandRaised>),
+    /// Toggles whether a participant of an active group call is muted, unmuted, or allowed to unmute themselves
+    ToggleGroupCallParticipantIsMuted(Box<ToggleGroupCallParticipantIsMuted>),
+    /// Pauses or unpauses screen sharing in a joined group call
+    ToggleGroupCallScreenSharingIsPaused(Box<ToggleGroupCallScreenSharingIsPaused>),
+    /// Changes the block state of a message sender. Currently, only users and supergroup chats can be blocked
+    ToggleMessageSenderIsBlocked(Box<ToggleMessageSenderIsBlocked>),
+    /// Toggles whether a session can accept incoming calls
+    ToggleSessionCanAcceptCalls(Box<ToggleSessionCanAcceptCalls>),
+    /// Toggles whether a session can accept incoming secret chats
+    ToggleSessionCanAcceptSecretChats(Box<ToggleSessionCanAcceptSecretChats>),
+    /// Toggles whether the message history of a supergroup is available to new members; requires can_change_info administrator right
+    ToggleSupergroupIsAllHistoryAvailable(Box<ToggleSupergroupIsAllHistoryAvailable>),
+    /// Upgrades supergroup to a broadcast group; requires owner privileges in the supergroup
+    ToggleSupergroupIsBroadcastGroup(Box<ToggleSupergroupIsBroadcastGroup>),
+    /// Toggles whether sender signature is added to sent messages in a channel; requires can_change_info administrator right
+    ToggleSupergroupSignMessages(Box<ToggleSupergroupSignMessages>),
+    /// Changes the owner of a chat. The current user must be a current owner of the chat. Use the method canTransferOwnership to check whether the ownership can be transferred from the current session. Available only for supergroups and channel chats
+    TransferChatOwnership(Box<TransferChatOwnership>),
+    /// Removes all pinned messages from a chat; requires can_pin_messages rights in the group or can_edit_messages rights in the channel
+    UnpinAllChatMessages(Box<UnpinAllChatMessages>),
+    /// Removes a pinned message from a chat; requires can_pin_messages rights in the group or can_edit_messages rights in the channel
+    UnpinChatMessage(Box<UnpinChatMessage>),
+    /// Creates a new supergroup from an existing basic group and sends a corresponding messageChatUpgradeTo and messageChatUpgradeFrom; requires creator privileges. Deactivates the original basic group
+    UpgradeBasicGroupChatToSupergroupChat(Box<UpgradeBasicGroupChatToSupergroupChat>),
+    /// Asynchronously uploads a file to the cloud without sending it in a message. updateFile will be used to notify about upload progress and successful completion of the upload. The file will not have a persistent remote identifier until it will be sent in a message
+    UploadFile(Box<UploadFile>),
+    /// Uploads a file with a sticker; returns the uploaded file
+    UploadStickerFile(Box<UploadStickerFile>),
+    /// Validates the order information provided by a user and returns the available shipping options for a flexible invoice
+    ValidateOrderInfo(Box<ValidateOrderInfo>),
+    /// Informs TDLib that messages are being viewed by the user. Sponsored messages must be marked as viewed only when the entire text of the message is shown on the screen (excluding the button). Many useful activities depend on whether the messages are currently being viewed or not (e.g., marking messages as read, incrementing a view counter, updating a view counter, removing deleted messages in supergroups and channels)
+    ViewMessages(Box<ViewMessages>),
+    /// Informs the server that some trending sticker sets have been viewed by the user
+    ViewTrendingStickerSets(Box<ViewTrendingStickerSets>),
+    /// Writes a part of a generated file. This method is intended to be used only if the application has no direct access to TDLib's file system, because it is usually slower than a direct write to the destination file
+    WriteGeneratedFilePart(Box<WriteGeneratedFilePart>),
+    /// Function unknown to this version of the library, e.g. a method of a newer TDLib
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl RObject for TdFunction {
+    #[doc(hidden)]
+    fn extra(&self) -> Option<&str> {
+        match self {
+            TdFunction::AcceptCall(t) => t.extra(),
+            TdFunction::AcceptTermsOfService(t) => t.extra(),
+            TdFunction::AddChatMember(t) => t.extra(),
+            TdFunction::AddChatMembers(t) => t.extra(),
+            TdFunction::AddChatToList(t) => t.extra(),
+            TdFunction::AddContact(t) => t.extra(),
+            TdFunction::AddCustomServerLanguagePack(t) => t.extra(),
+            TdFunction::AddFavoriteSticker(t) => t.extra(),
+            TdFunction::AddLocalMessage(t) => t.extra(),
+            TdFunction::AddLogMessage(t) => t.extra(),
+            TdFunction::AddNetworkStatistics(t) => t.extra(),
+            TdFunction::AddProxy(t) => t.extra(),
+            TdFunction::AddRecentSticker(t) => t.extra(),
+            TdFunction::AddRecentlyFoundChat(t) => t.extra(),
+            TdFunction::AddSavedAnimation(t) => t.extra(),
+            TdFunction::AddStickerToSet(t) => t.extra(),
+            TdFunction::AnswerCallbackQuery(t) => t.extra(),
+            TdFunction::AnswerCustomQuery(t) => t.extra(),
+            TdFunction::AnswerInlineQuery(t) => t.extra(),
+            TdFunction::AnswerPreCheckoutQuery(t) => t.extra(),
+            TdFunction::AnswerShippingQuery(t) => t.extra(),
+            TdFunction::BanChatMember(t) => t.extra(),
+            TdFunction::BlockMessageSenderFromReplies(t) => t.extra(),
+            TdFunction::CanTransferOwnership(t) => t.extra(),
+            TdFunction::CancelDownloadFile(t) => t.extra(),
+            TdFunction::CancelPasswordReset(t) => t.extra(),
+            TdFunction::CancelUploadFile(t) => t.extra(),
+            TdFunction::ChangeImportedContacts(t) => t.extra(),
+            TdFunction::ChangePhoneNumber(t) => t.extra(),
+            TdFunction::ChangeStickerSet(t) => t.extra(),
+            TdFunction::CheckAuthenticationBotToken(t) => t.extra(),
+            TdFunction::CheckAuthenticationCode(t) => t.extra(),
+            TdFunction::CheckAuthenticationPassword(t) => t.extra(),
+            TdFunction::CheckAuthenticationPasswordRecoveryCode(t) => t.extra(),
+            TdFunction::CheckChangePhoneNumberCode(t) => t.extra(),
+            TdFunction::CheckChatInviteLink(t) => t.extra(),
+            TdFunction::CheckChatUsername(t) => t.extra(),
+            TdFunction::CheckCreatedPublicChatsLimit(t) => t.extra(),
+            TdFunction::CheckDatabaseEncryptionKey(t) => t.extra(),
+            TdFunction::CheckEmailAddressVerificationCode(t) => t.extra(),
+            TdFunction::CheckPasswordRecoveryCode(t) => t.extra(),
+            TdFunction::CheckPhoneNumberConfirmationCode(t) => t.extra(),
+            TdFunction::CheckPhoneNumberVerificationCode(t) => t.extra(),
+            TdFunction::CheckRecoveryEmailAddressCode(t) => t.extra(),
+            TdFunction::CheckStickerSetName(t) => t.extra(),
+            TdFunction::CleanFileName(t) => t.extra(),
+            TdFunction::ClearAllDraftMessages(t) => t.extra(),
+            TdFunction::ClearImportedContacts(t) => t.extra(),
+            TdFunction::ClearRecentStickers(t) => t.extra(),
+            TdFunction::ClearRecentlyFoundChats(t) => t.extra(),
+            TdFunction::ClickAnimatedEmojiMessage(t) => t.extra(),
+            TdFunction::Close(t) => t.extra(),
+            TdFunction::CloseChat(t) => t.extra(),
+            TdFunction::CloseSecretChat(t) => t.extra(),
+            TdFunction::ConfirmQrCodeAuthentication(t) => t.extra(),
+            TdFunction::CreateBasicGroupChat(t) => t.extra(),
+            TdFunction::CreateCall(t) => t.extra(),
+            TdFunction::CreateChatFilter(t) => t.extra(),
+            TdFunction::CreateChatInviteLink(t) => t.extra(),
+            TdFunction::CreateNewBasicGroupChat(t) => t.extra(),
+            TdFunction::CreateNewSecretChat(t) => t.extra(),
+            TdFunction::CreateNewStickerSet(t) => t.extra(),
+            TdFunction::CreateNewSupergroupChat(t) => t.extra(),
+            TdFunction::CreatePrivateChat(t) => t.extra(),
+            TdFunction::CreateSecretChat(t) => t.extra(),
+            TdFunction::CreateSupergroupChat(t) => t.extra(),
+            TdFunction::CreateTemporaryPassword(t) => t.extra(),
+            TdFunction::CreateVideoChat(t) => t.extra(),
+            TdFunction::DeleteAccount(t) => t.extra(),
+            TdFunction::DeleteAllCallMessages(t) => t.extra(),
+            TdFunction::DeleteAllRevokedChatInviteLinks(t) => t.extra(),
+            TdFunction::DeleteChat(t) => t.extra(),
+            TdFunction::DeleteChatFilter(t) => t.extra(),
+            TdFunction::DeleteChatHistory(t) => t.extra(),
+            TdFunction::DeleteChatMessagesByDate(t) => t.extra(),
+            TdFunction::DeleteChatMessagesBySender(t) => t.extra(),
+            TdFunction::DeleteChatReplyMarkup(t) => t.extra(),
+            TdFunction::DeleteCommands(t) => t.extra(),
+            TdFunction::DeleteFile(t) => t.extra(),
+            TdFunction::DeleteLanguagePack(t) => t.extra(),
+            TdFunction::DeleteMessages(t) => t.extra(),
+            TdFunction::DeletePassportElement(t) => t.extra(),
+            TdFunction::DeleteProfilePhoto(t) => t.extra(),
+            TdFunction::DeleteRevokedChatInviteLink(t) => t.extra(),
+            TdFunction::DeleteSavedCredentials(t) => t.extra(),
+            TdFunction::DeleteSavedOrderInfo(t) => t.extra(),
+            TdFunction::Destroy(t) => t.extra(),
+            TdFunction::DisableProxy(t) => t.extra(),
+            TdFunction::DiscardCall(t) => t.extra(),
+            TdFunction::DisconnectAllWebsites(t) => t.extra(),
+            TdFunction::DisconnectWebsite(t) => t.extra(),
+            TdFunction::DownloadFile(t) => t.extra(),
+            TdFunction::EditChatFilter(t) => t.extra(),
+            TdFunction::EditChatInviteLink(t) => t.extra(),
+            TdFunction::EditCustomLanguagePackInfo(t) => t.extra(),
+            TdFunction::EditInlineMessageCaption(t) => t.extra(),
+            TdFunction::EditInlineMessageLiveLocation(t) => t.extra(),
+            TdFunction::EditInlineMessageMedia(t) => t.extra(),
+            TdFunction::EditInlineMessageReplyMarkup(t) => t.extra(),
+            TdFunction::EditInlineMessageText(t) => t.extra(),
+            TdFunction::EditMessageCaption(t) => t.extra(),
+            TdFunction::EditMessageLiveLocation(t) => t.extra(),
+            TdFunction::EditMessageMedia(t) => t.extra(),
+            TdFunction::EditMessageReplyMarkup(t) => t.extra(),
+            TdFunction::EditMessageSchedulingState(t) => t.extra(),
+            TdFunction::EditMessageText(t) => t.extra(),
+            TdFunction::EditProxy(t) => t.extra(),
+            TdFunction::EnableProxy(t) => t.extra(),
+            TdFunction::EndGroupCall(t) => t.extra(),
+            TdFunction::EndGroupCallRecording(t) => t.extra(),
+            TdFunction::EndGroupCallScreenSharing(t) => t.extra(),
+            TdFunction::FinishFileGeneration(t) => t.extra(),
+            TdFunction::ForwardMessages(t) => t.extra(),
+            TdFunction::GetAccountTtl(t) => t.extra(),
+            TdFunction::GetActiveLiveLocationMessages(t) => t.extra(),
+            TdFunction::GetActiveSessions(t) => t.extra(),
+            TdFunction::GetAllPassportElements(t) => t.extra(),
+            TdFunction::GetAnimatedEmoji(t) => t.extra(),
+            TdFunction::GetApplicationConfig(t) => t.extra(),
+            TdFunction::GetApplicationDownloadLink(t) => t.extra(),
+            TdFunction::GetArchivedStickerSets(t) => t.extra(),
+            TdFunction::GetAttachedStickerSets(t) => t.extra(),
+            TdFunction::GetAuthorizationState(t) => t.extra(),
+            TdFunction::GetAutoDownloadSettingsPresets(t) => t.extra(),
+            TdFunction::GetBackgroundUrl(t) => t.extra(),
+            TdFunction::GetBackgrounds(t) => t.extra(),
+            TdFunction::GetBankCardInfo(t) => t.extra(),
+            TdFunction::GetBasicGroup(t) => t.extra(),
+            TdFunction::GetBasicGroupFullInfo(t) => t.extra(),
+            TdFunction::GetBlockedMessageSenders(t) => t.extra(),
+            TdFunction::GetCallbackQueryAnswer(t) => t.extra(),
+            TdFunction::GetCallbackQueryMessage(t) => t.extra(),
+            TdFunction::GetChat(t) => t.extra(),
+            TdFunction::GetChatAdministrators(t) => t.extra(),
+            TdFunction::GetChatAvailableMessageSenders(t) => t.extra(),
+            TdFunction::GetChatEventLog(t) => t.extra(),
+            TdFunction::GetChatFilter(t) => t.extra(),
+            TdFunction::GetChatFilterDefaultIconName(t) => t.extra(),
+            TdFunction::GetChatHistory(t) => t.extra(),
+            TdFunction::GetChatInviteLink(t) => t.extra(),
+            TdFunction::GetChatInviteLinkCounts(t) => t.extra(),
+            TdFunction::GetChatInviteLinkMembers(t) => t.extra(),
+            TdFunction::GetChatInviteLinks(t) => t.extra(),
+            TdFunction::GetChatJoinRequests(t) => t.extra(),
+            TdFunction::GetChatListsToAddChat(t) => t.extra(),
+            TdFunction::GetChatMember(t) => t.extra(),
+            TdFunction::GetChatMessageByDate(t) => t.extra(),
+            TdFunction::GetChatMessageCalendar(t) => t.extra(),
+            TdFunction::GetChatMessageCount(t) => t.extra(),
+            TdFunction::GetChatNotificationSettingsExceptions(t) => t.extra(),
+            TdFunction::GetChatPinnedMessage(t) => t.extra(),
+            TdFunction::GetChatScheduledMessages(t) => t.extra(),
+            TdFunction::GetChatSparseMessagePositions(t) => t.extra(),
+            TdFunction::GetChatSponsoredMessage(t) => t.extra(),
+            TdFunction::GetChatStatistics(t) => t.extra(),
+            TdFunction::GetChats(t) => t.extra(),
+            TdFunction::GetCommands(t) => t.extra(),
+            TdFunction::GetConnectedWebsites(t) => t.extra(),
+            TdFunction::GetContacts(t) => t.extra(),
+            TdFunction::GetCountries(t) => t.extra(),
+            TdFunction::GetCountryCode(t) => t.extra(),
+            TdFunction::GetCreatedPublicChats(t) => t.extra(),
+            TdFunction::GetCurrentState(t) => t.extra(),
+            TdFunction::GetDatabaseStatistics(t) => t.extra(),
+            TdFunction::GetDeepLinkInfo(t) => t.extra(),
+            TdFunction::GetEmojiSuggestionsUrl(t) => t.extra(),
+            TdFunction::GetExternalLink(t) => t.extra(),
+            TdFunction::GetExternalLinkInfo(t) => t.extra(),
+            TdFunction::GetFavoriteStickers(t) => t.extra(),
+            TdFunction::GetFile(t) => t.extra(),
+            TdFunction::GetFileDownloadedPrefixSize(t) => t.extra(),
+            TdFunction::GetFileExtension(t) => t.extra(),
+            TdFunction::GetFileMimeType(t) => t.extra(),
+            TdFunction::GetGameHighScores(t) => t.extra(),
+            TdFunction::GetGroupCall(t) => t.extra(),
+            TdFunction::GetGroupCallInviteLink(t) => t.extra(),
+            TdFunction::GetGroupCallStreamSegment(t) => t.extra(),
+            TdFunction::GetGroupsInCommon(t) => t.extra(),
+            TdFunction::GetImportedContactCount(t) => t.extra(),
+            TdFunction::GetInactiveSupergroupChats(t) => t.extra(),
+            TdFunction::GetInlineGameHighScores(t) => t.extra(),
+            TdFunction::GetInlineQueryResults(t) => t.extra(),
+            TdFunction::GetInstalledStickerSets(t) => t.extra(),
+            TdFunction::GetInternalLinkType(t) => t.extra(),
+            TdFunction::GetJsonString(t) => t.extra(),
+            TdFunction::GetJsonValue(t) => t.extra(),
+            TdFunction::GetLanguagePackInfo(t) => t.extra(),
+            TdFunction::GetLanguagePackString(t) => t.extra(),
+            TdFunction::GetLanguagePackStrings(t) => t.extra(),
+            TdFunction::GetLocalizationTargetInfo(t) => t.extra(),
+            TdFunction::GetLogStream(t) => t.extra(),
+            TdFunction::GetLogTagVerbosityLevel(t) => t.extra(),
+            TdFunction::GetLogTags(t) => t.extra(),
+            TdFunction::GetLogVerbosityLevel(t) => t.extra(),
+            TdFunction::GetLoginUrl(t) => t.extra(),
+            TdFunction::GetLoginUrlInfo(t) => t.extra(),
+            TdFunction::GetMapThumbnailFile(t) => t.extra(),
+            TdFunction::GetMarkdownText(t) => t.extra(),
+            TdFunction::GetMe(t) => t.extra(),
+            TdFunction::GetMessage(t) => t.extra(),
+            TdFunction::GetMessageEmbeddingCode(t) => t.extra(),
+            TdFunction::GetMessageFileType(t) => t.extra(),
+            TdFunction::GetMessageImportConfirmationText(t) => t.extra(),
+            TdFunction::GetMessageLink(t) => t.extra(),
+            TdFunction::GetMessageLinkInfo(t) => t.extra(),
+            TdFunction::GetMessageLocally(t) => t.extra(),
+            TdFunction::GetMessagePublicForwards(t) => t.extra(),
+            TdFunction::GetMessageStatistics(t) => t.extra(),
+            TdFunction::GetMessageThread(t) => t.extra(),
+            TdFunction::GetMessageThreadHistory(t) => t.extra(),
+            TdFunction::GetMessageViewers(t) => t.extra(),
+            TdFunction::GetMessages(t) => t.extra(),
+            TdFunction::GetNetworkStatistics(t) => t.extra(),
+            TdFunction::GetOption(t) => t.extra(),
+            TdFunction::GetPassportAuthorizationForm(t) => t.extra(),
+            TdFunction::GetPassportAuthorizationFormAvailableElements(t) => t.extra(),
+            TdFunction::GetPassportElement(t) => t.extra(),
+            TdFunction::GetPasswordState(t) => t.extra(),
+            TdFunction::GetPaymentForm(t) => t.extra(),
+            TdFunction::GetPaymentReceipt(t) => t.extra(),
+            TdFunction::GetPhoneNumberInfo(t) => t.extra(),
+            TdFunction::GetPhoneNumberInfoSync(t) => t.extra(),
+            TdFunction::GetPollVoters(t) => t.extra(),
+            TdFunction::GetPreferredCountryLanguage(t) => t.extra(),
+            TdFunction::GetProxies(t) => t.extra(),
+            TdFunction::GetProxyLink(t) => t.extra(),
+            TdFunction::GetPushReceiverId(t) => t.extra(),
+            TdFunction::GetRecentInlineBots(t) => t.extra(),
+            TdFunction::GetRecentStickers(t) => t.extra(),
+            TdFunction::GetRecentlyOpenedChats(t) => t.extra(),
+            TdFunction::GetRecentlyVisitedTMeUrls(t) => t.extra(),
+            TdFunction::GetRecommendedChatFilters(t) => t.extra(),
+            TdFunction::GetRecoveryEmailAddress(t) => t.extra(),
+            TdFunction::GetRemoteFile(t) => t.extra(),
+            TdFunction::GetRepliedMessage(t) => t.extra(),
+            TdFunction::GetSavedAnimations(t) => t.extra(),
+            TdFunction::GetSavedOrderInfo(t) => t.extra(),
+            TdFunction::GetScopeNotificationSettings(t) => t.extra(),
+            TdFunction::GetSecretChat(t) => t.extra(),
+            TdFunction::GetStatisticalGraph(t) => t.extra(),
+            TdFunction::GetStickerEmojis(t) => t.extra(),
+            TdFunction::GetStickerSet(t) => t.extra(),
+            TdFunction::GetStickers(t) => t.extra(),
+            TdFunction::GetStorageStatistics(t) => t.extra(),
+            TdFunction::GetStorageStatisticsFast(t) => t.extra(),
+            TdFunction::GetSuggestedFileName(t) => t.extra(),
+            TdFunction::GetSuggestedStickerSetName(t) => t.extra(),
+            TdFunction::GetSuitableDiscussionChats(t) => t.extra(),
+            TdFunction::GetSupergroup(t) => t.extra(),
+            TdFunction::GetSupergroupFullInfo(t) => t.extra(),
+            TdFunction::GetSupergroupMembers(t) => t.extra(),
+            TdFunction::GetSupportUser(t) => t.extra(),
+            TdFunction::GetTemporaryPasswordState(t) => t.extra(),
+            TdFunction::GetTextEntities(t) => t.extra(),
+            TdFunction::GetTopChats(t) => t.extra(),
+            TdFunction::GetTrendingStickerSets(t) => t.extra(),
+            TdFunction::GetUser(t) => t.extra(),
+            TdFunction::GetUserFullInfo(t) => t.extra(),
+            TdFunction::GetUserPrivacySettingRules(t) => t.extra(),
+            TdFunction::GetUserProfilePhotos(t) => t.extra(),
+            TdFunction::GetVideoChatAvailableParticipants(t) => t.extra(),
+            TdFunction::GetWebPageInstantView(t) => t.extra(),
+            TdFunction::GetWebPagePreview(t) => t.extra(),
+            TdFunction::HideSuggestedAction(t) => t.extra(),
+            TdFunction::ImportContacts(t) => t.extra(),
+            TdFunction::ImportMessages(t) => t.extra(),
+            TdFunction::InviteGroupCallParticipants(t) => t.extra(),
+            TdFunction::JoinChat(t) => t.extra(),
+            TdFunction::JoinChatByInviteLink(t) => t.extra(),
+            TdFunction::JoinGroupCall(t) => t.extra(),
+            TdFunction::LeaveChat(t) => t.extra(),
+            TdFunction::LeaveGroupCall(t) => t.extra(),
+            TdFunction::LoadChats(t) => t.extra(),
+            TdFunction::LoadGroupCallParticipants(t) => t.extra(),
+            TdFunction::LogOut(t) => t.extra(),
+            TdFunction::OpenChat(t) => t.extra(),
+            TdFunction::OpenMessageContent(t) => t.extra(),
+            TdFunction::OptimizeStorage(t) => t.extra(),
+            TdFunction::ParseMarkdown(t) => t.extra(),
+            TdFunction::ParseTextEntities(t) => t.extra(),
+            TdFunction::PinChatMessage(t) => t.extra(),
+            TdFunction::PingProxy(t) => t.extra(),
+            TdFunction::ProcessChatJoinRequest(t) => t.extra(),
+            TdFunction::ProcessChatJoinRequests(t) => t.extra(),
+            TdFunction::ProcessPushNotification(t) => t.extra(),
+            TdFunction::ReadAllChatMentions(t) => t.extra(),
+            TdFunction::ReadFilePart(t) => t.extra(),
+            TdFunction::RecoverAuthenticationPassword(t) => t.extra(),
+            TdFunction::RecoverPassword(t) => t.extra(),
+            TdFunction::RegisterDevice(t) => t.extra(),
+            TdFunction::RegisterUser(t) => t.extra(),
+            TdFunction::RemoveBackground(t) => t.extra(),
+            TdFunction::RemoveChatActionBar(t) => t.extra(),
+            TdFunction::RemoveContacts(t) => t.extra(),
+            TdFunction::RemoveFavoriteSticker(t) => t.extra(),
+            TdFunction::RemoveNotification(t) => t.extra(),
+            TdFunction::RemoveNotificationGroup(t) => t.extra(),
+            TdFunction::RemoveProxy(t) => t.extra(),
+            TdFunction::RemoveRecentHashtag(t) => t.extra(),
+            TdFunction::RemoveRecentSticker(t) => t.extra(),
+            TdFunction::RemoveRecentlyFoundChat(t) => t.extra(),
+            TdFunction::RemoveSavedAnimation(t) => t.extra(),
+            TdFunction::RemoveStickerFromSet(t) => t.extra(),
+            TdFunction::RemoveTopChat(t) => t.extra(),
+            TdFunction::ReorderChatFilters(t) => t.extra(),
+            TdFunction::ReorderInstalledStickerSets(t) => t.extra(),
+            TdFunction::ReplacePrimaryChatInviteLink(t) => t.extra(),
+            TdFunction::ReportChat(t) => t.extra(),
+            TdFunction::ReportChatPhoto(t) => t.extra(),
+            TdFunction::ReportSupergroupSpam(t) => t.extra(),
+            TdFunction::RequestAuthenticationPasswordRecovery(t) => t.extra(),
+            TdFunction::RequestPasswordRecovery(t) => t.extra(),
+            TdFunction::RequestQrCodeAuthentication(t) => t.extra(),
+            TdFunction::ResendAuthenticationCode(t) => t.extra(),
+            TdFunction::ResendChangePhoneNumberCode(t) => t.extra(),
+            TdFunction::ResendEmailAddressVerificationCode(t) => t.extra(),
+            TdFunction::ResendMessages(t) => t.extra(),
+            TdFunction::ResendPhoneNumberConfirmationCode(t) => t.extra(),
+            TdFunction::ResendPhoneNumberVerificationCode(t) => t.extra(),
+            TdFunction::ResendRecoveryEmailAddressCode(t) => t.extra(),
+            TdFunction::ResetAllNotificationSettings(t) => t.extra(),
+            TdFunction::ResetBackgrounds(t) => t.extra(),
+            TdFunction::ResetNetworkStatistics(t) => t.extra(),
+            TdFunction::ResetPassword(t) => t.extra(),
+            TdFunction::RevokeChatInviteLink(t) => t.extra(),
+            TdFunction::RevokeGroupCallInviteLink(t) => t.extra(),
+            TdFunction::SaveApplicationLogEvent(t) => t.extra(),
+            TdFunction::SearchBackground(t) => t.extra(),
+            TdFunction::SearchCallMessages(t) => t.extra(),
+            TdFunction::SearchChatMembers(t) => t.extra(),
+            TdFunction::SearchChatMessages(t) => t.extra(),
+            TdFunction::SearchChatRecentLocationMessages(t) => t.extra(),
+            TdFunction::SearchChats(t) => t.extra(),
+            TdFunction::SearchChatsNearby(t) => t.extra(),
+            TdFunction::SearchChatsOnServer(t) => t.extra(),
+            TdFunction::SearchContacts(t) => t.extra(),
+            TdFunction::SearchEmojis(t) => t.extra(),
+            TdFunction::SearchHashtags(t) => t.extra(),
+            TdFunction::SearchInstalledStickerSets(t) => t.extra(),
+            TdFunction::SearchMessages(t) => t.extra(),
+            TdFunction::SearchPublicChat(t) => t.extra(),
+            TdFunction::SearchPublicChats(t) => t.extra(),
+            TdFunction::SearchSecretMessages(t) => t.extra(),
+            TdFunction::SearchStickerSet(t) => t.extra(),
+            TdFunction::SearchStickerSets(t) => t.extra(),
+            TdFunction::SearchStickers(t) => t.extra(),
+            TdFunction::SendBotStartMessage(t) => t.extra(),
+            TdFunction::SendCallDebugInformation(t) => t.extra(),
+            TdFunction::SendCallRating(t) => t.extra(),
+            TdFunction::SendCallSignalingData(t) => t.extra(),
+            TdFunction::SendChatAction(t) => t.extra(),
+            TdFunction::SendChatScreenshotTakenNotification(t) => t.extra(),
+            TdFunction::SendCustomRequest(t) => t.extra(),
+            TdFunction::SendEmailAddressVerificationCode(t) => t.extra(),
+            TdFunction::SendInlineQueryResultMessage(t) => t.extra(),
+            TdFunction::SendMessage(t) => t.extra(),
+            TdFunction::SendMessageAlbum(t) => t.extra(),
+            TdFunction::SendPassportAuthorizationForm(t) => t.extra(),
+            TdFunction::SendPaymentForm(t) => t.extra(),
+            TdFunction::SendPhoneNumberConfirmationCode(t) => t.extra(),
+            TdFunction::SendPhoneNumberVerificationCode(t) => t.extra(),
+            TdFunction::SetAccountTtl(t) => t.extra(),
+            TdFunction::SetAlarm(t) => t.extra(),
+            TdFunction::SetAuthenticationPhoneNumber(t) => t.extra(),
+            TdFunction::SetAutoDownloadSettings(t) => t.extra(),
+            TdFunction::SetBackground(t) => t.extra(),
+            TdFunction::SetBio(t) => t.extra(),
+            TdFunction::SetBotUpdatesStatus(t) => t.extra(),
+            TdFunction::SetChatClientData(t) => t.extra(),
+            TdFunction::SetChatDescription(t) => t.extra(),
+            TdFunction::SetChatDiscussionGroup(t) => t.extra(),
+            TdFunction::SetChatDraftMessage(t) => t.extra(),
+            TdFunction::SetChatLocation(t) => t.extra(),
+            TdFunction::SetChatMemberStatus(t) => t.extra(),
+            TdFunction::SetChatMessageSender(t) => t.extra(),
+            TdFunction::SetChatMessageTtl(t) => t.extra(),
+            TdFunction::SetChatNotificationSettings(t) => t.extra(),
+            TdFunction::SetChatPermissions(t) => t.extra(),
+            TdFunction::SetChatPhoto(t) => t.extra(),
+            TdFunction::SetChatSlowModeDelay(t) => t.extra(),
+            TdFunction::SetChatTheme(t) => t.extra(),
+            TdFunction::SetChatTitle(t) => t.extra(),
+            TdFunction::SetCommands(t) => t.extra(),
+            TdFunction::SetCustomLanguagePack(t) => t.extra(),
+            TdFunction::SetCustomLanguagePackString(t) => t.extra(),
+            TdFunction::SetDatabaseEncryptionKey(t) => t.extra(),
+            TdFunction::SetFileGenerationProgress(t) => t.extra(),
+            TdFunction::SetGameScore(t) => t.extra(),
+            TdFunction::SetGroupCallParticipantIsSpeaking(t) => t.extra(),
+            TdFunction::SetGroupCallParticipantVolumeLevel(t) => t.extra(),
+            TdFunction::SetGroupCallTitle(t) => t.extra(),
+            TdFunction::SetInactiveSessionTtl(t) => t.extra(),
+            TdFunction::SetInlineGameScore(t) => t.extra(),
+            TdFunction::SetLocation(t) => t.extra(),
+            TdFunction::SetLogStream(t) => t.extra(),
+            TdFunction::SetLogTagVerbosityLevel(t) => t.extra(),
+            TdFunction::SetLogVerbosityLevel(t) => t.extra(),
+            TdFunction::SetName(t) => t.extra(),
+            TdFunction::SetNetworkType(t) => t.extra(),
+            TdFunction::SetOption(t) => t.extra(),
+            TdFunction::SetPassportElement(t) => t.extra(),
+            TdFunction::SetPassportElementErrors(t) => t.extra(),
+            TdFunction::SetPassword(t) => t.extra(),
+            TdFunction::SetPinnedChats(t) => t.extra(),
+            TdFunction::SetPollAnswer(t) => t.extra(),
+            TdFunction::SetProfilePhoto(t) => t.extra(),
+            TdFunction::SetRecoveryEmailAddress(t) => t.extra(),
+            TdFunction::SetScopeNotificationSettings(t) => t.extra(),
+            TdFunction::SetStickerPositionInSet(t) => t.extra(),
+            TdFunction::SetStickerSetThumbnail(t) => t.extra(),
+            TdFunction::SetSupergroupStickerSet(t) => t.extra(),
+            TdFunction::SetSupergroupUsername(t) => t.extra(),
+            TdFunction::SetTdlibParameters(t) => t.extra(),
+            TdFunction::SetUserPrivacySettingRules(t) => t.extra(),
+            TdFunction::SetUsername(t) => t.extra(),
+            TdFunction::SetVideoChatDefaultParticipant(t) => t.extra(),
+            TdFunction::SharePhoneNumber(t) => t.extra(),
+            TdFunction::StartGroupCallRecording(t) => t.extra(),
+            TdFunction::StartGroupCallScreenSharing(t) => t.extra(),
+            TdFunction::StartScheduledGroupCall(t) => t.extra(),
+            TdFunction::StopPoll(t) => t.extra(),
+            TdFunction::SynchronizeLanguagePack(t) => t.extra(),
+            TdFunction::TerminateAllOtherSessions(t) => t.extra(),
+            TdFunction::TerminateSession(t) => t.extra(),
+            TdFunction::TestCallBytes(t) => t.extra(),
+            TdFunction::TestCallEmpty(t) => t.extra(),
+            TdFunction::TestCallString(t) => t.extra(),
+            TdFunction::TestCallVectorInt(t) => t.extra(),
+            TdFunction::TestCallVectorIntObject(t) => t.extra(),
+            TdFunction::TestCallVectorString(t) => t.extra(),
+            TdFunction::TestCallVectorStringObject(t) => t.extra(),
+            TdFunction::TestGetDifference(t) => t.extra(),
+            TdFunction::TestNetwork(t) => t.extra(),
+            TdFunction::TestProxy(t) => t.extra(),
+            TdFunction::TestReturnError(t) => t.extra(),
+            TdFunction::TestSquareInt(t) => t.extra(),
+            TdFunction::TestUseUpdate(t) => t.extra(),
+            TdFunction::ToggleChatDefaultDisableNotification(t) => t.extra(),
+            TdFunction::ToggleChatHasProtectedContent(t) => t.extra(),
+            TdFunction::ToggleChatIsMarkedAsUnread(t) => t.extra(),
+            TdFunction::ToggleChatIsPinned(t) => t.extra(),
+            TdFunction::ToggleGroupCallEnabledStartNotification(t) => t.extra(),
+            TdFunction::ToggleGroupCallIsMyVideoEnabled(t) => t.extra(),
+            TdFunction::ToggleGroupCallIsMyVideoPaused(t) => t.extra(),
+            TdFunction::ToggleGroupCallMuteNewParticipants(t) => t.extra(),
+            TdFunction::ToggleGroupCallParticipantIsHandRaised(t) => t.extra(),
+            TdFunction::ToggleGroupCallParticipantIsMuted(t) => t.extra(),
+            TdFunction::ToggleGroupCallScreenSharingIsPaused(t) => t.extra(),
+            TdFunction::ToggleMessageSenderIsBlocked(t) => t.extra(),
+            TdFunction::ToggleSessionCanAcceptCalls(t) => t.extra(),
+            TdFunction::ToggleSessionCanAcceptSecretChats(t) => t.extra(),
+            TdFunction::ToggleSupergroupIsAllHistoryAvailable(t) => t.extra(),
+            TdFunction::ToggleSupergroupIsBroadcastGroup(t) => t.extra(),
+            TdFunction::ToggleSupergroupSignMessages(t) => t.extra(),
+            TdFunction::TransferChatOwnership(t) => t.extra(),
+            TdFunction::UnpinAllChatMessages(t) => t.extra(),
+            TdFunction::UnpinChatMessage(t) => t.extra(),
+            TdFunction::UpgradeBasicGroupChatToSupergroupChat(t) => t.extra(),
+            TdFunction::UploadFile(t) => t.extra(),
+            TdFunction::UploadStickerFile(t) => t.extra(),
+            TdFunction::ValidateOrderInfo(t) => t.extra(),
+            TdFunction::ViewMessages(t) => t.extra(),
+            TdFunction::ViewTrendingStickerSets(t) => t.extra(),
+            TdFunction::WriteGeneratedFilePart(t) => t.extra(),
+            TdFunction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
+        }
+    }
+    #[doc(hidden)]
+    fn client_id(&self) -> Option<i32> {
+        match self {
+            TdFunction::AcceptCall(t) => t.client_id(),
+            TdFunction::AcceptTermsOfService(t) => t.client_id(),
+            TdFunction::AddChatMember(t) => t.client_id(),
+            TdFunction::AddChatMembers(t) => t.client_id(),
+            TdFunction::AddChatToList(t) => t.client_id(),
+            TdFunction::AddContact(t) => t.client_id(),
+            TdFunction::AddCustomServerLanguagePack(t) => t.client_id(),
+            TdFunction::AddFavoriteSticker(t) => t.client_id(),
+            TdFunction::AddLocalMessage(t) => t.client_id(),
+            TdFunction::AddLogMessage(t) => t.client_id(),
+            TdFunction::AddNetworkStatistics(t) => t.client_id(),
+            TdFunction::AddProxy(t) => t.client_id(),
+            TdFunction::AddRecentSticker(t) => t.client_id(),
+            TdFunction::AddRecentlyFoundChat(t) => t.client_id(),
+            TdFunction::AddSavedAnimation(t) => t.client_id(),
+            TdFunction::AddStickerToSet(t) => t.client_id(),
+            TdFunction::AnswerCallbackQuery(t) => t.client_id(),
+            TdFunction::AnswerCustomQuery(t) => t.client_id(),
+            TdFunction::AnswerInlineQuery(t) => t.client_id(),
+            TdFunction::AnswerPreCheckoutQuery(t) => t.client_id(),
+            TdFunction::AnswerShippingQuery(t) => t.client_id(),
+            TdFunction::BanChatMember(t) => t.client_id(),
+            TdFunction::BlockMessageSenderFromReplies(t) => t.client_id(),
+            TdFunction::CanTransferOwnership(t) => t.client_id(),
+            TdFunction::CancelDownloadFile(t) => t.client_id(),
+            TdFunction::CancelPasswordReset(t) => t.client_id(),
+            TdFunction::CancelUploadFile(t) => t.client_id(),
+            TdFunction::ChangeImportedContacts(t) => t.client_id(),
+            TdFunction::ChangePhoneNumber(t) => t.client_id(),
+            TdFunction::ChangeStickerSet(t) => t.client_id(),
+            TdFunction::CheckAuthenticationBotToken(t) => t.client_id(),
+            TdFunction::CheckAuthenticationCode(t) => t.client_id(),
+            TdFunction::CheckAuthenticationPassword(t) => t.client_id(),
+            TdFunction::CheckAuthenticationPasswordRecoveryCode(t) => t.client_id(),
+            TdFunction::CheckChangePhoneNumberCode(t) => t.client_id(),
+            TdFunction::CheckChatInviteLink(t) => t.client_id(),
+            TdFunction::CheckChatUsername(t) => t.client_id(),
+            TdFunction::CheckCreatedPublicChatsLimit(t) => t.client_id(),
+            TdFunction::CheckDatabaseEncryptionKey(t) => t.client_id(),
+            TdFunction::CheckEmailAddressVerificationCode(t) => t.client_id(),
+            TdFunction::CheckPasswordRecoveryCode(t) => t.client_id(),
+            TdFunction::CheckPhoneNumberConfirmationCode(t) => t.client_id(),
+            TdFunction::CheckPhoneNumberVerificationCode(t) => t.client_id(),
+            TdFunction::CheckRecoveryEmailAddressCode(t) => t.client_id(),
+            TdFunction::CheckStickerSetName(t) => t.client_id(),
+            TdFunction::CleanFileName(t) => t.client_id(),
+            TdFunction::ClearAllDraftMessages(t) => t.client_id(),
+            TdFunction::ClearImportedContacts(t) => t.client_id(),
+            TdFunction::ClearRecentStickers(t) => t.client_id(),
+            TdFunction::ClearRecentlyFoundChats(t) => t.client_id(),
+            TdFunction::ClickAnimatedEmojiMessage(t) => t.client_id(),
+            TdFunction::Close(t) => t.client_id(),
+            TdFunction::CloseChat(t) => t.client_id(),
+            TdFunction::CloseSecretChat(t) => t.client_id(),
+            TdFunction::ConfirmQrCodeAuthentication(t) => t.client_id(),
+            TdFunction::CreateBasicGroupChat(t) => t.client_id(),
+            TdFunction::CreateCall(t) => t.client_id(),
+            TdFunction::CreateChatFilter(t) => t.client_id(),
+            TdFunction::CreateChatInviteLink(t) => t.client_id(),
+            TdFunction::CreateNewBasicGroupChat(t) => t.client_id(),
+            TdFunction::CreateNewSecretChat(t) => t.client_id(),
+            TdFunction::CreateNewStickerSet(t) => t.client_id(),
+            TdFunction::CreateNewSupergroupChat(t) => t.client_id(),
+            TdFunction::CreatePrivateChat(t) => t.client_id(),
+            TdFunction::CreateSecretChat(t) => t.client_id(),
+            TdFunction::CreateSupergroupChat(t) => t.client_id(),
+            TdFunction::CreateTemporaryPassword(t) => t.client_id(),
+            TdFunction::CreateVideoChat(t) => t.client_id(),
+            TdFunction::DeleteAccount(t) => t.client_id(),
+            TdFunction::DeleteAllCallMessages(t) => t.client_id(),
+            TdFunction::DeleteAllRevokedChatInviteLinks(t) => t.client_id(),
+            TdFunction::DeleteChat(t) => t.client_id(),
+            TdFunction::DeleteChatFilter(t) => t.client_id(),
+            TdFunction::DeleteChatHistory(t) => t.client_id(),
+            TdFunction::DeleteChatMessagesByDate(t) => t.client_id(),
+            TdFunction::DeleteChatMessagesBySender(t) => t.client_id(),
+            TdFunction::DeleteChatReplyMarkup(t) => t.client_id(),
+            TdFunction::DeleteCommands(t) => t.client_id(),
+            TdFunction::DeleteFile(t) => t.client_id(),
+            TdFunction::DeleteLanguagePack(t) => t.client_id(),
+            TdFunction::DeleteMessages(t) => t.client_id(),
+            TdFunction::DeletePassportElement(t) => t.client_id(),
+            TdFunction::DeleteProfilePhoto(t) => t.client_id(),
+            TdFunction::DeleteRevokedChatInviteLink(t) => t.client_id(),
+            TdFunction::DeleteSavedCredentials(t) => t.client_id(),
+            TdFunction::DeleteSavedOrderInfo(t) => t.client_id(),
+            TdFunction::Destroy(t) => t.client_id(),
+            TdFunction::DisableProxy(t) => t.client_id(),
+            TdFunction::DiscardCall(t) => t.client_id(),
+            TdFunction::DisconnectAllWebsites(t) => t.client_id(),
+            TdFunction::DisconnectWebsite(t) => t.client_id(),
+            TdFunction::DownloadFile(t) => t.client_id(),
+            TdFunction::EditChatFilter(t) => t.client_id(),
+            TdFunction::EditChatInviteLink(t) => t.client_id(),
+            TdFunction::EditCustomLanguagePackInfo(t) => t.client_id(),
+            TdFunction::EditInlineMessageCaption(t) => t.client_id(),
+            TdFunction::EditInlineMessageLiveLocation(t) => t.client_id(),
+            TdFunction::EditInlineMessageMedia(t) => t.client_id(),
+            TdFunction::EditInlineMessageReplyMarkup(t) => t.client_id(),
+            TdFunction::EditInlineMessageText(t) => t.client_id(),
+            TdFunction::EditMessageCaption(t) => t.client_id(),
+            TdFunction::EditMessageLiveLocation(t) => t.client_id(),
+            TdFunction::EditMessageMedia(t) => t.client_id(),
+            TdFunction::EditMessageReplyMarkup(t) => t.client_id(),
+            TdFunction::EditMessageSchedulingState(t) => t.client_id(),
+            TdFunction::EditMessageText(t) => t.client_id(),
+            TdFunction::EditProxy(t) => t.client_id(),
+            TdFunction::EnableProxy(t) => t.client_id(),
+            TdFunction::EndGroupCall(t) => t.client_id(),
+            TdFunction::EndGroupCallRecording(t) => t.client_id(),
+            TdFunction::EndGroupCallScreenSharing(t) => t.client_id(),
+            TdFunction::FinishFileGeneration(t) => t.client_id(),
+            TdFunction::ForwardMessages(t) => t.client_id(),
+            TdFunction::GetAccountTtl(t) => t.client_id(),
+            TdFunction::GetActiveLiveLocationMessages(t) => t.client_id(),
+            TdFunction::GetActiveSessions(t) => t.client_id(),
+            TdFunction::GetAllPassportElements(t) => t.client_id(),
+            TdFunction::GetAnimatedEmoji(t) => t.client_id(),
+            TdFunction::GetApplicationConfig(t) => t.client_id(),
+            TdFunction::GetApplicationDownloadLink(t) => t.client_id(),
+            TdFunction::GetArchivedStickerSets(t) => t.client_id(),
+            TdFunction::GetAttachedStickerSets(t) => t.client_id(),
+            TdFunction::GetAuthorizationState(t) => t.client_id(),
+            TdFunction::GetAutoDownloadSettingsPresets(t) => t.client_id(),
+            TdFunction::GetBackgroundUrl(t) => t.client_id(),
+            TdFunction::GetBackgrounds(t) => t.client_id(),
+            TdFunction::GetBankCardInfo(t) => t.client_id(),
+            TdFunction::GetBasicGroup(t) => t.client_id(),
+            TdFunction::GetBasicGroupFullInfo(t) => t.client_id(),
+            TdFunction::GetBlockedMessageSenders(t) => t.client_id(),
+            TdFunction::GetCallbackQueryAnswer(t) => t.client_id(),
+            TdFunction::GetCallbackQueryMessage(t) => t.client_id(),
+            TdFunction::GetChat(t) => t.client_id(),
+            TdFunction::GetChatAdministrators(t) => t.client_id(),
+            TdFunction::GetChatAvailableMessageSenders(t) => t.client_id(),
+            TdFunction::GetChatEventLog(t) => t.client_id(),
+            TdFunction::GetChatFilter(t) => t.client_id(),
+            TdFunction::GetChatFilterDefaultIconName(t) => t.client_id(),
+            TdFunction::GetChatHistory(t) => t.client_id(),
+            TdFunction::GetChatInviteLink(t) => t.client_id(),
+            TdFunction::GetChatInviteLinkCounts(t) => t.client_id(),
+            TdFunction::GetChatInviteLinkMembers(t) => t.client_id(),
+            TdFunction::GetChatInviteLinks(t) => t.client_id(),
+            TdFunction::GetChatJoinRequests(t) => t.client_id(),
+            TdFunction::GetChatListsToAddChat(t) => t.client_id(),
+            TdFunction::GetChatMember(t) => t.client_id(),
+            TdFunction::GetChatMessageByDate(t) => t.client_id(),
+            TdFunction::GetChatMessageCalendar(t) => t.client_id(),
+            TdFunction::GetChatMessageCount(t) => t.client_id(),
+            TdFunction::GetChatNotificationSettingsExceptions(t) => t.client_id(),
+            TdFunction::GetChatPinnedMessage(t) => t.client_id(),
+            TdFunction::GetChatScheduledMessages(t) => t.client_id(),
+            TdFunction::GetChatSparseMessagePositions(t) => t.client_id(),
+            TdFunction::GetChatSponsoredMessage(t) => t.client_id(),
+            TdFunction::GetChatStatistics(t) => t.client_id(),
+            TdFunction::GetChats(t) => t.client_id(),
+            TdFunction::GetCommands(t) => t.client_id(),
+            TdFunction::GetConnectedWebsites(t) => t.client_id(),
+            TdFunction::GetContacts(t) => t.client_id(),
+            TdFunction::GetCountries(t) => t.client_id(),
+            TdFunction::GetCountryCode(t) => t.client_id(),
+            TdFunction::GetCreatedPublicChats(t) => t.client_id(),
+            TdFunction::GetCurrentState(t) => t.client_id(),
+            TdFunction::GetDatabaseStatistics(t) => t.client_id(),
+            TdFunction::GetDeepLinkInfo(t) => t.client_id(),
+            TdFunction::GetEmojiSuggestionsUrl(t) => t.client_id(),
+            TdFunction::GetExternalLink(t) => t.client_id(),
+            TdFunction::GetExternalLinkInfo(t) => t.client_id(),
+            TdFunction::GetFavoriteStickers(t) => t.client_id(),
+            TdFunction::GetFile(t) => t.client_id(),
+            TdFunction::GetFileDownloadedPrefixSize(t) => t.client_id(),
+            TdFunction::GetFileExtension(t) => t.client_id(),
+            TdFunction::GetFileMimeType(t) => t.client_id(),
+            TdFunction::GetGameHighScores(t) => t.client_id(),
+            TdFunction::GetGroupCall(t) => t.client_id(),
+            TdFunction::GetGroupCallInviteLink(t) => t.client_id(),
+            TdFunction::GetGroupCallStreamSegment(t) => t.client_id(),
+            TdFunction::GetGroupsInCommon(t) => t.client_id(),
+            TdFunction::GetImportedContactCount(t) => t.client_id(),
+            TdFunction::GetInactiveSupergroupChats(t) => t.client_id(),
+            TdFunction::GetInlineGameHighScores(t) => t.client_id(),
+            TdFunction::GetInlineQueryResults(t) => t.client_id(),
+            TdFunction::GetInstalledStickerSets(t) => t.client_id(),
+            TdFunction::GetInternalLinkType(t) => t.client_id(),
+            TdFunction::GetJsonString(t) => t.client_id(),
+            TdFunction::GetJsonValue(t) => t.client_id(),
+            TdFunction::GetLanguagePackInfo(t) => t.client_id(),
+            TdFunction::GetLanguagePackString(t) => t.client_id(),
+            TdFunction::GetLanguagePackStrings(t) => t.client_id(),
+            TdFunction::GetLocalizationTargetInfo(t) => t.client_id(),
+            TdFunction::GetLogStream(t) => t.client_id(),
+            TdFunction::GetLogTagVerbosityLevel(t) => t.client_id(),
+            TdFunction::GetLogTags(t) => t.client_id(),
+            TdFunction::GetLogVerbosityLevel(t) => t.client_id(),
+            TdFunction::GetLoginUrl(t) => t.client_id(),
+            TdFunction::GetLoginUrlInfo(t) => t.client_id(),
+            TdFunction::GetMapThumbnailFile(t) => t.client_id(),
+            TdFunction::GetMarkdownText(t) => t.client_id(),
+            TdFunction::GetMe(t) => t.client_id(),
+            TdFunction::GetMessage(t) => t.client_id(),
+            TdFunction::GetMessageEmbeddingCode(t) => t.client_id(),
+            TdFunction::GetMessageFileType(t) => t.client_id(),
+            TdFunction::GetMessageImportConfirmationText(t) => t.client_id(),
+            TdFunction::GetMessageLink(t) => t.client_id(),
+            TdFunction::GetMessageLinkInfo(t) => t.client_id(),
+            TdFunction::GetMessageLocally(t) => t.client_id(),
+            TdFunction::GetMessagePublicForwards(t) => t.client_id(),
+            TdFunction::GetMessageStatistics(t) => t.client_id(),
+            TdFunction::GetMessageThread(t) => t.client_id(),
+            TdFunction::GetMessageThreadHistory(t) => t.client_id(),
+            TdFunction::GetMessageViewers(t) => t.client_id(),
+            TdFunction::GetMessages(t) => t.client_id(),
+            TdFunction::GetNetworkStatistics(t) => t.client_id(),
+            TdFunction::GetOption(t) => t.client_id(),
+            TdFunction::GetPassportAuthorizationForm(t) => t.client_id(),
+            TdFunction::GetPassportAuthorizationFormAvailableElements(t) => t.client_id(),
+            TdFunction::GetPassportElement(t) => t.client_id(),
+            TdFunction::GetPasswordState(t) => t.client_id(),
+            TdFunction::GetPaymentForm(t) => t.client_id(),
+            TdFunction::GetPaymentReceipt(t) => t.client_id(),
+            TdFunction::GetPhoneNumberInfo(t) => t.client_id(),
+            TdFunction::GetPhoneNumberInfoSync(t) => t.client_id(),
+            TdFunction::GetPollVoters(t) => t.client_id(),
+            TdFunction::GetPreferredCountryLanguage(t) => t.client_id(),
+            TdFunction::GetProxies(t) => t.client_id(),
+            TdFunction::GetProxyLink(t) => t.client_id(),
+            TdFunction::GetPushReceiverId(t) => t.client_id(),
+            TdFunction::GetRecentInlineBots(t) => t.client_id(),
+            TdFunction::GetRecentStickers(t) => t.client_id(),
+            TdFunction::GetRecentlyOpenedChats(t) => t.client_id(),
+            TdFunction::GetRecentlyVisitedTMeUrls(t) => t.client_id(),
+            TdFunction::GetRecommendedChatFilters(t) => t.client_id(),
+            TdFunction::GetRecoveryEmailAddress(t) => t.client_id(),
+            TdFunction::GetRemoteFile(t) => t.client_id(),
+            TdFunction::GetRepliedMessage(t) => t.client_id(),
+            TdFunction::GetSavedAnimations(t) => t.client_id(),
+            TdFunction::GetSavedOrderInfo(t) => t.client_id(),
+            TdFunction::GetScopeNotificationSettings(t) => t.client_id(),
+            TdFunction::GetSecretChat(t) => t.client_id(),
+            TdFunction::GetStatisticalGraph(t) => t.client_id(),
+            TdFunction::GetStickerEmojis(t) => t.client_id(),
+            TdFunction::GetStickerSet(t) => t.client_id(),
+            TdFunction::GetStickers(t) => t.client_id(),
+            TdFunction::GetStorageStatistics(t) => t.client_id(),
+            TdFunction::GetStorageStatisticsFast(t) => t.client_id(),
+            TdFunction::GetSuggestedFileName(t) => t.client_id(),
+            TdFunction::GetSuggestedStickerSetName(t) => t.client_id(),
+            TdFunction::GetSuitableDiscussionChats(t) => t.client_id(),
+            TdFunction::GetSupergroup(t) => t.client_id(),
+            TdFunction::GetSupergroupFullInfo(t) => t.client_id(),
+            TdFunction::GetSupergroupMembers(t) => t.client_id(),
+            TdFunction::GetSupportUser(t) => t.client_id(),
+            TdFunction::GetTemporaryPasswordState(t) => t.client_id(),
+            TdFunction::GetTextEntities(t) => t.client_id(),
+            TdFunction::GetTopChats(t) => t.client_id(),
+            TdFunction::GetTrendingStickerSets(t) => t.client_id(),
+            TdFunction::GetUser(t) => t.client_id(),
+            TdFunction::GetUserFullInfo(t) => t.client_id(),
+            TdFunction::GetUserPrivacySettingRules(t) => t.client_id(),
+            TdFunction::GetUserProfilePhotos(t) => t.client_id(),
+            TdFunction::GetVideoChatAvailableParticipants(t) => t.client_id(),
+            TdFunction::GetWebPageInstantView(t) => t.client_id(),
+            TdFunction::GetWebPagePreview(t) => t.client_id(),
+            TdFunction::HideSuggestedAction(t) => t.client_id(),
+            TdFunction::ImportContacts(t) => t.client_id(),
+            TdFunction::ImportMessages(t) => t.client_id(),
+            TdFunction::InviteGroupCallParticipants(t) => t.client_id(),
+            TdFunction::JoinChat(t) => t.client_id(),
+            TdFunction::JoinChatByInviteLink(t) => t.client_id(),
+            TdFunction::JoinGroupCall(t) => t.client_id(),
+            TdFunction::LeaveChat(t) => t.client_id(),
+            TdFunction::LeaveGroupCall(t) => t.client_id(),
+            TdFunction::LoadChats(t) => t.client_id(),
+            TdFunction::LoadGroupCallParticipants(t) => t.client_id(),
+            TdFunction::LogOut(t) => t.client_id(),
+            TdFunction::OpenChat(t) => t.client_id(),
+            TdFunction::OpenMessageContent(t) => t.client_id(),
+            TdFunction::OptimizeStorage(t) => t.client_id(),
+            TdFunction::ParseMarkdown(t) => t.client_id(),
+            TdFunction::ParseTextEntities(t) => t.client_id(),
+            TdFunction::PinChatMessage(t) => t.client_id(),
+            TdFunction::PingProxy(t) => t.client_id(),
+            TdFunction::ProcessChatJoinRequest(t) => t.client_id(),
+            TdFunction::ProcessChatJoinRequests(t) => t.client_id(),
+            TdFunction::ProcessPushNotification(t) => t.client_id(),
+            TdFunction::ReadAllChatMentions(t) => t.client_id(),
+            TdFunction::ReadFilePart(t) => t.client_id(),
+            TdFunction::RecoverAuthenticationPassword(t) => t.client_id(),
+            TdFunction::RecoverPassword(t) => t.client_id(),
+            TdFunction::RegisterDevice(t) => t.client_id(),
+            TdFunction::RegisterUser(t) => t.client_id(),
+            TdFunction::RemoveBackground(t) => t.client_id(),
+            TdFunction::RemoveChatActionBar(t) => t.client_id(),
+            TdFunction::RemoveContacts(t) => t.client_id(),
+            TdFunction::RemoveFavoriteSticker(t) => t.client_id(),
+            TdFunction::RemoveNotification(t) => t.client_id(),
+            TdFunction::RemoveNotificationGroup(t) => t.client_id(),
+            TdFunction::RemoveProxy(t) => t.client_id(),
+            TdFunction::RemoveRecentHashtag(t) => t.client_id(),
+            TdFunction::RemoveRecentSticker(t) => t.client_id(),
+            TdFunction::RemoveRecentlyFoundChat(t) => t.client_id(),
+            TdFunction::RemoveSavedAnimation(t) => t.client_id(),
+            TdFunction::RemoveStickerFromSet(t) => t.client_id(),
+            TdFunction::RemoveTopChat(t) => t.client_id(),
+            TdFunction::ReorderChatFilters(t) => t.client_id(),
+            TdFunction::ReorderInstalledStickerSets(t) => t.client_id(),
+            TdFunction::ReplacePrimaryChatInviteLink(t) => t.client_id(),
+            TdFunction::ReportChat(t) => t.client_id(),
+            TdFunction::ReportChatPhoto(t) => t.client_id(),
+            TdFunction::ReportSupergroupSpam(t) => t.client_id(),
+            TdFunction::RequestAuthenticationPasswordRecovery(t) => t.client_id(),
+            TdFunction::RequestPasswordRecovery(t) => t.client_id(),
+            TdFunction::RequestQrCodeAuthentication(t) => t.client_id(),
+            TdFunction::ResendAuthenticationCode(t) => t.client_id(),
+            TdFunction::ResendChangePhoneNumberCode(t) => t.client_id(),
+            TdFunction::ResendEmailAddressVerificationCode(t) => t.client_id(),
+            TdFunction::ResendMessages(t) => t.client_id(),
+            TdFunction::ResendPhoneNumberConfirmationCode(t) => t.client_id(),
+            TdFunction::ResendPhoneNumberVerificationCode(t) => t.client_id(),
+            TdFunction::ResendRecoveryEmailAddressCode(t) => t.client_id(),
+            TdFunction::ResetAllNotificationSettings(t) => t.client_id(),
+            TdFunction::ResetBackgrounds(t) => t.client_id(),
+            TdFunction::ResetNetworkStatistics(t) => t.client_id(),
+            TdFunction::ResetPassword(t) => t.client_id(),
+            TdFunction::RevokeChatInviteLink(t) => t.client_id(),
+            TdFunction::RevokeGroupCallInviteLink(t) => t.client_id(),
+            TdFunction::SaveApplicationLogEvent(t) => t.client_id(),
+            TdFunction::SearchBackground(t) => t.client_id(),
+            TdFunction::SearchCallMessages(t) => t.client_id(),
+            TdFunction::SearchChatMembers(t) => t.client_id(),
+            TdFunction::SearchChatMessages(t) => t.client_id(),
+            TdFunction::SearchChatRecentLocationMessages(t) => t.client_id(),
+            TdFunction::SearchChats(t) => t.client_id(),
+            TdFunction::SearchChatsNearby(t) => t.client_id(),
+            TdFunction::SearchChatsOnServer(t) => t.client_id(),
+            TdFunction::SearchContacts(t) => t.client_id(),
+            TdFunction::SearchEmojis(t) => t.client_id(),
+            TdFunction::SearchHashtags(t) => t.client_id(),
+            TdFunction::SearchInstalledStickerSets(t) => t.client_id(),
+            TdFunction::SearchMessages(t) => t.client_id(),
+            TdFunction::SearchPublicChat(t) => t.client_id(),
+            TdFunction::SearchPublicChats(t) => t.client_id(),
+            TdFunction::SearchSecretMessages(t) => t.client_id(),
+            TdFunction::SearchStickerSet(t) => t.client_id(),
+            TdFunction::SearchStickerSets(t) => t.client_id(),
+            TdFunction::SearchStickers(t) => t.client_id(),
+            TdFunction::SendBotStartMessage(t) => t.client_id(),
+            TdFunction::SendCallDebugInformation(t) => t.client_id(),
+            TdFunction::SendCallRating(t) => t.client_id(),
+            TdFunction::SendCallSignalingData(t) => t.client_id(),
+            TdFunction::SendChatAction(t) => t.client_id(),
+            TdFunction::SendChatScreenshotTakenNotification(t) => t.client_id(),
+            TdFunction::SendCustomRequest(t) => t.client_id(),
+            TdFunction::SendEmailAddressVerificationCode(t) => t.client_id(),
+            TdFunction::SendInlineQueryResultMessage(t) => t.client_id(),
+            TdFunction::SendMessage(t) => t.client_id(),
+            TdFunction::SendMessageAlbum(t) => t.client_id(),
+            TdFunction::SendPassportAuthorizationForm(t) => t.client_id(),
+            TdFunction::SendPaymentForm(t) => t.client_id(),
+            TdFunction::SendPhoneNumberConfirmationCode(t) => t.client_id(),
+            TdFunction::SendPhoneNumberVerificationCode(t) => t.client_id(),
+            TdFunction::SetAccountTtl(t) => t.client_id(),
+            TdFunction::SetAlarm(t) => t.client_id(),
+            TdFunction::SetAuthenticationPhoneNumber(t) => t.client_id(),
+            TdFunction::SetAutoDownloadSettings(t) => t.client_id(),
+            TdFunction::SetBackground(t) => t.client_id(),
+            TdFunction::SetBio(t) => t.client_id(),
+            TdFunction::SetBotUpdatesStatus(t) => t.client_id(),
+            TdFunction::SetChatClientData(t) => t.client_id(),
+            TdFunction::SetChatDescription(t) => t.client_id(),
+            TdFunction::SetChatDiscussionGroup(t) => t.client_id(),
+            TdFunction::SetChatDraftMessage(t) => t.client_id(),
+            TdFunction::SetChatLocation(t) => t.client_id(),
+            TdFunction::SetChatMemberStatus(t) => t.client_id(),
+            TdFunction::SetChatMessageSender(t) => t.client_id(),
+            TdFunction::SetChatMessageTtl(t) => t.client_id(),
+            TdFunction::SetChatNotificationSettings(t) => t.client_id(),
+            TdFunction::SetChatPermissions(t) => t.client_id(),
+            TdFunction::SetChatPhoto(t) => t.client_id(),
+            TdFunction::SetChatSlowModeDelay(t) => t.client_id(),
+            TdFunction::SetChatTheme(t) => t.client_id(),
+            TdFunction::SetChatTitle(t) => t.client_id(),
+            TdFunction::SetCommands(t) => t.client_id(),
+            TdFunction::SetCustomLanguagePack(t) => t.client_id(),
+            TdFunction::SetCustomLanguagePackString(t) => t.client_id(),
+            TdFunction::SetDatabaseEncryptionKey(t) => t.client_id(),
+            TdFunction::SetFileGenerationProgress(t) => t.client_id(),
+            TdFunction::SetGameScore(t) => t.client_id(),
+            TdFunction::SetGroupCallParticipantIsSpeaking(t) => t.client_id(),
+            TdFunction::SetGroupCallParticipantVolumeLevel(t) => t.client_id(),
+            TdFunction::SetGroupCallTitle(t) => t.client_id(),
+            TdFunction::SetInactiveSessionTtl(t) => t.client_id(),
+            TdFunction::SetInlineGameScore(t) => t.client_id(),
+            TdFunction::SetLocation(t) => t.client_id(),
+            TdFunction::SetLogStream(t) => t.client_id(),
+            TdFunction::SetLogTagVerbosityLevel(t) => t.client_id(),
+            TdFunction::SetLogVerbosityLevel(t) => t.client_id(),
+            TdFunction::SetName(t) => t.client_id(),
+            TdFunction::SetNetworkType(t) => t.client_id(),
+            TdFunction::SetOption(t) => t.client_id(),
+            TdFunction::SetPassportElement(t) => t.client_id(),
+            TdFunction::SetPassportElementErrors(t) => t.client_id(),
+            TdFunction::SetPassword(t) => t.client_id(),
+            TdFunction::SetPinnedChats(t) => t.client_id(),
+            TdFunction::SetPollAnswer(t) => t.client_id(),
+            TdFunction::SetProfilePhoto(t) => t.client_id(),
+            TdFunction::SetRecoveryEmailAddress(t) => t.client_id(),
+            TdFunction::SetScopeNotificationSettings(t) => t.client_id(),
+            TdFunction::SetStickerPositionInSet(t) => t.client_id(),
+            TdFunction::SetStickerSetThumbnail(t) => t.client_id(),
+            TdFunction::SetSupergroupStickerSet(t) => t.client_id(),
+            TdFunction::SetSupergroupUsername(t) => t.client_id(),
+            TdFunction::SetTdlibParameters(t) => t.client_id(),
+            TdFunction::SetUserPrivacySettingRules(t) => t.client_id(),
+            TdFunction::SetUsername(t) => t.client_id(),
+            TdFunction::SetVideoChatDefaultParticipant(t) => t.client_id(),
+            TdFunction::SharePhoneNumber(t) => t.client_id(),
+            TdFunction::StartGroupCallRecording(t) => t.client_id(),
+            TdFunction::StartGroupCallScreenSharing(t) => t.client_id(),
+            TdFunction::StartScheduledGroupCall(t) => t.client_id(),
+            TdFunction::StopPoll(t) => t.client_id(),
+            TdFunction::SynchronizeLanguagePack(t) => t.client_id(),
+            TdFunction::TerminateAllOtherSessions(t) => t.client_id(),
+            TdFunction::TerminateSession(t) => t.client_id(),
+            TdFunction::TestCallBytes(t) => t.client_id(),
+            TdFunction::TestCallEmpty(t) => t.client_id(),
+            TdFunction::TestCallString(t) => t.client_id(),
+            TdFunction::TestCallVectorInt(t) => t.client_id(),
+            TdFunction::TestCallVectorIntObject(t) => t.client_id(),
+            TdFunction::TestCallVectorString(t) => t.client_id(),
+            TdFunction::TestCallVectorStringObject(t) => t.client_id(),
+            TdFunction::TestGetDifference(t) => t.client_id(),
+            TdFunction::TestNetwork(t) => t.client_id(),
+            TdFunction::TestProxy(t) => t.client_id(),
+            TdFunction::TestReturnError(t) => t.client_id(),
+            TdFunction::TestSquareInt(t) => t.client_id(),
+            TdFunction::TestUseUpdate(t) => t.client_id(),
+            TdFunction::ToggleChatDefaultDisableNotification(t) => t.client_id(),
+            TdFunction::ToggleChatHasProtectedContent(t) => t.client_id(),
+            TdFunction::ToggleChatIsMarkedAsUnread(t) => t.client_id(),
+            TdFunction::ToggleChatIsPinned(t) => t.client_id(),
+            TdFunction::ToggleGroupCallEnabledStartNotification(t) => t.client_id(),
+            TdFunction::ToggleGroupCallIsMyVideoEnabled(t) => t.client_id(),
+            TdFunction::ToggleGroupCallIsMyVideoPaused(t) => t.client_id(),
+            TdFunction::ToggleGroupCallMuteNewParticipants(t) => t.client_id(),
+            TdFunction::ToggleGroupCallParticipantIsHandRaised(t) => t.client_id(),
+            TdFunction::ToggleGroupCallParticipantIsMuted(t) => t.client_id(),
+            TdFunction::ToggleGroupCallScreenSharingIsPaused(t) => t.client_id(),
+            TdFunction::ToggleMessageSenderIsBlocked(t) => t.client_id(),
+            TdFunction::ToggleSessionCanAcceptCalls(t) => t.client_id(),
+            TdFunction::ToggleSessionCanAcceptSecretChats(t) => t.client_id(),
+            TdFunction::ToggleSupergroupIsAllHistoryAvailable(t) => t.client_id(),
+            TdFunction::ToggleSupergroupIsBroadcastGroup(t) => t.client_id(),
+            TdFunction::ToggleSupergroupSignMessages(t) => t.client_id(),
+            TdFunction::TransferChatOwnership(t) => t.client_id(),
+            TdFunction::UnpinAllChatMessages(t) => t.client_id(),
+            TdFunction::UnpinChatMessage(t) => t.client_id(),
+            TdFunction::UpgradeBasicGroupChatToSupergroupChat(t) => t.client_id(),
+            TdFunction::UploadFile(t) => t.client_id(),
+            TdFunction::UploadStickerFile(t) => t.client_id(),
+            TdFunction::ValidateOrderInfo(t) => t.client_id(),
+            TdFunction::ViewMessages(t) => t.client_id(),
+            TdFunction::ViewTrendingStickerSets(t) => t.client_id(),
+            TdFunction::WriteGeneratedFilePart(t) => t.client_id(),
+            TdFunction::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
+        }
+    }
+}
+
+impl RFunction for TdFunction {
+    type Response = TdObject;
+}
+
+impl TdFunction {
+    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+    /// Name of the function, value of its `@type`
+    pub fn type_name(&self) -> &str {
+        match self {
+            TdFunction::AcceptCall(_) => "acceptCall",
+            TdFunction::AcceptTermsOfService(_) => "acceptTermsOfService",
+            TdFunction::AddChatMember(_) => "addChatMember",
+            TdFunction::AddChatMembers(_) => "addChatMembers",
+            TdFunction::AddChatToList(_) => "addChatToList",
+            TdFunction::AddContact(_) => "addContact",
+            TdFunction::AddCustomServerLanguagePack(_) => "addCustomServerLanguagePack",
+            TdFunction::AddFavoriteSticker(_) => "addFavoriteSticker",
+            TdFunction::AddLocalMessage(_) => "addLocalMessage",
+            TdFunction::AddLogMessage(_) => "addLogMessage",
+            TdFunction::AddNetworkStatistics(_) => "addNetworkStatistics",
+            TdFunction::AddProxy(_) => "addProxy",
+            TdFunction::AddRecentSticker(_) => "addRecentSticker",
+            TdFunction::AddRecentlyFoundChat(_) => "addRecentlyFoundChat",
+            TdFunction::AddSavedAnimation(_) => "addSavedAnimation",
+            TdFunction::AddStickerToSet(_) => "addStickerToSet",
+            TdFunction::AnswerCallbackQuery(_) => "answerCallbackQuery",
+            TdFunction::AnswerCustomQuery(_) => "answerCustomQuery",
+            TdFunction::AnswerInlineQuery(_) => "answerInlineQuery",
+            TdFunction::AnswerPreCheckoutQuery(_) => "answerPreCheckoutQuery",
+            TdFunction::AnswerShippingQuery(_) => "answerShippingQuery",
+            TdFunction::BanChatMember(_) => "banChatMember",
+            TdFunction::BlockMessageSenderFromReplies(_) => "blockMessageSenderFromReplies",
+            TdFunction::CanTransferOwnership(_) => "canTransferOwnership",
+            TdFunction::CancelDownloadFile(_) => "cancelDownloadFile",
+            TdFunction::CancelPasswordReset(_) => "cancelPasswordReset",
+            TdFunction::CancelUploadFile(_) => "cancelUploadFile",
+            TdFunction::ChangeImportedContacts(_) => "changeImportedContacts",
+            TdFunction::ChangePhoneNumber(_) => "changePhoneNumber",
+            TdFunction::ChangeStickerSet(_) => "changeStickerSet",
+            TdFunction::CheckAuthenticationBotToken(_) => "checkAuthenticationBotToken",
+            TdFunction::CheckAuthenticationCode(_) => "checkAuthenticationCode",
+            TdFunction::CheckAuthenticationPassword(_) => "checkAuthenticationPassword",
+            TdFunction::CheckAuthenticationPasswordRecoveryCode(_) => {
+                "checkAuthenticationPasswordRecoveryCode"
+            }
+            TdFunction::CheckChangePhoneNumberCode(_) => "checkChangePhoneNumberCode",
+            TdFunction::CheckChatInviteLink(_) => "checkChatInviteLink",
+            TdFunction::CheckChatUsername(_) => "checkChatUsername",
+            TdFunction::CheckCreatedPublicChatsLimit(_) => "checkCreatedPublicChatsLimit",
+            TdFunction::CheckDatabaseEncryptionKey(_) => "checkDatabaseEncryptionKey",
+            TdFunction::CheckEmailAddressVerificationCode(_) => "checkEmailAddressVerificationCode",
+            TdFunction::CheckPasswordRecoveryCode(_) => "checkPasswordRecoveryCode",
+            TdFunction::CheckPhoneNumberConfirmationCode(_) => "checkPhoneNumberConfirmationCode",
+            TdFunction::CheckPhoneNumberVerificationCode(_) => "checkPhoneNumberVerificationCode",
+            TdFunction::CheckRecoveryEmailAddressCode(_) => "checkRecoveryEmailAddressCode",
+            TdFunction::CheckStickerSetName(_) => "checkStickerSetName",
+            TdFunction::CleanFileName(_) => "cleanFileName",
+            TdFunction::ClearAllDraftMessages(_) => "clearAllDraftMessages",
+            TdFunction::ClearImportedContacts(_) => "clearImportedContacts",
+            TdFunction::ClearRecentStickers(_) => "clearRecentStickers",
+            TdFunction::ClearRecentlyFoundChats(_) => "clearRecentlyFoundChats",
+            TdFunction::ClickAnimatedEmojiMessage(_) => "clickAnimatedEmojiMessage",
+            TdFunction::Close(_) => "close",
+            TdFunction::CloseChat(_) => "closeChat",
+            TdFunction::CloseSecretChat(_) => "closeSecretChat",
+            TdFunction::ConfirmQrCodeAuthentication(_) => "confirmQrCodeAuthentication",
+            TdFunction::CreateBasicGroupChat(_) => "createBasicGroupChat",
+            TdFunction::CreateCall(_) => "createCall",
+            TdFunction::CreateChatFilter(_) => "createChatFilter",
+            TdFunction::CreateChatInviteLink(_) => "createChatInviteLink",
+            TdFunction::CreateNewBasicGroupChat(_) => "createNewBasicGroupChat",
+            TdFunction::CreateNewSecretChat(_) => "createNewSecretChat",
+            TdFunction::CreateNewStickerSet(_) => "createNewStickerSet",
+            TdFunction::CreateNewSupergroupChat(_) => "createNewSupergroupChat",
+            TdFunction::CreatePrivateChat(_) => "createPrivateChat",
+            TdFunction::CreateSecretChat(_) => "createSecretChat",
+            TdFunction::CreateSupergroupChat(_) => "createSupergroupChat",
+            TdFunction::CreateTemporaryPassword(_) => "createTemporaryPassword",
+            TdFunction::CreateVideoChat(_) => "createVideoChat",
+            TdFunction::DeleteAccount(_) => "deleteAccount",
+            TdFunction::DeleteAllCallMessages(_) => "deleteAllCallMessages",
+            TdFunction::DeleteAllRevokedChatInviteLinks(_) => "deleteAllRevokedChatInviteLinks",
+            TdFunction::DeleteChat(_) => "deleteChat",
+            TdFunction::DeleteChatFilter(_) => "deleteChatFilter",
+            TdFunction::DeleteChatHistory(_) => "deleteChatHistory",
+            TdFunction::DeleteChatMessagesByDate(_) => "deleteChatMessagesByDate",
+            TdFunction::DeleteChatMessagesBySender(_) => "deleteChatMessagesBySender",
+            TdFunction::DeleteChatReplyMarkup(_) => "deleteChatReplyMarkup",
+            TdFunction::DeleteCommands(_) => "deleteCommands",
+            TdFunction::DeleteFile(_) => "deleteFile",
+            TdFunction::DeleteLanguagePack(_) => "deleteLanguagePack",
+            TdFunction::DeleteMessages(_) => "deleteMessages",
+            TdFunction::DeletePassportElement(_) => "deletePassportElement",
+            TdFunction::DeleteProfilePhoto(_) => "deleteProfilePhoto",
+            TdFunction::DeleteRevokedChatInviteLink(_) => "deleteRevokedChatInviteLink",
+            TdFunction::DeleteSavedCredentials(_) => "deleteSavedCredentials",
+            TdFunction::DeleteSavedOrderInfo(_) => "deleteSavedOrderInfo",
+            TdFunction::Destroy(_) => "destroy",
+            TdFunction::DisableProxy(_) => "disableProxy",
+            TdFunction::DiscardCall(_) => "discardCall",
+            TdFunction::DisconnectAllWebsites(_) => "disconnectAllWebsites",
+            TdFunction::DisconnectWebsite(_) => "disconnectWebsite",
+            TdFunction::DownloadFile(_) => "downloadFile",
+            TdFunction::EditChatFilter(_) => "editChatFilter",
+            TdFunction::EditChatInviteLink(_) => "editChatInviteLink",
+            TdFunction::EditCustomLanguagePackInfo(_) => "editCustomLanguagePackInfo",
+            TdFunction::EditInlineMessageCaption(_) => "editInlineMessageCaption",
+            TdFunction::EditInlineMessageLiveLocation(_) => "editInlineMessageLiveLocation",
+            TdFunction::EditInlineMessageMedia(_) => "editInlineMessageMedia",
+            TdFunction::EditInlineMessageReplyMarkup(_) => "editInlineMessageReplyMarkup",
+            TdFunction::EditInlineMessageText(_) => "editInlineMessageText",
+            TdFunction::EditMessageCaption(_) => "editMessageCaption",
+            TdFunction::EditMessageLiveLocation(_) => "editMessageLiveLocation",
+            TdFunction::EditMessageMedia(_) => "editMessageMedia",
+            TdFunction::EditMessageReplyMarkup(_) => "editMessageReplyMarkup",
+            TdFunction::EditMessageSchedulingState(_) => "editMessageSchedulingState",
+            TdFunction::EditMessageText(_) => "editMessageText",
+            TdFunction::EditProxy(_) => "editProxy",
+            TdFunction::EnableProxy(_) => "enableProxy",
+            TdFunction::EndGroupCall(_) => "endGroupCall",
+            TdFunction::EndGroupCallRecording(_) => "endGroupCallRecording",
+            TdFunction::EndGroupCallScreenSharing(_) => "endGroupCallScreenSharing",
+            TdFunction::FinishFileGeneration(_) => "finishFileGeneration",
+            TdFunction::ForwardMessages(_) => "forwardMessages",
+            TdFunction::GetAccountTtl(_) => "getAccountTtl",
+            TdFunction::GetActiveLiveLocationMessages(_) => "getActiveLiveLocationMessages",
+            TdFunction::GetActiveSessions(_) => "getActiveSessions",
+            TdFunction::GetAllPassportElements(_) => "getAllPassportElements",
+            TdFunction::GetAnimatedEmoji(_) => "getAnimatedEmoji",
+            TdFunction::GetApplicationConfig(_) => "getApplicationConfig",
+            TdFunction::GetApplicationDownloadLink(_) => "getApplicationDownloadLink",
+            TdFunction::GetArchivedStickerSets(_) => "getArchivedStickerSets",
+            TdFunction::GetAttachedStickerSets(_) => "getAttachedStickerSets",
+            TdFunction::GetAuthorizationState(_) => "getAuthorizationState",
+            TdFunction::GetAutoDownloadSettingsPresets(_) => "getAutoDownloadSettingsPresets",
+            TdFunction::GetBackgroundUrl(_) => "getBackgroundUrl",
+            TdFunction::GetBackgrounds(_) => "getBackgrounds",
+            TdFunction::GetBankCardInfo(_) => "getBankCardInfo",
+            TdFunction::GetBasicGroup(_) => "getBasicGroup",
+            TdFunction::GetBasicGroupFullInfo(_) => "getBasicGroupFullInfo",
+            TdFunction::GetBlockedMessageSenders(_) => "getBlockedMessageSenders",
+            TdFunction::GetCallbackQueryAnswer(_) => "getCallbackQueryAnswer",
+            TdFunction::GetCallbackQueryMessage(_) => "getCallbackQueryMessage",
+            TdFunction::GetChat(_) => "getChat",
+            TdFunction::GetChatAdministrators(_) => "getChatAdministrators",
+            TdFunction::GetChatAvailableMessageSenders(_) => "getChatAvailableMessageSenders",
+            TdFunction::GetChatEventLog(_) => "getChatEventLog",
+            TdFunction::GetChatFilter(_) => "getChatFilter",
+            TdFunction::GetChatFilterDefaultIconName(_) => "getChatFilterDefaultIconName",
+            TdFunction::GetChatHistory(_) => "getChatHistory",
+            TdFunction::GetChatInviteLink(_) => "getChatInviteLink",
+            TdFunction::GetChatInviteLinkCounts(_) => "getChatInviteLinkCounts",
+            TdFunction::GetChatInviteLinkMembers(_) => "getChatInviteLinkMembers",
+            TdFunction::GetChatInviteLinks(_) => "getChatInviteLinks",
+            TdFunction::GetChatJoinRequests(_) => "getChatJoinRequests",
+            TdFunction::GetChatListsToAddChat(_) => "getChatListsToAddChat",
+            TdFunction::GetChatMember(_) => "getChatMember",
+            TdFunction::GetChatMessageByDate(_) => "getChatMessageByDate",
+            TdFunction::GetChatMessageCalendar(_) => "getChatMessageCalendar",
+            TdFunction::GetChatMessageCount(_) => "getChatMessageCount",
+            TdFunction::GetChatNotificationSettingsExceptions(_) => {
+                "getChatNotificationSettingsExceptions"
+            }
+            TdFunction::GetChatPinnedMessage(_) => "getChatPinnedMessage",
+            TdFunction::GetChatScheduledMessages(_) => "getChatScheduledMessages",
+            TdFunction::GetChatSparseMessagePositions(_) => "getChatSparseMessagePositions",
+            TdFunction::GetChatSponsoredMessage(_) => "getChatSponsoredMessage",
+            TdFunction::GetChatStatistics(_) => "getChatStatistics",
+            TdFunction::GetChats(_) => "getChats",
+            TdFunction::GetCommands(_) => "getCommands",
+            TdFunction::GetConnectedWebsites(_) => "getConnectedWebsites",
+            TdFunction::GetContacts(_) => "getContacts",
+            TdFunction::GetCountries(_) => "getCountries",
+            TdFunction::GetCountryCode(_) => "getCountryCode",
+            TdFunction::GetCreatedPublicChats(_) => "getCreatedPublicChats",
+            TdFunction::GetCurrentState(_) => "getCurrentState",
+            TdFunction::GetDatabaseStatistics(_) => "getDatabaseStatistics",
+            TdFunction::GetDeepLinkInfo(_) => "getDeepLinkInfo",
+            TdFunction::GetEmojiSuggestionsUrl(_) => "getEmojiSuggestionsUrl",
+            TdFunction::GetExternalLink(_) => "getExternalLink",
+            TdFunction::GetExternalLinkInfo(_) => "getExternalLinkInfo",
+            TdFunction::GetFavoriteStickers(_) => "getFavoriteStickers",
+            TdFunction::GetFile(_) => "getFile",
+            TdFunction::GetFileDownloadedPrefixSize(_) => "getFileDownloadedPrefixSize",
+            TdFunction::GetFileExtension(_) => "getFileExtension",
+            TdFunction::GetFileMimeType(_) => "getFileMimeType",
+            TdFunction::GetGameHighScores(_) => "getGameHighScores",
+            TdFunction::GetGroupCall(_) => "getGroupCall",
+            TdFunction::GetGroupCallInviteLink(_) => "getGroupCallInviteLink",
+            TdFunction::GetGroupCallStreamSegment(_) => "getGroupCallStreamSegment",
+            TdFunction::GetGroupsInCommon(_) => "getGroupsInCommon",
+            TdFunction::GetImportedContactCount(_) => "getImportedContactCount",
+            TdFunction::GetInactiveSupergroupChats(_) => "getInactiveSupergroupChats",
+            TdFunction::GetInlineGameHighScores(_) => "getInlineGameHighScores",
+            TdFunction::GetInlineQueryResults(_) => "getInlineQueryResults",
+            TdFunction::GetInstalledStickerSets(_) => "getInstalledStickerSets",
+            TdFunction::GetInternalLinkType(_) => "getInternalLinkType",
+            TdFunction::GetJsonString(_) => "getJsonString",
+            TdFunction::GetJsonValue(_) => "getJsonValue",
+            TdFunction::GetLanguagePackInfo(_) => "getLanguagePackInfo",
+            TdFunction::GetLanguagePackString(_) => "getLanguagePackString",
+            TdFunction::GetLanguagePackStrings(_) => "getLanguagePackStrings",
+            TdFunction::GetLocalizationTargetInfo(_) => "getLocalizationTargetInfo",
+            TdFunction::GetLogStream(_) => "getLogStream",
+            TdFunction::GetLogTagVerbosityLevel(_) => "getLogTagVerbosityLevel",
+            TdFunction::GetLogTags(_) => "getLogTags",
+            TdFunction::GetLogVerbosityLevel(_) => "getLogVerbosityLevel",
+            TdFunction::GetLoginUrl(_) => "getLoginUrl",
+            TdFunction::GetLoginUrlInfo(_) => "getLoginUrlInfo",
+            TdFunction::GetMapThumbnailFile(_) => "getMapThumbnailFile",
+            TdFunction::GetMarkdownText(_) => "getMarkdownText",
+            TdFunction::GetMe(_) => "getMe",
+            TdFunction::GetMessage(_) => "getMessage",
+            TdFunction::GetMessageEmbeddingCode(_) => "getMessageEmbeddingCode",
+            TdFunction::GetMessageFileType(_) => "getMessageFileType",
+            TdFunction::GetMessageImportConfirmationText(_) => "getMessageImportConfirmationText",
+            TdFunction::GetMessageLink(_) => "getMessageLink",
+            TdFunction::GetMessageLinkInfo(_) => "getMessageLinkInfo",
+            TdFunction::GetMessageLocally(_) => "getMessageLocally",
+            TdFunction::GetMessagePublicForwards(_) => "getMessagePublicForwards",
+            TdFunction::GetMessageStatistics(_) => "getMessageStatistics",
+            TdFunction::GetMessageThread(_) => "getMessageThread",
+            TdFunction::GetMessageThreadHistory(_) => "getMessageThreadHistory",
+            TdFunction::GetMessageViewers(_) => "getMessageViewers",
+            TdFunction::GetMessages(_) => "getMessages",
+            TdFunction::GetNetworkStatistics(_) => "getNetworkStatistics",
+            TdFunction::GetOption(_) => "getOption",
+            TdFunction::GetPassportAuthorizationForm(_) => "getPassportAuthorizationForm",
+            TdFunction::GetPassportAuthorizationFormAvailableElements(_) => {
+                "getPassportAuthorizationFormAvailableElements"
+            }
+            TdFunction::GetPassportElement(_) => "getPassportElement",
+            TdFunction::GetPasswordState(_) => "getPasswordState",
+            TdFunction::GetPaymentForm(_) => "getPaymentForm",
+            TdFunction::GetPaymentReceipt(_) => "getPaymentReceipt",
+            TdFunction::GetPhoneNumberInfo(_) => "getPhoneNumberInfo",
+            TdFunction::GetPhoneNumberInfoSync(_) => "getPhoneNumberInfoSync",
+            TdFunction::GetPollVoters(_) => "getPollVoters",
+            TdFunction::GetPreferredCountryLanguage(_) => "getPreferredCountryLanguage",
+            TdFunction::GetProxies(_) => "getProxies",
+            TdFunction::GetProxyLink(_) => "getProxyLink",
+            TdFunction::GetPushReceiverId(_) => "getPushReceiverId",
+            TdFunction::GetRecentInlineBots(_) => "getRecentInlineBots",
+            TdFunction::GetRecentStickers(_) => "getRecentStickers",
+            TdFunction::GetRecentlyOpenedChats(_) => "getRecentlyOpenedChats",
+            TdFunction::GetRecentlyVisitedTMeUrls(_) => "getRecentlyVisitedTMeUrls",
+            TdFunction::GetRecommendedChatFilters(_) => "getRecommendedChatFilters",
+            TdFunction::GetRecoveryEmailAddress(_) => "getRecoveryEmailAddress",
+            TdFunction::GetRemoteFile(_) => "getRemoteFile",
+            TdFunction::GetRepliedMessage(_) => "getRepliedMessage",
+            TdFunction::GetSavedAnimations(_) => "getSavedAnimations",
+            TdFunction::GetSavedOrderInfo(_) => "getSavedOrderInfo",
+            TdFunction::GetScopeNotificationSettings(_) => "getScopeNotificationSettings",
+            TdFunction::GetSecretChat(_) => "getSecretChat",
+            TdFunction::GetStatisticalGraph(_) => "getStatisticalGraph",
+            TdFunction::GetStickerEmojis(_) => "getStickerEmojis",
+            TdFunction::GetStickerSet(_) => "getStickerSet",
+            TdFunction::GetStickers(_) => "getStickers",
+            TdFunction::GetStorageStatistics(_) => "getStorageStatistics",
+            TdFunction::GetStorageStatisticsFast(_) => "getStorageStatisticsFast",
+            TdFunction::GetSuggestedFileName(_) => "getSuggestedFileName",
+            TdFunction::GetSuggestedStickerSetName(_) => "getSuggestedStickerSetName",
+            TdFunction::GetSuitableDiscussionChats(_) => "getSuitableDiscussionChats",
+            TdFunction::GetSupergroup(_) => "getSupergroup",
+            TdFunction::GetSupergroupFullInfo(_) => "getSupergroupFullInfo",
+            TdFunction::GetSupergroupMembers(_) => "getSupergroupMembers",
+            TdFunction::GetSupportUser(_) => "getSupportUser",
+            TdFunction::GetTemporaryPasswordState(_) => "getTemporaryPasswordState",
+            TdFunction::GetTextEntities(_) => "getTextEntities",
+            TdFunction::GetTopChats(_) => "getTopChats",
+            TdFunction::GetTrendingStickerSets(_) => "getTrendingStickerSets",
+            TdFunction::GetUser(_) => "getUser",
+            TdFunction::GetUserFullInfo(_) => "getUserFullInfo",
+            TdFunction::GetUserPrivacySettingRules(_) => "getUserPrivacySettingRules",
+            TdFunction::GetUserProfilePhotos(_) => "getUserProfilePhotos",
+            TdFunction::GetVideoChatAvailableParticipants(_) => "getVideoChatAvailableParticipants",
+            TdFunction::GetWebPageInstantView(_) => "getWebPageInstantView",
+            TdFunction::GetWebPagePreview(_) => "getWebPagePreview",
+            TdFunction::HideSuggestedAction(_) => "hideSuggestedAction",
+            TdFunction::ImportContacts(_) => "importContacts",
+            TdFunction::ImportMessages(_) => "importMessages",
+            TdFunction::InviteGroupCallParticipants(_) => "inviteGroupCallParticipants",
+            TdFunction::JoinChat(_) => "joinChat",
+            TdFunction::JoinChatByInviteLink(_) => "joinChatByInviteLink",
+            TdFunction::JoinGroupCall(_) => "joinGroupCall",
+            TdFunction::LeaveChat(_) => "leaveChat",
+            TdFunction::LeaveGroupCall(_) => "leaveGroupCall",
+            TdFunction::LoadChats(_) => "loadChats",
+            TdFunction::LoadGroupCallParticipants(_) => "loadGroupCallParticipants",
+            TdFunction::LogOut(_) => "logOut",
+            TdFunction::OpenChat(_) => "openChat",
+            TdFunction::OpenMessageContent(_) => "openMessageContent",
+            TdFunction::OptimizeStorage(_) => "optimizeStorage",
+            TdFunction::ParseMarkdown(_) => "parseMarkdown",
+            TdFunction::ParseTextEntities(_) => "parseTextEntities",
+            TdFunction::PinChatMessage(_) => "pinChatMessage",
+            TdFunction::PingProxy(_) => "pingProxy",
+            TdFunction::ProcessChatJoinRequest(_) => "processChatJoinRequest",
+            TdFunction::ProcessChatJoinRequests(_) => "processChatJoinRequests",
+            TdFunction::ProcessPushNotification(_) => "processPushNotification",
+            TdFunction::ReadAllChatMentions(_) => "readAllChatMentions",
+            TdFunction::ReadFilePart(_) => "readFilePart",
+            TdFunction::RecoverAuthenticationPassword(_) => "recoverAuthenticationPassword",
+            TdFunction::RecoverPassword(_) => "recoverPassword",
+            TdFunction::RegisterDevice(_) => "registerDevice",
+            TdFunction::RegisterUser(_) => "registerUser",
+            TdFunction::RemoveBackground(_) => "removeBackground",
+            TdFunction::RemoveChatActionBar(_) => "removeChatActionBar",
+            TdFunction::RemoveContacts(_) => "removeContacts",
+            TdFunction::RemoveFavoriteSticker(_) => "removeFavoriteSticker",
+            TdFunction::RemoveNotification(_) => "removeNotification",
+            TdFunction::RemoveNotificationGroup(_) => "removeNotificationGroup",
+            TdFunction::RemoveProxy(_) => "removeProxy",
+            TdFunction::RemoveRecentHashtag(_) => "removeRecentHashtag",
+            TdFunction::RemoveRecentSticker(_) => "removeRecentSticker",
+            TdFunction::RemoveRecentlyFoundChat(_) => "removeRecentlyFoundChat",
+            TdFunction::RemoveSavedAnimation(_) => "removeSavedAnimation",
+            TdFunction::RemoveStickerFromSet(_) => "removeStickerFromSet",
+            TdFunction::RemoveTopChat(_) => "removeTopChat",
+            TdFunction::ReorderChatFilters(_) => "reorderChatFilters",
+            TdFunction::ReorderInstalledStickerSets(_) => "reorderInstalledStickerSets",
+            TdFunction::ReplacePrimaryChatInviteLink(_) => "replacePrimaryChatInviteLink",
+            TdFunction::ReportChat(_) => "reportChat",
+            TdFunction::ReportChatPhoto(_) => "reportChatPhoto",
+            TdFunction::ReportSupergroupSpam(_) => "reportSupergroupSpam",
+            TdFunction::RequestAuthenticationPasswordRecovery(_) => {
+                "requestAuthenticationPasswordRecovery"
+            }
+            TdFunction::RequestPasswordRecovery(_) => "requestPasswordRecovery",
+            TdFunction::RequestQrCodeAuthentication(_) => "requestQrCodeAuthentication",
+            TdFunction::ResendAuthenticationCode(_) => "resendAuthenticationCode",
+            TdFunction::ResendChangePhoneNumberCode(_) => "resendChangePhoneNumberCode",
+            TdFunction::ResendEmailAddressVerificationCode(_) => {
+                "resendEmailAddressVerificationCode"
+            }
+            TdFunction::ResendMessages(_) => "resendMessages",
+            TdFunction::ResendPhoneNumberConfirmationCode(_) => "resendPhoneNumberConfirmationCode",
+            TdFunction::ResendPhoneNumberVerificationCode(_) => "resendPhoneNumberVerificationCode",
+            TdFunction::ResendRecoveryEmailAddressCode(_) => "resendRecoveryEmailAddressCode",
+            TdFunction::ResetAllNotificationSettings(_) => "resetAllNotificationSettings",
+            TdFunction::ResetBackgrounds(_) => "resetBackgrounds",
+            TdFunction::ResetNetworkStatistics(_) => "resetNetworkStatistics",
+            TdFunction::ResetPassword(_) => "resetPassword",
+            TdFunction::RevokeChatInviteLink(_) => "revokeChatInviteLink",
+            TdFunction::RevokeGroupCallInviteLink(_) => "revokeGroupCallInviteLink",
+            TdFunction::SaveApplicationLogEvent(_) => "saveApplicationLogEvent",
+            TdFunction::SearchBackground(_) => "searchBackground",
+            TdFunction::SearchCallMessages(_) => "searchCallMessages",
+            TdFunction::SearchChatMembers(_) => "searchChatMembers",
+            TdFunction::SearchChatMessages(_) => "searchChatMessages",
+            TdFunction::SearchChatRecentLocationMessages(_) => "searchChatRecentLocationMessages",
+            TdFunction::SearchChats(_) => "searchChats",
+            TdFunction::SearchChatsNearby(_) => "searchChatsNearby",
+            TdFunction::SearchChatsOnServer(_) => "searchChatsOnServer",
+            TdFunction::SearchContacts(_) => "searchContacts",
+            TdFunction::SearchEmojis(_) => "searchEmojis",
+            TdFunction::SearchHashtags(_) => "searchHashtags",
+            TdFunction::SearchInstalledStickerSets(_) => "searchInstalledStickerSets",
+            TdFunction::SearchMessages(_) => "searchMessages",
+            TdFunction::SearchPublicChat(_) => "searchPublicChat",
+            TdFunction::SearchPublicChats(_) => "searchPublicChats",
+            TdFunction::SearchSecretMessages(_) => "searchSecretMessages",
+            TdFunction::SearchStickerSet(_) => "searchStickerSet",
+            TdFunction::SearchStickerSets(_) => "searchStickerSets",
+            TdFunction::SearchStickers(_) => "searchStickers",
+            TdFunction::SendBotStartMessage(_) => "sendBotStartMessage",
+            TdFunction::SendCallDebugInformation(_) => "sendCallDebugInformation",
+            TdFunction::SendCallRating(_) => "sendCallRating",
+            TdFunction::SendCallSignalingData(_) => "sendCallSignalingData",
+            TdFunction::SendChatAction(_) => "sendChatAction",
+            TdFunction::SendChatScreenshotTakenNotification(_) => {
+                "sendChatScreenshotTakenNotification"
+            }
+            TdFunction::SendCustomRequest(_) => "sendCustomRequest",
+            TdFunction::SendEmailAddressVerificationCode(_) => "sendEmailAddressVerificationCode",
+            TdFunction::SendInlineQueryResultMessage(_) => "sendInlineQueryResultMessage",
+            TdFunction::SendMessage(_) => "sendMessage",
+            TdFunction::SendMessageAlbum(_) => "sendMessageAlbum",
+            TdFunction::SendPassportAuthorizationForm(_) => "sendPassportAuthorizationForm",
+            TdFunction::SendPaymentForm(_) => "sendPaymentForm",
+            TdFunction::SendPhoneNumberConfirmationCode(_) => "sendPhoneNumberConfirmationCode",
+            TdFunction::SendPhoneNumberVerificationCode(_) => "sendPhoneNumberVerificationCode",
+            TdFunction::SetAccountTtl(_) => "setAccountTtl",
+            TdFunction::SetAlarm(_) => "setAlarm",
+            TdFunction::SetAuthenticationPhoneNumber(_) => "setAuthenticationPhoneNumber",
+            TdFunction::SetAutoDownloadSettings(_) => "setAutoDownloadSettings",
+            TdFunction::SetBackground(_) => "setBackground",
+            TdFunction::SetBio(_) => "setBio",
+            TdFunction::SetBotUpdatesStatus(_) => "setBotUpdatesStatus",
+            TdFunction::SetChatClientData(_) => "setChatClientData",
+            TdFunction::SetChatDescription(_) => "setChatDescription",
+            TdFunction::SetChatDiscussionGroup(_) => "setChatDiscussionGroup",
+            TdFunction::SetChatDraftMessage(_) => "setChatDraftMessage",
+            TdFunction::SetChatLocation(_) => "setChatLocation",
+            TdFunction::SetChatMemberStatus(_) => "setChatMemberStatus",
+            TdFunction::SetChatMessageSender(_) => "setChatMessageSender",
+            TdFunction::SetChatMessageTtl(_) => "setChatMessageTtl",
+            TdFunction::SetChatNotificationSettings(_) => "setChatNotificationSettings",
+            TdFunction::SetChatPermissions(_) => "setChatPermissions",
+            TdFunction::SetChatPhoto(_) => "setChatPhoto",
+            TdFunction::SetChatSlowModeDelay(_) => "setChatSlowModeDelay",
+            TdFunction::SetChatTheme(_) => "setChatTheme",
+            TdFunction::SetChatTitle(_) => "setChatTitle",
+            TdFunction::SetCommands(_) => "setCommands",
+            TdFunction::SetCustomLanguagePack(_) => "setCustomLanguagePack",
+            TdFunction::SetCustomLanguagePackString(_) => "setCustomLanguagePackString",
+            TdFunction::SetDatabaseEncryptionKey(_) => "setDatabaseEncryptionKey",
+            TdFunction::SetFileGenerationProgress(_) => "setFileGenerationProgress",
+            TdFunction::SetGameScore(_) => "setGameScore",
+            TdFunction::SetGroupCallParticipantIsSpeaking(_) => "setGroupCallParticipantIsSpeaking",
+            TdFunction::SetGroupCallParticipantVolumeLevel(_) => {
+                "setGroupCallParticipantVolumeLevel"
+            }
+            TdFunction::SetGroupCallTitle(_) => "setGroupCallTitle",
+            TdFunction::SetInactiveSessionTtl(_) => "setInactiveSessionTtl",
+            TdFunction::SetInlineGameScore(_) => "setInlineGameScore",
+            TdFunction::SetLocation(_) => "setLocation",
+            TdFunction::SetLogStream(_) => "setLogStream",
+            TdFunction::SetLogTagVerbosityLevel(_) => "setLogTagVerbosityLevel",
+            TdFunction::SetLogVerbosityLevel(_) => "setLogVerbosityLevel",
+            TdFunction::SetName(_) => "setName",
+            TdFunction::SetNetworkType(_) => "setNetworkType",
+            TdFunction::SetOption(_) => "setOption",
+            TdFunction::SetPassportElement(_) => "setPassportElement",
+            TdFunction::SetPassportElementErrors(_) => "setPassportElementErrors",
+            TdFunction::SetPassword(_) => "setPassword",
+            TdFunction::SetPinnedChats(_) => "setPinnedChats",
+            TdFunction::SetPollAnswer(_) => "setPollAnswer",
+            TdFunction::SetProfilePhoto(_) => "setProfilePhoto",
+            TdFunction::SetRecoveryEmailAddress(_) => "setRecoveryEmailAddress",
+            TdFunction::SetScopeNotificationSettings(_) => "setScopeNotificationSettings",
+            TdFunction::SetStickerPositionInSet(_) => "setStickerPositionInSet",
+            TdFunction::SetStickerSetThumbnail(_) => "setStickerSetThumbnail",
+            TdFunction::SetSupergroupStickerSet(_) => "setSupergroupStickerSet",
+            TdFunction::SetSupergroupUsername(_) => "setSupergroupUsername",
+            TdFunction::SetTdlibParameters(_) => "setTdlibParameters",
+            TdFunction::SetUserPrivacySettingRules(_) => "setUserPrivacySettingRules",
+            TdFunction::SetUsername(_) => "setUsername",
+            TdFunction::SetVideoChatDefaultParticipant(_) => "setVideoChatDefaultParticipant",
+            TdFunction::SharePhoneNumber(_) => "sharePhoneNumber",
+            TdFunction::StartGroupCallRecording(_) => "startGroupCallRecording",
+            TdFunction::StartGroupCallScreenSharing(_) => "startGroupCallScreenSharing",
+            TdFunction::StartScheduledGroupCall(_) => "startScheduledGroupCall",
+            TdFunction::StopPoll(_) => "stopPoll",
+            TdFunction::SynchronizeLanguagePack(_) => "synchronizeLanguagePack",
+            TdFunction::TerminateAllOtherSessions(_) => "terminateAllOtherSessions",
+            TdFunction::TerminateSession(_) => "terminateSession",
+            TdFunction::TestCallBytes(_) => "testCallBytes",
+            TdFunction::TestCallEmpty(_) => "testCallEmpty",
+            TdFunction::TestCallString(_) => "testCallString",
+            TdFunction::TestCallVectorInt(_) => "testCallVectorInt",
+            TdFunction::TestCallVectorIntObject(_) => "testCallVectorIntObject",
+            TdFunction::TestCallVectorString(_) => "testCallVectorString",
+            TdFunction::TestCallVectorStringObject(_) => "testCallVectorStringObject",
+            TdFunction::TestGetDifference(_) => "testGetDifference",
+            TdFunction::TestNetwork(_) => "testNetwork",
+            TdFunction::TestProxy(_) => "testProxy",
+            TdFunction::TestReturnError(_) => "testReturnError",
+            TdFunction::TestSquareInt(_) => "testSquareInt",
+            TdFunction::TestUseUpdate(_) => "testUseUpdate",
+            TdFunction::ToggleChatDefaultDisableNotification(_) => {
+                "toggleChatDefaultDisableNotification"
+            }
+            TdFunction::ToggleChatHasProtectedContent(_) => "toggleChatHasProtectedContent",
+            TdFunction::ToggleChatIsMarkedAsUnread(_) => "toggleChatIsMarkedAsUnread",
+            TdFunction::ToggleChatIsPinned(_) => "toggleChatIsPinned",
+            TdFunction::ToggleGroupCallEnabledStartNotification(_) => {
+                "toggleGroupCallEnabledStartNotification"
+            }
+            TdFunction::ToggleGroupCallIsMyVideoEnabled(_) => "toggleGroupCallIsMyVideoEnabled",
+            TdFunction::ToggleGroupCallIsMyVideoPaused(_) => "toggleGroupCallIsMyVideoPaused",
+            TdFunction::ToggleGroupCallMuteNewParticipants(_) => {
+                "toggleGroupCallMuteNewParticipants"
+            }
+            TdFunction::ToggleGroupCallParticipantIsHandRaised(_) => {
+                "toggleGroupCallParticipantIsHandRaised"
+            }
+            TdFunction::ToggleGroupCallParticipantIsMuted(_) => "toggleGroupCallParticipantIsMuted",
+            TdFunction::ToggleGroupCallScreenSharingIsPaused(_) => {
+                "toggleGroupCallScreenSharingIsPaused"
+            }
+            TdFunction::ToggleMessageSenderIsBlocked(_) => "toggleMessageSenderIsBlocked",
+            TdFunction::ToggleSessionCanAcceptCalls(_) => "toggleSessionCanAcceptCalls",
+            TdFunction::ToggleSessionCanAcceptSecretChats(_) => "toggleSessionCanAcceptSecretChats",
+            TdFunction::ToggleSupergroupIsAllHistoryAvailable(_) => {
+                "toggleSupergroupIsAllHistoryAvailable"
+            }
+            TdFunction::ToggleSupergroupIsBroadcastGroup(_) => "toggleSupergroupIsBroadcastGroup",
+            TdFunction::ToggleSupergroupSignMessages(_) => "toggleSupergroupSignMessages",
+            TdFunction::TransferChatOwnership(_) => "transferChatOwnership",
+            TdFunction::UnpinAllChatMessages(_) => "unpinAllChatMessages",
+            TdFunction::UnpinChatMessage(_) => "unpinChatMessage",
+            TdFunction::UpgradeBasicGroupChatToSupergroupChat(_) => {
+                "upgradeBasicGroupChatToSupergroupChat"
+            }
+            TdFunction::UploadFile(_) => "uploadFile",
+            TdFunction::UploadStickerFile(_) => "uploadStickerFile",
+            TdFunction::ValidateOrderInfo(_) => "validateOrderInfo",
+            TdFunction::ViewMessages(_) => "viewMessages",
+            TdFunction::ViewTrendingStickerSets(_) => "viewTrendingStickerSets",
+            TdFunction::WriteGeneratedFilePart(_) => "writeGeneratedFilePart",
+            TdFunction::Unknown { type_name, .. } => type_name,
+        }
+    }
+}
+
+impl AsRef<TdFunction> for TdFunction {
+    fn as_ref(&self) -> &TdFunction {
+        self
+    }
+}
+
+impl Serialize for TdFunction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            TdFunction::AcceptCall(f) => f.serialize(serializer),
+            TdFunction::AcceptTermsOfService(f) => f.serialize(serializer),
+            TdFunction::AddChatMember(f) => f.serialize(serializer),
+            TdFunction::AddChatMembers(f) => f.serialize(serializer),
+            TdFunction::AddChatToList(f) => f.serialize(serializer),
+            TdFunction::AddContact(f) => f.serialize(serializer),
+            TdFunction::AddCustomServerLanguagePack(f) => f.serialize(serializer),
+            TdFunction::AddFavoriteSticker(f) => f.serialize(serializer),
+            TdFunction::AddLocalMessage(f) => f.serialize(serializer),
+            TdFunction::AddLogMessage(f) => f.serialize(serializer),
+            TdFunction::AddNetworkStatistics(f) => f.serialize(serializer),
+            TdFunction::AddProxy(f) => f.serialize(serializer),
+            TdFunction::AddRecentSticker(f) => f.serialize(serializer),
+            TdFunction::AddRecentlyFoundChat(f) => f.serialize(serializer),
+            TdFunction::AddSavedAnimation(f) => f.serialize(serializer),
+            TdFunction::AddStickerToSet(f) => f.serialize(serializer),
+            TdFunction::AnswerCallbackQuery(f) => f.serialize(serializer),
+            TdFunction::AnswerCustomQuery(f) => f.serialize(serializer),
+            TdFunction::AnswerInlineQuery(f) => f.serialize(serializer),
+            TdFunction::AnswerPreCheckoutQuery(f) => f.serialize(serializer),
+            TdFunction::AnswerShippingQuery(f) => f.serialize(serializer),
+            TdFunction::BanChatMember(f) => f.serialize(serializer),
+            TdFunction::BlockMessageSenderFromReplies(f) => f.serialize(serializer),
+            TdFunction::CanTransferOwnership(f) => f.serialize(serializer),
+            TdFunction::CancelDownloadFile(f) => f.serialize(serializer),
+            TdFunction::CancelPasswordReset(f) => f.serialize(serializer),
+            TdFunction::CancelUploadFile(f) => f.serialize(serializer),
+            TdFunction::ChangeImportedContacts(f) => f.serialize(serializer),
+            TdFunction::ChangePhoneNumber(f) => f.serialize(serializer),
+            TdFunction::ChangeStickerSet(f) => f.serialize(serializer),
+            TdFunction::CheckAuthenticationBotToken(f) => f.serialize(serializer),
+            TdFunction::CheckAuthenticationCode(f) => f.serialize(serializer),
+            TdFunction::CheckAuthenticationPassword(f) => f.serialize(serializer),
+            TdFunction::CheckAuthenticationPasswordRecoveryCode(f) => f.serialize(serializer),
+            TdFunction::CheckChangePhoneNumberCode(f) => f.serialize(serializer),
+            TdFunction::CheckChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::CheckChatUsername(f) => f.serialize(serializer),
+            TdFunction::CheckCreatedPublicChatsLimit(f) => f.serialize(serializer),
+            TdFunction::CheckDatabaseEncryptionKey(f) => f.serialize(serializer),
+            TdFunction::CheckEmailAddressVerificationCode(f) => f.serialize(serializer),
+            TdFunction::CheckPasswordRecoveryCode(f) => f.serialize(serializer),
+            TdFunction::CheckPhoneNumberConfirmationCode(f) => f.serialize(serializer),
+            TdFunction::CheckPhoneNumberVerificationCode(f) => f.serialize(serializer),
+            TdFunction::CheckRecoveryEmailAddressCode(f) => f.serialize(serializer),
+            TdFunction::CheckStickerSetName(f) => f.serialize(serializer),
+            TdFunction::CleanFileName(f) => f.serialize(serializer),
+            TdFunction::ClearAllDraftMessages(f) => f.serialize(serializer),
+            TdFunction::ClearImportedContacts(f) => f.serialize(serializer),
+            TdFunction::ClearRecentStickers(f) => f.serialize(serializer),
+            TdFunction::ClearRecentlyFoundChats(f) => f.serialize(serializer),
+            TdFunction::ClickAnimatedEmojiMessage(f) => f.serialize(serializer),
+            TdFunction::Close(f) => f.serialize(serializer),
+            TdFunction::CloseChat(f) => f.serialize(serializer),
+            TdFunction::CloseSecretChat(f) => f.serialize(serializer),
+            TdFunction::ConfirmQrCodeAuthentication(f) => f.serialize(serializer),
+            TdFunction::CreateBasicGroupChat(f) => f.serialize(serializer),
+            TdFunction::CreateCall(f) => f.serialize(serializer),
+            TdFunction::CreateChatFilter(f) => f.serialize(serializer),
+            TdFunction::CreateChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::CreateNewBasicGroupChat(f) => f.serialize(serializer),
+            TdFunction::CreateNewSecretChat(f) => f.serialize(serializer),
+            TdFunction::CreateNewStickerSet(f) => f.serialize(serializer),
+            TdFunction::CreateNewSupergroupChat(f) => f.serialize(serializer),
+            TdFunction::CreatePrivateChat(f) => f.serialize(serializer),
+            TdFunction::CreateSecretChat(f) => f.serialize(serializer),
+            TdFunction::CreateSupergroupChat(f) => f.serialize(serializer),
+            TdFunction::CreateTemporaryPassword(f) => f.serialize(serializer),
+            TdFunction::CreateVideoChat(f) => f.serialize(serializer),
+            TdFunction::DeleteAccount(f) => f.serialize(serializer),
+            TdFunction::DeleteAllCallMessages(f) => f.serialize(serializer),
+            TdFunction::DeleteAllRevokedChatInviteLinks(f) => f.serialize(serializer),
+            TdFunction::DeleteChat(f) => f.serialize(serializer),
+            TdFunction::DeleteChatFilter(f) => f.serialize(serializer),
+            TdFunction::DeleteChatHistory(f) => f.serialize(serializer),
+            TdFunction::DeleteChatMessagesByDate(f) => f.serialize(serializer),
+            TdFunction::DeleteChatMessagesBySender(f) => f.serialize(serializer),
+            TdFunction::DeleteChatReplyMarkup(f) => f.serialize(serializer),
+            TdFunction::DeleteCommands(f) => f.serialize(serializer),
+            TdFunction::DeleteFile(f) => f.serialize(serializer),
+            TdFunction::DeleteLanguagePack(f) => f.serialize(serializer),
+            TdFunction::DeleteMessages(f) => f.serialize(serializer),
+            TdFunction::DeletePassportElement(f) => f.serialize(serializer),
+            TdFunction::DeleteProfilePhoto(f) => f.serialize(serializer),
+            TdFunction::DeleteRevokedChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::DeleteSavedCredentials(f) => f.serialize(serializer),
+            TdFunction::DeleteSavedOrderInfo(f) => f.serialize(serializer),
+            TdFunction::Destroy(f) => f.serialize(serializer),
+            TdFunction::DisableProxy(f) => f.serialize(serializer),
+            TdFunction::DiscardCall(f) => f.serialize(serializer),
+            TdFunction::DisconnectAllWebsites(f) => f.serialize(serializer),
+            TdFunction::DisconnectWebsite(f) => f.serialize(serializer),
+            TdFunction::DownloadFile(f) => f.serialize(serializer),
+            TdFunction::EditChatFilter(f) => f.serialize(serializer),
+            TdFunction::EditChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::EditCustomLanguagePackInfo(f) => f.serialize(serializer),
+            TdFunction::EditInlineMessageCaption(f) => f.serialize(serializer),
+            TdFunction::EditInlineMessageLiveLocation(f) => f.serialize(serializer),
+            TdFunction::EditInlineMessageMedia(f) => f.serialize(serializer),
+            TdFunction::EditInlineMessageReplyMarkup(f) => f.serialize(serializer),
+            TdFunction::EditInlineMessageText(f) => f.serialize(serializer),
+            TdFunction::EditMessageCaption(f) => f.serialize(serializer),
+            TdFunction::EditMessageLiveLocation(f) => f.serialize(serializer),
+            TdFunction::EditMessageMedia(f) => f.serialize(serializer),
+            TdFunction::EditMessageReplyMarkup(f) => f.serialize(serializer),
+            TdFunction::EditMessageSchedulingState(f) => f.serialize(serializer),
+            TdFunction::EditMessageText(f) => f.serialize(serializer),
+            TdFunction::EditProxy(f) => f.serialize(serializer),
+            TdFunction::EnableProxy(f) => f.serialize(serializer),
+            TdFunction::EndGroupCall(f) => f.serialize(serializer),
+            TdFunction::EndGroupCallRecording(f) => f.serialize(serializer),
+            TdFunction::EndGroupCallScreenSharing(f) => f.serialize(serializer),
+            TdFunction::FinishFileGeneration(f) => f.serialize(serializer),
+            TdFunction::ForwardMessages(f) => f.serialize(serializer),
+            TdFunction::GetAccountTtl(f) => f.serialize(serializer),
+            TdFunction::GetActiveLiveLocationMessages(f) => f.serialize(serializer),
+            TdFunction::GetActiveSessions(f) => f.serialize(serializer),
+            TdFunction::GetAllPassportElements(f) => f.serialize(serializer),
+            TdFunction::GetAnimatedEmoji(f) => f.serialize(serializer),
+            TdFunction::GetApplicationConfig(f) => f.serialize(serializer),
+            TdFunction::GetApplicationDownloadLink(f) => f.serialize(serializer),
+            TdFunction::GetArchivedStickerSets(f) => f.serialize(serializer),
+            TdFunction::GetAttachedStickerSets(f) => f.serialize(serializer),
+            TdFunction::GetAuthorizationState(f) => f.serialize(serializer),
+            TdFunction::GetAutoDownloadSettingsPresets(f) => f.serialize(serializer),
+            TdFunction::GetBackgroundUrl(f) => f.serialize(serializer),
+            TdFunction::GetBackgrounds(f) => f.serialize(serializer),
+            TdFunction::GetBankCardInfo(f) => f.serialize(serializer),
+            TdFunction::GetBasicGroup(f) => f.serialize(serializer),
+            TdFunction::GetBasicGroupFullInfo(f) => f.serialize(serializer),
+            TdFunction::GetBlockedMessageSenders(f) => f.serialize(serializer),
+            TdFunction::GetCallbackQueryAnswer(f) => f.serialize(serializer),
+            TdFunction::GetCallbackQueryMessage(f) => f.serialize(serializer),
+            TdFunction::GetChat(f) => f.serialize(serializer),
+            TdFunction::GetChatAdministrators(f) => f.serialize(serializer),
+            TdFunction::GetChatAvailableMessageSenders(f) => f.serialize(serializer),
+            TdFunction::GetChatEventLog(f) => f.serialize(serializer),
+            TdFunction::GetChatFilter(f) => f.serialize(serializer),
+            TdFunction::GetChatFilterDefaultIconName(f) => f.serialize(serializer),
+            TdFunction::GetChatHistory(f) => f.serialize(serializer),
+            TdFunction::GetChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::GetChatInviteLinkCounts(f) => f.serialize(serializer),
+            TdFunction::GetChatInviteLinkMembers(f) => f.serialize(serializer),
+            TdFunction::GetChatInviteLinks(f) => f.serialize(serializer),
+            TdFunction::GetChatJoinRequests(f) => f.serialize(serializer),
+            TdFunction::GetChatListsToAddChat(f) => f.serialize(serializer),
+            TdFunction::GetChatMember(f) => f.serialize(serializer),
+            TdFunction::GetChatMessageByDate(f) => f.serialize(serializer),
+            TdFunction::GetChatMessageCalendar(f) => f.serialize(serializer),
+            TdFunction::GetChatMessageCount(f) => f.serialize(serializer),
+            TdFunction::GetChatNotificationSettingsExceptions(f) => f.serialize(serializer),
+            TdFunction::GetChatPinnedMessage(f) => f.serialize(serializer),
+            TdFunction::GetChatScheduledMessages(f) => f.serialize(serializer),
+            TdFunction::GetChatSparseMessagePositions(f) => f.serialize(serializer),
+            TdFunction::GetChatSponsoredMessage(f) => f.serialize(serializer),
+            TdFunction::GetChatStatistics(f) => f.serialize(serializer),
+            TdFunction::GetChats(f) => f.serialize(serializer),
+            TdFunction::GetCommands(f) => f.serialize(serializer),
+            TdFunction::GetConnectedWebsites(f) => f.serialize(serializer),
+            TdFunction::GetContacts(f) => f.serialize(serializer),
+            TdFunction::GetCountries(f) => f.serialize(serializer),
+            TdFunction::GetCountryCode(f) => f.serialize(serializer),
+            TdFunction::GetCreatedPublicChats(f) => f.serialize(serializer),
+            TdFunction::GetCurrentState(f) => f.serialize(serializer),
+            TdFunction::GetDatabaseStatistics(f) => f.serialize(serializer),
+            TdFunction::GetDeepLinkInfo(f) => f.serialize(serializer),
+            TdFunction::GetEmojiSuggestionsUrl(f) => f.serialize(serializer),
+            TdFunction::GetExternalLink(f) => f.serialize(serializer),
+            TdFunction::GetExternalLinkInfo(f) => f.serialize(serializer),
+            TdFunction::GetFavoriteStickers(f) => f.serialize(serializer),
+            TdFunction::GetFile(f) => f.serialize(serializer),
+            TdFunction::GetFileDownloadedPrefixSize(f) => f.serialize(serializer),
+            TdFunction::GetFileExtension(f) => f.serialize(serializer),
+            TdFunction::GetFileMimeType(f) => f.serialize(serializer),
+            TdFunction::GetGameHighScores(f) => f.serialize(serializer),
+            TdFunction::GetGroupCall(f) => f.serialize(serializer),
+            TdFunction::GetGroupCallInviteLink(f) => f.serialize(serializer),
+            TdFunction::GetGroupCallStreamSegment(f) => f.serialize(serializer),
+            TdFunction::GetGroupsInCommon(f) => f.serialize(serializer),
+            TdFunction::GetImportedContactCount(f) => f.serialize(serializer),
+            TdFunction::GetInactiveSupergroupChats(f) => f.serialize(serializer),
+            TdFunction::GetInlineGameHighScores(f) => f.serialize(serializer),
+            TdFunction::GetInlineQueryResults(f) => f.serialize(serializer),
+            TdFunction::GetInstalledStickerSets(f) => f.serialize(serializer),
+            TdFunction::GetInternalLinkType(f) => f.serialize(serializer),
+            TdFunction::GetJsonString(f) => f.serialize(serializer),
+            TdFunction::GetJsonValue(f) => f.serialize(serializer),
+            TdFunction::GetLanguagePackInfo(f) => f.serialize(serializer),
+            TdFunction::GetLanguagePackString(f) => f.serialize(serializer),
+            TdFunction::GetLanguagePackStrings(f) => f.serialize(serializer),
+            TdFunction::GetLocalizationTargetInfo(f) => f.serialize(serializer),
+            TdFunction::GetLogStream(f) => f.serialize(serializer),
+            TdFunction::GetLogTagVerbosityLevel(f) => f.serialize(serializer),
+            TdFunction::GetLogTags(f) => f.serialize(serializer),
+            TdFunction::GetLogVerbosityLevel(f) => f.serialize(serializer),
+            TdFunction::GetLoginUrl(f) => f.serialize(serializer),
+            TdFunction::GetLoginUrlInfo(f) => f.serialize(serializer),
+            TdFunction::GetMapThumbnailFile(f) => f.serialize(serializer),
+            TdFunction::GetMarkdownText(f) => f.serialize(serializer),
+            TdFunction::GetMe(f) => f.serialize(serializer),
+            TdFunction::GetMessage(f) => f.serialize(serializer),
+            TdFunction::GetMessageEmbeddingCode(f) => f.serialize(serializer),
+            TdFunction::GetMessageFileType(f) => f.serialize(serializer),
+            TdFunction::GetMessageImportConfirmationText(f) => f.serialize(serializer),
+            TdFunction::GetMessageLink(f) => f.serialize(serializer),
+            TdFunction::GetMessageLinkInfo(f) => f.serialize(serializer),
+            TdFunction::GetMessageLocally(f) => f.serialize(serializer),
+            TdFunction::GetMessagePublicForwards(f) => f.serialize(serializer),
+            TdFunction::GetMessageStatistics(f) => f.serialize(serializer),
+            TdFunction::GetMessageThread(f) => f.serialize(serializer),
+            TdFunction::GetMessageThreadHistory(f) => f.serialize(serializer),
+            TdFunction::GetMessageViewers(f) => f.serialize(serializer),
+            TdFunction::GetMessages(f) => f.serialize(serializer),
+            TdFunction::GetNetworkStatistics(f) => f.serialize(serializer),
+            TdFunction::GetOption(f) => f.serialize(serializer),
+            TdFunction::GetPassportAuthorizationForm(f) => f.serialize(serializer),
+            TdFunction::GetPassportAuthorizationFormAvailableElements(f) => f.serialize(serializer),
+            TdFunction::GetPassportElement(f) => f.serialize(serializer),
+            TdFunction::GetPasswordState(f) => f.serialize(serializer),
+            TdFunction::GetPaymentForm(f) => f.serialize(serializer),
+            TdFunction::GetPaymentReceipt(f) => f.serialize(serializer),
+            TdFunction::GetPhoneNumberInfo(f) => f.serialize(serializer),
+            TdFunction::GetPhoneNumberInfoSync(f) => f.serialize(serializer),
+            TdFunction::GetPollVoters(f) => f.serialize(serializer),
+            TdFunction::GetPreferredCountryLanguage(f) => f.serialize(serializer),
+            TdFunction::GetProxies(f) => f.serialize(serializer),
+            TdFunction::GetProxyLink(f) => f.serialize(serializer),
+            TdFunction::GetPushReceiverId(f) => f.serialize(serializer),
+            TdFunction::GetRecentInlineBots(f) => f.serialize(serializer),
+            TdFunction::GetRecentStickers(f) => f.serialize(serializer),
+            TdFunction::GetRecentlyOpenedChats(f) => f.serialize(serializer),
+            TdFunction::GetRecentlyVisitedTMeUrls(f) => f.serialize(serializer),
+            TdFunction::GetRecommendedChatFilters(f) => f.serialize(serializer),
+            TdFunction::GetRecoveryEmailAddress(f) => f.serialize(serializer),
+            TdFunction::GetRemoteFile(f) => f.serialize(serializer),
+            TdFunction::GetRepliedMessage(f) => f.serialize(serializer),
+            TdFunction::GetSavedAnimations(f) => f.serialize(serializer),
+            TdFunction::GetSavedOrderInfo(f) => f.serialize(serializer),
+            TdFunction::GetScopeNotificationSettings(f) => f.serialize(serializer),
+            TdFunction::GetSecretChat(f) => f.serialize(serializer),
+            TdFunction::GetStatisticalGraph(f) => f.serialize(serializer),
+            TdFunction::GetStickerEmojis(f) => f.serialize(serializer),
+            TdFunction::GetStickerSet(f) => f.serialize(serializer),
+            TdFunction::GetStickers(f) => f.serialize(serializer),
+            TdFunction::GetStorageStatistics(f) => f.serialize(serializer),
+            TdFunction::GetStorageStatisticsFast(f) => f.serialize(serializer),
+            TdFunction::GetSuggestedFileName(f) => f.serialize(serializer),
+            TdFunction::GetSuggestedStickerSetName(f) => f.serialize(serializer),
+            TdFunction::GetSuitableDiscussionChats(f) => f.serialize(serializer),
+            TdFunction::GetSupergroup(f) => f.serialize(serializer),
+            TdFunction::GetSupergroupFullInfo(f) => f.serialize(serializer),
+            TdFunction::GetSupergroupMembers(f) => f.serialize(serializer),
+            TdFunction::GetSupportUser(f) => f.serialize(serializer),
+            TdFunction::GetTemporaryPasswordState(f) => f.serialize(serializer),
+            TdFunction::GetTextEntities(f) => f.serialize(serializer),
+            TdFunction::GetTopChats(f) => f.serialize(serializer),
+            TdFunction::GetTrendingStickerSets(f) => f.serialize(serializer),
+            TdFunction::GetUser(f) => f.serialize(serializer),
+            TdFunction::GetUserFullInfo(f) => f.serialize(serializer),
+            TdFunction::GetUserPrivacySettingRules(f) => f.serialize(serializer),
+            TdFunction::GetUserProfilePhotos(f) => f.serialize(serializer),
+            TdFunction::GetVideoChatAvailableParticipants(f) => f.serialize(serializer),
+            TdFunction::GetWebPageInstantView(f) => f.serialize(serializer),
+            TdFunction::GetWebPagePreview(f) => f.serialize(serializer),
+            TdFunction::HideSuggestedAction(f) => f.serialize(serializer),
+            TdFunction::ImportContacts(f) => f.serialize(serializer),
+            TdFunction::ImportMessages(f) => f.serialize(serializer),
+            TdFunction::InviteGroupCallParticipants(f) => f.serialize(serializer),
+            TdFunction::JoinChat(f) => f.serialize(serializer),
+            TdFunction::JoinChatByInviteLink(f) => f.serialize(serializer),
+            TdFunction::JoinGroupCall(f) => f.serialize(serializer),
+            TdFunction::LeaveChat(f) => f.serialize(serializer),
+            TdFunction::LeaveGroupCall(f) => f.serialize(serializer),
+            TdFunction::LoadChats(f) => f.serialize(serializer),
+            TdFunction::LoadGroupCallParticipants(f) => f.serialize(serializer),
+            TdFunction::LogOut(f) => f.serialize(serializer),
+            TdFunction::OpenChat(f) => f.serialize(serializer),
+            TdFunction::OpenMessageContent(f) => f.serialize(serializer),
+            TdFunction::OptimizeStorage(f) => f.serialize(serializer),
+            TdFunction::ParseMarkdown(f) => f.serialize(serializer),
+            TdFunction::ParseTextEntities(f) => f.serialize(serializer),
+            TdFunction::PinChatMessage(f) => f.serialize(serializer),
+            TdFunction::PingProxy(f) => f.serialize(serializer),
+            TdFunction::ProcessChatJoinRequest(f) => f.serialize(serializer),
+            TdFunction::ProcessChatJoinRequests(f) => f.serialize(serializer),
+            TdFunction::ProcessPushNotification(f) => f.serialize(serializer),
+            TdFunction::ReadAllChatMentions(f) => f.serialize(serializer),
+            TdFunction::ReadFilePart(f) => f.serialize(serializer),
+            TdFunction::RecoverAuthenticationPassword(f) => f.serialize(serializer),
+            TdFunction::RecoverPassword(f) => f.serialize(serializer),
+            TdFunction::RegisterDevice(f) => f.serialize(serializer),
+            TdFunction::RegisterUser(f) => f.serialize(serializer),
+            TdFunction::RemoveBackground(f) => f.serialize(serializer),
+            TdFunction::RemoveChatActionBar(f) => f.serialize(serializer),
+            TdFunction::RemoveContacts(f) => f.serialize(serializer),
+            TdFunction::RemoveFavoriteSticker(f) => f.serialize(serializer),
+            TdFunction::RemoveNotification(f) => f.serialize(serializer),
+            TdFunction::RemoveNotificationGroup(f) => f.serialize(serializer),
+            TdFunction::RemoveProxy(f) => f.serialize(serializer),
+            TdFunction::RemoveRecentHashtag(f) => f.serialize(serializer),
+            TdFunction::RemoveRecentSticker(f) => f.serialize(serializer),
+            TdFunction::RemoveRecentlyFoundChat(f) => f.serialize(serializer),
+            TdFunction::RemoveSavedAnimation(f) => f.serialize(serializer),
+            TdFunction::RemoveStickerFromSet(f) => f.serialize(serializer),
+            TdFunction::RemoveTopChat(f) => f.serialize(serializer),
+            TdFunction::ReorderChatFilters(f) => f.serialize(serializer),
+            TdFunction::ReorderInstalledStickerSets(f) => f.serialize(serializer),
+            TdFunction::ReplacePrimaryChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::ReportChat(f) => f.serialize(serializer),
+            TdFunction::ReportChatPhoto(f) => f.serialize(serializer),
+            TdFunction::ReportSupergroupSpam(f) => f.serialize(serializer),
+            TdFunction::RequestAuthenticationPasswordRecovery(f) => f.serialize(serializer),
+            TdFunction::RequestPasswordRecovery(f) => f.serialize(serializer),
+            TdFunction::RequestQrCodeAuthentication(f) => f.serialize(serializer),
+            TdFunction::ResendAuthenticationCode(f) => f.serialize(serializer),
+            TdFunction::ResendChangePhoneNumberCode(f) => f.serialize(serializer),
+            TdFunction::ResendEmailAddressVerificationCode(f) => f.serialize(serializer),
+            TdFunction::ResendMessages(f) => f.serialize(serializer),
+            TdFunction::ResendPhoneNumberConfirmationCode(f) => f.serialize(serializer),
+            TdFunction::ResendPhoneNumberVerificationCode(f) => f.serialize(serializer),
+            TdFunction::ResendRecoveryEmailAddressCode(f) => f.serialize(serializer),
+            TdFunction::ResetAllNotificationSettings(f) => f.serialize(serializer),
+            TdFunction::ResetBackgrounds(f) => f.serialize(serializer),
+            TdFunction::ResetNetworkStatistics(f) => f.serialize(serializer),
+            TdFunction::ResetPassword(f) => f.serialize(serializer),
+            TdFunction::RevokeChatInviteLink(f) => f.serialize(serializer),
+            TdFunction::RevokeGroupCallInviteLink(f) => f.serialize(serializer),
+            TdFunction::SaveApplicationLogEvent(f) => f.serialize(serializer),
+            TdFunction::SearchBackground(f) => f.serialize(serializer),
+            TdFunction::SearchCallMessages(f) => f.serialize(serializer),
+            TdFunction::SearchChatMembers(f) => f.serialize(serializer),
+            TdFunction::SearchChatMessages(f) => f.serialize(serializer),
+            TdFunction::SearchChatRecentLocationMessages(f) => f.serialize(serializer),
+            TdFunction::SearchChats(f) => f.serialize(serializer),
+            TdFunction::SearchChatsNearby(f) => f.serialize(serializer),
+            TdFunction::SearchChatsOnServer(f) => f.serialize(serializer),
+            TdFunction::SearchContacts(f) => f.serialize(serializer),
+            TdFunction::SearchEmojis(f) => f.serialize(serializer),
+            TdFunction::SearchHashtags(f) => f.serialize(serializer),
+            TdFunction::SearchInstalledStickerSets(f) => f.serialize(serializer),
+            TdFunction::SearchMessages(f) => f.serialize(serializer),
+            TdFunction::SearchPublicChat(f) => f.serialize(serializer),
+            TdFunction::SearchPublicChats(f) => f.serialize(serializer),
+            TdFunction::SearchSecretMessages(f) => f.serialize(serializer),
+            TdFunction::SearchStickerSet(f) => f.serialize(serializer),
+            TdFunction::SearchStickerSets(f) => f.serialize(serializer),
+            TdFunction::SearchStickers(f) => f.serialize(serializer),
+            TdFunction::SendBotStartMessage(f) => f.serialize(serializer),
+            TdFunction::SendCallDebugInformation(f) => f.serialize(serializer),
+            TdFunction::SendCallRating(f) => f.serialize(serializer),
+            TdFunction::SendCallSignalingData(f) => f.serialize(serializer),
+            TdFunction::SendChatAction(f) => f.serialize(serializer),
+            TdFunction::SendChatScreenshotTakenNotification(f) => f.serialize(serializer),
+            TdFunction::SendCustomRequest(f) => f.serialize(serializer),
+            TdFunction::SendEmailAddressVerificationCode(f) => f.serialize(serializer),
+            TdFunction::SendInlineQueryResultMessage(f) => f.serialize(serializer),
+            TdFunction::SendMessage(f) => f.serialize(serializer),
+            TdFunction::SendMessageAlbum(f) => f.serialize(serializer),
+            TdFunction::SendPassportAuthorizationForm(f) => f.serialize(serializer),
+            TdFunction::SendPaymentForm(f) => f.serialize(serializer),
+            TdFunction::SendPhoneNumberConfirmationCode(f) => f.serialize(serializer),
+            TdFunction::SendPhoneNumberVerificationCode(f) => f.serialize(serializer),
+            TdFunction::SetAccountTtl(f) => f.serialize(serializer),
+            TdFunction::SetAlarm(f) => f.serialize(serializer),
+            TdFunction::SetAuthenticationPhoneNumber(f) => f.serialize(serializer),
+            TdFunction::SetAutoDownloadSettings(f) => f.serialize(serializer),
+            TdFunction::SetBackground(f) => f.serialize(serializer),
+            TdFunction::SetBio(f) => f.serialize(serializer),
+            TdFunction::SetBotUpdatesStatus(f) => f.serialize(serializer),
+            TdFunction::SetChatClientData(f) => f.serialize(serializer),
+            TdFunction::SetChatDescription(f) => f.serialize(serializer),
+            TdFunction::SetChatDiscussionGroup(f) => f.serialize(serializer),
+            TdFunction::SetChatDraftMessage(f) => f.serialize(serializer),
+            TdFunction::SetChatLocation(f) => f.serialize(serializer),
+            TdFunction::SetChatMemberStatus(f) => f.serialize(serializer),
+            TdFunction::SetChatMessageSender(f) => f.serialize(serializer),
+            TdFunction::SetChatMessageTtl(f) => f.serialize(serializer),
+            TdFunction::SetChatNotificationSettings(f) => f.serialize(serializer),
+            TdFunction::SetChatPermissions(f) => f.serialize(serializer),
+            TdFunction::SetChatPhoto(f) => f.serialize(serializer),
+            TdFunction::SetChatSlowModeDelay(f) => f.serialize(serializer),
+            TdFunction::SetChatTheme(f) => f.serialize(serializer),
+            TdFunction::SetChatTitle(f) => f.serialize(serializer),
+            TdFunction::SetCommands(f) => f.serialize(serializer),
+            TdFunction::SetCustomLanguagePack(f) => f.serialize(serializer),
+            TdFunction::SetCustomLanguagePackString(f) => f.serialize(serializer),
+            TdFunction::SetDatabaseEncryptionKey(f) => f.serialize(serializer),
+            TdFunction::SetFileGenerationProgress(f) => f.serialize(serializer),
+            TdFunction::SetGameScore(f) => f.serialize(serializer),
+            TdFunction::SetGroupCallParticipantIsSpeaking(f) => f.serialize(serializer),
+            TdFunction::SetGroupCallParticipantVolumeLevel(f) => f.serialize(serializer),
+            TdFunction::SetGroupCallTitle(f) => f.serialize(serializer),
+            TdFunction::SetInactiveSessionTtl(f) => f.serialize(serializer),
+            TdFunction::SetInlineGameScore(f) => f.serialize(serializer),
+            TdFunction::SetLocation(f) => f.serialize(serializer),
+            TdFunction::SetLogStream(f) => f.serialize(serializer),
+            TdFunction::SetLogTagVerbosityLevel(f) => f.serialize(serializer),
+            TdFunction::SetLogVerbosityLevel(f) => f.serialize(serializer),
+            TdFunction::SetName(f) => f.serialize(serializer),
+            TdFunction::SetNetworkType(f) => f.serialize(serializer),
+            TdFunction::SetOption(f) => f.serialize(serializer),
+            TdFunction::SetPassportElement(f) => f.serialize(serializer),
+            TdFunction::SetPassportElementErrors(f) => f.serialize(serializer),
+            TdFunction::SetPassword(f) => f.serialize(serializer),
+            TdFunction::SetPinnedChats(f) => f.serialize(serializer),
+            TdFunction::SetPollAnswer(f) => f.serialize(serializer),
+            TdFunction::SetProfilePhoto(f) => f.serialize(serializer),
+            TdFunction::SetRecoveryEmailAddress(f) => f.serialize(serializer),
+            TdFunction::SetScopeNotificationSettings(f) => f.serialize(serializer),
+            TdFunction::SetStickerPositionInSet(f) => f.serialize(serializer),
+            TdFunction::SetStickerSetThumbnail(f) => f.serialize(serializer),
+            TdFunction::SetSupergroupStickerSet(f) => f.serialize(serializer),
+            TdFunction::SetSupergroupUsername(f) => f.serialize(serializer),
+            TdFunction::SetTdlibParameters(f) => f.serialize(serializer),
+            TdFunction::SetUserPrivacySettingRules(f) => f.serialize(serializer),
+            TdFunction::SetUsername(f) => f.serialize(serializer),
+            TdFunction::SetVideoChatDefaultParticipant(f) => f.serialize(serializer),
+            TdFunction::SharePhoneNumber(f) => f.serialize(serializer),
+            TdFunction::StartGroupCallRecording(f) => f.serialize(serializer),
+            TdFunction::StartGroupCallScreenSharing(f) => f.serialize(serializer),
+            TdFunction::StartScheduledGroupCall(f) => f.serialize(serializer),
+            TdFunction::StopPoll(f) => f.serialize(serializer),
+            TdFunction::SynchronizeLanguagePack(f) => f.serialize(serializer),
+            TdFunction::TerminateAllOtherSessions(f) => f.serialize(serializer),
+            TdFunction::TerminateSession(f) => f.serialize(serializer),
+            TdFunction::TestCallBytes(f) => f.serialize(serializer),
+            TdFunction::TestCallEmpty(f) => f.serialize(serializer),
+            TdFunction::TestCallString(f) => f.serialize(serializer),
+            TdFunction::TestCallVectorInt(f) => f.serialize(serializer),
+            TdFunction::TestCallVectorIntObject(f) => f.serialize(serializer),
+            TdFunction::TestCallVectorString(f) => f.serialize(serializer),
+            TdFunction::TestCallVectorStringObject(f) => f.serialize(serializer),
+            TdFunction::TestGetDifference(f) => f.serialize(serializer),
+            TdFunction::TestNetwork(f) => f.serialize(serializer),
+            TdFunction::TestProxy(f) => f.serialize(serializer),
+            TdFunction::TestReturnError(f) => f.serialize(serializer),
+            TdFunction::TestSquareInt(f) => f.serialize(serializer),
+            TdFunction::TestUseUpdate(f) => f.serialize(serializer),
+            TdFunction::ToggleChatDefaultDisableNotification(f) => f.serialize(serializer),
+            TdFunction::ToggleChatHasProtectedContent(f) => f.serialize(serializer),
+            TdFunction::ToggleChatIsMarkedAsUnread(f) => f.serialize(serializer),
+            TdFunction::ToggleChatIsPinned(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallEnabledStartNotification(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallIsMyVideoEnabled(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallIsMyVideoPaused(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallMuteNewParticipants(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallParticipantIsHandRaised(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallParticipantIsMuted(f) => f.serialize(serializer),
+            TdFunction::ToggleGroupCallScreenSharingIsPaused(f) => f.serialize(serializer),
+            TdFunction::ToggleMessageSenderIsBlocked(f) => f.serialize(serializer),
+            TdFunction::ToggleSessionCanAcceptCalls(f) => f.serialize(serializer),
+            TdFunction::ToggleSessionCanAcceptSecretChats(f) => f.serialize(serializer),
+            TdFunction::ToggleSupergroupIsAllHistoryAvailable(f) => f.serialize(serializer),
+            TdFunction::ToggleSupergroupIsBroadcastGroup(f) => f.serialize(serializer),
+            TdFunction::ToggleSupergroupSignMessages(f) => f.serialize(serializer),
+            TdFunction::TransferChatOwnership(f) => f.serialize(serializer),
+            TdFunction::UnpinAllChatMessages(f) => f.serialize(serializer),
+            TdFunction::UnpinChatMessage(f) => f.serialize(serializer),
+            TdFunction::UpgradeBasicGroupChatToSupergroupChat(f) => f.serialize(serializer),
+            TdFunction::UploadFile(f) => f.serialize(serializer),
+            TdFunction::UploadStickerFile(f) => f.serialize(serializer),
+            TdFunction::ValidateOrderInfo(f) => f.serialize(serializer),
+            TdFunction::ViewMessages(f) => f.serialize(serializer),
+            TdFunction::ViewTrendingStickerSets(f) => f.serialize(serializer),
+            TdFunction::WriteGeneratedFilePart(f) => f.serialize(serializer),
+            TdFunction::Unknown { raw, .. } => raw.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TdFunction {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "acceptCall" => TdFunction::AcceptCall(super::_common::from_raw(raw)?),
+            "acceptTermsOfService" => {
+                TdFunction::AcceptTermsOfService(super::_common::from_raw(raw)?)
+            }
+            "addChatMember" => TdFunction::AddChatMember(super::_common::from_raw(raw)?),
+            "addChatMembers" => TdFunction::AddChatMembers(super::_common::from_raw(raw)?),
+            "addChatToList" => TdFunction::AddChatToList(super::_common::from_raw(raw)?),
+            "addContact" => TdFunction::AddContact(super::_common::from_raw(raw)?),
+            "addCustomServerLanguagePack" => {
+                TdFunction::AddCustomServerLanguagePack(super::_common::from_raw(raw)?)
+            }
+            "addFavoriteSticker" => TdFunction::AddFavoriteSticker(super::_common::from_raw(raw)?),
+            "addLocalMessage" => TdFunction::AddLocalMessage(super::_common::from_raw(raw)?),
+            "addLogMessage" => TdFunction::AddLogMessage(super::_common::from_raw(raw)?),
+            "addNetworkStatistics" => {
+                TdFunction::AddNetworkStatistics(super::_common::from_raw(raw)?)
+            }
+            "addProxy" => TdFunction::AddProxy(super::_common::from_raw(raw)?),
+            "addRecentSticker" => TdFunction::AddRecentSticker(super::_common::from_raw(raw)?),
+            "addRecentlyFoundChat" => {
+                TdFunction::AddRecentlyFoundChat(super::_common::from_raw(raw)?)
+            }
+            "addSavedAnimation" => TdFunction::AddSavedAnimation(super::_common::from_raw(raw)?),
+            "addStickerToSet" => TdFunction::AddStickerToSet(super::_common::from_raw(raw)?),
+            "answerCallbackQuery" => {
+                TdFunction::AnswerCallbackQuery(super::_common::from_raw(raw)?)
+            }
+            "answerCustomQuery" => TdFunction::AnswerCustomQuery(super::_common::from_raw(raw)?),
+            "answerInlineQuery" => TdFunction::AnswerInlineQuery(super::_common::from_raw(raw)?),
+            "answerPreCheckoutQuery" => {
+                TdFunction::AnswerPreCheckoutQuery(super::_common::from_raw(raw)?)
+            }
+            "answerShippingQuery" => {
+                TdFunction::AnswerShippingQuery(super::_common::from_raw(raw)?)
+            }
+            "banChatMember" => TdFunction::BanChatMember(super::_common::from_raw(raw)?),
+            "blockMessageSenderFromReplies" => {
+                TdFunction::BlockMessageSenderFromReplies(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnership" => {
+                TdFunction::CanTransferOwnership(super::_common::from_raw(raw)?)
+            }
+            "cancelDownloadFile" => TdFunction::CancelDownloadFile(super::_common::from_raw(raw)?),
+            "cancelPasswordReset" => {
+                TdFunction::CancelPasswordReset(super::_common::from_raw(raw)?)
+            }
+            "cancelUploadFile" => TdFunction::CancelUploadFile(super::_common::from_raw(raw)?),
+            "changeImportedContacts" => {
+                TdFunction::ChangeImportedContacts(super::_common::from_raw(raw)?)
+            }
+            "changePhoneNumber" => TdFunction::ChangePhoneNumber(super::_common::from_raw(raw)?),
+            "changeStickerSet" => TdFunction::ChangeStickerSet(super::_common::from_raw(raw)?),
+            "checkAuthenticationBotToken" => {
+                TdFunction::CheckAuthenticationBotToken(super::_common::from_raw(raw)?)
+            }
+            "checkAuthenticationCode" => {
+                TdFunction::CheckAuthenticationCode(super::_common::from_raw(raw)?)
+            }
+            "checkAuthenticationPassword" => {
+                TdFunction::CheckAuthenticationPassword(super::_common::from_raw(raw)?)
+            }
+            "checkAuthenticationPasswordRecoveryCode" => {
+                TdFunction::CheckAuthenticationPasswordRecoveryCode(super::_common::from_raw(raw)?)
+            }
+            "checkChangePhoneNumberCode" => {
+                TdFunction::CheckChangePhoneNumberCode(super::_common::from_raw(raw)?)
+            }
+            "checkChatInviteLink" => {
+                TdFunction::CheckChatInviteLink(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsername" => TdFunction::CheckChatUsername(super::_common::from_raw(raw)?),
+            "checkCreatedPublicChatsLimit" => {
+                TdFunction::CheckCreatedPublicChatsLimit(super::_common::from_raw(raw)?)
+            }
+            "checkDatabaseEncryptionKey" => {
+                TdFunction::CheckDatabaseEncryptionKey(super::_common::from_raw(raw)?)
+            }
+            "checkEmailAddressVerificationCode" => {
+                TdFunction::CheckEmailAddressVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "checkPasswordRecoveryCode" => {
+                TdFunction::CheckPasswordRecoveryCode(super::_common::from_raw(raw)?)
+            }
+            "checkPhoneNumberConfirmationCode" => {
+                TdFunction::CheckPhoneNumberConfirmationCode(super::_common::from_raw(raw)?)
+            }
+            "checkPhoneNumberVerificationCode" => {
+                TdFunction::CheckPhoneNumberVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "checkRecoveryEmailAddressCode" => {
+                TdFunction::CheckRecoveryEmailAddressCode(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetName" => {
+                TdFunction::CheckStickerSetName(super::_common::from_raw(raw)?)
+            }
+            "cleanFileName" => TdFunction::CleanFileName(super::_common::from_raw(raw)?),
+            "clearAllDraftMessages" => {
+                TdFunction::ClearAllDraftMessages(super::_common::from_raw(raw)?)
+            }
+            "clearImportedContacts" => {
+                TdFunction::ClearImportedContacts(super::_common::from_raw(raw)?)
+            }
+            "clearRecentStickers" => {
+                TdFunction::ClearRecentStickers(super::_common::from_raw(raw)?)
+            }
+            "clearRecentlyFoundChats" => {
+                TdFunction::ClearRecentlyFoundChats(super::_common::from_raw(raw)?)
+            }
+            "clickAnimatedEmojiMessage" => {
+                TdFunction::ClickAnimatedEmojiMessage(super::_common::from_raw(raw)?)
+            }
+            "close" => TdFunction::Close(super::_common::from_raw(raw)?),
+            "closeChat" => TdFunction::CloseChat(super::_common::from_raw(raw)?),
+            "closeSecretChat" => TdFunction::CloseSecretChat(super::_common::from_raw(raw)?),
+            "confirmQrCodeAuthentication" => {
+                TdFunction::ConfirmQrCodeAuthentication(super::_common::from_raw(raw)?)
+            }
+            "createBasicGroupChat" => {
+                TdFunction::CreateBasicGroupChat(super::_common::from_raw(raw)?)
+            }
+            "createCall" => TdFunction::CreateCall(super::_common::from_raw(raw)?),
+            "createChatFilter" => TdFunction::CreateChatFilter(super::_common::from_raw(raw)?),
+            "createChatInviteLink" => {
+                TdFunction::CreateChatInviteLink(super::_common::from_raw(raw)?)
+            }
+            "createNewBasicGroupChat" => {
+                TdFunction::CreateNewBasicGroupChat(super::_common::from_raw(raw)?)
+            }
+            "createNewSecretChat" => {
+                TdFunction::CreateNewSecretChat(super::_common::from_raw(raw)?)
+            }
+            "createNewStickerSet" => {
+                TdFunction::CreateNewStickerSet(super::_common::from_raw(raw)?)
+            }
+            "createNewSupergroupChat" => {
+                TdFunction::CreateNewSupergroupChat(super::_common::from_raw(raw)?)
+            }
+            "createPrivateChat" => TdFunction::CreatePrivateChat(super::_common::from_raw(raw)?),
+            "createSecretChat" => TdFunction::CreateSecretChat(super::_common::from_raw(raw)?),
+            "createSupergroupChat" => {
+                TdFunction::CreateSupergroupChat(super::_common::from_raw(raw)?)
+            }
+            "createTemporaryPassword" => {
+                TdFunction::CreateTemporaryPassword(super::_common::from_raw(raw)?)
+            }
+            "createVideoChat" => TdFunction::CreateVideoChat(super::_common::from_raw(raw)?),
+            "deleteAccount" => TdFunction::DeleteAccount(super::_common::from_raw(raw)?),
+            "deleteAllCallMessages" => {
+                TdFunction::DeleteAllCallMessages(super::_common::from_raw(raw)?)
+            }
+            "deleteAllRevokedChatInviteLinks" => {
+                TdFunction::DeleteAllRevokedChatInviteLinks(super::_common::from_raw(raw)?)
+            }
+            "deleteChat" => TdFunction::DeleteChat(super::_common::from_raw(raw)?),
+            "deleteChatFilter" => TdFunction::DeleteChatFilter(super::_common::from_raw(raw)?),
+            "deleteChatHistory" => TdFunction::DeleteChatHistory(super::_common::from_raw(raw)?),
+            "deleteChatMessagesByDate" => {
+                TdFunction::DeleteChatMessagesByDate(super::_common::from_raw(raw)?)
+            }
+            "deleteChatMessagesBySender" => {
+                TdFunction::DeleteChatMessagesBySender(super::_common::from_raw(raw)?)
+            }
+            "deleteChatReplyMarkup" => {
+                TdFunction::DeleteChatReplyMarkup(super::_common::from_raw(raw)?)
+            }
+            "deleteCommands" => TdFunction::DeleteCommands(super::_common::from_raw(raw)?),
+            "deleteFile" => TdFunction::DeleteFile(super::_common::from_raw(raw)?),
+            "deleteLanguagePack" => TdFunction::DeleteLanguagePack(super::_common::from_raw(raw)?),
+            "deleteMessages" => TdFunction::DeleteMessages(super::_common::from_raw(raw)?),
+            "deletePassportElement" => {
+                TdFunction::DeletePassportElement(super::_common::from_raw(raw)?)
+            }
+            "deleteProfilePhoto" => TdFunction::DeleteProfilePhoto(super::_common::from_raw(raw)?),
+            "deleteRevokedChatInviteLink" => {
+                TdFunction::DeleteRevokedChatInviteLink(super::_common::from_raw(raw)?)
+            }
+            "deleteSavedCredentials" => {
+                TdFunction::DeleteSavedCredentials(super::_common::from_raw(raw)?)
+            }
+            "deleteSavedOrderInfo" => {
+                TdFunction::DeleteSavedOrderInfo(super::_common::from_raw(raw)?)
+            }
+            "destroy" => TdFunction::Destroy(super::_common::from_raw(raw)?),
+            "disableProxy" => TdFunction::DisableProxy(super::_common::from_raw(raw)?),
+            "discardCall" => TdFunction::DiscardCall(super::_common::from_raw(raw)?),
+            "disconnectAllWebsites" => {
+                TdFunction::DisconnectAllWebsites(super::_common::from_raw(raw)?)
+            }
+            "disconnectWebsite" => TdFunction::DisconnectWebsite(super::_common::from_raw(raw)?),
+            "downloadFile" => TdFunction::DownloadFile(super::_common::from_raw(raw)?),
+            "editChatFilter" => TdFunction::EditChatFilter(super::_common::from_raw(raw)?),
+            "editChatInviteLink" => TdFunction::EditChatInviteLink(super::_common::from_raw(raw)?),
+            "editCustomLanguagePackInfo" => {
+                TdFunction::EditCustomLanguagePackInfo(super::_common::from_raw(raw)?)
+            }
+            "editInlineMessageCaption" => {
+                TdFunction::EditInlineMessageCaption(super::_common::from_raw(raw)?)
+            }
+            "editInlineMessageLiveLocation" => {
+                TdFunction::EditInlineMessageLiveLocation(super::_common::from_raw(raw)?)
+            }
+            "editInlineMessageMedia" => {
+                TdFunction::EditInlineMessageMedia(super::_common::from_raw(raw)?)
+            }
+            "editInlineMessageReplyMarkup" => {
+                TdFunction::EditInlineMessageReplyMarkup(super::_common::from_raw(raw)?)
+            }
+            "editInlineMessageText" => {
+                TdFunction::EditInlineMessageText(super::_common::from_raw(raw)?)
+            }
+            "editMessageCaption" => TdFunction::EditMessageCaption(super::_common::from_raw(raw)?),
+            "editMessageLiveLocation" => {
+                TdFunction::EditMessageLiveLocation(super::_common::from_raw(raw)?)
+            }
+            "editMessageMedia" => TdFunction::EditMessageMedia(super::_common::from_raw(raw)?),
+            "editMessageReplyMarkup" => {
+                TdFunction::EditMessageReplyMarkup(super::_common::from_raw(raw)?)
+            }
+            "editMessageSchedulingState" => {
+                TdFunction::EditMessageSchedulingState(super::_common::from_raw(raw)?)
+            }
+            "editMessageText" => TdFunction::EditMessageText(super::_common::from_raw(raw)?),
+            "editProxy" => TdFunction::EditProxy(super::_common::from_raw(raw)?),
+            "enableProxy" => TdFunction::EnableProxy(super::_common::from_raw(raw)?),
+            "endGroupCall" => TdFunction::EndGroupCall(super::_common::from_raw(raw)?),
+            "endGroupCallRecording" => {
+                TdFunction::EndGroupCallRecording(super::_common::from_raw(raw)?)
+            }
+            "endGroupCallScreenSharing" => {
+                TdFunction::EndGroupCallScreenSharing(super::_common::from_raw(raw)?)
+            }
+            "finishFileGeneration" => {
+                TdFunction::FinishFileGeneration(super::_common::from_raw(raw)?)
+            }
+            "forwardMessages" => TdFunction::ForwardMessages(super::_common::from_raw(raw)?),
+            "getAccountTtl" => TdFunction::GetAccountTtl(super::_common::from_raw(raw)?),
+            "getActiveLiveLocationMessages" => {
+                TdFunction::GetActiveLiveLocationMessages(super::_common::from_raw(raw)?)
+            }
+            "getActiveSessions" => TdFunction::GetActiveSessions(super::_common::from_raw(raw)?),
+            "getAllPassportElements" => {
+                TdFunction::GetAllPassportElements(super::_common::from_raw(raw)?)
+            }
+            "getAnimatedEmoji" => TdFunction::GetAnimatedEmoji(super::_common::from_raw(raw)?),
+            "getApplicationConfig" => {
+                TdFunction::GetApplicationConfig(super::_common::from_raw(raw)?)
+            }
+            "getApplicationDownloadLink" => {
+                TdFunction::GetApplicationDownloadLink(super::_common::from_raw(raw)?)
+            }
+            "getArchivedStickerSets" => {
+                TdFunction::GetArchivedStickerSets(super::_common::from_raw(raw)?)
+            }
+            "getAttachedStickerSets" => {
+                TdFunction::GetAttachedStickerSets(super::_common::from_raw(raw)?)
+            }
+            "getAuthorizationState" => {
+                TdFunction::GetAuthorizationState(super::_common::from_raw(raw)?)
+            }
+            "getAutoDownloadSettingsPresets" => {
+                TdFunction::GetAutoDownloadSettingsPresets(super::_common::from_raw(raw)?)
+            }
+            "getBackgroundUrl" => TdFunction::GetBackgroundUrl(super::_common::from_raw(raw)?),
+            "getBackgrounds" => TdFunction::GetBackgrounds(super::_common::from_raw(raw)?),
+            "getBankCardInfo" => TdFunction::GetBankCardInfo(super::_common::from_raw(raw)?),
+            "getBasicGroup" => TdFunction::GetBasicGroup(super::_common::from_raw(raw)?),
+            "getBasicGroupFullInfo" => {
+                TdFunction::GetBasicGroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "getBlockedMessageSenders" => {
+                TdFunction::GetBlockedMessageSenders(super::_common::from_raw(raw)?)
+            }
+            "getCallbackQueryAnswer" => {
+                TdFunction::GetCallbackQueryAnswer(super::_common::from_raw(raw)?)
+            }
+            "getCallbackQueryMessage" => {
+                TdFunction::GetCallbackQueryMessage(super::_common::from_raw(raw)?)
+            }
+            "getChat" => TdFunction::GetChat(super::_common::from_raw(raw)?),
+            "getChatAdministrators" => {
+                TdFunction::GetChatAdministrators(super::_common::from_raw(raw)?)
+            }
+            "getChatAvailableMessageSenders" => {
+                TdFunction::GetChatAvailableMessageSenders(super::_common::from_raw(raw)?)
+            }
+            "getChatEventLog" => TdFunction::GetChatEventLog(super::_common::from_raw(raw)?),
+            "getChatFilter" => TdFunction::GetChatFilter(super::_common::from_raw(raw)?),
+            "getChatFilterDefaultIconName" => {
+                TdFunction::GetChatFilterDefaultIconName(super::_common::from_raw(raw)?)
+            }
+            "getChatHistory" => TdFunction::GetChatHistory(super::_common::from_raw(raw)?),
+            "getChatInviteLink" => TdFunction::GetChatInviteLink(super::_common::from_raw(raw)?),
+            "getChatInviteLinkCounts" => {
+                TdFunction::GetChatInviteLinkCounts(super::_common::from_raw(raw)?)
+            }
+            "getChatInviteLinkMembers" => {
+                TdFunction::GetChatInviteLinkMembers(super::_common::from_raw(raw)?)
+            }
+            "getChatInviteLinks" => TdFunction::GetChatInviteLinks(super::_common::from_raw(raw)?),
+            "getChatJoinRequests" => {
+                TdFunction::GetChatJoinRequests(super::_common::from_raw(raw)?)
+            }
+            "getChatListsToAddChat" => {
+                TdFunction::GetChatListsToAddChat(super::_common::from_raw(raw)?)
+            }
+            "getChatMember" => TdFunction::GetChatMember(super::_common::from_raw(raw)?),
+            "getChatMessageByDate" => {
+                TdFunction::GetChatMessageByDate(super::_common::from_raw(raw)?)
+            }
+            "getChatMessageCalendar" => {
+                TdFunction::GetChatMessageCalendar(super::_common::from_raw(raw)?)
+            }
+            "getChatMessageCount" => {
+                TdFunction::GetChatMessageCount(super::_common::from_raw(raw)?)
+            }
+            "getChatNotificationSettingsExceptions" => {
+                TdFunction::GetChatNotificationSettingsExceptions(super::_common::from_raw(raw)?)
+            }
+            "getChatPinnedMessage" => {
+                TdFunction::GetChatPinnedMessage(super::_common::from_raw(raw)?)
+            }
+            "getChatScheduledMessages" => {
+                TdFunction::GetChatScheduledMessages(super::_common::from_raw(raw)?)
+            }
+            "getChatSparseMessagePositions" => {
+                TdFunction::GetChatSparseMessagePositions(super::_common::from_raw(raw)?)
+            }
+            "getChatSponsoredMessage" => {
+                TdFunction::GetChatSponsoredMessage(super::_common::from_raw(raw)?)
+            }
+            "getChatStatistics" => TdFunction::GetChatStatistics(super::_common::from_raw(raw)?),
+            "getChats" => TdFunction::GetChats(super::_common::from_raw(raw)?),
+            "getCommands" => TdFunction::GetCommands(super::_common::from_raw(raw)?),
+            "getConnectedWebsites" => {
+                TdFunction::GetConnectedWebsites(super::_common::from_raw(raw)?)
+            }
+            "getContacts" => TdFunction::GetContacts(super::_common::from_raw(raw)?),
+            "getCountries" => TdFunction::GetCountries(super::_common::from_raw(raw)?),
+            "getCountryCode" => TdFunction::GetCountryCode(super::_common::from_raw(raw)?),
+            "getCreatedPublicChats" => {
+                TdFunction::GetCreatedPublicChats(super::_common::from_raw(raw)?)
+            }
+            "getCurrentState" => TdFunction::GetCurrentState(super::_common::from_raw(raw)?),
+            "getDatabaseStatistics" => {
+                TdFunction::GetDatabaseStatistics(super::_common::from_raw(raw)?)
+            }
+            "getDeepLinkInfo" => TdFunction::GetDeepLinkInfo(super::_common::from_raw(raw)?),
+            "getEmojiSuggestionsUrl" => {
+                TdFunction::GetEmojiSuggestionsUrl(super::_common::from_raw(raw)?)
+            }
+            "getExternalLink" => TdFunction::GetExternalLink(super::_common::from_raw(raw)?),
+            "getExternalLinkInfo" => {
+                TdFunction::GetExternalLinkInfo(super::_common::from_raw(raw)?)
+            }
+            "getFavoriteStickers" => {
+                TdFunction::GetFavoriteStickers(super::_common::from_raw(raw)?)
+            }
+            "getFile" => TdFunction::GetFile(super::_common::from_raw(raw)?),
+            "getFileDownloadedPrefixSize" => {
+                TdFunction::GetFileDownloadedPrefixSize(super::_common::from_raw(raw)?)
+            }
+            "getFileExtension" => TdFunction::GetFileExtension(super::_common::from_raw(raw)?),
+            "getFileMimeType" => TdFunction::GetFileMimeType(super::_common::from_raw(raw)?),
+            "getGameHighScores" => TdFunction::GetGameHighScores(super::_common::from_raw(raw)?),
+            "getGroupCall" => TdFunction::GetGroupCall(super::_common::from_raw(raw)?),
+            "getGroupCallInviteLink" => {
+                TdFunction::GetGroupCallInviteLink(super::_common::from_raw(raw)?)
+            }
+            "getGroupCallStreamSegment" => {
+                TdFunction::GetGroupCallStreamSegment(super::_common::from_raw(raw)?)
+            }
+            "getGroupsInCommon" => TdFunction::GetGroupsInCommon(super::_common::from_raw(raw)?),
+            "getImportedContactCount" => {
+                TdFunction::GetImportedContactCount(super::_common::from_raw(raw)?)
+            }
+            "getInactiveSupergroupChats" => {
+                TdFunction::GetInactiveSupergroupChats(super::_common::from_raw(raw)?)
+            }
+            "getInlineGameHighScores" => {
+                TdFunction::GetInlineGameHighScores(super::_common::from_raw(raw)?)
+            }
+            "getInlineQueryResults" => {
+                TdFunction::GetInlineQueryResults(super::_common::from_raw(raw)?)
+            }
+            "getInstalledStickerSets" => {
+                TdFunction::GetInstalledStickerSets(super::_common::from_raw(raw)?)
+            }
+            "getInternalLinkType" => {
+                TdFunction::GetInternalLinkType(super::_common::from_raw(raw)?)
+            }
+            "getJsonString" => TdFunction::GetJsonString(super::_common::from_raw(raw)?),
+            "getJsonValue" => TdFunction::GetJsonValue(super::_common::from_raw(raw)?),
+            "getLanguagePackInfo" => {
+                TdFunction::GetLanguagePackInfo(super::_common::from_raw(raw)?)
+            }
+            "getLanguagePackString" => {
+                TdFunction::GetLanguagePackString(super::_common::from_raw(raw)?)
+            }
+            "getLanguagePackStrings" => {
+                TdFunction::GetLanguagePackStrings(super::_common::from_raw(raw)?)
+            }
+            "getLocalizationTargetInfo" => {
+                TdFunction::GetLocalizationTargetInfo(super::_common::from_raw(raw)?)
+            }
+            "getLogStream" => TdFunction::GetLogStream(super::_common::from_raw(raw)?),
+            "getLogTagVerbosityLevel" => {
+                TdFunction::GetLogTagVerbosityLevel(super::_common::from_raw(raw)?)
+            }
+            "getLogTags" => TdFunction::GetLogTags(super::_common::from_raw(raw)?),
+            "getLogVerbosityLevel" => {
+                TdFunction::GetLogVerbosityLevel(super::_common::from_raw(raw)?)
+            }
+            "getLoginUrl" => TdFunction::GetLoginUrl(super::_common::from_raw(raw)?),
+            "getLoginUrlInfo" => TdFunction::GetLoginUrlInfo(super::_common::from_raw(raw)?),
+            "getMapThumbnailFile" => {
+                TdFunction::GetMapThumbnailFile(super::_common::from_raw(raw)?)
+            }
+            "getMarkdownText" => TdFunction::GetMarkdownText(super::_common::from_raw(raw)?),
+            "getMe" => TdFunction::GetMe(super::_common::from_raw(raw)?),
+            "getMessage" => TdFunction::GetMessage(super::_common::from_raw(raw)?),
+            "getMessageEmbeddingCode" => {
+                TdFunction::GetMessageEmbeddingCode(super::_common::from_raw(raw)?)
+            }
+            "getMessageFileType" => TdFunction::GetMessageFileType(super::_common::from_raw(raw)?),
+            "getMessageImportConfirmationText" => {
+                TdFunction::GetMessageImportConfirmationText(super::_common::from_raw(raw)?)
+            }
+            "getMessageLink" => TdFunction::GetMessageLink(super::_common::from_raw(raw)?),
+            "getMessageLinkInfo" => TdFunction::GetMessageLinkInfo(super::_common::from_raw(raw)?),
+            "getMessageLocally" => TdFunction::GetMessageLocally(super::_common::from_raw(raw)?),
+            "getMessagePublicForwards" => {
+                TdFunction::GetMessagePublicForwards(super::_common::from_raw(raw)?)
+            }
+            "getMessageStatistics" => {
+                TdFunction::GetMessageStatistics(super::_common::from_raw(raw)?)
+            }
+            "getMessageThread" => TdFunction::GetMessageThread(super::_common::from_raw(raw)?),
+            "getMessageThreadHistory" => {
+                TdFunction::GetMessageThreadHistory(super::_common::from_raw(raw)?)
+            }
+            "getMessageViewers" => TdFunction::GetMessageViewers(super::_common::from_raw(raw)?),
+            "getMessages" => TdFunction::GetMessages(super::_common::from_raw(raw)?),
+            "getNetworkStatistics" => {
+                TdFunction::GetNetworkStatistics(super::_common::from_raw(raw)?)
+            }
+            "getOption" => TdFunction::GetOption(super::_common::from_raw(raw)?),
+            "getPassportAuthorizationForm" => {
+                TdFunction::GetPassportAuthorizationForm(super::_common::from_raw(raw)?)
+            }
+            "getPassportAuthorizationFormAvailableElements" => {
+                TdFunction::GetPassportAuthorizationFormAvailableElements(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "getPassportElement" => TdFunction::GetPassportElement(super::_common::from_raw(raw)?),
+            "getPasswordState" => TdFunction::GetPasswordState(super::_common::from_raw(raw)?),
+            "getPaymentForm" => TdFunction::GetPaymentForm(super::_common::from_raw(raw)?),
+            "getPaymentReceipt" => TdFunction::GetPaymentReceipt(super::_common::from_raw(raw)?),
+            "getPhoneNumberInfo" => TdFunction::GetPhoneNumberInfo(super::_common::from_raw(raw)?),
+            "getPhoneNumberInfoSync" => {
+                TdFunction::GetPhoneNumberInfoSync(super::_common::from_raw(raw)?)
+            }
+            "getPollVoters" => TdFunction::GetPollVoters(super::_common::from_raw(raw)?),
+            "getPreferredCountryLanguage" => {
+                TdFunction::GetPreferredCountryLanguage(super::_common::from_raw(raw)?)
+            }
+            "getProxies" => TdFunction::GetProxies(super::_common::from_raw(raw)?),
+            "getProxyLink" => TdFunction::GetProxyLink(super::_common::from_raw(raw)?),
+            "getPushReceiverId" => TdFunction::GetPushReceiverId(super::_common::from_raw(raw)?),
+            "getRecentInlineBots" => {
+                TdFunction::GetRecentInlineBots(super::_common::from_raw(raw)?)
+            }
+            "getRecentStickers" => TdFunction::GetRecentStickers(super::_common::from_raw(raw)?),
+            "getRecentlyOpenedChats" => {
+                TdFunction::GetRecentlyOpenedChats(super::_common::from_raw(raw)?)
+            }
+            "getRecentlyVisitedTMeUrls" => {
+                TdFunction::GetRecentlyVisitedTMeUrls(super::_common::from_raw(raw)?)
+            }
+            "getRecommendedChatFilters" => {
+                TdFunction::GetRecommendedChatFilters(super::_common::from_raw(raw)?)
+            }
+            "getRecoveryEmailAddress" => {
+                TdFunction::GetRecoveryEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "getRemoteFile" => TdFunction::GetRemoteFile(super::_common::from_raw(raw)?),
+            "getRepliedMessage" => TdFunction::GetRepliedMessage(super::_common::from_raw(raw)?),
+            "getSavedAnimations" => TdFunction::GetSavedAnimations(super::_common::from_raw(raw)?),
+            "getSavedOrderInfo" => TdFunction::GetSavedOrderInfo(super::_common::from_raw(raw)?),
+            "getScopeNotificationSettings" => {
+                TdFunction::GetScopeNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "getSecretChat" => TdFunction::GetSecretChat(super::_common::from_raw(raw)?),
+            "getStatisticalGraph" => {
+                TdFunction::GetStatisticalGraph(super::_common::from_raw(raw)?)
+            }
+            "getStickerEmojis" => TdFunction::GetStickerEmojis(super::_common::from_raw(raw)?),
+            "getStickerSet" => TdFunction::GetStickerSet(super::_common::from_raw(raw)?),
+            "getStickers" => TdFunction::GetStickers(super::_common::from_raw(raw)?),
+            "getStorageStatistics" => {
+                TdFunction::GetStorageStatistics(super::_common::from_raw(raw)?)
+            }
+            "getStorageStatisticsFast" => {
+                TdFunction::GetStorageStatisticsFast(super::_common::from_raw(raw)?)
+            }
+            "getSuggestedFileName" => {
+                TdFunction::GetSuggestedFileName(super::_common::from_raw(raw)?)
+            }
+            "getSuggestedStickerSetName" => {
+                TdFunction::GetSuggestedStickerSetName(super::_common::from_raw(raw)?)
+            }
+            "getSuitableDiscussionChats" => {
+                TdFunction::GetSuitableDiscussionChats(super::_common::from_raw(raw)?)
+            }
+            "getSupergroup" => TdFunction::GetSupergroup(super::_common::from_raw(raw)?),
+            "getSupergroupFullInfo" => {
+                TdFunction::GetSupergroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "getSupergroupMembers" => {
+                TdFunction::GetSupergroupMembers(super::_common::from_raw(raw)?)
+            }
+            "getSupportUser" => TdFunction::GetSupportUser(super::_common::from_raw(raw)?),
+            "getTemporaryPasswordState" => {
+                TdFunction::GetTemporaryPasswordState(super::_common::from_raw(raw)?)
+            }
+            "getTextEntities" => TdFunction::GetTextEntities(super::_common::from_raw(raw)?),
+            "getTopChats" => TdFunction::GetTopChats(super::_common::from_raw(raw)?),
+            "getTrendingStickerSets" => {
+                TdFunction::GetTrendingStickerSets(super::_common::from_raw(raw)?)
+            }
+            "getUser" => TdFunction::GetUser(super::_common::from_raw(raw)?),
+            "getUserFullInfo" => TdFunction::GetUserFullInfo(super::_common::from_raw(raw)?),
+            "getUserPrivacySettingRules" => {
+                TdFunction::GetUserPrivacySettingRules(super::_common::from_raw(raw)?)
+            }
+            "getUserProfilePhotos" => {
+                TdFunction::GetUserProfilePhotos(super::_common::from_raw(raw)?)
+            }
+            "getVideoChatAvailableParticipants" => {
+                TdFunction::GetVideoChatAvailableParticipants(super::_common::from_raw(raw)?)
+            }
+            "getWebPageInstantView" => {
+                TdFunction::GetWebPageInstantView(super::_common::from_raw(raw)?)
+            }
+            "getWebPagePreview" => TdFunction::GetWebPagePreview(super::_common::from_raw(raw)?),
+            "hideSuggestedAction" => {
+                TdFunction::HideSuggestedAction(super::_common::from_raw(raw)?)
+            }
+            "importContacts" => TdFunction::ImportContacts(super::_common::from_raw(raw)?),
+            "importMessages" => TdFunction::ImportMessages(super::_common::from_raw(raw)?),
+            "inviteGroupCallParticipants" => {
+                TdFunction::InviteGroupCallParticipants(super::_common::from_raw(raw)?)
+            }
+            "joinChat" => TdFunction::JoinChat(super::_common::from_raw(raw)?),
+            "joinChatByInviteLink" => {
+                TdFunction::JoinChatByInviteLink(super::_common::from_raw(raw)?)
+            }
+            "joinGroupCall" => TdFunction::JoinGroupCall(super::_common::from_raw(raw)?),
+            "leaveChat" => TdFunction::LeaveChat(super::_common::from_raw(raw)?),
+            "leaveGroupCall" => TdFunction::LeaveGroupCall(super::_common::from_raw(raw)?),
+            "loadChats" => TdFunction::LoadChats(super::_common::from_raw(raw)?),
+            "loadGroupCallParticipants" => {
+                TdFunction::LoadGroupCallParticipants(super::_common::from_raw(raw)?)
+            }
+            "logOut" => TdFunction::LogOut(super::_common::from_raw(raw)?),
+            "openChat" => TdFunction::OpenChat(super::_common::from_raw(raw)?),
+            "openMessageContent" => TdFunction::OpenMessageContent(super::_common::from_raw(raw)?),
+            "optimizeStorage" => TdFunction::OptimizeStorage(super::_common::from_raw(raw)?),
+            "parseMarkdown" => TdFunction::ParseMarkdown(super::_common::from_raw(raw)?),
+            "parseTextEntities" => TdFunction::ParseTextEntities(super::_common::from_raw(raw)?),
+            "pinChatMessage" => TdFunction::PinChatMessage(super::_common::from_raw(raw)?),
+            "pingProxy" => TdFunction::PingProxy(super::_common::from_raw(raw)?),
+            "processChatJoinRequest" => {
+                TdFunction::ProcessChatJoinRequest(super::_common::from_raw(raw)?)
+            }
+            "processChatJoinRequests" => {
+                TdFunction::ProcessChatJoinRequests(super::_common::from_raw(raw)?)
+            }
+            "processPushNotification" => {
+                TdFunction::ProcessPushNotification(super::_common::from_raw(raw)?)
+            }
+            "readAllChatMentions" => {
+                TdFunction::ReadAllChatMentions(super::_common::from_raw(raw)?)
+            }
+            "readFilePart" => TdFunction::ReadFilePart(super::_common::from_raw(raw)?),
+            "recoverAuthenticationPassword" => {
+                TdFunction::RecoverAuthenticationPassword(super::_common::from_raw(raw)?)
+            }
+            "recoverPassword" => TdFunction::RecoverPassword(super::_common::from_raw(raw)?),
+            "registerDevice" => TdFunction::RegisterDevice(super::_common::from_raw(raw)?),
+            "registerUser" => TdFunction::RegisterUser(super::_common::from_raw(raw)?),
+            "removeBackground" => TdFunction::RemoveBackground(super::_common::from_raw(raw)?),
+            "removeChatActionBar" => {
+                TdFunction::RemoveChatActionBar(super::_common::from_raw(raw)?)
+            }
+            "removeContacts" => TdFunction::RemoveContacts(super::_common::from_raw(raw)?),
+            "removeFavoriteSticker" => {
+                TdFunction::RemoveFavoriteSticker(super::_common::from_raw(raw)?)
+            }
+            "removeNotification" => TdFunction::RemoveNotification(super::_common::from_raw(raw)?),
+            "removeNotificationGroup" => {
+                TdFunction::RemoveNotificationGroup(super::_common::from_raw(raw)?)
+            }
+            "removeProxy" => TdFunction::RemoveProxy(super::_common::from_raw(raw)?),
+            "removeRecentHashtag" => {
+                TdFunction::RemoveRecentHashtag(super::_common::from_raw(raw)?)
+            }
+            "removeRecentSticker" => {
+                TdFunction::RemoveRecentSticker(super::_common::from_raw(raw)?)
+            }
+            "removeRecentlyFoundChat" => {
+                TdFunction::RemoveRecentlyFoundChat(super::_common::from_raw(raw)?)
+            }
+            "removeSavedAnimation" => {
+                TdFunction::RemoveSavedAnimation(super::_common::from_raw(raw)?)
+            }
+            "removeStickerFromSet" => {
+                TdFunction::RemoveStickerFromSet(super::_common::from_raw(raw)?)
+            }
+            "removeTopChat" => TdFunction::RemoveTopChat(super::_common::from_raw(raw)?),
+            "reorderChatFilters" => TdFunction::ReorderChatFilters(super::_common::from_raw(raw)?),
+            "reorderInstalledStickerSets" => {
+                TdFunction::ReorderInstalledStickerSets(super::_common::from_raw(raw)?)
+            }
+            "replacePrimaryChatInviteLink" => {
+                TdFunction::ReplacePrimaryChatInviteLink(super::_common::from_raw(raw)?)
+            }
+            "reportChat" => TdFunction::ReportChat(super::_common::from_raw(raw)?),
+            "reportChatPhoto" => TdFunction::ReportChatPhoto(super::_common::from_raw(raw)?),
+            "reportSupergroupSpam" => {
+                TdFunction::ReportSupergroupSpam(super::_common::from_raw(raw)?)
+            }
+            "requestAuthenticationPasswordRecovery" => {
+                TdFunction::RequestAuthenticationPasswordRecovery(super::_common::from_raw(raw)?)
+            }
+            "requestPasswordRecovery" => {
+                TdFunction::RequestPasswordRecovery(super::_common::from_raw(raw)?)
+            }
+            "requestQrCodeAuthentication" => {
+                TdFunction::RequestQrCodeAuthentication(super::_common::from_raw(raw)?)
+            }
+            "resendAuthenticationCode" => {
+                TdFunction::ResendAuthenticationCode(super::_common::from_raw(raw)?)
+            }
+            "resendChangePhoneNumberCode" => {
+                TdFunction::ResendChangePhoneNumberCode(super::_common::from_raw(raw)?)
+            }
+            "resendEmailAddressVerificationCode" => {
+                TdFunction::ResendEmailAddressVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "resendMessages" => TdFunction::ResendMessages(super::_common::from_raw(raw)?),
+            "resendPhoneNumberConfirmationCode" => {
+                TdFunction::ResendPhoneNumberConfirmationCode(super::_common::from_raw(raw)?)
+            }
+            "resendPhoneNumberVerificationCode" => {
+                TdFunction::ResendPhoneNumberVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "resendRecoveryEmailAddressCode" => {
+                TdFunction::ResendRecoveryEmailAddressCode(super::_common::from_raw(raw)?)
+            }
+            "resetAllNotificationSettings" => {
+                TdFunction::ResetAllNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "resetBackgrounds" => TdFunction::ResetBackgrounds(super::_common::from_raw(raw)?),
+            "resetNetworkStatistics" => {
+                TdFunction::ResetNetworkStatistics(super::_common::from_raw(raw)?)
+            }
+            "resetPassword" => TdFunction::ResetPassword(super::_common::from_raw(raw)?),
+            "revokeChatInviteLink" => {
+                TdFunction::RevokeChatInviteLink(super::_common::from_raw(raw)?)
+            }
+            "revokeGroupCallInviteLink" => {
+                TdFunction::RevokeGroupCallInviteLink(super::_common::from_raw(raw)?)
+            }
+            "saveApplicationLogEvent" => {
+                TdFunction::SaveApplicationLogEvent(super::_common::from_raw(raw)?)
+            }
+            "searchBackground" => TdFunction::SearchBackground(super::_common::from_raw(raw)?),
+            "searchCallMessages" => TdFunction::SearchCallMessages(super::_common::from_raw(raw)?),
+            "searchChatMembers" => TdFunction::SearchChatMembers(super::_common::from_raw(raw)?),
+            "searchChatMessages" => TdFunction::SearchChatMessages(super::_common::from_raw(raw)?),
+            "searchChatRecentLocationMessages" => {
+                TdFunction::SearchChatRecentLocationMessages(super::_common::from_raw(raw)?)
+            }
+            "searchChats" => TdFunction::SearchChats(super::_common::from_raw(raw)?),
+            "searchChatsNearby" => TdFunction::SearchChatsNearby(super::_common::from_raw(raw)?),
+            "searchChatsOnServer" => {
+                TdFunction::SearchChatsOnServer(super::_common::from_raw(raw)?)
+            }
+            "searchContacts" => TdFunction::SearchContacts(super::_common::from_raw(raw)?),
+            "searchEmojis" => TdFunction::SearchEmojis(super::_common::from_raw(raw)?),
+            "searchHashtags" => TdFunction::SearchHashtags(super::_common::from_raw(raw)?),
+            "searchInstalledStickerSets" => {
+                TdFunction::SearchInstalledStickerSets(super::_common::from_raw(raw)?)
+            }
+            "searchMessages" => TdFunction::SearchMessages(super::_common::from_raw(raw)?),
+            "searchPublicChat" => TdFunction::SearchPublicChat(super::_common::from_raw(raw)?),
+            "searchPublicChats" => TdFunction::SearchPublicChats(super::_common::from_raw(raw)?),
+            "searchSecretMessages" => {
+                TdFunction::SearchSecretMessages(super::_common::from_raw(raw)?)
+            }
+            "searchStickerSet" => TdFunction::SearchStickerSet(super::_common::from_raw(raw)?),
+            "searchStickerSets" => TdFunction::SearchStickerSets(super::_common::from_raw(raw)?),
+            "searchStickers" => TdFunction::SearchStickers(super::_common::from_raw(raw)?),
+            "sendBotStartMessage" => {
+                TdFunction::SendBotStartMessage(super::_common::from_raw(raw)?)
+            }
+            "sendCallDebugInformation" => {
+                TdFunction::SendCallDebugInformation(super::_common::from_raw(raw)?)
+            }
+            "sendCallRating" => TdFunction::SendCallRating(super::_common::from_raw(raw)?),
+            "sendCallSignalingData" => {
+                TdFunction::SendCallSignalingData(super::_common::from_raw(raw)?)
+            }
+            "sendChatAction" => TdFunction::SendChatAction(super::_common::from_raw(raw)?),
+            "sendChatScreenshotTakenNotification" => {
+                TdFunction::SendChatScreenshotTakenNotification(super::_common::from_raw(raw)?)
+            }
+            "sendCustomRequest" => TdFunction::SendCustomRequest(super::_common::from_raw(raw)?),
+            "sendEmailAddressVerificationCode" => {
+                TdFunction::SendEmailAddressVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "sendInlineQueryResultMessage" => {
+                TdFunction::SendInlineQueryResultMessage(super::_common::from_raw(raw)?)
+            }
+            "sendMessage" => TdFunction::SendMessage(super::_common::from_raw(raw)?),
+            "sendMessageAlbum" => TdFunction::SendMessageAlbum(super::_common::from_raw(raw)?),
+            "sendPassportAuthorizationForm" => {
+                TdFunction::SendPassportAuthorizationForm(super::_common::from_raw(raw)?)
+            }
+            "sendPaymentForm" => TdFunction::SendPaymentForm(super::_common::from_raw(raw)?),
+            "sendPhoneNumberConfirmationCode" => {
+                TdFunction::SendPhoneNumberConfirmationCode(super::_common::from_raw(raw)?)
+            }
+            "sendPhoneNumberVerificationCode" => {
+                TdFunction::SendPhoneNumberVerificationCode(super::_common::from_raw(raw)?)
+            }
+            "setAccountTtl" => TdFunction::SetAccountTtl(super::_common::from_raw(raw)?),
+            "setAlarm" => TdFunction::SetAlarm(super::_common::from_raw(raw)?),
+            "setAuthenticationPhoneNumber" => {
+                TdFunction::SetAuthenticationPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "setAutoDownloadSettings" => {
+                TdFunction::SetAutoDownloadSettings(super::_common::from_raw(raw)?)
+            }
+            "setBackground" => TdFunction::SetBackground(super::_common::from_raw(raw)?),
+            "setBio" => TdFunction::SetBio(super::_common::from_raw(raw)?),
+            "setBotUpdatesStatus" => {
+                TdFunction::SetBotUpdatesStatus(super::_common::from_raw(raw)?)
+            }
+            "setChatClientData" => TdFunction::SetChatClientData(super::_common::from_raw(raw)?),
+            "setChatDescription" => TdFunction::SetChatDescription(super::_common::from_raw(raw)?),
+            "setChatDiscussionGroup" => {
+                TdFunction::SetChatDiscussionGroup(super::_common::from_raw(raw)?)
+            }
+            "setChatDraftMessage" => {
+                TdFunction::SetChatDraftMessage(super::_common::from_raw(raw)?)
+            }
+            "setChatLocation" => TdFunction::SetChatLocation(super::_common::from_raw(raw)?),
+            "setChatMemberStatus" => {
+                TdFunction::SetChatMemberStatus(super::_common::from_raw(raw)?)
+            }
+            "setChatMessageSender" => {
+                TdFunction::SetChatMessageSender(super::_common::from_raw(raw)?)
+            }
+            "setChatMessageTtl" => TdFunction::SetChatMessageTtl(super::_common::from_raw(raw)?),
+            "setChatNotificationSettings" => {
+                TdFunction::SetChatNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "setChatPermissions" => TdFunction::SetChatPermissions(super::_common::from_raw(raw)?),
+            "setChatPhoto" => TdFunction::SetChatPhoto(super::_common::from_raw(raw)?),
+            "setChatSlowModeDelay" => {
+                TdFunction::SetChatSlowModeDelay(super::_common::from_raw(raw)?)
+            }
+            "setChatTheme" => TdFunction::SetChatTheme(super::_common::from_raw(raw)?),
+            "setChatTitle" => TdFunction::SetChatTitle(super::_common::from_raw(raw)?),
+            "setCommands" => TdFunction::SetCommands(super::_common::from_raw(raw)?),
+            "setCustomLanguagePack" => {
+                TdFunction::SetCustomLanguagePack(super::_common::from_raw(raw)?)
+            }
+            "setCustomLanguagePackString" => {
+                TdFunction::SetCustomLanguagePackString(super::_common::from_raw(raw)?)
+            }
+            "setDatabaseEncryptionKey" => {
+                TdFunction::SetDatabaseEncryptionKey(super::_common::from_raw(raw)?)
+            }
+            "setFileGenerationProgress" => {
+                TdFunction::SetFileGenerationProgress(super::_common::from_raw(raw)?)
+            }
+            "setGameScore" => TdFunction::SetGameScore(super::_common::from_raw(raw)?),
+            "setGroupCallParticipantIsSpeaking" => {
+                TdFunction::SetGroupCallParticipantIsSpeaking(super::_common::from_raw(raw)?)
+            }
+            "setGroupCallParticipantVolumeLevel" => {
+                TdFunction::SetGroupCallParticipantVolumeLevel(super::_common::from_raw(raw)?)
+            }
+            "setGroupCallTitle" => TdFunction::SetGroupCallTitle(super::_common::from_raw(raw)?),
+            "setInactiveSessionTtl" => {
+                TdFunction::SetInactiveSessionTtl(super::_common::from_raw(raw)?)
+            }
+            "setInlineGameScore" => TdFunction::SetInlineGameScore(super::_common::from_raw(raw)?),
+            "setLocation" => TdFunction::SetLocation(super::_common::from_raw(raw)?),
+            "setLogStream" => TdFunction::SetLogStream(super::_common::from_raw(raw)?),
+            "setLogTagVerbosityLevel" => {
+                TdFunction::SetLogTagVerbosityLevel(super::_common::from_raw(raw)?)
+            }
+            "setLogVerbosityLevel" => {
+                TdFunction::SetLogVerbosityLevel(super::_common::from_raw(raw)?)
+            }
+            "setName" => TdFunction::SetName(super::_common::from_raw(raw)?),
+            "setNetworkType" => TdFunction::SetNetworkType(super::_common::from_raw(raw)?),
+            "setOption" => TdFunction::SetOption(super::_common::from_raw(raw)?),
+            "setPassportElement" => TdFunction::SetPassportElement(super::_common::from_raw(raw)?),
+            "setPassportElementErrors" => {
+                TdFunction::SetPassportElementErrors(super::_common::from_raw(raw)?)
+            }
+            "setPassword" => TdFunction::SetPassword(super::_common::from_raw(raw)?),
+            "setPinnedChats" => TdFunction::SetPinnedChats(super::_common::from_raw(raw)?),
+            "setPollAnswer" => TdFunction::SetPollAnswer(super::_common::from_raw(raw)?),
+            "setProfilePhoto" => TdFunction::SetProfilePhoto(super::_common::from_raw(raw)?),
+            "setRecoveryEmailAddress" => {
+                TdFunction::SetRecoveryEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "setScopeNotificationSettings" => {
+                TdFunction::SetScopeNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "setStickerPositionInSet" => {
+                TdFunction::SetStickerPositionInSet(super::_common::from_raw(raw)?)
+            }
+            "setStickerSetThumbnail" => {
+                TdFunction::SetStickerSetThumbnail(super::_common::from_raw(raw)?)
+            }
+            "setSupergroupStickerSet" => {
+                TdFunction::SetSupergroupStickerSet(super::_common::from_raw(raw)?)
+            }
+            "setSupergroupUsername" => {
+                TdFunction::SetSupergroupUsername(super::_common::from_raw(raw)?)
+            }
+            "setTdlibParameters" => TdFunction::SetTdlibParameters(super::_common::from_raw(raw)?),
+            "setUserPrivacySettingRules" => {
+                TdFunction::SetUserPrivacySettingRules(super::_common::from_raw(raw)?)
+            }
+            "setUsername" => TdFunction::SetUsername(super::_common::from_raw(raw)?),
+            "setVideoChatDefaultParticipant" => {
+                TdFunction::SetVideoChatDefaultParticipant(super::_common::from_raw(raw)?)
+            }
+            "sharePhoneNumber" => TdFunction::SharePhoneNumber(super::_common::from_raw(raw)?),
+            "startGroupCallRecording" => {
+                TdFunction::StartGroupCallRecording(super::_common::from_raw(raw)?)
+            }
+            "startGroupCallScreenSharing" => {
+                TdFunction::StartGroupCallScreenSharing(super::_common::from_raw(raw)?)
+            }
+            "startScheduledGroupCall" => {
+                TdFunction::StartScheduledGroupCall(super::_common::from_raw(raw)?)
+            }
+            "stopPoll" => TdFunction::StopPoll(super::_common::from_raw(raw)?),
+            "synchronizeLanguagePack" => {
+                TdFunction::SynchronizeLanguagePack(super::_common::from_raw(raw)?)
+            }
+            "terminateAllOtherSessions" => {
+                TdFunction::TerminateAllOtherSessions(super::_common::from_raw(raw)?)
+            }
+            "terminateSession" => TdFunction::TerminateSession(super::_common::from_raw(raw)?),
+            "testCallBytes" => TdFunction::TestCallBytes(super::_common::from_raw(raw)?),
+            "testCallEmpty" => TdFunction::TestCallEmpty(super::_common::from_raw(raw)?),
+            "testCallString" => TdFunction::TestCallString(super::_common::from_raw(raw)?),
+            "testCallVectorInt" => TdFunction::TestCallVectorInt(super::_common::from_raw(raw)?),
+            "testCallVectorIntObject" => {
+                TdFunction::TestCallVectorIntObject(super::_common::from_raw(raw)?)
+            }
+            "testCallVectorString" => {
+                TdFunction::TestCallVectorString(super::_common::from_raw(raw)?)
+            }
+            "testCallVectorStringObject" => {
+                TdFunction::TestCallVectorStringObject(super::_common::from_raw(raw)?)
+            }
+            "testGetDifference" => TdFunction::TestGetDifference(super::_common::from_raw(raw)?),
+            "testNetwork" => TdFunction::TestNetwork(super::_common::from_raw(raw)?),
+            "testProxy" => TdFunction::TestProxy(super::_common::from_raw(raw)?),
+            "testReturnError" => TdFunction::TestReturnError(super::_common::from_raw(raw)?),
+            "testSquareInt" => TdFunction::TestSquareInt(super::_common::from_raw(raw)?),
+            "testUseUpdate" => TdFunction::TestUseUpdate(super::_common::from_raw(raw)?),
+            "toggleChatDefaultDisableNotification" => {
+                TdFunction::ToggleChatDefaultDisableNotification(super::_common::from_raw(raw)?)
+            }
+            "toggleChatHasProtectedContent" => {
+                TdFunction::ToggleChatHasProtectedContent(super::_common::from_raw(raw)?)
+            }
+            "toggleChatIsMarkedAsUnread" => {
+                TdFunction::ToggleChatIsMarkedAsUnread(super::_common::from_raw(raw)?)
+            }
+            "toggleChatIsPinned" => TdFunction::ToggleChatIsPinned(super::_common::from_raw(raw)?),
+            "toggleGroupCallEnabledStartNotification" => {
+                TdFunction::ToggleGroupCallEnabledStartNotification(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallIsMyVideoEnabled" => {
+                TdFunction::ToggleGroupCallIsMyVideoEnabled(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallIsMyVideoPaused" => {
+                TdFunction::ToggleGroupCallIsMyVideoPaused(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallMuteNewParticipants" => {
+                TdFunction::ToggleGroupCallMuteNewParticipants(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallParticipantIsHandRaised" => {
+                TdFunction::ToggleGroupCallParticipantIsHandRaised(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallParticipantIsMuted" => {
+                TdFunction::ToggleGroupCallParticipantIsMuted(super::_common::from_raw(raw)?)
+            }
+            "toggleGroupCallScreenSharingIsPaused" => {
+                TdFunction::ToggleGroupCallScreenSharingIsPaused(super::_common::from_raw(raw)?)
+            }
+            "toggleMessageSenderIsBlocked" => {
+                TdFunction::ToggleMessageSenderIsBlocked(super::_common::from_raw(raw)?)
+            }
+            "toggleSessionCanAcceptCalls" => {
+                TdFunction::ToggleSessionCanAcceptCalls(super::_common::from_raw(raw)?)
+            }
+            "toggleSessionCanAcceptSecretChats" => {
+                TdFunction::ToggleSessionCanAcceptSecretChats(super::_common::from_raw(raw)?)
+            }
+            "toggleSupergroupIsAllHistoryAvailable" => {
+                TdFunction::ToggleSupergroupIsAllHistoryAvailable(super::_common::from_raw(raw)?)
+            }
+            "toggleSupergroupIsBroadcastGroup" => {
+                TdFunction::ToggleSupergroupIsBroadcastGroup(super::_common::from_raw(raw)?)
+            }
+            "toggleSupergroupSignMessages" => {
+                TdFunction::ToggleSupergroupSignMessages(super::_common::from_raw(raw)?)
+            }
+            "transferChatOwnership" => {
+                TdFunction::TransferChatOwnership(super::_common::from_raw(raw)?)
+            }
+            "unpinAllChatMessages" => {
+                TdFunction::UnpinAllChatMessages(super::_common::from_raw(raw)?)
+            }
+            "unpinChatMessage" => TdFunction::UnpinChatMessage(super::_common::from_raw(raw)?),
+            "upgradeBasicGroupChatToSupergroupChat" => {
+                TdFunction::UpgradeBasicGroupChatToSupergroupChat(super::_common::from_raw(raw)?)
+            }
+            "uploadFile" => TdFunction::UploadFile(super::_common::from_raw(raw)?),
+            "uploadStickerFile" => TdFunction::UploadStickerFile(super::_common::from_raw(raw)?),
+            "validateOrderInfo" => TdFunction::ValidateOrderInfo(super::_common::from_raw(raw)?),
+            "viewMessages" => TdFunction::ViewMessages(super::_common::from_raw(raw)?),
+            "viewTrendingStickerSets" => {
+                TdFunction::ViewTrendingStickerSets(super::_common::from_raw(raw)?)
+            }
+            "writeGeneratedFilePart" => {
+                TdFunction::WriteGeneratedFilePart(super::_common::from_raw(raw)?)
+            }
+            _ => TdFunction::Unknown { type_name, raw },
+        })
+    }
+}
@@ -0,0 +1,8043 @@
+use crate::errors::Result;
+use crate::types::*;
+
+/// Any TDLib object, tagged by its `@type`
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "@type")]
+pub enum TdObject {
+    /// Contains information about the period of inactivity after which the current user's account will automatically be deleted
+    #[serde(rename = "accountTtl")]
+    AccountTtl(Box<AccountTtl>),
+    /// Describes an address
+    #[serde(rename = "address")]
+    Address(Box<Address>),
+    /// Animated variant of a chat photo in MPEG4 format
+    #[serde(rename = "animatedChatPhoto")]
+    AnimatedChatPhoto(Box<AnimatedChatPhoto>),
+    /// Describes an animated representation of an emoji
+    #[serde(rename = "animatedEmoji")]
+    AnimatedEmoji(Box<AnimatedEmoji>),
+    /// Describes an animation file. The animation must be encoded in GIF or MPEG4 format
+    #[serde(rename = "animation")]
+    Animation(Box<Animation>),
+    /// Represents a list of animations
+    #[serde(rename = "animations")]
+    Animations(Box<Animations>),
+    /// Describes an audio file. Audio is usually in MP3 or M4A format
+    #[serde(rename = "audio")]
+    Audio(Box<Audio>),
+    /// Information about the authentication code that was sent
+    #[serde(rename = "authenticationCodeInfo")]
+    AuthenticationCodeInfo(Box<AuthenticationCodeInfo>),
+    /// An authentication code is delivered via a phone call to the specified phone number
+    #[serde(rename = "authenticationCodeTypeCall")]
+    AuthenticationCodeTypeCall(Box<AuthenticationCodeTypeCall>),
+    /// An authentication code is delivered by an immediately canceled call to the specified phone number. The phone number that calls is the code that must be entered automatically
+    #[serde(rename = "authenticationCodeTypeFlashCall")]
+    AuthenticationCodeTypeFlashCall(Box<AuthenticationCodeTypeFlashCall>),
+    /// An authentication code is delivered by an immediately canceled call to the specified phone number. The last digits of the phone number that calls are the code that must be entered manually by the user
+    #[serde(rename = "authenticationCodeTypeMissedCall")]
+    AuthenticationCodeTypeMissedCall(Box<AuthenticationCodeTypeMissedCall>),
+    /// An authentication code is delivered via an SMS message to the specified phone number
+    #[serde(rename = "authenticationCodeTypeSms")]
+    AuthenticationCodeTypeSms(Box<AuthenticationCodeTypeSms>),
+    /// An authentication code is delivered via a private Telegram message, which can be viewed from another active session
+    #[serde(rename = "authenticationCodeTypeTelegramMessage")]
+    AuthenticationCodeTypeTelegramMessage(Box<AuthenticationCodeTypeTelegramMessage>),
+    /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one must create a new instance of the TDLib client
+    #[serde(rename = "authorizationStateClosed")]
+    AuthorizationStateClosed(Box<AuthorizationStateClosed>),
+    /// TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received
+    #[serde(rename = "authorizationStateClosing")]
+    AuthorizationStateClosing(Box<AuthorizationStateClosing>),
+    /// The user is currently logging out
+    #[serde(rename = "authorizationStateLoggingOut")]
+    AuthorizationStateLoggingOut(Box<AuthorizationStateLoggingOut>),
+    /// The user has been successfully authorized. TDLib is now ready to answer queries
+    #[serde(rename = "authorizationStateReady")]
+    AuthorizationStateReady(Box<AuthorizationStateReady>),
+    /// TDLib needs the user's authentication code to authorize
+    #[serde(rename = "authorizationStateWaitCode")]
+    AuthorizationStateWaitCode(Box<AuthorizationStateWaitCode>),
+    /// TDLib needs an encryption key to decrypt the local database
+    #[serde(rename = "authorizationStateWaitEncryptionKey")]
+    AuthorizationStateWaitEncryptionKey(Box<AuthorizationStateWaitEncryptionKey>),
+    /// The user needs to confirm authorization on another logged in device by scanning a QR code with the provided link
+    #[serde(rename = "authorizationStateWaitOtherDeviceConfirmation")]
+    AuthorizationStateWaitOtherDeviceConfirmation(
+        Box<AuthorizationStateWaitOtherDeviceConfirmation>,
+    ),
+    /// The user has been authorized, but needs to enter a password to start using the application
+    #[serde(rename = "authorizationStateWaitPassword")]
+    AuthorizationStateWaitPassword(Box<AuthorizationStateWaitPassword>),
+    /// TDLib needs the user's phone number to authorize. Call `setAuthenticationPhoneNumber` to provide the phone number, or use `requestQrCodeAuthentication`, or `checkAuthenticationBotToken` for other authentication options
+    #[serde(rename = "authorizationStateWaitPhoneNumber")]
+    AuthorizationStateWaitPhoneNumber(Box<AuthorizationStateWaitPhoneNumber>),
+    /// The user is unregistered and need to accept terms of service and enter their first name and last name to finish registration
+    #[serde(rename = "authorizationStateWaitRegistration")]
+    AuthorizationStateWaitRegistration(Box<AuthorizationStateWaitRegistration>),
+    /// TDLib needs TdlibParameters for initialization
+    #[serde(rename = "authorizationStateWaitTdlibParameters")]
+    AuthorizationStateWaitTdlibParameters(Box<AuthorizationStateWaitTdlibParameters>),
+    /// Contains auto-download settings
+    #[serde(rename = "autoDownloadSettings")]
+    AutoDownloadSettings(Box<AutoDownloadSettings>),
+    /// Contains auto-download settings presets for the current user
+    #[serde(rename = "autoDownloadSettingsPresets")]
+    AutoDownloadSettingsPresets(Box<AutoDownloadSettingsPresets>),
+    /// Describes a chat background
+    #[serde(rename = "background")]
+    Background(Box<Background>),
+    /// Describes a freeform gradient fill of a background
+    #[serde(rename = "backgroundFillFreeformGradient")]
+    BackgroundFillFreeformGradient(Box<BackgroundFillFreeformGradient>),
+    /// Describes a gradient fill of a background
+    #[serde(rename = "backgroundFillGradient")]
+    BackgroundFillGradient(Box<BackgroundFillGradient>),
+    /// Describes a solid fill of a background
+    #[serde(rename = "backgroundFillSolid")]
+    BackgroundFillSolid(Box<BackgroundFillSolid>),
+    /// A filled background
+    #[serde(rename = "backgroundTypeFill")]
+    BackgroundTypeFill(Box<BackgroundTypeFill>),
+    /// A PNG or TGV (gzipped subset of SVG with MIME type "application/x-tgwallpattern") pattern to be combined with the background fill chosen by the user
+    #[serde(rename = "backgroundTypePattern")]
+    BackgroundTypePattern(Box<BackgroundTypePattern>),
+    /// A wallpaper in JPEG format
+    #[serde(rename = "backgroundTypeWallpaper")]
+    BackgroundTypeWallpaper(Box<BackgroundTypeWallpaper>),
+    /// Contains a list of backgrounds
+    #[serde(rename = "backgrounds")]
+    Backgrounds(Box<Backgrounds>),
+    /// Describes an action associated with a bank card number
+    #[serde(rename = "bankCardActionOpenUrl")]
+    BankCardActionOpenUrl(Box<BankCardActionOpenUrl>),
+    /// Information about a bank card
+    #[serde(rename = "bankCardInfo")]
+    BankCardInfo(Box<BankCardInfo>),
+    /// Represents a basic group of 0-200 users (must be upgraded to a supergroup to accommodate more than 200 users)
+    #[serde(rename = "basicGroup")]
+    BasicGroup(Box<BasicGroup>),
+    /// Contains full information about a basic group
+    #[serde(rename = "basicGroupFullInfo")]
+    BasicGroupFullInfo(Box<BasicGroupFullInfo>),
+    /// Represents a command supported by a bot
+    #[serde(rename = "botCommand")]
+    BotCommand(Box<BotCommand>),
+    /// A scope covering all group and supergroup chat administrators
+    #[serde(rename = "botCommandScopeAllChatAdministrators")]
+    BotCommandScopeAllChatAdministrators(Box<BotCommandScopeAllChatAdministrators>),
+    /// A scope covering all group and supergroup chats
+    #[serde(rename = "botCommandScopeAllGroupChats")]
+    BotCommandScopeAllGroupChats(Box<BotCommandScopeAllGroupChats>),
+    /// A scope covering all private chats
+    #[serde(rename = "botCommandScopeAllPrivateChats")]
+    BotCommandScopeAllPrivateChats(Box<BotCommandScopeAllPrivateChats>),
+    /// A scope covering all members of a chat
+    #[serde(rename = "botCommandScopeChat")]
+    BotCommandScopeChat(Box<BotCommandScopeChat>),
+    /// A scope covering all administrators of a chat
+    #[serde(rename = "botCommandScopeChatAdministrators")]
+    BotCommandScopeChatAdministrators(Box<BotCommandScopeChatAdministrators>),
+    /// A scope covering a member of a chat
+    #[serde(rename = "botCommandScopeChatMember")]
+    BotCommandScopeChatMember(Box<BotCommandScopeChatMember>),
+    /// A scope covering all users
+    #[serde(rename = "botCommandScopeDefault")]
+    BotCommandScopeDefault(Box<BotCommandScopeDefault>),
+    /// Contains a list of bot commands
+    #[serde(rename = "botCommands")]
+    BotCommands(Box<BotCommands>),
+    /// Describes a call
+    #[serde(rename = "call")]
+    Call(Box<Call>),
+    /// The call was ended before the conversation started. It was declined by the other party
+    #[serde(rename = "callDiscardReasonDeclined")]
+    CallDiscardReasonDeclined(Box<CallDiscardReasonDeclined>),
+    /// The call was ended during the conversation because the users were disconnected
+    #[serde(rename = "callDiscardReasonDisconnected")]
+    CallDiscardReasonDisconnected(Box<CallDiscardReasonDisconnected>),
+    /// The call wasn't discarded, or the reason is unknown
+    #[serde(rename = "callDiscardReasonEmpty")]
+    CallDiscardReasonEmpty(Box<CallDiscardReasonEmpty>),
+    /// The call was ended because one of the parties hung up
+    #[serde(rename = "callDiscardReasonHungUp")]
+    CallDiscardReasonHungUp(Box<CallDiscardReasonHungUp>),
+    /// The call was ended before the conversation started. It was canceled by the caller or missed by the other party
+    #[serde(rename = "callDiscardReasonMissed")]
+    CallDiscardReasonMissed(Box<CallDiscardReasonMissed>),
+    /// Contains the call identifier
+    #[serde(rename = "callId")]
+    CallId(Box<CallId>),
+    /// The speech was distorted
+    #[serde(rename = "callProblemDistortedSpeech")]
+    CallProblemDistortedSpeech(Box<CallProblemDistortedSpeech>),
+    /// The video was distorted
+    #[serde(rename = "callProblemDistortedVideo")]
+    CallProblemDistortedVideo(Box<CallProblemDistortedVideo>),
+    /// The call ended unexpectedly
+    #[serde(rename = "callProblemDropped")]
+    CallProblemDropped(Box<CallProblemDropped>),
+    /// The user heard their own voice
+    #[serde(rename = "callProblemEcho")]
+    CallProblemEcho(Box<CallProblemEcho>),
+    /// The other side kept disappearing
+    #[serde(rename = "callProblemInterruptions")]
+    CallProblemInterruptions(Box<CallProblemInterruptions>),
+    /// The user heard background noise
+    #[serde(rename = "callProblemNoise")]
+    CallProblemNoise(Box<CallProblemNoise>),
+    /// The video was pixelated
+    #[serde(rename = "callProblemPixelatedVideo")]
+    CallProblemPixelatedVideo(Box<CallProblemPixelatedVideo>),
+    /// The user couldn't hear the other side
+    #[serde(rename = "callProblemSilentLocal")]
+    CallProblemSilentLocal(Box<CallProblemSilentLocal>),
+    /// The other side couldn't hear the user
+    #[serde(rename = "callProblemSilentRemote")]
+    CallProblemSilentRemote(Box<CallProblemSilentRemote>),
+    /// Specifies the supported call protocols
+    #[serde(rename = "callProtocol")]
+    CallProtocol(Box<CallProtocol>),
+    /// Describes a server for relaying call data
+    #[serde(rename = "callServer")]
+    CallServer(Box<CallServer>),
+    /// A Telegram call reflector
+    #[serde(rename = "callServerTypeTelegramReflector")]
+    CallServerTypeTelegramReflector(Box<CallServerTypeTelegramReflector>),
+    /// A WebRTC server
+    #[serde(rename = "callServerTypeWebrtc")]
+    CallServerTypeWebrtc(Box<CallServerTypeWebrtc>),
+    /// The call has ended successfully
+    #[serde(rename = "callStateDiscarded")]
+    CallStateDiscarded(Box<CallStateDiscarded>),
+    /// The call has ended with an error
+    #[serde(rename = "callStateError")]
+    CallStateError(Box<CallStateError>),
+    /// The call has been answered and encryption keys are being exchanged
+    #[serde(rename = "callStateExchangingKeys")]
+    CallStateExchangingKeys(Box<CallStateExchangingKeys>),
+    /// The call is hanging up after discardCall has been called
+    #[serde(rename = "callStateHangingUp")]
+    CallStateHangingUp(Box<CallStateHangingUp>),
+    /// The call is pending, waiting to be accepted by a user
+    #[serde(rename = "callStatePending")]
+    CallStatePending(Box<CallStatePending>),
+    /// The call is ready to use
+    #[serde(rename = "callStateReady")]
+    CallStateReady(Box<CallStateReady>),
+    /// Contains a bot's answer to a callback query
+    #[serde(rename = "callbackQueryAnswer")]
+    CallbackQueryAnswer(Box<CallbackQueryAnswer>),
+    /// The payload for a general callback button
+    #[serde(rename = "callbackQueryPayloadData")]
+    CallbackQueryPayloadData(Box<CallbackQueryPayloadData>),
+    /// The payload for a callback button requiring password
+    #[serde(rename = "callbackQueryPayloadDataWithPassword")]
+    CallbackQueryPayloadDataWithPassword(Box<CallbackQueryPayloadDataWithPassword>),
+    /// The payload for a game callback button
+    #[serde(rename = "callbackQueryPayloadGame")]
+    CallbackQueryPayloadGame(Box<CallbackQueryPayloadGame>),
+    /// The session can be used
+    #[serde(rename = "canTransferOwnershipResultOk")]
+    CanTransferOwnershipResultOk(Box<CanTransferOwnershipResultOk>),
+    /// The 2-step verification needs to be enabled first
+    #[serde(rename = "canTransferOwnershipResultPasswordNeeded")]
+    CanTransferOwnershipResultPasswordNeeded(Box<CanTransferOwnershipResultPasswordNeeded>),
+    /// The 2-step verification was enabled recently, user needs to wait
+    #[serde(rename = "canTransferOwnershipResultPasswordTooFresh")]
+    CanTransferOwnershipResultPasswordTooFresh(Box<CanTransferOwnershipResultPasswordTooFresh>),
+    /// The session was created recently, user needs to wait
+    #[serde(rename = "canTransferOwnershipResultSessionTooFresh")]
+    CanTransferOwnershipResultSessionTooFresh(Box<CanTransferOwnershipResultSessionTooFresh>),
+    /// A chat. (Can be a private chat, basic group, supergroup, or secret chat)
+    #[serde(rename = "chat")]
+    Chat(Box<Chat>),
+    /// The chat is a private or secret chat and the other user can be added to the contact list using the method addContact
+    #[serde(rename = "chatActionBarAddContact")]
+    ChatActionBarAddContact(Box<ChatActionBarAddContact>),
+    /// The chat is a recently created group chat to which new members can be invited
+    #[serde(rename = "chatActionBarInviteMembers")]
+    ChatActionBarInviteMembers(Box<ChatActionBarInviteMembers>),
+    /// The chat is a private chat with an administrator of a chat to which the user sent join request
+    #[serde(rename = "chatActionBarJoinRequest")]
+    ChatActionBarJoinRequest(Box<ChatActionBarJoinRequest>),
+    /// The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be blocked using the method toggleMessageSenderIsBlocked, or the other user can be added to the contact list using the method addContact
+    #[serde(rename = "chatActionBarReportAddBlock")]
+    ChatActionBarReportAddBlock(Box<ChatActionBarReportAddBlock>),
+    /// The chat can be reported as spam using the method reportChat with the reason chatReportReasonSpam
+    #[serde(rename = "chatActionBarReportSpam")]
+    ChatActionBarReportSpam(Box<ChatActionBarReportSpam>),
+    /// The chat is a location-based supergroup, which can be reported as having unrelated location using the method reportChat with the reason chatReportReasonUnrelatedLocation
+    #[serde(rename = "chatActionBarReportUnrelatedLocation")]
+    ChatActionBarReportUnrelatedLocation(Box<ChatActionBarReportUnrelatedLocation>),
+    /// The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber
+    #[serde(rename = "chatActionBarSharePhoneNumber")]
+    ChatActionBarSharePhoneNumber(Box<ChatActionBarSharePhoneNumber>),
+    /// The user has canceled the previous action
+    #[serde(rename = "chatActionCancel")]
+    ChatActionCancel(Box<ChatActionCancel>),
+    /// The user is picking a contact to send
+    #[serde(rename = "chatActionChoosingContact")]
+    ChatActionChoosingContact(Box<ChatActionChoosingContact>),
+    /// The user is picking a location or venue to send
+    #[serde(rename = "chatActionChoosingLocation")]
+    ChatActionChoosingLocation(Box<ChatActionChoosingLocation>),
+    /// The user is picking a sticker to send
+    #[serde(rename = "chatActionChoosingSticker")]
+    ChatActionChoosingSticker(Box<ChatActionChoosingSticker>),
+    /// The user is recording a video
+    #[serde(rename = "chatActionRecordingVideo")]
+    ChatActionRecordingVideo(Box<ChatActionRecordingVideo>),
+    /// The user is recording a video note
+    #[serde(rename = "chatActionRecordingVideoNote")]
+    ChatActionRecordingVideoNote(Box<ChatActionRecordingVideoNote>),
+    /// The user is recording a voice note
+    #[serde(rename = "chatActionRecordingVoiceNote")]
+    ChatActionRecordingVoiceNote(Box<ChatActionRecordingVoiceNote>),
+    /// The user has started to play a game
+    #[serde(rename = "chatActionStartPlayingGame")]
+    ChatActionStartPlayingGame(Box<ChatActionStartPlayingGame>),
+    /// The user is typing a message
+    #[serde(rename = "chatActionTyping")]
+    ChatActionTyping(Box<ChatActionTyping>),
+    /// The user is uploading a document
+    #[serde(rename = "chatActionUploadingDocument")]
+    ChatActionUploadingDocument(Box<ChatActionUploadingDocument>),
+    /// The user is uploading a photo
+    #[serde(rename = "chatActionUploadingPhoto")]
+    ChatActionUploadingPhoto(Box<ChatActionUploadingPhoto>),
+    /// The user is uploading a video
+    #[serde(rename = "chatActionUploadingVideo")]
+    ChatActionUploadingVideo(Box<ChatActionUploadingVideo>),
+    /// The user is uploading a video note
+    #[serde(rename = "chatActionUploadingVideoNote")]
+    ChatActionUploadingVideoNote(Box<ChatActionUploadingVideoNote>),
+    /// The user is uploading a voice note
+    #[serde(rename = "chatActionUploadingVoiceNote")]
+    ChatActionUploadingVoiceNote(Box<ChatActionUploadingVoiceNote>),
+    /// The user is watching animations sent by the other party by clicking on an animated emoji
+    #[serde(rename = "chatActionWatchingAnimations")]
+    ChatActionWatchingAnimations(Box<ChatActionWatchingAnimations>),
+    /// Contains information about a chat administrator
+    #[serde(rename = "chatAdministrator")]
+    ChatAdministrator(Box<ChatAdministrator>),
+    /// Represents a list of chat administrators
+    #[serde(rename = "chatAdministrators")]
+    ChatAdministrators(Box<ChatAdministrators>),
+    /// Represents a chat event
+    #[serde(rename = "chatEvent")]
+    ChatEvent(Box<ChatEvent>),
+    /// The chat description was changed
+    #[serde(rename = "chatEventDescriptionChanged")]
+    ChatEventDescriptionChanged(Box<ChatEventDescriptionChanged>),
+    /// The has_protected_content setting of a channel was toggled
+    #[serde(rename = "chatEventHasProtectedContentToggled")]
+    ChatEventHasProtectedContentToggled(Box<ChatEventHasProtectedContentToggled>),
+    /// A revoked chat invite link was deleted
+    #[serde(rename = "chatEventInviteLinkDeleted")]
+    ChatEventInviteLinkDeleted(Box<ChatEventInviteLinkDeleted>),
+    /// A chat invite link was edited
+    #[serde(rename = "chatEventInviteLinkEdited")]
+    ChatEventInviteLinkEdited(Box<ChatEventInviteLinkEdited>),
+    /// A chat invite link was revoked
+    #[serde(rename = "chatEventInviteLinkRevoked")]
+    ChatEventInviteLinkRevoked(Box<ChatEventInviteLinkRevoked>),
+    /// The can_invite_users permission of a supergroup chat was toggled
+    #[serde(rename = "chatEventInvitesToggled")]
+    ChatEventInvitesToggled(Box<ChatEventInvitesToggled>),
+    /// The is_all_history_available setting of a supergroup was toggled
+    #[serde(rename = "chatEventIsAllHistoryAvailableToggled")]
+    ChatEventIsAllHistoryAvailableToggled(Box<ChatEventIsAllHistoryAvailableToggled>),
+    /// The linked chat of a supergroup was changed
+    #[serde(rename = "chatEventLinkedChatChanged")]
+    ChatEventLinkedChatChanged(Box<ChatEventLinkedChatChanged>),
+    /// The supergroup location was changed
+    #[serde(rename = "chatEventLocationChanged")]
+    ChatEventLocationChanged(Box<ChatEventLocationChanged>),
+    /// Represents a set of filters used to obtain a chat event log
+    #[serde(rename = "chatEventLogFilters")]
+    ChatEventLogFilters(Box<ChatEventLogFilters>),
+    /// A new chat member was invited
+    #[serde(rename = "chatEventMemberInvited")]
+    ChatEventMemberInvited(Box<ChatEventMemberInvited>),
+    /// A new member joined the chat
+    #[serde(rename = "chatEventMemberJoined")]
+    ChatEventMemberJoined(Box<ChatEventMemberJoined>),
+    /// A new member joined the chat via an invite link
+    #[serde(rename = "chatEventMemberJoinedByInviteLink")]
+    ChatEventMemberJoinedByInviteLink(Box<ChatEventMemberJoinedByInviteLink>),
+    /// A new member was accepted to the chat by an administrator
+    #[serde(rename = "chatEventMemberJoinedByRequest")]
+    ChatEventMemberJoinedByRequest(Box<ChatEventMemberJoinedByRequest>),
+    /// A member left the chat
+    #[serde(rename = "chatEventMemberLeft")]
+    ChatEventMemberLeft(Box<ChatEventMemberLeft>),
+    /// A chat member has gained/lost administrator status, or the list of their administrator privileges has changed
+    #[serde(rename = "chatEventMemberPromoted")]
+    ChatEventMemberPromoted(Box<ChatEventMemberPromoted>),
+    /// A chat member was restricted/unrestricted or banned/unbanned, or the list of their restrictions has changed
+    #[serde(rename = "chatEventMemberRestricted")]
+    ChatEventMemberRestricted(Box<ChatEventMemberRestricted>),
+    /// A message was deleted
+    #[serde(rename = "chatEventMessageDeleted")]
+    ChatEventMessageDeleted(Box<ChatEventMessageDeleted>),
+    /// A message was edited
+    #[serde(rename = "chatEventMessageEdited")]
+    ChatEventMessageEdited(Box<ChatEventMessageEdited>),
+    /// A message was pinned
+    #[serde(rename = "chatEventMessagePinned")]
+    ChatEventMessagePinned(Box<ChatEventMessagePinned>),
+    /// The message TTL was changed
+    #[serde(rename = "chatEventMessageTtlChanged")]
+    ChatEventMessageTtlChanged(Box<ChatEventMessageTtlChanged>),
+    /// A message was unpinned
+    #[serde(rename = "chatEventMessageUnpinned")]
+    ChatEventMessageUnpinned(Box<ChatEventMessageUnpinned>),
+    /// The chat permissions was changed
+    #[serde(rename = "chatEventPermissionsChanged")]
+    ChatEventPermissionsChanged(Box<ChatEventPermissionsChanged>),
+    /// The chat photo was changed
+    #[serde(rename = "chatEventPhotoChanged")]
+    ChatEventPhotoChanged(Box<ChatEventPhotoChanged>),
+    /// A poll in a message was stopped
+    #[serde(rename = "chatEventPollStopped")]
+    ChatEventPollStopped(Box<ChatEventPollStopped>),
+    /// The sign_messages setting of a channel was toggled
+    #[serde(rename = "chatEventSignMessagesToggled")]
+    ChatEventSignMessagesToggled(Box<ChatEventSignMessagesToggled>),
+    /// The slow_mode_delay setting of a supergroup was changed
+    #[serde(rename = "chatEventSlowModeDelayChanged")]
+    ChatEventSlowModeDelayChanged(Box<ChatEventSlowModeDelayChanged>),
+    /// The supergroup sticker set was changed
+    #[serde(rename = "chatEventStickerSetChanged")]
+    ChatEventStickerSetChanged(Box<ChatEventStickerSetChanged>),
+    /// The chat title was changed
+    #[serde(rename = "chatEventTitleChanged")]
+    ChatEventTitleChanged(Box<ChatEventTitleChanged>),
+    /// The chat username was changed
+    #[serde(rename = "chatEventUsernameChanged")]
+    ChatEventUsernameChanged(Box<ChatEventUsernameChanged>),
+    /// A video chat was created
+    #[serde(rename = "chatEventVideoChatCreated")]
+    ChatEventVideoChatCreated(Box<ChatEventVideoChatCreated>),
+    /// A video chat was ended
+    #[serde(rename = "chatEventVideoChatEnded")]
+    ChatEventVideoChatEnded(Box<ChatEventVideoChatEnded>),
+    /// The mute_new_participants setting of a video chat was toggled
+    #[serde(rename = "chatEventVideoChatMuteNewParticipantsToggled")]
+    ChatEventVideoChatMuteNewParticipantsToggled(Box<ChatEventVideoChatMuteNewParticipantsToggled>),
+    /// A video chat participant was muted or unmuted
+    #[serde(rename = "chatEventVideoChatParticipantIsMutedToggled")]
+    ChatEventVideoChatParticipantIsMutedToggled(Box<ChatEventVideoChatParticipantIsMutedToggled>),
+    /// A video chat participant volume level was changed
+    #[serde(rename = "chatEventVideoChatParticipantVolumeLevelChanged")]
+    ChatEventVideoChatParticipantVolumeLevelChanged(
+        Box<ChatEventVideoChatParticipantVolumeLevelChanged>,
+    ),
+    /// Contains a list of chat events
+    #[serde(rename = "chatEvents")]
+    ChatEvents(Box<ChatEvents>),
+    /// Represents a filter of user chats
+    #[serde(rename = "chatFilter")]
+    ChatFilter(Box<ChatFilter>),
+    /// Contains basic information about a chat filter
+    #[serde(rename = "chatFilterInfo")]
+    ChatFilterInfo(Box<ChatFilterInfo>),
+    /// Contains a chat invite link
+    #[serde(rename = "chatInviteLink")]
+    ChatInviteLink(Box<ChatInviteLink>),
+    /// Describes a chat administrator with a number of active and revoked chat invite links
+    #[serde(rename = "chatInviteLinkCount")]
+    ChatInviteLinkCount(Box<ChatInviteLinkCount>),
+    /// Contains a list of chat invite link counts
+    #[serde(rename = "chatInviteLinkCounts")]
+    ChatInviteLinkCounts(Box<ChatInviteLinkCounts>),
+    /// Contains information about a chat invite link
+    #[serde(rename = "chatInviteLinkInfo")]
+    ChatInviteLinkInfo(Box<ChatInviteLinkInfo>),
+    /// Describes a chat member joined a chat via an invite link
+    #[serde(rename = "chatInviteLinkMember")]
+    ChatInviteLinkMember(Box<ChatInviteLinkMember>),
+    /// Contains a list of chat members joined a chat via an invite link
+    #[serde(rename = "chatInviteLinkMembers")]
+    ChatInviteLinkMembers(Box<ChatInviteLinkMembers>),
+    /// Contains a list of chat invite links
+    #[serde(rename = "chatInviteLinks")]
+    ChatInviteLinks(Box<ChatInviteLinks>),
+    /// Describes a user that sent a join request and waits for administrator approval
+    #[serde(rename = "chatJoinRequest")]
+    ChatJoinRequest(Box<ChatJoinRequest>),
+    /// Contains a list of requests to join a chat
+    #[serde(rename = "chatJoinRequests")]
+    ChatJoinRequests(Box<ChatJoinRequests>),
+    /// Contains information about pending join requests for a chat
+    #[serde(rename = "chatJoinRequestsInfo")]
+    ChatJoinRequestsInfo(Box<ChatJoinRequestsInfo>),
+    /// A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives
+    #[serde(rename = "chatListArchive")]
+    ChatListArchive(Box<ChatListArchive>),
+    /// A list of chats belonging to a chat filter
+    #[serde(rename = "chatListFilter")]
+    ChatListFilter(Box<ChatListFilter>),
+    /// A main list of chats
+    #[serde(rename = "chatListMain")]
+    ChatListMain(Box<ChatListMain>),
+    /// Contains a list of chat lists
+    #[serde(rename = "chatLists")]
+    ChatLists(Box<ChatLists>),
+    /// Represents a location to which a chat is connected
+    #[serde(rename = "chatLocation")]
+    ChatLocation(Box<ChatLocation>),
+    /// Describes a user or a chat as a member of another chat
+    #[serde(rename = "chatMember")]
+    ChatMember(Box<ChatMember>),
+    /// The user is a member of the chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, ban unprivileged members, and manage video chats. In supergroups and channels, there are more detailed options for administrator privileges
+    #[serde(rename = "chatMemberStatusAdministrator")]
+    ChatMemberStatusAdministrator(Box<ChatMemberStatusAdministrator>),
+    /// The user or the chat was banned (and hence is not a member of the chat). Implies the user can't return to the chat, view messages, or be used as a participant identifier to join a video chat of the chat
+    #[serde(rename = "chatMemberStatusBanned")]
+    ChatMemberStatusBanned(Box<ChatMemberStatusBanned>),
+    /// The user is the owner of the chat and has all the administrator privileges
+    #[serde(rename = "chatMemberStatusCreator")]
+    ChatMemberStatusCreator(Box<ChatMemberStatusCreator>),
+    /// The user or the chat is not a chat member
+    #[serde(rename = "chatMemberStatusLeft")]
+    ChatMemberStatusLeft(Box<ChatMemberStatusLeft>),
+    /// The user is a member of the chat, without any additional privileges or restrictions
+    #[serde(rename = "chatMemberStatusMember")]
+    ChatMemberStatusMember(Box<ChatMemberStatusMember>),
+    /// The user is under certain restrictions in the chat. Not supported in basic groups and channels
+    #[serde(rename = "chatMemberStatusRestricted")]
+    ChatMemberStatusRestricted(Box<ChatMemberStatusRestricted>),
+    /// Contains a list of chat members
+    #[serde(rename = "chatMembers")]
+    ChatMembers(Box<ChatMembers>),
+    /// Returns the owner and administrators
+    #[serde(rename = "chatMembersFilterAdministrators")]
+    ChatMembersFilterAdministrators(Box<ChatMembersFilterAdministrators>),
+    /// Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel
+    #[serde(rename = "chatMembersFilterBanned")]
+    ChatMembersFilterBanned(Box<ChatMembersFilterBanned>),
+    /// Returns bot members of the chat
+    #[serde(rename = "chatMembersFilterBots")]
+    ChatMembersFilterBots(Box<ChatMembersFilterBots>),
+    /// Returns contacts of the user
+    #[serde(rename = "chatMembersFilterContacts")]
+    ChatMembersFilterContacts(Box<ChatMembersFilterContacts>),
+    /// Returns all chat members, including restricted chat members
+    #[serde(rename = "chatMembersFilterMembers")]
+    ChatMembersFilterMembers(Box<ChatMembersFilterMembers>),
+    /// Returns users which can be mentioned in the chat
+    #[serde(rename = "chatMembersFilterMention")]
+    ChatMembersFilterMention(Box<ChatMembersFilterMention>),
+    /// Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup
+    #[serde(rename = "chatMembersFilterRestricted")]
+    ChatMembersFilterRestricted(Box<ChatMembersFilterRestricted>),
+    /// Describes a chat located nearby
+    #[serde(rename = "chatNearby")]
+    ChatNearby(Box<ChatNearby>),
+    /// Contains information about notification settings for a chat
+    #[serde(rename = "chatNotificationSettings")]
+    ChatNotificationSettings(Box<ChatNotificationSettings>),
+    /// Describes actions that a user is allowed to take in a chat
+    #[serde(rename = "chatPermissions")]
+    ChatPermissions(Box<ChatPermissions>),
+    /// Describes a chat or user profile photo
+    #[serde(rename = "chatPhoto")]
+    ChatPhoto(Box<ChatPhoto>),
+    /// Contains basic information about the photo of a chat
+    #[serde(rename = "chatPhotoInfo")]
+    ChatPhotoInfo(Box<ChatPhotoInfo>),
+    /// Contains a list of chat or user profile photos
+    #[serde(rename = "chatPhotos")]
+    ChatPhotos(Box<ChatPhotos>),
+    /// Describes a position of a chat in a chat list
+    #[serde(rename = "chatPosition")]
+    ChatPosition(Box<ChatPosition>),
+    /// The chat has child abuse related content
+    #[serde(rename = "chatReportReasonChildAbuse")]
+    ChatReportReasonChildAbuse(Box<ChatReportReasonChildAbuse>),
+    /// The chat contains copyrighted content
+    #[serde(rename = "chatReportReasonCopyright")]
+    ChatReportReasonCopyright(Box<ChatReportReasonCopyright>),
+    /// A custom reason provided by the user
+    #[serde(rename = "chatReportReasonCustom")]
+    ChatReportReasonCustom(Box<ChatReportReasonCustom>),
+    /// The chat represents a fake account
+    #[serde(rename = "chatReportReasonFake")]
+    ChatReportReasonFake(Box<ChatReportReasonFake>),
+    /// The chat contains pornographic messages
+    #[serde(rename = "chatReportReasonPornography")]
+    ChatReportReasonPornography(Box<ChatReportReasonPornography>),
+    /// The chat contains spam messages
+    #[serde(rename = "chatReportReasonSpam")]
+    ChatReportReasonSpam(Box<ChatReportReasonSpam>),
+    /// The location-based chat is unrelated to its stated location
+    #[serde(rename = "chatReportReasonUnrelatedLocation")]
+    ChatReportReasonUnrelatedLocation(Box<ChatReportReasonUnrelatedLocation>),
+    /// The chat promotes violence
+    #[serde(rename = "chatReportReasonViolence")]
+    ChatReportReasonViolence(Box<ChatReportReasonViolence>),
+    /// The chat is sponsored by the user's MTProxy server
+    #[serde(rename = "chatSourceMtprotoProxy")]
+    ChatSourceMtprotoProxy(Box<ChatSourceMtprotoProxy>),
+    /// The chat contains a public service announcement
+    #[serde(rename = "chatSourcePublicServiceAnnouncement")]
+    ChatSourcePublicServiceAnnouncement(Box<ChatSourcePublicServiceAnnouncement>),
+    /// Contains statistics about administrator actions done by a user
+    #[serde(rename = "chatStatisticsAdministratorActionsInfo")]
+    ChatStatisticsAdministratorActionsInfo(Box<ChatStatisticsAdministratorActionsInfo>),
+    /// A detailed statistics about a channel chat
+    #[serde(rename = "chatStatisticsChannel")]
+    ChatStatisticsChannel(Box<ChatStatisticsChannel>),
+    /// Contains statistics about number of new members invited by a user
+    #[serde(rename = "chatStatisticsInviterInfo")]
+    ChatStatisticsInviterInfo(Box<ChatStatisticsInviterInfo>),
+    /// Contains statistics about interactions with a message
+    #[serde(rename = "chatStatisticsMessageInteractionInfo")]
+    ChatStatisticsMessageInteractionInfo(Box<ChatStatisticsMessageInteractionInfo>),
+    /// Contains statistics about messages sent by a user
+    #[serde(rename = "chatStatisticsMessageSenderInfo")]
+    ChatStatisticsMessageSenderInfo(Box<ChatStatisticsMessageSenderInfo>),
+    /// A detailed statistics about a supergroup chat
+    #[serde(rename = "chatStatisticsSupergroup")]
+    ChatStatisticsSupergroup(Box<ChatStatisticsSupergroup>),
+    /// Describes a chat theme
+    #[serde(rename = "chatTheme")]
+    ChatTheme(Box<ChatTheme>),
+    /// A basic group (a chat with 0-200 other users)
+    #[serde(rename = "chatTypeBasicGroup")]
+    ChatTypeBasicGroup(Box<ChatTypeBasicGroup>),
+    /// An ordinary chat with a user
+    #[serde(rename = "chatTypePrivate")]
+    ChatTypePrivate(Box<ChatTypePrivate>),
+    /// A secret chat with a user
+    #[serde(rename = "chatTypeSecret")]
+    ChatTypeSecret(Box<ChatTypeSecret>),
+    /// A supergroup or channel (with unlimited members)
+    #[serde(rename = "chatTypeSupergroup")]
+    ChatTypeSupergroup(Box<ChatTypeSupergroup>),
+    /// Represents a list of chats
+    #[serde(rename = "chats")]
+    Chats(Box<Chats>),
+    /// Represents a list of chats located nearby
+    #[serde(rename = "chatsNearby")]
+    ChatsNearby(Box<ChatsNearby>),
+    /// The username can be set
+    #[serde(rename = "checkChatUsernameResultOk")]
+    CheckChatUsernameResultOk(Box<CheckChatUsernameResultOk>),
+    /// The user has too much chats with username, one of them must be made private first
+    #[serde(rename = "checkChatUsernameResultPublicChatsTooMuch")]
+    CheckChatUsernameResultPublicChatsTooMuch(Box<CheckChatUsernameResultPublicChatsTooMuch>),
+    /// The user can't be a member of a public supergroup
+    #[serde(rename = "checkChatUsernameResultPublicGroupsUnavailable")]
+    CheckChatUsernameResultPublicGroupsUnavailable(
+        Box<CheckChatUsernameResultPublicGroupsUnavailable>,
+    ),
+    /// The username is invalid
+    #[serde(rename = "checkChatUsernameResultUsernameInvalid")]
+    CheckChatUsernameResultUsernameInvalid(Box<CheckChatUsernameResultUsernameInvalid>),
+    /// The username is occupied
+    #[serde(rename = "checkChatUsernameResultUsernameOccupied")]
+    CheckChatUsernameResultUsernameOccupied(Box<CheckChatUsernameResultUsernameOccupied>),
+    /// The name is invalid
+    #[serde(rename = "checkStickerSetNameResultNameInvalid")]
+    CheckStickerSetNameResultNameInvalid(Box<CheckStickerSetNameResultNameInvalid>),
+    /// The name is occupied
+    #[serde(rename = "checkStickerSetNameResultNameOccupied")]
+    CheckStickerSetNameResultNameOccupied(Box<CheckStickerSetNameResultNameOccupied>),
+    /// The name can be set
+    #[serde(rename = "checkStickerSetNameResultOk")]
+    CheckStickerSetNameResultOk(Box<CheckStickerSetNameResultOk>),
+    /// Represents a closed vector path. The path begins at the end point of the last command
+    #[serde(rename = "closedVectorPath")]
+    ClosedVectorPath(Box<ClosedVectorPath>),
+    /// Contains information about one website the current user is logged in with Telegram
+    #[serde(rename = "connectedWebsite")]
+    ConnectedWebsite(Box<ConnectedWebsite>),
+    /// Contains a list of websites the current user is logged in with Telegram
+    #[serde(rename = "connectedWebsites")]
+    ConnectedWebsites(Box<ConnectedWebsites>),
+    /// Currently establishing a connection to the Telegram servers
+    #[serde(rename = "connectionStateConnecting")]
+    ConnectionStateConnecting(Box<ConnectionStateConnecting>),
+    /// Currently establishing a connection with a proxy server
+    #[serde(rename = "connectionStateConnectingToProxy")]
+    ConnectionStateConnectingToProxy(Box<ConnectionStateConnectingToProxy>),
+    /// There is a working connection to the Telegram servers
+    #[serde(rename = "connectionStateReady")]
+    ConnectionStateReady(Box<ConnectionStateReady>),
+    /// Downloading data received while the application was offline
+    #[serde(rename = "connectionStateUpdating")]
+    ConnectionStateUpdating(Box<ConnectionStateUpdating>),
+    /// Currently waiting for the network to become available. Use setNetworkType to change the available network type
+    #[serde(rename = "connectionStateWaitingForNetwork")]
+    ConnectionStateWaitingForNetwork(Box<ConnectionStateWaitingForNetwork>),
+    /// Describes a user contact
+    #[serde(rename = "contact")]
+    Contact(Box<Contact>),
+    /// Contains a counter
+    #[serde(rename = "count")]
+    Count(Box<Count>),
+    /// Contains information about countries
+    #[serde(rename = "countries")]
+    Countries(Box<Countries>),
+    /// Contains information about a country
+    #[serde(rename = "countryInfo")]
+    CountryInfo(Box<CountryInfo>),
+    /// Contains the result of a custom request
+    #[serde(rename = "customRequestResult")]
+    CustomRequestResult(Box<CustomRequestResult>),
+    /// Contains database statistics
+    #[serde(rename = "databaseStatistics")]
+    DatabaseStatistics(Box<DatabaseStatistics>),
+    /// Represents a date according to the Gregorian calendar
+    #[serde(rename = "date")]
+    Date(Box<Date>),
+    /// Represents a date range
+    #[serde(rename = "dateRange")]
+    DateRange(Box<DateRange>),
+    /// File with the date it was uploaded
+    #[serde(rename = "datedFile")]
+    DatedFile(Box<DatedFile>),
+    /// Contains information about a tg: deep link
+    #[serde(rename = "deepLinkInfo")]
+    DeepLinkInfo(Box<DeepLinkInfo>),
+    /// A token for Apple Push Notification service
+    #[serde(rename = "deviceTokenApplePush")]
+    DeviceTokenApplePush(Box<DeviceTokenApplePush>),
+    /// A token for Apple Push Notification service VoIP notifications
+    #[serde(rename = "deviceTokenApplePushVoIP")]
+    DeviceTokenApplePushVoIP(Box<DeviceTokenApplePushVoIP>),
+    /// A token for BlackBerry Push Service
+    #[serde(rename = "deviceTokenBlackBerryPush")]
+    DeviceTokenBlackBerryPush(Box<DeviceTokenBlackBerryPush>),
+    /// A token for Firebase Cloud Messaging
+    #[serde(rename = "deviceTokenFirebaseCloudMessaging")]
+    DeviceTokenFirebaseCloudMessaging(Box<DeviceTokenFirebaseCloudMessaging>),
+    /// A token for Microsoft Push Notification Service
+    #[serde(rename = "deviceTokenMicrosoftPush")]
+    DeviceTokenMicrosoftPush(Box<DeviceTokenMicrosoftPush>),
+    /// A token for Microsoft Push Notification Service VoIP channel
+    #[serde(rename = "deviceTokenMicrosoftPushVoIP")]
+    DeviceTokenMicrosoftPushVoIP(Box<DeviceTokenMicrosoftPushVoIP>),
+    /// A token for Simple Push API for Firefox OS
+    #[serde(rename = "deviceTokenSimplePush")]
+    DeviceTokenSimplePush(Box<DeviceTokenSimplePush>),
+    /// A token for Tizen Push Service
+    #[serde(rename = "deviceTokenTizenPush")]
+    DeviceTokenTizenPush(Box<DeviceTokenTizenPush>),
+    /// A token for Ubuntu Push Client service
+    #[serde(rename = "deviceTokenUbuntuPush")]
+    DeviceTokenUbuntuPush(Box<DeviceTokenUbuntuPush>),
+    /// A token for web Push API
+    #[serde(rename = "deviceTokenWebPush")]
+    DeviceTokenWebPush(Box<DeviceTokenWebPush>),
+    /// A token for Windows Push Notification Services
+    #[serde(rename = "deviceTokenWindowsPush")]
+    DeviceTokenWindowsPush(Box<DeviceTokenWindowsPush>),
+    /// A regular animated sticker
+    #[serde(rename = "diceStickersRegular")]
+    DiceStickersRegular(Box<DiceStickersRegular>),
+    /// Animated stickers to be combined into a slot machine
+    #[serde(rename = "diceStickersSlotMachine")]
+    DiceStickersSlotMachine(Box<DiceStickersSlotMachine>),
+    /// Describes a document of any type
+    #[serde(rename = "document")]
+    Document(Box<Document>),
+    /// Contains information about a message draft
+    #[serde(rename = "draftMessage")]
+    DraftMessage(Box<DraftMessage>),
+    /// Information about the email address authentication code that was sent
+    #[serde(rename = "emailAddressAuthenticationCodeInfo")]
+    EmailAddressAuthenticationCodeInfo(Box<EmailAddressAuthenticationCodeInfo>),
+    /// Represents a list of emoji
+    #[serde(rename = "emojis")]
+    Emojis(Box<Emojis>),
+    /// Contains encrypted Telegram Passport data credentials
+    #[serde(rename = "encryptedCredentials")]
+    EncryptedCredentials(Box<EncryptedCredentials>),
+    /// Contains information about an encrypted Telegram Passport element; for bots only
+    #[serde(rename = "encryptedPassportElement")]
+    EncryptedPassportElement(Box<EncryptedPassportElement>),
+    /// An object of this type can be returned on every function call, in case of an error
+    #[serde(rename = "error")]
+    Error(Box<Error>),
+    /// Represents a file
+    #[serde(rename = "file")]
+    File(Box<File>),
+    /// Contains a part of a file
+    #[serde(rename = "filePart")]
+    FilePart(Box<FilePart>),
+    /// The file is an animation
+    #[serde(rename = "fileTypeAnimation")]
+    FileTypeAnimation(Box<FileTypeAnimation>),
+    /// The file is an audio file
+    #[serde(rename = "fileTypeAudio")]
+    FileTypeAudio(Box<FileTypeAudio>),
+    /// The file is a document
+    #[serde(rename = "fileTypeDocument")]
+    FileTypeDocument(Box<FileTypeDocument>),
+    /// The data is not a file
+    #[serde(rename = "fileTypeNone")]
+    FileTypeNone(Box<FileTypeNone>),
+    /// The file is a photo
+    #[serde(rename = "fileTypePhoto")]
+    FileTypePhoto(Box<FileTypePhoto>),
+    /// The file is a profile photo
+    #[serde(rename = "fileTypeProfilePhoto")]
+    FileTypeProfilePhoto(Box<FileTypeProfilePhoto>),
+    /// The file was sent to a secret chat (the file type is not known to the server)
+    #[serde(rename = "fileTypeSecret")]
+    FileTypeSecret(Box<FileTypeSecret>),
+    /// The file is a thumbnail of a file from a secret chat
+    #[serde(rename = "fileTypeSecretThumbnail")]
+    FileTypeSecretThumbnail(Box<FileTypeSecretThumbnail>),
+    /// The file is a file from Secure storage used for storing Telegram Passport files
+    #[serde(rename = "fileTypeSecure")]
+    FileTypeSecure(Box<FileTypeSecure>),
+    /// The file is a sticker
+    #[serde(rename = "fileTypeSticker")]
+    FileTypeSticker(Box<FileTypeSticker>),
+    /// The file is a thumbnail of another file
+    #[serde(rename = "fileTypeThumbnail")]
+    FileTypeThumbnail(Box<FileTypeThumbnail>),
+    /// The file type is not yet known
+    #[serde(rename = "fileTypeUnknown")]
+    FileTypeUnknown(Box<FileTypeUnknown>),
+    /// The file is a video
+    #[serde(rename = "fileTypeVideo")]
+    FileTypeVideo(Box<FileTypeVideo>),
+    /// The file is a video note
+    #[serde(rename = "fileTypeVideoNote")]
+    FileTypeVideoNote(Box<FileTypeVideoNote>),
+    /// The file is a voice note
+    #[serde(rename = "fileTypeVoiceNote")]
+    FileTypeVoiceNote(Box<FileTypeVoiceNote>),
+    /// The file is a wallpaper or a background pattern
+    #[serde(rename = "fileTypeWallpaper")]
+    FileTypeWallpaper(Box<FileTypeWallpaper>),
+    /// A text with some entities
+    #[serde(rename = "formattedText")]
+    FormattedText(Box<FormattedText>),
+    /// Contains a list of messages found by a search
+    #[serde(rename = "foundMessages")]
+    FoundMessages(Box<FoundMessages>),
+    /// Describes a game
+    #[serde(rename = "game")]
+    Game(Box<Game>),
+    /// Contains one row of the game high score table
+    #[serde(rename = "gameHighScore")]
+    GameHighScore(Box<GameHighScore>),
+    /// Contains a list of game high scores
+    #[serde(rename = "gameHighScores")]
+    GameHighScores(Box<GameHighScores>),
+    /// Describes a group call
+    #[serde(rename = "groupCall")]
+    GroupCall(Box<GroupCall>),
+    /// Contains the group call identifier
+    #[serde(rename = "groupCallId")]
+    GroupCallId(Box<GroupCallId>),
+    /// Represents a group call participant
+    #[serde(rename = "groupCallParticipant")]
+    GroupCallParticipant(Box<GroupCallParticipant>),
+    /// Contains information about a group call participant's video channel
+    #[serde(rename = "groupCallParticipantVideoInfo")]
+    GroupCallParticipantVideoInfo(Box<GroupCallParticipantVideoInfo>),
+    /// Describes a recently speaking participant in a group call
+    #[serde(rename = "groupCallRecentSpeaker")]
+    GroupCallRecentSpeaker(Box<GroupCallRecentSpeaker>),
+    /// The best available video quality
+    #[serde(rename = "groupCallVideoQualityFull")]
+    GroupCallVideoQualityFull(Box<GroupCallVideoQualityFull>),
+    /// The medium video quality
+    #[serde(rename = "groupCallVideoQualityMedium")]
+    GroupCallVideoQualityMedium(Box<GroupCallVideoQualityMedium>),
+    /// The worst available video quality
+    #[serde(rename = "groupCallVideoQualityThumbnail")]
+    GroupCallVideoQualityThumbnail(Box<GroupCallVideoQualityThumbnail>),
+    /// Describes a group of video synchronization source identifiers
+    #[serde(rename = "groupCallVideoSourceGroup")]
+    GroupCallVideoSourceGroup(Box<GroupCallVideoSourceGroup>),
+    /// Contains a list of hashtags
+    #[serde(rename = "hashtags")]
+    Hashtags(Box<Hashtags>),
+    /// Contains an HTTP URL
+    #[serde(rename = "httpUrl")]
+    HttpUrl(Box<HttpUrl>),
+    /// An identity document
+    #[serde(rename = "identityDocument")]
+    IdentityDocument(Box<IdentityDocument>),
+    /// Represents the result of an ImportContacts request
+    #[serde(rename = "importedContacts")]
+    ImportedContacts(Box<ImportedContacts>),
+    /// Represents a single button in an inline keyboard
+    #[serde(rename = "inlineKeyboardButton")]
+    InlineKeyboardButton(Box<InlineKeyboardButton>),
+    /// A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice
+    #[serde(rename = "inlineKeyboardButtonTypeBuy")]
+    InlineKeyboardButtonTypeBuy(Box<InlineKeyboardButtonTypeBuy>),
+    /// A button that sends a callback query to a bot
+    #[serde(rename = "inlineKeyboardButtonTypeCallback")]
+    InlineKeyboardButtonTypeCallback(Box<InlineKeyboardButtonTypeCallback>),
+    /// A button with a game that sends a callback query to a bot. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageGame
+    #[serde(rename = "inlineKeyboardButtonTypeCallbackGame")]
+    InlineKeyboardButtonTypeCallbackGame(Box<InlineKeyboardButtonTypeCallbackGame>),
+    /// A button that asks for password of the current user and then sends a callback query to a bot
+    #[serde(rename = "inlineKeyboardButtonTypeCallbackWithPassword")]
+    InlineKeyboardButtonTypeCallbackWithPassword(Box<InlineKeyboardButtonTypeCallbackWithPassword>),
+    /// A button that opens a specified URL and automatically authorize the current user if allowed to do so
+    #[serde(rename = "inlineKeyboardButtonTypeLoginUrl")]
+    InlineKeyboardButtonTypeLoginUrl(Box<InlineKeyboardButtonTypeLoginUrl>),
+    /// A button that forces an inline query to the bot to be inserted in the input field
+    #[serde(rename = "inlineKeyboardButtonTypeSwitchInline")]
+    InlineKeyboardButtonTypeSwitchInline(Box<InlineKeyboardButtonTypeSwitchInline>),
+    /// A button that opens a specified URL
+    #[serde(rename = "inlineKeyboardButtonTypeUrl")]
+    InlineKeyboardButtonTypeUrl(Box<InlineKeyboardButtonTypeUrl>),
+    /// A button with a user reference to be handled in the same way as textEntityTypeMentionName entities
+    #[serde(rename = "inlineKeyboardButtonTypeUser")]
+    InlineKeyboardButtonTypeUser(Box<InlineKeyboardButtonTypeUser>),
+    /// Represents an animation file
+    #[serde(rename = "inlineQueryResultAnimation")]
+    InlineQueryResultAnimation(Box<InlineQueryResultAnimation>),
+    /// Represents a link to an article or web page
+    #[serde(rename = "inlineQueryResultArticle")]
+    InlineQueryResultArticle(Box<InlineQueryResultArticle>),
+    /// Represents an audio file
+    #[serde(rename = "inlineQueryResultAudio")]
+    InlineQueryResultAudio(Box<InlineQueryResultAudio>),
+    /// Represents a user contact
+    #[serde(rename = "inlineQueryResultContact")]
+    InlineQueryResultContact(Box<InlineQueryResultContact>),
+    /// Represents a document
+    #[serde(rename = "inlineQueryResultDocument")]
+    InlineQueryResultDocument(Box<InlineQueryResultDocument>),
+    /// Represents information about a game
+    #[serde(rename = "inlineQueryResultGame")]
+    InlineQueryResultGame(Box<InlineQueryResultGame>),
+    /// Represents a point on the map
+    #[serde(rename = "inlineQueryResultLocation")]
+    InlineQueryResultLocation(Box<InlineQueryResultLocation>),
+    /// Represents a photo
+    #[serde(rename = "inlineQueryResultPhoto")]
+    InlineQueryResultPhoto(Box<InlineQueryResultPhoto>),
+    /// Represents a sticker
+    #[serde(rename = "inlineQueryResultSticker")]
+    InlineQueryResultSticker(Box<InlineQueryResultSticker>),
+    /// Represents information about a venue
+    #[serde(rename = "inlineQueryResultVenue")]
+    InlineQueryResultVenue(Box<InlineQueryResultVenue>),
+    /// Represents a video
+    #[serde(rename = "inlineQueryResultVideo")]
+    InlineQueryResultVideo(Box<InlineQueryResultVideo>),
+    /// Represents a voice note
+    #[serde(rename = "inlineQueryResultVoiceNote")]
+    InlineQueryResultVoiceNote(Box<InlineQueryResultVoiceNote>),
+    /// Represents the results of the inline query. Use sendInlineQueryResultMessage to send the result of the query
+    #[serde(rename = "inlineQueryResults")]
+    InlineQueryResults(Box<InlineQueryResults>),
+    /// A background from a local file
+    #[serde(rename = "inputBackgroundLocal")]
+    InputBackgroundLocal(Box<InputBackgroundLocal>),
+    /// A background from the server
+    #[serde(rename = "inputBackgroundRemote")]
+    InputBackgroundRemote(Box<InputBackgroundRemote>),
+    /// An animation in MPEG4 format; must be square, at most 10 seconds long, have width between 160 and 800 and be at most 2MB in size
+    #[serde(rename = "inputChatPhotoAnimation")]
+    InputChatPhotoAnimation(Box<InputChatPhotoAnimation>),
+    /// A previously used profile photo of the current user
+    #[serde(rename = "inputChatPhotoPrevious")]
+    InputChatPhotoPrevious(Box<InputChatPhotoPrevious>),
+    /// A static photo in JPEG format
+    #[serde(rename = "inputChatPhotoStatic")]
+    InputChatPhotoStatic(Box<InputChatPhotoStatic>),
+    /// Applies if a user enters new credentials using Apple Pay
+    #[serde(rename = "inputCredentialsApplePay")]
+    InputCredentialsApplePay(Box<InputCredentialsApplePay>),
+    /// Applies if a user enters new credentials using Google Pay
+    #[serde(rename = "inputCredentialsGooglePay")]
+    InputCredentialsGooglePay(Box<InputCredentialsGooglePay>),
+    /// Applies if a user enters new credentials on a payment provider website
+    #[serde(rename = "inputCredentialsNew")]
+    InputCredentialsNew(Box<InputCredentialsNew>),
+    /// Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password
+    #[serde(rename = "inputCredentialsSaved")]
+    InputCredentialsSaved(Box<InputCredentialsSaved>),
+    /// A file generated by the application
+    #[serde(rename = "inputFileGenerated")]
+    InputFileGenerated(Box<InputFileGenerated>),
+    /// A file defined by its unique ID
+    #[serde(rename = "inputFileId")]
+    InputFileId(Box<InputFileId>),
+    /// A file defined by a local path
+    #[serde(rename = "inputFileLocal")]
+    InputFileLocal(Box<InputFileLocal>),
+    /// A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application
+    #[serde(rename = "inputFileRemote")]
+    InputFileRemote(Box<InputFileRemote>),
+    /// An identity document to be saved to Telegram Passport
+    #[serde(rename = "inputIdentityDocument")]
+    InputIdentityDocument(Box<InputIdentityDocument>),
+    /// Represents a link to an animated GIF or an animated (i.e., without sound) H.264/MPEG-4 AVC video
+    #[serde(rename = "inputInlineQueryResultAnimation")]
+    InputInlineQueryResultAnimation(Box<InputInlineQueryResultAnimation>),
+    /// Represents a link to an article or web page
+    #[serde(rename = "inputInlineQueryResultArticle")]
+    InputInlineQueryResultArticle(Box<InputInlineQueryResultArticle>),
+    /// Represents a link to an MP3 audio file
+    #[serde(rename = "inputInlineQueryResultAudio")]
+    InputInlineQueryResultAudio(Box<InputInlineQueryResultAudio>),
+    /// Represents a user contact
+    #[serde(rename = "inputInlineQueryResultContact")]
+    InputInlineQueryResultContact(Box<InputInlineQueryResultContact>),
+    /// Represents a link to a file
+    #[serde(rename = "inputInlineQueryResultDocument")]
+    InputInlineQueryResultDocument(Box<InputInlineQueryResultDocument>),
+    /// Represents a game
+    #[serde(rename = "inputInlineQueryResultGame")]
+    InputInlineQueryResultGame(Box<InputInlineQueryResultGame>),
+    /// Represents a point on the map
+    #[serde(rename = "inputInlineQueryResultLocation")]
+    InputInlineQueryResultLocation(Box<InputInlineQueryResultLocation>),
+    /// Represents link to a JPEG image
+    #[serde(rename = "inputInlineQueryResultPhoto")]
+    InputInlineQueryResultPhoto(Box<InputInlineQueryResultPhoto>),
+    /// Represents a link to a WEBP or TGS sticker
+    #[serde(rename = "inputInlineQueryResultSticker")]
+    InputInlineQueryResultSticker(Box<InputInlineQueryResultSticker>),
+    /// Represents information about a venue
+    #[serde(rename = "inputInlineQueryResultVenue")]
+    InputInlineQueryResultVenue(Box<InputInlineQueryResultVenue>),
+    /// Represents a link to a page containing an embedded video player or a video file
+    #[serde(rename = "inputInlineQueryResultVideo")]
+    InputInlineQueryResultVideo(Box<InputInlineQueryResultVideo>),
+    /// Represents a link to an opus-encoded audio file within an OGG container, single channel audio
+    #[serde(rename = "inputInlineQueryResultVoiceNote")]
+    InputInlineQueryResultVoiceNote(Box<InputInlineQueryResultVoiceNote>),
+    /// An animation message (GIF-style).
+    #[serde(rename = "inputMessageAnimation")]
+    InputMessageAnimation(Box<InputMessageAnimation>),
+    /// An audio message
+    #[serde(rename = "inputMessageAudio")]
+    InputMessageAudio(Box<InputMessageAudio>),
+    /// A message containing a user contact
+    #[serde(rename = "inputMessageContact")]
+    InputMessageContact(Box<InputMessageContact>),
+    /// A dice message
+    #[serde(rename = "inputMessageDice")]
+    InputMessageDice(Box<InputMessageDice>),
+    /// A document message (general file)
+    #[serde(rename = "inputMessageDocument")]
+    InputMessageDocument(Box<InputMessageDocument>),
+    /// A forwarded message
+    #[serde(rename = "inputMessageForwarded")]
+    InputMessageForwarded(Box<InputMessageForwarded>),
+    /// A message with a game; not supported for channels or secret chats
+    #[serde(rename = "inputMessageGame")]
+    InputMessageGame(Box<InputMessageGame>),
+    /// A message with an invoice; can be used only by bots
+    #[serde(rename = "inputMessageInvoice")]
+    InputMessageInvoice(Box<InputMessageInvoice>),
+    /// A message with a location
+    #[serde(rename = "inputMessageLocation")]
+    InputMessageLocation(Box<InputMessageLocation>),
+    /// A photo message
+    #[serde(rename = "inputMessagePhoto")]
+    InputMessagePhoto(Box<InputMessagePhoto>),
+    /// A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot
+    #[serde(rename = "inputMessagePoll")]
+    InputMessagePoll(Box<InputMessagePoll>),
+    /// A sticker message
+    #[serde(rename = "inputMessageSticker")]
+    InputMessageSticker(Box<InputMessageSticker>),
+    /// A text message
+    #[serde(rename = "inputMessageText")]
+    InputMessageText(Box<InputMessageText>),
+    /// A message with information about a venue
+    #[serde(rename = "inputMessageVenue")]
+    InputMessageVenue(Box<InputMessageVenue>),
+    /// A video message
+    #[serde(rename = "inputMessageVideo")]
+    InputMessageVideo(Box<InputMessageVideo>),
+    /// A video note message
+    #[serde(rename = "inputMessageVideoNote")]
+    InputMessageVideoNote(Box<InputMessageVideoNote>),
+    /// A voice note message
+    #[serde(rename = "inputMessageVoiceNote")]
+    InputMessageVoiceNote(Box<InputMessageVoiceNote>),
+    /// A Telegram Passport element to be saved containing the user's address
+    #[serde(rename = "inputPassportElementAddress")]
+    InputPassportElementAddress(Box<InputPassportElementAddress>),
+    /// A Telegram Passport element to be saved containing the user's bank statement
+    #[serde(rename = "inputPassportElementBankStatement")]
+    InputPassportElementBankStatement(Box<InputPassportElementBankStatement>),
+    /// A Telegram Passport element to be saved containing the user's driver license
+    #[serde(rename = "inputPassportElementDriverLicense")]
+    InputPassportElementDriverLicense(Box<InputPassportElementDriverLicense>),
+    /// A Telegram Passport element to be saved containing the user's email address
+    #[serde(rename = "inputPassportElementEmailAddress")]
+    InputPassportElementEmailAddress(Box<InputPassportElementEmailAddress>),
+    /// Contains the description of an error in a Telegram Passport element; for bots only
+    #[serde(rename = "inputPassportElementError")]
+    InputPassportElementError(Box<InputPassportElementError>),
+    /// A data field contains an error. The error is considered resolved when the field's value changes
+    #[serde(rename = "inputPassportElementErrorSourceDataField")]
+    InputPassportElementErrorSourceDataField(Box<InputPassportElementErrorSourceDataField>),
+    /// The file contains an error. The error is considered resolved when the file changes
+    #[serde(rename = "inputPassportElementErrorSourceFile")]
+    InputPassportElementErrorSourceFile(Box<InputPassportElementErrorSourceFile>),
+    /// The list of attached files contains an error. The error is considered resolved when the file list changes
+    #[serde(rename = "inputPassportElementErrorSourceFiles")]
+    InputPassportElementErrorSourceFiles(Box<InputPassportElementErrorSourceFiles>),
+    /// The front side of the document contains an error. The error is considered resolved when the file with the front side of the document changes
+    #[serde(rename = "inputPassportElementErrorSourceFrontSide")]
+    InputPassportElementErrorSourceFrontSide(Box<InputPassportElementErrorSourceFrontSide>),
+    /// The reverse side of the document contains an error. The error is considered resolved when the file with the reverse side of the document changes
+    #[serde(rename = "inputPassportElementErrorSourceReverseSide")]
+    InputPassportElementErrorSourceReverseSide(Box<InputPassportElementErrorSourceReverseSide>),
+    /// The selfie contains an error. The error is considered resolved when the file with the selfie changes
+    #[serde(rename = "inputPassportElementErrorSourceSelfie")]
+    InputPassportElementErrorSourceSelfie(Box<InputPassportElementErrorSourceSelfie>),
+    /// One of the files containing the translation of the document contains an error. The error is considered resolved when the file with the translation changes
+    #[serde(rename = "inputPassportElementErrorSourceTranslationFile")]
+    InputPassportElementErrorSourceTranslationFile(
+        Box<InputPassportElementErrorSourceTranslationFile>,
+    ),
+    /// The translation of the document contains an error. The error is considered resolved when the list of files changes
+    #[serde(rename = "inputPassportElementErrorSourceTranslationFiles")]
+    InputPassportElementErrorSourceTranslationFiles(
+        Box<InputPassportElementErrorSourceTranslationFiles>,
+    ),
+    /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
+    #[serde(rename = "inputPassportElementErrorSourceUnspecified")]
+    InputPassportElementErrorSourceUnspecified(Box<InputPassportElementErrorSourceUnspecified>),
+    /// A Telegram Passport element to be saved containing the user's identity card
+    #[serde(rename = "inputPassportElementIdentityCard")]
+    InputPassportElementIdentityCard(Box<InputPassportElementIdentityCard>),
+    /// A Telegram Passport element to be saved containing the user's internal passport
+    #[serde(rename = "inputPassportElementInternalPassport")]
+    InputPassportElementInternalPassport(Box<InputPassportElementInternalPassport>),
+    /// A Telegram Passport element to be saved containing the user's passport
+    #[serde(rename = "inputPassportElementPassport")]
+    InputPassportElementPassport(Box<InputPassportElementPassport>),
+    /// A Telegram Passport element to be saved containing the user's passport registration
+    #[serde(rename = "inputPassportElementPassportRegistration")]
+    InputPassportElementPassportRegistration(Box<InputPassportElementPassportRegistration>),
+    /// A Telegram Passport element to be saved containing the user's personal details
+    #[serde(rename = "inputPassportElementPersonalDetails")]
+    InputPassportElementPersonalDetails(Box<InputPassportElementPersonalDetails>),
+    /// A Telegram Passport element to be saved containing the user's phone number
+    #[serde(rename = "inputPassportElementPhoneNumber")]
+    InputPassportElementPhoneNumber(Box<InputPassportElementPhoneNumber>),
+    /// A Telegram Passport element to be saved containing the user's rental agreement
+    #[serde(rename = "inputPassportElementRentalAgreement")]
+    InputPassportElementRentalAgreement(Box<InputPassportElementRentalAgreement>),
+    /// A Telegram Passport element to be saved containing the user's temporary registration
+    #[serde(rename = "inputPassportElementTemporaryRegistration")]
+    InputPassportElementTemporaryRegistration(Box<InputPassportElementTemporaryRegistration>),
+    /// A Telegram Passport element to be saved containing the user's utility bill
+    #[serde(rename = "inputPassportElementUtilityBill")]
+    InputPassportElementUtilityBill(Box<InputPassportElementUtilityBill>),
+    /// A personal document to be saved to Telegram Passport
+    #[serde(rename = "inputPersonalDocument")]
+    InputPersonalDocument(Box<InputPersonalDocument>),
+    /// An animated sticker in TGS format
+    #[serde(rename = "inputStickerAnimated")]
+    InputStickerAnimated(Box<InputStickerAnimated>),
+    /// A static sticker in PNG format, which will be converted to WEBP server-side
+    #[serde(rename = "inputStickerStatic")]
+    InputStickerStatic(Box<InputStickerStatic>),
+    /// A thumbnail to be sent along with a file; must be in JPEG or WEBP format for stickers, and less than 200 KB in size
+    #[serde(rename = "inputThumbnail")]
+    InputThumbnail(Box<InputThumbnail>),
+    /// The link is a link to the active sessions section of the app. Use getActiveSessions to handle the link
+    #[serde(rename = "internalLinkTypeActiveSessions")]
+    InternalLinkTypeActiveSessions(Box<InternalLinkTypeActiveSessions>),
+    /// The link contains an authentication code. Call checkAuthenticationCode with the code if the current authorization state is authorizationStateWaitCode
+    #[serde(rename = "internalLinkTypeAuthenticationCode")]
+    InternalLinkTypeAuthenticationCode(Box<InternalLinkTypeAuthenticationCode>),
+    /// The link is a link to a background. Call searchBackground with the given background name to process the link
+    #[serde(rename = "internalLinkTypeBackground")]
+    InternalLinkTypeBackground(Box<InternalLinkTypeBackground>),
+    /// The link is a link to a chat with a Telegram bot. Call searchPublicChat with the given bot username, check that the user is a bot, show START button in the chat with the bot, and then call sendBotStartMessage with the given start parameter after the button is pressed
+    #[serde(rename = "internalLinkTypeBotStart")]
+    InternalLinkTypeBotStart(Box<InternalLinkTypeBotStart>),
+    /// The link is a link to a Telegram bot, which is supposed to be added to a group chat. Call searchPublicChat with the given bot username, check that the user is a bot and can be added to groups, ask the current user to select a group to add the bot to, and then call sendBotStartMessage with the given start parameter and the chosen group chat. Bots can be added to a public group only by administrators of the group
+    #[serde(rename = "internalLinkTypeBotStartInGroup")]
+    InternalLinkTypeBotStartInGroup(Box<InternalLinkTypeBotStartInGroup>),
+    /// The link is a link to the change phone number section of the app
+    #[serde(rename = "internalLinkTypeChangePhoneNumber")]
+    InternalLinkTypeChangePhoneNumber(Box<InternalLinkTypeChangePhoneNumber>),
+    /// The link is a chat invite link. Call checkChatInviteLink with the given invite link to process the link
+    #[serde(rename = "internalLinkTypeChatInvite")]
+    InternalLinkTypeChatInvite(Box<InternalLinkTypeChatInvite>),
+    /// The link is a link to the filter settings section of the app
+    #[serde(rename = "internalLinkTypeFilterSettings")]
+    InternalLinkTypeFilterSettings(Box<InternalLinkTypeFilterSettings>),
+    /// The link is a link to a game. Call searchPublicChat with the given bot username, check that the user is a bot, ask the current user to select a chat to send the game, and then call sendMessage with inputMessageGame
+    #[serde(rename = "internalLinkTypeGame")]
+    InternalLinkTypeGame(Box<InternalLinkTypeGame>),
+    /// The link is a link to a language pack. Call getLanguagePackInfo with the given language pack identifier to process the link
+    #[serde(rename = "internalLinkTypeLanguagePack")]
+    InternalLinkTypeLanguagePack(Box<InternalLinkTypeLanguagePack>),
+    /// The link is a link to a Telegram message. Call getMessageLinkInfo with the given URL to process the link
+    #[serde(rename = "internalLinkTypeMessage")]
+    InternalLinkTypeMessage(Box<InternalLinkTypeMessage>),
+    /// The link contains a message draft text. A share screen needs to be shown to the user, then the chosen chat must be opened and the text is added to the input field
+    #[serde(rename = "internalLinkTypeMessageDraft")]
+    InternalLinkTypeMessageDraft(Box<InternalLinkTypeMessageDraft>),
+    /// The link contains a request of Telegram passport data. Call getPassportAuthorizationForm with the given parameters to process the link if the link was received from outside of the app, otherwise ignore it
+    #[serde(rename = "internalLinkTypePassportDataRequest")]
+    InternalLinkTypePassportDataRequest(Box<InternalLinkTypePassportDataRequest>),
+    /// The link can be used to confirm ownership of a phone number to prevent account deletion. Call sendPhoneNumberConfirmationCode with the given hash and phone number to process the link
+    #[serde(rename = "internalLinkTypePhoneNumberConfirmation")]
+    InternalLinkTypePhoneNumberConfirmation(Box<InternalLinkTypePhoneNumberConfirmation>),
+    /// The link is a link to a proxy. Call addProxy with the given parameters to process the link and add the proxy
+    #[serde(rename = "internalLinkTypeProxy")]
+    InternalLinkTypeProxy(Box<InternalLinkTypeProxy>),
+    /// The link is a link to a chat by its username. Call searchPublicChat with the given chat username to process the link
+    #[serde(rename = "internalLinkTypePublicChat")]
+    InternalLinkTypePublicChat(Box<InternalLinkTypePublicChat>),
+    /// The link can be used to login the current user on another device, but it must be scanned from QR-code using in-app camera. An alert similar to "This code can be used to allow someone to log in to your Telegram account. To confirm Telegram login, please go to Settings > Devices > Scan QR and scan the code" needs to be shown
+    #[serde(rename = "internalLinkTypeQrCodeAuthentication")]
+    InternalLinkTypeQrCodeAuthentication(Box<InternalLinkTypeQrCodeAuthentication>),
+    /// The link is a link to app settings
+    #[serde(rename = "internalLinkTypeSettings")]
+    InternalLinkTypeSettings(Box<InternalLinkTypeSettings>),
+    /// The link is a link to a sticker set. Call searchStickerSet with the given sticker set name to process the link and show the sticker set
+    #[serde(rename = "internalLinkTypeStickerSet")]
+    InternalLinkTypeStickerSet(Box<InternalLinkTypeStickerSet>),
+    /// The link is a link to a theme. TDLib has no theme support yet
+    #[serde(rename = "internalLinkTypeTheme")]
+    InternalLinkTypeTheme(Box<InternalLinkTypeTheme>),
+    /// The link is a link to the theme settings section of the app
+    #[serde(rename = "internalLinkTypeThemeSettings")]
+    InternalLinkTypeThemeSettings(Box<InternalLinkTypeThemeSettings>),
+    /// The link is an unknown tg: link. Call getDeepLinkInfo to process the link
+    #[serde(rename = "internalLinkTypeUnknownDeepLink")]
+    InternalLinkTypeUnknownDeepLink(Box<InternalLinkTypeUnknownDeepLink>),
+    /// The link is a link to an unsupported proxy. An alert can be shown to the user
+    #[serde(rename = "internalLinkTypeUnsupportedProxy")]
+    InternalLinkTypeUnsupportedProxy(Box<InternalLinkTypeUnsupportedProxy>),
+    /// The link is a link to a video chat. Call searchPublicChat with the given chat username, and then joinGoupCall with the given invite hash to process the link
+    #[serde(rename = "internalLinkTypeVideoChat")]
+    InternalLinkTypeVideoChat(Box<InternalLinkTypeVideoChat>),
+    /// Product invoice
+    #[serde(rename = "invoice")]
+    Invoice(Box<Invoice>),
+    /// Represents one member of a JSON object
+    #[serde(rename = "jsonObjectMember")]
+    JsonObjectMember(Box<JsonObjectMember>),
+    /// Represents a JSON array
+    #[serde(rename = "jsonValueArray")]
+    JsonValueArray(Box<JsonValueArray>),
+    /// Represents a boolean JSON value
+    #[serde(rename = "jsonValueBoolean")]
+    JsonValueBoolean(Box<JsonValueBoolean>),
+    /// Represents a null JSON value
+    #[serde(rename = "jsonValueNull")]
+    JsonValueNull(Box<JsonValueNull>),
+    /// Represents a numeric JSON value
+    #[serde(rename = "jsonValueNumber")]
+    JsonValueNumber(Box<JsonValueNumber>),
+    /// Represents a JSON object
+    #[serde(rename = "jsonValueObject")]
+    JsonValueObject(Box<JsonValueObject>),
+    /// Represents a string JSON value
+    #[serde(rename = "jsonValueString")]
+    JsonValueString(Box<JsonValueString>),
+    /// Represents a single button in a bot keyboard
+    #[serde(rename = "keyboardButton")]
+    KeyboardButton(Box<KeyboardButton>),
+    /// A button that sends the user's location when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestLocation")]
+    KeyboardButtonTypeRequestLocation(Box<KeyboardButtonTypeRequestLocation>),
+    /// A button that sends the user's phone number when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestPhoneNumber")]
+    KeyboardButtonTypeRequestPhoneNumber(Box<KeyboardButtonTypeRequestPhoneNumber>),
+    /// A button that allows the user to create and send a poll when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestPoll")]
+    KeyboardButtonTypeRequestPoll(Box<KeyboardButtonTypeRequestPoll>),
+    /// A simple button, with text that must be sent when the button is pressed
+    #[serde(rename = "keyboardButtonTypeText")]
+    KeyboardButtonTypeText(Box<KeyboardButtonTypeText>),
+    /// Portion of the price of a product (e.g., "delivery cost", "tax amount")
+    #[serde(rename = "labeledPricePart")]
+    LabeledPricePart(Box<LabeledPricePart>),
+    /// Contains information about a language pack
+    #[serde(rename = "languagePackInfo")]
+    LanguagePackInfo(Box<LanguagePackInfo>),
+    /// Represents one language pack string
+    #[serde(rename = "languagePackString")]
+    LanguagePackString(Box<LanguagePackString>),
+    /// A deleted language pack string, the value must be taken from the built-in English language pack
+    #[serde(rename = "languagePackStringValueDeleted")]
+    LanguagePackStringValueDeleted(Box<LanguagePackStringValueDeleted>),
+    /// An ordinary language pack string
+    #[serde(rename = "languagePackStringValueOrdinary")]
+    LanguagePackStringValueOrdinary(Box<LanguagePackStringValueOrdinary>),
+    /// A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info
+    #[serde(rename = "languagePackStringValuePluralized")]
+    LanguagePackStringValuePluralized(Box<LanguagePackStringValuePluralized>),
+    /// Contains a list of language pack strings
+    #[serde(rename = "languagePackStrings")]
+    LanguagePackStrings(Box<LanguagePackStrings>),
+    /// Represents a local file
+    #[serde(rename = "localFile")]
+    LocalFile(Box<LocalFile>),
+    /// Contains information about the current localization target
+    #[serde(rename = "localizationTargetInfo")]
+    LocalizationTargetInfo(Box<LocalizationTargetInfo>),
+    /// Describes a location on planet Earth
+    #[serde(rename = "location")]
+    Location(Box<Location>),
+    /// The log is written to stderr or an OS specific log
+    #[serde(rename = "logStreamDefault")]
+    LogStreamDefault(Box<LogStreamDefault>),
+    /// The log is written nowhere
+    #[serde(rename = "logStreamEmpty")]
+    LogStreamEmpty(Box<LogStreamEmpty>),
+    /// The log is written to a file
+    #[serde(rename = "logStreamFile")]
+    LogStreamFile(Box<LogStreamFile>),
+    /// Contains a list of available TDLib internal log tags
+    #[serde(rename = "logTags")]
+    LogTags(Box<LogTags>),
+    /// Contains a TDLib internal log verbosity level
+    #[serde(rename = "logVerbosityLevel")]
+    LogVerbosityLevel(Box<LogVerbosityLevel>),
+    /// An HTTP url needs to be open
+    #[serde(rename = "loginUrlInfoOpen")]
+    LoginUrlInfoOpen(Box<LoginUrlInfoOpen>),
+    /// An authorization confirmation dialog needs to be shown to the user
+    #[serde(rename = "loginUrlInfoRequestConfirmation")]
+    LoginUrlInfoRequestConfirmation(Box<LoginUrlInfoRequestConfirmation>),
+    /// The mask is placed relatively to the chin
+    #[serde(rename = "maskPointChin")]
+    MaskPointChin(Box<MaskPointChin>),
+    /// The mask is placed relatively to the eyes
+    #[serde(rename = "maskPointEyes")]
+    MaskPointEyes(Box<MaskPointEyes>),
+    /// The mask is placed relatively to the forehead
+    #[serde(rename = "maskPointForehead")]
+    MaskPointForehead(Box<MaskPointForehead>),
+    /// The mask is placed relatively to the mouth
+    #[serde(rename = "maskPointMouth")]
+    MaskPointMouth(Box<MaskPointMouth>),
+    /// Position on a photo where a mask is placed
+    #[serde(rename = "maskPosition")]
+    MaskPosition(Box<MaskPosition>),
+    /// Describes a message
+    #[serde(rename = "message")]
+    Message(Box<Message>),
+    /// A message with an animated emoji
+    #[serde(rename = "messageAnimatedEmoji")]
+    MessageAnimatedEmoji(Box<MessageAnimatedEmoji>),
+    /// An animation message (GIF-style).
+    #[serde(rename = "messageAnimation")]
+    MessageAnimation(Box<MessageAnimation>),
+    /// An audio message
+    #[serde(rename = "messageAudio")]
+    MessageAudio(Box<MessageAudio>),
+    /// A newly created basic group
+    #[serde(rename = "messageBasicGroupChatCreate")]
+    MessageBasicGroupChatCreate(Box<MessageBasicGroupChatCreate>),
+    /// Contains information about found messages, split by days according to the option "utc_time_offset"
+    #[serde(rename = "messageCalendar")]
+    MessageCalendar(Box<MessageCalendar>),
+    /// Contains information about found messages sent on a specific day
+    #[serde(rename = "messageCalendarDay")]
+    MessageCalendarDay(Box<MessageCalendarDay>),
+    /// A message with information about an ended call
+    #[serde(rename = "messageCall")]
+    MessageCall(Box<MessageCall>),
+    /// New chat members were added
+    #[serde(rename = "messageChatAddMembers")]
+    MessageChatAddMembers(Box<MessageChatAddMembers>),
+    /// An updated chat photo
+    #[serde(rename = "messageChatChangePhoto")]
+    MessageChatChangePhoto(Box<MessageChatChangePhoto>),
+    /// An updated chat title
+    #[serde(rename = "messageChatChangeTitle")]
+    MessageChatChangeTitle(Box<MessageChatChangeTitle>),
+    /// A chat member was deleted
+    #[serde(rename = "messageChatDeleteMember")]
+    MessageChatDeleteMember(Box<MessageChatDeleteMember>),
+    /// A deleted chat photo
+    #[serde(rename = "messageChatDeletePhoto")]
+    MessageChatDeletePhoto(Box<MessageChatDeletePhoto>),
+    /// A new member joined the chat via an invite link
+    #[serde(rename = "messageChatJoinByLink")]
+    MessageChatJoinByLink(Box<MessageChatJoinByLink>),
+    /// A new member was accepted to the chat by an administrator
+    #[serde(rename = "messageChatJoinByRequest")]
+    MessageChatJoinByRequest(Box<MessageChatJoinByRequest>),
+    /// A theme in the chat has been changed
+    #[serde(rename = "messageChatSetTheme")]
+    MessageChatSetTheme(Box<MessageChatSetTheme>),
+    /// The TTL (Time To Live) setting for messages in the chat has been changed
+    #[serde(rename = "messageChatSetTtl")]
+    MessageChatSetTtl(Box<MessageChatSetTtl>),
+    /// A supergroup has been created from a basic group
+    #[serde(rename = "messageChatUpgradeFrom")]
+    MessageChatUpgradeFrom(Box<MessageChatUpgradeFrom>),
+    /// A basic group was upgraded to a supergroup and was deactivated as the result
+    #[serde(rename = "messageChatUpgradeTo")]
+    MessageChatUpgradeTo(Box<MessageChatUpgradeTo>),
+    /// A message with a user contact
+    #[serde(rename = "messageContact")]
+    MessageContact(Box<MessageContact>),
+    /// A contact has registered with Telegram
+    #[serde(rename = "messageContactRegistered")]
+    MessageContactRegistered(Box<MessageContactRegistered>),
+    /// Options to be used when a message content is copied without reference to the original sender. Service messages and messageInvoice can't be copied
+    #[serde(rename = "messageCopyOptions")]
+    MessageCopyOptions(Box<MessageCopyOptions>),
+    /// A non-standard action has happened in the chat
+    #[serde(rename = "messageCustomServiceAction")]
+    MessageCustomServiceAction(Box<MessageCustomServiceAction>),
+    /// A dice message. The dice value is randomly generated by the server
+    #[serde(rename = "messageDice")]
+    MessageDice(Box<MessageDice>),
+    /// A document message (general file)
+    #[serde(rename = "messageDocument")]
+    MessageDocument(Box<MessageDocument>),
+    /// An expired photo message (self-destructed after TTL has elapsed)
+    #[serde(rename = "messageExpiredPhoto")]
+    MessageExpiredPhoto(Box<MessageExpiredPhoto>),
+    /// An expired video message (self-destructed after TTL has elapsed)
+    #[serde(rename = "messageExpiredVideo")]
+    MessageExpiredVideo(Box<MessageExpiredVideo>),
+    /// The messages was exported from a group chat
+    #[serde(rename = "messageFileTypeGroup")]
+    MessageFileTypeGroup(Box<MessageFileTypeGroup>),
+    /// The messages was exported from a private chat
+    #[serde(rename = "messageFileTypePrivate")]
+    MessageFileTypePrivate(Box<MessageFileTypePrivate>),
+    /// The messages was exported from a chat of unknown type
+    #[serde(rename = "messageFileTypeUnknown")]
+    MessageFileTypeUnknown(Box<MessageFileTypeUnknown>),
+    /// Contains information about a forwarded message
+    #[serde(rename = "messageForwardInfo")]
+    MessageForwardInfo(Box<MessageForwardInfo>),
+    /// The message was originally a post in a channel
+    #[serde(rename = "messageForwardOriginChannel")]
+    MessageForwardOriginChannel(Box<MessageForwardOriginChannel>),
+    /// The message was originally sent on behalf of a chat
+    #[serde(rename = "messageForwardOriginChat")]
+    MessageForwardOriginChat(Box<MessageForwardOriginChat>),
+    /// The message was originally sent by a user, which is hidden by their privacy settings
+    #[serde(rename = "messageForwardOriginHiddenUser")]
+    MessageForwardOriginHiddenUser(Box<MessageForwardOriginHiddenUser>),
+    /// The message was imported from an exported message history
+    #[serde(rename = "messageForwardOriginMessageImport")]
+    MessageForwardOriginMessageImport(Box<MessageForwardOriginMessageImport>),
+    /// The message was originally sent by a known user
+    #[serde(rename = "messageForwardOriginUser")]
+    MessageForwardOriginUser(Box<MessageForwardOriginUser>),
+    /// A message with a game
+    #[serde(rename = "messageGame")]
+    MessageGame(Box<MessageGame>),
+    /// A new high score was achieved in a game
+    #[serde(rename = "messageGameScore")]
+    MessageGameScore(Box<MessageGameScore>),
+    /// Contains information about interactions with a message
+    #[serde(rename = "messageInteractionInfo")]
+    MessageInteractionInfo(Box<MessageInteractionInfo>),
+    /// A message with information about an invite to a video chat
+    #[serde(rename = "messageInviteVideoChatParticipants")]
+    MessageInviteVideoChatParticipants(Box<MessageInviteVideoChatParticipants>),
+    /// A message with an invoice from a bot
+    #[serde(rename = "messageInvoice")]
+    MessageInvoice(Box<MessageInvoice>),
+    /// Contains an HTTPS link to a message in a supergroup or channel
+    #[serde(rename = "messageLink")]
+    MessageLink(Box<MessageLink>),
+    /// Contains information about a link to a message in a chat
+    #[serde(rename = "messageLinkInfo")]
+    MessageLinkInfo(Box<MessageLinkInfo>),
+    /// A message with a location
+    #[serde(rename = "messageLocation")]
+    MessageLocation(Box<MessageLocation>),
+    /// Telegram Passport data has been received; for bots only
+    #[serde(rename = "messagePassportDataReceived")]
+    MessagePassportDataReceived(Box<MessagePassportDataReceived>),
+    /// Telegram Passport data has been sent
+    #[serde(rename = "messagePassportDataSent")]
+    MessagePassportDataSent(Box<MessagePassportDataSent>),
+    /// A payment has been completed
+    #[serde(rename = "messagePaymentSuccessful")]
+    MessagePaymentSuccessful(Box<MessagePaymentSuccessful>),
+    /// A payment has been completed; for bots only
+    #[serde(rename = "messagePaymentSuccessfulBot")]
+    MessagePaymentSuccessfulBot(Box<MessagePaymentSuccessfulBot>),
+    /// A photo message
+    #[serde(rename = "messagePhoto")]
+    MessagePhoto(Box<MessagePhoto>),
+    /// A message has been pinned
+    #[serde(rename = "messagePinMessage")]
+    MessagePinMessage(Box<MessagePinMessage>),
+    /// A message with a poll
+    #[serde(rename = "messagePoll")]
+    MessagePoll(Box<MessagePoll>),
+    /// Contains information about a message in a specific position
+    #[serde(rename = "messagePosition")]
+    MessagePosition(Box<MessagePosition>),
+    /// Contains a list of message positions
+    #[serde(rename = "messagePositions")]
+    MessagePositions(Box<MessagePositions>),
+    /// A user in the chat came within proximity alert range
+    #[serde(rename = "messageProximityAlertTriggered")]
+    MessageProximityAlertTriggered(Box<MessageProximityAlertTriggered>),
+    /// Contains information about replies to a message
+    #[serde(rename = "messageReplyInfo")]
+    MessageReplyInfo(Box<MessageReplyInfo>),
+    /// The message will be sent at the specified date
+    #[serde(rename = "messageSchedulingStateSendAtDate")]
+    MessageSchedulingStateSendAtDate(Box<MessageSchedulingStateSendAtDate>),
+    /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
+    #[serde(rename = "messageSchedulingStateSendWhenOnline")]
+    MessageSchedulingStateSendWhenOnline(Box<MessageSchedulingStateSendWhenOnline>),
+    /// A screenshot of a message in the chat has been taken
+    #[serde(rename = "messageScreenshotTaken")]
+    MessageScreenshotTaken(Box<MessageScreenshotTaken>),
+    /// Options to be used when a message is sent
+    #[serde(rename = "messageSendOptions")]
+    MessageSendOptions(Box<MessageSendOptions>),
+    /// The message was sent on behalf of a chat
+    #[serde(rename = "messageSenderChat")]
+    MessageSenderChat(Box<MessageSenderChat>),
+    /// The message was sent by a known user
+    #[serde(rename = "messageSenderUser")]
+    MessageSenderUser(Box<MessageSenderUser>),
+    /// Represents a list of message senders
+    #[serde(rename = "messageSenders")]
+    MessageSenders(Box<MessageSenders>),
+    /// The message failed to be sent
+    #[serde(rename = "messageSendingStateFailed")]
+    MessageSendingStateFailed(Box<MessageSendingStateFailed>),
+    /// The message is being sent now, but has not yet been delivered to the server
+    #[serde(rename = "messageSendingStatePending")]
+    MessageSendingStatePending(Box<MessageSendingStatePending>),
+    /// A detailed statistics about a message
+    #[serde(rename = "messageStatistics")]
+    MessageStatistics(Box<MessageStatistics>),
+    /// A sticker message
+    #[serde(rename = "messageSticker")]
+    MessageSticker(Box<MessageSticker>),
+    /// A newly created supergroup or channel
+    #[serde(rename = "messageSupergroupChatCreate")]
+    MessageSupergroupChatCreate(Box<MessageSupergroupChatCreate>),
+    /// A text message
+    #[serde(rename = "messageText")]
+    MessageText(Box<MessageText>),
+    /// Contains information about a message thread
+    #[serde(rename = "messageThreadInfo")]
+    MessageThreadInfo(Box<MessageThreadInfo>),
+    /// Message content that is not supported in the current TDLib version
+    #[serde(rename = "messageUnsupported")]
+    MessageUnsupported(Box<MessageUnsupported>),
+    /// A message with information about a venue
+    #[serde(rename = "messageVenue")]
+    MessageVenue(Box<MessageVenue>),
+    /// A video message
+    #[serde(rename = "messageVideo")]
+    MessageVideo(Box<MessageVideo>),
+    /// A message with information about an ended video chat
+    #[serde(rename = "messageVideoChatEnded")]
+    MessageVideoChatEnded(Box<MessageVideoChatEnded>),
+    /// A new video chat was scheduled
+    #[serde(rename = "messageVideoChatScheduled")]
+    MessageVideoChatScheduled(Box<MessageVideoChatScheduled>),
+    /// A newly created video chat
+    #[serde(rename = "messageVideoChatStarted")]
+    MessageVideoChatStarted(Box<MessageVideoChatStarted>),
+    /// A video note message
+    #[serde(rename = "messageVideoNote")]
+    MessageVideoNote(Box<MessageVideoNote>),
+    /// A voice note message
+    #[serde(rename = "messageVoiceNote")]
+    MessageVoiceNote(Box<MessageVoiceNote>),
+    /// The current user has connected a website by logging in using Telegram Login Widget on it
+    #[serde(rename = "messageWebsiteConnected")]
+    MessageWebsiteConnected(Box<MessageWebsiteConnected>),
+    /// Contains a list of messages
+    #[serde(rename = "messages")]
+    Messages(Box<Messages>),
+    /// Thumbnail image of a very poor quality and low resolution
+    #[serde(rename = "minithumbnail")]
+    Minithumbnail(Box<Minithumbnail>),
+    /// A full list of available network statistic entries
+    #[serde(rename = "networkStatistics")]
+    NetworkStatistics(Box<NetworkStatistics>),
+    /// Contains information about the total amount of data that was used for calls
+    #[serde(rename = "networkStatisticsEntryCall")]
+    NetworkStatisticsEntryCall(Box<NetworkStatisticsEntryCall>),
+    /// Contains information about the total amount of data that was used to send and receive files
+    #[serde(rename = "networkStatisticsEntryFile")]
+    NetworkStatisticsEntryFile(Box<NetworkStatisticsEntryFile>),
+    /// A mobile network
+    #[serde(rename = "networkTypeMobile")]
+    NetworkTypeMobile(Box<NetworkTypeMobile>),
+    /// A mobile roaming network
+    #[serde(rename = "networkTypeMobileRoaming")]
+    NetworkTypeMobileRoaming(Box<NetworkTypeMobileRoaming>),
+    /// The network is not available
+    #[serde(rename = "networkTypeNone")]
+    NetworkTypeNone(Box<NetworkTypeNone>),
+    /// A different network type (e.g., Ethernet network)
+    #[serde(rename = "networkTypeOther")]
+    NetworkTypeOther(Box<NetworkTypeOther>),
+    /// A Wi-Fi network
+    #[serde(rename = "networkTypeWiFi")]
+    NetworkTypeWiFi(Box<NetworkTypeWiFi>),
+    /// Contains information about a notification
+    #[serde(rename = "notification")]
+    Notification(Box<Notification>),
+    /// Describes a group of notifications
+    #[serde(rename = "notificationGroup")]
+    NotificationGroup(Box<NotificationGroup>),
+    /// A group containing notifications of type notificationTypeNewCall
+    #[serde(rename = "notificationGroupTypeCalls")]
+    NotificationGroupTypeCalls(Box<NotificationGroupTypeCalls>),
+    /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
+    #[serde(rename = "notificationGroupTypeMentions")]
+    NotificationGroupTypeMentions(Box<NotificationGroupTypeMentions>),
+    /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with ordinary unread messages
+    #[serde(rename = "notificationGroupTypeMessages")]
+    NotificationGroupTypeMessages(Box<NotificationGroupTypeMessages>),
+    /// A group containing a notification of type notificationTypeNewSecretChat
+    #[serde(rename = "notificationGroupTypeSecretChat")]
+    NotificationGroupTypeSecretChat(Box<NotificationGroupTypeSecretChat>),
+    /// Notification settings applied to all channels when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopeChannelChats")]
+    NotificationSettingsScopeChannelChats(Box<NotificationSettingsScopeChannelChats>),
+    /// Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopeGroupChats")]
+    NotificationSettingsScopeGroupChats(Box<NotificationSettingsScopeGroupChats>),
+    /// Notification settings applied to all private and secret chats when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopePrivateChats")]
+    NotificationSettingsScopePrivateChats(Box<NotificationSettingsScopePrivateChats>),
+    /// New call was received
+    #[serde(rename = "notificationTypeNewCall")]
+    NotificationTypeNewCall(Box<NotificationTypeNewCall>),
+    /// New message was received
+    #[serde(rename = "notificationTypeNewMessage")]
+    NotificationTypeNewMessage(Box<NotificationTypeNewMessage>),
+    /// New message was received through a push notification
+    #[serde(rename = "notificationTypeNewPushMessage")]
+    NotificationTypeNewPushMessage(Box<NotificationTypeNewPushMessage>),
+    /// New secret chat was created
+    #[serde(rename = "notificationTypeNewSecretChat")]
+    NotificationTypeNewSecretChat(Box<NotificationTypeNewSecretChat>),
+    /// An object of this type is returned on a successful function call for certain functions
+    #[serde(rename = "ok")]
+    Ok(Box<Ok>),
+    /// Represents a boolean option
+    #[serde(rename = "optionValueBoolean")]
+    OptionValueBoolean(Box<OptionValueBoolean>),
+    /// Represents an unknown option or an option which has a default value
+    #[serde(rename = "optionValueEmpty")]
+    OptionValueEmpty(Box<OptionValueEmpty>),
+    /// Represents an integer option
+    #[serde(rename = "optionValueInteger")]
+    OptionValueInteger(Box<OptionValueInteger>),
+    /// Represents a string option
+    #[serde(rename = "optionValueString")]
+    OptionValueString(Box<OptionValueString>),
+    /// Order information
+    #[serde(rename = "orderInfo")]
+    OrderInfo(Box<OrderInfo>),
+    /// An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor
+    #[serde(rename = "pageBlockAnchor")]
+    PageBlockAnchor(Box<PageBlockAnchor>),
+    /// An animation
+    #[serde(rename = "pageBlockAnimation")]
+    PageBlockAnimation(Box<PageBlockAnimation>),
+    /// An audio file
+    #[serde(rename = "pageBlockAudio")]
+    PageBlockAudio(Box<PageBlockAudio>),
+    /// The author and publishing date of a page
+    #[serde(rename = "pageBlockAuthorDate")]
+    PageBlockAuthorDate(Box<PageBlockAuthorDate>),
+    /// A block quote
+    #[serde(rename = "pageBlockBlockQuote")]
+    PageBlockBlockQuote(Box<PageBlockBlockQuote>),
+    /// Contains a caption of an instant view web page block, consisting of a text and a trailing credit
+    #[serde(rename = "pageBlockCaption")]
+    PageBlockCaption(Box<PageBlockCaption>),
+    /// A link to a chat
+    #[serde(rename = "pageBlockChatLink")]
+    PageBlockChatLink(Box<PageBlockChatLink>),
+    /// A collage
+    #[serde(rename = "pageBlockCollage")]
+    PageBlockCollage(Box<PageBlockCollage>),
+    /// A page cover
+    #[serde(rename = "pageBlockCover")]
+    PageBlockCover(Box<PageBlockCover>),
+    /// A collapsible block
+    #[serde(rename = "pageBlockDetails")]
+    PageBlockDetails(Box<PageBlockDetails>),
+    /// An empty block separating a page
+    #[serde(rename = "pageBlockDivider")]
+    PageBlockDivider(Box<PageBlockDivider>),
+    /// An embedded web page
+    #[serde(rename = "pageBlockEmbedded")]
+    PageBlockEmbedded(Box<PageBlockEmbedded>),
+    /// An embedded post
+    #[serde(rename = "pageBlockEmbeddedPost")]
+    PageBlockEmbeddedPost(Box<PageBlockEmbeddedPost>),
+    /// The footer of a page
+    #[serde(rename = "pageBlockFooter")]
+    PageBlockFooter(Box<PageBlockFooter>),
+    /// A header
+    #[serde(rename = "pageBlockHeader")]
+    PageBlockHeader(Box<PageBlockHeader>),
+    /// The content must be center-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentCenter")]
+    PageBlockHorizontalAlignmentCenter(Box<PageBlockHorizontalAlignmentCenter>),
+    /// The content must be left-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentLeft")]
+    PageBlockHorizontalAlignmentLeft(Box<PageBlockHorizontalAlignmentLeft>),
+    /// The content must be right-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentRight")]
+    PageBlockHorizontalAlignmentRight(Box<PageBlockHorizontalAlignmentRight>),
+    /// A kicker
+    #[serde(rename = "pageBlockKicker")]
+    PageBlockKicker(Box<PageBlockKicker>),
+    /// A list of data blocks
+    #[serde(rename = "pageBlockList")]
+    PageBlockList(Box<PageBlockList>),
+    /// Describes an item of a list page block
+    #[serde(rename = "pageBlockListItem")]
+    PageBlockListItem(Box<PageBlockListItem>),
+    /// A map
+    #[serde(rename = "pageBlockMap")]
+    PageBlockMap(Box<PageBlockMap>),
+    /// A text paragraph
+    #[serde(rename = "pageBlockParagraph")]
+    PageBlockParagraph(Box<PageBlockParagraph>),
+    /// A photo
+    #[serde(rename = "pageBlockPhoto")]
+    PageBlockPhoto(Box<PageBlockPhoto>),
+    /// A preformatted text paragraph
+    #[serde(rename = "pageBlockPreformatted")]
+    PageBlockPreformatted(Box<PageBlockPreformatted>),
+    /// A pull quote
+    #[serde(rename = "pageBlockPullQuote")]
+    PageBlockPullQuote(Box<PageBlockPullQuote>),
+    /// Contains information about a related article
+    #[serde(rename = "pageBlockRelatedArticle")]
+    PageBlockRelatedArticle(Box<PageBlockRelatedArticle>),
+    /// Related articles
+    #[serde(rename = "pageBlockRelatedArticles")]
+    PageBlockRelatedArticles(Box<PageBlockRelatedArticles>),
+    /// A slideshow
+    #[serde(rename = "pageBlockSlideshow")]
+    PageBlockSlideshow(Box<PageBlockSlideshow>),
+    /// A subheader
+    #[serde(rename = "pageBlockSubheader")]
+    PageBlockSubheader(Box<PageBlockSubheader>),
+    /// The subtitle of a page
+    #[serde(rename = "pageBlockSubtitle")]
+    PageBlockSubtitle(Box<PageBlockSubtitle>),
+    /// A table
+    #[serde(rename = "pageBlockTable")]
+    PageBlockTable(Box<PageBlockTable>),
+    /// Represents a cell of a table
+    #[serde(rename = "pageBlockTableCell")]
+    PageBlockTableCell(Box<PageBlockTableCell>),
+    /// The title of a page
+    #[serde(rename = "pageBlockTitle")]
+    PageBlockTitle(Box<PageBlockTitle>),
+    /// The content must be bottom-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentBottom")]
+    PageBlockVerticalAlignmentBottom(Box<PageBlockVerticalAlignmentBottom>),
+    /// The content must be middle-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentMiddle")]
+    PageBlockVerticalAlignmentMiddle(Box<PageBlockVerticalAlignmentMiddle>),
+    /// The content must be top-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentTop")]
+    PageBlockVerticalAlignmentTop(Box<PageBlockVerticalAlignmentTop>),
+    /// A video
+    #[serde(rename = "pageBlockVideo")]
+    PageBlockVideo(Box<PageBlockVideo>),
+    /// A voice note
+    #[serde(rename = "pageBlockVoiceNote")]
+    PageBlockVoiceNote(Box<PageBlockVoiceNote>),
+    /// Contains information about a Telegram Passport authorization form that was requested
+    #[serde(rename = "passportAuthorizationForm")]
+    PassportAuthorizationForm(Box<PassportAuthorizationForm>),
+    /// A Telegram Passport element containing the user's address
+    #[serde(rename = "passportElementAddress")]
+    PassportElementAddress(Box<PassportElementAddress>),
+    /// A Telegram Passport element containing the user's bank statement
+    #[serde(rename = "passportElementBankStatement")]
+    PassportElementBankStatement(Box<PassportElementBankStatement>),
+    /// A Telegram Passport element containing the user's driver license
+    #[serde(rename = "passportElementDriverLicense")]
+    PassportElementDriverLicense(Box<PassportElementDriverLicense>),
+    /// A Telegram Passport element containing the user's email address
+    #[serde(rename = "passportElementEmailAddress")]
+    PassportElementEmailAddress(Box<PassportElementEmailAddress>),
+    /// Contains the description of an error in a Telegram Passport element
+    #[serde(rename = "passportElementError")]
+    PassportElementError(Box<PassportElementError>),
+    /// One of the data fields contains an error. The error will be considered resolved when the value of the field changes
+    #[serde(rename = "passportElementErrorSourceDataField")]
+    PassportElementErrorSourceDataField(Box<PassportElementErrorSourceDataField>),
+    /// The file contains an error. The error will be considered resolved when the file changes
+    #[serde(rename = "passportElementErrorSourceFile")]
+    PassportElementErrorSourceFile(Box<PassportElementErrorSourceFile>),
+    /// The list of attached files contains an error. The error will be considered resolved when the list of files changes
+    #[serde(rename = "passportElementErrorSourceFiles")]
+    PassportElementErrorSourceFiles(Box<PassportElementErrorSourceFiles>),
+    /// The front side of the document contains an error. The error will be considered resolved when the file with the front side changes
+    #[serde(rename = "passportElementErrorSourceFrontSide")]
+    PassportElementErrorSourceFrontSide(Box<PassportElementErrorSourceFrontSide>),
+    /// The reverse side of the document contains an error. The error will be considered resolved when the file with the reverse side changes
+    #[serde(rename = "passportElementErrorSourceReverseSide")]
+    PassportElementErrorSourceReverseSide(Box<PassportElementErrorSourceReverseSide>),
+    /// The selfie with the document contains an error. The error will be considered resolved when the file with the selfie changes
+    #[serde(rename = "passportElementErrorSourceSelfie")]
+    PassportElementErrorSourceSelfie(Box<PassportElementErrorSourceSelfie>),
+    /// One of files with the translation of the document contains an error. The error will be considered resolved when the file changes
+    #[serde(rename = "passportElementErrorSourceTranslationFile")]
+    PassportElementErrorSourceTranslationFile(Box<PassportElementErrorSourceTranslationFile>),
+    /// The translation of the document contains an error. The error will be considered resolved when the list of translation files changes
+    #[serde(rename = "passportElementErrorSourceTranslationFiles")]
+    PassportElementErrorSourceTranslationFiles(Box<PassportElementErrorSourceTranslationFiles>),
+    /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
+    #[serde(rename = "passportElementErrorSourceUnspecified")]
+    PassportElementErrorSourceUnspecified(Box<PassportElementErrorSourceUnspecified>),
+    /// A Telegram Passport element containing the user's identity card
+    #[serde(rename = "passportElementIdentityCard")]
+    PassportElementIdentityCard(Box<PassportElementIdentityCard>),
+    /// A Telegram Passport element containing the user's internal passport
+    #[serde(rename = "passportElementInternalPassport")]
+    PassportElementInternalPassport(Box<PassportElementInternalPassport>),
+    /// A Telegram Passport element containing the user's passport
+    #[serde(rename = "passportElementPassport")]
+    PassportElementPassport(Box<PassportElementPassport>),
+    /// A Telegram Passport element containing the user's passport registration pages
+    #[serde(rename = "passportElementPassportRegistration")]
+    PassportElementPassportRegistration(Box<PassportElementPassportRegistration>),
+    /// A Telegram Passport element containing the user's personal details
+    #[serde(rename = "passportElementPersonalDetails")]
+    PassportElementPersonalDetails(Box<PassportElementPersonalDetails>),
+    /// A Telegram Passport element containing the user's phone number
+    #[serde(rename = "passportElementPhoneNumber")]
+    PassportElementPhoneNumber(Box<PassportElementPhoneNumber>),
+    /// A Telegram Passport element containing the user's rental agreement
+    #[serde(rename = "passportElementRentalAgreement")]
+    PassportElementRentalAgreement(Box<PassportElementRentalAgreement>),
+    /// A Telegram Passport element containing the user's temporary registration
+    #[serde(rename = "passportElementTemporaryRegistration")]
+    PassportElementTemporaryRegistration(Box<PassportElementTemporaryRegistration>),
+    /// A Telegram Passport element containing the user's address
+    #[serde(rename = "passportElementTypeAddress")]
+    PassportElementTypeAddress(Box<PassportElementTypeAddress>),
+    /// A Telegram Passport element containing the user's bank statement
+    #[serde(rename = "passportElementTypeBankStatement")]
+    PassportElementTypeBankStatement(Box<PassportElementTypeBankStatement>),
+    /// A Telegram Passport element containing the user's driver license
+    #[serde(rename = "passportElementTypeDriverLicense")]
+    PassportElementTypeDriverLicense(Box<PassportElementTypeDriverLicense>),
+    /// A Telegram Passport element containing the user's email address
+    #[serde(rename = "passportElementTypeEmailAddress")]
+    PassportElementTypeEmailAddress(Box<PassportElementTypeEmailAddress>),
+    /// A Telegram Passport element containing the user's identity card
+    #[serde(rename = "passportElementTypeIdentityCard")]
+    PassportElementTypeIdentityCard(Box<PassportElementTypeIdentityCard>),
+    /// A Telegram Passport element containing the user's internal passport
+    #[serde(rename = "passportElementTypeInternalPassport")]
+    PassportElementTypeInternalPassport(Box<PassportElementTypeInternalPassport>),
+    /// A Telegram Passport element containing the user's passport
+    #[serde(rename = "passportElementTypePassport")]
+    PassportElementTypePassport(Box<PassportElementTypePassport>),
+    /// A Telegram Passport element containing the registration page of the user's passport
+    #[serde(rename = "passportElementTypePassportRegistration")]
+    PassportElementTypePassportRegistration(Box<PassportElementTypePassportRegistration>),
+    /// A Telegram Passport element containing the user's personal details
+    #[serde(rename = "passportElementTypePersonalDetails")]
+    PassportElementTypePersonalDetails(Box<PassportElementTypePersonalDetails>),
+    /// A Telegram Passport element containing the user's phone number
+    #[serde(rename = "passportElementTypePhoneNumber")]
+    PassportElementTypePhoneNumber(Box<PassportElementTypePhoneNumber>),
+    /// A Telegram Passport element containing the user's rental agreement
+    #[serde(rename = "passportElementTypeRentalAgreement")]
+    PassportElementTypeRentalAgreement(Box<PassportElementTypeRentalAgreement>),
+    /// A Telegram Passport element containing the user's temporary registration
+    #[serde(rename = "passportElementTypeTemporaryRegistration")]
+    PassportElementTypeTemporaryRegistration(Box<PassportElementTypeTemporaryRegistration>),
+    /// A Telegram Passport element containing the user's utility bill
+    #[serde(rename = "passportElementTypeUtilityBill")]
+    PassportElementTypeUtilityBill(Box<PassportElementTypeUtilityBill>),
+    /// A Telegram Passport element containing the user's utility bill
+    #[serde(rename = "passportElementUtilityBill")]
+    PassportElementUtilityBill(Box<PassportElementUtilityBill>),
+    /// Contains information about saved Telegram Passport elements
+    #[serde(rename = "passportElements")]
+    PassportElements(Box<PassportElements>),
+    /// Contains information about a Telegram Passport elements and corresponding errors
+    #[serde(rename = "passportElementsWithErrors")]
+    PassportElementsWithErrors(Box<PassportElementsWithErrors>),
+    /// Contains a description of the required Telegram Passport element that was requested by a service
+    #[serde(rename = "passportRequiredElement")]
+    PassportRequiredElement(Box<PassportRequiredElement>),
+    /// Contains information about a Telegram Passport element that was requested by a service
+    #[serde(rename = "passportSuitableElement")]
+    PassportSuitableElement(Box<PassportSuitableElement>),
+    /// Represents the current state of 2-step verification
+    #[serde(rename = "passwordState")]
+    PasswordState(Box<PasswordState>),
+    /// Contains information about an invoice payment form
+    #[serde(rename = "paymentForm")]
+    PaymentForm(Box<PaymentForm>),
+    /// Theme colors for a payment form
+    #[serde(rename = "paymentFormTheme")]
+    PaymentFormTheme(Box<PaymentFormTheme>),
+    /// Contains information about a successful payment
+    #[serde(rename = "paymentReceipt")]
+    PaymentReceipt(Box<PaymentReceipt>),
+    /// Contains the result of a payment request
+    #[serde(rename = "paymentResult")]
+    PaymentResult(Box<PaymentResult>),
+    /// Stripe payment provider
+    #[serde(rename = "paymentsProviderStripe")]
+    PaymentsProviderStripe(Box<PaymentsProviderStripe>),
+    /// Contains the user's personal details
+    #[serde(rename = "personalDetails")]
+    PersonalDetails(Box<PersonalDetails>),
+    /// A personal document, containing some information about a user
+    #[serde(rename = "personalDocument")]
+    PersonalDocument(Box<PersonalDocument>),
+    /// Contains settings for the authentication of the user's phone number
+    #[serde(rename = "phoneNumberAuthenticationSettings")]
+    PhoneNumberAuthenticationSettings(Box<PhoneNumberAuthenticationSettings>),
+    /// Contains information about a phone number
+    #[serde(rename = "phoneNumberInfo")]
+    PhoneNumberInfo(Box<PhoneNumberInfo>),
+    /// Describes a photo
+    #[serde(rename = "photo")]
+    Photo(Box<Photo>),
+    /// Describes an image in JPEG format
+    #[serde(rename = "photoSize")]
+    PhotoSize(Box<PhotoSize>),
+    /// A point on a Cartesian plane
+    #[serde(rename = "point")]
+    Point(Box<Point>),
+    /// Describes a poll
+    #[serde(rename = "poll")]
+    Poll(Box<Poll>),
+    /// Describes one answer option of a poll
+    #[serde(rename = "pollOption")]
+    PollOption(Box<PollOption>),
+    /// A poll in quiz mode, which has exactly one correct answer option and can be answered only once
+    #[serde(rename = "pollTypeQuiz")]
+    PollTypeQuiz(Box<PollTypeQuiz>),
+    /// A regular poll
+    #[serde(rename = "pollTypeRegular")]
+    PollTypeRegular(Box<PollTypeRegular>),
+    /// Describes a user profile photo
+    #[serde(rename = "profilePhoto")]
+    ProfilePhoto(Box<ProfilePhoto>),
+    /// Represents a list of proxy servers
+    #[serde(rename = "proxies")]
+    Proxies(Box<Proxies>),
+    /// Contains information about a proxy server
+    #[serde(rename = "proxy")]
+    Proxy(Box<Proxy>),
+    /// A HTTP transparent proxy server
+    #[serde(rename = "proxyTypeHttp")]
+    ProxyTypeHttp(Box<ProxyTypeHttp>),
+    /// An MTProto proxy server
+    #[serde(rename = "proxyTypeMtproto")]
+    ProxyTypeMtproto(Box<ProxyTypeMtproto>),
+    /// A SOCKS5 proxy server
+    #[serde(rename = "proxyTypeSocks5")]
+    ProxyTypeSocks5(Box<ProxyTypeSocks5>),
+    /// The chat is public, because it has username
+    #[serde(rename = "publicChatTypeHasUsername")]
+    PublicChatTypeHasUsername(Box<PublicChatTypeHasUsername>),
+    /// The chat is public, because it is a location-based supergroup
+    #[serde(rename = "publicChatTypeIsLocationBased")]
+    PublicChatTypeIsLocationBased(Box<PublicChatTypeIsLocationBased>),
+    /// An animation message (GIF-style).
+    #[serde(rename = "pushMessageContentAnimation")]
+    PushMessageContentAnimation(Box<PushMessageContentAnimation>),
+    /// An audio message
+    #[serde(rename = "pushMessageContentAudio")]
+    PushMessageContentAudio(Box<PushMessageContentAudio>),
+    /// A newly created basic group
+    #[serde(rename = "pushMessageContentBasicGroupChatCreate")]
+    PushMessageContentBasicGroupChatCreate(Box<PushMessageContentBasicGroupChatCreate>),
+    /// New chat members were invited to a group
+    #[serde(rename = "pushMessageContentChatAddMembers")]
+    PushMessageContentChatAddMembers(Box<PushMessageContentChatAddMembers>),
+    /// A chat photo was edited
+    #[serde(rename = "pushMessageContentChatChangePhoto")]
+    PushMessageContentChatChangePhoto(Box<PushMessageContentChatChangePhoto>),
+    /// A chat title was edited
+    #[serde(rename = "pushMessageContentChatChangeTitle")]
+    PushMessageContentChatChangeTitle(Box<PushMessageContentChatChangeTitle>),
+    /// A chat member was deleted
+    #[serde(rename = "pushMessageContentChatDeleteMember")]
+    PushMessageContentChatDeleteMember(Box<PushMessageContentChatDeleteMember>),
+    /// A new member joined the chat via an invite link
+    #[serde(rename = "pushMessageContentChatJoinByLink")]
+    PushMessageContentChatJoinByLink(Box<PushMessageContentChatJoinByLink>),
+    /// A new member was accepted to the chat by an administrator
+    #[serde(rename = "pushMessageContentChatJoinByRequest")]
+    PushMessageContentChatJoinByRequest(Box<PushMessageContentChatJoinByRequest>),
+    /// A chat theme was edited
+    #[serde(rename = "pushMessageContentChatSetTheme")]
+    PushMessageContentChatSetTheme(Box<PushMessageContentChatSetTheme>),
+    /// A message with a user contact
+    #[serde(rename = "pushMessageContentContact")]
+    PushMessageContentContact(Box<PushMessageContentContact>),
+    /// A contact has registered with Telegram
+    #[serde(rename = "pushMessageContentContactRegistered")]
+    PushMessageContentContactRegistered(Box<PushMessageContentContactRegistered>),
+    /// A document message (a general file)
+    #[serde(rename = "pushMessageContentDocument")]
+    PushMessageContentDocument(Box<PushMessageContentDocument>),
+    /// A message with a game
+    #[serde(rename = "pushMessageContentGame")]
+    PushMessageContentGame(Box<PushMessageContentGame>),
+    /// A new high score was achieved in a game
+    #[serde(rename = "pushMessageContentGameScore")]
+    PushMessageContentGameScore(Box<PushMessageContentGameScore>),
+    /// A general message with hidden content
+    #[serde(rename = "pushMessageContentHidden")]
+    PushMessageContentHidden(Box<PushMessageContentHidden>),
+    /// A message with an invoice from a bot
+    #[serde(rename = "pushMessageContentInvoice")]
+    PushMessageContentInvoice(Box<PushMessageContentInvoice>),
+    /// A message with a location
+    #[serde(rename = "pushMessageContentLocation")]
+    PushMessageContentLocation(Box<PushMessageContentLocation>),
+    /// A media album
+    #[serde(rename = "pushMessageContentMediaAlbum")]
+    PushMessageContentMediaAlbum(Box<PushMessageContentMediaAlbum>),
+    /// A forwarded messages
+    #[serde(rename = "pushMessageContentMessageForwards")]
+    PushMessageContentMessageForwards(Box<PushMessageContentMessageForwards>),
+    /// A photo message
+    #[serde(rename = "pushMessageContentPhoto")]
+    PushMessageContentPhoto(Box<PushMessageContentPhoto>),
+    /// A message with a poll
+    #[serde(rename = "pushMessageContentPoll")]
+    PushMessageContentPoll(Box<PushMessageContentPoll>),
+    /// A screenshot of a message in the chat has been taken
+    #[serde(rename = "pushMessageContentScreenshotTaken")]
+    PushMessageContentScreenshotTaken(Box<PushMessageContentScreenshotTaken>),
+    /// A message with a sticker
+    #[serde(rename = "pushMessageContentSticker")]
+    PushMessageContentSticker(Box<PushMessageContentSticker>),
+    /// A text message
+    #[serde(rename = "pushMessageContentText")]
+    PushMessageContentText(Box<PushMessageContentText>),
+    /// A video message
+    #[serde(rename = "pushMessageContentVideo")]
+    PushMessageContentVideo(Box<PushMessageContentVideo>),
+    /// A video note message
+    #[serde(rename = "pushMessageContentVideoNote")]
+    PushMessageContentVideoNote(Box<PushMessageContentVideoNote>),
+    /// A voice note message
+    #[serde(rename = "pushMessageContentVoiceNote")]
+    PushMessageContentVoiceNote(Box<PushMessageContentVoiceNote>),
+    /// Contains a globally unique push receiver identifier, which can be used to identify which account has received a push notification
+    #[serde(rename = "pushReceiverId")]
+    PushReceiverId(Box<PushReceiverId>),
+    /// Describes a recommended chat filter
+    #[serde(rename = "recommendedChatFilter")]
+    RecommendedChatFilter(Box<RecommendedChatFilter>),
+    /// Contains a list of recommended chat filters
+    #[serde(rename = "recommendedChatFilters")]
+    RecommendedChatFilters(Box<RecommendedChatFilters>),
+    /// Contains information about the current recovery email address
+    #[serde(rename = "recoveryEmailAddress")]
+    RecoveryEmailAddress(Box<RecoveryEmailAddress>),
+    /// Represents a remote file
+    #[serde(rename = "remoteFile")]
+    RemoteFile(Box<RemoteFile>),
+    /// Instructs application to force a reply to this message
+    #[serde(rename = "replyMarkupForceReply")]
+    ReplyMarkupForceReply(Box<ReplyMarkupForceReply>),
+    /// Contains an inline keyboard layout
+    #[serde(rename = "replyMarkupInlineKeyboard")]
+    ReplyMarkupInlineKeyboard(Box<ReplyMarkupInlineKeyboard>),
+    /// Instructs application to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent
+    #[serde(rename = "replyMarkupRemoveKeyboard")]
+    ReplyMarkupRemoveKeyboard(Box<ReplyMarkupRemoveKeyboard>),
+    /// Contains a custom keyboard layout to quickly reply to bots
+    #[serde(rename = "replyMarkupShowKeyboard")]
+    ReplyMarkupShowKeyboard(Box<ReplyMarkupShowKeyboard>),
+    /// The password reset request was declined
+    #[serde(rename = "resetPasswordResultDeclined")]
+    ResetPasswordResultDeclined(Box<ResetPasswordResultDeclined>),
+    /// The password was reset
+    #[serde(rename = "resetPasswordResultOk")]
+    ResetPasswordResultOk(Box<ResetPasswordResultOk>),
+    /// The password reset request is pending
+    #[serde(rename = "resetPasswordResultPending")]
+    ResetPasswordResultPending(Box<ResetPasswordResultPending>),
+    /// An anchor
+    #[serde(rename = "richTextAnchor")]
+    RichTextAnchor(Box<RichTextAnchor>),
+    /// A link to an anchor on the same web page
+    #[serde(rename = "richTextAnchorLink")]
+    RichTextAnchorLink(Box<RichTextAnchorLink>),
+    /// A bold rich text
+    #[serde(rename = "richTextBold")]
+    RichTextBold(Box<RichTextBold>),
+    /// A rich text email link
+    #[serde(rename = "richTextEmailAddress")]
+    RichTextEmailAddress(Box<RichTextEmailAddress>),
+    /// A fixed-width rich text
+    #[serde(rename = "richTextFixed")]
+    RichTextFixed(Box<RichTextFixed>),
+    /// A small image inside the text
+    #[serde(rename = "richTextIcon")]
+    RichTextIcon(Box<RichTextIcon>),
+    /// An italicized rich text
+    #[serde(rename = "richTextItalic")]
+    RichTextItalic(Box<RichTextItalic>),
+    /// A marked rich text
+    #[serde(rename = "richTextMarked")]
+    RichTextMarked(Box<RichTextMarked>),
+    /// A rich text phone number
+    #[serde(rename = "richTextPhoneNumber")]
+    RichTextPhoneNumber(Box<RichTextPhoneNumber>),
+    /// A plain text
+    #[serde(rename = "richTextPlain")]
+    RichTextPlain(Box<RichTextPlain>),
+    /// A reference to a richTexts object on the same web page
+    #[serde(rename = "richTextReference")]
+    RichTextReference(Box<RichTextReference>),
+    /// A strikethrough rich text
+    #[serde(rename = "richTextStrikethrough")]
+    RichTextStrikethrough(Box<RichTextStrikethrough>),
+    /// A subscript rich text
+    #[serde(rename = "richTextSubscript")]
+    RichTextSubscript(Box<RichTextSubscript>),
+    /// A superscript rich text
+    #[serde(rename = "richTextSuperscript")]
+    RichTextSuperscript(Box<RichTextSuperscript>),
+    /// An underlined rich text
+    #[serde(rename = "richTextUnderline")]
+    RichTextUnderline(Box<RichTextUnderline>),
+    /// A rich text URL link
+    #[serde(rename = "richTextUrl")]
+    RichTextUrl(Box<RichTextUrl>),
+    /// A concatenation of rich texts
+    #[serde(rename = "richTexts")]
+    RichTexts(Box<RichTexts>),
+    /// Contains information about saved card credentials
+    #[serde(rename = "savedCredentials")]
+    SavedCredentials(Box<SavedCredentials>),
+    /// Contains information about notification settings for several chats
+    #[serde(rename = "scopeNotificationSettings")]
+    ScopeNotificationSettings(Box<ScopeNotificationSettings>),
+    /// Returns only animation messages
+    #[serde(rename = "searchMessagesFilterAnimation")]
+    SearchMessagesFilterAnimation(Box<SearchMessagesFilterAnimation>),
+    /// Returns only audio messages
+    #[serde(rename = "searchMessagesFilterAudio")]
+    SearchMessagesFilterAudio(Box<SearchMessagesFilterAudio>),
+    /// Returns only messages containing chat photos
+    #[serde(rename = "searchMessagesFilterChatPhoto")]
+    SearchMessagesFilterChatPhoto(Box<SearchMessagesFilterChatPhoto>),
+    /// Returns only document messages
+    #[serde(rename = "searchMessagesFilterDocument")]
+    SearchMessagesFilterDocument(Box<SearchMessagesFilterDocument>),
+    /// Returns all found messages, no filter is applied
+    #[serde(rename = "searchMessagesFilterEmpty")]
+    SearchMessagesFilterEmpty(Box<SearchMessagesFilterEmpty>),
+    /// Returns only failed to send messages. This filter can be used only if the message database is used
+    #[serde(rename = "searchMessagesFilterFailedToSend")]
+    SearchMessagesFilterFailedToSend(Box<SearchMessagesFilterFailedToSend>),
+    /// Returns only messages with mentions of the current user, or messages that are replies to their messages
+    #[serde(rename = "searchMessagesFilterMention")]
+    SearchMessagesFilterMention(Box<SearchMessagesFilterMention>),
+    /// Returns only photo messages
+    #[serde(rename = "searchMessagesFilterPhoto")]
+    SearchMessagesFilterPhoto(Box<SearchMessagesFilterPhoto>),
+    /// Returns only photo and video messages
+    #[serde(rename = "searchMessagesFilterPhotoAndVideo")]
+    SearchMessagesFilterPhotoAndVideo(Box<SearchMessagesFilterPhotoAndVideo>),
+    /// Returns only pinned messages
+    #[serde(rename = "searchMessagesFilterPinned")]
+    SearchMessagesFilterPinned(Box<SearchMessagesFilterPinned>),
+    /// Returns only messages with unread mentions of the current user, or messages that are replies to their messages. When using this filter the results can't be additionally filtered by a query, a message thread or by the sending user
+    #[serde(rename = "searchMessagesFilterUnreadMention")]
+    SearchMessagesFilterUnreadMention(Box<SearchMessagesFilterUnreadMention>),
+    /// Returns only messages containing URLs
+    #[serde(rename = "searchMessagesFilterUrl")]
+    SearchMessagesFilterUrl(Box<SearchMessagesFilterUrl>),
+    /// Returns only video messages
+    #[serde(rename = "searchMessagesFilterVideo")]
+    SearchMessagesFilterVideo(Box<SearchMessagesFilterVideo>),
+    /// Returns only video note messages
+    #[serde(rename = "searchMessagesFilterVideoNote")]
+    SearchMessagesFilterVideoNote(Box<SearchMessagesFilterVideoNote>),
+    /// Returns only voice and video note messages
+    #[serde(rename = "searchMessagesFilterVoiceAndVideoNote")]
+    SearchMessagesFilterVoiceAndVideoNote(Box<SearchMessagesFilterVoiceAndVideoNote>),
+    /// Returns only voice note messages
+    #[serde(rename = "searchMessagesFilterVoiceNote")]
+    SearchMessagesFilterVoiceNote(Box<SearchMessagesFilterVoiceNote>),
+    /// Contains a value representing a number of seconds
+    #[serde(rename = "seconds")]
+    Seconds(Box<Seconds>),
+    /// Represents a secret chat
+    #[serde(rename = "secretChat")]
+    SecretChat(Box<SecretChat>),
+    /// The secret chat is closed
+    #[serde(rename = "secretChatStateClosed")]
+    SecretChatStateClosed(Box<SecretChatStateClosed>),
+    /// The secret chat is not yet created; waiting for the other user to get online
+    #[serde(rename = "secretChatStatePending")]
+    SecretChatStatePending(Box<SecretChatStatePending>),
+    /// The secret chat is ready to use
+    #[serde(rename = "secretChatStateReady")]
+    SecretChatStateReady(Box<SecretChatStateReady>),
+    /// Contains information about one session in a Telegram application used by the current user. Sessions must be shown to the user in the returned order
+    #[serde(rename = "session")]
+    Session(Box<Session>),
+    /// Contains a list of sessions
+    #[serde(rename = "sessions")]
+    Sessions(Box<Sessions>),
+    /// One shipping option
+    #[serde(rename = "shippingOption")]
+    ShippingOption(Box<ShippingOption>),
+    /// Describes a sponsored message
+    #[serde(rename = "sponsoredMessage")]
+    SponsoredMessage(Box<SponsoredMessage>),
+    /// The graph data to be asynchronously loaded through getStatisticalGraph
+    #[serde(rename = "statisticalGraphAsync")]
+    StatisticalGraphAsync(Box<StatisticalGraphAsync>),
+    /// A graph data
+    #[serde(rename = "statisticalGraphData")]
+    StatisticalGraphData(Box<StatisticalGraphData>),
+    /// An error message to be shown to the user instead of the graph
+    #[serde(rename = "statisticalGraphError")]
+    StatisticalGraphError(Box<StatisticalGraphError>),
+    /// A value with information about its recent changes
+    #[serde(rename = "statisticalValue")]
+    StatisticalValue(Box<StatisticalValue>),
+    /// Describes a sticker
+    #[serde(rename = "sticker")]
+    Sticker(Box<Sticker>),
+    /// Represents a sticker set
+    #[serde(rename = "stickerSet")]
+    StickerSet(Box<StickerSet>),
+    /// Represents short information about a sticker set
+    #[serde(rename = "stickerSetInfo")]
+    StickerSetInfo(Box<StickerSetInfo>),
+    /// Represents a list of sticker sets
+    #[serde(rename = "stickerSets")]
+    StickerSets(Box<StickerSets>),
+    /// Represents a list of stickers
+    #[serde(rename = "stickers")]
+    Stickers(Box<Stickers>),
+    /// Contains the exact storage usage statistics split by chats and file type
+    #[serde(rename = "storageStatistics")]
+    StorageStatistics(Box<StorageStatistics>),
+    /// Contains the storage usage statistics for a specific chat
+    #[serde(rename = "storageStatisticsByChat")]
+    StorageStatisticsByChat(Box<StorageStatisticsByChat>),
+    /// Contains the storage usage statistics for a specific file type
+    #[serde(rename = "storageStatisticsByFileType")]
+    StorageStatisticsByFileType(Box<StorageStatisticsByFileType>),
+    /// Contains approximate storage usage statistics, excluding files of unknown file type
+    #[serde(rename = "storageStatisticsFast")]
+    StorageStatisticsFast(Box<StorageStatisticsFast>),
+    /// Suggests the user to check whether they still remember their 2-step verification password
+    #[serde(rename = "suggestedActionCheckPassword")]
+    SuggestedActionCheckPassword(Box<SuggestedActionCheckPassword>),
+    /// Suggests the user to check whether authorization phone number is correct and change the phone number if it is inaccessible
+    #[serde(rename = "suggestedActionCheckPhoneNumber")]
+    SuggestedActionCheckPhoneNumber(Box<SuggestedActionCheckPhoneNumber>),
+    /// Suggests the user to convert specified supergroup to a broadcast group
+    #[serde(rename = "suggestedActionConvertToBroadcastGroup")]
+    SuggestedActionConvertToBroadcastGroup(Box<SuggestedActionConvertToBroadcastGroup>),
+    /// Suggests the user to enable "archive_and_mute_new_chats_from_unknown_users" option
+    #[serde(rename = "suggestedActionEnableArchiveAndMuteNewChats")]
+    SuggestedActionEnableArchiveAndMuteNewChats(Box<SuggestedActionEnableArchiveAndMuteNewChats>),
+    /// Suggests the user to set a 2-step verification password to be able to log in again
+    #[serde(rename = "suggestedActionSetPassword")]
+    SuggestedActionSetPassword(Box<SuggestedActionSetPassword>),
+    /// Suggests the user to view a hint about the meaning of one and two check marks on sent messages
+    #[serde(rename = "suggestedActionViewChecksHint")]
+    SuggestedActionViewChecksHint(Box<SuggestedActionViewChecksHint>),
+    /// Represents a supergroup or channel with zero or more members (subscribers in the case of channels). From the point of view of the system, a channel is a special kind of a supergroup: only administrators can post and see the list of members, and posts from all administrators use the name and photo of the channel instead of individual names and profile photos. Unlike supergroups, channels can have an unlimited number of subscribers
+    #[serde(rename = "supergroup")]
+    Supergroup(Box<Supergroup>),
+    /// Contains full information about a supergroup or channel
+    #[serde(rename = "supergroupFullInfo")]
+    SupergroupFullInfo(Box<SupergroupFullInfo>),
+    /// Returns the owner and administrators
+    #[serde(rename = "supergroupMembersFilterAdministrators")]
+    SupergroupMembersFilterAdministrators(Box<SupergroupMembersFilterAdministrators>),
+    /// Returns users banned from the supergroup or channel; can be used only by administrators
+    #[serde(rename = "supergroupMembersFilterBanned")]
+    SupergroupMembersFilterBanned(Box<SupergroupMembersFilterBanned>),
+    /// Returns bot members of the supergroup or channel
+    #[serde(rename = "supergroupMembersFilterBots")]
+    SupergroupMembersFilterBots(Box<SupergroupMembersFilterBots>),
+    /// Returns contacts of the user, which are members of the supergroup or channel
+    #[serde(rename = "supergroupMembersFilterContacts")]
+    SupergroupMembersFilterContacts(Box<SupergroupMembersFilterContacts>),
+    /// Returns users which can be mentioned in the supergroup
+    #[serde(rename = "supergroupMembersFilterMention")]
+    SupergroupMembersFilterMention(Box<SupergroupMembersFilterMention>),
+    /// Returns recently active users in reverse chronological order
+    #[serde(rename = "supergroupMembersFilterRecent")]
+    SupergroupMembersFilterRecent(Box<SupergroupMembersFilterRecent>),
+    /// Returns restricted supergroup members; can be used only by administrators
+    #[serde(rename = "supergroupMembersFilterRestricted")]
+    SupergroupMembersFilterRestricted(Box<SupergroupMembersFilterRestricted>),
+    /// Used to search for supergroup or channel members via a (string) query
+    #[serde(rename = "supergroupMembersFilterSearch")]
+    SupergroupMembersFilterSearch(Box<SupergroupMembersFilterSearch>),
+    /// Represents a URL linking to an internal Telegram entity
+    #[serde(rename = "tMeUrl")]
+    TMeUrl(Box<TMeUrl>),
+    /// A chat invite link
+    #[serde(rename = "tMeUrlTypeChatInvite")]
+    TMeUrlTypeChatInvite(Box<TMeUrlTypeChatInvite>),
+    /// A URL linking to a sticker set
+    #[serde(rename = "tMeUrlTypeStickerSet")]
+    TMeUrlTypeStickerSet(Box<TMeUrlTypeStickerSet>),
+    /// A URL linking to a public supergroup or channel
+    #[serde(rename = "tMeUrlTypeSupergroup")]
+    TMeUrlTypeSupergroup(Box<TMeUrlTypeSupergroup>),
+    /// A URL linking to a user
+    #[serde(rename = "tMeUrlTypeUser")]
+    TMeUrlTypeUser(Box<TMeUrlTypeUser>),
+    /// Contains a list of t.me URLs
+    #[serde(rename = "tMeUrls")]
+    TMeUrls(Box<TMeUrls>),
+    /// Contains parameters for TDLib initialization
+    #[serde(rename = "tdlibParameters")]
+    TdlibParameters(Box<TdlibParameters>),
+    /// Returns information about the availability of a temporary password, which can be used for payments
+    #[serde(rename = "temporaryPasswordState")]
+    TemporaryPasswordState(Box<TemporaryPasswordState>),
+    /// Contains Telegram terms of service
+    #[serde(rename = "termsOfService")]
+    TermsOfService(Box<TermsOfService>),
+    /// A simple object containing a sequence of bytes; for testing only
+    #[serde(rename = "testBytes")]
+    TestBytes(Box<TestBytes>),
+    /// A simple object containing a number; for testing only
+    #[serde(rename = "testInt")]
+    TestInt(Box<TestInt>),
+    /// A simple object containing a string; for testing only
+    #[serde(rename = "testString")]
+    TestString(Box<TestString>),
+    /// A simple object containing a vector of numbers; for testing only
+    #[serde(rename = "testVectorInt")]
+    TestVectorInt(Box<TestVectorInt>),
+    /// A simple object containing a vector of objects that hold a number; for testing only
+    #[serde(rename = "testVectorIntObject")]
+    TestVectorIntObject(Box<TestVectorIntObject>),
+    /// A simple object containing a vector of strings; for testing only
+    #[serde(rename = "testVectorString")]
+    TestVectorString(Box<TestVectorString>),
+    /// A simple object containing a vector of objects that hold a string; for testing only
+    #[serde(rename = "testVectorStringObject")]
+    TestVectorStringObject(Box<TestVectorStringObject>),
+    /// Contains some text
+    #[serde(rename = "text")]
+    Text(Box<Text>),
+    /// Contains a list of text entities
+    #[serde(rename = "textEntities")]
+    TextEntities(Box<TextEntities>),
+    /// Represents a part of the text that needs to be formatted in some unusual way
+    #[serde(rename = "textEntity")]
+    TextEntity(Box<TextEntity>),
+    /// A bank card number. The getBankCardInfo method can be used to get information about the bank card
+    #[serde(rename = "textEntityTypeBankCardNumber")]
+    TextEntityTypeBankCardNumber(Box<TextEntityTypeBankCardNumber>),
+    /// A bold text
+    #[serde(rename = "textEntityTypeBold")]
+    TextEntityTypeBold(Box<TextEntityTypeBold>),
+    /// A bot command, beginning with "/"
+    #[serde(rename = "textEntityTypeBotCommand")]
+    TextEntityTypeBotCommand(Box<TextEntityTypeBotCommand>),
+    /// A cashtag text, beginning with "$" and consisting of capital English letters (e.g., "$USD")
+    #[serde(rename = "textEntityTypeCashtag")]
+    TextEntityTypeCashtag(Box<TextEntityTypeCashtag>),
+    /// Text that must be formatted as if inside a code HTML tag
+    #[serde(rename = "textEntityTypeCode")]
+    TextEntityTypeCode(Box<TextEntityTypeCode>),
+    /// An email address
+    #[serde(rename = "textEntityTypeEmailAddress")]
+    TextEntityTypeEmailAddress(Box<TextEntityTypeEmailAddress>),
+    /// A hashtag text, beginning with "#"
+    #[serde(rename = "textEntityTypeHashtag")]
+    TextEntityTypeHashtag(Box<TextEntityTypeHashtag>),
+    /// An italic text
+    #[serde(rename = "textEntityTypeItalic")]
+    TextEntityTypeItalic(Box<TextEntityTypeItalic>),
+    /// A media timestamp
+    #[serde(rename = "textEntityTypeMediaTimestamp")]
+    TextEntityTypeMediaTimestamp(Box<TextEntityTypeMediaTimestamp>),
+    /// A mention of a user by their username
+    #[serde(rename = "textEntityTypeMention")]
+    TextEntityTypeMention(Box<TextEntityTypeMention>),
+    /// A text shows instead of a raw mention of the user (e.g., when the user has no username)
+    #[serde(rename = "textEntityTypeMentionName")]
+    TextEntityTypeMentionName(Box<TextEntityTypeMentionName>),
+    /// A phone number
+    #[serde(rename = "textEntityTypePhoneNumber")]
+    TextEntityTypePhoneNumber(Box<TextEntityTypePhoneNumber>),
+    /// Text that must be formatted as if inside a pre HTML tag
+    #[serde(rename = "textEntityTypePre")]
+    TextEntityTypePre(Box<TextEntityTypePre>),
+    /// Text that must be formatted as if inside pre, and code HTML tags
+    #[serde(rename = "textEntityTypePreCode")]
+    TextEntityTypePreCode(Box<TextEntityTypePreCode>),
+    /// A strikethrough text
+    #[serde(rename = "textEntityTypeStrikethrough")]
+    TextEntityTypeStrikethrough(Box<TextEntityTypeStrikethrough>),
+    /// A text description shown instead of a raw URL
+    #[serde(rename = "textEntityTypeTextUrl")]
+    TextEntityTypeTextUrl(Box<TextEntityTypeTextUrl>),
+    /// An underlined text
+    #[serde(rename = "textEntityTypeUnderline")]
+    TextEntityTypeUnderline(Box<TextEntityTypeUnderline>),
+    /// An HTTP URL
+    #[serde(rename = "textEntityTypeUrl")]
+    TextEntityTypeUrl(Box<TextEntityTypeUrl>),
+    /// The text uses HTML-style formatting. The same as Telegram Bot API "HTML" parse mode
+    #[serde(rename = "textParseModeHTML")]
+    TextParseModeHTML(Box<TextParseModeHTML>),
+    /// The text uses Markdown-style formatting
+    #[serde(rename = "textParseModeMarkdown")]
+    TextParseModeMarkdown(Box<TextParseModeMarkdown>),
+    /// Describes theme settings
+    #[serde(rename = "themeSettings")]
+    ThemeSettings(Box<ThemeSettings>),
+    /// Represents a thumbnail
+    #[serde(rename = "thumbnail")]
+    Thumbnail(Box<Thumbnail>),
+    /// The thumbnail is in static GIF format. It will be used only for some bot inline results
+    #[serde(rename = "thumbnailFormatGif")]
+    ThumbnailFormatGif(Box<ThumbnailFormatGif>),
+    /// The thumbnail is in JPEG format
+    #[serde(rename = "thumbnailFormatJpeg")]
+    ThumbnailFormatJpeg(Box<ThumbnailFormatJpeg>),
+    /// The thumbnail is in MPEG4 format. It will be used only for some animations and videos
+    #[serde(rename = "thumbnailFormatMpeg4")]
+    ThumbnailFormatMpeg4(Box<ThumbnailFormatMpeg4>),
+    /// The thumbnail is in PNG format. It will be used only for background patterns
+    #[serde(rename = "thumbnailFormatPng")]
+    ThumbnailFormatPng(Box<ThumbnailFormatPng>),
+    /// The thumbnail is in TGS format. It will be used only for animated sticker sets
+    #[serde(rename = "thumbnailFormatTgs")]
+    ThumbnailFormatTgs(Box<ThumbnailFormatTgs>),
+    /// The thumbnail is in WEBP format. It will be used only for some stickers
+    #[serde(rename = "thumbnailFormatWebp")]
+    ThumbnailFormatWebp(Box<ThumbnailFormatWebp>),
+    /// A category containing frequently used private chats with bot users
+    #[serde(rename = "topChatCategoryBots")]
+    TopChatCategoryBots(Box<TopChatCategoryBots>),
+    /// A category containing frequently used chats used for calls
+    #[serde(rename = "topChatCategoryCalls")]
+    TopChatCategoryCalls(Box<TopChatCategoryCalls>),
+    /// A category containing frequently used channels
+    #[serde(rename = "topChatCategoryChannels")]
+    TopChatCategoryChannels(Box<TopChatCategoryChannels>),
+    /// A category containing frequently used chats used to forward messages
+    #[serde(rename = "topChatCategoryForwardChats")]
+    TopChatCategoryForwardChats(Box<TopChatCategoryForwardChats>),
+    /// A category containing frequently used basic groups and supergroups
+    #[serde(rename = "topChatCategoryGroups")]
+    TopChatCategoryGroups(Box<TopChatCategoryGroups>),
+    /// A category containing frequently used chats with inline bots sorted by their usage in inline mode
+    #[serde(rename = "topChatCategoryInlineBots")]
+    TopChatCategoryInlineBots(Box<TopChatCategoryInlineBots>),
+    /// A category containing frequently used private chats with non-bot users
+    #[serde(rename = "topChatCategoryUsers")]
+    TopChatCategoryUsers(Box<TopChatCategoryUsers>),
+    /// Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update
+    #[serde(rename = "updateActiveNotifications")]
+    UpdateActiveNotifications(Box<UpdateActiveNotifications>),
+    /// Some animated emoji message was clicked and a big animated sticker must be played if the message is visible on the screen. chatActionWatchingAnimations with the text of the message needs to be sent if the sticker is played
+    #[serde(rename = "updateAnimatedEmojiMessageClicked")]
+    UpdateAnimatedEmojiMessageClicked(Box<UpdateAnimatedEmojiMessageClicked>),
+    /// The parameters of animation search through GetOption("animation_search_bot_username") bot has changed
+    #[serde(rename = "updateAnimationSearchParameters")]
+    UpdateAnimationSearchParameters(Box<UpdateAnimationSearchParameters>),
+    /// The user authorization state has changed
+    #[serde(rename = "updateAuthorizationState")]
+    UpdateAuthorizationState(Box<UpdateAuthorizationState>),
+    /// Some data of a basic group has changed. This update is guaranteed to come before the basic group identifier is returned to the application
+    #[serde(rename = "updateBasicGroup")]
+    UpdateBasicGroup(Box<UpdateBasicGroup>),
+    /// Some data in basicGroupFullInfo has been changed
+    #[serde(rename = "updateBasicGroupFullInfo")]
+    UpdateBasicGroupFullInfo(Box<UpdateBasicGroupFullInfo>),
+    /// New call was created or information about a call was updated
+    #[serde(rename = "updateCall")]
+    UpdateCall(Box<UpdateCall>),
+    /// A message sender activity in the chat has changed
+    #[serde(rename = "updateChatAction")]
+    UpdateChatAction(Box<UpdateChatAction>),
+    /// The chat action bar was changed
+    #[serde(rename = "updateChatActionBar")]
+    UpdateChatActionBar(Box<UpdateChatActionBar>),
+    /// The value of the default disable_notification parameter, used when a message is sent to the chat, was changed
+    #[serde(rename = "updateChatDefaultDisableNotification")]
+    UpdateChatDefaultDisableNotification(Box<UpdateChatDefaultDisableNotification>),
+    /// A chat draft has changed. Be aware that the update may come in the currently opened chat but with old content of the draft. If the user has changed the content of the draft, this update mustn't be applied
+    #[serde(rename = "updateChatDraftMessage")]
+    UpdateChatDraftMessage(Box<UpdateChatDraftMessage>),
+    /// The list of chat filters or a chat filter has changed
+    #[serde(rename = "updateChatFilters")]
+    UpdateChatFilters(Box<UpdateChatFilters>),
+    /// A chat content was allowed or restricted for saving
+    #[serde(rename = "updateChatHasProtectedContent")]
+    UpdateChatHasProtectedContent(Box<UpdateChatHasProtectedContent>),
+    /// A chat's has_scheduled_messages field has changed
+    #[serde(rename = "updateChatHasScheduledMessages")]
+    UpdateChatHasScheduledMessages(Box<UpdateChatHasScheduledMessages>),
+    /// A chat was blocked or unblocked
+    #[serde(rename = "updateChatIsBlocked")]
+    UpdateChatIsBlocked(Box<UpdateChatIsBlocked>),
+    /// A chat was marked as unread or was read
+    #[serde(rename = "updateChatIsMarkedAsUnread")]
+    UpdateChatIsMarkedAsUnread(Box<UpdateChatIsMarkedAsUnread>),
+    /// The last message of a chat was changed. If last_message is null, then the last message in the chat became unknown. Some new unknown messages might be added to the chat in this case
+    #[serde(rename = "updateChatLastMessage")]
+    UpdateChatLastMessage(Box<UpdateChatLastMessage>),
+    /// User rights changed in a chat; for bots only
+    #[serde(rename = "updateChatMember")]
+    UpdateChatMember(Box<UpdateChatMember>),
+    /// The message sender that is selected to send messages in a chat has changed
+    #[serde(rename = "updateChatMessageSender")]
+    UpdateChatMessageSender(Box<UpdateChatMessageSender>),
+    /// The message Time To Live setting for a chat was changed
+    #[serde(rename = "updateChatMessageTtl")]
+    UpdateChatMessageTtl(Box<UpdateChatMessageTtl>),
+    /// Notification settings for a chat were changed
+    #[serde(rename = "updateChatNotificationSettings")]
+    UpdateChatNotificationSettings(Box<UpdateChatNotificationSettings>),
+    /// The number of online group members has changed. This update with non-zero count is sent only for currently opened chats. There is no guarantee that it will be sent just after the count has changed
+    #[serde(rename = "updateChatOnlineMemberCount")]
+    UpdateChatOnlineMemberCount(Box<UpdateChatOnlineMemberCount>),
+    /// The chat pending join requests were changed
+    #[serde(rename = "updateChatPendingJoinRequests")]
+    UpdateChatPendingJoinRequests(Box<UpdateChatPendingJoinRequests>),
+    /// Chat permissions was changed
+    #[serde(rename = "updateChatPermissions")]
+    UpdateChatPermissions(Box<UpdateChatPermissions>),
+    /// A chat photo was changed
+    #[serde(rename = "updateChatPhoto")]
+    UpdateChatPhoto(Box<UpdateChatPhoto>),
+    /// The position of a chat in a chat list has changed. Instead of this update updateChatLastMessage or updateChatDraftMessage might be sent
+    #[serde(rename = "updateChatPosition")]
+    UpdateChatPosition(Box<UpdateChatPosition>),
+    /// Incoming messages were read or the number of unread messages has been changed
+    #[serde(rename = "updateChatReadInbox")]
+    UpdateChatReadInbox(Box<UpdateChatReadInbox>),
+    /// Outgoing messages were read
+    #[serde(rename = "updateChatReadOutbox")]
+    UpdateChatReadOutbox(Box<UpdateChatReadOutbox>),
+    /// The default chat reply markup was changed. Can occur because new messages with reply markup were received or because an old reply markup was hidden by the user
+    #[serde(rename = "updateChatReplyMarkup")]
+    UpdateChatReplyMarkup(Box<UpdateChatReplyMarkup>),
+    /// The chat theme was changed
+    #[serde(rename = "updateChatTheme")]
+    UpdateChatTheme(Box<UpdateChatTheme>),
+    /// The list of available chat themes has changed
+    #[serde(rename = "updateChatThemes")]
+    UpdateChatThemes(Box<UpdateChatThemes>),
+    /// The title of a chat was changed
+    #[serde(rename = "updateChatTitle")]
+    UpdateChatTitle(Box<UpdateChatTitle>),
+    /// The chat unread_mention_count has changed
+    #[serde(rename = "updateChatUnreadMentionCount")]
+    UpdateChatUnreadMentionCount(Box<UpdateChatUnreadMentionCount>),
+    /// A chat video chat state has changed
+    #[serde(rename = "updateChatVideoChat")]
+    UpdateChatVideoChat(Box<UpdateChatVideoChat>),
+    /// The connection state has changed. This update must be used only to show a human-readable description of the connection state
+    #[serde(rename = "updateConnectionState")]
+    UpdateConnectionState(Box<UpdateConnectionState>),
+    /// Some messages were deleted
+    #[serde(rename = "updateDeleteMessages")]
+    UpdateDeleteMessages(Box<UpdateDeleteMessages>),
+    /// The list of supported dice emojis has changed
+    #[serde(rename = "updateDiceEmojis")]
+    UpdateDiceEmojis(Box<UpdateDiceEmojis>),
+    /// The list of favorite stickers was updated
+    #[serde(rename = "updateFavoriteStickers")]
+    UpdateFavoriteStickers(Box<UpdateFavoriteStickers>),
+    /// Information about a file was updated
+    #[serde(rename = "updateFile")]
+    UpdateFile(Box<UpdateFile>),
+    /// The file generation process needs to be started by the application
+    #[serde(rename = "updateFileGenerationStart")]
+    UpdateFileGenerationStart(Box<UpdateFileGenerationStart>),
+    /// File generation is no longer needed
+    #[serde(rename = "updateFileGenerationStop")]
+    UpdateFileGenerationStop(Box<UpdateFileGenerationStop>),
+    /// Information about a group call was updated
+    #[serde(rename = "updateGroupCall")]
+    UpdateGroupCall(Box<UpdateGroupCall>),
+    /// Information about a group call participant was changed. The updates are sent only after the group call is received through getGroupCall and only if the call is joined or being joined
+    #[serde(rename = "updateGroupCallParticipant")]
+    UpdateGroupCallParticipant(Box<UpdateGroupCallParticipant>),
+    /// Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications
+    #[serde(rename = "updateHavePendingNotifications")]
+    UpdateHavePendingNotifications(Box<UpdateHavePendingNotifications>),
+    /// The list of installed sticker sets was updated
+    #[serde(rename = "updateInstalledStickerSets")]
+    UpdateInstalledStickerSets(Box<UpdateInstalledStickerSets>),
+    /// Some language pack strings have been updated
+    #[serde(rename = "updateLanguagePackStrings")]
+    UpdateLanguagePackStrings(Box<UpdateLanguagePackStrings>),
+    /// The message content has changed
+    #[serde(rename = "updateMessageContent")]
+    UpdateMessageContent(Box<UpdateMessageContent>),
+    /// The message content was opened. Updates voice note messages to "listened", video note messages to "viewed" and starts the TTL timer for self-destructing messages
+    #[serde(rename = "updateMessageContentOpened")]
+    UpdateMessageContentOpened(Box<UpdateMessageContentOpened>),
+    /// A message was edited. Changes in the message content will come in a separate updateMessageContent
+    #[serde(rename = "updateMessageEdited")]
+    UpdateMessageEdited(Box<UpdateMessageEdited>),
+    /// The information about interactions with a message has changed
+    #[serde(rename = "updateMessageInteractionInfo")]
+    UpdateMessageInteractionInfo(Box<UpdateMessageInteractionInfo>),
+    /// The message pinned state was changed
+    #[serde(rename = "updateMessageIsPinned")]
+    UpdateMessageIsPinned(Box<UpdateMessageIsPinned>),
+    /// A message with a live location was viewed. When the update is received, the application is supposed to update the live location
+    #[serde(rename = "updateMessageLiveLocationViewed")]
+    UpdateMessageLiveLocationViewed(Box<UpdateMessageLiveLocationViewed>),
+    /// A message with an unread mention was read
+    #[serde(rename = "updateMessageMentionRead")]
+    UpdateMessageMentionRead(Box<UpdateMessageMentionRead>),
+    /// A request to send a message has reached the Telegram server. This doesn't mean that the message will be sent successfully or even that the send message request will be processed. This update will be sent only if the option "use_quick_ack" is set to true. This update may be sent multiple times for the same message
+    #[serde(rename = "updateMessageSendAcknowledged")]
+    UpdateMessageSendAcknowledged(Box<UpdateMessageSendAcknowledged>),
+    /// A message failed to send. Be aware that some messages being sent can be irrecoverably deleted, in which case updateDeleteMessages will be received instead of this update
+    #[serde(rename = "updateMessageSendFailed")]
+    UpdateMessageSendFailed(Box<UpdateMessageSendFailed>),
+    /// A message has been successfully sent
+    #[serde(rename = "updateMessageSendSucceeded")]
+    UpdateMessageSendSucceeded(Box<UpdateMessageSendSucceeded>),
+    /// New call signaling data arrived
+    #[serde(rename = "updateNewCallSignalingData")]
+    UpdateNewCallSignalingData(Box<UpdateNewCallSignalingData>),
+    /// A new incoming callback query; for bots only
+    #[serde(rename = "updateNewCallbackQuery")]
+    UpdateNewCallbackQuery(Box<UpdateNewCallbackQuery>),
+    /// A new chat has been loaded/created. This update is guaranteed to come before the chat identifier is returned to the application. The chat field changes will be reported through separate updates
+    #[serde(rename = "updateNewChat")]
+    UpdateNewChat(Box<UpdateNewChat>),
+    /// A user sent a join request to a chat; for bots only
+    #[serde(rename = "updateNewChatJoinRequest")]
+    UpdateNewChatJoinRequest(Box<UpdateNewChatJoinRequest>),
+    /// The user has chosen a result of an inline query; for bots only
+    #[serde(rename = "updateNewChosenInlineResult")]
+    UpdateNewChosenInlineResult(Box<UpdateNewChosenInlineResult>),
+    /// A new incoming event; for bots only
+    #[serde(rename = "updateNewCustomEvent")]
+    UpdateNewCustomEvent(Box<UpdateNewCustomEvent>),
+    /// A new incoming query; for bots only
+    #[serde(rename = "updateNewCustomQuery")]
+    UpdateNewCustomQuery(Box<UpdateNewCustomQuery>),
+    /// A new incoming callback query from a message sent via a bot; for bots only
+    #[serde(rename = "updateNewInlineCallbackQuery")]
+    UpdateNewInlineCallbackQuery(Box<UpdateNewInlineCallbackQuery>),
+    /// A new incoming inline query; for bots only
+    #[serde(rename = "updateNewInlineQuery")]
+    UpdateNewInlineQuery(Box<UpdateNewInlineQuery>),
+    /// A new message was received; can also be an outgoing message
+    #[serde(rename = "updateNewMessage")]
+    UpdateNewMessage(Box<UpdateNewMessage>),
+    /// A new incoming pre-checkout query; for bots only. Contains full information about a checkout
+    #[serde(rename = "updateNewPreCheckoutQuery")]
+    UpdateNewPreCheckoutQuery(Box<UpdateNewPreCheckoutQuery>),
+    /// A new incoming shipping query; for bots only. Only for invoices with flexible price
+    #[serde(rename = "updateNewShippingQuery")]
+    UpdateNewShippingQuery(Box<UpdateNewShippingQuery>),
+    /// A notification was changed
+    #[serde(rename = "updateNotification")]
+    UpdateNotification(Box<UpdateNotification>),
+    /// A list of active notifications in a notification group has changed
+    #[serde(rename = "updateNotificationGroup")]
+    UpdateNotificationGroup(Box<UpdateNotificationGroup>),
+    /// An option changed its value
+    #[serde(rename = "updateOption")]
+    UpdateOption(Box<UpdateOption>),
+    /// A poll was updated; for bots only
+    #[serde(rename = "updatePoll")]
+    UpdatePoll(Box<UpdatePoll>),
+    /// A user changed the answer to a poll; for bots only
+    #[serde(rename = "updatePollAnswer")]
+    UpdatePollAnswer(Box<UpdatePollAnswer>),
+    /// The list of recently used stickers was updated
+    #[serde(rename = "updateRecentStickers")]
+    UpdateRecentStickers(Box<UpdateRecentStickers>),
+    /// The list of saved animations was updated
+    #[serde(rename = "updateSavedAnimations")]
+    UpdateSavedAnimations(Box<UpdateSavedAnimations>),
+    /// Notification settings for some type of chats were updated
+    #[serde(rename = "updateScopeNotificationSettings")]
+    UpdateScopeNotificationSettings(Box<UpdateScopeNotificationSettings>),
+    /// Some data of a secret chat has changed. This update is guaranteed to come before the secret chat identifier is returned to the application
+    #[serde(rename = "updateSecretChat")]
+    UpdateSecretChat(Box<UpdateSecretChat>),
+    /// The selected background has changed
+    #[serde(rename = "updateSelectedBackground")]
+    UpdateSelectedBackground(Box<UpdateSelectedBackground>),
+    /// A service notification from the server was received. Upon receiving this the application must show a popup with the content of the notification
+    #[serde(rename = "updateServiceNotification")]
+    UpdateServiceNotification(Box<UpdateServiceNotification>),
+    /// A sticker set has changed
+    #[serde(rename = "updateStickerSet")]
+    UpdateStickerSet(Box<UpdateStickerSet>),
+    /// The list of suggested to the user actions has changed
+    #[serde(rename = "updateSuggestedActions")]
+    UpdateSuggestedActions(Box<UpdateSuggestedActions>),
+    /// Some data of a supergroup or a channel has changed. This update is guaranteed to come before the supergroup identifier is returned to the application
+    #[serde(rename = "updateSupergroup")]
+    UpdateSupergroup(Box<UpdateSupergroup>),
+    /// Some data in supergroupFullInfo has been changed
+    #[serde(rename = "updateSupergroupFullInfo")]
+    UpdateSupergroupFullInfo(Box<UpdateSupergroupFullInfo>),
+    /// New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method must be called with the reason "Decline ToS update"
+    #[serde(rename = "updateTermsOfService")]
+    UpdateTermsOfService(Box<UpdateTermsOfService>),
+    /// The list of trending sticker sets was updated or some of them were viewed
+    #[serde(rename = "updateTrendingStickerSets")]
+    UpdateTrendingStickerSets(Box<UpdateTrendingStickerSets>),
+    /// Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used
+    #[serde(rename = "updateUnreadChatCount")]
+    UpdateUnreadChatCount(Box<UpdateUnreadChatCount>),
+    /// Number of unread messages in a chat list has changed. This update is sent only if the message database is used
+    #[serde(rename = "updateUnreadMessageCount")]
+    UpdateUnreadMessageCount(Box<UpdateUnreadMessageCount>),
+    /// Some data of a user has changed. This update is guaranteed to come before the user identifier is returned to the application
+    #[serde(rename = "updateUser")]
+    UpdateUser(Box<UpdateUser>),
+    /// Some data in userFullInfo has been changed
+    #[serde(rename = "updateUserFullInfo")]
+    UpdateUserFullInfo(Box<UpdateUserFullInfo>),
+    /// Some privacy setting rules have been changed
+    #[serde(rename = "updateUserPrivacySettingRules")]
+    UpdateUserPrivacySettingRules(Box<UpdateUserPrivacySettingRules>),
+    /// The user went online or offline
+    #[serde(rename = "updateUserStatus")]
+    UpdateUserStatus(Box<UpdateUserStatus>),
+    /// The list of users nearby has changed. The update is guaranteed to be sent only 60 seconds after a successful searchChatsNearby request
+    #[serde(rename = "updateUsersNearby")]
+    UpdateUsersNearby(Box<UpdateUsersNearby>),
+    /// Contains a list of updates
+    #[serde(rename = "updates")]
+    Updates(Box<Updates>),
+    /// Represents a user
+    #[serde(rename = "user")]
+    User(Box<User>),
+    /// Contains full information about a user
+    #[serde(rename = "userFullInfo")]
+    UserFullInfo(Box<UserFullInfo>),
+    /// A privacy setting for managing whether the user can be called
+    #[serde(rename = "userPrivacySettingAllowCalls")]
+    UserPrivacySettingAllowCalls(Box<UserPrivacySettingAllowCalls>),
+    /// A privacy setting for managing whether the user can be invited to chats
+    #[serde(rename = "userPrivacySettingAllowChatInvites")]
+    UserPrivacySettingAllowChatInvites(Box<UserPrivacySettingAllowChatInvites>),
+    /// A privacy setting for managing whether the user can be found by their phone number. Checked only if the phone number is not known to the other user. Can be set only to "Allow contacts" or "Allow all"
+    #[serde(rename = "userPrivacySettingAllowFindingByPhoneNumber")]
+    UserPrivacySettingAllowFindingByPhoneNumber(Box<UserPrivacySettingAllowFindingByPhoneNumber>),
+    /// A privacy setting for managing whether peer-to-peer connections can be used for calls
+    #[serde(rename = "userPrivacySettingAllowPeerToPeerCalls")]
+    UserPrivacySettingAllowPeerToPeerCalls(Box<UserPrivacySettingAllowPeerToPeerCalls>),
+    /// A rule to allow all users to do something
+    #[serde(rename = "userPrivacySettingRuleAllowAll")]
+    UserPrivacySettingRuleAllowAll(Box<UserPrivacySettingRuleAllowAll>),
+    /// A rule to allow all members of certain specified basic groups and supergroups to doing something
+    #[serde(rename = "userPrivacySettingRuleAllowChatMembers")]
+    UserPrivacySettingRuleAllowChatMembers(Box<UserPrivacySettingRuleAllowChatMembers>),
+    /// A rule to allow all of a user's contacts to do something
+    #[serde(rename = "userPrivacySettingRuleAllowContacts")]
+    UserPrivacySettingRuleAllowContacts(Box<UserPrivacySettingRuleAllowContacts>),
+    /// A rule to allow certain specified users to do something
+    #[serde(rename = "userPrivacySettingRuleAllowUsers")]
+    UserPrivacySettingRuleAllowUsers(Box<UserPrivacySettingRuleAllowUsers>),
+    /// A rule to restrict all users from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictAll")]
+    UserPrivacySettingRuleRestrictAll(Box<UserPrivacySettingRuleRestrictAll>),
+    /// A rule to restrict all members of specified basic groups and supergroups from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictChatMembers")]
+    UserPrivacySettingRuleRestrictChatMembers(Box<UserPrivacySettingRuleRestrictChatMembers>),
+    /// A rule to restrict all contacts of a user from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictContacts")]
+    UserPrivacySettingRuleRestrictContacts(Box<UserPrivacySettingRuleRestrictContacts>),
+    /// A rule to restrict all specified users from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictUsers")]
+    UserPrivacySettingRuleRestrictUsers(Box<UserPrivacySettingRuleRestrictUsers>),
+    /// A list of privacy rules. Rules are matched in the specified order. The first matched rule defines the privacy setting for a given user. If no rule matches, the action is not allowed
+    #[serde(rename = "userPrivacySettingRules")]
+    UserPrivacySettingRules(Box<UserPrivacySettingRules>),
+    /// A privacy setting for managing whether a link to the user's account is included in forwarded messages
+    #[serde(rename = "userPrivacySettingShowLinkInForwardedMessages")]
+    UserPrivacySettingShowLinkInForwardedMessages(
+        Box<UserPrivacySettingShowLinkInForwardedMessages>,
+    ),
+    /// A privacy setting for managing whether the user's phone number is visible
+    #[serde(rename = "userPrivacySettingShowPhoneNumber")]
+    UserPrivacySettingShowPhoneNumber(Box<UserPrivacySettingShowPhoneNumber>),
+    /// A privacy setting for managing whether the user's profile photo is visible
+    #[serde(rename = "userPrivacySettingShowProfilePhoto")]
+    UserPrivacySettingShowProfilePhoto(Box<UserPrivacySettingShowProfilePhoto>),
+    /// A privacy setting for managing whether the user's online status is visible
+    #[serde(rename = "userPrivacySettingShowStatus")]
+    UserPrivacySettingShowStatus(Box<UserPrivacySettingShowStatus>),
+    /// The user status was never changed
+    #[serde(rename = "userStatusEmpty")]
+    UserStatusEmpty(Box<UserStatusEmpty>),
+    /// The user is offline, but was online last month
+    #[serde(rename = "userStatusLastMonth")]
+    UserStatusLastMonth(Box<UserStatusLastMonth>),
+    /// The user is offline, but was online last week
+    #[serde(rename = "userStatusLastWeek")]
+    UserStatusLastWeek(Box<UserStatusLastWeek>),
+    /// The user is offline
+    #[serde(rename = "userStatusOffline")]
+    UserStatusOffline(Box<UserStatusOffline>),
+    /// The user is online
+    #[serde(rename = "userStatusOnline")]
+    UserStatusOnline(Box<UserStatusOnline>),
+    /// The user was online recently
+    #[serde(rename = "userStatusRecently")]
+    UserStatusRecently(Box<UserStatusRecently>),
+    /// A bot (see https://core.telegram.org/bots)
+    #[serde(rename = "userTypeBot")]
+    UserTypeBot(Box<UserTypeBot>),
+    /// A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user
+    #[serde(rename = "userTypeDeleted")]
+    UserTypeDeleted(Box<UserTypeDeleted>),
+    /// A regular user
+    #[serde(rename = "userTypeRegular")]
+    UserTypeRegular(Box<UserTypeRegular>),
+    /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
+    #[serde(rename = "userTypeUnknown")]
+    UserTypeUnknown(Box<UserTypeUnknown>),
+    /// Represents a list of users
+    #[serde(rename = "users")]
+    Users(Box<Users>),
+    /// Contains a temporary identifier of validated order information, which is stored for one hour. Also contains the available shipping options
+    #[serde(rename = "validatedOrderInfo")]
+    ValidatedOrderInfo(Box<ValidatedOrderInfo>),
+    /// A cubic Bézier curve to a given point
+    #[serde(rename = "vectorPathCommandCubicBezierCurve")]
+    VectorPathCommandCubicBezierCurve(Box<VectorPathCommandCubicBezierCurve>),
+    /// A straight line to a given point
+    #[serde(rename = "vectorPathCommandLine")]
+    VectorPathCommandLine(Box<VectorPathCommandLine>),
+    /// Describes a venue
+    #[serde(rename = "venue")]
+    Venue(Box<Venue>),
+    /// Describes a video file
+    #[serde(rename = "video")]
+    Video(Box<Video>),
+    /// Describes a video chat
+    #[serde(rename = "videoChat")]
+    VideoChat(Box<VideoChat>),
+    /// Describes a video note. The video must be equal in width and height, cropped to a circle, and stored in MPEG4 format
+    #[serde(rename = "videoNote")]
+    VideoNote(Box<VideoNote>),
+    /// Describes a voice note. The voice note must be encoded with the Opus codec, and stored inside an OGG container. Voice notes can have only a single audio channel
+    #[serde(rename = "voiceNote")]
+    VoiceNote(Box<VoiceNote>),
+    /// Describes a web page preview
+    #[serde(rename = "webPage")]
+    WebPage(Box<WebPage>),
+    /// Describes an instant view page for a web page
+    #[serde(rename = "webPageInstantView")]
+    WebPageInstantView(Box<WebPageInstantView>),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl RObject for TdObject {
+    #[doc(hidden)]
+    fn extra(&self) -> Option<&str> {
+        match self {
+            TdObject::AccountTtl(t) => t.extra(),
+            TdObject::Address(t) => t.extra(),
+            TdObject::AnimatedChatPhoto(t) => t.extra(),
+            TdObject::AnimatedEmoji(t) => t.extra(),
+            TdObject::Animation(t) => t.extra(),
+            TdObject::Animations(t) => t.extra(),
+            TdObject::Audio(t) => t.extra(),
+            TdObject::AuthenticationCodeInfo(t) => t.extra(),
+            TdObject::AuthenticationCodeTypeCall(t) => t.extra(),
+            TdObject::AuthenticationCodeTypeFlashCall(t) => t.extra(),
+            TdObject::AuthenticationCodeTypeMissedCall(t) => t.extra(),
+            TdObject::AuthenticationCodeTypeSms(t) => t.extra(),
+            TdObject::AuthenticationCodeTypeTelegramMessage(t) => t.extra(),
+            TdObject::AuthorizationStateClosed(t) => t.extra(),
+            TdObject::AuthorizationStateClosing(t) => t.extra(),
+            TdObject::AuthorizationStateLoggingOut(t) => t.extra(),
+            TdObject::AuthorizationStateReady(t) => t.extra(),
+            TdObject::AuthorizationStateWaitCode(t) => t.extra(),
+            TdObject::AuthorizationStateWaitEncryptionKey(t) => t.extra(),
+            TdObject::AuthorizationStateWaitOtherDeviceConfirmation(t) => t.extra(),
+            TdObject::AuthorizationStateWaitPassword(t) => t.extra(),
+            TdObject::AuthorizationStateWaitPhoneNumber(t) => t.extra(),
+            TdObject::AuthorizationStateWaitRegistration(t) => t.extra(),
+            TdObject::AuthorizationStateWaitTdlibParameters(t) => t.extra(),
+            TdObject::AutoDownloadSettings(t) => t.extra(),
+            TdObject::AutoDownloadSettingsPresets(t) => t.extra(),
+            TdObject::Background(t) => t.extra(),
+            TdObject::BackgroundFillFreeformGradient(t) => t.extra(),
+            TdObject::BackgroundFillGradient(t) => t.extra(),
+            TdObject::BackgroundFillSolid(t) => t.extra(),
+            TdObject::BackgroundTypeFill(t) => t.extra(),
+            TdObject::BackgroundTypePattern(t) => t.extra(),
+            TdObject::BackgroundTypeWallpaper(t) => t.extra(),
+            TdObject::Backgrounds(t) => t.extra(),
+            TdObject::BankCardActionOpenUrl(t) => t.extra(),
+            TdObject::BankCardInfo(t) => t.extra(),
+            TdObject::BasicGroup(t) => t.extra(),
+            TdObject::BasicGroupFullInfo(t) => t.extra(),
+            TdObject::BotCommand(t) => t.extra(),
+            TdObject::BotCommandScopeAllChatAdministrators(t) => t.extra(),
+            TdObject::BotCommandScopeAllGroupChats(t) => t.extra(),
+            TdObject::BotCommandScopeAllPrivateChats(t) => t.extra(),
+            TdObject::BotCommandScopeChat(t) => t.extra(),
+            TdObject::BotCommandScopeChatAdministrators(t) => t.extra(),
+            TdObject::BotCommandScopeChatMember(t) => t.extra(),
+            TdObject::BotCommandScopeDefault(t) => t.extra(),
+            TdObject::BotCommands(t) => t.extra(),
+            TdObject::Call(t) => t.extra(),
+            TdObject::CallDiscardReasonDeclined(t) => t.extra(),
+            TdObject::CallDiscardReasonDisconnected(t) => t.extra(),
+            TdObject::CallDiscardReasonEmpty(t) => t.extra(),
+            TdObject::CallDiscardReasonHungUp(t) => t.extra(),
+            TdObject::CallDiscardReasonMissed(t) => t.extra(),
+            TdObject::CallId(t) => t.extra(),
+            TdObject::CallProblemDistortedSpeech(t) => t.extra(),
+            TdObject::CallProblemDistortedVideo(t) => t.extra(),
+            TdObject::CallProblemDropped(t) => t.extra(),
+            TdObject::CallProblemEcho(t) => t.extra(),
+            TdObject::CallProblemInterruptions(t) => t.extra(),
+            TdObject::CallProblemNoise(t) => t.extra(),
+            TdObject::CallProblemPixelatedVideo(t) => t.extra(),
+            TdObject::CallProblemSilentLocal(t) => t.extra(),
+            TdObject::CallProblemSilentRemote(t) => t.extra(),
+            TdObject::CallProtocol(t) => t.extra(),
+            TdObject::CallServer(t) => t.extra(),
+            TdObject::CallServerTypeTelegramReflector(t) => t.extra(),
+            TdObject::CallServerTypeWebrtc(t) => t.extra(),
+            TdObject::CallStateDiscarded(t) => t.extra(),
+            TdObject::CallStateError(t) => t.extra(),
+            TdObject::CallStateExchangingKeys(t) => t.extra(),
+            TdObject::CallStateHangingUp(t) => t.extra(),
+            TdObject::CallStatePending(t) => t.extra(),
+            TdObject::CallStateReady(t) => t.extra(),
+            TdObject::CallbackQueryAnswer(t) => t.extra(),
+            TdObject::CallbackQueryPayloadData(t) => t.extra(),
+            TdObject::CallbackQueryPayloadDataWithPassword(t) => t.extra(),
+            TdObject::CallbackQueryPayloadGame(t) => t.extra(),
+            TdObject::CanTransferOwnershipResultOk(t) => t.extra(),
+            TdObject::CanTransferOwnershipResultPasswordNeeded(t) => t.extra(),
+            TdObject::CanTransferOwnershipResultPasswordTooFresh(t) => t.extra(),
+            TdObject::CanTransferOwnershipResultSessionTooFresh(t) => t.extra(),
+            TdObject::Chat(t) => t.extra(),
+            TdObject::ChatActionBarAddContact(t) => t.extra(),
+            TdObject::ChatActionBarInviteMembers(t) => t.extra(),
+            TdObject::ChatActionBarJoinRequest(t) => t.extra(),
+            TdObject::ChatActionBarReportAddBlock(t) => t.extra(),
+            TdObject::ChatActionBarReportSpam(t) => t.extra(),
+            TdObject::ChatActionBarReportUnrelatedLocation(t) => t.extra(),
+            TdObject::ChatActionBarSharePhoneNumber(t) => t.extra(),
+            TdObject::ChatActionCancel(t) => t.extra(),
+            TdObject::ChatActionChoosingContact(t) => t.extra(),
+            TdObject::ChatActionChoosingLocation(t) => t.extra(),
+            TdObject::ChatActionChoosingSticker(t) => t.extra(),
+            TdObject::ChatActionRecordingVideo(t) => t.extra(),
+            TdObject::ChatActionRecordingVideoNote(t) => t.extra(),
+            TdObject::ChatActionRecordingVoiceNote(t) => t.extra(),
+            TdObject::ChatActionStartPlayingGame(t) => t.extra(),
+            TdObject::ChatActionTyping(t) => t.extra(),
+            TdObject::ChatActionUploadingDocument(t) => t.extra(),
+            TdObject::ChatActionUploadingPhoto(t) => t.extra(),
+            TdObject::ChatActionUploadingVideo(t) => t.extra(),
+            TdObject::ChatActionUploadingVideoNote(t) => t.extra(),
+            TdObject::ChatActionUploadingVoiceNote(t) => t.extra(),
+            TdObject::ChatActionWatchingAnimations(t) => t.extra(),
+            TdObject::ChatAdministrator(t) => t.extra(),
+            TdObject::ChatAdministrators(t) => t.extra(),
+            TdObject::ChatEvent(t) => t.extra(),
+            TdObject::ChatEventDescriptionChanged(t) => t.extra(),
+            TdObject::ChatEventHasProtectedContentToggled(t) => t.extra(),
+            TdObject::ChatEventInviteLinkDeleted(t) => t.extra(),
+            TdObject::ChatEventInviteLinkEdited(t) => t.extra(),
+            TdObject::ChatEventInviteLinkRevoked(t) => t.extra(),
+            TdObject::ChatEventInvitesToggled(t) => t.extra(),
+            TdObject::ChatEventIsAllHistoryAvailableToggled(t) => t.extra(),
+            TdObject::ChatEventLinkedChatChanged(t) => t.extra(),
+            TdObject::ChatEventLocationChanged(t) => t.extra(),
+            TdObject::ChatEventLogFilters(t) => t.extra(),
+            TdObject::ChatEventMemberInvited(t) => t.extra(),
+            TdObject::ChatEventMemberJoined(t) => t.extra(),
+            TdObject::ChatEventMemberJoinedByInviteLink(t) => t.extra(),
+            TdObject::ChatEventMemberJoinedByRequest(t) => t.extra(),
+            TdObject::ChatEventMemberLeft(t) => t.extra(),
+            TdObject::ChatEventMemberPromoted(t) => t.extra(),
+            TdObject::ChatEventMemberRestricted(t) => t.extra(),
+            TdObject::ChatEventMessageDeleted(t) => t.extra(),
+            TdObject::ChatEventMessageEdited(t) => t.extra(),
+            TdObject::ChatEventMessagePinned(t) => t.extra(),
+            TdObject::ChatEventMessageTtlChanged(t) => t.extra(),
+            TdObject::ChatEventMessageUnpinned(t) => t.extra(),
+            TdObject::ChatEventPermissionsChanged(t) => t.extra(),
+            TdObject::ChatEventPhotoChanged(t) => t.extra(),
+            TdObject::ChatEventPollStopped(t) => t.extra(),
+            TdObject::ChatEventSignMessagesToggled(t) => t.extra(),
+            TdObject::ChatEventSlowModeDelayChanged(t) => t.extra(),
+            TdObject::ChatEventStickerSetChanged(t) => t.extra(),
+            TdObject::ChatEventTitleChanged(t) => t.extra(),
+            TdObject::ChatEventUsernameChanged(t) => t.extra(),
+            TdObject::ChatEventVideoChatCreated(t) => t.extra(),
+            TdObject::ChatEventVideoChatEnded(t) => t.extra(),
+            TdObject::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.extra(),
+            TdObject::ChatEventVideoChatParticipantIsMutedToggled(t) => t.extra(),
+            TdObject::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.extra(),
+            TdObject::ChatEvents(t) => t.extra(),
+            TdObject::ChatFilter(t) => t.extra(),
+            TdObject::ChatFilterInfo(t) => t.extra(),
+            TdObject::ChatInviteLink(t) => t.extra(),
+            TdObject::ChatInviteLinkCount(t) => t.extra(),
+            TdObject::ChatInviteLinkCounts(t) => t.extra(),
+            TdObject::ChatInviteLinkInfo(t) => t.extra(),
+            TdObject::ChatInviteLinkMember(t) => t.extra(),
+            TdObject::ChatInviteLinkMembers(t) => t.extra(),
+            TdObject::ChatInviteLinks(t) => t.extra(),
+            TdObject::ChatJoinRequest(t) => t.extra(),
+            TdObject::ChatJoinRequests(t) => t.extra(),
+            TdObject::ChatJoinRequestsInfo(t) => t.extra(),
+            TdObject::ChatListArchive(t) => t.extra(),
+            TdObject::ChatListFilter(t) => t.extra(),
+            TdObject::ChatListMain(t) => t.extra(),
+            TdObject::ChatLists(t) => t.extra(),
+            TdObject::ChatLocation(t) => t.extra(),
+            TdObject::ChatMember(t) => t.extra(),
+            TdObject::ChatMemberStatusAdministrator(t) => t.extra(),
+            TdObject::ChatMemberStatusBanned(t) => t.extra(),
+            TdObject::ChatMemberStatusCreator(t) => t.extra(),
+            TdObject::ChatMemberStatusLeft(t) => t.extra(),
+            TdObject::ChatMemberStatusMember(t) => t.extra(),
+            TdObject::ChatMemberStatusRestricted(t) => t.extra(),
+            TdObject::ChatMembers(t) => t.extra(),
+            TdObject::ChatMembersFilterAdministrators(t) => t.extra(),
+            TdObject::ChatMembersFilterBanned(t) => t.extra(),
+            TdObject::ChatMembersFilterBots(t) => t.extra(),
+            TdObject::ChatMembersFilterContacts(t) => t.extra(),
+            TdObject::ChatMembersFilterMembers(t) => t.extra(),
+            TdObject::ChatMembersFilterMention(t) => t.extra(),
+            TdObject::ChatMembersFilterRestricted(t) => t.extra(),
+            TdObject::ChatNearby(t) => t.extra(),
+            TdObject::ChatNotificationSettings(t) => t.extra(),
+            TdObject::ChatPermissions(t) => t.extra(),
+            TdObject::ChatPhoto(t) => t.extra(),
+            TdObject::ChatPhotoInfo(t) => t.extra(),
+            TdObject::ChatPhotos(t) => t.extra(),
+            TdObject::ChatPosition(t) => t.extra(),
+            TdObject::ChatReportReasonChildAbuse(t) => t.extra(),
+            TdObject::ChatReportReasonCopyright(t) => t.extra(),
+            TdObject::ChatReportReasonCustom(t) => t.extra(),
+            TdObject::ChatReportReasonFake(t) => t.extra(),
+            TdObject::ChatReportReasonPornography(t) => t.extra(),
+            TdObject::ChatReportReasonSpam(t) => t.extra(),
+            TdObject::ChatReportReasonUnrelatedLocation(t) => t.extra(),
+            TdObject::ChatReportReasonViolence(t) => t.extra(),
+            TdObject::ChatSourceMtprotoProxy(t) => t.extra(),
+            TdObject::ChatSourcePublicServiceAnnouncement(t) => t.extra(),
+            TdObject::ChatStatisticsAdministratorActionsInfo(t) => t.extra(),
+            TdObject::ChatStatisticsChannel(t) => t.extra(),
+            TdObject::ChatStatisticsInviterInfo(t) => t.extra(),
+            TdObject::ChatStatisticsMessageInteractionInfo(t) => t.extra(),
+            TdObject::ChatStatisticsMessageSenderInfo(t) => t.extra(),
+            TdObject::ChatStatisticsSupergroup(t) => t.extra(),
+            TdObject::ChatTheme(t) => t.extra(),
+            TdObject::ChatTypeBasicGroup(t) => t.extra(),
+            TdObject::ChatTypePrivate(t) => t.extra(),
+            TdObject::ChatTypeSecret(t) => t.extra(),
+            TdObject::ChatTypeSupergroup(t) => t.extra(),
+            TdObject::Chats(t) => t.extra(),
+            TdObject::ChatsNearby(t) => t.extra(),
+            TdObject::CheckChatUsernameResultOk(t) => t.extra(),
+            TdObject::CheckChatUsernameResultPublicChatsTooMuch(t) => t.extra(),
+            TdObject::CheckChatUsernameResultPublicGroupsUnavailable(t) => t.extra(),
+            TdObject::CheckChatUsernameResultUsernameInvalid(t) => t.extra(),
+            TdObject::CheckChatUsernameResultUsernameOccupied(t) => t.extra(),
+            TdObject::CheckStickerSetNameResultNameInvalid(t) => t.extra(),
+            TdObject::CheckStickerSetNameResultNameOccupied(t) => t.extra(),
+            TdObject::CheckStickerSetNameResultOk(t) => t.extra(),
+            TdObject::ClosedVectorPath(t) => t.extra(),
+            TdObject::ConnectedWebsite(t) => t.extra(),
+            TdObject::ConnectedWebsites(t) => t.extra(),
+            TdObject::ConnectionStateConnecting(t) => t.extra(),
+            TdObject::ConnectionStateConnectingToProxy(t) => t.extra(),
+            TdObject::ConnectionStateReady(t) => t.extra(),
+            TdObject::ConnectionStateUpdating(t) => t.extra(),
+            TdObject::ConnectionStateWaitingForNetwork(t) => t.extra(),
+            TdObject::Contact(t) => t.extra(),
+            TdObject::Count(t) => t.extra(),
+            TdObject::Countries(t) => t.extra(),
+            TdObject::CountryInfo(t) => t.extra(),
+            TdObject::CustomRequestResult(t) => t.extra(),
+            TdObject::DatabaseStatistics(t) => t.extra(),
+            TdObject::Date(t) => t.extra(),
+            TdObject::DateRange(t) => t.extra(),
+            TdObject::DatedFile(t) => t.extra(),
+            TdObject::DeepLinkInfo(t) => t.extra(),
+            TdObject::DeviceTokenApplePush(t) => t.extra(),
+            TdObject::DeviceTokenApplePushVoIP(t) => t.extra(),
+            TdObject::DeviceTokenBlackBerryPush(t) => t.extra(),
+            TdObject::DeviceTokenFirebaseCloudMessaging(t) => t.extra(),
+            TdObject::DeviceTokenMicrosoftPush(t) => t.extra(),
+            TdObject::DeviceTokenMicrosoftPushVoIP(t) => t.extra(),
+            TdObject::DeviceTokenSimplePush(t) => t.extra(),
+            TdObject::DeviceTokenTizenPush(t) => t.extra(),
+            TdObject::DeviceTokenUbuntuPush(t) => t.extra(),
+            TdObject::DeviceTokenWebPush(t) => t.extra(),
+            TdObject::DeviceTokenWindowsPush(t) => t.extra(),
+            TdObject::DiceStickersRegular(t) => t.extra(),
+            TdObject::DiceStickersSlotMachine(t) => t.extra(),
+            TdObject::Document(t) => t.extra(),
+            TdObject::DraftMessage(t) => t.extra(),
+            TdObject::EmailAddressAuthenticationCodeInfo(t) => t.extra(),
+            TdObject::Emojis(t) => t.extra(),
+            TdObject::EncryptedCredentials(t) => t.extra(),
+            TdObject::EncryptedPassportElement(t) => t.extra(),
+            TdObject::Error(t) => t.extra(),
+            TdObject::File(t) => t.extra(),
+            TdObject::FilePart(t) => t.extra(),
+            TdObject::FileTypeAnimation(t) => t.extra(),
+            TdObject::FileTypeAudio(t) => t.extra(),
+            TdObject::FileTypeDocument(t) => t.extra(),
+            TdObject::FileTypeNone(t) => t.extra(),
+            TdObject::FileTypePhoto(t) => t.extra(),
+            TdObject::FileTypeProfilePhoto(t) => t.extra(),
+            TdObject::FileTypeSecret(t) => t.extra(),
+            TdObject::FileTypeSecretThumbnail(t) => t.extra(),
+            TdObject::FileTypeSecure(t) => t.extra(),
+            TdObject::FileTypeSticker(t) => t.extra(),
+            TdObject::FileTypeThumbnail(t) => t.extra(),
+            TdObject::FileTypeUnknown(t) => t.extra(),
+            TdObject::FileTypeVideo(t) => t.extra(),
+            TdObject::FileTypeVideoNote(t) => t.extra(),
+            TdObject::FileTypeVoiceNote(t) => t.extra(),
+            TdObject::FileTypeWallpaper(t) => t.extra(),
+            TdObject::FormattedText(t) => t.extra(),
+            TdObject::FoundMessages(t) => t.extra(),
+            TdObject::Game(t) => t.extra(),
+            TdObject::GameHighScore(t) => t.extra(),
+            TdObject::GameHighScores(t) => t.extra(),
+            TdObject::GroupCall(t) => t.extra(),
+            TdObject::GroupCallId(t) => t.extra(),
+            TdObject::GroupCallParticipant(t) => t.extra(),
+            TdObject::GroupCallParticipantVideoInfo(t) => t.extra(),
+            TdObject::GroupCallRecentSpeaker(t) => t.extra(),
+            TdObject::GroupCallVideoQualityFull(t) => t.extra(),
+            TdObject::GroupCallVideoQualityMedium(t) => t.extra(),
+            TdObject::GroupCallVideoQualityThumbnail(t) => t.extra(),
+            TdObject::GroupCallVideoSourceGroup(t) => t.extra(),
+            TdObject::Hashtags(t) => t.extra(),
+            TdObject::HttpUrl(t) => t.extra(),
+            TdObject::IdentityDocument(t) => t.extra(),
+            TdObject::ImportedContacts(t) => t.extra(),
+            TdObject::InlineKeyboardButton(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeBuy(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeCallback(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeCallbackGame(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeCallbackWithPassword(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeLoginUrl(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeSwitchInline(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeUrl(t) => t.extra(),
+            TdObject::InlineKeyboardButtonTypeUser(t) => t.extra(),
+            TdObject::InlineQueryResultAnimation(t) => t.extra(),
+            TdObject::InlineQueryResultArticle(t) => t.extra(),
+            TdObject::InlineQueryResultAudio(t) => t.extra(),
+            TdObject::InlineQueryResultContact(t) => t.extra(),
+            TdObject::InlineQueryResultDocument(t) => t.extra(),
+            TdObject::InlineQueryResultGame(t) => t.extra(),
+            TdObject::InlineQueryResultLocation(t) => t.extra(),
+            TdObject::InlineQueryResultPhoto(t) => t.extra(),
+            TdObject::InlineQueryResultSticker(t) => t.extra(),
+            TdObject::InlineQueryResultVenue(t) => t.extra(),
+            TdObject::InlineQueryResultVideo(t) => t.extra(),
+            TdObject::InlineQueryResultVoiceNote(t) => t.extra(),
+            TdObject::InlineQueryResults(t) => t.extra(),
+            TdObject::InputBackgroundLocal(t) => t.extra(),
+            TdObject::InputBackgroundRemote(t) => t.extra(),
+            TdObject::InputChatPhotoAnimation(t) => t.extra(),
+            TdObject::InputChatPhotoPrevious(t) => t.extra(),
+            TdObject::InputChatPhotoStatic(t) => t.extra(),
+            TdObject::InputCredentialsApplePay(t) => t.extra(),
+            TdObject::InputCredentialsGooglePay(t) => t.extra(),
+            TdObject::InputCredentialsNew(t) => t.extra(),
+            TdObject::InputCredentialsSaved(t) => t.extra(),
+            TdObject::InputFileGenerated(t) => t.extra(),
+            TdObject::InputFileId(t) => t.extra(),
+            TdObject::InputFileLocal(t) => t.extra(),
+            TdObject::InputFileRemote(t) => t.extra(),
+            TdObject::InputIdentityDocument(t) => t.extra(),
+            TdObject::InputInlineQueryResultAnimation(t) => t.extra(),
+            TdObject::InputInlineQueryResultArticle(t) => t.extra(),
+            TdObject::InputInlineQueryResultAudio(t) => t.extra(),
+            TdObject::InputInlineQueryResultContact(t) => t.extra(),
+            TdObject::InputInlineQueryResultDocument(t) => t.extra(),
+            TdObject::InputInlineQueryResultGame(t) => t.extra(),
+            TdObject::InputInlineQueryResultLocation(t) => t.extra(),
+            TdObject::InputInlineQueryResultPhoto(t) => t.extra(),
+            TdObject::InputInlineQueryResultSticker(t) => t.extra(),
+            TdObject::InputInlineQueryResultVenue(t) => t.extra(),
+            TdObject::InputInlineQueryResultVideo(t) => t.extra(),
+            TdObject::InputInlineQueryResultVoiceNote(t) => t.extra(),
+            TdObject::InputMessageAnimation(t) => t.extra(),
+            TdObject::InputMessageAudio(t) => t.extra(),
+            TdObject::InputMessageContact(t) => t.extra(),
+            TdObject::InputMessageDice(t) => t.extra(),
+            TdObject::InputMessageDocument(t) => t.extra(),
+            TdObject::InputMessageForwarded(t) => t.extra(),
+            TdObject::InputMessageGame(t) => t.extra(),
+            TdObject::InputMessageInvoice(t) => t.extra(),
+            TdObject::InputMessageLocation(t) => t.extra(),
+            TdObject::InputMessagePhoto(t) => t.extra(),
+            TdObject::InputMessagePoll(t) => t.extra(),
+            TdObject::InputMessageSticker(t) => t.extra(),
+            TdObject::InputMessageText(t) => t.extra(),
+            TdObject::InputMessageVenue(t) => t.extra(),
+            TdObject::InputMessageVideo(t) => t.extra(),
+            TdObject::InputMessageVideoNote(t) => t.extra(),
+            TdObject::InputMessageVoiceNote(t) => t.extra(),
+            TdObject::InputPassportElementAddress(t) => t.extra(),
+            TdObject::InputPassportElementBankStatement(t) => t.extra(),
+            TdObject::InputPassportElementDriverLicense(t) => t.extra(),
+            TdObject::InputPassportElementEmailAddress(t) => t.extra(),
+            TdObject::InputPassportElementError(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceDataField(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceFile(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceFiles(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceFrontSide(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceReverseSide(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceSelfie(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceTranslationFile(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceTranslationFiles(t) => t.extra(),
+            TdObject::InputPassportElementErrorSourceUnspecified(t) => t.extra(),
+            TdObject::InputPassportElementIdentityCard(t) => t.extra(),
+            TdObject::InputPassportElementInternalPassport(t) => t.extra(),
+            TdObject::InputPassportElementPassport(t) => t.extra(),
+            TdObject::InputPassportElementPassportRegistration(t) => t.extra(),
+            TdObject::InputPassportElementPersonalDetails(t) => t.extra(),
+            TdObject::InputPassportElementPhoneNumber(t) => t.extra(),
+            TdObject::InputPassportElementRentalAgreement(t) => t.extra(),
+            TdObject::InputPassportElementTemporaryRegistration(t) => t.extra(),
+            TdObject::InputPassportElementUtilityBill(t) => t.extra(),
+            TdObject::InputPersonalDocument(t) => t.extra(),
+            TdObject::InputStickerAnimated(t) => t.extra(),
+            TdObject::InputStickerStatic(t) => t.extra(),
+            TdObject::InputThumbnail(t) => t.extra(),
+            TdObject::InternalLinkTypeActiveSessions(t) => t.extra(),
+            TdObject::InternalLinkTypeAuthenticationCode(t) => t.extra(),
+            TdObject::InternalLinkTypeBackground(t) => t.extra(),
+            TdObject::InternalLinkTypeBotStart(t) => t.extra(),
+            TdObject::InternalLinkTypeBotStartInGroup(t) => t.extra(),
+            TdObject::InternalLinkTypeChangePhoneNumber(t) => t.extra(),
+            TdObject::InternalLinkTypeChatInvite(t) => t.extra(),
+            TdObject::InternalLinkTypeFilterSettings(t) => t.extra(),
+            TdObject::InternalLinkTypeGame(t) => t.extra(),
+            TdObject::InternalLinkTypeLanguagePack(t) => t.extra(),
+            TdObject::InternalLinkTypeMessage(t) => t.extra(),
+            TdObject::InternalLinkTypeMessageDraft(t) => t.extra(),
+            TdObject::InternalLinkTypePassportDataRequest(t) => t.extra(),
+            TdObject::InternalLinkTypePhoneNumberConfirmation(t) => t.extra(),
+            TdObject::InternalLinkTypeProxy(t) => t.extra(),
+            TdObject::InternalLinkTypePublicChat(t) => t.extra(),
+            TdObject::InternalLinkTypeQrCodeAuthentication(t) => t.extra(),
+            TdObject::InternalLinkTypeSettings(t) => t.extra(),
+            TdObject::InternalLinkTypeStickerSet(t) => t.extra(),
+            TdObject::InternalLinkTypeTheme(t) => t.extra(),
+            TdObject::InternalLinkTypeThemeSettings(t) => t.extra(),
+            TdObject::InternalLinkTypeUnknownDeepLink(t) => t.extra(),
+            TdObject::InternalLinkTypeUnsupportedProxy(t) => t.extra(),
+            TdObject::InternalLinkTypeVideoChat(t) => t.extra(),
+            TdObject::Invoice(t) => t.extra(),
+            TdObject::JsonObjectMember(t) => t.extra(),
+            TdObject::JsonValueArray(t) => t.extra(),
+            TdObject::JsonValueBoolean(t) => t.extra(),
+            TdObject::JsonValueNull(t) => t.extra(),
+            TdObject::JsonValueNumber(t) => t.extra(),
+            TdObject::JsonValueObject(t) => t.extra(),
+            TdObject::JsonValueString(t) => t.extra(),
+            TdObject::KeyboardButton(t) => t.extra(),
+            TdObject::KeyboardButtonTypeRequestLocation(t) => t.extra(),
+            TdObject::KeyboardButtonTypeRequestPhoneNumber(t) => t.extra(),
+            TdObject::KeyboardButtonTypeRequestPoll(t) => t.extra(),
+            TdObject::KeyboardButtonTypeText(t) => t.extra(),
+            TdObject::LabeledPricePart(t) => t.extra(),
+            TdObject::LanguagePackInfo(t) => t.extra(),
+            TdObject::LanguagePackString(t) => t.extra(),
+            TdObject::LanguagePackStringValueDeleted(t) => t.extra(),
+            TdObject::LanguagePackStringValueOrdinary(t) => t.extra(),
+            TdObject::LanguagePackStringValuePluralized(t) => t.extra(),
+            TdObject::LanguagePackStrings(t) => t.extra(),
+            TdObject::LocalFile(t) => t.extra(),
+            TdObject::LocalizationTargetInfo(t) => t.extra(),
+            TdObject::Location(t) => t.extra(),
+            TdObject::LogStreamDefault(t) => t.extra(),
+            TdObject::LogStreamEmpty(t) => t.extra(),
+            TdObject::LogStreamFile(t) => t.extra(),
+            TdObject::LogTags(t) => t.extra(),
+            TdObject::LogVerbosityLevel(t) => t.extra(),
+            TdObject::LoginUrlInfoOpen(t) => t.extra(),
+            TdObject::LoginUrlInfoRequestConfirmation(t) => t.extra(),
+            TdObject::MaskPointChin(t) => t.extra(),
+            TdObject::MaskPointEyes(t) => t.extra(),
+            TdObject::MaskPointForehead(t) => t.extra(),
+            TdObject::MaskPointMouth(t) => t.extra(),
+            TdObject::MaskPosition(t) => t.extra(),
+            TdObject::Message(t) => t.extra(),
+            TdObject::MessageAnimatedEmoji(t) => t.extra(),
+            TdObject::MessageAnimation(t) => t.extra(),
+            TdObject::MessageAudio(t) => t.extra(),
+            TdObject::MessageBasicGroupChatCreate(t) => t.extra(),
+            TdObject::MessageCalendar(t) => t.extra(),
+            TdObject::MessageCalendarDay(t) => t.extra(),
+            TdObject::MessageCall(t) => t.extra(),
+            TdObject::MessageChatAddMembers(t) => t.extra(),
+            TdObject::MessageChatChangePhoto(t) => t.extra(),
+            TdObject::MessageChatChangeTitle(t) => t.extra(),
+            TdObject::MessageChatDeleteMember(t) => t.extra(),
+            TdObject::MessageChatDeletePhoto(t) => t.extra(),
+            TdObject::MessageChatJoinByLink(t) => t.extra(),
+            TdObject::MessageChatJoinByRequest(t) => t.extra(),
+            TdObject::MessageChatSetTheme(t) => t.extra(),
+            TdObject::MessageChatSetTtl(t) => t.extra(),
+            TdObject::MessageChatUpgradeFrom(t) => t.extra(),
+            TdObject::MessageChatUpgradeTo(t) => t.extra(),
+            TdObject::MessageContact(t) => t.extra(),
+            TdObject::MessageContactRegistered(t) => t.extra(),
+            TdObject::MessageCopyOptions(t) => t.extra(),
+            TdObject::MessageCustomServiceAction(t) => t.extra(),
+            TdObject::MessageDice(t) => t.extra(),
+            TdObject::MessageDocument(t) => t.extra(),
+            TdObject::MessageExpiredPhoto(t) => t.extra(),
+            TdObject::MessageExpiredVideo(t) => t.extra(),
+            TdObject::MessageFileTypeGroup(t) => t.extra(),
+            TdObject::MessageFileTypePrivate(t) => t.extra(),
+            TdObject::MessageFileTypeUnknown(t) => t.extra(),
+            TdObject::MessageForwardInfo(t) => t.extra(),
+            TdObject::MessageForwardOriginChannel(t) => t.extra(),
+            TdObject::MessageForwardOriginChat(t) => t.extra(),
+            TdObject::MessageForwardOriginHiddenUser(t) => t.extra(),
+            TdObject::MessageForwardOriginMessageImport(t) => t.extra(),
+            TdObject::MessageForwardOriginUser(t) => t.extra(),
+            TdObject::MessageGame(t) => t.extra(),
+            TdObject::MessageGameScore(t) => t.extra(),
+            TdObject::MessageInteractionInfo(t) => t.extra(),
+            TdObject::MessageInviteVideoChatParticipants(t) => t.extra(),
+            TdObject::MessageInvoice(t) => t.extra(),
+            TdObject::MessageLink(t) => t.extra(),
+            TdObject::MessageLinkInfo(t) => t.extra(),
+            TdObject::MessageLocation(t) => t.extra(),
+            TdObject::MessagePassportDataReceived(t) => t.extra(),
+            TdObject::MessagePassportDataSent(t) => t.extra(),
+            TdObject::MessagePaymentSuccessful(t) => t.extra(),
+            TdObject::MessagePaymentSuccessfulBot(t) => t.extra(),
+            TdObject::MessagePhoto(t) => t.extra(),
+            TdObject::MessagePinMessage(t) => t.extra(),
+            TdObject::MessagePoll(t) => t.extra(),
+            TdObject::MessagePosition(t) => t.extra(),
+            TdObject::MessagePositions(t) => t.extra(),
+            TdObject::MessageProximityAlertTriggered(t) => t.extra(),
+            TdObject::MessageReplyInfo(t) => t.extra(),
+            TdObject::MessageSchedulingStateSendAtDate(t) => t.extra(),
+            TdObject::MessageSchedulingStateSendWhenOnline(t) => t.extra(),
+            TdObject::MessageScreenshotTaken(t) => t.extra(),
+            TdObject::MessageSendOptions(t) => t.extra(),
+            TdObject::MessageSenderChat(t) => t.extra(),
+            TdObject::MessageSenderUser(t) => t.extra(),
+            TdObject::MessageSenders(t) => t.extra(),
+            TdObject::MessageSendingStateFailed(t) => t.extra(),
+            TdObject::MessageSendingStatePending(t) => t.extra(),
+            TdObject::MessageStatistics(t) => t.extra(),
+            TdObject::MessageSticker(t) => t.extra(),
+            TdObject::MessageSupergroupChatCreate(t) => t.extra(),
+            TdObject::MessageText(t) => t.extra(),
+            TdObject::MessageThreadInfo(t) => t.extra(),
+            TdObject::MessageUnsupported(t) => t.extra(),
+            TdObject::MessageVenue(t) => t.extra(),
+            TdObject::MessageVideo(t) => t.extra(),
+            TdObject::MessageVideoChatEnded(t) => t.extra(),
+            TdObject::MessageVideoChatScheduled(t) => t.extra(),
+            TdObject::MessageVideoChatStarted(t) => t.extra(),
+            TdObject::MessageVideoNote(t) => t.extra(),
+            TdObject::MessageVoiceNote(t) => t.extra(),
+            TdObject::MessageWebsiteConnected(t) => t.extra(),
+            TdObject::Messages(t) => t.extra(),
+            TdObject::Minithumbnail(t) => t.extra(),
+            TdObject::NetworkStatistics(t) => t.extra(),
+            TdObject::NetworkStatisticsEntryCall(t) => t.extra(),
+            TdObject::NetworkStatisticsEntryFile(t) => t.extra(),
+            TdObject::NetworkTypeMobile(t) => t.extra(),
+            TdObject::NetworkTypeMobileRoaming(t) => t.extra(),
+            TdObject::NetworkTypeNone(t) => t.extra(),
+            TdObject::NetworkTypeOther(t) => t.extra(),
+            TdObject::NetworkTypeWiFi(t) => t.extra(),
+            TdObject::Notification(t) => t.extra(),
+            TdObject::NotificationGroup(t) => t.extra(),
+            TdObject::NotificationGroupTypeCalls(t) => t.extra(),
+            TdObject::NotificationGroupTypeMentions(t) => t.extra(),
+            TdObject::NotificationGroupTypeMessages(t) => t.extra(),
+            TdObject::NotificationGroupTypeSecretChat(t) => t.extra(),
+            TdObject::NotificationSettingsScopeChannelChats(t) => t.extra(),
+            TdObject::NotificationSettingsScopeGroupChats(t) => t.extra(),
+            TdObject::NotificationSettingsScopePrivateChats(t) => t.extra(),
+            TdObject::NotificationTypeNewCall(t) => t.extra(),
+            TdObject::NotificationTypeNewMessage(t) => t.extra(),
+            TdObject::NotificationTypeNewPushMessage(t) => t.extra(),
+            TdObject::NotificationTypeNewSecretChat(t) => t.extra(),
+            TdObject::Ok(t) => t.extra(),
+            TdObject::OptionValueBoolean(t) => t.extra(),
+            TdObject::OptionValueEmpty(t) => t.extra(),
+            TdObject::OptionValueInteger(t) => t.extra(),
+            TdObject::OptionValueString(t) => t.extra(),
+            TdObject::OrderInfo(t) => t.extra(),
+            TdObject::PageBlockAnchor(t) => t.extra(),
+            TdObject::PageBlockAnimation(t) => t.extra(),
+            TdObject::PageBlockAudio(t) => t.extra(),
+            TdObject::PageBlockAuthorDate(t) => t.extra(),
+            TdObject::PageBlockBlockQuote(t) => t.extra(),
+            TdObject::PageBlockCaption(t) => t.extra(),
+            TdObject::PageBlockChatLink(t) => t.extra(),
+            TdObject::PageBlockCollage(t) => t.extra(),
+            TdObject::PageBlockCover(t) => t.extra(),
+            TdObject::PageBlockDetails(t) => t.extra(),
+            TdObject::PageBlockDivider(t) => t.extra(),
+            TdObject::PageBlockEmbedded(t) => t.extra(),
+            TdObject::PageBlockEmbeddedPost(t) => t.extra(),
+            TdObject::PageBlockFooter(t) => t.extra(),
+            TdObject::PageBlockHeader(t) => t.extra(),
+            TdObject::PageBlockHorizontalAlignmentCenter(t) => t.extra(),
+            TdObject::PageBlockHorizontalAlignmentLeft(t) => t.extra(),
+            TdObject::PageBlockHorizontalAlignmentRight(t) => t.extra(),
+            TdObject::PageBlockKicker(t) => t.extra(),
+            TdObject::PageBlockList(t) => t.extra(),
+            TdObject::PageBlockListItem(t) => t.extra(),
+            TdObject::PageBlockMap(t) => t.extra(),
+            TdObject::PageBlockParagraph(t) => t.extra(),
+            TdObject::PageBlockPhoto(t) => t.extra(),
+            TdObject::PageBlockPreformatted(t) => t.extra(),
+            TdObject::PageBlockPullQuote(t) => t.extra(),
+            TdObject::PageBlockRelatedArticle(t) => t.extra(),
+            TdObject::PageBlockRelatedArticles(t) => t.extra(),
+            TdObject::PageBlockSlideshow(t) => t.extra(),
+            TdObject::PageBlockSubheader(t) => t.extra(),
+            TdObject::PageBlockSubtitle(t) => t.extra(),
+            TdObject::PageBlockTable(t) => t.extra(),
+            TdObject::PageBlockTableCell(t) => t.extra(),
+            TdObject::PageBlockTitle(t) => t.extra(),
+            TdObject::PageBlockVerticalAlignmentBottom(t) => t.extra(),
+            TdObject::PageBlockVerticalAlignmentMiddle(t) => t.extra(),
+            TdObject::PageBlockVerticalAlignmentTop(t) => t.extra(),
+            TdObject::PageBlockVideo(t) => t.extra(),
+            TdObject::PageBlockVoiceNote(t) => t.extra(),
+            TdObject::PassportAuthorizationForm(t) => t.extra(),
+            TdObject::PassportElementAddress(t) => t.extra(),
+            TdObject::PassportElementBankStatement(t) => t.extra(),
+            TdObject::PassportElementDriverLicense(t) => t.extra(),
+            TdObject::PassportElementEmailAddress(t) => t.extra(),
+            TdObject::PassportElementError(t) => t.extra(),
+            TdObject::PassportElementErrorSourceDataField(t) => t.extra(),
+            TdObject::PassportElementErrorSourceFile(t) => t.extra(),
+            TdObject::PassportElementErrorSourceFiles(t) => t.extra(),
+            TdObject::PassportElementErrorSourceFrontSide(t) => t.extra(),
+            TdObject::PassportElementErrorSourceReverseSide(t) => t.extra(),
+            TdObject::PassportElementErrorSourceSelfie(t) => t.extra(),
+            TdObject::PassportElementErrorSourceTranslationFile(t) => t.extra(),
+            TdObject::PassportElementErrorSourceTranslationFiles(t) => t.extra(),
+            TdObject::PassportElementErrorSourceUnspecified(t) => t.extra(),
+            TdObject::PassportElementIdentityCard(t) => t.extra(),
+            TdObject::PassportElementInternalPassport(t) => t.extra(),
+            TdObject::PassportElementPassport(t) => t.extra(),
+            TdObject::PassportElementPassportRegistration(t) => t.extra(),
+            TdObject::PassportElementPersonalDetails(t) => t.extra(),
+            TdObject::PassportElementPhoneNumber(t) => t.extra(),
+            TdObject::PassportElementRentalAgreement(t) => t.extra(),
+            TdObject::PassportElementTemporaryRegistration(t) => t.extra(),
+            TdObject::PassportElementTypeAddress(t) => t.extra(),
+            TdObject::PassportElementTypeBankStatement(t) => t.extra(),
+            TdObject::PassportElementTypeDriverLicense(t) => t.extra(),
+            TdObject::PassportElementTypeEmailAddress(t) => t.extra(),
+            TdObject::PassportElementTypeIdentityCard(t) => t.extra(),
+            TdObject::PassportElementTypeInternalPassport(t) => t.extra(),
+            TdObject::PassportElementTypePassport(t) => t.extra(),
+            TdObject::PassportElementTypePassportRegistration(t) => t.extra(),
+            TdObject::PassportElementTypePersonalDetails(t) => t.extra(),
+            TdObject::PassportElementTypePhoneNumber(t) => t.extra(),
+            TdObject::PassportElementTypeRentalAgreement(t) => t.extra(),
+            TdObject::PassportElementTypeTemporaryRegistration(t) => t.extra(),
+            TdObject::PassportElementTypeUtilityBill(t) => t.extra(),
+            TdObject::PassportElementUtilityBill(t) => t.extra(),
+            TdObject::PassportElements(t) => t.extra(),
+            TdObject::PassportElementsWithErrors(t) => t.extra(),
+            TdObject::PassportRequiredElement(t) => t.extra(),
+            TdObject::PassportSuitableElement(t) => t.extra(),
+            TdObject::PasswordState(t) => t.extra(),
+            TdObject::PaymentForm(t) => t.extra(),
+            TdObject::PaymentFormTheme(t) => t.extra(),
+            TdObject::PaymentReceipt(t) => t.extra(),
+            TdObject::PaymentResult(t) => t.extra(),
+            TdObject::PaymentsProviderStripe(t) => t.extra(),
+            TdObject::PersonalDetails(t) => t.extra(),
+            TdObject::PersonalDocument(t) => t.extra(),
+            TdObject::PhoneNumberAuthenticationSettings(t) => t.extra(),
+            TdObject::PhoneNumberInfo(t) => t.extra(),
+            TdObject::Photo(t) => t.extra(),
+            TdObject::PhotoSize(t) => t.extra(),
+            TdObject::Point(t) => t.extra(),
+            TdObject::Poll(t) => t.extra(),
+            TdObject::PollOption(t) => t.extra(),
+            TdObject::PollTypeQuiz(t) => t.extra(),
+            TdObject::PollTypeRegular(t) => t.extra(),
+            TdObject::ProfilePhoto(t) => t.extra(),
+            TdObject::Proxies(t) => t.extra(),
+            TdObject::Proxy(t) => t.extra(),
+            TdObject::ProxyTypeHttp(t) => t.extra(),
+            TdObject::ProxyTypeMtproto(t) => t.extra(),
+            TdObject::ProxyTypeSocks5(t) => t.extra(),
+            TdObject::PublicChatTypeHasUsername(t) => t.extra(),
+            TdObject::PublicChatTypeIsLocationBased(t) => t.extra(),
+            TdObject::PushMessageContentAnimation(t) => t.extra(),
+            TdObject::PushMessageContentAudio(t) => t.extra(),
+            TdObject::PushMessageContentBasicGroupChatCreate(t) => t.extra(),
+            TdObject::PushMessageContentChatAddMembers(t) => t.extra(),
+            TdObject::PushMessageContentChatChangePhoto(t) => t.extra(),
+            TdObject::PushMessageContentChatChangeTitle(t) => t.extra(),
+            TdObject::PushMessageContentChatDeleteMember(t) => t.extra(),
+            TdObject::PushMessageContentChatJoinByLink(t) => t.extra(),
+            TdObject::PushMessageContentChatJoinByRequest(t) => t.extra(),
+            TdObject::PushMessageContentChatSetTheme(t) => t.extra(),
+            TdObject::PushMessageContentContact(t) => t.extra(),
+            TdObject::PushMessageContentContactRegistered(t) => t.extra(),
+            TdObject::PushMessageContentDocument(t) => t.extra(),
+            TdObject::PushMessageContentGame(t) => t.extra(),
+            TdObject::PushMessageContentGameScore(t) => t.extra(),
+            TdObject::PushMessageContentHidden(t) => t.extra(),
+            TdObject::PushMessageContentInvoice(t) => t.extra(),
+            TdObject::PushMessageContentLocation(t) => t.extra(),
+            TdObject::PushMessageContentMediaAlbum(t) => t.extra(),
+            TdObject::PushMessageContentMessageForwards(t) => t.extra(),
+            TdObject::PushMessageContentPhoto(t) => t.extra(),
+            TdObject::PushMessageContentPoll(t) => t.extra(),
+            TdObject::PushMessageContentScreenshotTaken(t) => t.extra(),
+            TdObject::PushMessageContentSticker(t) => t.extra(),
+            TdObject::PushMessageContentText(t) => t.extra(),
+            TdObject::PushMessageContentVideo(t) => t.extra(),
+            TdObject::PushMessageContentVideoNote(t) => t.extra(),
+            TdObject::PushMessageContentVoiceNote(t) => t.extra(),
+            TdObject::PushReceiverId(t) => t.extra(),
+            TdObject::RecommendedChatFilter(t) => t.extra(),
+            TdObject::RecommendedChatFilters(t) => t.extra(),
+            TdObject::RecoveryEmailAddress(t) => t.extra(),
+            TdObject::RemoteFile(t) => t.extra(),
+            TdObject::ReplyMarkupForceReply(t) => t.extra(),
+            TdObject::ReplyMarkupInlineKeyboard(t) => t.extra(),
+            TdObject::ReplyMarkupRemoveKeyboard(t) => t.extra(),
+            TdObject::ReplyMarkupShowKeyboard(t) => t.extra(),
+            TdObject::ResetPasswordResultDeclined(t) => t.extra(),
+            TdObject::ResetPasswordResultOk(t) => t.extra(),
+            TdObject::ResetPasswordResultPending(t) => t.extra(),
+            TdObject::RichTextAnchor(t) => t.extra(),
+            TdObject::RichTextAnchorLink(t) => t.extra(),
+            TdObject::RichTextBold(t) => t.extra(),
+            TdObject::RichTextEmailAddress(t) => t.extra(),
+            TdObject::RichTextFixed(t) => t.extra(),
+            TdObject::RichTextIcon(t) => t.extra(),
+            TdObject::RichTextItalic(t) => t.extra(),
+            TdObject::RichTextMarked(t) => t.extra(),
+            TdObject::RichTextPhoneNumber(t) => t.extra(),
+            TdObject::RichTextPlain(t) => t.extra(),
+            TdObject::RichTextReference(t) => t.extra(),
+            TdObject::RichTextStrikethrough(t) => t.extra(),
+            TdObject::RichTextSubscript(t) => t.extra(),
+            TdObject::RichTextSuperscript(t) => t.extra(),
+            TdObject::RichTextUnderline(t) => t.extra(),
+            TdObject::RichTextUrl(t) => t.extra(),
+            TdObject::RichTexts(t) => t.extra(),
+            TdObject::SavedCredentials(t) => t.extra(),
+            TdObject::ScopeNotificationSettings(t) => t.extra(),
+            TdObject::SearchMessagesFilterAnimation(t) => t.extra(),
+            TdObject::SearchMessagesFilterAudio(t) => t.extra(),
+            TdObject::SearchMessagesFilterChatPhoto(t) => t.extra(),
+            TdObject::SearchMessagesFilterDocument(t) => t.extra(),
+            TdObject::SearchMessagesFilterEmpty(t) => t.extra(),
+            TdObject::SearchMessagesFilterFailedToSend(t) => t.extra(),
+            TdObject::SearchMessagesFilterMention(t) => t.extra(),
+            TdObject::SearchMessagesFilterPhoto(t) => t.extra(),
+            TdObject::SearchMessagesFilterPhotoAndVideo(t) => t.extra(),
+            TdObject::SearchMessagesFilterPinned(t) => t.extra(),
+            TdObject::SearchMessagesFilterUnreadMention(t) => t.extra(),
+            TdObject::SearchMessagesFilterUrl(t) => t.extra(),
+            TdObject::SearchMessagesFilterVideo(t) => t.extra(),
+            TdObject::SearchMessagesFilterVideoNote(t) => t.extra(),
+            TdObject::SearchMessagesFilterVoiceAndVideoNote(t) => t.extra(),
+            TdObject::SearchMessagesFilterVoiceNote(t) => t.extra(),
+            TdObject::Seconds(t) => t.extra(),
+            TdObject::SecretChat(t) => t.extra(),
+            TdObject::SecretChatStateClosed(t) => t.extra(),
+            TdObject::SecretChatStatePending(t) => t.extra(),
+            TdObject::SecretChatStateReady(t) => t.extra(),
+            TdObject::Session(t) => t.extra(),
+            TdObject::Sessions(t) => t.extra(),
+            TdObject::ShippingOption(t) => t.extra(),
+            TdObject::SponsoredMessage(t) => t.extra(),
+            TdObject::StatisticalGraphAsync(t) => t.extra(),
+            TdObject::StatisticalGraphData(t) => t.extra(),
+            TdObject::StatisticalGraphError(t) => t.extra(),
+            TdObject::StatisticalValue(t) => t.extra(),
+            TdObject::Sticker(t) => t.extra(),
+            TdObject::StickerSet(t) => t.extra(),
+            TdObject::StickerSetInfo(t) => t.extra(),
+            TdObject::StickerSets(t) => t.extra(),
+            TdObject::Stickers(t) => t.extra(),
+            TdObject::StorageStatistics(t) => t.extra(),
+            TdObject::StorageStatisticsByChat(t) => t.extra(),
+            TdObject::StorageStatisticsByFileType(t) => t.extra(),
+            TdObject::StorageStatisticsFast(t) => t.extra(),
+            TdObject::SuggestedActionCheckPassword(t) => t.extra(),
+            TdObject::SuggestedActionCheckPhoneNumber(t) => t.extra(),
+            TdObject::SuggestedActionConvertToBroadcastGroup(t) => t.extra(),
+            TdObject::SuggestedActionEnableArchiveAndMuteNewChats(t) => t.extra(),
+            TdObject::SuggestedActionSetPassword(t) => t.extra(),
+            TdObject::SuggestedActionViewChecksHint(t) => t.extra(),
+            TdObject::Supergroup(t) => t.extra(),
+            TdObject::SupergroupFullInfo(t) => t.extra(),
+            TdObject::SupergroupMembersFilterAdministrators(t) => t.extra(),
+            TdObject::SupergroupMembersFilterBanned(t) => t.extra(),
+            TdObject::SupergroupMembersFilterBots(t) => t.extra(),
+            TdObject::SupergroupMembersFilterContacts(t) => t.extra(),
+            TdObject::SupergroupMembersFilterMention(t) => t.extra(),
+            TdObject::SupergroupMembersFilterRecent(t) => t.extra(),
+            TdObject::SupergroupMembersFilterRestricted(t) => t.extra(),
+            TdObject::SupergroupMembersFilterSearch(t) => t.extra(),
+            TdObject::TMeUrl(t) => t.extra(),
+            TdObject::TMeUrlTypeChatInvite(t) => t.extra(),
+            TdObject::TMeUrlTypeStickerSet(t) => t.extra(),
+            TdObject::TMeUrlTypeSupergroup(t) => t.extra(),
+            TdObject::TMeUrlTypeUser(t) => t.extra(),
+            TdObject::TMeUrls(t) => t.extra(),
+            TdObject::TdlibParameters(t) => t.extra(),
+            TdObject::TemporaryPasswordState(t) => t.extra(),
+            TdObject::TermsOfService(t) => t.extra(),
+            TdObject::TestBytes(t) => t.extra(),
+            TdObject::TestInt(t) => t.extra(),
+            TdObject::TestString(t) => t.extra(),
+            TdObject::TestVectorInt(t) => t.extra(),
+            TdObject::TestVectorIntObject(t) => t.extra(),
+            TdObject::TestVectorString(t) => t.extra(),
+            TdObject::TestVectorStringObject(t) => t.extra(),
+            TdObject::Text(t) => t.extra(),
+            TdObject::TextEntities(t) => t.extra(),
+            TdObject::TextEntity(t) => t.extra(),
+            TdObject::TextEntityTypeBankCardNumber(t) => t.extra(),
+            TdObject::TextEntityTypeBold(t) => t.extra(),
+            TdObject::TextEntityTypeBotCommand(t) => t.extra(),
+            TdObject::TextEntityTypeCashtag(t) => t.extra(),
+            TdObject::TextEntityTypeCode(t) => t.extra(),
+            TdObject::TextEntityTypeEmailAddress(t) => t.extra(),
+            TdObject::TextEntityTypeHashtag(t) => t.extra(),
+            TdObject::TextEntityTypeItalic(t) => t.extra(),
+            TdObject::TextEntityTypeMediaTimestamp(t) => t.extra(),
+            TdObject::TextEntityTypeMention(t) => t.extra(),
+            TdObject::TextEntityTypeMentionName(t) => t.extra(),
+            TdObject::TextEntityTypePhoneNumber(t) => t.extra(),
+            TdObject::TextEntityTypePre(t) => t.extra(),
+            TdObject::TextEntityTypePreCode(t) => t.extra(),
+            TdObject::TextEntityTypeStrikethrough(t) => t.extra(),
+            TdObject::TextEntityTypeTextUrl(t) => t.extra(),
+            TdObject::TextEntityTypeUnderline(t) => t.extra(),
+            TdObject::TextEntityTypeUrl(t) => t.extra(),
+            TdObject::TextParseModeHTML(t) => t.extra(),
+            TdObject::TextParseModeMarkdown(t) => t.extra(),
+            TdObject::ThemeSettings(t) => t.extra(),
+            TdObject::Thumbnail(t) => t.extra(),
+            TdObject::ThumbnailFormatGif(t) => t.extra(),
+            TdObject::ThumbnailFormatJpeg(t) => t.extra(),
+            TdObject::ThumbnailFormatMpeg4(t) => t.extra(),
+            TdObject::ThumbnailFormatPng(t) => t.extra(),
+            TdObject::ThumbnailFormatTgs(t) => t.extra(),
+            TdObject::ThumbnailFormatWebp(t) => t.extra(),
+            TdObject::TopChatCategoryBots(t) => t.extra(),
+            TdObject::TopChatCategoryCalls(t) => t.extra(),
+            TdObject::TopChatCategoryChannels(t) => t.extra(),
+            TdObject::TopChatCategoryForwardChats(t) => t.extra(),
+            TdObject::TopChatCategoryGroups(t) => t.extra(),
+            TdObject::TopChatCategoryInlineBots(t) => t.extra(),
+            TdObject::TopChatCategoryUsers(t) => t.extra(),
+            TdObject::UpdateActiveNotifications(t) => t.extra(),
+            TdObject::UpdateAnimatedEmojiMessageClicked(t) => t.extra(),
+            TdObject::UpdateAnimationSearchParameters(t) => t.extra(),
+            TdObject::UpdateAuthorizationState(t) => t.extra(),
+            TdObject::UpdateBasicGroup(t) => t.extra(),
+            TdObject::UpdateBasicGroupFullInfo(t) => t.extra(),
+            TdObject::UpdateCall(t) => t.extra(),
+            TdObject::UpdateChatAction(t) => t.extra(),
+            TdObject::UpdateChatActionBar(t) => t.extra(),
+            TdObject::UpdateChatDefaultDisableNotification(t) => t.extra(),
+            TdObject::UpdateChatDraftMessage(t) => t.extra(),
+            TdObject::UpdateChatFilters(t) => t.extra(),
+            TdObject::UpdateChatHasProtectedContent(t) => t.extra(),
+            TdObject::UpdateChatHasScheduledMessages(t) => t.extra(),
+            TdObject::UpdateChatIsBlocked(t) => t.extra(),
+            TdObject::UpdateChatIsMarkedAsUnread(t) => t.extra(),
+            TdObject::UpdateChatLastMessage(t) => t.extra(),
+            TdObject::UpdateChatMember(t) => t.extra(),
+            TdObject::UpdateChatMessageSender(t) => t.extra(),
+            TdObject::UpdateChatMessageTtl(t) => t.extra(),
+            TdObject::UpdateChatNotificationSettings(t) => t.extra(),
+            TdObject::UpdateChatOnlineMemberCount(t) => t.extra(),
+            TdObject::UpdateChatPendingJoinRequests(t) => t.extra(),
+            TdObject::UpdateChatPermissions(t) => t.extra(),
+            TdObject::UpdateChatPhoto(t) => t.extra(),
+            TdObject::UpdateChatPosition(t) => t.extra(),
+            TdObject::UpdateChatReadInbox(t) => t.extra(),
+            TdObject::UpdateChatReadOutbox(t) => t.extra(),
+            TdObject::UpdateChatReplyMarkup(t) => t.extra(),
+            TdObject::UpdateChatTheme(t) => t.extra(),
+            TdObject::UpdateChatThemes(t) => t.extra(),
+            TdObject::UpdateChatTitle(t) => t.extra(),
+            TdObject::UpdateChatUnreadMentionCount(t) => t.extra(),
+            TdObject::UpdateChatVideoChat(t) => t.extra(),
+            TdObject::UpdateConnectionState(t) => t.extra(),
+            TdObject::UpdateDeleteMessages(t) => t.extra(),
+            TdObject::UpdateDiceEmojis(t) => t.extra(),
+            TdObject::UpdateFavoriteStickers(t) => t.extra(),
+            TdObject::UpdateFile(t) => t.extra(),
+            TdObject::UpdateFileGenerationStart(t) => t.extra(),
+            TdObject::UpdateFileGenerationStop(t) => t.extra(),
+            TdObject::UpdateGroupCall(t) => t.extra(),
+            TdObject::UpdateGroupCallParticipant(t) => t.extra(),
+            TdObject::UpdateHavePendingNotifications(t) => t.extra(),
+            TdObject::UpdateInstalledStickerSets(t) => t.extra(),
+            TdObject::UpdateLanguagePackStrings(t) => t.extra(),
+            TdObject::UpdateMessageContent(t) => t.extra(),
+            TdObject::UpdateMessageContentOpened(t) => t.extra(),
+            TdObject::UpdateMessageEdited(t) => t.extra(),
+            TdObject::UpdateMessageInteractionInfo(t) => t.extra(),
+            TdObject::UpdateMessageIsPinned(t) => t.extra(),
+            TdObject::UpdateMessageLiveLocationViewed(t) => t.extra(),
+            TdObject::UpdateMessageMentionRead(t) => t.extra(),
+            TdObject::UpdateMessageSendAcknowledged(t) => t.extra(),
+            TdObject::UpdateMessageSendFailed(t) => t.extra(),
+            TdObject::UpdateMessageSendSucceeded(t) => t.extra(),
+            TdObject::UpdateNewCallSignalingData(t) => t.extra(),
+            TdObject::UpdateNewCallbackQuery(t) => t.extra(),
+            TdObject::UpdateNewChat(t) => t.extra(),
+            TdObject::UpdateNewChatJoinRequest(t) => t.extra(),
+            TdObject::UpdateNewChosenInlineResult(t) => t.extra(),
+            TdObject::UpdateNewCustomEvent(t) => t.extra(),
+            TdObject::UpdateNewCustomQuery(t) => t.extra(),
+            TdObject::UpdateNewInlineCallbackQuery(t) => t.extra(),
+            TdObject::UpdateNewInlineQuery(t) => t.extra(),
+            TdObject::UpdateNewMessage(t) => t.extra(),
+            TdObject::UpdateNewPreCheckoutQuery(t) => t.extra(),
+            TdObject::UpdateNewShippingQuery(t) => t.extra(),
+            TdObject::UpdateNotification(t) => t.extra(),
+            TdObject::UpdateNotificationGroup(t) => t.extra(),
+            TdObject::UpdateOption(t) => t.extra(),
+            TdObject::UpdatePoll(t) => t.extra(),
+            TdObject::UpdatePollAnswer(t) => t.extra(),
+            TdObject::UpdateRecentStickers(t) => t.extra(),
+            TdObject::UpdateSavedAnimations(t) => t.extra(),
+            TdObject::UpdateScopeNotificationSettings(t) => t.extra(),
+            TdObject::UpdateSecretChat(t) => t.extra(),
+            TdObject::UpdateSelectedBackground(t) => t.extra(),
+            TdObject::UpdateServiceNotification(t) => t.extra(),
+            TdObject::UpdateStickerSet(t) => t.extra(),
+            TdObject::UpdateSuggestedActions(t) => t.extra(),
+            TdObject::UpdateSupergroup(t) => t.extra(),
+            TdObject::UpdateSupergroupFullInfo(t) => t.extra(),
+            TdObject::UpdateTermsOfService(t) => t.extra(),
+            TdObject::UpdateTrendingStickerSets(t) => t.extra(),
+            TdObject::UpdateUnreadChatCount(t) => t.extra(),
+            TdObject::UpdateUnreadMessageCount(t) => t.extra(),
+            TdObject::UpdateUser(t) => t.extra(),
+            TdObject::UpdateUserFullInfo(t) => t.extra(),
+            TdObject::UpdateUserPrivacySettingRules(t) => t.extra(),
+            TdObject::UpdateUserStatus(t) => t.extra(),
+            TdObject::UpdateUsersNearby(t) => t.extra(),
+            TdObject::Updates(t) => t.extra(),
+            TdObject::User(t) => t.extra(),
+            TdObject::UserFullInfo(t) => t.extra(),
+            TdObject::UserPrivacySettingAllowCalls(t) => t.extra(),
+            TdObject::UserPrivacySettingAllowChatInvites(t) => t.extra(),
+            TdObject::UserPrivacySettingAllowFindingByPhoneNumber(t) => t.extra(),
+            TdObject::UserPrivacySettingAllowPeerToPeerCalls(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleAllowAll(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleAllowChatMembers(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleAllowContacts(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleAllowUsers(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleRestrictAll(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleRestrictChatMembers(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleRestrictContacts(t) => t.extra(),
+            TdObject::UserPrivacySettingRuleRestrictUsers(t) => t.extra(),
+            TdObject::UserPrivacySettingRules(t) => t.extra(),
+            TdObject::UserPrivacySettingShowLinkInForwardedMessages(t) => t.extra(),
+            TdObject::UserPrivacySettingShowPhoneNumber(t) => t.extra(),
+            TdObject::UserPrivacySettingShowProfilePhoto(t) => t.extra(),
+            TdObject::UserPrivacySettingShowStatus(t) => t.extra(),
+            TdObject::UserStatusEmpty(t) => t.extra(),
+            TdObject::UserStatusLastMonth(t) => t.extra(),
+            TdObject::UserStatusLastWeek(t) => t.extra(),
+            TdObject::UserStatusOffline(t) => t.extra(),
+            TdObject::UserStatusOnline(t) => t.extra(),
+            TdObject::UserStatusRecently(t) => t.extra(),
+            TdObject::UserTypeBot(t) => t.extra(),
+            TdObject::UserTypeDeleted(t) => t.extra(),
+            TdObject::UserTypeRegular(t) => t.extra(),
+            TdObject::UserTypeUnknown(t) => t.extra(),
+            TdObject::Users(t) => t.extra(),
+            TdObject::ValidatedOrderInfo(t) => t.extra(),
+            TdObject::VectorPathCommandCubicBezierCurve(t) => t.extra(),
+            TdObject::VectorPathCommandLine(t) => t.extra(),
+            TdObject::Venue(t) => t.extra(),
+            TdObject::Video(t) => t.extra(),
+            TdObject::VideoChat(t) => t.extra(),
+            TdObject::VideoNote(t) => t.extra(),
+            TdObject::VoiceNote(t) => t.extra(),
+            TdObject::WebPage(t) => t.extra(),
+            TdObject::WebPageInstantView(t) => t.extra(),
+            TdObject::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
+        }
+    }
+    #[doc(hidden)]
+    fn client_id(&self) -> Option<i32> {
+        match self {
+            TdObject::AccountTtl(t) => t.client_id(),
+            TdObject::Address(t) => t.client_id(),
+            TdObject::AnimatedChatPhoto(t) => t.client_id(),
+            TdObject::AnimatedEmoji(t) => t.client_id(),
+            TdObject::Animation(t) => t.client_id(),
+            TdObject::Animations(t) => t.client_id(),
+            TdObject::Audio(t) => t.client_id(),
+            TdObject::AuthenticationCodeInfo(t) => t.client_id(),
+            TdObject::AuthenticationCodeTypeCall(t) => t.client_id(),
+            TdObject::AuthenticationCodeTypeFlashCall(t) => t.client_id(),
+            TdObject::AuthenticationCodeTypeMissedCall(t) => t.client_id(),
+            TdObject::AuthenticationCodeTypeSms(t) => t.client_id(),
+            TdObject::AuthenticationCodeTypeTelegramMessage(t) => t.client_id(),
+            TdObject::AuthorizationStateClosed(t) => t.client_id(),
+            TdObject::AuthorizationStateClosing(t) => t.client_id(),
+            TdObject::AuthorizationStateLoggingOut(t) => t.client_id(),
+            TdObject::AuthorizationStateReady(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitCode(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitEncryptionKey(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitOtherDeviceConfirmation(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitPassword(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitPhoneNumber(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitRegistration(t) => t.client_id(),
+            TdObject::AuthorizationStateWaitTdlibParameters(t) => t.client_id(),
+            TdObject::AutoDownloadSettings(t) => t.client_id(),
+            TdObject::AutoDownloadSettingsPresets(t) => t.client_id(),
+            TdObject::Background(t) => t.client_id(),
+            TdObject::BackgroundFillFreeformGradient(t) => t.client_id(),
+            TdObject::BackgroundFillGradient(t) => t.client_id(),
+            TdObject::BackgroundFillSolid(t) => t.client_id(),
+            TdObject::BackgroundTypeFill(t) => t.client_id(),
+            TdObject::BackgroundTypePattern(t) => t.client_id(),
+            TdObject::BackgroundTypeWallpaper(t) => t.client_id(),
+            TdObject::Backgrounds(t) => t.client_id(),
+            TdObject::BankCardActionOpenUrl(t) => t.client_id(),
+            TdObject::BankCardInfo(t) => t.client_id(),
+            TdObject::BasicGroup(t) => t.client_id(),
+            TdObject::BasicGroupFullInfo(t) => t.client_id(),
+            TdObject::BotCommand(t) => t.client_id(),
+            TdObject::BotCommandScopeAllChatAdministrators(t) => t.client_id(),
+            TdObject::BotCommandScopeAllGroupChats(t) => t.client_id(),
+            TdObject::BotCommandScopeAllPrivateChats(t) => t.client_id(),
+            TdObject::BotCommandScopeChat(t) => t.client_id(),
+            TdObject::BotCommandScopeChatAdministrators(t) => t.client_id(),
+            TdObject::BotCommandScopeChatMember(t) => t.client_id(),
+            TdObject::BotCommandScopeDefault(t) => t.client_id(),
+            TdObject::BotCommands(t) => t.client_id(),
+            TdObject::Call(t) => t.client_id(),
+            TdObject::CallDiscardReasonDeclined(t) => t.client_id(),
+            TdObject::CallDiscardReasonDisconnected(t) => t.client_id(),
+            TdObject::CallDiscardReasonEmpty(t) => t.client_id(),
+            TdObject::CallDiscardReasonHungUp(t) => t.client_id(),
+            TdObject::CallDiscardReasonMissed(t) => t.client_id(),
+            TdObject::CallId(t) => t.client_id(),
+            TdObject::CallProblemDistortedSpeech(t) => t.client_id(),
+            TdObject::CallProblemDistortedVideo(t) => t.client_id(),
+            TdObject::CallProblemDropped(t) => t.client_id(),
+            TdObject::CallProblemEcho(t) => t.client_id(),
+            TdObject::CallProblemInterruptions(t) => t.client_id(),
+            TdObject::CallProblemNoise(t) => t.client_id(),
+            TdObject::CallProblemPixelatedVideo(t) => t.client_id(),
+            TdObject::CallProblemSilentLocal(t) => t.client_id(),
+            TdObject::CallProblemSilentRemote(t) => t.client_id(),
+            TdObject::CallProtocol(t) => t.client_id(),
+            TdObject::CallServer(t) => t.client_id(),
+            TdObject::CallServerTypeTelegramReflector(t) => t.client_id(),
+            TdObject::CallServerTypeWebrtc(t) => t.client_id(),
+            TdObject::CallStateDiscarded(t) => t.client_id(),
+            TdObject::CallStateError(t) => t.client_id(),
+            TdObject::CallStateExchangingKeys(t) => t.client_id(),
+            TdObject::CallStateHangingUp(t) => t.client_id(),
+            TdObject::CallStatePending(t) => t.client_id(),
+            TdObject::CallStateReady(t) => t.client_id(),
+            TdObject::CallbackQueryAnswer(t) => t.client_id(),
+            TdObject::CallbackQueryPayloadData(t) => t.client_id(),
+            TdObject::CallbackQueryPayloadDataWithPassword(t) => t.client_id(),
+            TdObject::CallbackQueryPayloadGame(t) => t.client_id(),
+            TdObject::CanTransferOwnershipResultOk(t) => t.client_id(),
+            TdObject::CanTransferOwnershipResultPasswordNeeded(t) => t.client_id(),
+            TdObject::CanTransferOwnershipResultPasswordTooFresh(t) => t.client_id(),
+            TdObject::CanTransferOwnershipResultSessionTooFresh(t) => t.client_id(),
+            TdObject::Chat(t) => t.client_id(),
+            TdObject::ChatActionBarAddContact(t) => t.client_id(),
+            TdObject::ChatActionBarInviteMembers(t) => t.client_id(),
+            TdObject::ChatActionBarJoinRequest(t) => t.client_id(),
+            TdObject::ChatActionBarReportAddBlock(t) => t.client_id(),
+            TdObject::ChatActionBarReportSpam(t) => t.client_id(),
+            TdObject::ChatActionBarReportUnrelatedLocation(t) => t.client_id(),
+            TdObject::ChatActionBarSharePhoneNumber(t) => t.client_id(),
+            TdObject::ChatActionCancel(t) => t.client_id(),
+            TdObject::ChatActionChoosingContact(t) => t.client_id(),
+            TdObject::ChatActionChoosingLocation(t) => t.client_id(),
+            TdObject::ChatActionChoosingSticker(t) => t.client_id(),
+            TdObject::ChatActionRecordingVideo(t) => t.client_id(),
+            TdObject::ChatActionRecordingVideoNote(t) => t.client_id(),
+            TdObject::ChatActionRecordingVoiceNote(t) => t.client_id(),
+            TdObject::ChatActionStartPlayingGame(t) => t.client_id(),
+            TdObject::ChatActionTyping(t) => t.client_id(),
+            TdObject::ChatActionUploadingDocument(t) => t.client_id(),
+            TdObject::ChatActionUploadingPhoto(t) => t.client_id(),
+            TdObject::ChatActionUploadingVideo(t) => t.client_id(),
+            TdObject::ChatActionUploadingVideoNote(t) => t.client_id(),
+            TdObject::ChatActionUploadingVoiceNote(t) => t.client_id(),
+            TdObject::ChatActionWatchingAnimations(t) => t.client_id(),
+            TdObject::ChatAdministrator(t) => t.client_id(),
+            TdObject::ChatAdministrators(t) => t.client_id(),
+            TdObject::ChatEvent(t) => t.client_id(),
+            TdObject::ChatEventDescriptionChanged(t) => t.client_id(),
+            TdObject::ChatEventHasProtectedContentToggled(t) => t.client_id(),
+            TdObject::ChatEventInviteLinkDeleted(t) => t.client_id(),
+            TdObject::ChatEventInviteLinkEdited(t) => t.client_id(),
+            TdObject::ChatEventInviteLinkRevoked(t) => t.client_id(),
+            TdObject::ChatEventInvitesToggled(t) => t.client_id(),
+            TdObject::ChatEventIsAllHistoryAvailableToggled(t) => t.client_id(),
+            TdObject::ChatEventLinkedChatChanged(t) => t.client_id(),
+            TdObject::ChatEventLocationChanged(t) => t.client_id(),
+            TdObject::ChatEventLogFilters(t) => t.client_id(),
+            TdObject::ChatEventMemberInvited(t) => t.client_id(),
+            TdObject::ChatEventMemberJoined(t) => t.client_id(),
+            TdObject::ChatEventMemberJoinedByInviteLink(t) => t.client_id(),
+            TdObject::ChatEventMemberJoinedByRequest(t) => t.client_id(),
+            TdObject::ChatEventMemberLeft(t) => t.client_id(),
+            TdObject::ChatEventMemberPromoted(t) => t.client_id(),
+            TdObject::ChatEventMemberRestricted(t) => t.client_id(),
+            TdObject::ChatEventMessageDeleted(t) => t.client_id(),
+            TdObject::ChatEventMessageEdited(t) => t.client_id(),
+            TdObject::ChatEventMessagePinned(t) => t.client_id(),
+            TdObject::ChatEventMessageTtlChanged(t) => t.client_id(),
+            TdObject::ChatEventMessageUnpinned(t) => t.client_id(),
+            TdObject::ChatEventPermissionsChanged(t) => t.client_id(),
+            TdObject::ChatEventPhotoChanged(t) => t.client_id(),
+            TdObject::ChatEventPollStopped(t) => t.client_id(),
+            TdObject::ChatEventSignMessagesToggled(t) => t.client_id(),
+            TdObject::ChatEventSlowModeDelayChanged(t) => t.client_id(),
+            TdObject::ChatEventStickerSetChanged(t) => t.client_id(),
+            TdObject::ChatEventTitleChanged(t) => t.client_id(),
+            TdObject::ChatEventUsernameChanged(t) => t.client_id(),
+            TdObject::ChatEventVideoChatCreated(t) => t.client_id(),
+            TdObject::ChatEventVideoChatEnded(t) => t.client_id(),
+            TdObject::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.client_id(),
+            TdObject::ChatEventVideoChatParticipantIsMutedToggled(t) => t.client_id(),
+            TdObject::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.client_id(),
+            TdObject::ChatEvents(t) => t.client_id(),
+            TdObject::ChatFilter(t) => t.client_id(),
+            TdObject::ChatFilterInfo(t) => t.client_id(),
+            TdObject::ChatInviteLink(t) => t.client_id(),
+            TdObject::ChatInviteLinkCount(t) => t.client_id(),
+            TdObject::ChatInviteLinkCounts(t) => t.client_id(),
+            TdObject::ChatInviteLinkInfo(t) => t.client_id(),
+            TdObject::ChatInviteLinkMember(t) => t.client_id(),
+            TdObject::ChatInviteLinkMembers(t) => t.client_id(),
+            TdObject::ChatInviteLinks(t) => t.client_id(),
+            TdObject::ChatJoinRequest(t) => t.client_id(),
+            TdObject::ChatJoinRequests(t) => t.client_id(),
+            TdObject::ChatJoinRequestsInfo(t) => t.client_id(),
+            TdObject::ChatListArchive(t) => t.client_id(),
+            TdObject::ChatListFilter(t) => t.client_id(),
+            TdObject::ChatListMain(t) => t.client_id(),
+            TdObject::ChatLists(t) => t.client_id(),
+            TdObject::ChatLocation(t) => t.client_id(),
+            TdObject::ChatMember(t) => t.client_id(),
+            TdObject::ChatMemberStatusAdministrator(t) => t.client_id(),
+            TdObject::ChatMemberStatusBanned(t) => t.client_id(),
+            TdObject::ChatMemberStatusCreator(t) => t.client_id(),
+            TdObject::ChatMemberStatusLeft(t) => t.client_id(),
+            TdObject::ChatMemberStatusMember(t) => t.client_id(),
+            TdObject::ChatMemberStatusRestricted(t) => t.client_id(),
+            TdObject::ChatMembers(t) => t.client_id(),
+            TdObject::ChatMembersFilterAdministrators(t) => t.client_id(),
+            TdObject::ChatMembersFilterBanned(t) => t.client_id(),
+            TdObject::ChatMembersFilterBots(t) => t.client_id(),
+            TdObject::ChatMembersFilterContacts(t) => t.client_id(),
+            TdObject::ChatMembersFilterMembers(t) => t.client_id(),
+            TdObject::ChatMembersFilterMention(t) => t.client_id(),
+            TdObject::ChatMembersFilterRestricted(t) => t.client_id(),
+            TdObject::ChatNearby(t) => t.client_id(),
+            TdObject::ChatNotificationSettings(t) => t.client_id(),
+            TdObject::ChatPermissions(t) => t.client_id(),
+            TdObject::ChatPhoto(t) => t.client_id(),
+            TdObject::ChatPhotoInfo(t) => t.client_id(),
+            TdObject::ChatPhotos(t) => t.client_id(),
+            TdObject::ChatPosition(t) => t.client_id(),
+            TdObject::ChatReportReasonChildAbuse(t) => t.client_id(),
+            TdObject::ChatReportReasonCopyright(t) => t.client_id(),
+            TdObject::ChatReportReasonCustom(t) => t.client_id(),
+            TdObject::ChatReportReasonFake(t) => t.client_id(),
+            TdObject::ChatReportReasonPornography(t) => t.client_id(),
+            TdObject::ChatReportReasonSpam(t) => t.client_id(),
+            TdObject::ChatReportReasonUnrelatedLocation(t) => t.client_id(),
+            TdObject::ChatReportReasonViolence(t) => t.client_id(),
+            TdObject::ChatSourceMtprotoProxy(t) => t.client_id(),
+            TdObject::ChatSourcePublicServiceAnnouncement(t) => t.client_id(),
+            TdObject::ChatStatisticsAdministratorActionsInfo(t) => t.client_id(),
+            TdObject::ChatStatisticsChannel(t) => t.client_id(),
+            TdObject::ChatStatisticsInviterInfo(t) => t.client_id(),
+            TdObject::ChatStatisticsMessageInteractionInfo(t) => t.client_id(),
+            TdObject::ChatStatisticsMessageSenderInfo(t) => t.client_id(),
+            TdObject::ChatStatisticsSupergroup(t) => t.client_id(),
+            TdObject::ChatTheme(t) => t.client_id(),
+            TdObject::ChatTypeBasicGroup(t) => t.client_id(),
+            TdObject::ChatTypePrivate(t) => t.client_id(),
+            TdObject::ChatTypeSecret(t) => t.client_id(),
+            TdObject::ChatTypeSupergroup(t) => t.client_id(),
+            TdObject::Chats(t) => t.client_id(),
+            TdObject::ChatsNearby(t) => t.client_id(),
+            TdObject::CheckChatUsernameResultOk(t) => t.client_id(),
+            TdObject::CheckChatUsernameResultPublicChatsTooMuch(t) => t.client_id(),
+            TdObject::CheckChatUsernameResultPublicGroupsUnavailable(t) => t.client_id(),
+            TdObject::CheckChatUsernameResultUsernameInvalid(t) => t.client_id(),
+            TdObject::CheckChatUsernameResultUsernameOccupied(t) => t.client_id(),
+            TdObject::CheckStickerSetNameResultNameInvalid(t) => t.client_id(),
+            TdObject::CheckStickerSetNameResultNameOccupied(t) => t.client_id(),
+            TdObject::CheckStickerSetNameResultOk(t) => t.client_id(),
+            TdObject::ClosedVectorPath(t) => t.client_id(),
+            TdObject::ConnectedWebsite(t) => t.client_id(),
+            TdObject::ConnectedWebsites(t) => t.client_id(),
+            TdObject::ConnectionStateConnecting(t) => t.client_id(),
+            TdObject::ConnectionStateConnectingToProxy(t) => t.client_id(),
+            TdObject::ConnectionStateReady(t) => t.client_id(),
+            TdObject::ConnectionStateUpdating(t) => t.client_id(),
+            TdObject::ConnectionStateWaitingForNetwork(t) => t.client_id(),
+            TdObject::Contact(t) => t.client_id(),
+            TdObject::Count(t) => t.client_id(),
+            TdObject::Countries(t) => t.client_id(),
+            TdObject::CountryInfo(t) => t.client_id(),
+            TdObject::CustomRequestResult(t) => t.client_id(),
+            TdObject::DatabaseStatistics(t) => t.client_id(),
+            TdObject::Date(t) => t.client_id(),
+            TdObject::DateRange(t) => t.client_id(),
+            TdObject::DatedFile(t) => t.client_id(),
+            TdObject::DeepLinkInfo(t) => t.client_id(),
+            TdObject::DeviceTokenApplePush(t) => t.client_id(),
+            TdObject::DeviceTokenApplePushVoIP(t) => t.client_id(),
+            TdObject::DeviceTokenBlackBerryPush(t) => t.client_id(),
+            TdObject::DeviceTokenFirebaseCloudMessaging(t) => t.client_id(),
+            TdObject::DeviceTokenMicrosoftPush(t) => t.client_id(),
+            TdObject::DeviceTokenMicrosoftPushVoIP(t) => t.client_id(),
+            TdObject::DeviceTokenSimplePush(t) => t.client_id(),
+            TdObject::DeviceTokenTizenPush(t) => t.client_id(),
+            TdObject::DeviceTokenUbuntuPush(t) => t.client_id(),
+            TdObject::DeviceTokenWebPush(t) => t.client_id(),
+            TdObject::DeviceTokenWindowsPush(t) => t.client_id(),
+            TdObject::DiceStickersRegular(t) => t.client_id(),
+            TdObject::DiceStickersSlotMachine(t) => t.client_id(),
+            TdObject::Document(t) => t.client_id(),
+            TdObject::DraftMessage(t) => t.client_id(),
+            TdObject::EmailAddressAuthenticationCodeInfo(t) => t.client_id(),
+            TdObject::Emojis(t) => t.client_id(),
+            TdObject::EncryptedCredentials(t) => t.client_id(),
+            TdObject::EncryptedPassportElement(t) => t.client_id(),
+            TdObject::Error(t) => t.client_id(),
+            TdObject::File(t) => t.client_id(),
+            TdObject::FilePart(t) => t.client_id(),
+            TdObject::FileTypeAnimation(t) => t.client_id(),
+            TdObject::FileTypeAudio(t) => t.client_id(),
+            TdObject::FileTypeDocument(t) => t.client_id(),
+            TdObject::FileTypeNone(t) => t.client_id(),
+            TdObject::FileTypePhoto(t) => t.client_id(),
+            TdObject::FileTypeProfilePhoto(t) => t.client_id(),
+            TdObject::FileTypeSecret(t) => t.client_id(),
+            TdObject::FileTypeSecretThumbnail(t) => t.client_id(),
+            TdObject::FileTypeSecure(t) => t.client_id(),
+            TdObject::FileTypeSticker(t) => t.client_id(),
+            TdObject::FileTypeThumbnail(t) => t.client_id(),
+            TdObject::FileTypeUnknown(t) => t.client_id(),
+            TdObject::FileTypeVideo(t) => t.client_id(),
+            TdObject::FileTypeVideoNote(t) => t.client_id(),
+            TdObject::FileTypeVoiceNote(t) => t.client_id(),
+            TdObject::FileTypeWallpaper(t) => t.client_id(),
+            TdObject::FormattedText(t) => t.client_id(),
+            TdObject::FoundMessages(t) => t.client_id(),
+            TdObject::Game(t) => t.client_id(),
+            TdObject::GameHighScore(t) => t.client_id(),
+            TdObject::GameHighScores(t) => t.client_id(),
+            TdObject::GroupCall(t) => t.client_id(),
+            TdObject::GroupCallId(t) => t.client_id(),
+            TdObject::GroupCallParticipant(t) => t.client_id(),
+            TdObject::GroupCallParticipantVideoInfo(t) => t.client_id(),
+            TdObject::GroupCallRecentSpeaker(t) => t.client_id(),
+            TdObject::GroupCallVideoQualityFull(t) => t.client_id(),
+            TdObject::GroupCallVideoQualityMedium(t) => t.client_id(),
+            TdObject::GroupCallVideoQualityThumbnail(t) => t.client_id(),
+            TdObject::GroupCallVideoSourceGroup(t) => t.client_id(),
+            TdObject::Hashtags(t) => t.client_id(),
+            TdObject::HttpUrl(t) => t.client_id(),
+            TdObject::IdentityDocument(t) => t.client_id(),
+            TdObject::ImportedContacts(t) => t.client_id(),
+            TdObject::InlineKeyboardButton(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeBuy(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeCallback(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeCallbackGame(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeCallbackWithPassword(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeLoginUrl(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeSwitchInline(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeUrl(t) => t.client_id(),
+            TdObject::InlineKeyboardButtonTypeUser(t) => t.client_id(),
+            TdObject::InlineQueryResultAnimation(t) => t.client_id(),
+            TdObject::InlineQueryResultArticle(t) => t.client_id(),
+            TdObject::InlineQueryResultAudio(t) => t.client_id(),
+            TdObject::InlineQueryResultContact(t) => t.client_id(),
+            TdObject::InlineQueryResultDocument(t) => t.client_id(),
+            TdObject::InlineQueryResultGame(t) => t.client_id(),
+            TdObject::InlineQueryResultLocation(t) => t.client_id(),
+            TdObject::InlineQueryResultPhoto(t) => t.client_id(),
+            TdObject::InlineQueryResultSticker(t) => t.client_id(),
+            TdObject::InlineQueryResultVenue(t) => t.client_id(),
+            TdObject::InlineQueryResultVideo(t) => t.client_id(),
+            TdObject::InlineQueryResultVoiceNote(t) => t.client_id(),
+            TdObject::InlineQueryResults(t) => t.client_id(),
+            TdObject::InputBackgroundLocal(t) => t.client_id(),
+            TdObject::InputBackgroundRemote(t) => t.client_id(),
+            TdObject::InputChatPhotoAnimation(t) => t.client_id(),
+            TdObject::InputChatPhotoPrevious(t) => t.client_id(),
+            TdObject::InputChatPhotoStatic(t) => t.client_id(),
+            TdObject::InputCredentialsApplePay(t) => t.client_id(),
+            TdObject::InputCredentialsGooglePay(t) => t.client_id(),
+            TdObject::InputCredentialsNew(t) => t.client_id(),
+            TdObject::InputCredentialsSaved(t) => t.client_id(),
+            TdObject::InputFileGenerated(t) => t.client_id(),
+            TdObject::InputFileId(t) => t.client_id(),
+            TdObject::InputFileLocal(t) => t.client_id(),
+            TdObject::InputFileRemote(t) => t.client_id(),
+            TdObject::InputIdentityDocument(t) => t.client_id(),
+            TdObject::InputInlineQueryResultAnimation(t) => t.client_id(),
+            TdObject::InputInlineQueryResultArticle(t) => t.client_id(),
+            TdObject::InputInlineQueryResultAudio(t) => t.client_id(),
+            TdObject::InputInlineQueryResultContact(t) => t.client_id(),
+            TdObject::InputInlineQueryResultDocument(t) => t.client_id(),
+            TdObject::InputInlineQueryResultGame(t) => t.client_id(),
+            TdObject::InputInlineQueryResultLocation(t) => t.client_id(),
+            TdObject::InputInlineQueryResultPhoto(t) => t.client_id(),
+            TdObject::InputInlineQueryResultSticker(t) => t.client_id(),
+            TdObject::InputInlineQueryResultVenue(t) => t.client_id(),
+            TdObject::InputInlineQueryResultVideo(t) => t.client_id(),
+            TdObject::InputInlineQueryResultVoiceNote(t) => t.client_id(),
+            TdObject::InputMessageAnimation(t) => t.client_id(),
+            TdObject::InputMessageAudio(t) => t.client_id(),
+            TdObject::InputMessageContact(t) => t.client_id(),
+            TdObject::InputMessageDice(t) => t.client_id(),
+            TdObject::InputMessageDocument(t) => t.client_id(),
+            TdObject::InputMessageForwarded(t) => t.client_id(),
+            TdObject::InputMessageGame(t) => t.client_id(),
+            TdObject::InputMessageInvoice(t) => t.client_id(),
+            TdObject::InputMessageLocation(t) => t.client_id(),
+            TdObject::InputMessagePhoto(t) => t.client_id(),
+            TdObject::InputMessagePoll(t) => t.client_id(),
+            TdObject::InputMessageSticker(t) => t.client_id(),
+            TdObject::InputMessageText(t) => t.client_id(),
+            TdObject::InputMessageVenue(t) => t.client_id(),
+            TdObject::InputMessageVideo(t) => t.client_id(),
+            TdObject::InputMessageVideoNote(t) => t.client_id(),
+            TdObject::InputMessageVoiceNote(t) => t.client_id(),
+            TdObject::InputPassportElementAddress(t) => t.client_id(),
+            TdObject::InputPassportElementBankStatement(t) => t.client_id(),
+            TdObject::InputPassportElementDriverLicense(t) => t.client_id(),
+            TdObject::InputPassportElementEmailAddress(t) => t.client_id(),
+            TdObject::InputPassportElementError(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceDataField(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceFile(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceFiles(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceFrontSide(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceReverseSide(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceSelfie(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceTranslationFile(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceTranslationFiles(t) => t.client_id(),
+            TdObject::InputPassportElementErrorSourceUnspecified(t) => t.client_id(),
+            TdObject::InputPassportElementIdentityCard(t) => t.client_id(),
+            TdObject::InputPassportElementInternalPassport(t) => t.client_id(),
+            TdObject::InputPassportElementPassport(t) => t.client_id(),
+            TdObject::InputPassportElementPassportRegistration(t) => t.client_id(),
+            TdObject::InputPassportElementPersonalDetails(t) => t.client_id(),
+            TdObject::InputPassportElementPhoneNumber(t) => t.client_id(),
+            TdObject::InputPassportElementRentalAgreement(t) => t.client_id(),
+            TdObject::InputPassportElementTemporaryRegistration(t) => t.client_id(),
+            TdObject::InputPassportElementUtilityBill(t) => t.client_id(),
+            TdObject::InputPersonalDocument(t) => t.client_id(),
+            TdObject::InputStickerAnimated(t) => t.client_id(),
+            TdObject::InputStickerStatic(t) => t.client_id(),
+            TdObject::InputThumbnail(t) => t.client_id(),
+            TdObject::InternalLinkTypeActiveSessions(t) => t.client_id(),
+            TdObject::InternalLinkTypeAuthenticationCode(t) => t.client_id(),
+            TdObject::InternalLinkTypeBackground(t) => t.client_id(),
+            TdObject::InternalLinkTypeBotStart(t) => t.client_id(),
+            TdObject::InternalLinkTypeBotStartInGroup(t) => t.client_id(),
+            TdObject::InternalLinkTypeChangePhoneNumber(t) => t.client_id(),
+            TdObject::InternalLinkTypeChatInvite(t) => t.client_id(),
+            TdObject::InternalLinkTypeFilterSettings(t) => t.client_id(),
+            TdObject::InternalLinkTypeGame(t) => t.client_id(),
+            TdObject::InternalLinkTypeLanguagePack(t) => t.client_id(),
+            TdObject::InternalLinkTypeMessage(t) => t.client_id(),
+            TdObject::InternalLinkTypeMessageDraft(t) => t.client_id(),
+            TdObject::InternalLinkTypePassportDataRequest(t) => t.client_id(),
+            TdObject::InternalLinkTypePhoneNumberConfirmation(t) => t.client_id(),
+            TdObject::InternalLinkTypeProxy(t) => t.client_id(),
+            TdObject::InternalLinkTypePublicChat(t) => t.client_id(),
+            TdObject::InternalLinkTypeQrCodeAuthentication(t) => t.client_id(),
+            TdObject::InternalLinkTypeSettings(t) => t.client_id(),
+            TdObject::InternalLinkTypeStickerSet(t) => t.client_id(),
+            TdObject::InternalLinkTypeTheme(t) => t.client_id(),
+            TdObject::InternalLinkTypeThemeSettings(t) => t.client_id(),
+            TdObject::InternalLinkTypeUnknownDeepLink(t) => t.client_id(),
+            TdObject::InternalLinkTypeUnsupportedProxy(t) => t.client_id(),
+            TdObject::InternalLinkTypeVideoChat(t) => t.client_id(),
+            TdObject::Invoice(t) => t.client_id(),
+            TdObject::JsonObjectMember(t) => t.client_id(),
+            TdObject::JsonValueArray(t) => t.client_id(),
+            TdObject::JsonValueBoolean(t) => t.client_id(),
+            TdObject::JsonValueNull(t) => t.client_id(),
+            TdObject::JsonValueNumber(t) => t.client_id(),
+            TdObject::JsonValueObject(t) => t.client_id(),
+            TdObject::JsonValueString(t) => t.client_id(),
+            TdObject::KeyboardButton(t) => t.client_id(),
+            TdObject::KeyboardButtonTypeRequestLocation(t) => t.client_id(),
+            TdObject::KeyboardButtonTypeRequestPhoneNumber(t) => t.client_id(),
+            TdObject::KeyboardButtonTypeRequestPoll(t) => t.client_id(),
+            TdObject::KeyboardButtonTypeText(t) => t.client_id(),
+            TdObject::LabeledPricePart(t) => t.client_id(),
+            TdObject::LanguagePackInfo(t) => t.client_id(),
+            TdObject::LanguagePackString(t) => t.client_id(),
+            TdObject::LanguagePackStringValueDeleted(t) => t.client_id(),
+            TdObject::LanguagePackStringValueOrdinary(t) => t.client_id(),
+            TdObject::LanguagePackStringValuePluralized(t) => t.client_id(),
+            TdObject::LanguagePackStrings(t) => t.client_id(),
+            TdObject::LocalFile(t) => t.client_id(),
+            TdObject::LocalizationTargetInfo(t) => t.client_id(),
+            TdObject::Location(t) => t.client_id(),
+            TdObject::LogStreamDefault(t) => t.client_id(),
+            TdObject::LogStreamEmpty(t) => t.client_id(),
+            TdObject::LogStreamFile(t) => t.client_id(),
+            TdObject::LogTags(t) => t.client_id(),
+            TdObject::LogVerbosityLevel(t) => t.client_id(),
+            TdObject::LoginUrlInfoOpen(t) => t.client_id(),
+            TdObject::LoginUrlInfoRequestConfirmation(t) => t.client_id(),
+            TdObject::MaskPointChin(t) => t.client_id(),
+            TdObject::MaskPointEyes(t) => t.client_id(),
+            TdObject::MaskPointForehead(t) => t.client_id(),
+            TdObject::MaskPointMouth(t) => t.client_id(),
+            TdObject::MaskPosition(t) => t.client_id(),
+            TdObject::Message(t) => t.client_id(),
+            TdObject::MessageAnimatedEmoji(t) => t.client_id(),
+            TdObject::MessageAnimation(t) => t.client_id(),
+            TdObject::MessageAudio(t) => t.client_id(),
+            TdObject::MessageBasicGroupChatCreate(t) => t.client_id(),
+            TdObject::MessageCalendar(t) => t.client_id(),
+            TdObject::MessageCalendarDay(t) => t.client_id(),
+            TdObject::MessageCall(t) => t.client_id(),
+            TdObject::MessageChatAddMembers(t) => t.client_id(),
+            TdObject::MessageChatChangePhoto(t) => t.client_id(),
+            TdObject::MessageChatChangeTitle(t) => t.client_id(),
+            TdObject::MessageChatDeleteMember(t) => t.client_id(),
+            TdObject::MessageChatDeletePhoto(t) => t.client_id(),
+            TdObject::MessageChatJoinByLink(t) => t.client_id(),
+            TdObject::MessageChatJoinByRequest(t) => t.client_id(),
+            TdObject::MessageChatSetTheme(t) => t.client_id(),
+            TdObject::MessageChatSetTtl(t) => t.client_id(),
+            TdObject::MessageChatUpgradeFrom(t) => t.client_id(),
+            TdObject::MessageChatUpgradeTo(t) => t.client_id(),
+            TdObject::MessageContact(t) => t.client_id(),
+            TdObject::MessageContactRegistered(t) => t.client_id(),
+            TdObject::MessageCopyOptions(t) => t.client_id(),
+            TdObject::MessageCustomServiceAction(t) => t.client_id(),
+            TdObject::MessageDice(t) => t.client_id(),
+            TdObject::MessageDocument(t) => t.client_id(),
+            TdObject::MessageExpiredPhoto(t) => t.client_id(),
+            TdObject::MessageExpiredVideo(t) => t.client_id(),
+            TdObject::MessageFileTypeGroup(t) => t.client_id(),
+            TdObject::MessageFileTypePrivate(t) => t.client_id(),
+            TdObject::MessageFileTypeUnknown(t) => t.client_id(),
+            TdObject::MessageForwardInfo(t) => t.client_id(),
+            TdObject::MessageForwardOriginChannel(t) => t.client_id(),
+            TdObject::MessageForwardOriginChat(t) => t.client_id(),
+            TdObject::MessageForwardOriginHiddenUser(t) => t.client_id(),
+            TdObject::MessageForwardOriginMessageImport(t) => t.client_id(),
+            TdObject::MessageForwardOriginUser(t) => t.client_id(),
+            TdObject::MessageGame(t) => t.client_id(),
+            TdObject::MessageGameScore(t) => t.client_id(),
+            TdObject::MessageInteractionInfo(t) => t.client_id(),
+            TdObject::MessageInviteVideoChatParticipants(t) => t.client_id(),
+            TdObject::MessageInvoice(t) => t.client_id(),
+            TdObject::MessageLink(t) => t.client_id(),
+            TdObject::MessageLinkInfo(t) => t.client_id(),
+            TdObject::MessageLocation(t) => t.client_id(),
+            TdObject::MessagePassportDataReceived(t) => t.client_id(),
+            TdObject::MessagePassportDataSent(t) => t.client_id(),
+            TdObject::MessagePaymentSuccessful(t) => t.client_id(),
+            TdObject::MessagePaymentSuccessfulBot(t) => t.client_id(),
+            TdObject::MessagePhoto(t) => t.client_id(),
+            TdObject::MessagePinMessage(t) => t.client_id(),
+            TdObject::MessagePoll(t) => t.client_id(),
+            TdObject::MessagePosition(t) => t.client_id(),
+            TdObject::MessagePositions(t) => t.client_id(),
+            TdObject::MessageProximityAlertTriggered(t) => t.client_id(),
+            TdObject::MessageReplyInfo(t) => t.client_id(),
+            TdObject::MessageSchedulingStateSendAtDate(t) => t.client_id(),
+            TdObject::MessageSchedulingStateSendWhenOnline(t) => t.client_id(),
+            TdObject::MessageScreenshotTaken(t) => t.client_id(),
+            TdObject::MessageSendOptions(t) => t.client_id(),
+            TdObject::MessageSenderChat(t) => t.client_id(),
+            TdObject::MessageSenderUser(t) => t.client_id(),
+            TdObject::MessageSenders(t) => t.client_id(),
+            TdObject::MessageSendingStateFailed(t) => t.client_id(),
+            TdObject::MessageSendingStatePending(t) => t.client_id(),
+            TdObject::MessageStatistics(t) => t.client_id(),
+            TdObject::MessageSticker(t) => t.client_id(),
+            TdObject::MessageSupergroupChatCreate(t) => t.client_id(),
+            TdObject::MessageText(t) => t.client_id(),
+            TdObject::MessageThreadInfo(t) => t.client_id(),
+            TdObject::MessageUnsupported(t) => t.client_id(),
+            TdObject::MessageVenue(t) => t.client_id(),
+            TdObject::MessageVideo(t) => t.client_id(),
+            TdObject::MessageVideoChatEnded(t) => t.client_id(),
+            TdObject::MessageVideoChatScheduled(t) => t.client_id(),
+            TdObject::MessageVideoChatStarted(t) => t.client_id(),
+            TdObject::MessageVideoNote(t) => t.client_id(),
+            TdObject::MessageVoiceNote(t) => t.client_id(),
+            TdObject::MessageWebsiteConnected(t) => t.client_id(),
+            TdObject::Messages(t) => t.client_id(),
+            TdObject::Minithumbnail(t) => t.client_id(),
+            TdObject::NetworkStatistics(t) => t.client_id(),
+            TdObject::NetworkStatisticsEntryCall(t) => t.client_id(),
+            TdObject::NetworkStatisticsEntryFile(t) => t.client_id(),
+            TdObject::NetworkTypeMobile(t) => t.client_id(),
+            TdObject::NetworkTypeMobileRoaming(t) => t.client_id(),
+            TdObject::NetworkTypeNone(t) => t.client_id(),
+            TdObject::NetworkTypeOther(t) => t.client_id(),
+            TdObject::NetworkTypeWiFi(t) => t.client_id(),
+            TdObject::Notification(t) => t.client_id(),
+            TdObject::NotificationGroup(t) => t.client_id(),
+            TdObject::NotificationGroupTypeCalls(t) => t.client_id(),
+            TdObject::NotificationGroupTypeMentions(t) => t.client_id(),
+            TdObject::NotificationGroupTypeMessages(t) => t.client_id(),
+            TdObject::NotificationGroupTypeSecretChat(t) => t.client_id(),
+            TdObject::NotificationSettingsScopeChannelChats(t) => t.client_id(),
+            TdObject::NotificationSettingsScopeGroupChats(t) => t.client_id(),
+            TdObject::NotificationSettingsScopePrivateChats(t) => t.client_id(),
+            TdObject::NotificationTypeNewCall(t) => t.client_id(),
+            TdObject::NotificationTypeNewMessage(t) => t.client_id(),
+            TdObject::NotificationTypeNewPushMessage(t) => t.client_id(),
+            TdObject::NotificationTypeNewSecretChat(t) => t.client_id(),
+            TdObject::Ok(t) => t.client_id(),
+            TdObject::OptionValueBoolean(t) => t.client_id(),
+            TdObject::OptionValueEmpty(t) => t.client_id(),
+            TdObject::OptionValueInteger(t) => t.client_id(),
+            TdObject::OptionValueString(t) => t.client_id(),
+            TdObject::OrderInfo(t) => t.client_id(),
+            TdObject::PageBlockAnchor(t) => t.client_id(),
+            TdObject::PageBlockAnimation(t) => t.client_id(),
+            TdObject::PageBlockAudio(t) => t.client_id(),
+            TdObject::PageBlockAuthorDate(t) => t.client_id(),
+            TdObject::PageBlockBlockQuote(t) => t.client_id(),
+            TdObject::PageBlockCaption(t) => t.client_id(),
+            TdObject::PageBlockChatLink(t) => t.client_id(),
+            TdObject::PageBlockCollage(t) => t.client_id(),
+            TdObject::PageBlockCover(t) => t.client_id(),
+            TdObject::PageBlockDetails(t) => t.client_id(),
+            TdObject::PageBlockDivider(t) => t.client_id(),
+            TdObject::PageBlockEmbedded(t) => t.client_id(),
+            TdObject::PageBlockEmbeddedPost(t) => t.client_id(),
+            TdObject::PageBlockFooter(t) => t.client_id(),
+            TdObject::PageBlockHeader(t) => t.client_id(),
+            TdObject::PageBlockHorizontalAlignmentCenter(t) => t.client_id(),
+            TdObject::PageBlockHorizontalAlignmentLeft(t) => t.client_id(),
+            TdObject::PageBlockHorizontalAlignmentRight(t) => t.client_id(),
+            TdObject::PageBlockKicker(t) => t.client_id(),
+            TdObject::PageBlockList(t) => t.client_id(),
+            TdObject::PageBlockListItem(t) => t.client_id(),
+            TdObject::PageBlockMap(t) => t.client_id(),
+            TdObject::PageBlockParagraph(t) => t.client_id(),
+            TdObject::PageBlockPhoto(t) => t.client_id(),
+            TdObject::PageBlockPreformatted(t) => t.client_id(),
+            TdObject::PageBlockPullQuote(t) => t.client_id(),
+            TdObject::PageBlockRelatedArticle(t) => t.client_id(),
+            TdObject::PageBlockRelatedArticles(t) => t.client_id(),
+            TdObject::PageBlockSlideshow(t) => t.client_id(),
+            TdObject::PageBlockSubheader(t) => t.client_id(),
+            TdObject::PageBlockSubtitle(t) => t.client_id(),
+            TdObject::PageBlockTable(t) => t.client_id(),
+            TdObject::PageBlockTableCell(t) => t.client_id(),
+            TdObject::PageBlockTitle(t) => t.client_id(),
+            TdObject::PageBlockVerticalAlignmentBottom(t) => t.client_id(),
+            TdObject::PageBlockVerticalAlignmentMiddle(t) => t.client_id(),
+            TdObject::PageBlockVerticalAlignmentTop(t) => t.client_id(),
+            TdObject::PageBlockVideo(t) => t.client_id(),
+            TdObject::PageBlockVoiceNote(t) => t.client_id(),
+            TdObject::PassportAuthorizationForm(t) => t.client_id(),
+            TdObject::PassportElementAddress(t) => t.client_id(),
+            TdObject::PassportElementBankStatement(t) => t.client_id(),
+            TdObject::PassportElementDriverLicense(t) => t.client_id(),
+            TdObject::PassportElementEmailAddress(t) => t.client_id(),
+            TdObject::PassportElementError(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceDataField(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceFile(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceFiles(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceFrontSide(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceReverseSide(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceSelfie(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceTranslationFile(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceTranslationFiles(t) => t.client_id(),
+            TdObject::PassportElementErrorSourceUnspecified(t) => t.client_id(),
+            TdObject::PassportElementIdentityCard(t) => t.client_id(),
+            TdObject::PassportElementInternalPassport(t) => t.client_id(),
+            TdObject::PassportElementPassport(t) => t.client_id(),
+            TdObject::PassportElementPassportRegistration(t) => t.client_id(),
+            TdObject::PassportElementPersonalDetails(t) => t.client_id(),
+            TdObject::PassportElementPhoneNumber(t) => t.client_id(),
+            TdObject::PassportElementRentalAgreement(t) => t.client_id(),
+            TdObject::PassportElementTemporaryRegistration(t) => t.client_id(),
+            TdObject::PassportElementTypeAddress(t) => t.client_id(),
+            TdObject::PassportElementTypeBankStatement(t) => t.client_id(),
+            TdObject::PassportElementTypeDriverLicense(t) => t.client_id(),
+            TdObject::PassportElementTypeEmailAddress(t) => t.client_id(),
+            TdObject::PassportElementTypeIdentityCard(t) => t.client_id(),
+            TdObject::PassportElementTypeInternalPassport(t) => t.client_id(),
+            TdObject::PassportElementTypePassport(t) => t.client_id(),
+            TdObject::PassportElementTypePassportRegistration(t) => t.client_id(),
+            TdObject::PassportElementTypePersonalDetails(t) => t.client_id(),
+            TdObject::PassportElementTypePhoneNumber(t) => t.client_id(),
+            TdObject::PassportElementTypeRentalAgreement(t) => t.client_id(),
+            TdObject::PassportElementTypeTemporaryRegistration(t) => t.client_id(),
+            TdObject::PassportElementTypeUtilityBill(t) => t.client_id(),
+            TdObject::PassportElementUtilityBill(t) => t.client_id(),
+            TdObject::PassportElements(t) => t.client_id(),
+            TdObject::PassportElementsWithErrors(t) => t.client_id(),
+            TdObject::PassportRequiredElement(t) => t.client_id(),
+            TdObject::PassportSuitableElement(t) => t.client_id(),
+            TdObject::PasswordState(t) => t.client_id(),
+            TdObject::PaymentForm(t) => t.client_id(),
+            TdObject::PaymentFormTheme(t) => t.client_id(),
+            TdObject::PaymentReceipt(t) => t.client_id(),
+            TdObject::PaymentResult(t) => t.client_id(),
+            TdObject::PaymentsProviderStripe(t) => t.client_id(),
+            TdObject::PersonalDetails(t) => t.client_id(),
+            TdObject::PersonalDocument(t) => t.client_id(),
+            TdObject::PhoneNumberAuthenticationSettings(t) => t.client_id(),
+            TdObject::PhoneNumberInfo(t) => t.client_id(),
+            TdObject::Photo(t) => t.client_id(),
+            TdObject::PhotoSize(t) => t.client_id(),
+            TdObject::Point(t) => t.client_id(),
+            TdObject::Poll(t) => t.client_id(),
+            TdObject::PollOption(t) => t.client_id(),
+            TdObject::PollTypeQuiz(t) => t.client_id(),
+            TdObject::PollTypeRegular(t) => t.client_id(),
+            TdObject::ProfilePhoto(t) => t.client_id(),
+            TdObject::Proxies(t) => t.client_id(),
+            TdObject::Proxy(t) => t.client_id(),
+            TdObject::ProxyTypeHttp(t) => t.client_id(),
+            TdObject::ProxyTypeMtproto(t) => t.client_id(),
+            TdObject::ProxyTypeSocks5(t) => t.client_id(),
+            TdObject::PublicChatTypeHasUsername(t) => t.client_id(),
+            TdObject::PublicChatTypeIsLocationBased(t) => t.client_id(),
+            TdObject::PushMessageContentAnimation(t) => t.client_id(),
+            TdObject::PushMessageContentAudio(t) => t.client_id(),
+            TdObject::PushMessageContentBasicGroupChatCreate(t) => t.client_id(),
+            TdObject::PushMessageContentChatAddMembers(t) => t.client_id(),
+            TdObject::PushMessageContentChatChangePhoto(t) => t.client_id(),
+            TdObject::PushMessageContentChatChangeTitle(t) => t.client_id(),
+            TdObject::PushMessageContentChatDeleteMember(t) => t.client_id(),
+            TdObject::PushMessageContentChatJoinByLink(t) => t.client_id(),
+            TdObject::PushMessageContentChatJoinByRequest(t) => t.client_id(),
+            TdObject::PushMessageContentChatSetTheme(t) => t.client_id(),
+            TdObject::PushMessageContentContact(t) => t.client_id(),
+            TdObject::PushMessageContentContactRegistered(t) => t.client_id(),
+            TdObject::PushMessageContentDocument(t) => t.client_id(),
+            TdObject::PushMessageContentGame(t) => t.client_id(),
+            TdObject::PushMessageContentGameScore(t) => t.client_id(),
+            TdObject::PushMessageContentHidden(t) => t.client_id(),
+            TdObject::PushMessageContentInvoice(t) => t.client_id(),
+            TdObject::PushMessageContentLocation(t) => t.client_id(),
+            TdObject::PushMessageContentMediaAlbum(t) => t.client_id(),
+            TdObject::PushMessageContentMessageForwards(t) => t.client_id(),
+            TdObject::PushMessageContentPhoto(t) => t.client_id(),
+            TdObject::PushMessageContentPoll(t) => t.client_id(),
+            TdObject::PushMessageContentScreenshotTaken(t) => t.client_id(),
+            TdObject::PushMessageContentSticker(t) => t.client_id(),
+            TdObject::PushMessageContentText(t) => t.client_id(),
+            TdObject::PushMessageContentVideo(t) => t.client_id(),
+            TdObject::PushMessageContentVideoNote(t) => t.client_id(),
+            TdObject::PushMessageContentVoiceNote(t) => t.client_id(),
+            TdObject::PushReceiverId(t) => t.client_id(),
+            TdObject::RecommendedChatFilter(t) => t.client_id(),
+            TdObject::RecommendedChatFilters(t) => t.client_id(),
+            TdObject::RecoveryEmailAddress(t) => t.client_id(),
+            TdObject::RemoteFile(t) => t.client_id(),
+            TdObject::ReplyMarkupForceReply(t) => t.client_id(),
+            TdObject::ReplyMarkupInlineKeyboard(t) => t.client_id(),
+            TdObject::ReplyMarkupRemoveKeyboard(t) => t.client_id(),
+            TdObject::ReplyMarkupShowKeyboard(t) => t.client_id(),
+            TdObject::ResetPasswordResultDeclined(t) => t.client_id(),
+            TdObject::ResetPasswordResultOk(t) => t.client_id(),
+            TdObject::ResetPasswordResultPending(t) => t.client_id(),
+            TdObject::RichTextAnchor(t) => t.client_id(),
+            TdObject::RichTextAnchorLink(t) => t.client_id(),
+            TdObject::RichTextBold(t) => t.client_id(),
+            TdObject::RichTextEmailAddress(t) => t.client_id(),
+            TdObject::RichTextFixed(t) => t.client_id(),
+            TdObject::RichTextIcon(t) => t.client_id(),
+            TdObject::RichTextItalic(t) => t.client_id(),
+            TdObject::RichTextMarked(t) => t.client_id(),
+            TdObject::RichTextPhoneNumber(t) => t.client_id(),
+            TdObject::RichTextPlain(t) => t.client_id(),
+            TdObject::RichTextReference(t) => t.client_id(),
+            TdObject::RichTextStrikethrough(t) => t.client_id(),
+            TdObject::RichTextSubscript(t) => t.client_id(),
+            TdObject::RichTextSuperscript(t) => t.client_id(),
+            TdObject::RichTextUnderline(t) => t.client_id(),
+            TdObject::RichTextUrl(t) => t.client_id(),
+            TdObject::RichTexts(t) => t.client_id(),
+            TdObject::SavedCredentials(t) => t.client_id(),
+            TdObject::ScopeNotificationSettings(t) => t.client_id(),
+            TdObject::SearchMessagesFilterAnimation(t) => t.client_id(),
+            TdObject::SearchMessagesFilterAudio(t) => t.client_id(),
+            TdObject::SearchMessagesFilterChatPhoto(t) => t.client_id(),
+            TdObject::SearchMessagesFilterDocument(t) => t.client_id(),
+            TdObject::SearchMessagesFilterEmpty(t) => t.client_id(),
+            TdObject::SearchMessagesFilterFailedToSend(t) => t.client_id(),
+            TdObject::SearchMessagesFilterMention(t) => t.client_id(),
+            TdObject::SearchMessagesFilterPhoto(t) => t.client_id(),
+            TdObject::SearchMessagesFilterPhotoAndVideo(t) => t.client_id(),
+            TdObject::SearchMessagesFilterPinned(t) => t.client_id(),
+            TdObject::SearchMessagesFilterUnreadMention(t) => t.client_id(),
+            TdObject::SearchMessagesFilterUrl(t) => t.client_id(),
+            TdObject::SearchMessagesFilterVideo(t) => t.client_id(),
+            TdObject::SearchMessagesFilterVideoNote(t) => t.client_id(),
+            TdObject::SearchMessagesFilterVoiceAndVideoNote(t) => t.client_id(),
+            TdObject::SearchMessagesFilterVoiceNote(t) => t.client_id(),
+            TdObject::Seconds(t) => t.client_id(),
+            TdObject::SecretChat(t) => t.client_id(),
+            TdObject::SecretChatStateClosed(t) => t.client_id(),
+            TdObject::SecretChatStatePending(t) => t.client_id(),
+            TdObject::SecretChatStateReady(t) => t.client_id(),
+            TdObject::Session(t) => t.client_id(),
+            TdObject::Sessions(t) => t.client_id(),
+            TdObject::ShippingOption(t) => t.client_id(),
+            TdObject::SponsoredMessage(t) => t.client_id(),
+            TdObject::StatisticalGraphAsync(t) => t.client_id(),
+            TdObject::StatisticalGraphData(t) => t.client_id(),
+            TdObject::StatisticalGraphError(t) => t.client_id(),
+            TdObject::StatisticalValue(t) => t.client_id(),
+            TdObject::Sticker(t) => t.client_id(),
+            TdObject::StickerSet(t) => t.client_id(),
+            TdObject::StickerSetInfo(t) => t.client_id(),
+            TdObject::StickerSets(t) => t.client_id(),
+            TdObject::Stickers(t) => t.client_id(),
+            TdObject::StorageStatistics(t) => t.client_id(),
+            TdObject::StorageStatisticsByChat(t) => t.client_id(),
+            TdObject::StorageStatisticsByFileType(t) => t.client_id(),
+            TdObject::StorageStatisticsFast(t) => t.client_id(),
+            TdObject::SuggestedActionCheckPassword(t) => t.client_id(),
+            TdObject::SuggestedActionCheckPhoneNumber(t) => t.client_id(),
+            TdObject::SuggestedActionConvertToBroadcastGroup(t) => t.client_id(),
+            TdObject::SuggestedActionEnableArchiveAndMuteNewChats(t) => t.client_id(),
+            TdObject::SuggestedActionSetPassword(t) => t.client_id(),
+            TdObject::SuggestedActionViewChecksHint(t) => t.client_id(),
+            TdObject::Supergroup(t) => t.client_id(),
+            TdObject::SupergroupFullInfo(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterAdministrators(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterBanned(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterBots(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterContacts(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterMention(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterRecent(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterRestricted(t) => t.client_id(),
+            TdObject::SupergroupMembersFilterSearch(t) => t.client_id(),
+            TdObject::TMeUrl(t) => t.client_id(),
+            TdObject::TMeUrlTypeChatInvite(t) => t.client_id(),
+            TdObject::TMeUrlTypeStickerSet(t) => t.client_id(),
+            TdObject::TMeUrlTypeSupergroup(t) => t.client_id(),
+            TdObject::TMeUrlTypeUser(t) => t.client_id(),
+            TdObject::TMeUrls(t) => t.client_id(),
+            TdObject::TdlibParameters(t) => t.client_id(),
+            TdObject::TemporaryPasswordState(t) => t.client_id(),
+            TdObject::TermsOfService(t) => t.client_id(),
+            TdObject::TestBytes(t) => t.client_id(),
+            TdObject::TestInt(t) => t.client_id(),
+            TdObject::TestString(t) => t.client_id(),
+            TdObject::TestVectorInt(t) => t.client_id(),
+            TdObject::TestVectorIntObject(t) => t.client_id(),
+            TdObject::TestVectorString(t) => t.client_id(),
+            TdObject::TestVectorStringObject(t) => t.client_id(),
+            TdObject::Text(t) => t.client_id(),
+            TdObject::TextEntities(t) => t.client_id(),
+            TdObject::TextEntity(t) => t.client_id(),
+            TdObject::TextEntityTypeBankCardNumber(t) => t.client_id(),
+            TdObject::TextEntityTypeBold(t) => t.client_id(),
+            TdObject::TextEntityTypeBotCommand(t) => t.client_id(),
+            TdObject::TextEntityTypeCashtag(t) => t.client_id(),
+            TdObject::TextEntityTypeCode(t) => t.client_id(),
+            TdObject::TextEntityTypeEmailAddress(t) => t.client_id(),
+            TdObject::TextEntityTypeHashtag(t) => t.client_id(),
+            TdObject::TextEntityTypeItalic(t) => t.client_id(),
+            TdObject::TextEntityTypeMediaTimestamp(t) => t.client_id(),
+            TdObject::TextEntityTypeMention(t) => t.client_id(),
+            TdObject::TextEntityTypeMentionName(t) => t.client_id(),
+            TdObject::TextEntityTypePhoneNumber(t) => t.client_id(),
+            TdObject::TextEntityTypePre(t) => t.client_id(),
+            TdObject::TextEntityTypePreCode(t) => t.client_id(),
+            TdObject::TextEntityTypeStrikethrough(t) => t.client_id(),
+            TdObject::TextEntityTypeTextUrl(t) => t.client_id(),
+            TdObject::TextEntityTypeUnderline(t) => t.client_id(),
+            TdObject::TextEntityTypeUrl(t) => t.client_id(),
+            TdObject::TextParseModeHTML(t) => t.client_id(),
+            TdObject::TextParseModeMarkdown(t) => t.client_id(),
+            TdObject::ThemeSettings(t) => t.client_id(),
+            TdObject::Thumbnail(t) => t.client_id(),
+            TdObject::ThumbnailFormatGif(t) => t.client_id(),
+            TdObject::ThumbnailFormatJpeg(t) => t.client_id(),
+            TdObject::ThumbnailFormatMpeg4(t) => t.client_id(),
+            TdObject::ThumbnailFormatPng(t) => t.client_id(),
+            TdObject::ThumbnailFormatTgs(t) => t.client_id(),
+            TdObject::ThumbnailFormatWebp(t) => t.client_id(),
+            TdObject::TopChatCategoryBots(t) => t.client_id(),
+            TdObject::TopChatCategoryCalls(t) => t.client_id(),
+            TdObject::TopChatCategoryChannels(t) => t.client_id(),
+            TdObject::TopChatCategoryForwardChats(t) => t.client_id(),
+            TdObject::TopChatCategoryGroups(t) => t.client_id(),
+            TdObject::TopChatCategoryInlineBots(t) => t.client_id(),
+            TdObject::TopChatCategoryUsers(t) => t.client_id(),
+            TdObject::UpdateActiveNotifications(t) => t.client_id(),
+            TdObject::UpdateAnimatedEmojiMessageClicked(t) => t.client_id(),
+            TdObject::UpdateAnimationSearchParameters(t) => t.client_id(),
+            TdObject::UpdateAuthorizationState(t) => t.client_id(),
+            TdObject::UpdateBasicGroup(t) => t.client_id(),
+            TdObject::UpdateBasicGroupFullInfo(t) => t.client_id(),
+            TdObject::UpdateCall(t) => t.client_id(),
+            TdObject::UpdateChatAction(t) => t.client_id(),
+            TdObject::UpdateChatActionBar(t) => t.client_id(),
+            TdObject::UpdateChatDefaultDisableNotification(t) => t.client_id(),
+            TdObject::UpdateChatDraftMessage(t) => t.client_id(),
+            TdObject::UpdateChatFilters(t) => t.client_id(),
+            TdObject::UpdateChatHasProtectedContent(t) => t.client_id(),
+            TdObject::UpdateChatHasScheduledMessages(t) => t.client_id(),
+            TdObject::UpdateChatIsBlocked(t) => t.client_id(),
+            TdObject::UpdateChatIsMarkedAsUnread(t) => t.client_id(),
+            TdObject::UpdateChatLastMessage(t) => t.client_id(),
+            TdObject::UpdateChatMember(t) => t.client_id(),
+            TdObject::UpdateChatMessageSender(t) => t.client_id(),
+            TdObject::UpdateChatMessageTtl(t) => t.client_id(),
+            TdObject::UpdateChatNotificationSettings(t) => t.client_id(),
+            TdObject::UpdateChatOnlineMemberCount(t) => t.client_id(),
+            TdObject::UpdateChatPendingJoinRequests(t) => t.client_id(),
+            TdObject::UpdateChatPermissions(t) => t.client_id(),
+            TdObject::UpdateChatPhoto(t) => t.client_id(),
+            TdObject::UpdateChatPosition(t) => t.client_id(),
+            TdObject::UpdateChatReadInbox(t) => t.client_id(),
+            TdObject::UpdateChatReadOutbox(t) => t.client_id(),
+            TdObject::UpdateChatReplyMarkup(t) => t.client_id(),
+            TdObject::UpdateChatTheme(t) => t.client_id(),
+            TdObject::UpdateChatThemes(t) => t.client_id(),
+            TdObject::UpdateChatTitle(t) => t.client_id(),
+            TdObject::UpdateChatUnreadMentionCount(t) => t.client_id(),
+            TdObject::UpdateChatVideoChat(t) => t.client_id(),
+            TdObject::UpdateConnectionState(t) => t.client_id(),
+            TdObject::UpdateDeleteMessages(t) => t.client_id(),
+            TdObject::UpdateDiceEmojis(t) => t.client_id(),
+            TdObject::UpdateFavoriteStickers(t) => t.client_id(),
+            TdObject::UpdateFile(t) => t.client_id(),
+            TdObject::UpdateFileGenerationStart(t) => t.client_id(),
+            TdObject::UpdateFileGenerationStop(t) => t.client_id(),
+            TdObject::UpdateGroupCall(t) => t.client_id(),
+            TdObject::UpdateGroupCallParticipant(t) => t.client_id(),
+            TdObject::UpdateHavePendingNotifications(t) => t.client_id(),
+            TdObject::UpdateInstalledStickerSets(t) => t.client_id(),
+            TdObject::UpdateLanguagePackStrings(t) => t.client_id(),
+            TdObject::UpdateMessageContent(t) => t.client_id(),
+            TdObject::UpdateMessageContentOpened(t) => t.client_id(),
+            TdObject::UpdateMessageEdited(t) => t.client_id(),
+            TdObject::UpdateMessageInteractionInfo(t) => t.client_id(),
+            TdObject::UpdateMessageIsPinned(t) => t.client_id(),
+            TdObject::UpdateMessageLiveLocationViewed(t) => t.client_id(),
+            TdObject::UpdateMessageMentionRead(t) => t.client_id(),
+            TdObject::UpdateMessageSendAcknowledged(t) => t.client_id(),
+            TdObject::UpdateMessageSendFailed(t) => t.client_id(),
+            TdObject::UpdateMessageSendSucceeded(t) => t.client_id(),
+            TdObject::UpdateNewCallSignalingData(t) => t.client_id(),
+            TdObject::UpdateNewCallbackQuery(t) => t.client_id(),
+            TdObject::UpdateNewChat(t) => t.client_id(),
+            TdObject::UpdateNewChatJoinRequest(t) => t.client_id(),
+            TdObject::UpdateNewChosenInlineResult(t) => t.client_id(),
+            TdObject::UpdateNewCustomEvent(t) => t.client_id(),
+            TdObject::UpdateNewCustomQuery(t) => t.client_id(),
+            TdObject::UpdateNewInlineCallbackQuery(t) => t.client_id(),
+            TdObject::UpdateNewInlineQuery(t) => t.client_id(),
+            TdObject::UpdateNewMessage(t) => t.client_id(),
+            TdObject::UpdateNewPreCheckoutQuery(t) => t.client_id(),
+            TdObject::UpdateNewShippingQuery(t) => t.client_id(),
+            TdObject::UpdateNotification(t) => t.client_id(),
+            TdObject::UpdateNotificationGroup(t) => t.client_id(),
+            TdObject::UpdateOption(t) => t.client_id(),
+            TdObject::UpdatePoll(t) => t.client_id(),
+            TdObject::UpdatePollAnswer(t) => t.client_id(),
+            TdObject::UpdateRecentStickers(t) => t.client_id(),
+            TdObject::UpdateSavedAnimations(t) => t.client_id(),
+            TdObject::UpdateScopeNotificationSettings(t) => t.client_id(),
+            TdObject::UpdateSecretChat(t) => t.client_id(),
+            TdObject::UpdateSelectedBackground(t) => t.client_id(),
+            TdObject::UpdateServiceNotification(t) => t.client_id(),
+            TdObject::UpdateStickerSet(t) => t.client_id(),
+            TdObject::UpdateSuggestedActions(t) => t.client_id(),
+            TdObject::UpdateSupergroup(t) => t.client_id(),
+            TdObject::UpdateSupergroupFullInfo(t) => t.client_id(),
+            TdObject::UpdateTermsOfService(t) => t.client_id(),
+            TdObject::UpdateTrendingStickerSets(t) => t.client_id(),
+            TdObject::UpdateUnreadChatCount(t) => t.client_id(),
+            TdObject::UpdateUnreadMessageCount(t) => t.client_id(),
+            TdObject::UpdateUser(t) => t.client_id(),
+            TdObject::UpdateUserFullInfo(t) => t.client_id(),
+            TdObject::UpdateUserPrivacySettingRules(t) => t.client_id(),
+            TdObject::UpdateUserStatus(t) => t.client_id(),
+            TdObject::UpdateUsersNearby(t) => t.client_id(),
+            TdObject::Updates(t) => t.client_id(),
+            TdObject::User(t) => t.client_id(),
+            TdObject::UserFullInfo(t) => t.client_id(),
+            TdObject::UserPrivacySettingAllowCalls(t) => t.client_id(),
+            TdObject::UserPrivacySettingAllowChatInvites(t) => t.client_id(),
+            TdObject::UserPrivacySettingAllowFindingByPhoneNumber(t) => t.client_id(),
+            TdObject::UserPrivacySettingAllowPeerToPeerCalls(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleAllowAll(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleAllowChatMembers(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleAllowContacts(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleAllowUsers(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleRestrictAll(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleRestrictChatMembers(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleRestrictContacts(t) => t.client_id(),
+            TdObject::UserPrivacySettingRuleRestrictUsers(t) => t.client_id(),
+            TdObject::UserPrivacySettingRules(t) => t.client_id(),
+            TdObject::UserPrivacySettingShowLinkInForwardedMessages(t) => t.client_id(),
+            TdObject::UserPrivacySettingShowPhoneNumber(t) => t.client_id(),
+            TdObject::UserPrivacySettingShowProfilePhoto(t) => t.client_id(),
+            TdObject::UserPrivacySettingShowStatus(t) => t.client_id(),
+            TdObject::UserStatusEmpty(t) => t.client_id(),
+            TdObject::UserStatusLastMonth(t) => t.client_id(),
+            TdObject::UserStatusLastWeek(t) => t.client_id(),
+            TdObject::UserStatusOffline(t) => t.client_id(),
+            TdObject::UserStatusOnline(t) => t.client_id(),
+            TdObject::UserStatusRecently(t) => t.client_id(),
+            TdObject::UserTypeBot(t) => t.client_id(),
+            TdObject::UserTypeDeleted(t) => t.client_id(),
+            TdObject::UserTypeRegular(t) => t.client_id(),
+            TdObject::UserTypeUnknown(t) => t.client_id(),
+            TdObject::Users(t) => t.client_id(),
+            TdObject::ValidatedOrderInfo(t) => t.client_id(),
+            TdObject::VectorPathCommandCubicBezierCurve(t) => t.client_id(),
+            TdObject::VectorPathCommandLine(t) => t.client_id(),
+            TdObject::Venue(t) => t.client_id(),
+            TdObject::Video(t) => t.client_id(),
+            TdObject::VideoChat(t) => t.client_id(),
+            TdObject::VideoNote(t) => t.client_id(),
+            TdObject::VoiceNote(t) => t.client_id(),
+            TdObject::WebPage(t) => t.client_id(),
+            TdObject::WebPageInstantView(t) => t.client_id(),
+            TdObject::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
+        }
+    }
+}
+
+impl TdObject {
+    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+    /// Name of the object type, value of its `@type`
+    pub fn type_name(&self) -> &str {
+        match self {
+            TdObject::AccountTtl(_) => "accountTtl",
+            TdObject::Address(_) => "address",
+            TdObject::AnimatedChatPhoto(_) => "animatedChatPhoto",
+            TdObject::AnimatedEmoji(_) => "animatedEmoji",
+            TdObject::Animation(_) => "animation",
+            TdObject::Animations(_) => "animations",
+            TdObject::Audio(_) => "audio",
+            TdObject::AuthenticationCodeInfo(_) => "authenticationCodeInfo",
+            TdObject::AuthenticationCodeTypeCall(_) => "authenticationCodeTypeCall",
+            TdObject::AuthenticationCodeTypeFlashCall(_) => "authenticationCodeTypeFlashCall",
+            TdObject::AuthenticationCodeTypeMissedCall(_) => "authenticationCodeTypeMissedCall",
+            TdObject::AuthenticationCodeTypeSms(_) => "authenticationCodeTypeSms",
+            TdObject::AuthenticationCodeTypeTelegramMessage(_) => {
+                "authenticationCodeTypeTelegramMessage"
+            }
+            TdObject::AuthorizationStateClosed(_) => "authorizationStateClosed",
+            TdObject::AuthorizationStateClosing(_) => "authorizationStateClosing",
+            TdObject::AuthorizationStateLoggingOut(_) => "authorizationStateLoggingOut",
+            TdObject::AuthorizationStateReady(_) => "authorizationStateReady",
+            TdObject::AuthorizationStateWaitCode(_) => "authorizationStateWaitCode",
+            TdObject::AuthorizationStateWaitEncryptionKey(_) => {
+                "authorizationStateWaitEncryptionKey"
+            }
+            TdObject::AuthorizationStateWaitOtherDeviceConfirmation(_) => {
+                "authorizationStateWaitOtherDeviceConfirmation"
+            }
+            TdObject::AuthorizationStateWaitPassword(_) => "authorizationStateWaitPassword",
+            TdObject::AuthorizationStateWaitPhoneNumber(_) => "authorizationStateWaitPhoneNumber",
+            TdObject::AuthorizationStateWaitRegistration(_) => "authorizationStateWaitRegistration",
+            TdObject::AuthorizationStateWaitTdlibParameters(_) => {
+                "authorizationStateWaitTdlibParameters"
+            }
+            TdObject::AutoDownloadSettings(_) => "autoDownloadSettings",
+            TdObject::AutoDownloadSettingsPresets(_) => "autoDownloadSettingsPresets",
+            TdObject::Background(_) => "background",
+            TdObject::BackgroundFillFreeformGradient(_) => "backgroundFillFreeformGradient",
+            TdObject::BackgroundFillGradient(_) => "backgroundFillGradient",
+            TdObject::BackgroundFillSolid(_) => "backgroundFillSolid",
+            TdObject::BackgroundTypeFill(_) => "backgroundTypeFill",
+            TdObject::BackgroundTypePattern(_) => "backgroundTypePattern",
+            TdObject::BackgroundTypeWallpaper(_) => "backgroundTypeWallpaper",
+            TdObject::Backgrounds(_) => "backgrounds",
+            TdObject::BankCardActionOpenUrl(_) => "bankCardActionOpenUrl",
+            TdObject::BankCardInfo(_) => "bankCardInfo",
+            TdObject::BasicGroup(_) => "basicGroup",
+            TdObject::BasicGroupFullInfo(_) => "basicGroupFullInfo",
+            TdObject::BotCommand(_) => "botCommand",
+            TdObject::BotCommandScopeAllChatAdministrators(_) => {
+                "botCommandScopeAllChatAdministrators"
+            }
+            TdObject::BotCommandScopeAllGroupChats(_) => "botCommandScopeAllGroupChats",
+            TdObject::BotCommandScopeAllPrivateChats(_) => "botCommandScopeAllPrivateChats",
+            TdObject::BotCommandScopeChat(_) => "botCommandScopeChat",
+            TdObject::BotCommandScopeChatAdministrators(_) => "botCommandScopeChatAdministrators",
+            TdObject::BotCommandScopeChatMember(_) => "botCommandScopeChatMember",
+            TdObject::BotCommandScopeDefault(_) => "botCommandScopeDefault",
+            TdObject::BotCommands(_) => "botCommands",
+            TdObject::Call(_) => "call",
+            TdObject::CallDiscardReasonDeclined(_) => "callDiscardReasonDeclined",
+            TdObject::CallDiscardReasonDisconnected(_) => "callDiscardReasonDisconnected",
+            TdObject::CallDiscardReasonEmpty(_) => "callDiscardReasonEmpty",
+            TdObject::CallDiscardReasonHungUp(_) => "callDiscardReasonHungUp",
+            TdObject::CallDiscardReasonMissed(_) => "callDiscardReasonMissed",
+            TdObject::CallId(_) => "callId",
+            TdObject::CallProblemDistortedSpeech(_) => "callProblemDistortedSpeech",
+            TdObject::CallProblemDistortedVideo(_) => "callProblemDistortedVideo",
+            TdObject::CallProblemDropped(_) => "callProblemDropped",
+            TdObject::CallProblemEcho(_) => "callProblemEcho",
+            TdObject::CallProblemInterruptions(_) => "callProblemInterruptions",
+            TdObject::CallProblemNoise(_) => "callProblemNoise",
+            TdObject::CallProblemPixelatedVideo(_) => "callProblemPixelatedVideo",
+            TdObject::CallProblemSilentLocal(_) => "callProblemSilentLocal",
+            TdObject::CallProblemSilentRemote(_) => "callProblemSilentRemote",
+            TdObject::CallProtocol(_) => "callProtocol",
+            TdObject::CallServer(_) => "callServer",
+            TdObject::CallServerTypeTelegramReflector(_) => "callServerTypeTelegramReflector",
+            TdObject::CallServerTypeWebrtc(_) => "callServerTypeWebrtc",
+            TdObject::CallStateDiscarded(_) => "callStateDiscarded",
+            TdObject::CallStateError(_) => "callStateError",
+            TdObject::CallStateExchangingKeys(_) => "callStateExchangingKeys",
+            TdObject::CallStateHangingUp(_) => "callStateHangingUp",
+            TdObject::CallStatePending(_) => "callStatePending",
+            TdObject::CallStateReady(_) => "callStateReady",
+            TdObject::CallbackQueryAnswer(_) => "callbackQueryAnswer",
+            TdObject::CallbackQueryPayloadData(_) => "callbackQueryPayloadData",
+            TdObject::CallbackQueryPayloadDataWithPassword(_) => {
+                "callbackQueryPayloadDataWithPassword"
+            }
+            TdObject::CallbackQueryPayloadGame(_) => "callbackQueryPayloadGame",
+            TdObject::CanTransferOwnershipResultOk(_) => "canTransferOwnershipResultOk",
+            TdObject::CanTransferOwnershipResultPasswordNeeded(_) => {
+                "canTransferOwnershipResultPasswordNeeded"
+            }
+            TdObject::CanTransferOwnershipResultPasswordTooFresh(_) => {
+                "canTransferOwnershipResultPasswordTooFresh"
+            }
+            TdObject::CanTransferOwnershipResultSessionTooFresh(_) => {
+                "canTransferOwnershipResultSessionTooFresh"
+            }
+            TdObject::Chat(_) => "chat",
+            TdObject::ChatActionBarAddContact(_) => "chatActionBarAddContact",
+            TdObject::ChatActionBarInviteMembers(_) => "chatActionBarInviteMembers",
+            TdObject::ChatActionBarJoinRequest(_) => "chatActionBarJoinRequest",
+            TdObject::ChatActionBarReportAddBlock(_) => "chatActionBarReportAddBlock",
+            TdObject::ChatActionBarReportSpam(_) => "chatActionBarReportSpam",
+            TdObject::ChatActionBarReportUnrelatedLocation(_) => {
+                "chatActionBarReportUnrelatedLocation"
+            }
+            TdObject::ChatActionBarSharePhoneNumber(_) => "chatActionBarSharePhoneNumber",
+            TdObject::ChatActionCancel(_) => "chatActionCancel",
+            TdObject::ChatActionChoosingContact(_) => "chatActionChoosingContact",
+            TdObject::ChatActionChoosingLocation(_) => "chatActionChoosingLocation",
+            TdObject::ChatActionChoosingSticker(_) => "chatActionChoosingSticker",
+            TdObject::ChatActionRecordingVideo(_) => "chatActionRecordingVideo",
+            TdObject::ChatActionRecordingVideoNote(_) => "chatActionRecordingVideoNote",
+            TdObject::ChatActionRecordingVoiceNote(_) => "chatActionRecordingVoiceNote",
+            TdObject::ChatActionStartPlayingGame(_) => "chatActionStartPlayingGame",
+            TdObject::ChatActionTyping(_) => "chatActionTyping",
+            TdObject::ChatActionUploadingDocument(_) => "chatActionUploadingDocument",
+            TdObject::ChatActionUploadingPhoto(_) => "chatActionUploadingPhoto",
+            TdObject::ChatActionUploadingVideo(_) => "chatActionUploadingVideo",
+            TdObject::ChatActionUploadingVideoNote(_) => "chatActionUploadingVideoNote",
+            TdObject::ChatActionUploadingVoiceNote(_) => "chatActionUploadingVoiceNote",
+            TdObject::ChatActionWatchingAnimations(_) => "chatActionWatchingAnimations",
+            TdObject::ChatAdministrator(_) => "chatAdministrator",
+            TdObject::ChatAdministrators(_) => "chatAdministrators",
+            TdObject::ChatEvent(_) => "chatEvent",
+            TdObject::ChatEventDescriptionChanged(_) => "chatEventDescriptionChanged",
+            TdObject::ChatEventHasProtectedContentToggled(_) => {
+                "chatEventHasProtectedContentToggled"
+            }
+            TdObject::ChatEventInviteLinkDeleted(_) => "chatEventInviteLinkDeleted",
+            TdObject::ChatEventInviteLinkEdited(_) => "chatEventInviteLinkEdited",
+            TdObject::ChatEventInviteLinkRevoked(_) => "chatEventInviteLinkRevoked",
+            TdObject::ChatEventInvitesToggled(_) => "chatEventInvitesToggled",
+            TdObject::ChatEventIsAllHistoryAvailableToggled(_) => {
+                "chatEventIsAllHistoryAvailableToggled"
+            }
+            TdObject::ChatEventLinkedChatChanged(_) => "chatEventLinkedChatChanged",
+            TdObject::ChatEventLocationChanged(_) => "chatEventLocationChanged",
+            TdObject::ChatEventLogFilters(_) => "chatEventLogFilters",
+            TdObject::ChatEventMemberInvited(_) => "chatEventMemberInvited",
+            TdObject::ChatEventMemberJoined(_) => "chatEventMemberJoined",
+            TdObject::ChatEventMemberJoinedByInviteLink(_) => "chatEventMemberJoinedByInviteLink",
+            TdObject::ChatEventMemberJoinedByRequest(_) => "chatEventMemberJoinedByRequest",
+            TdObject::ChatEventMemberLeft(_) => "chatEventMemberLeft",
+            TdObject::ChatEventMemberPromoted(_) => "chatEventMemberPromoted",
+            TdObject::ChatEventMemberRestricted(_) => "chatEventMemberRestricted",
+            TdObject::ChatEventMessageDeleted(_) => "chatEventMessageDeleted",
+            TdObject::ChatEventMessageEdited(_) => "chatEventMessageEdited",
+            TdObject::ChatEventMessagePinned(_) => "chatEventMessagePinned",
+            TdObject::ChatEventMessageTtlChanged(_) => "chatEventMessageTtlChanged",
+            TdObject::ChatEventMessageUnpinned(_) => "chatEventMessageUnpinned",
+            TdObject::ChatEventPermissionsChanged(_) => "chatEventPermissionsChanged",
+            TdObject::ChatEventPhotoChanged(_) => "chatEventPhotoChanged",
+            TdObject::ChatEventPollStopped(_) => "chatEventPollStopped",
+            TdObject::ChatEventSignMessagesToggled(_) => "chatEventSignMessagesToggled",
+            TdObject::ChatEventSlowModeDelayChanged(_) => "chatEventSlowModeDelayChanged",
+            TdObject::ChatEventStickerSetChanged(_) => "chatEventStickerSetChanged",
+            TdObject::ChatEventTitleChanged(_) => "chatEventTitleChanged",
+            TdObject::ChatEventUsernameChanged(_) => "chatEventUsernameChanged",
+            TdObject::ChatEventVideoChatCreated(_) => "chatEventVideoChatCreated",
+            TdObject::ChatEventVideoChatEnded(_) => "chatEventVideoChatEnded",
+            TdObject::ChatEventVideoChatMuteNewParticipantsToggled(_) => {
+                "chatEventVideoChatMuteNewParticipantsToggled"
+            }
+            TdObject::ChatEventVideoChatParticipantIsMutedToggled(_) => {
+                "chatEventVideoChatParticipantIsMutedToggled"
+            }
+            TdObject::ChatEventVideoChatParticipantVolumeLevelChanged(_) => {
+                "chatEventVideoChatParticipantVolumeLevelChanged"
+            }
+            TdObject::ChatEvents(_) => "chatEvents",
+            TdObject::ChatFilter(_) => "chatFilter",
+            TdObject::ChatFilterInfo(_) => "chatFilterInfo",
+            TdObject::ChatInviteLink(_) => "chatInviteLink",
+            TdObject::ChatInviteLinkCount(_) => "chatInviteLinkCount",
+            TdObject::ChatInviteLinkCounts(_) => "chatInviteLinkCounts",
+            TdObject::ChatInviteLinkInfo(_) => "chatInviteLinkInfo",
+            TdObject::ChatInviteLinkMember(_) => "chatInviteLinkMember",
+            TdObject::ChatInviteLinkMembers(_) => "chatInviteLinkMembers",
+            TdObject::ChatInviteLinks(_) => "chatInviteLinks",
+            TdObject::ChatJoinRequest(_) => "chatJoinRequest",
+            TdObject::ChatJoinRequests(_) => "chatJoinRequests",
+            TdObject::ChatJoinRequestsInfo(_) => "chatJoinRequestsInfo",
+            TdObject::ChatListArchive(_) => "chatListArchive",
+            TdObject::ChatListFilter(_) => "chatListFilter",
+            TdObject::ChatListMain(_) => "chatListMain",
+            TdObject::ChatLists(_) => "chatLists",
+            TdObject::ChatLocation(_) => "chatLocation",
+            TdObject::ChatMember(_) => "chatMember",
+            TdObject::ChatMemberStatusAdministrator(_) => "chatMemberStatusAdministrator",
+            TdObject::ChatMemberStatusBanned(_) => "chatMemberStatusBanned",
+            TdObject::ChatMemberStatusCreator(_) => "chatMemberStatusCreator",
+            TdObject::ChatMemberStatusLeft(_) => "chatMemberStatusLeft",
+            TdObject::ChatMemberStatusMember(_) => "chatMemberStatusMember",
+            TdObject::ChatMemberStatusRestricted(_) => "chatMemberStatusRestricted",
+            TdObject::ChatMembers(_) => "chatMembers",
+            TdObject::ChatMembersFilterAdministrators(_) => "chatMembersFilterAdministrators",
+            TdObject::ChatMembersFilterBanned(_) => "chatMembersFilterBanned",
+            TdObject::ChatMembersFilterBots(_) => "chatMembersFilterBots",
+            TdObject::ChatMembersFilterContacts(_) => "chatMembersFilterContacts",
+            TdObject::ChatMembersFilterMembers(_) => "chatMembersFilterMembers",
+            TdObject::ChatMembersFilterMention(_) => "chatMembersFilterMention",
+            TdObject::ChatMembersFilterRestricted(_) => "chatMembersFilterRestricted",
+            TdObject::ChatNearby(_) => "chatNearby",
+            TdObject::ChatNotificationSettings(_) => "chatNotificationSettings",
+            TdObject::ChatPermissions(_) => "chatPermissions",
+            TdObject::ChatPhoto(_) => "chatPhoto",
+            TdObject::ChatPhotoInfo(_) => "chatPhotoInfo",
+            TdObject::ChatPhotos(_) => "chatPhotos",
+            TdObject::ChatPosition(_) => "chatPosition",
+            TdObject::ChatReportReasonChildAbuse(_) => "chatReportReasonChildAbuse",
+            TdObject::ChatReportReasonCopyright(_) => "chatReportReasonCopyright",
+            TdObject::ChatReportReasonCustom(_) => "chatReportReasonCustom",
+            TdObject::ChatReportReasonFake(_) => "chatReportReasonFake",
+            TdObject::ChatReportReasonPornography(_) => "chatReportReasonPornography",
+            TdObject::ChatReportReasonSpam(_) => "chatReportReasonSpam",
+            TdObject::ChatReportReasonUnrelatedLocation(_) => "chatReportReasonUnrelatedLocation",
+            TdObject::ChatReportReasonViolence(_) => "chatReportReasonViolence",
+            TdObject::ChatSourceMtprotoProxy(_) => "chatSourceMtprotoProxy",
+            TdObject::ChatSourcePublicServiceAnnouncement(_) => {
+                "chatSourcePublicServiceAnnouncement"
+            }
+            TdObject::ChatStatisticsAdministratorActionsInfo(_) => {
+                "chatStatisticsAdministratorActionsInfo"
+            }
+            TdObject::ChatStatisticsChannel(_) => "chatStatisticsChannel",
+            TdObject::ChatStatisticsInviterInfo(_) => "chatStatisticsInviterInfo",
+            TdObject::ChatStatisticsMessageInteractionInfo(_) => {
+                "chatStatisticsMessageInteractionInfo"
+            }
+            TdObject::ChatStatisticsMessageSenderInfo(_) => "chatStatisticsMessageSenderInfo",
+            TdObject::ChatStatisticsSupergroup(_) => "chatStatisticsSupergroup",
+            TdObject::ChatTheme(_) => "chatTheme",
+            TdObject::ChatTypeBasicGroup(_) => "chatTypeBasicGroup",
+            TdObject::ChatTypePrivate(_) => "chatTypePrivate",
+            TdObject::ChatTypeSecret(_) => "chatTypeSecret",
+            TdObject::ChatTypeSupergroup(_) => "chatTypeSupergroup",
+            TdObject::Chats(_) => "chats",
+            TdObject::ChatsNearby(_) => "chatsNearby",
+            TdObject::CheckChatUsernameResultOk(_) => "checkChatUsernameResultOk",
+            TdObject::CheckChatUsernameResultPublicChatsTooMuch(_) => {
+                "checkChatUsernameResultPublicChatsTooMuch"
+            }
+            TdObject::CheckChatUsernameResultPublicGroupsUnavailable(_) => {
+                "checkChatUsernameResultPublicGroupsUnavailable"
+            }
+            TdObject::CheckChatUsernameResultUsernameInvalid(_) => {
+                "checkChatUsernameResultUsernameInvalid"
+            }
+            TdObject::CheckChatUsernameResultUsernameOccupied(_) => {
+                "checkChatUsernameResultUsernameOccupied"
+            }
+            TdObject::CheckStickerSetNameResultNameInvalid(_) => {
+                "checkStickerSetNameResultNameInvalid"
+            }
+            TdObject::CheckStickerSetNameResultNameOccupied(_) => {
+                "checkStickerSetNameResultNameOccupied"
+            }
+            TdObject::CheckStickerSetNameResultOk(_) => "checkStickerSetNameResultOk",
+            TdObject::ClosedVectorPath(_) => "closedVectorPath",
+            TdObject::ConnectedWebsite(_) => "connectedWebsite",
+            TdObject::ConnectedWebsites(_) => "connectedWebsites",
+            TdObject::ConnectionStateConnecting(_) => "connectionStateConnecting",
+            TdObject::ConnectionStateConnectingToProxy(_) => "connectionStateConnectingToProxy",
+            TdObject::ConnectionStateReady(_) => "connectionStateReady",
+            TdObject::ConnectionStateUpdating(_) => "connectionStateUpdating",
+            TdObject::ConnectionStateWaitingForNetwork(_) => "connectionStateWaitingForNetwork",
+            TdObject::Contact(_) => "contact",
+            TdObject::Count(_) => "count",
+            TdObject::Countries(_) => "countries",
+            TdObject::CountryInfo(_) => "countryInfo",
+            TdObject::CustomRequestResult(_) => "customRequestResult",
+            TdObject::DatabaseStatistics(_) => "databaseStatistics",
+            TdObject::Date(_) => "date",
+            TdObject::DateRange(_) => "dateRange",
+            TdObject::DatedFile(_) => "datedFile",
+            TdObject::DeepLinkInfo(_) => "deepLinkInfo",
+            TdObject::DeviceTokenApplePush(_) => "deviceTokenApplePush",
+            TdObject::DeviceTokenApplePushVoIP(_) => "deviceTokenApplePushVoIP",
+            TdObject::DeviceTokenBlackBerryPush(_) => "deviceTokenBlackBerryPush",
+            TdObject::DeviceTokenFirebaseCloudMessaging(_) => "deviceTokenFirebaseCloudMessaging",
+            TdObject::DeviceTokenMicrosoftPush(_) => "deviceTokenMicrosoftPush",
+            TdObject::DeviceTokenMicrosoftPushVoIP(_) => "deviceTokenMicrosoftPushVoIP",
+            TdObject::DeviceTokenSimplePush(_) => "deviceTokenSimplePush",
+            TdObject::DeviceTokenTizenPush(_) => "deviceTokenTizenPush",
+            TdObject::DeviceTokenUbuntuPush(_) => "deviceTokenUbuntuPush",
+            TdObject::DeviceTokenWebPush(_) => "deviceTokenWebPush",
+            TdObject::DeviceTokenWindowsPush(_) => "deviceTokenWindowsPush",
+            TdObject::DiceStickersRegular(_) => "diceStickersRegular",
+            TdObject::DiceStickersSlotMachine(_) => "diceStickersSlotMachine",
+            TdObject::Document(_) => "document",
+            TdObject::DraftMessage(_) => "draftMessage",
+            TdObject::EmailAddressAuthenticationCodeInfo(_) => "emailAddressAuthenticationCodeInfo",
+            TdObject::Emojis(_) => "emojis",
+            TdObject::EncryptedCredentials(_) => "encryptedCredentials",
+            TdObject::EncryptedPassportElement(_) => "encryptedPassportElement",
+            TdObject::Error(_) => "error",
+            TdObject::File(_) => "file",
+            TdObject::FilePart(_) => "filePart",
+            TdObject::FileTypeAnimation(_) => "fileTypeAnimation",
+            TdObject::FileTypeAudio(_) => "fileTypeAudio",
+            TdObject::FileTypeDocument(_) => "fileTypeDocument",
+            TdObject::FileTypeNone(_) => "fileTypeNone",
+            TdObject::FileTypePhoto(_) => "fileTypePhoto",
+            TdObject::FileTypeProfilePhoto(_) => "fileTypeProfilePhoto",
+            TdObject::FileTypeSecret(_) => "fileTypeSecret",
+            TdObject::FileTypeSecretThumbnail(_) => "fileTypeSecretThumbnail",
+            TdObject::FileTypeSecure(_) => "fileTypeSecure",
+            TdObject::FileTypeSticker(_) => "fileTypeSticker",
+            TdObject::FileTypeThumbnail(_) => "fileTypeThumbnail",
+            TdObject::FileTypeUnknown(_) => "fileTypeUnknown",
+            TdObject::FileTypeVideo(_) => "fileTypeVideo",
+            TdObject::FileTypeVideoNote(_) => "fileTypeVideoNote",
+            TdObject::FileTypeVoiceNote(_) => "fileTypeVoiceNote",
+            TdObject::FileTypeWallpaper(_) => "fileTypeWallpaper",
+            TdObject::FormattedText(_) => "formattedText",
+            TdObject::FoundMessages(_) => "foundMessages",
+            TdObject::Game(_) => "game",
+            TdObject::GameHighScore(_) => "gameHighScore",
+            TdObject::GameHighScores(_) => "gameHighScores",
+            TdObject::GroupCall(_) => "groupCall",
+            TdObject::GroupCallId(_) => "groupCallId",
+            TdObject::GroupCallParticipant(_) => "groupCallParticipant",
+            TdObject::GroupCallParticipantVideoInfo(_) => "groupCallParticipantVideoInfo",
+            TdObject::GroupCallRecentSpeaker(_) => "groupCallRecentSpeaker",
+            TdObject::GroupCallVideoQualityFull(_) => "groupCallVideoQualityFull",
+            TdObject::GroupCallVideoQualityMedium(_) => "groupCallVideoQualityMedium",
+            TdObject::GroupCallVideoQualityThumbnail(_) => "groupCallVideoQualityThumbnail",
+            TdObject::GroupCallVideoSourceGroup(_) => "groupCallVideoSourceGroup",
+            TdObject::Hashtags(_) => "hashtags",
+            TdObject::HttpUrl(_) => "httpUrl",
+            TdObject::IdentityDocument(_) => "identityDocument",
+            TdObject::ImportedContacts(_) => "importedContacts",
+            TdObject::InlineKeyboardButton(_) => "inlineKeyboardButton",
+            TdObject::InlineKeyboardButtonTypeBuy(_) => "inlineKeyboardButtonTypeBuy",
+            TdObject::InlineKeyboardButtonTypeCallback(_) => "inlineKeyboardButtonTypeCallback",
+            TdObject::InlineKeyboardButtonTypeCallbackGame(_) => {
+                "inlineKeyboardButtonTypeCallbackGame"
+            }
+            TdObject::InlineKeyboardButtonTypeCallbackWithPassword(_) => {
+                "inlineKeyboardButtonTypeCallbackWithPassword"
+            }
+            TdObject::InlineKeyboardButtonTypeLoginUrl(_) => "inlineKeyboardButtonTypeLoginUrl",
+            TdObject::InlineKeyboardButtonTypeSwitchInline(_) => {
+                "inlineKeyboardButtonTypeSwitchInline"
+            }
+            TdObject::InlineKeyboardButtonTypeUrl(_) => "inlineKeyboardButtonTypeUrl",
+            TdObject::InlineKeyboardButtonTypeUser(_) => "inlineKeyboardButtonTypeUser",
+            TdObject::InlineQueryResultAnimation(_) => "inlineQueryResultAnimation",
+            TdObject::InlineQueryResultArticle(_) => "inlineQueryResultArticle",
+            TdObject::InlineQueryResultAudio(_) => "inlineQueryResultAudio",
+            TdObject::InlineQueryResultContact(_) => "inlineQueryResultContact",
+            TdObject::InlineQueryResultDocument(_) => "inlineQueryResultDocument",
+            TdObject::InlineQueryResultGame(_) => "inlineQueryResultGame",
+            TdObject::InlineQueryResultLocation(_) => "inlineQueryResultLocation",
+            TdObject::InlineQueryResultPhoto(_) => "inlineQueryResultPhoto",
+            TdObject::InlineQueryResultSticker(_) => "inlineQueryResultSticker",
+            TdObject::InlineQueryResultVenue(_) => "inlineQueryResultVenue",
+            TdObject::InlineQueryResultVideo(_) => "inlineQueryResultVideo",
+            TdObject::InlineQueryResultVoiceNote(_) => "inlineQueryResultVoiceNote",
+            TdObject::InlineQueryResults(_) => "inlineQueryResults",
+            TdObject::InputBackgroundLocal(_) => "inputBackgroundLocal",
+            TdObject::InputBackgroundRemote(_) => "inputBackgroundRemote",
+            TdObject::InputChatPhotoAnimation(_) => "inputChatPhotoAnimation",
+            TdObject::InputChatPhotoPrevious(_) => "inputChatPhotoPrevious",
+            TdObject::InputChatPhotoStatic(_) => "inputChatPhotoStatic",
+            TdObject::InputCredentialsApplePay(_) => "inputCredentialsApplePay",
+            TdObject::InputCredentialsGooglePay(_) => "inputCredentialsGooglePay",
+            TdObject::InputCredentialsNew(_) => "inputCredentialsNew",
+            TdObject::InputCredentialsSaved(_) => "inputCredentialsSaved",
+            TdObject::InputFileGenerated(_) => "inputFileGenerated",
+            TdObject::InputFileId(_) => "inputFileId",
+            TdObject::InputFileLocal(_) => "inputFileLocal",
+            TdObject::InputFileRemote(_) => "inputFileRemote",
+            TdObject::InputIdentityDocument(_) => "inputIdentityDocument",
+            TdObject::InputInlineQueryResultAnimation(_) => "inputInlineQueryResultAnimation",
+            TdObject::InputInlineQueryResultArticle(_) => "inputInlineQueryResultArticle",
+            TdObject::InputInlineQueryResultAudio(_) => "inputInlineQueryResultAudio",
+            TdObject::InputInlineQueryResultContact(_) => "inputInlineQueryResultContact",
+            TdObject::InputInlineQueryResultDocument(_) => "inputInlineQueryResultDocument",
+            TdObject::InputInlineQueryResultGame(_) => "inputInlineQueryResultGame",
+            TdObject::InputInlineQueryResultLocation(_) => "inputInlineQueryResultLocation",
+            TdObject::InputInlineQueryResultPhoto(_) => "inputInlineQueryResultPhoto",
+            TdObject::InputInlineQueryResultSticker(_) => "inputInlineQueryResultSticker",
+            TdObject::InputInlineQueryResultVenue(_) => "inputInlineQueryResultVenue",
+            TdObject::InputInlineQueryResultVideo(_) => "inputInlineQueryResultVideo",
+            TdObject::InputInlineQueryResultVoiceNote(_) => "inputInlineQueryResultVoiceNote",
+            TdObject::InputMessageAnimation(_) => "inputMessageAnimation",
+            TdObject::InputMessageAudio(_) => "inputMessageAudio",
+            TdObject::InputMessageContact(_) => "inputMessageContact",
+            TdObject::InputMessageDice(_) => "inputMessageDice",
+            TdObject::InputMessageDocument(_) => "inputMessageDocument",
+            TdObject::InputMessageForwarded(_) => "inputMessageForwarded",
+            TdObject::InputMessageGame(_) => "inputMessageGame",
+            TdObject::InputMessageInvoice(_) => "inputMessageInvoice",
+            TdObject::InputMessageLocation(_) => "inputMessageLocation",
+            TdObject::InputMessagePhoto(_) => "inputMessagePhoto",
+            TdObject::InputMessagePoll(_) => "inputMessagePoll",
+            TdObject::InputMessageSticker(_) => "inputMessageSticker",
+            TdObject::InputMessageText(_) => "inputMessageText",
+            TdObject::InputMessageVenue(_) => "inputMessageVenue",
+            TdObject::InputMessageVideo(_) => "inputMessageVideo",
+            TdObject::InputMessageVideoNote(_) => "inputMessageVideoNote",
+            TdObject::InputMessageVoiceNote(_) => "inputMessageVoiceNote",
+            TdObject::InputPassportElementAddress(_) => "inputPassportElementAddress",
+            TdObject::InputPassportElementBankStatement(_) => "inputPassportElementBankStatement",
+            TdObject::InputPassportElementDriverLicense(_) => "inputPassportElementDriverLicense",
+            TdObject::InputPassportElementEmailAddress(_) => "inputPassportElementEmailAddress",
+            TdObject::InputPassportElementError(_) => "inputPassportElementError",
+            TdObject::InputPassportElementErrorSourceDataField(_) => {
+                "inputPassportElementErrorSourceDataField"
+            }
+            TdObject::InputPassportElementErrorSourceFile(_) => {
+                "inputPassportElementErrorSourceFile"
+            }
+            TdObject::InputPassportElementErrorSourceFiles(_) => {
+                "inputPassportElementErrorSourceFiles"
+            }
+            TdObject::InputPassportElementErrorSourceFrontSide(_) => {
+                "inputPassportElementErrorSourceFrontSide"
+            }
+            TdObject::InputPassportElementErrorSourceReverseSide(_) => {
+                "inputPassportElementErrorSourceReverseSide"
+            }
+            TdObject::InputPassportElementErrorSourceSelfie(_) => {
+                "inputPassportElementErrorSourceSelfie"
+            }
+            TdObject::InputPassportElementErrorSourceTranslationFile(_) => {
+                "inputPassportElementErrorSourceTranslationFile"
+            }
+            TdObject::InputPassportElementErrorSourceTranslationFiles(_) => {
+                "inputPassportElementErrorSourceTranslationFiles"
+            }
+            TdObject::InputPassportElementErrorSourceUnspecified(_) => {
+                "inputPassportElementErrorSourceUnspecified"
+            }
+            TdObject::InputPassportElementIdentityCard(_) => "inputPassportElementIdentityCard",
+            TdObject::InputPassportElementInternalPassport(_) => {
+                "inputPassportElementInternalPassport"
+            }
+            TdObject::InputPassportElementPassport(_) => "inputPassportElementPassport",
+            TdObject::InputPassportElementPassportRegistration(_) => {
+                "inputPassportElementPassportRegistration"
+            }
+            TdObject::InputPassportElementPersonalDetails(_) => {
+                "inputPassportElementPersonalDetails"
+            }
+            TdObject::InputPassportElementPhoneNumber(_) => "inputPassportElementPhoneNumber",
+            TdObject::InputPassportElementRentalAgreement(_) => {
+                "inputPassportElementRentalAgreement"
+            }
+            TdObject::InputPassportElementTemporaryRegistration(_) => {
+                "inputPassportElementTemporaryRegistration"
+            }
+            TdObject::InputPassportElementUtilityBill(_) => "inputPassportElementUtilityBill",
+            TdObject::InputPersonalDocument(_) => "inputPersonalDocument",
+            TdObject::InputStickerAnimated(_) => "inputStickerAnimated",
+            TdObject::InputStickerStatic(_) => "inputStickerStatic",
+            TdObject::InputThumbnail(_) => "inputThumbnail",
+            TdObject::InternalLinkTypeActiveSessions(_) => "internalLinkTypeActiveSessions",
+            TdObject::InternalLinkTypeAuthenticationCode(_) => "internalLinkTypeAuthenticationCode",
+            TdObject::InternalLinkTypeBackground(_) => "internalLinkTypeBackground",
+            TdObject::InternalLinkTypeBotStart(_) => "internalLinkTypeBotStart",
+            TdObject::InternalLinkTypeBotStartInGroup(_) => "internalLinkTypeBotStartInGroup",
+            TdObject::InternalLinkTypeChangePhoneNumber(_) => "internalLinkTypeChangePhoneNumber",
+            TdObject::InternalLinkTypeChatInvite(_) => "internalLinkTypeChatInvite",
+            TdObject::InternalLinkTypeFilterSettings(_) => "internalLinkTypeFilterSettings",
+            TdObject::InternalLinkTypeGame(_) => "internalLinkTypeGame",
+            TdObject::InternalLinkTypeLanguagePack(_) => "internalLinkTypeLanguagePack",
+            TdObject::InternalLinkTypeMessage(_) => "internalLinkTypeMessage",
+            TdObject::InternalLinkTypeMessageDraft(_) => "internalLinkTypeMessageDraft",
+            TdObject::InternalLinkTypePassportDataRequest(_) => {
+                "internalLinkTypePassportDataRequest"
+            }
+            TdObject::InternalLinkTypePhoneNumberConfirmation(_) => {
+                "internalLinkTypePhoneNumberConfirmation"
+            }
+            TdObject::InternalLinkTypeProxy(_) => "internalLinkTypeProxy",
+            TdObject::InternalLinkTypePublicChat(_) => "internalLinkTypePublicChat",
+            TdObject::InternalLinkTypeQrCodeAuthentication(_) => {
+                "internalLinkTypeQrCodeAuthentication"
+            }
+            TdObject::InternalLinkTypeSettings(_) => "internalLinkTypeSettings",
+            TdObject::InternalLinkTypeStickerSet(_) => "internalLinkTypeStickerSet",
+            TdObject::InternalLinkTypeTheme(_) => "internalLinkTypeTheme",
+            TdObject::InternalLinkTypeThemeSettings(_) => "internalLinkTypeThemeSettings",
+            TdObject::InternalLinkTypeUnknownDeepLink(_) => "internalLinkTypeUnknownDeepLink",
+            TdObject::InternalLinkTypeUnsupportedProxy(_) => "internalLinkTypeUnsupportedProxy",
+            TdObject::InternalLinkTypeVideoChat(_) => "internalLinkTypeVideoChat",
+            TdObject::Invoice(_) => "invoice",
+            TdObject::JsonObjectMember(_) => "jsonObjectMember",
+            TdObject::JsonValueArray(_) => "jsonValueArray",
+            TdObject::JsonValueBoolean(_) => "jsonValueBoolean",
+            TdObject::JsonValueNull(_) => "jsonValueNull",
+            TdObject::JsonValueNumber(_) => "jsonValueNumber",
+            TdObject::JsonValueObject(_) => "jsonValueObject",
+            TdObject::JsonValueString(_) => "jsonValueString",
+            TdObject::KeyboardButton(_) => "keyboardButton",
+            TdObject::KeyboardButtonTypeRequestLocation(_) => "keyboardButtonTypeRequestLocation",
+            TdObject::KeyboardButtonTypeRequestPhoneNumber(_) => {
+                "keyboardButtonTypeRequestPhoneNumber"
+            }
+            TdObject::KeyboardButtonTypeRequestPoll(_) => "keyboardButtonTypeRequestPoll",
+            TdObject::KeyboardButtonTypeText(_) => "keyboardButtonTypeText",
+            TdObject::LabeledPricePart(_) => "labeledPricePart",
+            TdObject::LanguagePackInfo(_) => "languagePackInfo",
+            TdObject::LanguagePackString(_) => "languagePackString",
+            TdObject::LanguagePackStringValueDeleted(_) => "languagePackStringValueDeleted",
+            TdObject::LanguagePackStringValueOrdinary(_) => "languagePackStringValueOrdinary",
+            TdObject::LanguagePackStringValuePluralized(_) => "languagePackStringValuePluralized",
+            TdObject::LanguagePackStrings(_) => "languagePackStrings",
+            TdObject::LocalFile(_) => "localFile",
+            TdObject::LocalizationTargetInfo(_) => "localizationTargetInfo",
+            TdObject::Location(_) => "location",
+            TdObject::LogStreamDefault(_) => "logStreamDefault",
+            TdObject::LogStreamEmpty(_) => "logStreamEmpty",
+            TdObject::LogStreamFile(_) => "logStreamFile",
+            TdObject::LogTags(_) => "logTags",
+            TdObject::LogVerbosityLevel(_) => "logVerbosityLevel",
+            TdObject::LoginUrlInfoOpen(_) => "loginUrlInfoOpen",
+            TdObject::LoginUrlInfoRequestConfirmation(_) => "loginUrlInfoRequestConfirmation",
+            TdObject::MaskPointChin(_) => "maskPointChin",
+            TdObject::MaskPointEyes(_) => "maskPointEyes",
+            TdObject::MaskPointForehead(_) => "maskPointForehead",
+            TdObject::MaskPointMouth(_) => "maskPointMouth",
+            TdObject::MaskPosition(_) => "maskPosition",
+            TdObject::Message(_) => "message",
+            TdObject::MessageAnimatedEmoji(_) => "messageAnimatedEmoji",
+            TdObject::MessageAnimation(_) => "messageAnimation",
+            TdObject::MessageAudio(_) => "messageAudio",
+            TdObject::MessageBasicGroupChatCreate(_) => "messageBasicGroupChatCreate",
+            TdObject::MessageCalendar(_) => "messageCalendar",
+            TdObject::MessageCalendarDay(_) => "messageCalendarDay",
+            TdObject::MessageCall(_) => "messageCall",
+            TdObject::MessageChatAddMembers(_) => "messageChatAddMembers",
+            TdObject::MessageChatChangePhoto(_) => "messageChatChangePhoto",
+            TdObject::MessageChatChangeTitle(_) => "messageChatChangeTitle",
+            TdObject::MessageChatDeleteMember(_) => "messageChatDeleteMember",
+            TdObject::MessageChatDeletePhoto(_) => "messageChatDeletePhoto",
+            TdObject::MessageChatJoinByLink(_) => "messageChatJoinByLink",
+            TdObject::MessageChatJoinByRequest(_) => "messageChatJoinByRequest",
+            TdObject::MessageChatSetTheme(_) => "messageChatSetTheme",
+            TdObject::MessageChatSetTtl(_) => "messageChatSetTtl",
+            TdObject::MessageChatUpgradeFrom(_) => "messageChatUpgradeFrom",
+            TdObject::MessageChatUpgradeTo(_) => "messageChatUpgradeTo",
+            TdObject::MessageContact(_) => "messageContact",
+            TdObject::MessageContactRegistered(_) => "messageContactRegistered",
+            TdObject::MessageCopyOptions(_) => "messageCopyOptions",
+            TdObject::MessageCustomServiceAction(_) => "messageCustomServiceAction",
+            TdObject::MessageDice(_) => "messageDice",
+            TdObject::MessageDocument(_) => "messageDocument",
+            TdObject::MessageExpiredPhoto(_) => "messageExpiredPhoto",
+            TdObject::MessageExpiredVideo(_) => "messageExpiredVideo",
+            TdObject::MessageFileTypeGroup(_) => "messageFileTypeGroup",
+            TdObject::MessageFileTypePrivate(_) => "messageFileTypePrivate",
+            TdObject::MessageFileTypeUnknown(_) => "messageFileTypeUnknown",
+            TdObject::MessageForwardInfo(_) => "messageForwardInfo",
+            TdObject::MessageForwardOriginChannel(_) => "messageForwardOriginChannel",
+            TdObject::MessageForwardOriginChat(_) => "messageForwardOriginChat",
+            TdObject::MessageForwardOriginHiddenUser(_) => "messageForwardOriginHiddenUser",
+            TdObject::MessageForwardOriginMessageImport(_) => "messageForwardOriginMessageImport",
+            TdObject::MessageForwardOriginUser(_) => "messageForwardOriginUser",
+            TdObject::MessageGame(_) => "messageGame",
+            TdObject::MessageGameScore(_) => "messageGameScore",
+            TdObject::MessageInteractionInfo(_) => "messageInteractionInfo",
+            TdObject::MessageInviteVideoChatParticipants(_) => "messageInviteVideoChatParticipants",
+            TdObject::MessageInvoice(_) => "messageInvoice",
+            TdObject::MessageLink(_) => "messageLink",
+            TdObject::MessageLinkInfo(_) => "messageLinkInfo",
+            TdObject::MessageLocation(_) => "messageLocation",
+            TdObject::MessagePassportDataReceived(_) => "messagePassportDataReceived",
+            TdObject::MessagePassportDataSent(_) => "messagePassportDataSent",
+            TdObject::MessagePaymentSuccessful(_) => "messagePaymentSuccessful",
+            TdObject::MessagePaymentSuccessfulBot(_) => "messagePaymentSuccessfulBot",
+            TdObject::MessagePhoto(_) => "messagePhoto",
+            TdObject::MessagePinMessage(_) => "messagePinMessage",
+            TdObject::MessagePoll(_) => "messagePoll",
+            TdObject::MessagePosition(_) => "messagePosition",
+            TdObject::MessagePositions(_) => "messagePositions",
+            TdObject::MessageProximityAlertTriggered(_) => "messageProximityAlertTriggered",
+            TdObject::MessageReplyInfo(_) => "messageReplyInfo",
+            TdObject::MessageSchedulingStateSendAtDate(_) => "messageSchedulingStateSendAtDate",
+            TdObject::MessageSchedulingStateSendWhenOnline(_) => {
+                "messageSchedulingStateSendWhenOnline"
+            }
+            TdObject::MessageScreenshotTaken(_) => "messageScreenshotTaken",
+            TdObject::MessageSendOptions(_) => "messageSendOptions",
+            TdObject::MessageSenderChat(_) => "messageSenderChat",
+            TdObject::MessageSenderUser(_) => "messageSenderUser",
+            TdObject::MessageSenders(_) => "messageSenders",
+            TdObject::MessageSendingStateFailed(_) => "messageSendingStateFailed",
+            TdObject::MessageSendingStatePending(_) => "messageSendingStatePending",
+            TdObject::MessageStatistics(_) => "messageStatistics",
+            TdObject::MessageSticker(_) => "messageSticker",
+            TdObject::MessageSupergroupChatCreate(_) => "messageSupergroupChatCreate",
+            TdObject::MessageText(_) => "messageText",
+            TdObject::MessageThreadInfo(_) => "messageThreadInfo",
+            TdObject::MessageUnsupported(_) => "messageUnsupported",
+            TdObject::MessageVenue(_) => "messageVenue",
+            TdObject::MessageVideo(_) => "messageVideo",
+            TdObject::MessageVideoChatEnded(_) => "messageVideoChatEnded",
+            TdObject::MessageVideoChatScheduled(_) => "messageVideoChatScheduled",
+            TdObject::MessageVideoChatStarted(_) => "messageVideoChatStarted",
+            TdObject::MessageVideoNote(_) => "messageVideoNote",
+            TdObject::MessageVoiceNote(_) => "messageVoiceNote",
+            TdObject::MessageWebsiteConnected(_) => "messageWebsiteConnected",
+            TdObject::Messages(_) => "messages",
+            TdObject::Minithumbnail(_) => "minithumbnail",
+            TdObject::NetworkStatistics(_) => "networkStatistics",
+            TdObject::NetworkStatisticsEntryCall(_) => "networkStatisticsEntryCall",
+            TdObject::NetworkStatisticsEntryFile(_) => "networkStatisticsEntryFile",
+            TdObject::NetworkTypeMobile(_) => "networkTypeMobile",
+            TdObject::NetworkTypeMobileRoaming(_) => "networkTypeMobileRoaming",
+            TdObject::NetworkTypeNone(_) => "networkTypeNone",
+            TdObject::NetworkTypeOther(_) => "networkTypeOther",
+            TdObject::NetworkTypeWiFi(_) => "networkTypeWiFi",
+            TdObject::Notification(_) => "notification",
+            TdObject::NotificationGroup(_) => "notificationGroup",
+            TdObject::NotificationGroupTypeCalls(_) => "notificationGroupTypeCalls",
+            TdObject::NotificationGroupTypeMentions(_) => "notificationGroupTypeMentions",
+            TdObject::NotificationGroupTypeMessages(_) => "notificationGroupTypeMessages",
+            TdObject::NotificationGroupTypeSecretChat(_) => "notificationGroupTypeSecretChat",
+            TdObject::NotificationSettingsScopeChannelChats(_) => {
+                "notificationSettingsScopeChannelChats"
+            }
+            TdObject::NotificationSettingsScopeGroupChats(_) => {
+                "notificationSettingsScopeGroupChats"
+            }
+            TdObject::NotificationSettingsScopePrivateChats(_) => {
+                "notificationSettingsScopePrivateChats"
+            }
+            TdObject::NotificationTypeNewCall(_) => "notificationTypeNewCall",
+            TdObject::NotificationTypeNewMessage(_) => "notificationTypeNewMessage",
+            TdObject::NotificationTypeNewPushMessage(_) => "notificationTypeNewPushMessage",
+            TdObject::NotificationTypeNewSecretChat(_) => "notificationTypeNewSecretChat",
+            TdObject::Ok(_) => "ok",
+            TdObject::OptionValueBoolean(_) => "optionValueBoolean",
+            TdObject::OptionValueEmpty(_) => "optionValueEmpty",
+            TdObject::OptionValueInteger(_) => "optionValueInteger",
+            TdObject::OptionValueString(_) => "optionValueString",
+            TdObject::OrderInfo(_) => "orderInfo",
+            TdObject::PageBlockAnchor(_) => "pageBlockAnchor",
+            TdObject::PageBlockAnimation(_) => "pageBlockAnimation",
+            TdObject::PageBlockAudio(_) => "pageBlockAudio",
+            TdObject::PageBlockAuthorDate(_) => "pageBlockAuthorDate",
+            TdObject::PageBlockBlockQuote(_) => "pageBlockBlockQuote",
+            TdObject::PageBlockCaption(_) => "pageBlockCaption",
+            TdObject::PageBlockChatLink(_) => "pageBlockChatLink",
+            TdObject::PageBlockCollage(_) => "pageBlockCollage",
+            TdObject::PageBlockCover(_) => "pageBlockCover",
+            TdObject::PageBlockDetails(_) => "pageBlockDetails",
+            TdObject::PageBlockDivider(_) => "pageBlockDivider",
+            TdObject::PageBlockEmbedded(_) => "pageBlockEmbedded",
+            TdObject::PageBlockEmbeddedPost(_) => "pageBlockEmbeddedPost",
+            TdObject::PageBlockFooter(_) => "pageBlockFooter",
+            TdObject::PageBlockHeader(_) => "pageBlockHeader",
+            TdObject::PageBlockHorizontalAlignmentCenter(_) => "pageBlockHorizontalAlignmentCenter",
+            TdObject::PageBlockHorizontalAlignmentLeft(_) => "pageBlockHorizontalAlignmentLeft",
+            TdObject::PageBlockHorizontalAlignmentRight(_) => "pageBlockHorizontalAlignmentRight",
+            TdObject::PageBlockKicker(_) => "pageBlockKicker",
+            TdObject::PageBlockList(_) => "pageBlockList",
+            TdObject::PageBlockListItem(_) => "pageBlockListItem",
+            TdObject::PageBlockMap(_) => "pageBlockMap",
+            TdObject::PageBlockParagraph(_) => "pageBlockParagraph",
+            TdObject::PageBlockPhoto(_) => "pageBlockPhoto",
+            TdObject::PageBlockPreformatted(_) => "pageBlockPreformatted",
+            TdObject::PageBlockPullQuote(_) => "pageBlockPullQuote",
+            TdObject::PageBlockRelatedArticle(_) => "pageBlockRelatedArticle",
+            TdObject::PageBlockRelatedArticles(_) => "pageBlockRelatedArticles",
+            TdObject::PageBlockSlideshow(_) => "pageBlockSlideshow",
+            TdObject::PageBlockSubheader(_) => "pageBlockSubheader",
+            TdObject::PageBlockSubtitle(_) => "pageBlockSubtitle",
+            TdObject::PageBlockTable(_) => "pageBlockTable",
+            TdObject::PageBlockTableCell(_) => "pageBlockTableCell",
+            TdObject::PageBlockTitle(_) => "pageBlockTitle",
+            TdObject::PageBlockVerticalAlignmentBottom(_) => "pageBlockVerticalAlignmentBottom",
+            TdObject::PageBlockVerticalAlignmentMiddle(_) => "pageBlockVerticalAlignmentMiddle",
+            TdObject::PageBlockVerticalAlignmentTop(_) => "pageBlockVerticalAlignmentTop",
+            TdObject::PageBlockVideo(_) => "pageBlockVideo",
+            TdObject::PageBlockVoiceNote(_) => "pageBlockVoiceNote",
+            TdObject::PassportAuthorizationForm(_) => "passportAuthorizationForm",
+            TdObject::PassportElementAddress(_) => "passportElementAddress",
+            TdObject::PassportElementBankStatement(_) => "passportElementBankStatement",
+            TdObject::PassportElementDriverLicense(_) => "passportElementDriverLicense",
+            TdObject::PassportElementEmailAddress(_) => "passportElementEmailAddress",
+            TdObject::PassportElementError(_) => "passportElementError",
+            TdObject::PassportElementErrorSourceDataField(_) => {
+                "passportElementErrorSourceDataField"
+            }
+            TdObject::PassportElementErrorSourceFile(_) => "passportElementErrorSourceFile",
+            TdObject::PassportElementErrorSourceFiles(_) => "passportElementErrorSourceFiles",
+            TdObject::PassportElementErrorSourceFrontSide(_) => {
+                "passportElementErrorSourceFrontSide"
+            }
+            TdObject::PassportElementErrorSourceReverseSide(_) => {
+                "passportElementErrorSourceReverseSide"
+            }
+            TdObject::PassportElementErrorSourceSelfie(_) => "passportElementErrorSourceSelfie",
+            TdObject::PassportElementErrorSourceTranslationFile(_) => {
+                "passportElementErrorSourceTranslationFile"
+            }
+            TdObject::PassportElementErrorSourceTranslationFiles(_) => {
+                "passportElementErrorSourceTranslationFiles"
+            }
+            TdObject::PassportElementErrorSourceUnspecified(_) => {
+                "passportElementErrorSourceUnspecified"
+            }
+            TdObject::PassportElementIdentityCard(_) => "passportElementIdentityCard",
+            TdObject::PassportElementInternalPassport(_) => "passportElementInternalPassport",
+            TdObject::PassportElementPassport(_) => "passportElementPassport",
+            TdObject::PassportElementPassportRegistration(_) => {
+                "passportElementPassportRegistration"
+            }
+            TdObject::PassportElementPersonalDetails(_) => "passportElementPersonalDetails",
+            TdObject::PassportElementPhoneNumber(_) => "passportElementPhoneNumber",
+            TdObject::PassportElementRentalAgreement(_) => "passportElementRentalAgreement",
+            TdObject::PassportElementTemporaryRegistration(_) => {
+                "passportElementTemporaryRegistration"
+            }
+            TdObject::PassportElementTypeAddress(_) => "passportElementTypeAddress",
+            TdObject::PassportElementTypeBankStatement(_) => "passportElementTypeBankStatement",
+            TdObject::PassportElementTypeDriverLicense(_) => "passportElementTypeDriverLicense",
+            TdObject::PassportElementTypeEmailAddress(_) => "passportElementTypeEmailAddress",
+            TdObject::PassportElementTypeIdentityCard(_) => "passportElementTypeIdentityCard",
+            TdObject::PassportElementTypeInternalPassport(_) => {
+                "passportElementTypeInternalPassport"
+            }
+            TdObject::PassportElementTypePassport(_) => "passportElementTypePassport",
+            TdObject::PassportElementTypePassportRegistration(_) => {
+                "passportElementTypePassportRegistration"
+            }
+            TdObject::PassportElementTypePersonalDetails(_) => "passportElementTypePersonalDetails",
+            TdObject::PassportElementTypePhoneNumber(_) => "passportElementTypePhoneNumber",
+            TdObject::PassportElementTypeRentalAgreement(_) => "passportElementTypeRentalAgreement",
+            TdObject::PassportElementTypeTemporaryRegistration(_) => {
+                "passportElementTypeTemporaryRegistration"
+            }
+            TdObject::PassportElementTypeUtilityBill(_) => "passportElementTypeUtilityBill",
+            TdObject::PassportElementUtilityBill(_) => "passportElementUtilityBill",
+            TdObject::PassportElements(_) => "passportElements",
+            TdObject::PassportElementsWithErrors(_) => "passportElementsWithErrors",
+            TdObject::PassportRequiredElement(_) => "passportRequiredElement",
+            TdObject::PassportSuitableElement(_) => "passportSuitableElement",
+            TdObject::PasswordState(_) => "passwordState",
+            TdObject::PaymentForm(_) => "paymentForm",
+            TdObject::PaymentFormTheme(_) => "paymentFormTheme",
+            TdObject::PaymentReceipt(_) => "paymentReceipt",
+            TdObject::PaymentResult(_) => "paymentResult",
+            TdObject::PaymentsProviderStripe(_) => "paymentsProviderStripe",
+            TdObject::PersonalDetails(_) => "personalDetails",
+            TdObject::PersonalDocument(_) => "personalDocument",
+            TdObject::PhoneNumberAuthenticationSettings(_) => "phoneNumberAuthenticationSettings",
+            TdObject::PhoneNumberInfo(_) => "phoneNumberInfo",
+            TdObject::Photo(_) => "photo",
+            TdObject::PhotoSize(_) => "photoSize",
+            TdObject::Point(_) => "point",
+            TdObject::Poll(_) => "poll",
+            TdObject::PollOption(_) => "pollOption",
+            TdObject::PollTypeQuiz(_) => "pollTypeQuiz",
+            TdObject::PollTypeRegular(_) => "pollTypeRegular",
+            TdObject::ProfilePhoto(_) => "profilePhoto",
+            TdObject::Proxies(_) => "proxies",
+            TdObject::Proxy(_) => "proxy",
+            TdObject::ProxyTypeHttp(_) => "proxyTypeHttp",
+            TdObject::ProxyTypeMtproto(_) => "proxyTypeMtproto",
+            TdObject::ProxyTypeSocks5(_) => "proxyTypeSocks5",
+            TdObject::PublicChatTypeHasUsername(_) => "publicChatTypeHasUsername",
+            TdObject::PublicChatTypeIsLocationBased(_) => "publicChatTypeIsLocationBased",
+            TdObject::PushMessageContentAnimation(_) => "pushMessageContentAnimation",
+            TdObject::PushMessageContentAudio(_) => "pushMessageContentAudio",
+            TdObject::PushMessageContentBasicGroupChatCreate(_) => {
+                "pushMessageContentBasicGroupChatCreate"
+            }
+            TdObject::PushMessageContentChatAddMembers(_) => "pushMessageContentChatAddMembers",
+            TdObject::PushMessageContentChatChangePhoto(_) => "pushMessageContentChatChangePhoto",
+            TdObject::PushMessageContentChatChangeTitle(_) => "pushMessageContentChatChangeTitle",
+            TdObject::PushMessageContentChatDeleteMember(_) => "pushMessageContentChatDeleteMember",
+            TdObject::PushMessageContentChatJoinByLink(_) => "pushMessageContentChatJoinByLink",
+            TdObject::PushMessageContentChatJoinByRequest(_) => {
+                "pushMessageContentChatJoinByRequest"
+            }
+            TdObject::PushMessageContentChatSetTheme(_) => "pushMessageContentChatSetTheme",
+            TdObject::PushMessageContentContact(_) => "pushMessageContentContact",
+            TdObject::PushMessageContentContactRegistered(_) => {
+                "pushMessageContentContactRegistered"
+            }
+            TdObject::PushMessageContentDocument(_) => "pushMessageContentDocument",
+            TdObject::PushMessageContentGame(_) => "pushMessageContentGame",
+            TdObject::PushMessageContentGameScore(_) => "pushMessageContentGameScore",
+            TdObject::PushMessageContentHidden(_) => "pushMessageContentHidden",
+            TdObject::PushMessageContentInvoice(_) => "pushMessageContentInvoice",
+            TdObject::PushMessageContentLocation(_) => "pushMessageContentLocation",
+            TdObject::PushMessageContentMediaAlbum(_) => "pushMessageContentMediaAlbum",
+            TdObject::PushMessageContentMessageForwards(_) => "pushMessageContentMessageForwards",
+            TdObject::PushMessageContentPhoto(_) => "pushMessageContentPhoto",
+            TdObject::PushMessageContentPoll(_) => "pushMessageContentPoll",
+            TdObject::PushMessageContentScreenshotTaken(_) => "pushMessageContentScreenshotTaken",
+            TdObject::PushMessageContentSticker(_) => "pushMessageContentSticker",
+            TdObject::PushMessageContentText(_) => "pushMessageContentText",
+            TdObject::PushMessageContentVideo(_) => "pushMessageContentVideo",
+            TdObject::PushMessageContentVideoNote(_) => "pushMessageContentVideoNote",
+            TdObject::PushMessageContentVoiceNote(_) => "pushMessageContentVoiceNote",
+            TdObject::PushReceiverId(_) => "pushReceiverId",
+            TdObject::RecommendedChatFilter(_) => "recommendedChatFilter",
+            TdObject::RecommendedChatFilters(_) => "recommendedChatFilters",
+            TdObject::RecoveryEmailAddress(_) => "recoveryEmailAddress",
+            TdObject::RemoteFile(_) => "remoteFile",
+            TdObject::ReplyMarkupForceReply(_) => "replyMarkupForceReply",
+            TdObject::ReplyMarkupInlineKeyboard(_) => "replyMarkupInlineKeyboard",
+            TdObject::ReplyMarkupRemoveKeyboard(_) => "replyMarkupRemoveKeyboard",
+            TdObject::ReplyMarkupShowKeyboard(_) => "replyMarkupShowKeyboard",
+            TdObject::ResetPasswordResultDeclined(_) => "resetPasswordResultDeclined",
+            TdObject::ResetPasswordResultOk(_) => "resetPasswordResultOk",
+            TdObject::ResetPasswordResultPending(_) => "resetPasswordResultPending",
+            TdObject::RichTextAnchor(_) => "richTextAnchor",
+            TdObject::RichTextAnchorLink(_) => "richTextAnchorLink",
+            TdObject::RichTextBold(_) => "richTextBold",
+            TdObject::RichTextEmailAddress(_) => "richTextEmailAddress",
+            TdObject::RichTextFixed(_) => "richTextFixed",
+            TdObject::RichTextIcon(_) => "richTextIcon",
+            TdObject::RichTextItalic(_) => "richTextItalic",
+            TdObject::RichTextMarked(_) => "richTextMarked",
+            TdObject::RichTextPhoneNumber(_) => "richTextPhoneNumber",
+            TdObject::RichTextPlain(_) => "richTextPlain",
+            TdObject::RichTextReference(_) => "richTextReference",
+            TdObject::RichTextStrikethrough(_) => "richTextStrikethrough",
+            TdObject::RichTextSubscript(_) => "richTextSubscript",
+            TdObject::RichTextSuperscript(_) => "richTextSuperscript",
+            TdObject::RichTextUnderline(_) => "richTextUnderline",
+            TdObject::RichTextUrl(_) => "richTextUrl",
+            TdObject::RichTexts(_) => "richTexts",
+            TdObject::SavedCredentials(_) => "savedCredentials",
+            TdObject::ScopeNotificationSettings(_) => "scopeNotificationSettings",
+            TdObject::SearchMessagesFilterAnimation(_) => "searchMessagesFilterAnimation",
+            TdObject::SearchMessagesFilterAudio(_) => "searchMessagesFilterAudio",
+            TdObject::SearchMessagesFilterChatPhoto(_) => "searchMessagesFilterChatPhoto",
+            TdObject::SearchMessagesFilterDocument(_) => "searchMessagesFilterDocument",
+            TdObject::SearchMessagesFilterEmpty(_) => "searchMessagesFilterEmpty",
+            TdObject::SearchMessagesFilterFailedToSend(_) => "searchMessagesFilterFailedToSend",
+            TdObject::SearchMessagesFilterMention(_) => "searchMessagesFilterMention",
+            TdObject::SearchMessagesFilterPhoto(_) => "searchMessagesFilterPhoto",
+            TdObject::SearchMessagesFilterPhotoAndVideo(_) => "searchMessagesFilterPhotoAndVideo",
+            TdObject::SearchMessagesFilterPinned(_) => "searchMessagesFilterPinned",
+            TdObject::SearchMessagesFilterUnreadMention(_) => "searchMessagesFilterUnreadMention",
+            TdObject::SearchMessagesFilterUrl(_) => "searchMessagesFilterUrl",
+            TdObject::SearchMessagesFilterVideo(_) => "searchMessagesFilterVideo",
+            TdObject::SearchMessagesFilterVideoNote(_) => "searchMessagesFilterVideoNote",
+            TdObject::SearchMessagesFilterVoiceAndVideoNote(_) => {
+                "searchMessagesFilterVoiceAndVideoNote"
+            }
+            TdObject::SearchMessagesFilterVoiceNote(_) => "searchMessagesFilterVoiceNote",
+            TdObject::Seconds(_) => "seconds",
+            TdObject::SecretChat(_) => "secretChat",
+            TdObject::SecretChatStateClosed(_) => "secretChatStateClosed",
+            TdObject::SecretChatStatePending(_) => "secretChatStatePending",
+            TdObject::SecretChatStateReady(_) => "secretChatStateReady",
+            TdObject::Session(_) => "session",
+            TdObject::Sessions(_) => "sessions",
+            TdObject::ShippingOption(_) => "shippingOption",
+            TdObject::SponsoredMessage(_) => "sponsoredMessage",
+            TdObject::StatisticalGraphAsync(_) => "statisticalGraphAsync",
+            TdObject::StatisticalGraphData(_) => "statisticalGraphData",
+            TdObject::StatisticalGraphError(_) => "statisticalGraphError",
+            TdObject::StatisticalValue(_) => "statisticalValue",
+            TdObject::Sticker(_) => "sticker",
+            TdObject::StickerSet(_) => "stickerSet",
+            TdObject::StickerSetInfo(_) => "stickerSetInfo",
+            TdObject::StickerSets(_) => "stickerSets",
+            TdObject::Stickers(_) => "stickers",
+            TdObject::StorageStatistics(_) => "storageStatistics",
+            TdObject::StorageStatisticsByChat(_) => "storageStatisticsByChat",
+            TdObject::StorageStatisticsByFileType(_) => "storageStatisticsByFileType",
+            TdObject::StorageStatisticsFast(_) => "storageStatisticsFast",
+            TdObject::SuggestedActionCheckPassword(_) => "suggestedActionCheckPassword",
+            TdObject::SuggestedActionCheckPhoneNumber(_) => "suggestedActionCheckPhoneNumber",
+            TdObject::SuggestedActionConvertToBroadcastGroup(_) => {
+                "suggestedActionConvertToBroadcastGroup"
+            }
+            TdObject::SuggestedActionEnableArchiveAndMuteNewChats(_) => {
+                "suggestedActionEnableArchiveAndMuteNewChats"
+            }
+            TdObject::SuggestedActionSetPassword(_) => "suggestedActionSetPassword",
+            TdObject::SuggestedActionViewChecksHint(_) => "suggestedActionViewChecksHint",
+            TdObject::Supergroup(_) => "supergroup",
+            TdObject::SupergroupFullInfo(_) => "supergroupFullInfo",
+            TdObject::SupergroupMembersFilterAdministrators(_) => {
+                "supergroupMembersFilterAdministrators"
+            }
+            TdObject::SupergroupMembersFilterBanned(_) => "supergroupMembersFilterBanned",
+            TdObject::SupergroupMembersFilterBots(_) => "supergroupMembersFilterBots",
+            TdObject::SupergroupMembersFilterContacts(_) => "supergroupMembersFilterContacts",
+            TdObject::SupergroupMembersFilterMention(_) => "supergroupMembersFilterMention",
+            TdObject::SupergroupMembersFilterRecent(_) => "supergroupMembersFilterRecent",
+            TdObject::SupergroupMembersFilterRestricted(_) => "supergroupMembersFilterRestricted",
+            TdObject::SupergroupMembersFilterSearch(_) => "supergroupMembersFilterSearch",
+            TdObject::TMeUrl(_) => "tMeUrl",
+            TdObject::TMeUrlTypeChatInvite(_) => "tMeUrlTypeChatInvite",
+            TdObject::TMeUrlTypeStickerSet(_) => "tMeUrlTypeStickerSet",
+            TdObject::TMeUrlTypeSupergroup(_) => "tMeUrlTypeSupergroup",
+            TdObject::TMeUrlTypeUser(_) => "tMeUrlTypeUser",
+            TdObject::TMeUrls(_) => "tMeUrls",
+            TdObject::TdlibParameters(_) => "tdlibParameters",
+            TdObject::TemporaryPasswordState(_) => "temporaryPasswordState",
+            TdObject::TermsOfService(_) => "termsOfService",
+            TdObject::TestBytes(_) => "testBytes",
+            TdObject::TestInt(_) => "testInt",
+            TdObject::TestString(_) => "testString",
+            TdObject::TestVectorInt(_) => "testVectorInt",
+            TdObject::TestVectorIntObject(_) => "testVectorIntObject",
+            TdObject::TestVectorString(_) => "testVectorString",
+            TdObject::TestVectorStringObject(_) => "testVectorStringObject",
+            TdObject::Text(_) => "text",
+            TdObject::TextEntities(_) => "textEntities",
+            TdObject::TextEntity(_) => "textEntity",
+            TdObject::TextEntityTypeBankCardNumber(_) => "textEntityTypeBankCardNumber",
+            TdObject::TextEntityTypeBold(_) => "textEntityTypeBold",
+            TdObject::TextEntityTypeBotCommand(_) => "textEntityTypeBotCommand",
+            TdObject::TextEntityTypeCashtag(_) => "textEntityTypeCashtag",
+            TdObject::TextEntityTypeCode(_) => "textEntityTypeCode",
+            TdObject::TextEntityTypeEmailAddress(_) => "textEntityTypeEmailAddress",
+            TdObject::TextEntityTypeHashtag(_) => "textEntityTypeHashtag",
+            TdObject::TextEntityTypeItalic(_) => "textEntityTypeItalic",
+            TdObject::TextEntityTypeMediaTimestamp(_) => "textEntityTypeMediaTimestamp",
+            TdObject::TextEntityTypeMention(_) => "textEntityTypeMention",
+            TdObject::TextEntityTypeMentionName(_) => "textEntityTypeMentionName",
+            TdObject::TextEntityTypePhoneNumber(_) => "textEntityTypePhoneNumber",
+            TdObject::TextEntityTypePre(_) => "textEntityTypePre",
+            TdObject::TextEntityTypePreCode(_) => "textEntityTypePreCode",
+            TdObject::TextEntityTypeStrikethrough(_) => "textEntityTypeStrikethrough",
+            TdObject::TextEntityTypeTextUrl(_) => "textEntityTypeTextUrl",
+            TdObject::TextEntityTypeUnderline(_) => "textEntityTypeUnderline",
+            TdObject::TextEntityTypeUrl(_) => "textEntityTypeUrl",
+            TdObject::TextParseModeHTML(_) => "textParseModeHTML",
+            TdObject::TextParseModeMarkdown(_) => "textParseModeMarkdown",
+            TdObject::ThemeSettings(_) => "themeSettings",
+            TdObject::Thumbnail(_) => "thumbnail",
+            TdObject::ThumbnailFormatGif(_) => "thumbnailFormatGif",
+            TdObject::ThumbnailFormatJpeg(_) => "thumbnailFormatJpeg",
+            TdObject::ThumbnailFormatMpeg4(_) => "thumbnailFormatMpeg4",
+            TdObject::ThumbnailFormatPng(_) => "thumbnailFormatPng",
+            TdObject::ThumbnailFormatTgs(_) => "thumbnailFormatTgs",
+            TdObject::ThumbnailFormatWebp(_) => "thumbnailFormatWebp",
+            TdObject::TopChatCategoryBots(_) => "topChatCategoryBots",
+            TdObject::TopChatCategoryCalls(_) => "topChatCategoryCalls",
+            TdObject::TopChatCategoryChannels(_) => "topChatCategoryChannels",
+            TdObject::TopChatCategoryForwardChats(_) => "topChatCategoryForwardChats",
+            TdObject::TopChatCategoryGroups(_) => "topChatCategoryGroups",
+            TdObject::TopChatCategoryInlineBots(_) => "topChatCategoryInlineBots",
+            TdObject::TopChatCategoryUsers(_) => "topChatCategoryUsers",
+            TdObject::UpdateActiveNotifications(_) => "updateActiveNotifications",
+            TdObject::UpdateAnimatedEmojiMessageClicked(_) => "updateAnimatedEmojiMessageClicked",
+            TdObject::UpdateAnimationSearchParameters(_) => "updateAnimationSearchParameters",
+            TdObject::UpdateAuthorizationState(_) => "updateAuthorizationState",
+            TdObject::UpdateBasicGroup(_) => "updateBasicGroup",
+            TdObject::UpdateBasicGroupFullInfo(_) => "updateBasicGroupFullInfo",
+            TdObject::UpdateCall(_) => "updateCall",
+            TdObject::UpdateChatAction(_) => "updateChatAction",
+            TdObject::UpdateChatActionBar(_) => "updateChatActionBar",
+            TdObject::UpdateChatDefaultDisableNotification(_) => {
+                "updateChatDefaultDisableNotification"
+            }
+            TdObject::UpdateChatDraftMessage(_) => "updateChatDraftMessage",
+            TdObject::UpdateChatFilters(_) => "updateChatFilters",
+            TdObject::UpdateChatHasProtectedContent(_) => "updateChatHasProtectedContent",
+            TdObject::UpdateChatHasScheduledMessages(_) => "updateChatHasScheduledMessages",
+            TdObject::UpdateChatIsBlocked(_) => "updateChatIsBlocked",
+            TdObject::UpdateChatIsMarkedAsUnread(_) => "updateChatIsMarkedAsUnread",
+            TdObject::UpdateChatLastMessage(_) => "updateChatLastMessage",
+            TdObject::UpdateChatMember(_) => "updateChatMember",
+            TdObject::UpdateChatMessageSender(_) => "updateChatMessageSender",
+            TdObject::UpdateChatMessageTtl(_) => "updateChatMessageTtl",
+            TdObject::UpdateChatNotificationSettings(_) => "updateChatNotificationSettings",
+            TdObject::UpdateChatOnlineMemberCount(_) => "updateChatOnlineMemberCount",
+            TdObject::UpdateChatPendingJoinRequests(_) => "updateChatPendingJoinRequests",
+            TdObject::UpdateChatPermissions(_) => "updateChatPermissions",
+            TdObject::UpdateChatPhoto(_) => "updateChatPhoto",
+            TdObject::UpdateChatPosition(_) => "updateChatPosition",
+            TdObject::UpdateChatReadInbox(_) => "updateChatReadInbox",
+            TdObject::UpdateChatReadOutbox(_) => "updateChatReadOutbox",
+            TdObject::UpdateChatReplyMarkup(_) => "updateChatReplyMarkup",
+            TdObject::UpdateChatTheme(_) => "updateChatTheme",
+            TdObject::UpdateChatThemes(_) => "updateChatThemes",
+            TdObject::UpdateChatTitle(_) => "updateChatTitle",
+            TdObject::UpdateChatUnreadMentionCount(_) => "updateChatUnreadMentionCount",
+            TdObject::UpdateChatVideoChat(_) => "updateChatVideoChat",
+            TdObject::UpdateConnectionState(_) => "updateConnectionState",
+            TdObject::UpdateDeleteMessages(_) => "updateDeleteMessages",
+            TdObject::UpdateDiceEmojis(_) => "updateDiceEmojis",
+            TdObject::UpdateFavoriteStickers(_) => "updateFavoriteStickers",
+            TdObject::UpdateFile(_) => "updateFile",
+            TdObject::UpdateFileGenerationStart(_) => "updateFileGenerationStart",
+            TdObject::UpdateFileGenerationStop(_) => "updateFileGenerationStop",
+            TdObject::UpdateGroupCall(_) => "updateGroupCall",
+            TdObject::UpdateGroupCallParticipant(_) => "updateGroupCallParticipant",
+            TdObject::UpdateHavePendingNotifications(_) => "updateHavePendingNotifications",
+            TdObject::UpdateInstalledStickerSets(_) => "updateInstalledStickerSets",
+            TdObject::UpdateLanguagePackStrings(_) => "updateLanguagePackStrings",
+            TdObject::UpdateMessageContent(_) => "updateMessageContent",
+            TdObject::UpdateMessageContentOpened(_) => "updateMessageContentOpened",
+            TdObject::UpdateMessageEdited(_) => "updateMessageEdited",
+            TdObject::UpdateMessageInteractionInfo(_) => "updateMessageInteractionInfo",
+            TdObject::UpdateMessageIsPinned(_) => "updateMessageIsPinned",
+            TdObject::UpdateMessageLiveLocationViewed(_) => "updateMessageLiveLocationViewed",
+            TdObject::UpdateMessageMentionRead(_) => "updateMessageMentionRead",
+            TdObject::UpdateMessageSendAcknowledged(_) => "updateMessageSendAcknowledged",
+            TdObject::UpdateMessageSendFailed(_) => "updateMessageSendFailed",
+            TdObject::UpdateMessageSendSucceeded(_) => "updateMessageSendSucceeded",
+            TdObject::UpdateNewCallSignalingData(_) => "updateNewCallSignalingData",
+            TdObject::UpdateNewCallbackQuery(_) => "updateNewCallbackQuery",
+            TdObject::UpdateNewChat(_) => "updateNewChat",
+            TdObject::UpdateNewChatJoinRequest(_) => "updateNewChatJoinRequest",
+            TdObject::UpdateNewChosenInlineResult(_) => "updateNewChosenInlineResult",
+            TdObject::UpdateNewCustomEvent(_) => "updateNewCustomEvent",
+            TdObject::UpdateNewCustomQuery(_) => "updateNewCustomQuery",
+            TdObject::UpdateNewInlineCallbackQuery(_) => "updateNewInlineCallbackQuery",
+            TdObject::UpdateNewInlineQuery(_) => "updateNewInlineQuery",
+            TdObject::UpdateNewMessage(_) => "updateNewMessage",
+            TdObject::UpdateNewPreCheckoutQuery(_) => "updateNewPreCheckoutQuery",
+            TdObject::UpdateNewShippingQuery(_) => "updateNewShippingQuery",
+            TdObject::UpdateNotification(_) => "updateNotification",
+            TdObject::UpdateNotificationGroup(_) => "updateNotificationGroup",
+            TdObject::UpdateOption(_) => "updateOption",
+            TdObject::UpdatePoll(_) => "updatePoll",
+            TdObject::UpdatePollAnswer(_) => "updatePollAnswer",
+            TdObject::UpdateRecentStickers(_) => "updateRecentStickers",
+            TdObject::UpdateSavedAnimations(_) => "updateSavedAnimations",
+            TdObject::UpdateScopeNotificationSettings(_) => "updateScopeNotificationSettings",
+            TdObject::UpdateSecretChat(_) => "updateSecretChat",
+            TdObject::UpdateSelectedBackground(_) => "updateSelectedBackground",
+            TdObject::UpdateServiceNotification(_) => "updateServiceNotification",
+            TdObject::UpdateStickerSet(_) => "updateStickerSet",
+            TdObject::UpdateSuggestedActions(_) => "updateSuggestedActions",
+            TdObject::UpdateSupergroup(_) => "updateSupergroup",
+            TdObject::UpdateSupergroupFullInfo(_) => "updateSupergroupFullInfo",
+            TdObject::UpdateTermsOfService(_) => "updateTermsOfService",
+            TdObject::UpdateTrendingStickerSets(_) => "updateTrendingStickerSets",
+            TdObject::UpdateUnreadChatCount(_) => "updateUnreadChatCount",
+            TdObject::UpdateUnreadMessageCount(_) => "updateUnreadMessageCount",
+            TdObject::UpdateUser(_) => "updateUser",
+            TdObject::UpdateUserFullInfo(_) => "updateUserFullInfo",
+            TdObject::UpdateUserPrivacySettingRules(_) => "updateUserPrivacySettingRules",
+            TdObject::UpdateUserStatus(_) => "updateUserStatus",
+            TdObject::UpdateUsersNearby(_) => "updateUsersNearby",
+            TdObject::Updates(_) => "updates",
+            TdObject::User(_) => "user",
+            TdObject::UserFullInfo(_) => "userFullInfo",
+            TdObject::UserPrivacySettingAllowCalls(_) => "userPrivacySettingAllowCalls",
+            TdObject::UserPrivacySettingAllowChatInvites(_) => "userPrivacySettingAllowChatInvites",
+            TdObject::UserPrivacySettingAllowFindingByPhoneNumber(_) => {
+                "userPrivacySettingAllowFindingByPhoneNumber"
+            }
+            TdObject::UserPrivacySettingAllowPeerToPeerCalls(_) => {
+                "userPrivacySettingAllowPeerToPeerCalls"
+            }
+            TdObject::UserPrivacySettingRuleAllowAll(_) => "userPrivacySettingRuleAllowAll",
+            TdObject::UserPrivacySettingRuleAllowChatMembers(_) => {
+                "userPrivacySettingRuleAllowChatMembers"
+            }
+            TdObject::UserPrivacySettingRuleAllowContacts(_) => {
+                "userPrivacySettingRuleAllowContacts"
+            }
+            TdObject::UserPrivacySettingRuleAllowUsers(_) => "userPrivacySettingRuleAllowUsers",
+            TdObject::UserPrivacySettingRuleRestrictAll(_) => "userPrivacySettingRuleRestrictAll",
+            TdObject::UserPrivacySettingRuleRestrictChatMembers(_) => {
+                "userPrivacySettingRuleRestrictChatMembers"
+            }
+            TdObject::UserPrivacySettingRuleRestrictContacts(_) => {
+                "userPrivacySettingRuleRestrictContacts"
+            }
+            TdObject::UserPrivacySettingRuleRestrictUsers(_) => {
+                "userPrivacySettingRuleRestrictUsers"
+            }
+            TdObject::UserPrivacySettingRules(_) => "userPrivacySettingRules",
+            TdObject::UserPrivacySettingShowLinkInForwardedMessages(_) => {
+                "userPrivacySettingShowLinkInForwardedMessages"
+            }
+            TdObject::UserPrivacySettingShowPhoneNumber(_) => "userPrivacySettingShowPhoneNumber",
+            TdObject::UserPrivacySettingShowProfilePhoto(_) => "userPrivacySettingShowProfilePhoto",
+            TdObject::UserPrivacySettingShowStatus(_) => "userPrivacySettingShowStatus",
+            TdObject::UserStatusEmpty(_) => "userStatusEmpty",
+            TdObject::UserStatusLastMonth(_) => "userStatusLastMonth",
+            TdObject::UserStatusLastWeek(_) => "userStatusLastWeek",
+            TdObject::UserStatusOffline(_) => "userStatusOffline",
+            TdObject::UserStatusOnline(_) => "userStatusOnline",
+            TdObject::UserStatusRecently(_) => "userStatusRecently",
+            TdObject::UserTypeBot(_) => "userTypeBot",
+            TdObject::UserTypeDeleted(_) => "userTypeDeleted",
+            TdObject::UserTypeRegular(_) => "userTypeRegular",
+            TdObject::UserTypeUnknown(_) => "userTypeUnknown",
+            TdObject::Users(_) => "users",
+            TdObject::ValidatedOrderInfo(_) => "validatedOrderInfo",
+            TdObject::VectorPathCommandCubicBezierCurve(_) => "vectorPathCommandCubicBezierCurve",
+            TdObject::VectorPathCommandLine(_) => "vectorPathCommandLine",
+            TdObject::Venue(_) => "venue",
+            TdObject::Video(_) => "video",
+            TdObject::VideoChat(_) => "videoChat",
+            TdObject::VideoNote(_) => "videoNote",
+            TdObject::VoiceNote(_) => "voiceNote",
+            TdObject::WebPage(_) => "webPage",
+            TdObject::WebPageInstantView(_) => "webPageInstantView",
+            TdObject::Unknown { type_name, .. } => type_name,
+        }
+    }
+}
+
+impl AsRef<TdObject> for TdObject {
+    fn as_ref(&self) -> &TdObject {
+        self
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TdObject {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "accountTtl" => TdObject::AccountTtl(super::_common::from_raw(raw)?),
+            "address" => TdObject::Address(super::_common::from_raw(raw)?),
+            "animatedChatPhoto" => TdObject::AnimatedChatPhoto(super::_common::from_raw(raw)?),
+            "animatedEmoji" => TdObject::AnimatedEmoji(super::_common::from_raw(raw)?),
+            "animation" => TdObject::Animation(super::_common::from_raw(raw)?),
+            "animations" => TdObject::Animations(super::_common::from_raw(raw)?),
+            "audio" => TdObject::Audio(super::_common::from_raw(raw)?),
+            "authenticationCodeInfo" => {
+                TdObject::AuthenticationCodeInfo(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeCall" => {
+                TdObject::AuthenticationCodeTypeCall(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeFlashCall" => {
+                TdObject::AuthenticationCodeTypeFlashCall(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeMissedCall" => {
+                TdObject::AuthenticationCodeTypeMissedCall(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeSms" => {
+                TdObject::AuthenticationCodeTypeSms(super::_common::from_raw(raw)?)
+            }
+            "authenticationCodeTypeTelegramMessage" => {
+                TdObject::AuthenticationCodeTypeTelegramMessage(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateClosed" => {
+                TdObject::AuthorizationStateClosed(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateClosing" => {
+                TdObject::AuthorizationStateClosing(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateLoggingOut" => {
+                TdObject::AuthorizationStateLoggingOut(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateReady" => {
+                TdObject::AuthorizationStateReady(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitCode" => {
+                TdObject::AuthorizationStateWaitCode(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitEncryptionKey" => {
+                TdObject::AuthorizationStateWaitEncryptionKey(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitOtherDeviceConfirmation" => {
+                TdObject::AuthorizationStateWaitOtherDeviceConfirmation(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "authorizationStateWaitPassword" => {
+                TdObject::AuthorizationStateWaitPassword(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitPhoneNumber" => {
+                TdObject::AuthorizationStateWaitPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitRegistration" => {
+                TdObject::AuthorizationStateWaitRegistration(super::_common::from_raw(raw)?)
+            }
+            "authorizationStateWaitTdlibParameters" => {
+                TdObject::AuthorizationStateWaitTdlibParameters(super::_common::from_raw(raw)?)
+            }
+            "autoDownloadSettings" => {
+                TdObject::AutoDownloadSettings(super::_common::from_raw(raw)?)
+            }
+            "autoDownloadSettingsPresets" => {
+                TdObject::AutoDownloadSettingsPresets(super::_common::from_raw(raw)?)
+            }
+            "background" => TdObject::Background(super::_common::from_raw(raw)?),
+            "backgroundFillFreeformGradient" => {
+                TdObject::BackgroundFillFreeformGradient(super::_common::from_raw(raw)?)
+            }
+            "backgroundFillGradient" => {
+                TdObject::BackgroundFillGradient(super::_common::from_raw(raw)?)
+            }
+            "backgroundFillSolid" => TdObject::BackgroundFillSolid(super::_common::from_raw(raw)?),
+            "backgroundTypeFill" => TdObject::BackgroundTypeFill(super::_common::from_raw(raw)?),
+            "backgroundTypePattern" => {
+                TdObject::BackgroundTypePattern(super::_common::from_raw(raw)?)
+            }
+            "backgroundTypeWallpaper" => {
+                TdObject::BackgroundTypeWallpaper(super::_common::from_raw(raw)?)
+            }
+            "backgrounds" => TdObject::Backgrounds(super::_common::from_raw(raw)?),
+            "bankCardActionOpenUrl" => {
+                TdObject::BankCardActionOpenUrl(super::_common::from_raw(raw)?)
+            }
+            "bankCardInfo" => TdObject::BankCardInfo(super::_common::from_raw(raw)?),
+            "basicGroup" => TdObject::BasicGroup(super::_common::from_raw(raw)?),
+            "basicGroupFullInfo" => TdObject::BasicGroupFullInfo(super::_common::from_raw(raw)?),
+            "botCommand" => TdObject::BotCommand(super::_common::from_raw(raw)?),
+            "botCommandScopeAllChatAdministrators" => {
+                TdObject::BotCommandScopeAllChatAdministrators(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeAllGroupChats" => {
+                TdObject::BotCommandScopeAllGroupChats(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeAllPrivateChats" => {
+                TdObject::BotCommandScopeAllPrivateChats(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeChat" => TdObject::BotCommandScopeChat(super::_common::from_raw(raw)?),
+            "botCommandScopeChatAdministrators" => {
+                TdObject::BotCommandScopeChatAdministrators(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeChatMember" => {
+                TdObject::BotCommandScopeChatMember(super::_common::from_raw(raw)?)
+            }
+            "botCommandScopeDefault" => {
+                TdObject::BotCommandScopeDefault(super::_common::from_raw(raw)?)
+            }
+            "botCommands" => TdObject::BotCommands(super::_common::from_raw(raw)?),
+            "call" => TdObject::Call(super::_common::from_raw(raw)?),
+            "callDiscardReasonDeclined" => {
+                TdObject::CallDiscardReasonDeclined(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonDisconnected" => {
+                TdObject::CallDiscardReasonDisconnected(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonEmpty" => {
+                TdObject::CallDiscardReasonEmpty(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonHungUp" => {
+                TdObject::CallDiscardReasonHungUp(super::_common::from_raw(raw)?)
+            }
+            "callDiscardReasonMissed" => {
+                TdObject::CallDiscardReasonMissed(super::_common::from_raw(raw)?)
+            }
+            "callId" => TdObject::CallId(super::_common::from_raw(raw)?),
+            "callProblemDistortedSpeech" => {
+                TdObject::CallProblemDistortedSpeech(super::_common::from_raw(raw)?)
+            }
+            "callProblemDistortedVideo" => {
+                TdObject::CallProblemDistortedVideo(super::_common::from_raw(raw)?)
+            }
+            "callProblemDropped" => TdObject::CallProblemDropped(super::_common::from_raw(raw)?),
+            "callProblemEcho" => TdObject::CallProblemEcho(super::_common::from_raw(raw)?),
+            "callProblemInterruptions" => {
+                TdObject::CallProblemInterruptions(super::_common::from_raw(raw)?)
+            }
+            "callProblemNoise" => TdObject::CallProblemNoise(super::_common::from_raw(raw)?),
+            "callProblemPixelatedVideo" => {
+                TdObject::CallProblemPixelatedVideo(super::_common::from_raw(raw)?)
+            }
+            "callProblemSilentLocal" => {
+                TdObject::CallProblemSilentLocal(super::_common::from_raw(raw)?)
+            }
+            "callProblemSilentRemote" => {
+                TdObject::CallProblemSilentRemote(super::_common::from_raw(raw)?)
+            }
+            "callProtocol" => TdObject::CallProtocol(super::_common::from_raw(raw)?),
+            "callServer" => TdObject::CallServer(super::_common::from_raw(raw)?),
+            "callServerTypeTelegramReflector" => {
+                TdObject::CallServerTypeTelegramReflector(super::_common::from_raw(raw)?)
+            }
+            "callServerTypeWebrtc" => {
+                TdObject::CallServerTypeWebrtc(super::_common::from_raw(raw)?)
+            }
+            "callStateDiscarded" => TdObject::CallStateDiscarded(super::_common::from_raw(raw)?),
+            "callStateError" => TdObject::CallStateError(super::_common::from_raw(raw)?),
+            "callStateExchangingKeys" => {
+                TdObject::CallStateExchangingKeys(super::_common::from_raw(raw)?)
+            }
+            "callStateHangingUp" => TdObject::CallStateHangingUp(super::_common::from_raw(raw)?),
+            "callStatePending" => TdObject::CallStatePending(super::_common::from_raw(raw)?),
+            "callStateReady" => TdObject::CallStateReady(super::_common::from_raw(raw)?),
+            "callbackQueryAnswer" => TdObject::CallbackQueryAnswer(super::_common::from_raw(raw)?),
+            "callbackQueryPayloadData" => {
+                TdObject::CallbackQueryPayloadData(super::_common::from_raw(raw)?)
+            }
+            "callbackQueryPayloadDataWithPassword" => {
+                TdObject::CallbackQueryPayloadDataWithPassword(super::_common::from_raw(raw)?)
+            }
+            "callbackQueryPayloadGame" => {
+                TdObject::CallbackQueryPayloadGame(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultOk" => {
+                TdObject::CanTransferOwnershipResultOk(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultPasswordNeeded" => {
+                TdObject::CanTransferOwnershipResultPasswordNeeded(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultPasswordTooFresh" => {
+                TdObject::CanTransferOwnershipResultPasswordTooFresh(super::_common::from_raw(raw)?)
+            }
+            "canTransferOwnershipResultSessionTooFresh" => {
+                TdObject::CanTransferOwnershipResultSessionTooFresh(super::_common::from_raw(raw)?)
+            }
+            "chat" => TdObject::Chat(super::_common::from_raw(raw)?),
+            "chatActionBarAddContact" => {
+                TdObject::ChatActionBarAddContact(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarInviteMembers" => {
+                TdObject::ChatActionBarInviteMembers(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarJoinRequest" => {
+                TdObject::ChatActionBarJoinRequest(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarReportAddBlock" => {
+                TdObject::ChatActionBarReportAddBlock(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarReportSpam" => {
+                TdObject::ChatActionBarReportSpam(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarReportUnrelatedLocation" => {
+                TdObject::ChatActionBarReportUnrelatedLocation(super::_common::from_raw(raw)?)
+            }
+            "chatActionBarSharePhoneNumber" => {
+                TdObject::ChatActionBarSharePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "chatActionCancel" => TdObject::ChatActionCancel(super::_common::from_raw(raw)?),
+            "chatActionChoosingContact" => {
+                TdObject::ChatActionChoosingContact(super::_common::from_raw(raw)?)
+            }
+            "chatActionChoosingLocation" => {
+                TdObject::ChatActionChoosingLocation(super::_common::from_raw(raw)?)
+            }
+            "chatActionChoosingSticker" => {
+                TdObject::ChatActionChoosingSticker(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVideo" => {
+                TdObject::ChatActionRecordingVideo(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVideoNote" => {
+                TdObject::ChatActionRecordingVideoNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionRecordingVoiceNote" => {
+                TdObject::ChatActionRecordingVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionStartPlayingGame" => {
+                TdObject::ChatActionStartPlayingGame(super::_common::from_raw(raw)?)
+            }
+            "chatActionTyping" => TdObject::ChatActionTyping(super::_common::from_raw(raw)?),
+            "chatActionUploadingDocument" => {
+                TdObject::ChatActionUploadingDocument(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingPhoto" => {
+                TdObject::ChatActionUploadingPhoto(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVideo" => {
+                TdObject::ChatActionUploadingVideo(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVideoNote" => {
+                TdObject::ChatActionUploadingVideoNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionUploadingVoiceNote" => {
+                TdObject::ChatActionUploadingVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "chatActionWatchingAnimations" => {
+                TdObject::ChatActionWatchingAnimations(super::_common::from_raw(raw)?)
+            }
+            "chatAdministrator" => TdObject::ChatAdministrator(super::_common::from_raw(raw)?),
+            "chatAdministrators" => TdObject::ChatAdministrators(super::_common::from_raw(raw)?),
+            "chatEvent" => TdObject::ChatEvent(super::_common::from_raw(raw)?),
+            "chatEventDescriptionChanged" => {
+                TdObject::ChatEventDescriptionChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventHasProtectedContentToggled" => {
+                TdObject::ChatEventHasProtectedContentToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkDeleted" => {
+                TdObject::ChatEventInviteLinkDeleted(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkEdited" => {
+                TdObject::ChatEventInviteLinkEdited(super::_common::from_raw(raw)?)
+            }
+            "chatEventInviteLinkRevoked" => {
+                TdObject::ChatEventInviteLinkRevoked(super::_common::from_raw(raw)?)
+            }
+            "chatEventInvitesToggled" => {
+                TdObject::ChatEventInvitesToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventIsAllHistoryAvailableToggled" => {
+                TdObject::ChatEventIsAllHistoryAvailableToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventLinkedChatChanged" => {
+                TdObject::ChatEventLinkedChatChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventLocationChanged" => {
+                TdObject::ChatEventLocationChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventLogFilters" => TdObject::ChatEventLogFilters(super::_common::from_raw(raw)?),
+            "chatEventMemberInvited" => {
+                TdObject::ChatEventMemberInvited(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoined" => {
+                TdObject::ChatEventMemberJoined(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoinedByInviteLink" => {
+                TdObject::ChatEventMemberJoinedByInviteLink(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberJoinedByRequest" => {
+                TdObject::ChatEventMemberJoinedByRequest(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberLeft" => TdObject::ChatEventMemberLeft(super::_common::from_raw(raw)?),
+            "chatEventMemberPromoted" => {
+                TdObject::ChatEventMemberPromoted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMemberRestricted" => {
+                TdObject::ChatEventMemberRestricted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageDeleted" => {
+                TdObject::ChatEventMessageDeleted(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageEdited" => {
+                TdObject::ChatEventMessageEdited(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessagePinned" => {
+                TdObject::ChatEventMessagePinned(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageTtlChanged" => {
+                TdObject::ChatEventMessageTtlChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventMessageUnpinned" => {
+                TdObject::ChatEventMessageUnpinned(super::_common::from_raw(raw)?)
+            }
+            "chatEventPermissionsChanged" => {
+                TdObject::ChatEventPermissionsChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventPhotoChanged" => {
+                TdObject::ChatEventPhotoChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventPollStopped" => {
+                TdObject::ChatEventPollStopped(super::_common::from_raw(raw)?)
+            }
+            "chatEventSignMessagesToggled" => {
+                TdObject::ChatEventSignMessagesToggled(super::_common::from_raw(raw)?)
+            }
+            "chatEventSlowModeDelayChanged" => {
+                TdObject::ChatEventSlowModeDelayChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventStickerSetChanged" => {
+                TdObject::ChatEventStickerSetChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventTitleChanged" => {
+                TdObject::ChatEventTitleChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventUsernameChanged" => {
+                TdObject::ChatEventUsernameChanged(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatCreated" => {
+                TdObject::ChatEventVideoChatCreated(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatEnded" => {
+                TdObject::ChatEventVideoChatEnded(super::_common::from_raw(raw)?)
+            }
+            "chatEventVideoChatMuteNewParticipantsToggled" => {
+                TdObject::ChatEventVideoChatMuteNewParticipantsToggled(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "chatEventVideoChatParticipantIsMutedToggled" => {
+                TdObject::ChatEventVideoChatParticipantIsMutedToggled(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "chatEventVideoChatParticipantVolumeLevelChanged" => {
+                TdObject::ChatEventVideoChatParticipantVolumeLevelChanged(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "chatEvents" => TdObject::ChatEvents(super::_common::from_raw(raw)?),
+            "chatFilter" => TdObject::ChatFilter(super::_common::from_raw(raw)?),
+            "chatFilterInfo" => TdObject::ChatFilterInfo(super::_common::from_raw(raw)?),
+            "chatInviteLink" => TdObject::ChatInviteLink(super::_common::from_raw(raw)?),
+            "chatInviteLinkCount" => TdObject::ChatInviteLinkCount(super::_common::from_raw(raw)?),
+            "chatInviteLinkCounts" => {
+                TdObject::ChatInviteLinkCounts(super::_common::from_raw(raw)?)
+            }
+            "chatInviteLinkInfo" => TdObject::ChatInviteLinkInfo(super::_common::from_raw(raw)?),
+            "chatInviteLinkMember" => {
+                TdObject::ChatInviteLinkMember(super::_common::from_raw(raw)?)
+            }
+            "chatInviteLinkMembers" => {
+                TdObject::ChatInviteLinkMembers(super::_common::from_raw(raw)?)
+            }
+            "chatInviteLinks" => TdObject::ChatInviteLinks(super::_common::from_raw(raw)?),
+            "chatJoinRequest" => TdObject::ChatJoinRequest(super::_common::from_raw(raw)?),
+            "chatJoinRequests" => TdObject::ChatJoinRequests(super::_common::from_raw(raw)?),
+            "chatJoinRequestsInfo" => {
+                TdObject::ChatJoinRequestsInfo(super::_common::from_raw(raw)?)
+            }
+            "chatListArchive" => TdObject::ChatListArchive(super::_common::from_raw(raw)?),
+            "chatListFilter" => TdObject::ChatListFilter(super::_common::from_raw(raw)?),
+            "chatListMain" => TdObject::ChatListMain(super::_common::from_raw(raw)?),
+            "chatLists" => TdObject::ChatLists(super::_common::from_raw(raw)?),
+            "chatLocation" => TdObject::ChatLocation(super::_common::from_raw(raw)?),
+            "chatMember" => TdObject::ChatMember(super::_common::from_raw(raw)?),
+            "chatMemberStatusAdministrator" => {
+                TdObject::ChatMemberStatusAdministrator(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusBanned" => {
+                TdObject::ChatMemberStatusBanned(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusCreator" => {
+                TdObject::ChatMemberStatusCreator(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusLeft" => {
+                TdObject::ChatMemberStatusLeft(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusMember" => {
+                TdObject::ChatMemberStatusMember(super::_common::from_raw(raw)?)
+            }
+            "chatMemberStatusRestricted" => {
+                TdObject::ChatMemberStatusRestricted(super::_common::from_raw(raw)?)
+            }
+            "chatMembers" => TdObject::ChatMembers(super::_common::from_raw(raw)?),
+            "chatMembersFilterAdministrators" => {
+                TdObject::ChatMembersFilterAdministrators(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterBanned" => {
+                TdObject::ChatMembersFilterBanned(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterBots" => {
+                TdObject::ChatMembersFilterBots(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterContacts" => {
+                TdObject::ChatMembersFilterContacts(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterMembers" => {
+                TdObject::ChatMembersFilterMembers(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterMention" => {
+                TdObject::ChatMembersFilterMention(super::_common::from_raw(raw)?)
+            }
+            "chatMembersFilterRestricted" => {
+                TdObject::ChatMembersFilterRestricted(super::_common::from_raw(raw)?)
+            }
+            "chatNearby" => TdObject::ChatNearby(super::_common::from_raw(raw)?),
+            "chatNotificationSettings" => {
+                TdObject::ChatNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "chatPermissions" => TdObject::ChatPermissions(super::_common::from_raw(raw)?),
+            "chatPhoto" => TdObject::ChatPhoto(super::_common::from_raw(raw)?),
+            "chatPhotoInfo" => TdObject::ChatPhotoInfo(super::_common::from_raw(raw)?),
+            "chatPhotos" => TdObject::ChatPhotos(super::_common::from_raw(raw)?),
+            "chatPosition" => TdObject::ChatPosition(super::_common::from_raw(raw)?),
+            "chatReportReasonChildAbuse" => {
+                TdObject::ChatReportReasonChildAbuse(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonCopyright" => {
+                TdObject::ChatReportReasonCopyright(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonCustom" => {
+                TdObject::ChatReportReasonCustom(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonFake" => {
+                TdObject::ChatReportReasonFake(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonPornography" => {
+                TdObject::ChatReportReasonPornography(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonSpam" => {
+                TdObject::ChatReportReasonSpam(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonUnrelatedLocation" => {
+                TdObject::ChatReportReasonUnrelatedLocation(super::_common::from_raw(raw)?)
+            }
+            "chatReportReasonViolence" => {
+                TdObject::ChatReportReasonViolence(super::_common::from_raw(raw)?)
+            }
+            "chatSourceMtprotoProxy" => {
+                TdObject::ChatSourceMtprotoProxy(super::_common::from_raw(raw)?)
+            }
+            "chatSourcePublicServiceAnnouncement" => {
+                TdObject::ChatSourcePublicServiceAnnouncement(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsAdministratorActionsInfo" => {
+                TdObject::ChatStatisticsAdministratorActionsInfo(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsChannel" => {
+                TdObject::ChatStatisticsChannel(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsInviterInfo" => {
+                TdObject::ChatStatisticsInviterInfo(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsMessageInteractionInfo" => {
+                TdObject::ChatStatisticsMessageInteractionInfo(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsMessageSenderInfo" => {
+                TdObject::ChatStatisticsMessageSenderInfo(super::_common::from_raw(raw)?)
+            }
+            "chatStatisticsSupergroup" => {
+                TdObject::ChatStatisticsSupergroup(super::_common::from_raw(raw)?)
+            }
+            "chatTheme" => TdObject::ChatTheme(super::_common::from_raw(raw)?),
+            "chatTypeBasicGroup" => TdObject::ChatTypeBasicGroup(super::_common::from_raw(raw)?),
+            "chatTypePrivate" => TdObject::ChatTypePrivate(super::_common::from_raw(raw)?),
+            "chatTypeSecret" => TdObject::ChatTypeSecret(super::_common::from_raw(raw)?),
+            "chatTypeSupergroup" => TdObject::ChatTypeSupergroup(super::_common::from_raw(raw)?),
+            "chats" => TdObject::Chats(super::_common::from_raw(raw)?),
+            "chatsNearby" => TdObject::ChatsNearby(super::_common::from_raw(raw)?),
+            "checkChatUsernameResultOk" => {
+                TdObject::CheckChatUsernameResultOk(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultPublicChatsTooMuch" => {
+                TdObject::CheckChatUsernameResultPublicChatsTooMuch(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultPublicGroupsUnavailable" => {
+                TdObject::CheckChatUsernameResultPublicGroupsUnavailable(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "checkChatUsernameResultUsernameInvalid" => {
+                TdObject::CheckChatUsernameResultUsernameInvalid(super::_common::from_raw(raw)?)
+            }
+            "checkChatUsernameResultUsernameOccupied" => {
+                TdObject::CheckChatUsernameResultUsernameOccupied(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultNameInvalid" => {
+                TdObject::CheckStickerSetNameResultNameInvalid(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultNameOccupied" => {
+                TdObject::CheckStickerSetNameResultNameOccupied(super::_common::from_raw(raw)?)
+            }
+            "checkStickerSetNameResultOk" => {
+                TdObject::CheckStickerSetNameResultOk(super::_common::from_raw(raw)?)
+            }
+            "closedVectorPath" => TdObject::ClosedVectorPath(super::_common::from_raw(raw)?),
+            "connectedWebsite" => TdObject::ConnectedWebsite(super::_common::from_raw(raw)?),
+            "connectedWebsites" => TdObject::ConnectedWebsites(super::_common::from_raw(raw)?),
+            "connectionStateConnecting" => {
+                TdObject::ConnectionStateConnecting(super::_common::from_raw(raw)?)
+            }
+            "connectionStateConnectingToProxy" => {
+                TdObject::ConnectionStateConnectingToProxy(super::_common::from_raw(raw)?)
+            }
+            "connectionStateReady" => {
+                TdObject::ConnectionStateReady(super::_common::from_raw(raw)?)
+            }
+            "connectionStateUpdating" => {
+                TdObject::ConnectionStateUpdating(super::_common::from_raw(raw)?)
+            }
+            "connectionStateWaitingForNetwork" => {
+                TdObject::ConnectionStateWaitingForNetwork(super::_common::from_raw(raw)?)
+            }
+            "contact" => TdObject::Contact(super::_common::from_raw(raw)?),
+            "count" => TdObject::Count(super::_common::from_raw(raw)?),
+            "countries" => TdObject::Countries(super::_common::from_raw(raw)?),
+            "countryInfo" => TdObject::CountryInfo(super::_common::from_raw(raw)?),
+            "customRequestResult" => TdObject::CustomRequestResult(super::_common::from_raw(raw)?),
+            "databaseStatistics" => TdObject::DatabaseStatistics(super::_common::from_raw(raw)?),
+            "date" => TdObject::Date(super::_common::from_raw(raw)?),
+            "dateRange" => TdObject::DateRange(super::_common::from_raw(raw)?),
+            "datedFile" => TdObject::DatedFile(super::_common::from_raw(raw)?),
+            "deepLinkInfo" => TdObject::DeepLinkInfo(super::_common::from_raw(raw)?),
+            "deviceTokenApplePush" => {
+                TdObject::DeviceTokenApplePush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenApplePushVoIP" => {
+                TdObject::DeviceTokenApplePushVoIP(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenBlackBerryPush" => {
+                TdObject::DeviceTokenBlackBerryPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenFirebaseCloudMessaging" => {
+                TdObject::DeviceTokenFirebaseCloudMessaging(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenMicrosoftPush" => {
+                TdObject::DeviceTokenMicrosoftPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenMicrosoftPushVoIP" => {
+                TdObject::DeviceTokenMicrosoftPushVoIP(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenSimplePush" => {
+                TdObject::DeviceTokenSimplePush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenTizenPush" => {
+                TdObject::DeviceTokenTizenPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenUbuntuPush" => {
+                TdObject::DeviceTokenUbuntuPush(super::_common::from_raw(raw)?)
+            }
+            "deviceTokenWebPush" => TdObject::DeviceTokenWebPush(super::_common::from_raw(raw)?),
+            "deviceTokenWindowsPush" => {
+                TdObject::DeviceTokenWindowsPush(super::_common::from_raw(raw)?)
+            }
+            "diceStickersRegular" => TdObject::DiceStickersRegular(super::_common::from_raw(raw)?),
+            "diceStickersSlotMachine" => {
+                TdObject::DiceStickersSlotMachine(super::_common::from_raw(raw)?)
+            }
+            "document" => TdObject::Document(super::_common::from_raw(raw)?),
+            "draftMessage" => TdObject::DraftMessage(super::_common::from_raw(raw)?),
+            "emailAddressAuthenticationCodeInfo" => {
+                TdObject::EmailAddressAuthenticationCodeInfo(super::_common::from_raw(raw)?)
+            }
+            "emojis" => TdObject::Emojis(super::_common::from_raw(raw)?),
+            "encryptedCredentials" => {
+                TdObject::EncryptedCredentials(super::_common::from_raw(raw)?)
+            }
+            "encryptedPassportElement" => {
+                TdObject::EncryptedPassportElement(super::_common::from_raw(raw)?)
+            }
+            "error" => TdObject::Error(super::_common::from_raw(raw)?),
+            "file" => TdObject::File(super::_common::from_raw(raw)?),
+            "filePart" => TdObject::FilePart(super::_common::from_raw(raw)?),
+            "fileTypeAnimation" => TdObject::FileTypeAnimation(super::_common::from_raw(raw)?),
+            "fileTypeAudio" => TdObject::FileTypeAudio(super::_common::from_raw(raw)?),
+            "fileTypeDocument" => TdObject::FileTypeDocument(super::_common::from_raw(raw)?),
+            "fileTypeNone" => TdObject::FileTypeNone(super::_common::from_raw(raw)?),
+            "fileTypePhoto" => TdObject::FileTypePhoto(super::_common::from_raw(raw)?),
+            "fileTypeProfilePhoto" => {
+                TdObject::FileTypeProfilePhoto(super::_common::from_raw(raw)?)
+            }
+            "fileTypeSecret" => TdObject::FileTypeSecret(super::_common::from_raw(raw)?),
+            "fileTypeSecretThumbnail" => {
+                TdObject::FileTypeSecretThumbnail(super::_common::from_raw(raw)?)
+            }
+            "fileTypeSecure" => TdObject::FileTypeSecure(super::_common::from_raw(raw)?),
+            "fileTypeSticker" => TdObject::FileTypeSticker(super::_common::from_raw(raw)?),
+            "fileTypeThumbnail" => TdObject::FileTypeThumbnail(super::_common::from_raw(raw)?),
+            "fileTypeUnknown" => TdObject::FileTypeUnknown(super::_common::from_raw(raw)?),
+            "fileTypeVideo" => TdObject::FileTypeVideo(super::_common::from_raw(raw)?),
+            "fileTypeVideoNote" => TdObject::FileTypeVideoNote(super::_common::from_raw(raw)?),
+            "fileTypeVoiceNote" => TdObject::FileTypeVoiceNote(super::_common::from_raw(raw)?),
+            "fileTypeWallpaper" => TdObject::FileTypeWallpaper(super::_common::from_raw(raw)?),
+            "formattedText" => TdObject::FormattedText(super::_common::from_raw(raw)?),
+            "foundMessages" => TdObject::FoundMessages(super::_common::from_raw(raw)?),
+            "game" => TdObject::Game(super::_common::from_raw(raw)?),
+            "gameHighScore" => TdObject::GameHighScore(super::_common::from_raw(raw)?),
+            "gameHighScores" => TdObject::GameHighScores(super::_common::from_raw(raw)?),
+            "groupCall" => TdObject::GroupCall(super::_common::from_raw(raw)?),
+            "groupCallId" => TdObject::GroupCallId(super::_common::from_raw(raw)?),
+            "groupCallParticipant" => {
+                TdObject::GroupCallParticipant(super::_common::from_raw(raw)?)
+            }
+            "groupCallParticipantVideoInfo" => {
+                TdObject::GroupCallParticipantVideoInfo(super::_common::from_raw(raw)?)
+            }
+            "groupCallRecentSpeaker" => {
+                TdObject::GroupCallRecentSpeaker(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoQualityFull" => {
+                TdObject::GroupCallVideoQualityFull(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoQualityMedium" => {
+                TdObject::GroupCallVideoQualityMedium(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoQualityThumbnail" => {
+                TdObject::GroupCallVideoQualityThumbnail(super::_common::from_raw(raw)?)
+            }
+            "groupCallVideoSourceGroup" => {
+                TdObject::GroupCallVideoSourceGroup(super::_common::from_raw(raw)?)
+            }
+            "hashtags" => TdObject::Hashtags(super::_common::from_raw(raw)?),
+            "httpUrl" => TdObject::HttpUrl(super::_common::from_raw(raw)?),
+            "identityDocument" => TdObject::IdentityDocument(super::_common::from_raw(raw)?),
+            "importedContacts" => TdObject::ImportedContacts(super::_common::from_raw(raw)?),
+            "inlineKeyboardButton" => {
+                TdObject::InlineKeyboardButton(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeBuy" => {
+                TdObject::InlineKeyboardButtonTypeBuy(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallback" => {
+                TdObject::InlineKeyboardButtonTypeCallback(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallbackGame" => {
+                TdObject::InlineKeyboardButtonTypeCallbackGame(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeCallbackWithPassword" => {
+                TdObject::InlineKeyboardButtonTypeCallbackWithPassword(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "inlineKeyboardButtonTypeLoginUrl" => {
+                TdObject::InlineKeyboardButtonTypeLoginUrl(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeSwitchInline" => {
+                TdObject::InlineKeyboardButtonTypeSwitchInline(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeUrl" => {
+                TdObject::InlineKeyboardButtonTypeUrl(super::_common::from_raw(raw)?)
+            }
+            "inlineKeyboardButtonTypeUser" => {
+                TdObject::InlineKeyboardButtonTypeUser(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultAnimation" => {
+                TdObject::InlineQueryResultAnimation(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultArticle" => {
+                TdObject::InlineQueryResultArticle(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultAudio" => {
+                TdObject::InlineQueryResultAudio(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultContact" => {
+                TdObject::InlineQueryResultContact(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultDocument" => {
+                TdObject::InlineQueryResultDocument(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultGame" => {
+                TdObject::InlineQueryResultGame(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultLocation" => {
+                TdObject::InlineQueryResultLocation(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultPhoto" => {
+                TdObject::InlineQueryResultPhoto(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultSticker" => {
+                TdObject::InlineQueryResultSticker(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultVenue" => {
+                TdObject::InlineQueryResultVenue(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultVideo" => {
+                TdObject::InlineQueryResultVideo(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResultVoiceNote" => {
+                TdObject::InlineQueryResultVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "inlineQueryResults" => TdObject::InlineQueryResults(super::_common::from_raw(raw)?),
+            "inputBackgroundLocal" => {
+                TdObject::InputBackgroundLocal(super::_common::from_raw(raw)?)
+            }
+            "inputBackgroundRemote" => {
+                TdObject::InputBackgroundRemote(super::_common::from_raw(raw)?)
+            }
+            "inputChatPhotoAnimation" => {
+                TdObject::InputChatPhotoAnimation(super::_common::from_raw(raw)?)
+            }
+            "inputChatPhotoPrevious" => {
+                TdObject::InputChatPhotoPrevious(super::_common::from_raw(raw)?)
+            }
+            "inputChatPhotoStatic" => {
+                TdObject::InputChatPhotoStatic(super::_common::from_raw(raw)?)
+            }
+            "inputCredentialsApplePay" => {
+                TdObject::InputCredentialsApplePay(super::_common::from_raw(raw)?)
+            }
+            "inputCredentialsGooglePay" => {
+                TdObject::InputCredentialsGooglePay(super::_common::from_raw(raw)?)
+            }
+            "inputCredentialsNew" => TdObject::InputCredentialsNew(super::_common::from_raw(raw)?),
+            "inputCredentialsSaved" => {
+                TdObject::InputCredentialsSaved(super::_common::from_raw(raw)?)
+            }
+            "inputFileGenerated" => TdObject::InputFileGenerated(super::_common::from_raw(raw)?),
+            "inputFileId" => TdObject::InputFileId(super::_common::from_raw(raw)?),
+            "inputFileLocal" => TdObject::InputFileLocal(super::_common::from_raw(raw)?),
+            "inputFileRemote" => TdObject::InputFileRemote(super::_common::from_raw(raw)?),
+            "inputIdentityDocument" => {
+                TdObject::InputIdentityDocument(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultAnimation" => {
+                TdObject::InputInlineQueryResultAnimation(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultArticle" => {
+                TdObject::InputInlineQueryResultArticle(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultAudio" => {
+                TdObject::InputInlineQueryResultAudio(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultContact" => {
+                TdObject::InputInlineQueryResultContact(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultDocument" => {
+                TdObject::InputInlineQueryResultDocument(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultGame" => {
+                TdObject::InputInlineQueryResultGame(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultLocation" => {
+                TdObject::InputInlineQueryResultLocation(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultPhoto" => {
+                TdObject::InputInlineQueryResultPhoto(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultSticker" => {
+                TdObject::InputInlineQueryResultSticker(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVenue" => {
+                TdObject::InputInlineQueryResultVenue(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVideo" => {
+                TdObject::InputInlineQueryResultVideo(super::_common::from_raw(raw)?)
+            }
+            "inputInlineQueryResultVoiceNote" => {
+                TdObject::InputInlineQueryResultVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "inputMessageAnimation" => {
+                TdObject::InputMessageAnimation(super::_common::from_raw(raw)?)
+            }
+            "inputMessageAudio" => TdObject::InputMessageAudio(super::_common::from_raw(raw)?),
+            "inputMessageContact" => TdObject::InputMessageContact(super::_common::from_raw(raw)?),
+            "inputMessageDice" => TdObject::InputMessageDice(super::_common::from_raw(raw)?),
+            "inputMessageDocument" => {
+                TdObject::InputMessageDocument(super::_common::from_raw(raw)?)
+            }
+            "inputMessageForwarded" => {
+                TdObject::InputMessageForwarded(super::_common::from_raw(raw)?)
+            }
+            "inputMessageGame" => TdObject::InputMessageGame(super::_common::from_raw(raw)?),
+            "inputMessageInvoice" => TdObject::InputMessageInvoice(super::_common::from_raw(raw)?),
+            "inputMessageLocation" => {
+                TdObject::InputMessageLocation(super::_common::from_raw(raw)?)
+            }
+            "inputMessagePhoto" => TdObject::InputMessagePhoto(super::_common::from_raw(raw)?),
+            "inputMessagePoll" => TdObject::InputMessagePoll(super::_common::from_raw(raw)?),
+            "inputMessageSticker" => TdObject::InputMessageSticker(super::_common::from_raw(raw)?),
+            "inputMessageText" => TdObject::InputMessageText(super::_common::from_raw(raw)?),
+            "inputMessageVenue" => TdObject::InputMessageVenue(super::_common::from_raw(raw)?),
+            "inputMessageVideo" => TdObject::InputMessageVideo(super::_common::from_raw(raw)?),
+            "inputMessageVideoNote" => {
+                TdObject::InputMessageVideoNote(super::_common::from_raw(raw)?)
+            }
+            "inputMessageVoiceNote" => {
+                TdObject::InputMessageVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementAddress" => {
+                TdObject::InputPassportElementAddress(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementBankStatement" => {
+                TdObject::InputPassportElementBankStatement(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementDriverLicense" => {
+                TdObject::InputPassportElementDriverLicense(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementEmailAddress" => {
+                TdObject::InputPassportElementEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementError" => {
+                TdObject::InputPassportElementError(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceDataField" => {
+                TdObject::InputPassportElementErrorSourceDataField(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFile" => {
+                TdObject::InputPassportElementErrorSourceFile(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFiles" => {
+                TdObject::InputPassportElementErrorSourceFiles(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceFrontSide" => {
+                TdObject::InputPassportElementErrorSourceFrontSide(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceReverseSide" => {
+                TdObject::InputPassportElementErrorSourceReverseSide(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceSelfie" => {
+                TdObject::InputPassportElementErrorSourceSelfie(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementErrorSourceTranslationFile" => {
+                TdObject::InputPassportElementErrorSourceTranslationFile(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "inputPassportElementErrorSourceTranslationFiles" => {
+                TdObject::InputPassportElementErrorSourceTranslationFiles(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "inputPassportElementErrorSourceUnspecified" => {
+                TdObject::InputPassportElementErrorSourceUnspecified(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementIdentityCard" => {
+                TdObject::InputPassportElementIdentityCard(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementInternalPassport" => {
+                TdObject::InputPassportElementInternalPassport(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPassport" => {
+                TdObject::InputPassportElementPassport(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPassportRegistration" => {
+                TdObject::InputPassportElementPassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPersonalDetails" => {
+                TdObject::InputPassportElementPersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementPhoneNumber" => {
+                TdObject::InputPassportElementPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementRentalAgreement" => {
+                TdObject::InputPassportElementRentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementTemporaryRegistration" => {
+                TdObject::InputPassportElementTemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "inputPassportElementUtilityBill" => {
+                TdObject::InputPassportElementUtilityBill(super::_common::from_raw(raw)?)
+            }
+            "inputPersonalDocument" => {
+                TdObject::InputPersonalDocument(super::_common::from_raw(raw)?)
+            }
+            "inputStickerAnimated" => {
+                TdObject::InputStickerAnimated(super::_common::from_raw(raw)?)
+            }
+            "inputStickerStatic" => TdObject::InputStickerStatic(super::_common::from_raw(raw)?),
+            "inputThumbnail" => TdObject::InputThumbnail(super::_common::from_raw(raw)?),
+            "internalLinkTypeActiveSessions" => {
+                TdObject::InternalLinkTypeActiveSessions(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeAuthenticationCode" => {
+                TdObject::InternalLinkTypeAuthenticationCode(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBackground" => {
+                TdObject::InternalLinkTypeBackground(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBotStart" => {
+                TdObject::InternalLinkTypeBotStart(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeBotStartInGroup" => {
+                TdObject::InternalLinkTypeBotStartInGroup(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeChangePhoneNumber" => {
+                TdObject::InternalLinkTypeChangePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeChatInvite" => {
+                TdObject::InternalLinkTypeChatInvite(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeFilterSettings" => {
+                TdObject::InternalLinkTypeFilterSettings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeGame" => {
+                TdObject::InternalLinkTypeGame(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeLanguagePack" => {
+                TdObject::InternalLinkTypeLanguagePack(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeMessage" => {
+                TdObject::InternalLinkTypeMessage(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeMessageDraft" => {
+                TdObject::InternalLinkTypeMessageDraft(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypePassportDataRequest" => {
+                TdObject::InternalLinkTypePassportDataRequest(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypePhoneNumberConfirmation" => {
+                TdObject::InternalLinkTypePhoneNumberConfirmation(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeProxy" => {
+                TdObject::InternalLinkTypeProxy(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypePublicChat" => {
+                TdObject::InternalLinkTypePublicChat(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeQrCodeAuthentication" => {
+                TdObject::InternalLinkTypeQrCodeAuthentication(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeSettings" => {
+                TdObject::InternalLinkTypeSettings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeStickerSet" => {
+                TdObject::InternalLinkTypeStickerSet(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeTheme" => {
+                TdObject::InternalLinkTypeTheme(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeThemeSettings" => {
+                TdObject::InternalLinkTypeThemeSettings(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeUnknownDeepLink" => {
+                TdObject::InternalLinkTypeUnknownDeepLink(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeUnsupportedProxy" => {
+                TdObject::InternalLinkTypeUnsupportedProxy(super::_common::from_raw(raw)?)
+            }
+            "internalLinkTypeVideoChat" => {
+                TdObject::InternalLinkTypeVideoChat(super::_common::from_raw(raw)?)
+            }
+            "invoice" => TdObject::Invoice(super::_common::from_raw(raw)?),
+            "jsonObjectMember" => TdObject::JsonObjectMember(super::_common::from_raw(raw)?),
+            "jsonValueArray" => TdObject::JsonValueArray(super::_common::from_raw(raw)?),
+            "jsonValueBoolean" => TdObject::JsonValueBoolean(super::_common::from_raw(raw)?),
+            "jsonValueNull" => TdObject::JsonValueNull(super::_common::from_raw(raw)?),
+            "jsonValueNumber" => TdObject::JsonValueNumber(super::_common::from_raw(raw)?),
+            "jsonValueObject" => TdObject::JsonValueObject(super::_common::from_raw(raw)?),
+            "jsonValueString" => TdObject::JsonValueString(super::_common::from_raw(raw)?),
+            "keyboardButton" => TdObject::KeyboardButton(super::_common::from_raw(raw)?),
+            "keyboardButtonTypeRequestLocation" => {
+                TdObject::KeyboardButtonTypeRequestLocation(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeRequestPhoneNumber" => {
+                TdObject::KeyboardButtonTypeRequestPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeRequestPoll" => {
+                TdObject::KeyboardButtonTypeRequestPoll(super::_common::from_raw(raw)?)
+            }
+            "keyboardButtonTypeText" => {
+                TdObject::KeyboardButtonTypeText(super::_common::from_raw(raw)?)
+            }
+            "labeledPricePart" => TdObject::LabeledPricePart(super::_common::from_raw(raw)?),
+            "languagePackInfo" => TdObject::LanguagePackInfo(super::_common::from_raw(raw)?),
+            "languagePackString" => TdObject::LanguagePackString(super::_common::from_raw(raw)?),
+            "languagePackStringValueDeleted" => {
+                TdObject::LanguagePackStringValueDeleted(super::_common::from_raw(raw)?)
+            }
+            "languagePackStringValueOrdinary" => {
+                TdObject::LanguagePackStringValueOrdinary(super::_common::from_raw(raw)?)
+            }
+            "languagePackStringValuePluralized" => {
+                TdObject::LanguagePackStringValuePluralized(super::_common::from_raw(raw)?)
+            }
+            "languagePackStrings" => TdObject::LanguagePackStrings(super::_common::from_raw(raw)?),
+            "localFile" => TdObject::LocalFile(super::_common::from_raw(raw)?),
+            "localizationTargetInfo" => {
+                TdObject::LocalizationTargetInfo(super::_common::from_raw(raw)?)
+            }
+            "location" => TdObject::Location(super::_common::from_raw(raw)?),
+            "logStreamDefault" => TdObject::LogStreamDefault(super::_common::from_raw(raw)?),
+            "logStreamEmpty" => TdObject::LogStreamEmpty(super::_common::from_raw(raw)?),
+            "logStreamFile" => TdObject::LogStreamFile(super::_common::from_raw(raw)?),
+            "logTags" => TdObject::LogTags(super::_common::from_raw(raw)?),
+            "logVerbosityLevel" => TdObject::LogVerbosityLevel(super::_common::from_raw(raw)?),
+            "loginUrlInfoOpen" => TdObject::LoginUrlInfoOpen(super::_common::from_raw(raw)?),
+            "loginUrlInfoRequestConfirmation" => {
+                TdObject::LoginUrlInfoRequestConfirmation(super::_common::from_raw(raw)?)
+            }
+            "maskPointChin" => TdObject::MaskPointChin(super::_common::from_raw(raw)?),
+            "maskPointEyes" => TdObject::MaskPointEyes(super::_common::from_raw(raw)?),
+            "maskPointForehead" => TdObject::MaskPointForehead(super::_common::from_raw(raw)?),
+            "maskPointMouth" => TdObject::MaskPointMouth(super::_common::from_raw(raw)?),
+            "maskPosition" => TdObject::MaskPosition(super::_common::from_raw(raw)?),
+            "message" => TdObject::Message(super::_common::from_raw(raw)?),
+            "messageAnimatedEmoji" => {
+                TdObject::MessageAnimatedEmoji(super::_common::from_raw(raw)?)
+            }
+            "messageAnimation" => TdObject::MessageAnimation(super::_common::from_raw(raw)?),
+            "messageAudio" => TdObject::MessageAudio(super::_common::from_raw(raw)?),
+            "messageBasicGroupChatCreate" => {
+                TdObject::MessageBasicGroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "messageCalendar" => TdObject::MessageCalendar(super::_common::from_raw(raw)?),
+            "messageCalendarDay" => TdObject::MessageCalendarDay(super::_common::from_raw(raw)?),
+            "messageCall" => TdObject::MessageCall(super::_common::from_raw(raw)?),
+            "messageChatAddMembers" => {
+                TdObject::MessageChatAddMembers(super::_common::from_raw(raw)?)
+            }
+            "messageChatChangePhoto" => {
+                TdObject::MessageChatChangePhoto(super::_common::from_raw(raw)?)
+            }
+            "messageChatChangeTitle" => {
+                TdObject::MessageChatChangeTitle(super::_common::from_raw(raw)?)
+            }
+            "messageChatDeleteMember" => {
+                TdObject::MessageChatDeleteMember(super::_common::from_raw(raw)?)
+            }
+            "messageChatDeletePhoto" => {
+                TdObject::MessageChatDeletePhoto(super::_common::from_raw(raw)?)
+            }
+            "messageChatJoinByLink" => {
+                TdObject::MessageChatJoinByLink(super::_common::from_raw(raw)?)
+            }
+            "messageChatJoinByRequest" => {
+                TdObject::MessageChatJoinByRequest(super::_common::from_raw(raw)?)
+            }
+            "messageChatSetTheme" => TdObject::MessageChatSetTheme(super::_common::from_raw(raw)?),
+            "messageChatSetTtl" => TdObject::MessageChatSetTtl(super::_common::from_raw(raw)?),
+            "messageChatUpgradeFrom" => {
+                TdObject::MessageChatUpgradeFrom(super::_common::from_raw(raw)?)
+            }
+            "messageChatUpgradeTo" => {
+                TdObject::MessageChatUpgradeTo(super::_common::from_raw(raw)?)
+            }
+            "messageContact" => TdObject::MessageContact(super::_common::from_raw(raw)?),
+            "messageContactRegistered" => {
+                TdObject::MessageContactRegistered(super::_common::from_raw(raw)?)
+            }
+            "messageCopyOptions" => TdObject::MessageCopyOptions(super::_common::from_raw(raw)?),
+            "messageCustomServiceAction" => {
+                TdObject::MessageCustomServiceAction(super::_common::from_raw(raw)?)
+            }
+            "messageDice" => TdObject::MessageDice(super::_common::from_raw(raw)?),
+            "messageDocument" => TdObject::MessageDocument(super::_common::from_raw(raw)?),
+            "messageExpiredPhoto" => TdObject::MessageExpiredPhoto(super::_common::from_raw(raw)?),
+            "messageExpiredVideo" => TdObject::MessageExpiredVideo(super::_common::from_raw(raw)?),
+            "messageFileTypeGroup" => {
+                TdObject::MessageFileTypeGroup(super::_common::from_raw(raw)?)
+            }
+            "messageFileTypePrivate" => {
+                TdObject::MessageFileTypePrivate(super::_common::from_raw(raw)?)
+            }
+            "messageFileTypeUnknown" => {
+                TdObject::MessageFileTypeUnknown(super::_common::from_raw(raw)?)
+            }
+            "messageForwardInfo" => TdObject::MessageForwardInfo(super::_common::from_raw(raw)?),
+            "messageForwardOriginChannel" => {
+                TdObject::MessageForwardOriginChannel(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginChat" => {
+                TdObject::MessageForwardOriginChat(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginHiddenUser" => {
+                TdObject::MessageForwardOriginHiddenUser(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginMessageImport" => {
+                TdObject::MessageForwardOriginMessageImport(super::_common::from_raw(raw)?)
+            }
+            "messageForwardOriginUser" => {
+                TdObject::MessageForwardOriginUser(super::_common::from_raw(raw)?)
+            }
+            "messageGame" => TdObject::MessageGame(super::_common::from_raw(raw)?),
+            "messageGameScore" => TdObject::MessageGameScore(super::_common::from_raw(raw)?),
+            "messageInteractionInfo" => {
+                TdObject::MessageInteractionInfo(super::_common::from_raw(raw)?)
+            }
+            "messageInviteVideoChatParticipants" => {
+                TdObject::MessageInviteVideoChatParticipants(super::_common::from_raw(raw)?)
+            }
+            "messageInvoice" => TdObject::MessageInvoice(super::_common::from_raw(raw)?),
+            "messageLink" => TdObject::MessageLink(super::_common::from_raw(raw)?),
+            "messageLinkInfo" => TdObject::MessageLinkInfo(super::_common::from_raw(raw)?),
+            "messageLocation" => TdObject::MessageLocation(super::_common::from_raw(raw)?),
+            "messagePassportDataReceived" => {
+                TdObject::MessagePassportDataReceived(super::_common::from_raw(raw)?)
+            }
+            "messagePassportDataSent" => {
+                TdObject::MessagePassportDataSent(super::_common::from_raw(raw)?)
+            }
+            "messagePaymentSuccessful" => {
+                TdObject::MessagePaymentSuccessful(super::_common::from_raw(raw)?)
+            }
+            "messagePaymentSuccessfulBot" => {
+                TdObject::MessagePaymentSuccessfulBot(super::_common::from_raw(raw)?)
+            }
+            "messagePhoto" => TdObject::MessagePhoto(super::_common::from_raw(raw)?),
+            "messagePinMessage" => TdObject::MessagePinMessage(super::_common::from_raw(raw)?),
+            "messagePoll" => TdObject::MessagePoll(super::_common::from_raw(raw)?),
+            "messagePosition" => TdObject::MessagePosition(super::_common::from_raw(raw)?),
+            "messagePositions" => TdObject::MessagePositions(super::_common::from_raw(raw)?),
+            "messageProximityAlertTriggered" => {
+                TdObject::MessageProximityAlertTriggered(super::_common::from_raw(raw)?)
+            }
+            "messageReplyInfo" => TdObject::MessageReplyInfo(super::_common::from_raw(raw)?),
+            "messageSchedulingStateSendAtDate" => {
+                TdObject::MessageSchedulingStateSendAtDate(super::_common::from_raw(raw)?)
+            }
+            "messageSchedulingStateSendWhenOnline" => {
+                TdObject::MessageSchedulingStateSendWhenOnline(super::_common::from_raw(raw)?)
+            }
+            "messageScreenshotTaken" => {
+                TdObject::MessageScreenshotTaken(super::_common::from_raw(raw)?)
+            }
+            "messageSendOptions" => TdObject::MessageSendOptions(super::_common::from_raw(raw)?),
+            "messageSenderChat" => TdObject::MessageSenderChat(super::_common::from_raw(raw)?),
+            "messageSenderUser" => TdObject::MessageSenderUser(super::_common::from_raw(raw)?),
+            "messageSenders" => TdObject::MessageSenders(super::_common::from_raw(raw)?),
+            "messageSendingStateFailed" => {
+                TdObject::MessageSendingStateFailed(super::_common::from_raw(raw)?)
+            }
+            "messageSendingStatePending" => {
+                TdObject::MessageSendingStatePending(super::_common::from_raw(raw)?)
+            }
+            "messageStatistics" => TdObject::MessageStatistics(super::_common::from_raw(raw)?),
+            "messageSticker" => TdObject::MessageSticker(super::_common::from_raw(raw)?),
+            "messageSupergroupChatCreate" => {
+                TdObject::MessageSupergroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "messageText" => TdObject::MessageText(super::_common::from_raw(raw)?),
+            "messageThreadInfo" => TdObject::MessageThreadInfo(super::_common::from_raw(raw)?),
+            "messageUnsupported" => TdObject::MessageUnsupported(super::_common::from_raw(raw)?),
+            "messageVenue" => TdObject::MessageVenue(super::_common::from_raw(raw)?),
+            "messageVideo" => TdObject::MessageVideo(super::_common::from_raw(raw)?),
+            "messageVideoChatEnded" => {
+                TdObject::MessageVideoChatEnded(super::_common::from_raw(raw)?)
+            }
+            "messageVideoChatScheduled" => {
+                TdObject::MessageVideoChatScheduled(super::_common::from_raw(raw)?)
+            }
+            "messageVideoChatStarted" => {
+                TdObject::MessageVideoChatStarted(super::_common::from_raw(raw)?)
+            }
+            "messageVideoNote" => TdObject::MessageVideoNote(super::_common::from_raw(raw)?),
+            "messageVoiceNote" => TdObject::MessageVoiceNote(super::_common::from_raw(raw)?),
+            "messageWebsiteConnected" => {
+                TdObject::MessageWebsiteConnected(super::_common::from_raw(raw)?)
+            }
+            "messages" => TdObject::Messages(super::_common::from_raw(raw)?),
+            "minithumbnail" => TdObject::Minithumbnail(super::_common::from_raw(raw)?),
+            "networkStatistics" => TdObject::NetworkStatistics(super::_common::from_raw(raw)?),
+            "networkStatisticsEntryCall" => {
+                TdObject::NetworkStatisticsEntryCall(super::_common::from_raw(raw)?)
+            }
+            "networkStatisticsEntryFile" => {
+                TdObject::NetworkStatisticsEntryFile(super::_common::from_raw(raw)?)
+            }
+            "networkTypeMobile" => TdObject::NetworkTypeMobile(super::_common::from_raw(raw)?),
+            "networkTypeMobileRoaming" => {
+                TdObject::NetworkTypeMobileRoaming(super::_common::from_raw(raw)?)
+            }
+            "networkTypeNone" => TdObject::NetworkTypeNone(super::_common::from_raw(raw)?),
+            "networkTypeOther" => TdObject::NetworkTypeOther(super::_common::from_raw(raw)?),
+            "networkTypeWiFi" => TdObject::NetworkTypeWiFi(super::_common::from_raw(raw)?),
+            "notification" => TdObject::Notification(super::_common::from_raw(raw)?),
+            "notificationGroup" => TdObject::NotificationGroup(super::_common::from_raw(raw)?),
+            "notificationGroupTypeCalls" => {
+                TdObject::NotificationGroupTypeCalls(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeMentions" => {
+                TdObject::NotificationGroupTypeMentions(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeMessages" => {
+                TdObject::NotificationGroupTypeMessages(super::_common::from_raw(raw)?)
+            }
+            "notificationGroupTypeSecretChat" => {
+                TdObject::NotificationGroupTypeSecretChat(super::_common::from_raw(raw)?)
+            }
+            "notificationSettingsScopeChannelChats" => {
+                TdObject::NotificationSettingsScopeChannelChats(super::_common::from_raw(raw)?)
+            }
+            "notificationSettingsScopeGroupChats" => {
+                TdObject::NotificationSettingsScopeGroupChats(super::_common::from_raw(raw)?)
+            }
+            "notificationSettingsScopePrivateChats" => {
+                TdObject::NotificationSettingsScopePrivateChats(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewCall" => {
+                TdObject::NotificationTypeNewCall(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewMessage" => {
+                TdObject::NotificationTypeNewMessage(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewPushMessage" => {
+                TdObject::NotificationTypeNewPushMessage(super::_common::from_raw(raw)?)
+            }
+            "notificationTypeNewSecretChat" => {
+                TdObject::NotificationTypeNewSecretChat(super::_common::from_raw(raw)?)
+            }
+            "ok" => TdObject::Ok(super::_common::from_raw(raw)?),
+            "optionValueBoolean" => TdObject::OptionValueBoolean(super::_common::from_raw(raw)?),
+            "optionValueEmpty" => TdObject::OptionValueEmpty(super::_common::from_raw(raw)?),
+            "optionValueInteger" => TdObject::OptionValueInteger(super::_common::from_raw(raw)?),
+            "optionValueString" => TdObject::OptionValueString(super::_common::from_raw(raw)?),
+            "orderInfo" => TdObject::OrderInfo(super::_common::from_raw(raw)?),
+            "pageBlockAnchor" => TdObject::PageBlockAnchor(super::_common::from_raw(raw)?),
+            "pageBlockAnimation" => TdObject::PageBlockAnimation(super::_common::from_raw(raw)?),
+            "pageBlockAudio" => TdObject::PageBlockAudio(super::_common::from_raw(raw)?),
+            "pageBlockAuthorDate" => TdObject::PageBlockAuthorDate(super::_common::from_raw(raw)?),
+            "pageBlockBlockQuote" => TdObject::PageBlockBlockQuote(super::_common::from_raw(raw)?),
+            "pageBlockCaption" => TdObject::PageBlockCaption(super::_common::from_raw(raw)?),
+            "pageBlockChatLink" => TdObject::PageBlockChatLink(super::_common::from_raw(raw)?),
+            "pageBlockCollage" => TdObject::PageBlockCollage(super::_common::from_raw(raw)?),
+            "pageBlockCover" => TdObject::PageBlockCover(super::_common::from_raw(raw)?),
+            "pageBlockDetails" => TdObject::PageBlockDetails(super::_common::from_raw(raw)?),
+            "pageBlockDivider" => TdObject::PageBlockDivider(super::_common::from_raw(raw)?),
+            "pageBlockEmbedded" => TdObject::PageBlockEmbedded(super::_common::from_raw(raw)?),
+            "pageBlockEmbeddedPost" => {
+                TdObject::PageBlockEmbeddedPost(super::_common::from_raw(raw)?)
+            }
+            "pageBlockFooter" => TdObject::PageBlockFooter(super::_common::from_raw(raw)?),
+            "pageBlockHeader" => TdObject::PageBlockHeader(super::_common::from_raw(raw)?),
+            "pageBlockHorizontalAlignmentCenter" => {
+                TdObject::PageBlockHorizontalAlignmentCenter(super::_common::from_raw(raw)?)
+            }
+            "pageBlockHorizontalAlignmentLeft" => {
+                TdObject::PageBlockHorizontalAlignmentLeft(super::_common::from_raw(raw)?)
+            }
+            "pageBlockHorizontalAlignmentRight" => {
+                TdObject::PageBlockHorizontalAlignmentRight(super::_common::from_raw(raw)?)
+            }
+            "pageBlockKicker" => TdObject::PageBlockKicker(super::_common::from_raw(raw)?),
+            "pageBlockList" => TdObject::PageBlockList(super::_common::from_raw(raw)?),
+            "pageBlockListItem" => TdObject::PageBlockListItem(super::_common::from_raw(raw)?),
+            "pageBlockMap" => TdObject::PageBlockMap(super::_common::from_raw(raw)?),
+            "pageBlockParagraph" => TdObject::PageBlockParagraph(super::_common::from_raw(raw)?),
+            "pageBlockPhoto" => TdObject::PageBlockPhoto(super::_common::from_raw(raw)?),
+            "pageBlockPreformatted" => {
+                TdObject::PageBlockPreformatted(super::_common::from_raw(raw)?)
+            }
+            "pageBlockPullQuote" => TdObject::PageBlockPullQuote(super::_common::from_raw(raw)?),
+            "pageBlockRelatedArticle" => {
+                TdObject::PageBlockRelatedArticle(super::_common::from_raw(raw)?)
+            }
+            "pageBlockRelatedArticles" => {
+                TdObject::PageBlockRelatedArticles(super::_common::from_raw(raw)?)
+            }
+            "pageBlockSlideshow" => TdObject::PageBlockSlideshow(super::_common::from_raw(raw)?),
+            "pageBlockSubheader" => TdObject::PageBlockSubheader(super::_common::from_raw(raw)?),
+            "pageBlockSubtitle" => TdObject::PageBlockSubtitle(super::_common::from_raw(raw)?),
+            "pageBlockTable" => TdObject::PageBlockTable(super::_common::from_raw(raw)?),
+            "pageBlockTableCell" => TdObject::PageBlockTableCell(super::_common::from_raw(raw)?),
+            "pageBlockTitle" => TdObject::PageBlockTitle(super::_common::from_raw(raw)?),
+            "pageBlockVerticalAlignmentBottom" => {
+                TdObject::PageBlockVerticalAlignmentBottom(super::_common::from_raw(raw)?)
+            }
+            "pageBlockVerticalAlignmentMiddle" => {
+                TdObject::PageBlockVerticalAlignmentMiddle(super::_common::from_raw(raw)?)
+            }
+            "pageBlockVerticalAlignmentTop" => {
+                TdObject::PageBlockVerticalAlignmentTop(super::_common::from_raw(raw)?)
+            }
+            "pageBlockVideo" => TdObject::PageBlockVideo(super::_common::from_raw(raw)?),
+            "pageBlockVoiceNote" => TdObject::PageBlockVoiceNote(super::_common::from_raw(raw)?),
+            "passportAuthorizationForm" => {
+                TdObject::PassportAuthorizationForm(super::_common::from_raw(raw)?)
+            }
+            "passportElementAddress" => {
+                TdObject::PassportElementAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementBankStatement" => {
+                TdObject::PassportElementBankStatement(super::_common::from_raw(raw)?)
+            }
+            "passportElementDriverLicense" => {
+                TdObject::PassportElementDriverLicense(super::_common::from_raw(raw)?)
+            }
+            "passportElementEmailAddress" => {
+                TdObject::PassportElementEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementError" => {
+                TdObject::PassportElementError(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceDataField" => {
+                TdObject::PassportElementErrorSourceDataField(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFile" => {
+                TdObject::PassportElementErrorSourceFile(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFiles" => {
+                TdObject::PassportElementErrorSourceFiles(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceFrontSide" => {
+                TdObject::PassportElementErrorSourceFrontSide(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceReverseSide" => {
+                TdObject::PassportElementErrorSourceReverseSide(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceSelfie" => {
+                TdObject::PassportElementErrorSourceSelfie(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceTranslationFile" => {
+                TdObject::PassportElementErrorSourceTranslationFile(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceTranslationFiles" => {
+                TdObject::PassportElementErrorSourceTranslationFiles(super::_common::from_raw(raw)?)
+            }
+            "passportElementErrorSourceUnspecified" => {
+                TdObject::PassportElementErrorSourceUnspecified(super::_common::from_raw(raw)?)
+            }
+            "passportElementIdentityCard" => {
+                TdObject::PassportElementIdentityCard(super::_common::from_raw(raw)?)
+            }
+            "passportElementInternalPassport" => {
+                TdObject::PassportElementInternalPassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementPassport" => {
+                TdObject::PassportElementPassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementPassportRegistration" => {
+                TdObject::PassportElementPassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementPersonalDetails" => {
+                TdObject::PassportElementPersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "passportElementPhoneNumber" => {
+                TdObject::PassportElementPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "passportElementRentalAgreement" => {
+                TdObject::PassportElementRentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTemporaryRegistration" => {
+                TdObject::PassportElementTemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeAddress" => {
+                TdObject::PassportElementTypeAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeBankStatement" => {
+                TdObject::PassportElementTypeBankStatement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeDriverLicense" => {
+                TdObject::PassportElementTypeDriverLicense(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeEmailAddress" => {
+                TdObject::PassportElementTypeEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeIdentityCard" => {
+                TdObject::PassportElementTypeIdentityCard(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeInternalPassport" => {
+                TdObject::PassportElementTypeInternalPassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePassport" => {
+                TdObject::PassportElementTypePassport(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePassportRegistration" => {
+                TdObject::PassportElementTypePassportRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePersonalDetails" => {
+                TdObject::PassportElementTypePersonalDetails(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypePhoneNumber" => {
+                TdObject::PassportElementTypePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeRentalAgreement" => {
+                TdObject::PassportElementTypeRentalAgreement(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeTemporaryRegistration" => {
+                TdObject::PassportElementTypeTemporaryRegistration(super::_common::from_raw(raw)?)
+            }
+            "passportElementTypeUtilityBill" => {
+                TdObject::PassportElementTypeUtilityBill(super::_common::from_raw(raw)?)
+            }
+            "passportElementUtilityBill" => {
+                TdObject::PassportElementUtilityBill(super::_common::from_raw(raw)?)
+            }
+            "passportElements" => TdObject::PassportElements(super::_common::from_raw(raw)?),
+            "passportElementsWithErrors" => {
+                TdObject::PassportElementsWithErrors(super::_common::from_raw(raw)?)
+            }
+            "passportRequiredElement" => {
+                TdObject::PassportRequiredElement(super::_common::from_raw(raw)?)
+            }
+            "passportSuitableElement" => {
+                TdObject::PassportSuitableElement(super::_common::from_raw(raw)?)
+            }
+            "passwordState" => TdObject::PasswordState(super::_common::from_raw(raw)?),
+            "paymentForm" => TdObject::PaymentForm(super::_common::from_raw(raw)?),
+            "paymentFormTheme" => TdObject::PaymentFormTheme(super::_common::from_raw(raw)?),
+            "paymentReceipt" => TdObject::PaymentReceipt(super::_common::from_raw(raw)?),
+            "paymentResult" => TdObject::PaymentResult(super::_common::from_raw(raw)?),
+            "paymentsProviderStripe" => {
+                TdObject::PaymentsProviderStripe(super::_common::from_raw(raw)?)
+            }
+            "personalDetails" => TdObject::PersonalDetails(super::_common::from_raw(raw)?),
+            "personalDocument" => TdObject::PersonalDocument(super::_common::from_raw(raw)?),
+            "phoneNumberAuthenticationSettings" => {
+                TdObject::PhoneNumberAuthenticationSettings(super::_common::from_raw(raw)?)
+            }
+            "phoneNumberInfo" => TdObject::PhoneNumberInfo(super::_common::from_raw(raw)?),
+            "photo" => TdObject::Photo(super::_common::from_raw(raw)?),
+            "photoSize" => TdObject::PhotoSize(super::_common::from_raw(raw)?),
+            "point" => TdObject::Point(super::_common::from_raw(raw)?),
+            "poll" => TdObject::Poll(super::_common::from_raw(raw)?),
+            "pollOption" => TdObject::PollOption(super::_common::from_raw(raw)?),
+            "pollTypeQuiz" => TdObject::PollTypeQuiz(super::_common::from_raw(raw)?),
+            "pollTypeRegular" => TdObject::PollTypeRegular(super::_common::from_raw(raw)?),
+            "profilePhoto" => TdObject::ProfilePhoto(super::_common::from_raw(raw)?),
+            "proxies" => TdObject::Proxies(super::_common::from_raw(raw)?),
+            "proxy" => TdObject::Proxy(super::_common::from_raw(raw)?),
+            "proxyTypeHttp" => TdObject::ProxyTypeHttp(super::_common::from_raw(raw)?),
+            "proxyTypeMtproto" => TdObject::ProxyTypeMtproto(super::_common::from_raw(raw)?),
+            "proxyTypeSocks5" => TdObject::ProxyTypeSocks5(super::_common::from_raw(raw)?),
+            "publicChatTypeHasUsername" => {
+                TdObject::PublicChatTypeHasUsername(super::_common::from_raw(raw)?)
+            }
+            "publicChatTypeIsLocationBased" => {
+                TdObject::PublicChatTypeIsLocationBased(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentAnimation" => {
+                TdObject::PushMessageContentAnimation(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentAudio" => {
+                TdObject::PushMessageContentAudio(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentBasicGroupChatCreate" => {
+                TdObject::PushMessageContentBasicGroupChatCreate(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatAddMembers" => {
+                TdObject::PushMessageContentChatAddMembers(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatChangePhoto" => {
+                TdObject::PushMessageContentChatChangePhoto(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatChangeTitle" => {
+                TdObject::PushMessageContentChatChangeTitle(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatDeleteMember" => {
+                TdObject::PushMessageContentChatDeleteMember(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatJoinByLink" => {
+                TdObject::PushMessageContentChatJoinByLink(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatJoinByRequest" => {
+                TdObject::PushMessageContentChatJoinByRequest(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentChatSetTheme" => {
+                TdObject::PushMessageContentChatSetTheme(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentContact" => {
+                TdObject::PushMessageContentContact(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentContactRegistered" => {
+                TdObject::PushMessageContentContactRegistered(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentDocument" => {
+                TdObject::PushMessageContentDocument(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentGame" => {
+                TdObject::PushMessageContentGame(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentGameScore" => {
+                TdObject::PushMessageContentGameScore(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentHidden" => {
+                TdObject::PushMessageContentHidden(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentInvoice" => {
+                TdObject::PushMessageContentInvoice(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentLocation" => {
+                TdObject::PushMessageContentLocation(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentMediaAlbum" => {
+                TdObject::PushMessageContentMediaAlbum(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentMessageForwards" => {
+                TdObject::PushMessageContentMessageForwards(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentPhoto" => {
+                TdObject::PushMessageContentPhoto(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentPoll" => {
+                TdObject::PushMessageContentPoll(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentScreenshotTaken" => {
+                TdObject::PushMessageContentScreenshotTaken(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentSticker" => {
+                TdObject::PushMessageContentSticker(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentText" => {
+                TdObject::PushMessageContentText(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentVideo" => {
+                TdObject::PushMessageContentVideo(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentVideoNote" => {
+                TdObject::PushMessageContentVideoNote(super::_common::from_raw(raw)?)
+            }
+            "pushMessageContentVoiceNote" => {
+                TdObject::PushMessageContentVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "pushReceiverId" => TdObject::PushReceiverId(super::_common::from_raw(raw)?),
+            "recommendedChatFilter" => {
+                TdObject::RecommendedChatFilter(super::_common::from_raw(raw)?)
+            }
+            "recommendedChatFilters" => {
+                TdObject::RecommendedChatFilters(super::_common::from_raw(raw)?)
+            }
+            "recoveryEmailAddress" => {
+                TdObject::RecoveryEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "remoteFile" => TdObject::RemoteFile(super::_common::from_raw(raw)?),
+            "replyMarkupForceReply" => {
+                TdObject::ReplyMarkupForceReply(super::_common::from_raw(raw)?)
+            }
+            "replyMarkupInlineKeyboard" => {
+                TdObject::ReplyMarkupInlineKeyboard(super::_common::from_raw(raw)?)
+            }
+            "replyMarkupRemoveKeyboard" => {
+                TdObject::ReplyMarkupRemoveKeyboard(super::_common::from_raw(raw)?)
+            }
+            "replyMarkupShowKeyboard" => {
+                TdObject::ReplyMarkupShowKeyboard(super::_common::from_raw(raw)?)
+            }
+            "resetPasswordResultDeclined" => {
+                TdObject::ResetPasswordResultDeclined(super::_common::from_raw(raw)?)
+            }
+            "resetPasswordResultOk" => {
+                TdObject::ResetPasswordResultOk(super::_common::from_raw(raw)?)
+            }
+            "resetPasswordResultPending" => {
+                TdObject::ResetPasswordResultPending(super::_common::from_raw(raw)?)
+            }
+            "richTextAnchor" => TdObject::RichTextAnchor(super::_common::from_raw(raw)?),
+            "richTextAnchorLink" => TdObject::RichTextAnchorLink(super::_common::from_raw(raw)?),
+            "richTextBold" => TdObject::RichTextBold(super::_common::from_raw(raw)?),
+            "richTextEmailAddress" => {
+                TdObject::RichTextEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "richTextFixed" => TdObject::RichTextFixed(super::_common::from_raw(raw)?),
+            "richTextIcon" => TdObject::RichTextIcon(super::_common::from_raw(raw)?),
+            "richTextItalic" => TdObject::RichTextItalic(super::_common::from_raw(raw)?),
+            "richTextMarked" => TdObject::RichTextMarked(super::_common::from_raw(raw)?),
+            "richTextPhoneNumber" => TdObject::RichTextPhoneNumber(super::_common::from_raw(raw)?),
+            "richTextPlain" => TdObject::RichTextPlain(super::_common::from_raw(raw)?),
+            "richTextReference" => TdObject::RichTextReference(super::_common::from_raw(raw)?),
+            "richTextStrikethrough" => {
+                TdObject::RichTextStrikethrough(super::_common::from_raw(raw)?)
+            }
+            "richTextSubscript" => TdObject::RichTextSubscript(super::_common::from_raw(raw)?),
+            "richTextSuperscript" => TdObject::RichTextSuperscript(super::_common::from_raw(raw)?),
+            "richTextUnderline" => TdObject::RichTextUnderline(super::_common::from_raw(raw)?),
+            "richTextUrl" => TdObject::RichTextUrl(super::_common::from_raw(raw)?),
+            "richTexts" => TdObject::RichTexts(super::_common::from_raw(raw)?),
+            "savedCredentials" => TdObject::SavedCredentials(super::_common::from_raw(raw)?),
+            "scopeNotificationSettings" => {
+                TdObject::ScopeNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterAnimation" => {
+                TdObject::SearchMessagesFilterAnimation(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterAudio" => {
+                TdObject::SearchMessagesFilterAudio(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterChatPhoto" => {
+                TdObject::SearchMessagesFilterChatPhoto(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterDocument" => {
+                TdObject::SearchMessagesFilterDocument(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterEmpty" => {
+                TdObject::SearchMessagesFilterEmpty(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterFailedToSend" => {
+                TdObject::SearchMessagesFilterFailedToSend(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterMention" => {
+                TdObject::SearchMessagesFilterMention(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPhoto" => {
+                TdObject::SearchMessagesFilterPhoto(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPhotoAndVideo" => {
+                TdObject::SearchMessagesFilterPhotoAndVideo(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterPinned" => {
+                TdObject::SearchMessagesFilterPinned(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterUnreadMention" => {
+                TdObject::SearchMessagesFilterUnreadMention(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterUrl" => {
+                TdObject::SearchMessagesFilterUrl(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVideo" => {
+                TdObject::SearchMessagesFilterVideo(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVideoNote" => {
+                TdObject::SearchMessagesFilterVideoNote(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVoiceAndVideoNote" => {
+                TdObject::SearchMessagesFilterVoiceAndVideoNote(super::_common::from_raw(raw)?)
+            }
+            "searchMessagesFilterVoiceNote" => {
+                TdObject::SearchMessagesFilterVoiceNote(super::_common::from_raw(raw)?)
+            }
+            "seconds" => TdObject::Seconds(super::_common::from_raw(raw)?),
+            "secretChat" => TdObject::SecretChat(super::_common::from_raw(raw)?),
+            "secretChatStateClosed" => {
+                TdObject::SecretChatStateClosed(super::_common::from_raw(raw)?)
+            }
+            "secretChatStatePending" => {
+                TdObject::SecretChatStatePending(super::_common::from_raw(raw)?)
+            }
+            "secretChatStateReady" => {
+                TdObject::SecretChatStateReady(super::_common::from_raw(raw)?)
+            }
+            "session" => TdObject::Session(super::_common::from_raw(raw)?),
+            "sessions" => TdObject::Sessions(super::_common::from_raw(raw)?),
+            "shippingOption" => TdObject::ShippingOption(super::_common::from_raw(raw)?),
+            "sponsoredMessage" => TdObject::SponsoredMessage(super::_common::from_raw(raw)?),
+            "statisticalGraphAsync" => {
+                TdObject::StatisticalGraphAsync(super::_common::from_raw(raw)?)
+            }
+            "statisticalGraphData" => {
+                TdObject::StatisticalGraphData(super::_common::from_raw(raw)?)
+            }
+            "statisticalGraphError" => {
+                TdObject::StatisticalGraphError(super::_common::from_raw(raw)?)
+            }
+            "statisticalValue" => TdObject::StatisticalValue(super::_common::from_raw(raw)?),
+            "sticker" => TdObject::Sticker(super::_common::from_raw(raw)?),
+            "stickerSet" => TdObject::StickerSet(super::_common::from_raw(raw)?),
+            "stickerSetInfo" => TdObject::StickerSetInfo(super::_common::from_raw(raw)?),
+            "stickerSets" => TdObject::StickerSets(super::_common::from_raw(raw)?),
+            "stickers" => TdObject::Stickers(super::_common::from_raw(raw)?),
+            "storageStatistics" => TdObject::StorageStatistics(super::_common::from_raw(raw)?),
+            "storageStatisticsByChat" => {
+                TdObject::StorageStatisticsByChat(super::_common::from_raw(raw)?)
+            }
+            "storageStatisticsByFileType" => {
+                TdObject::StorageStatisticsByFileType(super::_common::from_raw(raw)?)
+            }
+            "storageStatisticsFast" => {
+                TdObject::StorageStatisticsFast(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionCheckPassword" => {
+                TdObject::SuggestedActionCheckPassword(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionCheckPhoneNumber" => {
+                TdObject::SuggestedActionCheckPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionConvertToBroadcastGroup" => {
+                TdObject::SuggestedActionConvertToBroadcastGroup(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionEnableArchiveAndMuteNewChats" => {
+                TdObject::SuggestedActionEnableArchiveAndMuteNewChats(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "suggestedActionSetPassword" => {
+                TdObject::SuggestedActionSetPassword(super::_common::from_raw(raw)?)
+            }
+            "suggestedActionViewChecksHint" => {
+                TdObject::SuggestedActionViewChecksHint(super::_common::from_raw(raw)?)
+            }
+            "supergroup" => TdObject::Supergroup(super::_common::from_raw(raw)?),
+            "supergroupFullInfo" => TdObject::SupergroupFullInfo(super::_common::from_raw(raw)?),
+            "supergroupMembersFilterAdministrators" => {
+                TdObject::SupergroupMembersFilterAdministrators(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterBanned" => {
+                TdObject::SupergroupMembersFilterBanned(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterBots" => {
+                TdObject::SupergroupMembersFilterBots(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterContacts" => {
+                TdObject::SupergroupMembersFilterContacts(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterMention" => {
+                TdObject::SupergroupMembersFilterMention(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterRecent" => {
+                TdObject::SupergroupMembersFilterRecent(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterRestricted" => {
+                TdObject::SupergroupMembersFilterRestricted(super::_common::from_raw(raw)?)
+            }
+            "supergroupMembersFilterSearch" => {
+                TdObject::SupergroupMembersFilterSearch(super::_common::from_raw(raw)?)
+            }
+            "tMeUrl" => TdObject::TMeUrl(super::_common::from_raw(raw)?),
+            "tMeUrlTypeChatInvite" => {
+                TdObject::TMeUrlTypeChatInvite(super::_common::from_raw(raw)?)
+            }
+            "tMeUrlTypeStickerSet" => {
+                TdObject::TMeUrlTypeStickerSet(super::_common::from_raw(raw)?)
+            }
+            "tMeUrlTypeSupergroup" => {
+                TdObject::TMeUrlTypeSupergroup(super::_common::from_raw(raw)?)
+            }
+            "tMeUrlTypeUser" => TdObject::TMeUrlTypeUser(super::_common::from_raw(raw)?),
+            "tMeUrls" => TdObject::TMeUrls(super::_common::from_raw(raw)?),
+            "tdlibParameters" => TdObject::TdlibParameters(super::_common::from_raw(raw)?),
+            "temporaryPasswordState" => {
+                TdObject::TemporaryPasswordState(super::_common::from_raw(raw)?)
+            }
+            "termsOfService" => TdObject::TermsOfService(super::_common::from_raw(raw)?),
+            "testBytes" => TdObject::TestBytes(super::_common::from_raw(raw)?),
+            "testInt" => TdObject::TestInt(super::_common::from_raw(raw)?),
+            "testString" => TdObject::TestString(super::_common::from_raw(raw)?),
+            "testVectorInt" => TdObject::TestVectorInt(super::_common::from_raw(raw)?),
+            "testVectorIntObject" => TdObject::TestVectorIntObject(super::_common::from_raw(raw)?),
+            "testVectorString" => TdObject::TestVectorString(super::_common::from_raw(raw)?),
+            "testVectorStringObject" => {
+                TdObject::TestVectorStringObject(super::_common::from_raw(raw)?)
+            }
+            "text" => TdObject::Text(super::_common::from_raw(raw)?),
+            "textEntities" => TdObject::TextEntities(super::_common::from_raw(raw)?),
+            "textEntity" => TdObject::TextEntity(super::_common::from_raw(raw)?),
+            "textEntityTypeBankCardNumber" => {
+                TdObject::TextEntityTypeBankCardNumber(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeBold" => TdObject::TextEntityTypeBold(super::_common::from_raw(raw)?),
+            "textEntityTypeBotCommand" => {
+                TdObject::TextEntityTypeBotCommand(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeCashtag" => {
+                TdObject::TextEntityTypeCashtag(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeCode" => TdObject::TextEntityTypeCode(super::_common::from_raw(raw)?),
+            "textEntityTypeEmailAddress" => {
+                TdObject::TextEntityTypeEmailAddress(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeHashtag" => {
+                TdObject::TextEntityTypeHashtag(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeItalic" => {
+                TdObject::TextEntityTypeItalic(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeMediaTimestamp" => {
+                TdObject::TextEntityTypeMediaTimestamp(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeMention" => {
+                TdObject::TextEntityTypeMention(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeMentionName" => {
+                TdObject::TextEntityTypeMentionName(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypePhoneNumber" => {
+                TdObject::TextEntityTypePhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypePre" => TdObject::TextEntityTypePre(super::_common::from_raw(raw)?),
+            "textEntityTypePreCode" => {
+                TdObject::TextEntityTypePreCode(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeStrikethrough" => {
+                TdObject::TextEntityTypeStrikethrough(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeTextUrl" => {
+                TdObject::TextEntityTypeTextUrl(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeUnderline" => {
+                TdObject::TextEntityTypeUnderline(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeUrl" => TdObject::TextEntityTypeUrl(super::_common::from_raw(raw)?),
+            "textParseModeHTML" => TdObject::TextParseModeHTML(super::_common::from_raw(raw)?),
+            "textParseModeMarkdown" => {
+                TdObject::TextParseModeMarkdown(super::_common::from_raw(raw)?)
+            }
+            "themeSettings" => TdObject::ThemeSettings(super::_common::from_raw(raw)?),
+            "thumbnail" => TdObject::Thumbnail(super::_common::from_raw(raw)?),
+            "thumbnailFormatGif" => TdObject::ThumbnailFormatGif(super::_common::from_raw(raw)?),
+            "thumbnailFormatJpeg" => TdObject::ThumbnailFormatJpeg(super::_common::from_raw(raw)?),
+            "thumbnailFormatMpeg4" => {
+                TdObject::ThumbnailFormatMpeg4(super::_common::from_raw(raw)?)
+            }
+            "thumbnailFormatPng" => TdObject::ThumbnailFormatPng(super::_common::from_raw(raw)?),
+            "thumbnailFormatTgs" => TdObject::ThumbnailFormatTgs(super::_common::from_raw(raw)?),
+            "thumbnailFormatWebp" => TdObject::ThumbnailFormatWebp(super::_common::from_raw(raw)?),
+            "topChatCategoryBots" => TdObject::TopChatCategoryBots(super::_common::from_raw(raw)?),
+            "topChatCategoryCalls" => {
+                TdObject::TopChatCategoryCalls(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryChannels" => {
+                TdObject::TopChatCategoryChannels(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryForwardChats" => {
+                TdObject::TopChatCategoryForwardChats(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryGroups" => {
+                TdObject::TopChatCategoryGroups(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryInlineBots" => {
+                TdObject::TopChatCategoryInlineBots(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryUsers" => {
+                TdObject::TopChatCategoryUsers(super::_common::from_raw(raw)?)
+            }
+            "updateActiveNotifications" => {
+                TdObject::UpdateActiveNotifications(super::_common::from_raw(raw)?)
+            }
+            "updateAnimatedEmojiMessageClicked" => {
+                TdObject::UpdateAnimatedEmojiMessageClicked(super::_common::from_raw(raw)?)
+            }
+            "updateAnimationSearchParameters" => {
+                TdObject::UpdateAnimationSearchParameters(super::_common::from_raw(raw)?)
+            }
+            "updateAuthorizationState" => {
+                TdObject::UpdateAuthorizationState(super::_common::from_raw(raw)?)
+            }
+            "updateBasicGroup" => TdObject::UpdateBasicGroup(super::_common::from_raw(raw)?),
+            "updateBasicGroupFullInfo" => {
+                TdObject::UpdateBasicGroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "updateCall" => TdObject::UpdateCall(super::_common::from_raw(raw)?),
+            "updateChatAction" => TdObject::UpdateChatAction(super::_common::from_raw(raw)?),
+            "updateChatActionBar" => TdObject::UpdateChatActionBar(super::_common::from_raw(raw)?),
+            "updateChatDefaultDisableNotification" => {
+                TdObject::UpdateChatDefaultDisableNotification(super::_common::from_raw(raw)?)
+            }
+            "updateChatDraftMessage" => {
+                TdObject::UpdateChatDraftMessage(super::_common::from_raw(raw)?)
+            }
+            "updateChatFilters" => TdObject::UpdateChatFilters(super::_common::from_raw(raw)?),
+            "updateChatHasProtectedContent" => {
+                TdObject::UpdateChatHasProtectedContent(super::_common::from_raw(raw)?)
+            }
+            "updateChatHasScheduledMessages" => {
+                TdObject::UpdateChatHasScheduledMessages(super::_common::from_raw(raw)?)
+            }
+            "updateChatIsBlocked" => TdObject::UpdateChatIsBlocked(super::_common::from_raw(raw)?),
+            "updateChatIsMarkedAsUnread" => {
+                TdObject::UpdateChatIsMarkedAsUnread(super::_common::from_raw(raw)?)
+            }
+            "updateChatLastMessage" => {
+                TdObject::UpdateChatLastMessage(super::_common::from_raw(raw)?)
+            }
+            "updateChatMember" => TdObject::UpdateChatMember(super::_common::from_raw(raw)?),
+            "updateChatMessageSender" => {
+                TdObject::UpdateChatMessageSender(super::_common::from_raw(raw)?)
+            }
+            "updateChatMessageTtl" => {
+                TdObject::UpdateChatMessageTtl(super::_common::from_raw(raw)?)
+            }
+            "updateChatNotificationSettings" => {
+                TdObject::UpdateChatNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "updateChatOnlineMemberCount" => {
+                TdObject::UpdateChatOnlineMemberCount(super::_common::from_raw(raw)?)
+            }
+            "updateChatPendingJoinRequests" => {
+                TdObject::UpdateChatPendingJoinRequests(super::_common::from_raw(raw)?)
+            }
+            "updateChatPermissions" => {
+                TdObject::UpdateChatPermissions(super::_common::from_raw(raw)?)
+            }
+            "updateChatPhoto" => TdObject::UpdateChatPhoto(super::_common::from_raw(raw)?),
+            "updateChatPosition" => TdObject::UpdateChatPosition(super::_common::from_raw(raw)?),
+            "updateChatReadInbox" => TdObject::UpdateChatReadInbox(super::_common::from_raw(raw)?),
+            "updateChatReadOutbox" => {
+                TdObject::UpdateChatReadOutbox(super::_common::from_raw(raw)?)
+            }
+            "updateChatReplyMarkup" => {
+                TdObject::UpdateChatReplyMarkup(super::_common::from_raw(raw)?)
+            }
+            "updateChatTheme" => TdObject::UpdateChatTheme(super::_common::from_raw(raw)?),
+            "updateChatThemes" => TdObject::UpdateChatThemes(super::_common::from_raw(raw)?),
+            "updateChatTitle" => TdObject::UpdateChatTitle(super::_common::from_raw(raw)?),
+            "updateChatUnreadMentionCount" => {
+                TdObject::UpdateChatUnreadMentionCount(super::_common::from_raw(raw)?)
+            }
+            "updateChatVideoChat" => TdObject::UpdateChatVideoChat(super::_common::from_raw(raw)?),
+            "updateConnectionState" => {
+                TdObject::UpdateConnectionState(super::_common::from_raw(raw)?)
+            }
+            "updateDeleteMessages" => {
+                TdObject::UpdateDeleteMessages(super::_common::from_raw(raw)?)
+            }
+            "updateDiceEmojis" => TdObject::UpdateDiceEmojis(super::_common::from_raw(raw)?),
+            "updateFavoriteStickers" => {
+                TdObject::UpdateFavoriteStickers(super::_common::from_raw(raw)?)
+            }
+            "updateFile" => TdObject::UpdateFile(super::_common::from_raw(raw)?),
+            "updateFileGenerationStart" => {
+                TdObject::UpdateFileGenerationStart(super::_common::from_raw(raw)?)
+            }
+            "updateFileGenerationStop" => {
+                TdObject::UpdateFileGenerationStop(super::_common::from_raw(raw)?)
+            }
+            "updateGroupCall" => TdObject::UpdateGroupCall(super::_common::from_raw(raw)?),
+            "updateGroupCallParticipant" => {
+                TdObject::UpdateGroupCallParticipant(super::_common::from_raw(raw)?)
+            }
+            "updateHavePendingNotifications" => {
+                TdObject::UpdateHavePendingNotifications(super::_common::from_raw(raw)?)
+            }
+            "updateInstalledStickerSets" => {
+                TdObject::UpdateInstalledStickerSets(super::_common::from_raw(raw)?)
+            }
+            "updateLanguagePackStrings" => {
+                TdObject::UpdateLanguagePackStrings(super::_common::from_raw(raw)?)
+            }
+            "updateMessageContent" => {
+                TdObject::UpdateMessageContent(super::_common::from_raw(raw)?)
+            }
+            "updateMessageContentOpened" => {
+                TdObject::UpdateMessageContentOpened(super::_common::from_raw(raw)?)
+            }
+            "updateMessageEdited" => TdObject::UpdateMessageEdited(super::_common::from_raw(raw)?),
+            "updateMessageInteractionInfo" => {
+                TdObject::UpdateMessageInteractionInfo(super::_common::from_raw(raw)?)
+            }
+            "updateMessageIsPinned" => {
+                TdObject::UpdateMessageIsPinned(super::_common::from_raw(raw)?)
+            }
+            "updateMessageLiveLocationViewed" => {
+                TdObject::UpdateMessageLiveLocationViewed(super::_common::from_raw(raw)?)
+            }
+            "updateMessageMentionRead" => {
+                TdObject::UpdateMessageMentionRead(super::_common::from_raw(raw)?)
+            }
+            "updateMessageSendAcknowledged" => {
+                TdObject::UpdateMessageSendAcknowledged(super::_common::from_raw(raw)?)
+            }
+            "updateMessageSendFailed" => {
+                TdObject::UpdateMessageSendFailed(super::_common::from_raw(raw)?)
+            }
+            "updateMessageSendSucceeded" => {
+                TdObject::UpdateMessageSendSucceeded(super::_common::from_raw(raw)?)
+            }
+            "updateNewCallSignalingData" => {
+                TdObject::UpdateNewCallSignalingData(super::_common::from_raw(raw)?)
+            }
+            "updateNewCallbackQuery" => {
+                TdObject::UpdateNewCallbackQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewChat" => TdObject::UpdateNewChat(super::_common::from_raw(raw)?),
+            "updateNewChatJoinRequest" => {
+                TdObject::UpdateNewChatJoinRequest(super::_common::from_raw(raw)?)
+            }
+            "updateNewChosenInlineResult" => {
+                TdObject::UpdateNewChosenInlineResult(super::_common::from_raw(raw)?)
+            }
+            "updateNewCustomEvent" => {
+                TdObject::UpdateNewCustomEvent(super::_common::from_raw(raw)?)
+            }
+            "updateNewCustomQuery" => {
+                TdObject::UpdateNewCustomQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewInlineCallbackQuery" => {
+                TdObject::UpdateNewInlineCallbackQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewInlineQuery" => {
+                TdObject::UpdateNewInlineQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewMessage" => TdObject::UpdateNewMessage(super::_common::from_raw(raw)?),
+            "updateNewPreCheckoutQuery" => {
+                TdObject::UpdateNewPreCheckoutQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewShippingQuery" => {
+                TdObject::UpdateNewShippingQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNotification" => TdObject::UpdateNotification(super::_common::from_raw(raw)?),
+            "updateNotificationGroup" => {
+                TdObject::UpdateNotificationGroup(super::_common::from_raw(raw)?)
+            }
+            "updateOption" => TdObject::UpdateOption(super::_common::from_raw(raw)?),
+            "updatePoll" => TdObject::UpdatePoll(super::_common::from_raw(raw)?),
+            "updatePollAnswer" => TdObject::UpdatePollAnswer(super::_common::from_raw(raw)?),
+            "updateRecentStickers" => {
+                TdObject::UpdateRecentStickers(super::_common::from_raw(raw)?)
+            }
+            "updateSavedAnimations" => {
+                TdObject::UpdateSavedAnimations(super::_common::from_raw(raw)?)
+            }
+            "updateScopeNotificationSettings" => {
+                TdObject::UpdateScopeNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "updateSecretChat" => TdObject::UpdateSecretChat(super::_common::from_raw(raw)?),
+            "updateSelectedBackground" => {
+                TdObject::UpdateSelectedBackground(super::_common::from_raw(raw)?)
+            }
+            "updateServiceNotification" => {
+                TdObject::UpdateServiceNotification(super::_common::from_raw(raw)?)
+            }
+            "updateStickerSet" => TdObject::UpdateStickerSet(super::_common::from_raw(raw)?),
+            "updateSuggestedActions" => {
+                TdObject::UpdateSuggestedActions(super::_common::from_raw(raw)?)
+            }
+            "updateSupergroup" => TdObject::UpdateSupergroup(super::_common::from_raw(raw)?),
+            "updateSupergroupFullInfo" => {
+                TdObject::UpdateSupergroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "updateTermsOfService" => {
+                TdObject::UpdateTermsOfService(super::_common::from_raw(raw)?)
+            }
+            "updateTrendingStickerSets" => {
+                TdObject::UpdateTrendingStickerSets(super::_common::from_raw(raw)?)
+            }
+            "updateUnreadChatCount" => {
+                TdObject::UpdateUnreadChatCount(super::_common::from_raw(raw)?)
+            }
+            "updateUnreadMessageCount" => {
+                TdObject::UpdateUnreadMessageCount(super::_common::from_raw(raw)?)
+            }
+            "updateUser" => TdObject::UpdateUser(super::_common::from_raw(raw)?),
+            "updateUserFullInfo" => TdObject::UpdateUserFullInfo(super::_common::from_raw(raw)?),
+            "updateUserPrivacySettingRules" => {
+                TdObject::UpdateUserPrivacySettingRules(super::_common::from_raw(raw)?)
+            }
+            "updateUserStatus" => TdObject::UpdateUserStatus(super::_common::from_raw(raw)?),
+            "updateUsersNearby" => TdObject::UpdateUsersNearby(super::_common::from_raw(raw)?),
+            "updates" => TdObject::Updates(super::_common::from_raw(raw)?),
+            "user" => TdObject::User(super::_common::from_raw(raw)?),
+            "userFullInfo" => TdObject::UserFullInfo(super::_common::from_raw(raw)?),
+            "userPrivacySettingAllowCalls" => {
+                TdObject::UserPrivacySettingAllowCalls(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingAllowChatInvites" => {
+                TdObject::UserPrivacySettingAllowChatInvites(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingAllowFindingByPhoneNumber" => {
+                TdObject::UserPrivacySettingAllowFindingByPhoneNumber(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "userPrivacySettingAllowPeerToPeerCalls" => {
+                TdObject::UserPrivacySettingAllowPeerToPeerCalls(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowAll" => {
+                TdObject::UserPrivacySettingRuleAllowAll(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowChatMembers" => {
+                TdObject::UserPrivacySettingRuleAllowChatMembers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowContacts" => {
+                TdObject::UserPrivacySettingRuleAllowContacts(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowUsers" => {
+                TdObject::UserPrivacySettingRuleAllowUsers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictAll" => {
+                TdObject::UserPrivacySettingRuleRestrictAll(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictChatMembers" => {
+                TdObject::UserPrivacySettingRuleRestrictChatMembers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictContacts" => {
+                TdObject::UserPrivacySettingRuleRestrictContacts(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictUsers" => {
+                TdObject::UserPrivacySettingRuleRestrictUsers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRules" => {
+                TdObject::UserPrivacySettingRules(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowLinkInForwardedMessages" => {
+                TdObject::UserPrivacySettingShowLinkInForwardedMessages(super::_common::from_raw(
+                    raw,
+                )?)
+            }
+            "userPrivacySettingShowPhoneNumber" => {
+                TdObject::UserPrivacySettingShowPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowProfilePhoto" => {
+                TdObject::UserPrivacySettingShowProfilePhoto(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowStatus" => {
+                TdObject::UserPrivacySettingShowStatus(super::_common::from_raw(raw)?)
+            }
+            "userStatusEmpty" => TdObject::UserStatusEmpty(super::_common::from_raw(raw)?),
+            "userStatusLastMonth" => TdObject::UserStatusLastMonth(super::_common::from_raw(raw)?),
+            "userStatusLastWeek" => TdObject::UserStatusLastWeek(super::_common::from_raw(raw)?),
+            "userStatusOffline" => TdObject::UserStatusOffline(super::_common::from_raw(raw)?),
+            "userStatusOnline" => TdObject::UserStatusOnline(super::_common::from_raw(raw)?),
+            "userStatusRecently" => TdObject::UserStatusRecently(super::_common::from_raw(raw)?),
+            "userTypeBot" => TdObject::UserTypeBot(super::_common::from_raw(raw)?),
+            "userTypeDeleted" => TdObject::UserTypeDeleted(super::_common::from_raw(raw)?),
+            "userTypeRegular" => TdObject::UserTypeRegular(super::_common::from_raw(raw)?),
+            "userTypeUnknown" => TdObject::UserTypeUnknown(super::_common::from_raw(raw)?),
+            "users" => TdObject::Users(super::_common::from_raw(raw)?),
+            "validatedOrderInfo" => TdObject::ValidatedOrderInfo(super::_common::from_raw(raw)?),
+            "vectorPathCommandCubicBezierCurve" => {
+                TdObject::VectorPathCommandCubicBezierCurve(super::_common::from_raw(raw)?)
+            }
+            "vectorPathCommandLine" => {
+                TdObject::VectorPathCommandLine(super::_common::from_raw(raw)?)
+            }
+            "venue" => TdObject::Venue(super::_common::from_raw(raw)?),
+            "video" => TdObject::Video(super::_common::from_raw(raw)?),
+            "videoChat" => TdObject::VideoChat(super::_common::from_raw(raw)?),
+            "videoNote" => TdObject::VideoNote(super::_common::from_raw(raw)?),
+            "voiceNote" => TdObject::VoiceNote(super::_common::from_raw(raw)?),
+            "webPage" => TdObject::WebPage(super::_common::from_raw(raw)?),
+            "webPageInstantView" => TdObject::WebPageInstantView(super::_common::from_raw(raw)?),
+            _ => TdObject::Unknown { type_name, raw },
+        })
+    }
+}
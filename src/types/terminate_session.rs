@@ -19,7 +19,7 @@ pub struct TerminateSession {
     #[serde(default)]
     session_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for TerminateSession {
     }
 }
 
-impl RFunction for TerminateSession {}
+impl RFunction for TerminateSession {
+    type Response = Ok;
+}
 
 impl TerminateSession {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
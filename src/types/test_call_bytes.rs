@@ -15,7 +15,7 @@ pub struct TestCallBytes {
     #[serde(default)]
     x: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallBytes {
     }
 }
 
-impl RFunction for TestCallBytes {}
+impl RFunction for TestCallBytes {
+    type Response = TestBytes;
+}
 
 impl TestCallBytes {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct TestCallString {
     #[serde(default)]
     x: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallString {
     }
 }
 
-impl RFunction for TestCallString {}
+impl RFunction for TestCallString {
+    type Response = TestString;
+}
 
 impl TestCallString {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct TestCallVectorInt {
     #[serde(default)]
     x: Vec<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallVectorInt {
     }
 }
 
-impl RFunction for TestCallVectorInt {}
+impl RFunction for TestCallVectorInt {
+    type Response = TestVectorInt;
+}
 
 impl TestCallVectorInt {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
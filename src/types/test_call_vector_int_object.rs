@@ -15,7 +15,7 @@ pub struct TestCallVectorIntObject {
     #[serde(default)]
     x: Vec<TestInt>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallVectorIntObject {
     }
 }
 
-impl RFunction for TestCallVectorIntObject {}
+impl RFunction for TestCallVectorIntObject {
+    type Response = TestVectorIntObject;
+}
 
 impl TestCallVectorIntObject {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct TestCallVectorString {
     #[serde(default)]
     x: Vec<String>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallVectorString {
     }
 }
 
-impl RFunction for TestCallVectorString {}
+impl RFunction for TestCallVectorString {
+    type Response = TestVectorString;
+}
 
 impl TestCallVectorString {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
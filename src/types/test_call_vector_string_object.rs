@@ -15,7 +15,7 @@ pub struct TestCallVectorStringObject {
     #[serde(default)]
     x: Vec<TestString>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestCallVectorStringObject {
     }
 }
 
-impl RFunction for TestCallVectorStringObject {}
+impl RFunction for TestCallVectorStringObject {
+    type Response = TestVectorStringObject;
+}
 
 impl TestCallVectorStringObject {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -32,7 +32,7 @@ pub struct TestProxy {
     #[serde(default)]
     timeout: f32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -47,7 +47,9 @@ impl RObject for TestProxy {
     }
 }
 
-impl RFunction for TestProxy {}
+impl RFunction for TestProxy {
+    type Response = Ok;
+}
 
 impl TestProxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
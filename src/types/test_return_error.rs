@@ -13,7 +13,7 @@ pub struct TestReturnError {
     /// The error to be returned
     error: Error,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for TestReturnError {
     }
 }
 
-impl RFunction for TestReturnError {}
+impl RFunction for TestReturnError {
+    type Response = Error;
+}
 
 impl TestReturnError {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -15,7 +15,7 @@ pub struct TestSquareInt {
     #[serde(default)]
     x: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for TestSquareInt {
     }
 }
 
-impl RFunction for TestSquareInt {}
+impl RFunction for TestSquareInt {
+    type Response = TestInt;
+}
 
 impl TestSquareInt {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
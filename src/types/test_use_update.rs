@@ -11,7 +11,7 @@ pub struct TestUseUpdate {
     #[serde(rename(serialize = "@client_id", deserialize = "@client_id"))]
     client_id: Option<i32>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -28,7 +28,9 @@ impl RObject for TestUseUpdate {
 
 impl TDUpdate for TestUseUpdate {}
 
-impl RFunction for TestUseUpdate {}
+impl RFunction for TestUseUpdate {
+    type Response = Update;
+}
 
 impl TestUseUpdate {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
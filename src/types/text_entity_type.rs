@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDTextEntityType: Debug + RObject {}
 
 /// Represents a part of the text which must be formatted differently
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum TextEntityType {
     #[doc(hidden)]
@@ -68,6 +68,12 @@ pub enum TextEntityType {
     /// An HTTP URL
     #[serde(rename = "textEntityTypeUrl")]
     Url(TextEntityTypeUrl),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for TextEntityType {
@@ -92,6 +98,7 @@ impl RObject for TextEntityType {
             TextEntityType::TextUrl(t) => t.extra(),
             TextEntityType::Underline(t) => t.extra(),
             TextEntityType::Url(t) => t.extra(),
+            TextEntityType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -117,12 +124,59 @@ impl RObject for TextEntityType {
             TextEntityType::TextUrl(t) => t.client_id(),
             TextEntityType::Underline(t) => t.client_id(),
             TextEntityType::Url(t) => t.client_id(),
+            TextEntityType::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for TextEntityType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "textEntityTypeBankCardNumber" => {
+                TextEntityType::BankCardNumber(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeBold" => TextEntityType::Bold(super::_common::from_raw(raw)?),
+            "textEntityTypeBotCommand" => {
+                TextEntityType::BotCommand(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeCashtag" => TextEntityType::Cashtag(super::_common::from_raw(raw)?),
+            "textEntityTypeCode" => TextEntityType::Code(super::_common::from_raw(raw)?),
+            "textEntityTypeEmailAddress" => {
+                TextEntityType::EmailAddress(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeHashtag" => TextEntityType::Hashtag(super::_common::from_raw(raw)?),
+            "textEntityTypeItalic" => TextEntityType::Italic(super::_common::from_raw(raw)?),
+            "textEntityTypeMediaTimestamp" => {
+                TextEntityType::MediaTimestamp(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeMention" => TextEntityType::Mention(super::_common::from_raw(raw)?),
+            "textEntityTypeMentionName" => {
+                TextEntityType::MentionName(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypePhoneNumber" => {
+                TextEntityType::PhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypePre" => TextEntityType::Pre(super::_common::from_raw(raw)?),
+            "textEntityTypePreCode" => TextEntityType::PreCode(super::_common::from_raw(raw)?),
+            "textEntityTypeStrikethrough" => {
+                TextEntityType::Strikethrough(super::_common::from_raw(raw)?)
+            }
+            "textEntityTypeTextUrl" => TextEntityType::TextUrl(super::_common::from_raw(raw)?),
+            "textEntityTypeUnderline" => TextEntityType::Underline(super::_common::from_raw(raw)?),
+            "textEntityTypeUrl" => TextEntityType::Url(super::_common::from_raw(raw)?),
+            _ => TextEntityType::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl TextEntityType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
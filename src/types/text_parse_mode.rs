@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDTextParseMode: Debug + RObject {}
 
 /// Describes the way the text needs to be parsed for TextEntities
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum TextParseMode {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum TextParseMode {
     /// The text uses Markdown-style formatting
     #[serde(rename = "textParseModeMarkdown")]
     Markdown(TextParseModeMarkdown),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for TextParseMode {
@@ -28,6 +34,7 @@ impl RObject for TextParseMode {
         match self {
             TextParseMode::HTML(t) => t.extra(),
             TextParseMode::Markdown(t) => t.extra(),
+            TextParseMode::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,29 @@ impl RObject for TextParseMode {
         match self {
             TextParseMode::HTML(t) => t.client_id(),
             TextParseMode::Markdown(t) => t.client_id(),
+            TextParseMode::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for TextParseMode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "textParseModeHTML" => TextParseMode::HTML(super::_common::from_raw(raw)?),
+            "textParseModeMarkdown" => TextParseMode::Markdown(super::_common::from_raw(raw)?),
+            _ => TextParseMode::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl TextParseMode {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDThumbnailFormat: Debug + RObject {}
 
 /// Describes format of the thumbnail
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum ThumbnailFormat {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum ThumbnailFormat {
     /// The thumbnail is in WEBP format. It will be used only for some stickers
     #[serde(rename = "thumbnailFormatWebp")]
     Webp(ThumbnailFormatWebp),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for ThumbnailFormat {
@@ -44,6 +50,7 @@ impl RObject for ThumbnailFormat {
             ThumbnailFormat::Png(t) => t.extra(),
             ThumbnailFormat::Tgs(t) => t.extra(),
             ThumbnailFormat::Webp(t) => t.extra(),
+            ThumbnailFormat::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -57,12 +64,33 @@ impl RObject for ThumbnailFormat {
             ThumbnailFormat::Png(t) => t.client_id(),
             ThumbnailFormat::Tgs(t) => t.client_id(),
             ThumbnailFormat::Webp(t) => t.client_id(),
+            ThumbnailFormat::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for ThumbnailFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "thumbnailFormatGif" => ThumbnailFormat::Gif(super::_common::from_raw(raw)?),
+            "thumbnailFormatJpeg" => ThumbnailFormat::Jpeg(super::_common::from_raw(raw)?),
+            "thumbnailFormatMpeg4" => ThumbnailFormat::Mpeg4(super::_common::from_raw(raw)?),
+            "thumbnailFormatPng" => ThumbnailFormat::Png(super::_common::from_raw(raw)?),
+            "thumbnailFormatTgs" => ThumbnailFormat::Tgs(super::_common::from_raw(raw)?),
+            "thumbnailFormatWebp" => ThumbnailFormat::Webp(super::_common::from_raw(raw)?),
+            _ => ThumbnailFormat::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl ThumbnailFormat {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
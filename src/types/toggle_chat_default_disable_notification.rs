@@ -19,7 +19,7 @@ pub struct ToggleChatDefaultDisableNotification {
     #[serde(default)]
     default_disable_notification: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleChatDefaultDisableNotification {
     }
 }
 
-impl RFunction for ToggleChatDefaultDisableNotification {}
+impl RFunction for ToggleChatDefaultDisableNotification {
+    type Response = Ok;
+}
 
 impl ToggleChatDefaultDisableNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
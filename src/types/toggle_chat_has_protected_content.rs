@@ -19,7 +19,7 @@ pub struct ToggleChatHasProtectedContent {
     #[serde(default)]
     has_protected_content: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleChatHasProtectedContent {
     }
 }
 
-impl RFunction for ToggleChatHasProtectedContent {}
+impl RFunction for ToggleChatHasProtectedContent {
+    type Response = Ok;
+}
 
 impl ToggleChatHasProtectedContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ToggleChatIsMarkedAsUnread {
     #[serde(default)]
     is_marked_as_unread: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleChatIsMarkedAsUnread {
     }
 }
 
-impl RFunction for ToggleChatIsMarkedAsUnread {}
+impl RFunction for ToggleChatIsMarkedAsUnread {
+    type Response = Ok;
+}
 
 impl ToggleChatIsMarkedAsUnread {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct ToggleChatIsPinned {
     #[serde(default)]
     is_pinned: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ToggleChatIsPinned {
     }
 }
 
-impl RFunction for ToggleChatIsPinned {}
+impl RFunction for ToggleChatIsPinned {
+    type Response = Ok;
+}
 
 impl ToggleChatIsPinned {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
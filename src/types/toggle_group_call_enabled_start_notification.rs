@@ -19,7 +19,7 @@ pub struct ToggleGroupCallEnabledStartNotification {
     #[serde(default)]
     enabled_start_notification: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleGroupCallEnabledStartNotification {
     }
 }
 
-impl RFunction for ToggleGroupCallEnabledStartNotification {}
+impl RFunction for ToggleGroupCallEnabledStartNotification {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallEnabledStartNotification {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
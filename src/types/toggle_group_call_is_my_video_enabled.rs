@@ -19,7 +19,7 @@ pub struct ToggleGroupCallIsMyVideoEnabled {
     #[serde(default)]
     is_my_video_enabled: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleGroupCallIsMyVideoEnabled {
     }
 }
 
-impl RFunction for ToggleGroupCallIsMyVideoEnabled {}
+impl RFunction for ToggleGroupCallIsMyVideoEnabled {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallIsMyVideoEnabled {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
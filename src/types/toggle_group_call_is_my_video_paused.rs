@@ -19,7 +19,7 @@ pub struct ToggleGroupCallIsMyVideoPaused {
     #[serde(default)]
     is_my_video_paused: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleGroupCallIsMyVideoPaused {
     }
 }
 
-impl RFunction for ToggleGroupCallIsMyVideoPaused {}
+impl RFunction for ToggleGroupCallIsMyVideoPaused {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallIsMyVideoPaused {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
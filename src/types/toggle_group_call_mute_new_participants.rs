@@ -19,7 +19,7 @@ pub struct ToggleGroupCallMuteNewParticipants {
     #[serde(default)]
     mute_new_participants: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleGroupCallMuteNewParticipants {
     }
 }
 
-impl RFunction for ToggleGroupCallMuteNewParticipants {}
+impl RFunction for ToggleGroupCallMuteNewParticipants {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallMuteNewParticipants {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
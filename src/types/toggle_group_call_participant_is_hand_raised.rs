@@ -23,7 +23,7 @@ pub struct ToggleGroupCallParticipantIsHandRaised {
     #[serde(default)]
     is_hand_raised: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ToggleGroupCallParticipantIsHandRaised {
     }
 }
 
-impl RFunction for ToggleGroupCallParticipantIsHandRaised {}
+impl RFunction for ToggleGroupCallParticipantIsHandRaised {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallParticipantIsHandRaised {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
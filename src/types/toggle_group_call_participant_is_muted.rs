@@ -23,7 +23,7 @@ pub struct ToggleGroupCallParticipantIsMuted {
     #[serde(default)]
     is_muted: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ToggleGroupCallParticipantIsMuted {
     }
 }
 
-impl RFunction for ToggleGroupCallParticipantIsMuted {}
+impl RFunction for ToggleGroupCallParticipantIsMuted {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallParticipantIsMuted {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ToggleGroupCallScreenSharingIsPaused {
     #[serde(default)]
     is_paused: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleGroupCallScreenSharingIsPaused {
     }
 }
 
-impl RFunction for ToggleGroupCallScreenSharingIsPaused {}
+impl RFunction for ToggleGroupCallScreenSharingIsPaused {
+    type Response = Ok;
+}
 
 impl ToggleGroupCallScreenSharingIsPaused {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ToggleMessageSenderIsBlocked {
     #[serde(default)]
     is_blocked: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleMessageSenderIsBlocked {
     }
 }
 
-impl RFunction for ToggleMessageSenderIsBlocked {}
+impl RFunction for ToggleMessageSenderIsBlocked {
+    type Response = Ok;
+}
 
 impl ToggleMessageSenderIsBlocked {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
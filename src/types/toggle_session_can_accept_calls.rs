@@ -23,7 +23,7 @@ pub struct ToggleSessionCanAcceptCalls {
     #[serde(default)]
     can_accept_calls: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ToggleSessionCanAcceptCalls {
     }
 }
 
-impl RFunction for ToggleSessionCanAcceptCalls {}
+impl RFunction for ToggleSessionCanAcceptCalls {
+    type Response = Ok;
+}
 
 impl ToggleSessionCanAcceptCalls {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -23,7 +23,7 @@ pub struct ToggleSessionCanAcceptSecretChats {
     #[serde(default)]
     can_accept_secret_chats: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for ToggleSessionCanAcceptSecretChats {
     }
 }
 
-impl RFunction for ToggleSessionCanAcceptSecretChats {}
+impl RFunction for ToggleSessionCanAcceptSecretChats {
+    type Response = Ok;
+}
 
 impl ToggleSessionCanAcceptSecretChats {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct ToggleSupergroupIsAllHistoryAvailable {
     #[serde(default)]
     is_all_history_available: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleSupergroupIsAllHistoryAvailable {
     }
 }
 
-impl RFunction for ToggleSupergroupIsAllHistoryAvailable {}
+impl RFunction for ToggleSupergroupIsAllHistoryAvailable {
+    type Response = Ok;
+}
 
 impl ToggleSupergroupIsAllHistoryAvailable {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
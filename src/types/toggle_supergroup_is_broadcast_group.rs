@@ -15,7 +15,7 @@ pub struct ToggleSupergroupIsBroadcastGroup {
     #[serde(default)]
     supergroup_id: i64,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -30,7 +30,9 @@ impl RObject for ToggleSupergroupIsBroadcastGroup {
     }
 }
 
-impl RFunction for ToggleSupergroupIsBroadcastGroup {}
+impl RFunction for ToggleSupergroupIsBroadcastGroup {
+    type Response = Ok;
+}
 
 impl ToggleSupergroupIsBroadcastGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
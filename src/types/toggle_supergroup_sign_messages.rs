@@ -19,7 +19,7 @@ pub struct ToggleSupergroupSignMessages {
     #[serde(default)]
     sign_messages: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for ToggleSupergroupSignMessages {
     }
 }
 
-impl RFunction for ToggleSupergroupSignMessages {}
+impl RFunction for ToggleSupergroupSignMessages {
+    type Response = Ok;
+}
 
 impl ToggleSupergroupSignMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
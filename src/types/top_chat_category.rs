@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDTopChatCategory: Debug + RObject {}
 
 /// Represents the categories of chats for which a list of frequently used chats can be retrieved
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum TopChatCategory {
     #[doc(hidden)]
@@ -35,6 +35,12 @@ pub enum TopChatCategory {
     /// A category containing frequently used private chats with non-bot users
     #[serde(rename = "topChatCategoryUsers")]
     Users(TopChatCategoryUsers),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for TopChatCategory {
@@ -48,6 +54,7 @@ impl RObject for TopChatCategory {
             TopChatCategory::Groups(t) => t.extra(),
             TopChatCategory::InlineBots(t) => t.extra(),
             TopChatCategory::Users(t) => t.extra(),
+            TopChatCategory::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -62,12 +69,38 @@ impl RObject for TopChatCategory {
             TopChatCategory::Groups(t) => t.client_id(),
             TopChatCategory::InlineBots(t) => t.client_id(),
             TopChatCategory::Users(t) => t.client_id(),
+            TopChatCategory::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for TopChatCategory {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "topChatCategoryBots" => TopChatCategory::Bots(super::_common::from_raw(raw)?),
+            "topChatCategoryCalls" => TopChatCategory::Calls(super::_common::from_raw(raw)?),
+            "topChatCategoryChannels" => TopChatCategory::Channels(super::_common::from_raw(raw)?),
+            "topChatCategoryForwardChats" => {
+                TopChatCategory::ForwardChats(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryGroups" => TopChatCategory::Groups(super::_common::from_raw(raw)?),
+            "topChatCategoryInlineBots" => {
+                TopChatCategory::InlineBots(super::_common::from_raw(raw)?)
+            }
+            "topChatCategoryUsers" => TopChatCategory::Users(super::_common::from_raw(raw)?),
+            _ => TopChatCategory::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl TopChatCategory {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
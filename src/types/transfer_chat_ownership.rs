@@ -23,7 +23,7 @@ pub struct TransferChatOwnership {
     #[serde(default)]
     password: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for TransferChatOwnership {
     }
 }
 
-impl RFunction for TransferChatOwnership {}
+impl RFunction for TransferChatOwnership {
+    type Response = Ok;
+}
 
 impl TransferChatOwnership {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDUpdate: Debug + RObject {}
 
 /// Contains notifications about data changes
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum Update {
     #[doc(hidden)]
@@ -293,6 +293,12 @@ pub enum Update {
     /// The list of users nearby has changed. The update is guaranteed to be sent only 60 seconds after a successful searchChatsNearby request
     #[serde(rename = "updateUsersNearby")]
     UsersNearby(UpdateUsersNearby),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for Update {
@@ -392,6 +398,7 @@ impl RObject for Update {
             Update::UserPrivacySettingRules(t) => t.extra(),
             Update::UserStatus(t) => t.extra(),
             Update::UsersNearby(t) => t.extra(),
+            Update::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -492,12 +499,194 @@ impl RObject for Update {
             Update::UserPrivacySettingRules(t) => t.client_id(),
             Update::UserStatus(t) => t.client_id(),
             Update::UsersNearby(t) => t.client_id(),
+            Update::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for Update {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "testUseUpdate" => Update::TestUseUpdate(super::_common::from_raw(raw)?),
+            "updateActiveNotifications" => {
+                Update::ActiveNotifications(super::_common::from_raw(raw)?)
+            }
+            "updateAnimatedEmojiMessageClicked" => {
+                Update::AnimatedEmojiMessageClicked(super::_common::from_raw(raw)?)
+            }
+            "updateAnimationSearchParameters" => {
+                Update::AnimationSearchParameters(super::_common::from_raw(raw)?)
+            }
+            "updateAuthorizationState" => {
+                Update::AuthorizationState(super::_common::from_raw(raw)?)
+            }
+            "updateBasicGroup" => Update::BasicGroup(super::_common::from_raw(raw)?),
+            "updateBasicGroupFullInfo" => {
+                Update::BasicGroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "updateCall" => Update::Call(super::_common::from_raw(raw)?),
+            "updateChatAction" => Update::ChatAction(super::_common::from_raw(raw)?),
+            "updateChatActionBar" => Update::ChatActionBar(super::_common::from_raw(raw)?),
+            "updateChatDefaultDisableNotification" => {
+                Update::ChatDefaultDisableNotification(super::_common::from_raw(raw)?)
+            }
+            "updateChatDraftMessage" => Update::ChatDraftMessage(super::_common::from_raw(raw)?),
+            "updateChatFilters" => Update::ChatFilters(super::_common::from_raw(raw)?),
+            "updateChatHasProtectedContent" => {
+                Update::ChatHasProtectedContent(super::_common::from_raw(raw)?)
+            }
+            "updateChatHasScheduledMessages" => {
+                Update::ChatHasScheduledMessages(super::_common::from_raw(raw)?)
+            }
+            "updateChatIsBlocked" => Update::ChatIsBlocked(super::_common::from_raw(raw)?),
+            "updateChatIsMarkedAsUnread" => {
+                Update::ChatIsMarkedAsUnread(super::_common::from_raw(raw)?)
+            }
+            "updateChatLastMessage" => Update::ChatLastMessage(super::_common::from_raw(raw)?),
+            "updateChatMember" => Update::ChatMember(super::_common::from_raw(raw)?),
+            "updateChatMessageSender" => Update::ChatMessageSender(super::_common::from_raw(raw)?),
+            "updateChatMessageTtl" => Update::ChatMessageTtl(super::_common::from_raw(raw)?),
+            "updateChatNotificationSettings" => {
+                Update::ChatNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "updateChatOnlineMemberCount" => {
+                Update::ChatOnlineMemberCount(super::_common::from_raw(raw)?)
+            }
+            "updateChatPendingJoinRequests" => {
+                Update::ChatPendingJoinRequests(super::_common::from_raw(raw)?)
+            }
+            "updateChatPermissions" => Update::ChatPermissions(super::_common::from_raw(raw)?),
+            "updateChatPhoto" => Update::ChatPhoto(super::_common::from_raw(raw)?),
+            "updateChatPosition" => Update::ChatPosition(super::_common::from_raw(raw)?),
+            "updateChatReadInbox" => Update::ChatReadInbox(super::_common::from_raw(raw)?),
+            "updateChatReadOutbox" => Update::ChatReadOutbox(super::_common::from_raw(raw)?),
+            "updateChatReplyMarkup" => Update::ChatReplyMarkup(super::_common::from_raw(raw)?),
+            "updateChatTheme" => Update::ChatTheme(super::_common::from_raw(raw)?),
+            "updateChatThemes" => Update::ChatThemes(super::_common::from_raw(raw)?),
+            "updateChatTitle" => Update::ChatTitle(super::_common::from_raw(raw)?),
+            "updateChatUnreadMentionCount" => {
+                Update::ChatUnreadMentionCount(super::_common::from_raw(raw)?)
+            }
+            "updateChatVideoChat" => Update::ChatVideoChat(super::_common::from_raw(raw)?),
+            "updateConnectionState" => Update::ConnectionState(super::_common::from_raw(raw)?),
+            "updateDeleteMessages" => Update::DeleteMessages(super::_common::from_raw(raw)?),
+            "updateDiceEmojis" => Update::DiceEmojis(super::_common::from_raw(raw)?),
+            "updateFavoriteStickers" => Update::FavoriteStickers(super::_common::from_raw(raw)?),
+            "updateFile" => Update::File(super::_common::from_raw(raw)?),
+            "updateFileGenerationStart" => {
+                Update::FileGenerationStart(super::_common::from_raw(raw)?)
+            }
+            "updateFileGenerationStop" => {
+                Update::FileGenerationStop(super::_common::from_raw(raw)?)
+            }
+            "updateGroupCall" => Update::GroupCall(super::_common::from_raw(raw)?),
+            "updateGroupCallParticipant" => {
+                Update::GroupCallParticipant(super::_common::from_raw(raw)?)
+            }
+            "updateHavePendingNotifications" => {
+                Update::HavePendingNotifications(super::_common::from_raw(raw)?)
+            }
+            "updateInstalledStickerSets" => {
+                Update::InstalledStickerSets(super::_common::from_raw(raw)?)
+            }
+            "updateLanguagePackStrings" => {
+                Update::LanguagePackStrings(super::_common::from_raw(raw)?)
+            }
+            "updateMessageContent" => Update::MessageContent(super::_common::from_raw(raw)?),
+            "updateMessageContentOpened" => {
+                Update::MessageContentOpened(super::_common::from_raw(raw)?)
+            }
+            "updateMessageEdited" => Update::MessageEdited(super::_common::from_raw(raw)?),
+            "updateMessageInteractionInfo" => {
+                Update::MessageInteractionInfo(super::_common::from_raw(raw)?)
+            }
+            "updateMessageIsPinned" => Update::MessageIsPinned(super::_common::from_raw(raw)?),
+            "updateMessageLiveLocationViewed" => {
+                Update::MessageLiveLocationViewed(super::_common::from_raw(raw)?)
+            }
+            "updateMessageMentionRead" => {
+                Update::MessageMentionRead(super::_common::from_raw(raw)?)
+            }
+            "updateMessageSendAcknowledged" => {
+                Update::MessageSendAcknowledged(super::_common::from_raw(raw)?)
+            }
+            "updateMessageSendFailed" => Update::MessageSendFailed(super::_common::from_raw(raw)?),
+            "updateMessageSendSucceeded" => {
+                Update::MessageSendSucceeded(super::_common::from_raw(raw)?)
+            }
+            "updateNewCallSignalingData" => {
+                Update::NewCallSignalingData(super::_common::from_raw(raw)?)
+            }
+            "updateNewCallbackQuery" => Update::NewCallbackQuery(super::_common::from_raw(raw)?),
+            "updateNewChat" => Update::NewChat(super::_common::from_raw(raw)?),
+            "updateNewChatJoinRequest" => {
+                Update::NewChatJoinRequest(super::_common::from_raw(raw)?)
+            }
+            "updateNewChosenInlineResult" => {
+                Update::NewChosenInlineResult(super::_common::from_raw(raw)?)
+            }
+            "updateNewCustomEvent" => Update::NewCustomEvent(super::_common::from_raw(raw)?),
+            "updateNewCustomQuery" => Update::NewCustomQuery(super::_common::from_raw(raw)?),
+            "updateNewInlineCallbackQuery" => {
+                Update::NewInlineCallbackQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewInlineQuery" => Update::NewInlineQuery(super::_common::from_raw(raw)?),
+            "updateNewMessage" => Update::NewMessage(super::_common::from_raw(raw)?),
+            "updateNewPreCheckoutQuery" => {
+                Update::NewPreCheckoutQuery(super::_common::from_raw(raw)?)
+            }
+            "updateNewShippingQuery" => Update::NewShippingQuery(super::_common::from_raw(raw)?),
+            "updateNotification" => Update::Notification(super::_common::from_raw(raw)?),
+            "updateNotificationGroup" => Update::NotificationGroup(super::_common::from_raw(raw)?),
+            "updateOption" => Update::Option(super::_common::from_raw(raw)?),
+            "updatePoll" => Update::Poll(super::_common::from_raw(raw)?),
+            "updatePollAnswer" => Update::PollAnswer(super::_common::from_raw(raw)?),
+            "updateRecentStickers" => Update::RecentStickers(super::_common::from_raw(raw)?),
+            "updateSavedAnimations" => Update::SavedAnimations(super::_common::from_raw(raw)?),
+            "updateScopeNotificationSettings" => {
+                Update::ScopeNotificationSettings(super::_common::from_raw(raw)?)
+            }
+            "updateSecretChat" => Update::SecretChat(super::_common::from_raw(raw)?),
+            "updateSelectedBackground" => {
+                Update::SelectedBackground(super::_common::from_raw(raw)?)
+            }
+            "updateServiceNotification" => {
+                Update::ServiceNotification(super::_common::from_raw(raw)?)
+            }
+            "updateStickerSet" => Update::StickerSet(super::_common::from_raw(raw)?),
+            "updateSuggestedActions" => Update::SuggestedActions(super::_common::from_raw(raw)?),
+            "updateSupergroup" => Update::Supergroup(super::_common::from_raw(raw)?),
+            "updateSupergroupFullInfo" => {
+                Update::SupergroupFullInfo(super::_common::from_raw(raw)?)
+            }
+            "updateTermsOfService" => Update::TermsOfService(super::_common::from_raw(raw)?),
+            "updateTrendingStickerSets" => {
+                Update::TrendingStickerSets(super::_common::from_raw(raw)?)
+            }
+            "updateUnreadChatCount" => Update::UnreadChatCount(super::_common::from_raw(raw)?),
+            "updateUnreadMessageCount" => {
+                Update::UnreadMessageCount(super::_common::from_raw(raw)?)
+            }
+            "updateUser" => Update::User(super::_common::from_raw(raw)?),
+            "updateUserFullInfo" => Update::UserFullInfo(super::_common::from_raw(raw)?),
+            "updateUserPrivacySettingRules" => {
+                Update::UserPrivacySettingRules(super::_common::from_raw(raw)?)
+            }
+            "updateUserStatus" => Update::UserStatus(super::_common::from_raw(raw)?),
+            "updateUsersNearby" => Update::UsersNearby(super::_common::from_raw(raw)?),
+            _ => Update::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl Update {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
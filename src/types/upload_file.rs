@@ -23,7 +23,7 @@ pub struct UploadFile {
     #[serde(default)]
     priority: i32,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -38,7 +38,9 @@ impl RObject for UploadFile {
     }
 }
 
-impl RFunction for UploadFile {}
+impl RFunction for UploadFile {
+    type Response = File;
+}
 
 impl UploadFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -19,7 +19,7 @@ pub struct UploadStickerFile {
     #[serde(skip_serializing_if = "InputSticker::_is_default")]
     sticker: InputSticker,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -34,7 +34,9 @@ impl RObject for UploadStickerFile {
     }
 }
 
-impl RFunction for UploadStickerFile {}
+impl RFunction for UploadStickerFile {
+    type Response = File;
+}
 
 impl UploadStickerFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
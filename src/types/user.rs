@@ -13,70 +13,70 @@ pub struct User {
     /// User identifier
 
     #[serde(default)]
-    id: i64,
+    pub(crate) id: i64,
     /// First name of the user
 
     #[serde(default)]
-    first_name: String,
+    pub(crate) first_name: String,
     /// Last name of the user
 
     #[serde(default)]
-    last_name: String,
+    pub(crate) last_name: String,
     /// Username of the user
 
     #[serde(default)]
-    username: String,
+    pub(crate) username: String,
     /// Phone number of the user
 
     #[serde(default)]
-    phone_number: String,
+    pub(crate) phone_number: String,
     /// Current online status of the user
 
     #[serde(skip_serializing_if = "UserStatus::_is_default")]
-    status: UserStatus,
+    pub(crate) status: UserStatus,
     /// Profile photo of the user; may be null
-    profile_photo: Option<ProfilePhoto>,
+    pub(crate) profile_photo: Option<ProfilePhoto>,
     /// The user is a contact of the current user
 
     #[serde(default)]
-    is_contact: bool,
+    pub(crate) is_contact: bool,
     /// The user is a contact of the current user and the current user is a contact of the user
 
     #[serde(default)]
-    is_mutual_contact: bool,
+    pub(crate) is_mutual_contact: bool,
     /// True, if the user is verified
 
     #[serde(default)]
-    is_verified: bool,
+    pub(crate) is_verified: bool,
     /// True, if the user is Telegram support account
 
     #[serde(default)]
-    is_support: bool,
+    pub(crate) is_support: bool,
     /// If non-empty, it contains a human-readable description of the reason why access to this user must be restricted
 
     #[serde(default)]
-    restriction_reason: String,
+    pub(crate) restriction_reason: String,
     /// True, if many users reported this user as a scam
 
     #[serde(default)]
-    is_scam: bool,
+    pub(crate) is_scam: bool,
     /// True, if many users reported this user as a fake account
 
     #[serde(default)]
-    is_fake: bool,
+    pub(crate) is_fake: bool,
     /// If false, the user is inaccessible, and the only information known about the user is inside this class. It can't be passed to any method except GetUser
 
     #[serde(default)]
-    have_access: bool,
+    pub(crate) have_access: bool,
     /// Type of the user
 
     #[serde(rename(serialize = "type", deserialize = "type"))]
     #[serde(skip_serializing_if = "UserType::_is_default")]
-    type_: UserType,
+    pub(crate) type_: UserType,
     /// IETF language tag of the user's language; only available to bots
 
     #[serde(default)]
-    language_code: String,
+    pub(crate) language_code: String,
 }
 
 impl RObject for User {
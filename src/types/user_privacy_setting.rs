@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDUserPrivacySetting: Debug + RObject {}
 
 /// Describes available user privacy settings
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum UserPrivacySetting {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum UserPrivacySetting {
     /// A privacy setting for managing whether the user's online status is visible
     #[serde(rename = "userPrivacySettingShowStatus")]
     ShowStatus(UserPrivacySettingShowStatus),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for UserPrivacySetting {
@@ -52,6 +58,7 @@ impl RObject for UserPrivacySetting {
             UserPrivacySetting::ShowPhoneNumber(t) => t.extra(),
             UserPrivacySetting::ShowProfilePhoto(t) => t.extra(),
             UserPrivacySetting::ShowStatus(t) => t.extra(),
+            UserPrivacySetting::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -67,12 +74,51 @@ impl RObject for UserPrivacySetting {
             UserPrivacySetting::ShowPhoneNumber(t) => t.client_id(),
             UserPrivacySetting::ShowProfilePhoto(t) => t.client_id(),
             UserPrivacySetting::ShowStatus(t) => t.client_id(),
+            UserPrivacySetting::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for UserPrivacySetting {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "userPrivacySettingAllowCalls" => {
+                UserPrivacySetting::AllowCalls(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingAllowChatInvites" => {
+                UserPrivacySetting::AllowChatInvites(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingAllowFindingByPhoneNumber" => {
+                UserPrivacySetting::AllowFindingByPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingAllowPeerToPeerCalls" => {
+                UserPrivacySetting::AllowPeerToPeerCalls(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowLinkInForwardedMessages" => {
+                UserPrivacySetting::ShowLinkInForwardedMessages(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowPhoneNumber" => {
+                UserPrivacySetting::ShowPhoneNumber(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowProfilePhoto" => {
+                UserPrivacySetting::ShowProfilePhoto(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingShowStatus" => {
+                UserPrivacySetting::ShowStatus(super::_common::from_raw(raw)?)
+            }
+            _ => UserPrivacySetting::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl UserPrivacySetting {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
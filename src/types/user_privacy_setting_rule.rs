@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDUserPrivacySettingRule: Debug + RObject {}
 
 /// Represents a single rule for managing privacy settings
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum UserPrivacySettingRule {
     #[doc(hidden)]
@@ -38,6 +38,12 @@ pub enum UserPrivacySettingRule {
     /// A rule to restrict all specified users from doing something
     #[serde(rename = "userPrivacySettingRuleRestrictUsers")]
     RestrictUsers(UserPrivacySettingRuleRestrictUsers),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for UserPrivacySettingRule {
@@ -52,6 +58,9 @@ impl RObject for UserPrivacySettingRule {
             UserPrivacySettingRule::RestrictChatMembers(t) => t.extra(),
             UserPrivacySettingRule::RestrictContacts(t) => t.extra(),
             UserPrivacySettingRule::RestrictUsers(t) => t.extra(),
+            UserPrivacySettingRule::Unknown { raw, .. } => {
+                raw.get("@extra").and_then(|e| e.as_str())
+            }
 
             _ => None,
         }
@@ -67,12 +76,51 @@ impl RObject for UserPrivacySettingRule {
             UserPrivacySettingRule::RestrictChatMembers(t) => t.client_id(),
             UserPrivacySettingRule::RestrictContacts(t) => t.client_id(),
             UserPrivacySettingRule::RestrictUsers(t) => t.client_id(),
+            UserPrivacySettingRule::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for UserPrivacySettingRule {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "userPrivacySettingRuleAllowAll" => {
+                UserPrivacySettingRule::AllowAll(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowChatMembers" => {
+                UserPrivacySettingRule::AllowChatMembers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowContacts" => {
+                UserPrivacySettingRule::AllowContacts(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleAllowUsers" => {
+                UserPrivacySettingRule::AllowUsers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictAll" => {
+                UserPrivacySettingRule::RestrictAll(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictChatMembers" => {
+                UserPrivacySettingRule::RestrictChatMembers(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictContacts" => {
+                UserPrivacySettingRule::RestrictContacts(super::_common::from_raw(raw)?)
+            }
+            "userPrivacySettingRuleRestrictUsers" => {
+                UserPrivacySettingRule::RestrictUsers(super::_common::from_raw(raw)?)
+            }
+            _ => UserPrivacySettingRule::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl UserPrivacySettingRule {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
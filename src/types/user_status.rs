@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDUserStatus: Debug + RObject {}
 
 /// Describes the last time the user was online
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum UserStatus {
     #[doc(hidden)]
@@ -32,6 +32,12 @@ pub enum UserStatus {
     /// The user was online recently
     #[serde(rename = "userStatusRecently")]
     Recently(UserStatusRecently),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for UserStatus {
@@ -44,6 +50,7 @@ impl RObject for UserStatus {
             UserStatus::Offline(t) => t.extra(),
             UserStatus::Online(t) => t.extra(),
             UserStatus::Recently(t) => t.extra(),
+            UserStatus::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -57,12 +64,33 @@ impl RObject for UserStatus {
             UserStatus::Offline(t) => t.client_id(),
             UserStatus::Online(t) => t.client_id(),
             UserStatus::Recently(t) => t.client_id(),
+            UserStatus::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for UserStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "userStatusEmpty" => UserStatus::Empty(super::_common::from_raw(raw)?),
+            "userStatusLastMonth" => UserStatus::LastMonth(super::_common::from_raw(raw)?),
+            "userStatusLastWeek" => UserStatus::LastWeek(super::_common::from_raw(raw)?),
+            "userStatusOffline" => UserStatus::Offline(super::_common::from_raw(raw)?),
+            "userStatusOnline" => UserStatus::Online(super::_common::from_raw(raw)?),
+            "userStatusRecently" => UserStatus::Recently(super::_common::from_raw(raw)?),
+            _ => UserStatus::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl UserStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
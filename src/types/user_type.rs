@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDUserType: Debug + RObject {}
 
 /// Represents the type of a user. The following types are possible: regular users, deleted users and bots
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum UserType {
     #[doc(hidden)]
@@ -26,6 +26,12 @@ pub enum UserType {
     /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
     #[serde(rename = "userTypeUnknown")]
     Unknown(UserTypeUnknown),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    UnknownType {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for UserType {
@@ -36,6 +42,7 @@ impl RObject for UserType {
             UserType::Deleted(t) => t.extra(),
             UserType::Regular(t) => t.extra(),
             UserType::Unknown(t) => t.extra(),
+            UserType::UnknownType { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -47,12 +54,31 @@ impl RObject for UserType {
             UserType::Deleted(t) => t.client_id(),
             UserType::Regular(t) => t.client_id(),
             UserType::Unknown(t) => t.client_id(),
+            UserType::UnknownType { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for UserType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "userTypeBot" => UserType::Bot(super::_common::from_raw(raw)?),
+            "userTypeDeleted" => UserType::Deleted(super::_common::from_raw(raw)?),
+            "userTypeRegular" => UserType::Regular(super::_common::from_raw(raw)?),
+            "userTypeUnknown" => UserType::Unknown(super::_common::from_raw(raw)?),
+            _ => UserType::UnknownType { type_name, raw },
+        })
+    }
+}
+
 impl UserType {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
@@ -25,7 +25,7 @@ pub struct ValidateOrderInfo {
     #[serde(default)]
     allow_save: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -40,7 +40,9 @@ impl RObject for ValidateOrderInfo {
     }
 }
 
-impl RFunction for ValidateOrderInfo {}
+impl RFunction for ValidateOrderInfo {
+    type Response = ValidatedOrderInfo;
+}
 
 impl ValidateOrderInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
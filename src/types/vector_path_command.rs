@@ -8,7 +8,7 @@ use std::fmt::Debug;
 pub trait TDVectorPathCommand: Debug + RObject {}
 
 /// Represents a vector path command
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 #[serde(tag = "@type")]
 pub enum VectorPathCommand {
     #[doc(hidden)]
@@ -20,6 +20,12 @@ pub enum VectorPathCommand {
     /// A straight line to a given point
     #[serde(rename = "vectorPathCommandLine")]
     Line(VectorPathCommandLine),
+    /// Object of a type unknown to this version of the library, e.g. sent by a newer TDLib
+    #[serde(untagged, serialize_with = "super::_common::serialize_unknown")]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl RObject for VectorPathCommand {
@@ -28,6 +34,7 @@ impl RObject for VectorPathCommand {
         match self {
             VectorPathCommand::CubicBezierCurve(t) => t.extra(),
             VectorPathCommand::Line(t) => t.extra(),
+            VectorPathCommand::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()),
 
             _ => None,
         }
@@ -37,12 +44,31 @@ impl RObject for VectorPathCommand {
         match self {
             VectorPathCommand::CubicBezierCurve(t) => t.client_id(),
             VectorPathCommand::Line(t) => t.client_id(),
+            VectorPathCommand::Unknown { raw, .. } => raw
+                .get("@client_id")
+                .and_then(|c| c.as_i64())
+                .map(|c| c as i32),
 
             _ => None,
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for VectorPathCommand {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let (type_name, raw) = super::_common::deserialize_unknown(deserializer)?;
+        Ok(match type_name.as_str() {
+            "vectorPathCommandCubicBezierCurve" => {
+                VectorPathCommand::CubicBezierCurve(super::_common::from_raw(raw)?)
+            }
+            "vectorPathCommandLine" => VectorPathCommand::Line(super::_common::from_raw(raw)?),
+            _ => VectorPathCommand::Unknown { type_name, raw },
+        })
+    }
+}
+
 impl VectorPathCommand {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
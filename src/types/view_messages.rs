@@ -27,7 +27,7 @@ pub struct ViewMessages {
     #[serde(default)]
     force_read: bool,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for ViewMessages {
     }
 }
 
-impl RFunction for ViewMessages {}
+impl RFunction for ViewMessages {
+    type Response = Ok;
+}
 
 impl ViewMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
@@ -16,7 +16,7 @@ pub struct ViewTrendingStickerSets {
     #[serde(default)]
     sticker_set_ids: Vec<i64>,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -31,7 +31,9 @@ impl RObject for ViewTrendingStickerSets {
     }
 }
 
-impl RFunction for ViewTrendingStickerSets {}
+impl RFunction for ViewTrendingStickerSets {
+    type Response = Ok;
+}
 
 impl ViewTrendingStickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
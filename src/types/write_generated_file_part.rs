@@ -27,7 +27,7 @@ pub struct WriteGeneratedFilePart {
     #[serde(default)]
     data: String,
 
-    #[serde(rename(serialize = "@type"))]
+    #[serde(rename(serialize = "@type", deserialize = "@type"))]
     td_type: String,
 }
 
@@ -42,7 +42,9 @@ impl RObject for WriteGeneratedFilePart {
     }
 }
 
-impl RFunction for WriteGeneratedFilePart {}
+impl RFunction for WriteGeneratedFilePart {
+    type Response = Ok;
+}
 
 impl WriteGeneratedFilePart {
     pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {